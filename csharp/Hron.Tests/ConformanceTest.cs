@@ -289,9 +289,9 @@ public partial class ConformanceTest
 
     // Matches tests
 
-    public static TheoryData<string, string, string, bool> GetMatchesTests()
+    public static TheoryData<string, string, string, bool, string?> GetMatchesTests()
     {
-        var data = new TheoryData<string, string, string, bool>();
+        var data = new TheoryData<string, string, string, bool, string?>();
         if (!Spec.RootElement.GetProperty("eval").TryGetProperty("matches", out var matchesSection)) return data;
         if (!matchesSection.TryGetProperty("tests", out var tests)) return data;
 
@@ -300,8 +300,9 @@ public partial class ConformanceTest
             var name = tc.GetProperty("name").GetString()!;
             var expression = tc.GetProperty("expression").GetString()!;
             var datetime = tc.GetProperty("datetime").GetString()!;
-            var expected = tc.GetProperty("expected").GetBoolean();
-            data.Add(name, expression, datetime, expected);
+            var expected = tc.TryGetProperty("expected", out var expectedProp) && expectedProp.GetBoolean();
+            var errorContains = tc.TryGetProperty("error_contains", out var errorProp) ? errorProp.GetString() : null;
+            data.Add(name, expression, datetime, expected, errorContains);
         }
 
         return data;
@@ -309,11 +310,17 @@ public partial class ConformanceTest
 
     [Theory]
     [MemberData(nameof(GetMatchesTests))]
-    public void MatchesTests(string _name, string expression, string datetimeStr, bool expected)
+    public void MatchesTests(string _name, string expression, string datetimeStr, bool expected, string? errorContains)
     {
         _ = _name; // Used for test display
         var s = Schedule.Parse(expression);
         var datetime = ParseZonedDateTime(datetimeStr);
+        if (errorContains != null)
+        {
+            var ex = Assert.Throws<HronException>(() => s.Matches(datetime));
+            Assert.Contains(errorContains, ex.Message);
+            return;
+        }
         var result = s.Matches(datetime);
 
         Assert.Equal(expected, result);
@@ -450,9 +457,11 @@ public partial class ConformanceTest
     public static TheoryData<string, string, string> GetToCronTests()
     {
         var data = new TheoryData<string, string, string>();
-        var tests = Spec.RootElement.GetProperty("cron").GetProperty("to_cron").GetProperty("tests");
+        var cronSpec = Spec.RootElement.GetProperty("cron");
+        var tests = cronSpec.GetProperty("to_cron").GetProperty("tests").EnumerateArray()
+            .Concat(cronSpec.GetProperty("to_cron_during").GetProperty("tests").EnumerateArray());
 
-        foreach (var tc in tests.EnumerateArray())
+        foreach (var tc in tests)
         {
             var name = tc.GetProperty("name").GetString()!;
             var hron = tc.GetProperty("hron").GetString()!;
@@ -313,6 +313,32 @@ public class IteratorTest
         Assert.Single(results);
     }
 
+    [Fact]
+    public void UpcomingWeekdayOccurrenceCannotBeMatched()
+    {
+        var schedule = Schedule.Parse("on friday at 17:00 in UTC");
+        var from = ParseZoned("2026-02-06T12:00:00+00:00[UTC]");
+
+        var results = schedule.Occurrences(from).Take(10).ToList();
+
+        Assert.Single(results);
+        var ex = Assert.Throws<HronException>(() => schedule.Matches(results[0]));
+        Assert.Contains("no evaluation time", ex.Message);
+    }
+
+    [Fact]
+    public void MatchesRejectsRelativeAnchor()
+    {
+        var now = ParseZoned("2026-02-06T12:00:00+00:00[UTC]");
+
+        foreach (var anchor in new[] { "today", "now" })
+        {
+            var schedule = Schedule.Parse($"every 2 days at 09:00 starting {anchor} in UTC");
+            var ex = Assert.Throws<HronException>(() => schedule.Matches(now));
+            Assert.Contains("no evaluation time", ex.Message);
+        }
+    }
+
     // =========================================================================
     // Timezone Handling
     // =========================================================================
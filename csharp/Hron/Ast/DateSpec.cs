@@ -2,8 +2,10 @@ namespace Hron.Ast;
 
 /// <summary>
 /// Represents a date specification (either named like "feb 14" or ISO like "2026-03-15").
+/// A next-weekday date ("on friday") is the nearest upcoming day with that weekday, resolved
+/// against the evaluation time rather than a fixed date.
 /// </summary>
-public sealed record DateSpec(DateSpecKind Kind, MonthName? Month, int Day, string? Date)
+public sealed record DateSpec(DateSpecKind Kind, MonthName? Month, int Day, string? Date, Weekday? Weekday = null)
 {
     /// <summary>
     /// Creates a named date specification.
@@ -16,6 +18,12 @@ public sealed record DateSpec(DateSpecKind Kind, MonthName? Month, int Day, stri
     /// </summary>
     public static DateSpec Iso(string date)
         => new(DateSpecKind.Iso, null, 0, date);
+
+    /// <summary>
+    /// Creates a next-weekday date specification.
+    /// </summary>
+    public static DateSpec NextWeekday(Weekday weekday)
+        => new(DateSpecKind.NextWeekday, null, 0, null, weekday);
 }
 
 /// <summary>
@@ -26,5 +34,7 @@ public enum DateSpecKind
     /// <summary>A named date (e.g., feb 14).</summary>
     Named,
     /// <summary>An ISO date (e.g., 2026-03-15).</summary>
-    Iso
+    Iso,
+    /// <summary>The nearest upcoming day with a weekday (e.g., friday).</summary>
+    NextWeekday
 }
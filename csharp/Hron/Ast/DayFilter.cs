@@ -5,6 +5,12 @@ namespace Hron.Ast;
 /// </summary>
 public sealed record DayFilter(DayFilterKind Kind, IReadOnlyList<Weekday> Days)
 {
+    private static readonly Ast.Weekday[] Weekdays =
+    [
+        Ast.Weekday.Monday, Ast.Weekday.Tuesday, Ast.Weekday.Wednesday,
+        Ast.Weekday.Thursday, Ast.Weekday.Friday
+    ];
+
     /// <summary>
     /// Creates a filter that matches every day.
     /// </summary>
@@ -24,6 +30,30 @@ public sealed record DayFilter(DayFilterKind Kind, IReadOnlyList<Weekday> Days)
     /// Creates a filter that matches specific days.
     /// </summary>
     public static DayFilter SpecificDays(IReadOnlyList<Weekday> days) => new(DayFilterKind.Days, days);
+
+    /// <summary>
+    /// Returns the canonical form of this filter. An explicit day list is sorted and
+    /// de-duplicated, and one covering exactly the weekdays or all seven days collapses into
+    /// the equivalent keyword filter. "saturday, sunday" stays a list: "weekend" is a keyword
+    /// of its own.
+    /// </summary>
+    public DayFilter Canonical()
+    {
+        if (Kind != DayFilterKind.Days)
+        {
+            return this;
+        }
+        var sorted = Days.Distinct().Order().ToList();
+        if (sorted.Count == 7)
+        {
+            return Every();
+        }
+        if (sorted.SequenceEqual(Weekdays))
+        {
+            return Weekday();
+        }
+        return SpecificDays(sorted);
+    }
 }
 
 /// <summary>
@@ -0,0 +1,74 @@
+namespace Hron.Ast;
+
+/// <summary>
+/// Represents a single month, inclusive month range, or inclusive month-day range in a
+/// during clause. Wraps past December when start is after end: "nov to feb" is nov, dec,
+/// jan, feb. Month forms leave the days at zero; an end of feb 29 means the last day of
+/// February in common years.
+/// </summary>
+/// <param name="Start">The first month</param>
+/// <param name="End">The last month (equal to Start for a single month)</param>
+/// <param name="StartDay">The first day in the start month, or 0 for a whole-month bound</param>
+/// <param name="EndDay">The last day in the end month, or 0 for a whole-month bound</param>
+public sealed record DuringSpec(MonthName Start, MonthName End, int StartDay = 0, int EndDay = 0)
+{
+    /// <summary>
+    /// Creates a single month specification.
+    /// </summary>
+    public static DuringSpec Month(MonthName month) => new(month, month);
+
+    /// <summary>
+    /// Creates a whole-month range specification.
+    /// </summary>
+    public static DuringSpec Months(MonthName start, MonthName end) => new(start, end);
+
+    /// <summary>
+    /// Returns the first day this specification covers in its start month.
+    /// </summary>
+    public int FirstDay => StartDay == 0 ? 1 : StartDay;
+
+    /// <summary>
+    /// Returns the last day this specification covers in its end month. Whole months end on
+    /// their longest day (feb 29).
+    /// </summary>
+    public int LastDay => EndDay == 0 ? End.MaxDay() : EndDay;
+
+    /// <summary>
+    /// Checks whether a month-day falls within this specification.
+    /// </summary>
+    public bool Contains(MonthName month, int day)
+    {
+        var key = month.Number() * 100 + day;
+        var first = Start.Number() * 100 + FirstDay;
+        var last = End.Number() * 100 + LastDay;
+        if (first <= last)
+        {
+            return first <= key && key <= last;
+        }
+        return key >= first || key <= last;
+    }
+
+    /// <summary>
+    /// Checks whether this specification covers whole months: "jun 1 to aug 31" is "jun to aug".
+    /// </summary>
+    public bool WholeMonths => FirstDay == 1 && LastDay == End.MaxDay();
+
+    /// <summary>
+    /// Returns the months this specification touches, in calendar order from the start.
+    /// </summary>
+    public IReadOnlyList<MonthName> Expand()
+    {
+        var length = End.Number() - Start.Number() + 1;
+        // "jun 15 to jun 10" wraps through every month
+        if (length <= 0 || (length == 1 && FirstDay > LastDay))
+        {
+            length += 12;
+        }
+        var months = new List<MonthName>(length);
+        for (var i = 0; i < length; i++)
+        {
+            months.Add((MonthName)((Start.Number() - 1 + i) % 12 + 1));
+        }
+        return months;
+    }
+}
@@ -1,9 +1,18 @@
 namespace Hron.Ast;
 
 /// <summary>
-/// Represents an exception date.
+/// Represents an exception date, date range, or day of the week. Ranges keep their start in
+/// Month/Day/Date and their end in EndMonth/EndDay/EndDate.
 /// </summary>
-public sealed record ExceptionSpec(ExceptionSpecKind Kind, MonthName? Month, int Day, string? Date)
+public sealed record ExceptionSpec(
+    ExceptionSpecKind Kind,
+    MonthName? Month,
+    int Day,
+    string? Date,
+    Weekday? Weekday = null,
+    MonthName? EndMonth = null,
+    int EndDay = 0,
+    string? EndDate = null)
 {
     /// <summary>
     /// Creates a named exception specification.
@@ -16,6 +25,25 @@ public sealed record ExceptionSpec(ExceptionSpecKind Kind, MonthName? Month, int
     /// </summary>
     public static ExceptionSpec Iso(string date)
         => new(ExceptionSpecKind.Iso, null, 0, date);
+
+    /// <summary>
+    /// Creates an exception for every occurrence of a day of the week.
+    /// </summary>
+    public static ExceptionSpec DayOfWeek(Weekday weekday)
+        => new(ExceptionSpecKind.Weekday, null, 0, null, weekday);
+
+    /// <summary>
+    /// Creates a one-off inclusive ISO date range exception: "2026-12-24 to 2026-12-31".
+    /// </summary>
+    public static ExceptionSpec IsoRange(string start, string end)
+        => new(ExceptionSpecKind.IsoRange, null, 0, start, EndDate: end);
+
+    /// <summary>
+    /// Creates a recurring inclusive range of named dates: "dec 24 to jan 2" matches every year,
+    /// wrapping past december when the end comes first.
+    /// </summary>
+    public static ExceptionSpec NamedRange(MonthName startMonth, int startDay, MonthName endMonth, int endDay)
+        => new(ExceptionSpecKind.NamedRange, startMonth, startDay, null, EndMonth: endMonth, EndDay: endDay);
 }
 
 /// <summary>
@@ -26,5 +54,11 @@ public enum ExceptionSpecKind
     /// <summary>A named exception (e.g., dec 25).</summary>
     Named,
     /// <summary>An ISO exception (e.g., 2026-12-25).</summary>
-    Iso
+    Iso,
+    /// <summary>A day-of-week exception (e.g., friday).</summary>
+    Weekday,
+    /// <summary>A one-off inclusive ISO date range (e.g., 2026-12-24 to 2026-12-31).</summary>
+    IsoRange,
+    /// <summary>A recurring inclusive range of named dates (e.g., dec 24 to jan 2).</summary>
+    NamedRange
 }
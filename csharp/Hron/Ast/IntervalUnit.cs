@@ -1,12 +1,17 @@
 namespace Hron.Ast;
 
 /// <summary>
-/// Represents the unit of an interval (minutes or hours).
+/// Represents the unit of an interval (minutes, hours, or days).
 /// </summary>
 public enum IntervalUnit
 {
     Minutes,
-    Hours
+    Hours,
+    /// <summary>
+    /// Day-granularity step: the window is active on every Nth day (aligned like
+    /// "every N days at ...") and fires once, at the window start.
+    /// </summary>
+    Days
 }
 
 public static class IntervalUnitExtensions
@@ -18,6 +23,7 @@ public static class IntervalUnitExtensions
     {
         IntervalUnit.Minutes => interval == 1 ? "minute" : "min",
         IntervalUnit.Hours => interval == 1 ? "hour" : "hours",
+        IntervalUnit.Days => interval == 1 ? "day" : "days",
         _ => throw new ArgumentOutOfRangeException(nameof(unit))
     };
 }
@@ -0,0 +1,9 @@
+namespace Hron.Ast;
+
+/// <summary>
+/// An ISO week repeat expression like "every year in iso weeks 1, 27 on monday at 9:00".
+/// </summary>
+/// <param name="Weeks">The ISO 8601 week numbers (1-53) to fire in</param>
+/// <param name="Days">The days of the week to fire</param>
+/// <param name="Times">The times of day to fire</param>
+public sealed record IsoWeekRepeat(IReadOnlyList<int> Weeks, IReadOnlyList<Weekday> Days, IReadOnlyList<TimeOfDay> Times) : IScheduleExpr;
@@ -59,6 +59,16 @@ public static class MonthNameExtensions
     /// </summary>
     public static int Number(this MonthName month) => (int)month;
 
+    /// <summary>
+    /// Returns the month's longest length: February has 29 days.
+    /// </summary>
+    public static int MaxDay(this MonthName month) => month switch
+    {
+        MonthName.February => 29,
+        MonthName.April or MonthName.June or MonthName.September or MonthName.November => 30,
+        _ => 31
+    };
+
     /// <summary>
     /// Returns the short display name (e.g., "jan", "feb").
     /// </summary>
@@ -20,7 +20,9 @@ public sealed record MonthTarget(
     int NearestWeekdayDay = 0,
     NearestDirection? NearestWeekdayDirection = null,
     OrdinalPosition? OrdinalValue = null,
-    Weekday? WeekdayValue = null)
+    Weekday? WeekdayValue = null,
+    int Count = 0,
+    IReadOnlyList<OrdinalPosition>? Ordinals = null)
 {
     /// <summary>
     /// Creates a month target for specific days.
@@ -34,6 +36,21 @@ public sealed record MonthTarget(
     public static MonthTarget LastDay() =>
         new(MonthTargetKind.LastDay, []);
 
+    /// <summary>
+    /// Creates a month target for the final n calendar days of the month ("the last 3 days"),
+    /// n &gt;= 2. It counts back from the month's real length; n beyond it covers the whole month.
+    /// </summary>
+    public static MonthTarget LastNDays(int n) =>
+        new(MonthTargetKind.LastNDays, [], Count: n);
+
+    /// <summary>
+    /// Creates a month target for the nth Monday-to-Friday day of the month ("the first business
+    /// day", "the 3rd weekday"), n in 1-23. Excepted dates are not business days, so an except
+    /// landing on it moves the occurrence to the next one.
+    /// </summary>
+    public static MonthTarget BusinessDay(int n) =>
+        new(MonthTargetKind.BusinessDay, [], Count: n);
+
     /// <summary>
     /// Creates a month target for the last weekday of the month.
     /// </summary>
@@ -56,6 +73,15 @@ public sealed record MonthTarget(
     public static MonthTarget OrdinalWeekday(OrdinalPosition ordinal, Weekday weekday) =>
         new(MonthTargetKind.OrdinalWeekday, [], OrdinalValue: ordinal, WeekdayValue: weekday);
 
+    /// <summary>
+    /// Creates a month target for several ordinal weekdays (e.g., the first and last friday).
+    /// A single ordinal is an ordinal weekday target instead.
+    /// </summary>
+    /// <param name="ordinals">The sorted, distinct ordinal positions.</param>
+    /// <param name="weekday">The day of the week.</param>
+    public static MonthTarget OrdinalWeekdays(IReadOnlyList<OrdinalPosition> ordinals, Weekday weekday) =>
+        new(MonthTargetKind.OrdinalWeekdays, [], WeekdayValue: weekday, Ordinals: ordinals);
+
     /// <summary>
     /// Returns all days specified by this target (for DAYS kind only).
     /// </summary>
@@ -84,10 +110,16 @@ public enum MonthTargetKind
     Days,
     /// <summary>The last day of the month.</summary>
     LastDay,
+    /// <summary>The final N calendar days of the month (e.g., last 3 days).</summary>
+    LastNDays,
     /// <summary>The last weekday (Mon-Fri) of the month.</summary>
     LastWeekday,
     /// <summary>Nearest weekday to a given day of the month.</summary>
     NearestWeekday,
     /// <summary>Ordinal weekday of month (e.g., first monday, last friday).</summary>
-    OrdinalWeekday
+    OrdinalWeekday,
+    /// <summary>Several ordinal weekdays of month (e.g., first and last friday).</summary>
+    OrdinalWeekdays,
+    /// <summary>The nth Monday-to-Friday day of the month (e.g., first business day).</summary>
+    BusinessDay
 }
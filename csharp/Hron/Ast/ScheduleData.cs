@@ -7,49 +7,77 @@ namespace Hron.Ast;
 /// <param name="Timezone">The IANA timezone (may be null)</param>
 /// <param name="Except">The exception dates</param>
 /// <param name="Until">The until date (may be null)</param>
-/// <param name="Anchor">The anchor date for interval alignment (ISO string, may be null)</param>
-/// <param name="During">The months during which the schedule applies</param>
+/// <param name="Anchor">The anchor date for interval alignment (ISO string, "today", or "now"; may be null)</param>
+/// <param name="During">The during clause as written: months, month ranges, and date ranges</param>
 public sealed record ScheduleData(
     IScheduleExpr Expr,
     string? Timezone,
     IReadOnlyList<ExceptionSpec> Except,
     UntilSpec? Until,
     string? Anchor,
-    IReadOnlyList<MonthName> During)
+    IReadOnlyList<DuringSpec> During)
 {
+    /// <summary>
+    /// A month-day anchor ("starting jan 6"), resolved to the next such date at evaluation.
+    /// </summary>
+    public DateSpec? AnchorNamed { get; init; }
+
+    /// <summary>
+    /// The time on the anchor date before which nothing occurs ("starting ... at 09:00").
+    /// </summary>
+    public TimeOfDay? AnchorTime { get; init; }
+
     /// <summary>
     /// Creates a new ScheduleData with just the expression.
     /// </summary>
     public static ScheduleData Of(IScheduleExpr expr)
         => new(expr, null, [], null, null, []);
 
+    /// <summary>
+    /// Returns the months any during spec touches, for month-level skipping.
+    /// </summary>
+    public IReadOnlyList<MonthName> DuringMonths()
+        => During.SelectMany(spec => spec.Expand()).ToList();
+
+    /// <summary>
+    /// Whether the anchor is resolved against the evaluation time: "today", "now", or a month-day.
+    /// </summary>
+    public bool HasRelativeAnchor => AnchorNamed is not null || Anchor is "today" or "now";
+
+    /// <summary>
+    /// The starting anchor as written, without its time; null without a starting clause.
+    /// </summary>
+    public string? AnchorText => AnchorNamed is { } named
+        ? $"{named.Month!.Value.ToDisplayString()} {named.Day}"
+        : Anchor;
+
     /// <summary>
     /// Returns a copy with the specified timezone.
     /// </summary>
     public ScheduleData WithTimezone(string? timezone)
-        => new(Expr, timezone, Except, Until, Anchor, During);
+        => this with { Timezone = timezone };
 
     /// <summary>
     /// Returns a copy with the specified exceptions.
     /// </summary>
     public ScheduleData WithExcept(IReadOnlyList<ExceptionSpec> except)
-        => new(Expr, Timezone, except, Until, Anchor, During);
+        => this with { Except = except };
 
     /// <summary>
     /// Returns a copy with the specified until date.
     /// </summary>
     public ScheduleData WithUntil(UntilSpec? until)
-        => new(Expr, Timezone, Except, until, Anchor, During);
+        => this with { Until = until };
 
     /// <summary>
     /// Returns a copy with the specified anchor date.
     /// </summary>
     public ScheduleData WithAnchor(string? anchor)
-        => new(Expr, Timezone, Except, Until, anchor, During);
+        => this with { Anchor = anchor, AnchorNamed = null };
 
     /// <summary>
     /// Returns a copy with the specified during months.
     /// </summary>
     public ScheduleData WithDuring(IReadOnlyList<MonthName> during)
-        => new(Expr, Timezone, Except, Until, Anchor, during);
+        => this with { During = during.Select(DuringSpec.Month).ToList() };
 }
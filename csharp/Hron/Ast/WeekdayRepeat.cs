@@ -0,0 +1,10 @@
+namespace Hron.Ast;
+
+/// <summary>
+/// A business-day interval expression like "every 3 weekdays at 9:00". Only Monday through Friday
+/// count toward the interval, so weekends neither fire nor advance it. Days are counted from the
+/// starting anchor (or its next weekday), defaulting to 1970-01-01.
+/// </summary>
+/// <param name="Interval">The number of business days between firings (2 or more)</param>
+/// <param name="Times">The times of day to fire</param>
+public sealed record WeekdayRepeat(int Interval, IReadOnlyList<TimeOfDay> Times) : IScheduleExpr;
@@ -1,3 +1,4 @@
+using System.Globalization;
 using Hron.Ast;
 
 namespace Hron.Cron;
@@ -20,44 +21,90 @@ public static class CronConverter
         {
             throw HronException.Cron("not expressible as cron (until clauses not supported)");
         }
-        if (data.During.Count > 0)
+        foreach (var spec in data.During)
         {
-            throw HronException.Cron("not expressible as cron (during clauses not supported)");
+            if (!spec.WholeMonths)
+            {
+                throw HronException.Cron("not expressible as cron (during dates narrower than whole months)");
+            }
         }
+        var month = MonthField(data.DuringMonths());
 
         return data.Expr switch
         {
-            DayRepeat dr => DayRepeatToCron(dr),
-            IntervalRepeat ir => IntervalRepeatToCron(ir),
+            DayRepeat dr => DayRepeatToCron(dr, month),
+            IntervalRepeat ir => IntervalRepeatToCron(ir, month),
             WeekRepeat => throw HronException.Cron("not expressible as cron (multi-week intervals not supported)"),
-            MonthRepeat mr => MonthRepeatToCron(mr),
+            MonthRepeat mr => MonthRepeatToCron(mr, month),
             SingleDate => throw HronException.Cron("not expressible as cron (single dates are not repeating)"),
             YearRepeat => throw HronException.Cron("not expressible as cron (yearly schedules not supported in 5-field cron)"),
+            IsoWeekRepeat => throw HronException.Cron("not expressible as cron (cron has no week-of-year field)"),
+            WeekdayRepeat => throw HronException.Cron("not expressible as cron (business-day intervals not supported)"),
             _ => throw new ArgumentException($"Unknown expression type: {data.Expr.GetType()}", nameof(data))
         };
     }
 
-    private static string DayRepeatToCron(DayRepeat dr)
+    // Returns the cron month field for a during clause. Runs of three or more
+    // consecutive months collapse into a range.
+    private static string MonthField(IReadOnlyList<MonthName> during)
+    {
+        var months = during.Select(m => m.Number()).Distinct().Order().ToList();
+        if (months.Count == 0)
+        {
+            return "*";
+        }
+
+        var parts = new List<string>();
+        for (var i = 0; i < months.Count;)
+        {
+            var j = i;
+            while (j + 1 < months.Count && months[j + 1] == months[j] + 1)
+            {
+                j++;
+            }
+            if (j - i >= 2)
+            {
+                parts.Add($"{months[i]}-{months[j]}");
+            }
+            else
+            {
+                for (var k = i; k <= j; k++)
+                {
+                    parts.Add(months[k].ToString());
+                }
+            }
+            i = j + 1;
+        }
+        return string.Join(",", parts);
+    }
+
+    private static string DayRepeatToCron(DayRepeat dr, string month)
     {
         if (dr.Interval > 1)
         {
             throw HronException.Cron("not expressible as cron (multi-day intervals not supported)");
         }
-        if (dr.Times.Count != 1)
+        var time = TimeFields(dr.Times);
+        if (time is null)
         {
             throw HronException.Cron("not expressible as cron (multiple times not supported)");
         }
 
-        var t = dr.Times[0];
         var dow = DayFilterToCronDOW(dr.Days);
 
-        return $"{t.Minute} {t.Hour} * * {dow}";
+        return $"{time} * {month} {dow}";
     }
 
-    private static string IntervalRepeatToCron(IntervalRepeat ir)
+    private static string IntervalRepeatToCron(IntervalRepeat ir, string month)
     {
+        if (ir.Unit == IntervalUnit.Days)
+        {
+            throw HronException.Cron("not expressible as cron (multi-day interval windows not supported)");
+        }
+        // Hour steps may start past the hour: "from 00:15" fires at :15
+        var offsetOk = ir.FromTime.Minute == 0 || ir.Unit == IntervalUnit.Hours;
         var fullDay = ir.FromTime.Hour == 0
-            && ir.FromTime.Minute == 0
+            && offsetOk
             && ir.ToTime.Hour == 23
             && ir.ToTime.Minute == 59;
 
@@ -76,39 +123,65 @@ public static class CronConverter
             {
                 throw HronException.Cron($"not expressible as cron (*/{ir.Interval} breaks at hour boundaries)");
             }
-            return $"*/{ir.Interval} * * * *";
+            return $"*/{ir.Interval} * * {month} *";
         }
 
         // Hours
-        return $"0 */{ir.Interval} * * *";
+        if (ir.Interval == 1 && ir.FromTime.Minute != 0)
+        {
+            return $"{ir.FromTime.Minute} * * {month} *";
+        }
+        return $"{ir.FromTime.Minute} */{ir.Interval} * {month} *";
     }
 
-    private static string MonthRepeatToCron(MonthRepeat mr)
+    private static string MonthRepeatToCron(MonthRepeat mr, string month)
     {
         if (mr.Interval > 1)
         {
             throw HronException.Cron("not expressible as cron (multi-month intervals not supported)");
         }
-        if (mr.Times.Count != 1)
+        var time = TimeFields(mr.Times);
+        if (time is null)
         {
             throw HronException.Cron("not expressible as cron (multiple times not supported)");
         }
 
-        var t = mr.Times[0];
-
         return mr.Target.Kind switch
         {
-            MonthTargetKind.Days => $"{t.Minute} {t.Hour} {FormatIntList(mr.Target.ExpandDays())} * *",
+            MonthTargetKind.Days => $"{time} {FormatIntList(mr.Target.ExpandDays())} {month} *",
             MonthTargetKind.LastDay => throw HronException.Cron("not expressible as cron (last day of month not supported)"),
+            MonthTargetKind.LastNDays => throw HronException.Cron("not expressible as cron (last days of month not supported)"),
+            // The first business day is always the weekday nearest the 1st
+            MonthTargetKind.BusinessDay when mr.Target.Count == 1 => $"{time} 1W {month} *",
+            MonthTargetKind.BusinessDay => throw HronException.Cron("not expressible as cron (nth business day of month not supported)"),
             MonthTargetKind.LastWeekday => throw HronException.Cron("not expressible as cron (last weekday of month not supported)"),
             MonthTargetKind.NearestWeekday when mr.Target.NearestWeekdayDirection.HasValue =>
                 throw HronException.Cron("not expressible as cron (directional nearest weekday not supported)"),
-            MonthTargetKind.NearestWeekday => $"{t.Minute} {t.Hour} {mr.Target.NearestWeekdayDay}W * *",
+            MonthTargetKind.NearestWeekday => $"{time} {mr.Target.NearestWeekdayDay}W {month} *",
             MonthTargetKind.OrdinalWeekday => throw HronException.Cron("not expressible as cron (ordinal weekday of month not supported)"),
+            MonthTargetKind.OrdinalWeekdays => throw HronException.Cron("not expressible as cron (ordinal weekdays of month not supported)"),
             _ => throw new ArgumentException("Unknown month target kind")
         };
     }
 
+    /// <summary>
+    /// Returns the exact minute and hour fields for a list of times: "0 9,17", "0,30 9", or
+    /// "0,30 9,17" when the times are every listed minute at every listed hour. Returns null when
+    /// comma fields would fire at times not in the list.
+    /// </summary>
+    private static string? TimeFields(IReadOnlyList<TimeOfDay> times)
+    {
+        var distinct = times.Select(t => t.TotalMinutes).Distinct().Count();
+        var minutes = times.Select(t => t.Minute).Distinct().Order().ToList();
+        var hours = times.Select(t => t.Hour).Distinct().Order().ToList();
+        // Distinct times are a subset of the grid, so equal counts mean equal sets
+        if (minutes.Count * hours.Count != distinct)
+        {
+            return null;
+        }
+        return $"{FormatIntList(minutes)} {FormatIntList(hours)}";
+    }
+
     private static string DayFilterToCronDOW(DayFilter f)
     {
         return f.Kind switch
@@ -188,52 +261,60 @@ public static class CronConverter
         }
 
         // Standard time-based cron
-        var minute = ParseSingleValue(minuteField, "minute", 0, 59);
-        var hour = ParseSingleValue(hourField, "hour", 0, 23);
-        var time = new TimeOfDay(hour, minute);
+        var times = ParseCronTimes(minuteField, hourField);
 
         // DOM-based (monthly) - when DOM is specified and DOW is *
         if (domField != "*" && dowField == "*")
         {
             var target = ParseDomField(domField);
-            return ScheduleData.Of(new MonthRepeat(1, target, [time])).WithDuring(during);
+            return ScheduleData.Of(new MonthRepeat(1, target, times)).WithDuring(during);
         }
 
         // DOW-based (day repeat)
         var days = ParseCronDOW(dowField);
-        return ScheduleData.Of(new DayRepeat(1, days, [time])).WithDuring(during);
+        return ScheduleData.Of(new DayRepeat(1, days, times)).WithDuring(during);
+    }
+
+    /// <summary>
+    /// Converts a cron @ shortcut like "@daily" to a ScheduleData.
+    /// </summary>
+    internal static ScheduleData ParseCronShortcut(string cron)
+    {
+        var expr = CronShortcutExpr(cron.ToLowerInvariant())
+            ?? throw HronException.Cron($"unknown @ shortcut: {cron}");
+        return ScheduleData.Of(expr);
     }
 
     /// <summary>
-    /// Parse @ shortcuts like @daily, @hourly, etc.
+    /// The expression a lowercase cron @ shortcut stands for, or null if it is not one.
     /// </summary>
-    private static ScheduleData ParseCronShortcut(string cron)
+    internal static IScheduleExpr? CronShortcutExpr(string shortcut)
     {
-        return cron.ToLowerInvariant() switch
+        return shortcut switch
         {
-            "@yearly" or "@annually" => ScheduleData.Of(new YearRepeat(
+            "@yearly" or "@annually" => new YearRepeat(
                 1,
                 YearTarget.Date(MonthName.January, 1),
-                [new TimeOfDay(0, 0)])),
-            "@monthly" => ScheduleData.Of(new MonthRepeat(
+                [new TimeOfDay(0, 0)]),
+            "@monthly" => new MonthRepeat(
                 1,
                 MonthTarget.Days([DayOfMonthSpec.Single(1)]),
-                [new TimeOfDay(0, 0)])),
-            "@weekly" => ScheduleData.Of(new DayRepeat(
+                [new TimeOfDay(0, 0)]),
+            "@weekly" => new DayRepeat(
                 1,
                 DayFilter.SpecificDays([Weekday.Sunday]),
-                [new TimeOfDay(0, 0)])),
-            "@daily" or "@midnight" => ScheduleData.Of(new DayRepeat(
+                [new TimeOfDay(0, 0)]),
+            "@daily" or "@midnight" => new DayRepeat(
                 1,
                 DayFilter.Every(),
-                [new TimeOfDay(0, 0)])),
-            "@hourly" => ScheduleData.Of(new IntervalRepeat(
+                [new TimeOfDay(0, 0)]),
+            "@hourly" => new IntervalRepeat(
                 1,
                 IntervalUnit.Hours,
                 new TimeOfDay(0, 0),
                 new TimeOfDay(23, 59),
-                null)),
-            _ => throw HronException.Cron($"unknown @ shortcut: {cron}")
+                null),
+            _ => null
         };
     }
 
@@ -384,10 +465,9 @@ public static class CronConverter
                 throw HronException.Cron("DOM must be * when using # for nth weekday");
             }
 
-            var minute = ParseSingleValue(minuteField, "minute", 0, 59);
-            var hour = ParseSingleValue(hourField, "hour", 0, 23);
+            var times = ParseCronTimes(minuteField, hourField);
 
-            return ScheduleData.Of(new MonthRepeat(1, MonthTarget.OrdinalWeekday(ordinal, weekday), [new TimeOfDay(hour, minute)]))
+            return ScheduleData.Of(new MonthRepeat(1, MonthTarget.OrdinalWeekday(ordinal, weekday), times))
                 .WithDuring(during);
         }
 
@@ -403,10 +483,9 @@ public static class CronConverter
                 throw HronException.Cron("DOM must be * when using nL for last weekday");
             }
 
-            var minute = ParseSingleValue(minuteField, "minute", 0, 59);
-            var hour = ParseSingleValue(hourField, "hour", 0, 23);
+            var times = ParseCronTimes(minuteField, hourField);
 
-            return ScheduleData.Of(new MonthRepeat(1, MonthTarget.OrdinalWeekday(OrdinalPosition.Last, weekday), [new TimeOfDay(hour, minute)]))
+            return ScheduleData.Of(new MonthRepeat(1, MonthTarget.OrdinalWeekday(OrdinalPosition.Last, weekday), times))
                 .WithDuring(during);
         }
 
@@ -433,12 +512,11 @@ public static class CronConverter
             throw HronException.Cron("DOW must be * when using L or LW in DOM");
         }
 
-        var minute = ParseSingleValue(minuteField, "minute", 0, 59);
-        var hour = ParseSingleValue(hourField, "hour", 0, 23);
+        var times = ParseCronTimes(minuteField, hourField);
 
         var target = domField == "LW" ? MonthTarget.LastWeekday() : MonthTarget.LastDay();
 
-        return ScheduleData.Of(new MonthRepeat(1, target, [new TimeOfDay(hour, minute)]))
+        return ScheduleData.Of(new MonthRepeat(1, target, times))
             .WithDuring(during);
     }
 
@@ -473,12 +551,11 @@ public static class CronConverter
             throw HronException.Cron($"W day must be 1-31, got {day}");
         }
 
-        var minute = ParseSingleValue(minuteField, "minute", 0, 59);
-        var hour = ParseSingleValue(hourField, "hour", 0, 23);
+        var times = ParseCronTimes(minuteField, hourField);
 
         var target = MonthTarget.NearestWeekday(day, direction: null);
 
-        return ScheduleData.Of(new MonthRepeat(1, target, [new TimeOfDay(hour, minute)]))
+        return ScheduleData.Of(new MonthRepeat(1, target, times))
             .WithDuring(during);
     }
 
@@ -598,12 +675,14 @@ public static class CronConverter
             }
         }
 
-        // Hour interval: 0 */N or 0 range/N
-        if (hourField.Contains('/') && (minuteField == "0" || minuteField == "00"))
+        // Hour interval: M */N, M range/N, or M * (every hour at minute M)
+        var minuteOk = int.TryParse(minuteField, NumberStyles.None, CultureInfo.InvariantCulture, out var minute)
+            && minute <= 59;
+        if (minuteOk && (hourField.Contains('/') || hourField == "*"))
         {
             var slashIdx = hourField.IndexOf('/');
-            var rangePart = hourField[..slashIdx];
-            var stepStr = hourField[(slashIdx + 1)..];
+            var rangePart = slashIdx < 0 ? hourField : hourField[..slashIdx];
+            var stepStr = slashIdx < 0 ? "1" : hourField[(slashIdx + 1)..];
 
             if (!int.TryParse(stepStr, out var interval) || interval == 0)
             {
@@ -640,13 +719,13 @@ public static class CronConverter
 
             if ((domField == "*" || domField == "?") && (dowField == "*" || dowField == "?"))
             {
-                // Use :59 only for full day (00:00 to 23:59), otherwise use :00
-                var endMinute = (fromHour == 0 && toHour == 23) ? 59 : 0;
+                // Use :59 only for full day (00:00 to 23:59), otherwise end on the last firing
+                var endMinute = (fromHour == 0 && toHour == 23) ? 59 : minute;
 
                 return ScheduleData.Of(new IntervalRepeat(
                     interval,
                     IntervalUnit.Hours,
-                    new TimeOfDay(fromHour, 0),
+                    new TimeOfDay(fromHour, minute),
                     new TimeOfDay(toHour, endMinute),
                     null)).WithDuring(during);
             }
@@ -837,31 +916,13 @@ public static class CronConverter
             }
         }
 
-        // Check for special patterns
-        if (days.Count == 5)
+        // Cron has no weekend keyword, so 0,6 reads as the weekend
+        var canonical = DayFilter.SpecificDays(days).Canonical();
+        if (canonical.Kind == DayFilterKind.Days && canonical.Days.SequenceEqual(new[] { Weekday.Saturday, Weekday.Sunday }))
         {
-            var sorted = days.OrderBy(d => d.Number()).ToList();
-            var weekdays = new List<Weekday>
-            {
-                Weekday.Monday, Weekday.Tuesday, Weekday.Wednesday,
-                Weekday.Thursday, Weekday.Friday
-            };
-            if (sorted.SequenceEqual(weekdays))
-            {
-                return DayFilter.Weekday();
-            }
+            return DayFilter.Weekend();
         }
-        if (days.Count == 2)
-        {
-            var sorted = days.OrderBy(d => d.Number()).ToList();
-            var weekend = new List<Weekday> { Weekday.Saturday, Weekday.Sunday };
-            if (sorted.SequenceEqual(weekend))
-            {
-                return DayFilter.Weekend();
-            }
-        }
-
-        return DayFilter.SpecificDays(days);
+        return canonical;
     }
 
     /// <summary>
@@ -914,6 +975,27 @@ public static class CronConverter
         _ => throw HronException.Cron($"invalid DOW number: {n}")
     };
 
+    /// <summary>
+    /// Parses the minute and hour fields into times. Either field may be a comma list; every hour
+    /// pairs with every minute, so "0,30 9,17" gives four times.
+    /// </summary>
+    private static IReadOnlyList<TimeOfDay> ParseCronTimes(string minuteField, string hourField)
+    {
+        var minutes = ParseValueList(minuteField, "minute", 0, 59);
+        var hours = ParseValueList(hourField, "hour", 0, 23);
+        return hours
+            .SelectMany(h => minutes.Select(m => new TimeOfDay(h, m)))
+            .Distinct()
+            .OrderBy(t => t.TotalMinutes)
+            .ToList();
+    }
+
+    /// <summary>
+    /// Parse a comma list of numeric values, each validated like a single value.
+    /// </summary>
+    private static List<int> ParseValueList(string field, string name, int min, int max)
+        => field.Split(',').Select(v => ParseSingleValue(v, name, min, max)).ToList();
+
     /// <summary>
     /// Parse a single numeric value with validation.
     /// </summary>
@@ -29,16 +29,22 @@ public static class Display
             sb.Append(RenderUntil(data.Until));
         }
 
-        if (!string.IsNullOrEmpty(data.Anchor))
+        if (!string.IsNullOrEmpty(data.AnchorText))
         {
             sb.Append(" starting ");
-            sb.Append(data.Anchor);
+            sb.Append(data.AnchorText);
+        }
+
+        if (data.AnchorTime is { } anchorTime)
+        {
+            sb.Append(" at ");
+            sb.Append(anchorTime);
         }
 
         if (data.During.Count > 0)
         {
             sb.Append(" during ");
-            sb.Append(RenderMonthList(data.During));
+            sb.Append(RenderDuringSpecs(data.During));
         }
 
         if (!string.IsNullOrEmpty(data.Timezone))
@@ -58,6 +64,8 @@ public static class Display
         MonthRepeat mr => RenderMonthRepeat(mr),
         SingleDate sd => RenderSingleDate(sd),
         YearRepeat yr => RenderYearRepeat(yr),
+        IsoWeekRepeat iw => RenderIsoWeekRepeat(iw),
+        WeekdayRepeat wr => $"every {wr.Interval} weekdays at {FormatTimeList(wr.Times)}",
         _ => throw new ArgumentException($"Unknown expression type: {expr.GetType()}", nameof(expr))
     };
 
@@ -65,6 +73,10 @@ public static class Display
     {
         if (dr.Interval > 1)
         {
+            if (dr.Days.Kind != DayFilterKind.Every)
+            {
+                return $"every {dr.Interval} days on {RenderDayFilter(dr.Days)} at {FormatTimeList(dr.Times)}";
+            }
             return $"every {dr.Interval} days at {FormatTimeList(dr.Times)}";
         }
         return $"every {RenderDayFilter(dr.Days)} at {FormatTimeList(dr.Times)}";
@@ -114,22 +126,31 @@ public static class Display
         return $"every year on {targetStr} at {FormatTimeList(yr.Times)}";
     }
 
+    private static string RenderIsoWeekRepeat(IsoWeekRepeat iw)
+        => $"every year in iso weeks {string.Join(", ", iw.Weeks)} on {FormatDayList(iw.Days)} at {FormatTimeList(iw.Times)}";
+
     private static string RenderDayFilter(DayFilter f) => f.Kind switch
     {
         DayFilterKind.Every => "day",
         DayFilterKind.Weekday => "weekday",
         DayFilterKind.Weekend => "weekend",
-        DayFilterKind.Days => FormatDayList(f.Days),
+        DayFilterKind.Days => f.Canonical() is { Kind: DayFilterKind.Days } canonical
+            ? FormatDayList(canonical.Days)
+            : RenderDayFilter(f.Canonical()),
         _ => throw new ArgumentOutOfRangeException()
     };
 
     private static string RenderMonthTarget(MonthTarget target) => target.Kind switch
     {
         MonthTargetKind.LastDay => "last day",
+        MonthTargetKind.LastNDays => $"last {target.Count} days",
+        MonthTargetKind.BusinessDay => $"{OrdinalNumber(target.Count)} business day",
         MonthTargetKind.LastWeekday => "last weekday",
         MonthTargetKind.Days => FormatOrdinalDaySpecs(target.Specs),
         MonthTargetKind.NearestWeekday => RenderNearestWeekday(target),
         MonthTargetKind.OrdinalWeekday => $"{target.OrdinalValue!.Value.ToDisplayString()} {target.WeekdayValue!.Value.ToDisplayString()}",
+        MonthTargetKind.OrdinalWeekdays =>
+            $"{string.Join(", ", target.Ordinals!.SkipLast(1).Select(o => o.ToDisplayString()))} and {target.Ordinals![^1].ToDisplayString()} {target.WeekdayValue!.Value.ToDisplayString()}",
         _ => throw new ArgumentOutOfRangeException()
     };
 
@@ -157,6 +178,7 @@ public static class Display
     {
         DateSpecKind.Named => $"{spec.Month!.Value.ToDisplayString()} {spec.Day}",
         DateSpecKind.Iso => spec.Date!,
+        DateSpecKind.NextWeekday => spec.Weekday!.Value.ToDisplayString(),
         _ => throw new ArgumentOutOfRangeException()
     };
 
@@ -167,6 +189,10 @@ public static class Display
     {
         ExceptionSpecKind.Named => $"{exc.Month!.Value.ToDisplayString()} {exc.Day}",
         ExceptionSpecKind.Iso => exc.Date!,
+        ExceptionSpecKind.Weekday => exc.Weekday!.Value.ToDisplayString(),
+        ExceptionSpecKind.IsoRange => $"{exc.Date} to {exc.EndDate}",
+        ExceptionSpecKind.NamedRange =>
+            $"{exc.Month!.Value.ToDisplayString()} {exc.Day} to {exc.EndMonth!.Value.ToDisplayString()} {exc.EndDay}",
         _ => throw new ArgumentOutOfRangeException()
     };
 
@@ -177,8 +203,24 @@ public static class Display
         _ => throw new ArgumentOutOfRangeException()
     };
 
-    private static string RenderMonthList(IReadOnlyList<MonthName> months)
-        => string.Join(", ", months.Select(m => m.ToDisplayString()));
+    private static string RenderDuringSpecs(IReadOnlyList<DuringSpec> specs)
+        => string.Join(", ", specs.Select(RenderDuringSpec));
+
+    private static string RenderDuringSpec(DuringSpec spec)
+    {
+        // Date ranges covering whole months print as months
+        if (spec.WholeMonths)
+        {
+            return spec.Start == spec.End
+                ? spec.Start.ToDisplayString()
+                : $"{spec.Start.ToDisplayString()} to {spec.End.ToDisplayString()}";
+        }
+        if (spec.Start == spec.End && spec.FirstDay == spec.LastDay)
+        {
+            return $"{spec.Start.ToDisplayString()} {spec.FirstDay}";
+        }
+        return $"{spec.Start.ToDisplayString()} {spec.FirstDay} to {spec.End.ToDisplayString()} {spec.LastDay}";
+    }
 
     private static string FormatTimeList(IReadOnlyList<TimeOfDay> times)
         => string.Join(", ", times.Select(t => t.ToString()));
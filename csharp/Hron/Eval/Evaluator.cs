@@ -1,3 +1,4 @@
+using System.Globalization;
 using Hron.Ast;
 
 namespace Hron.Eval;
@@ -14,6 +15,7 @@ namespace Hron.Eval;
 ///   <item><description>Week repeat: 54 weeks (covers one year + margin)</description></item>
 ///   <item><description>Month repeat: 24 * interval months (covers 2 years scaled by interval)</description></item>
 ///   <item><description>Year repeat: 8 * interval years (covers reasonable future horizon)</description></item>
+///   <item><description>ISO week repeat: 7 ISO years (week 53 only exists in long years)</description></item>
 /// </list>
 /// <para>These limits are generous safety bounds. In practice, valid schedules
 /// find occurrences within the first few iterations.</para>
@@ -51,19 +53,30 @@ public static class Evaluator
     /// <summary>Epoch Monday for week alignment.</summary>
     private static readonly DateOnly EpochMonday = new(1970, 1, 5);
 
+    /// <summary>ISO years searched for a matching week; week 53 only exists in long ISO years.</summary>
+    private const int IsoYearHorizon = 7;
+
     /// <summary>
     /// Computes the next occurrence after the given time.
     /// </summary>
     public static DateTimeOffset? NextFrom(ScheduleData data, DateTimeOffset now, TimeZoneInfo location)
     {
+        data = ResolveAnchor(data, now, location);
         // Check if this is a NearestWeekday with direction - it handles during filter internally
         var handlesDuringInternally = data.Expr is MonthRepeat mr &&
             mr.Target.Kind == MonthTargetKind.NearestWeekday &&
             mr.Target.NearestWeekdayDirection.HasValue;
+        var during = data.During;
+
+        // Nothing occurs before the starting anchor, so search from its start
+        if (AnchorStart(data, location) is { } start && now < start)
+        {
+            now = start.AddTicks(-1);
+        }
 
         for (var i = 0; i < MaxIterations; i++)
         {
-            var candidate = NextCandidate(data.Expr, now, location, data.Anchor, data.During);
+            var candidate = NextCandidate(data.Expr, now, location, data.Anchor, data.DuringMonths(), data.Except);
             if (candidate is null)
             {
                 return null;
@@ -75,7 +88,9 @@ public static class Evaluator
             // Check exception list
             if (IsExcepted(DateOnly.FromDateTime(t.DateTime), data.Except))
             {
-                now = t;
+                // Advance past this day (or the whole excepted range) and retry
+                var runEnd = ExceptedRunEnd(DateOnly.FromDateTime(t.DateTime), data.Except);
+                now = AtTimeOnDate(runEnd.AddDays(1), new TimeOfDay(0, 0), location).AddTicks(-1);
                 continue;
             }
 
@@ -90,11 +105,11 @@ public static class Evaluator
             }
 
             // Check during clause (skip if handled internally for NearestWeekday with direction)
-            if (!handlesDuringInternally && !MatchesDuring(DateOnly.FromDateTime(t.DateTime), data.During))
+            if (!handlesDuringInternally && !MatchesDuring(DateOnly.FromDateTime(t.DateTime), during))
             {
-                // Skip to next month that matches
-                var nextMonth = NextDuringMonth(DateOnly.FromDateTime(t.DateTime), data.During);
-                now = AtTimeOnDate(nextMonth, new TimeOfDay(0, 0), location).AddTicks(-1);
+                // Skip ahead to the start of the next during period
+                var nextStart = NextDuringStart(DateOnly.FromDateTime(t.DateTime), during);
+                now = AtTimeOnDate(nextStart, new TimeOfDay(0, 0), location).AddTicks(-1);
                 continue;
             }
 
@@ -109,6 +124,7 @@ public static class Evaluator
     /// </summary>
     public static IReadOnlyList<DateTimeOffset> NextNFrom(ScheduleData data, DateTimeOffset now, int n, TimeZoneInfo location)
     {
+        data = PinRelative(data, now, location);
         var results = new List<DateTimeOffset>(n);
         var current = now;
 
@@ -131,6 +147,7 @@ public static class Evaluator
     /// </summary>
     public static IEnumerable<DateTimeOffset> Occurrences(ScheduleData data, DateTimeOffset from, TimeZoneInfo location)
     {
+        data = PinRelative(data, from, location);
         var current = from;
         while (true)
         {
@@ -164,6 +181,19 @@ public static class Evaluator
     /// </summary>
     public static bool Matches(ScheduleData data, DateTimeOffset dt, TimeZoneInfo location)
     {
+        // There is no evaluation time to resolve a relative anchor against;
+        // resolving it against dt would make every date its own anchor.
+        if (data.HasRelativeAnchor)
+        {
+            throw HronException.Eval(
+                $"matches has no evaluation time to resolve 'starting {data.AnchorText}' against; pin the anchor to a date first");
+        }
+        // Nor an "on <weekday>" date: every such weekday would be the upcoming one
+        if (data.Expr is SingleDate { DateSpec.Kind: DateSpecKind.NextWeekday } upcoming)
+        {
+            throw HronException.Eval(
+                $"matches has no evaluation time to resolve 'on {upcoming.DateSpec.Weekday!.Value.ToDisplayString()}' against; use a date instead");
+        }
         var converted = TimeZoneInfo.ConvertTime(dt, location);
         var date = DateOnly.FromDateTime(converted.DateTime);
 
@@ -179,6 +209,12 @@ public static class Evaluator
             return false;
         }
 
+        // Nothing occurs before the starting anchor
+        if (AnchorStart(data, location) is { } start && dt < start)
+        {
+            return false;
+        }
+
         // Check until
         if (data.Until is not null)
         {
@@ -192,11 +228,16 @@ public static class Evaluator
         return data.Expr switch
         {
             DayRepeat dr => MatchesDayRepeat(dr, date, dt, location, data.Anchor),
+            IntervalRepeat { Unit: IntervalUnit.Days } ir =>
+                MatchesDayRepeat(DayStepRepeat(ir), date, dt, location, data.Anchor),
             IntervalRepeat ir => MatchesIntervalRepeat(ir, date, converted),
             WeekRepeat wr => MatchesWeekRepeat(wr, date, dt, location, data.Anchor),
-            MonthRepeat mr => MatchesMonthRepeat(mr, date, dt, location, data.Anchor),
+            MonthRepeat mr => MatchesMonthRepeat(mr, date, dt, location, data.Anchor, data.Except),
             SingleDate sd => MatchesSingleDate(sd, date, dt, location),
             YearRepeat yr => MatchesYearRepeat(yr, date, dt, location, data.Anchor),
+            IsoWeekRepeat iw => MatchesIsoWeekRepeat(iw, date, dt, location),
+            WeekdayRepeat wr => WeekdayIntervalAligned(data.Anchor is not null ? DateOnly.Parse(data.Anchor) : EpochDate, wr.Interval, date)
+                && TimeMatchesWithDst(date, wr.Times, location, dt),
             _ => false
         };
     }
@@ -221,6 +262,74 @@ public static class Evaluator
         return false;
     }
 
+    /// <summary>
+    /// Pins a relative anchor to a date in the schedule zone: "starting today" and "starting now"
+    /// to the date of <paramref name="now"/>, "starting jan 6" to the next jan 6 on or after it.
+    /// </summary>
+    private static ScheduleData ResolveAnchor(ScheduleData data, DateTimeOffset now, TimeZoneInfo location)
+    {
+        if (!data.HasRelativeAnchor)
+        {
+            return data;
+        }
+        var today = DateOnly.FromDateTime(TimeZoneInfo.ConvertTime(now, location).DateTime);
+        if (data.AnchorNamed is not { } named)
+        {
+            return data.WithAnchor(today.ToString("yyyy-MM-dd", CultureInfo.InvariantCulture));
+        }
+        var month = (int)named.Month!.Value;
+        // Try up to 8 years forward (covers leap year cycles)
+        for (var y = 0; y < 8; y++)
+        {
+            var year = today.Year + y;
+            if (named.Day > DateTime.DaysInMonth(year, month))
+            {
+                continue;
+            }
+            var d = new DateOnly(year, month, named.Day);
+            if (d >= today)
+            {
+                return data.WithAnchor(d.ToString("yyyy-MM-dd", CultureInfo.InvariantCulture));
+            }
+        }
+        throw HronException.Eval($"starting date {data.AnchorText} never occurs");
+    }
+
+    /// <summary>
+    /// The instant nothing occurs before: the resolved anchor date at the "starting ... at" time,
+    /// or at midnight.
+    /// </summary>
+    private static DateTimeOffset? AnchorStart(ScheduleData data, TimeZoneInfo location)
+    {
+        if (data.Anchor is null)
+        {
+            return null;
+        }
+        return AtTimeOnDate(DateOnly.Parse(data.Anchor), data.AnchorTime ?? new TimeOfDay(0, 0), location);
+    }
+
+    /// <summary>
+    /// Pins what the schedule resolves against the evaluation time, as seen from
+    /// <paramref name="now"/>: "on &lt;weekday&gt;" becomes the one date it names (the nearest such
+    /// weekday with a time still ahead), and a relative anchor becomes its date.
+    /// </summary>
+    private static ScheduleData PinRelative(ScheduleData data, DateTimeOffset now, TimeZoneInfo location)
+    {
+        data = ResolveAnchor(data, now, location);
+        if (data.Expr is not SingleDate { DateSpec.Kind: DateSpecKind.NextWeekday } sd)
+        {
+            return data;
+        }
+        var next = NextSingleDate(sd, TimeZoneInfo.ConvertTime(now, location), location);
+        if (next is null)
+        {
+            return data;
+        }
+        var date = DateOnly.FromDateTime(TimeZoneInfo.ConvertTime(next.Value, location).DateTime);
+        var pinned = sd with { DateSpec = DateSpec.Iso(date.ToString("yyyy-MM-dd", CultureInfo.InvariantCulture)) };
+        return data with { Expr = pinned };
+    }
+
     private static bool MatchesDayRepeat(DayRepeat dr, DateOnly date, DateTimeOffset dt, TimeZoneInfo location, string? anchor)
     {
         if (!MatchesDayFilter(date, dr.Days))
@@ -240,6 +349,11 @@ public static class Evaluator
         return true;
     }
 
+    // A day-step interval window fires once, at the window start, on each aligned day: the day
+    // repeat with the window start as its only time.
+    private static DayRepeat DayStepRepeat(IntervalRepeat ir) =>
+        new(ir.Interval, ir.DayFilter ?? DayFilter.Every(), [ir.FromTime]);
+
     private static bool MatchesIntervalRepeat(IntervalRepeat ir, DateOnly date, DateTimeOffset converted)
     {
         if (ir.DayFilter is not null && !MatchesDayFilter(date, ir.DayFilter))
@@ -275,7 +389,7 @@ public static class Evaluator
         return weeks >= 0 && weeks % wr.Interval == 0;
     }
 
-    private static bool MatchesMonthRepeat(MonthRepeat mr, DateOnly date, DateTimeOffset dt, TimeZoneInfo location, string? anchor)
+    private static bool MatchesMonthRepeat(MonthRepeat mr, DateOnly date, DateTimeOffset dt, TimeZoneInfo location, string? anchor, IReadOnlyList<ExceptionSpec> except)
     {
         if (!TimeMatchesWithDst(date, mr.Times, location, dt))
         {
@@ -290,20 +404,24 @@ public static class Evaluator
                 return false;
             }
         }
-        return MatchesMonthTarget(date, mr.Target);
+        return MatchesMonthTarget(date, mr.Target, except);
     }
 
-    private static bool MatchesMonthTarget(DateOnly date, MonthTarget target)
+    private static bool MatchesMonthTarget(DateOnly date, MonthTarget target, IReadOnlyList<ExceptionSpec> except)
     {
         return target.Kind switch
         {
             MonthTargetKind.Days => target.ExpandDays().Contains(date.Day),
             MonthTargetKind.LastDay => date == LastDayOfMonth(date.Year, date.Month),
+            MonthTargetKind.LastNDays => date.Day > LastDayOfMonth(date.Year, date.Month).Day - target.Count,
+            MonthTargetKind.BusinessDay => NthBusinessDay(date.Year, date.Month, target.Count, except) == date,
             MonthTargetKind.LastWeekday => date == LastWeekdayOfMonth(date.Year, date.Month),
             MonthTargetKind.NearestWeekday =>
                 NearestWeekday(date.Year, date.Month, target.NearestWeekdayDay, target.NearestWeekdayDirection) is { } nwd && date == nwd,
             MonthTargetKind.OrdinalWeekday =>
                 MatchesOrdinalWeekday(date, target),
+            MonthTargetKind.OrdinalWeekdays =>
+                OrdinalWeekdayDates(date.Year, date.Month, target.Ordinals!, target.WeekdayValue!.Value).Contains(date),
             _ => false
         };
     }
@@ -328,6 +446,8 @@ public static class Evaluator
         {
             DateSpecKind.Iso => date == DateOnly.Parse(sd.DateSpec.Date!),
             DateSpecKind.Named => date.Month == sd.DateSpec.Month!.Value.Number() && date.Day == sd.DateSpec.Day,
+            // Matches rejects it up front; any day with that weekday can be it
+            DateSpecKind.NextWeekday => date.DayOfWeek == sd.DateSpec.Weekday!.Value.ToDayOfWeek(),
             _ => false
         };
     }
@@ -350,6 +470,19 @@ public static class Evaluator
         return MatchesYearTarget(date, yr.Target);
     }
 
+    private static bool MatchesIsoWeekRepeat(IsoWeekRepeat iw, DateOnly date, DateTimeOffset dt, TimeZoneInfo location)
+    {
+        if (!iw.Weeks.Contains(ISOWeek.GetWeekOfYear(date.ToDateTime(TimeOnly.MinValue))))
+        {
+            return false;
+        }
+        if (!iw.Days.Contains(WeekdayExtensions.FromDayOfWeek(date.DayOfWeek)))
+        {
+            return false;
+        }
+        return TimeMatchesWithDst(date, iw.Times, location, dt);
+    }
+
     private static bool MatchesYearTarget(DateOnly date, YearTarget target)
     {
         return target.Kind switch
@@ -377,12 +510,20 @@ public static class Evaluator
     }
 
     /// <summary>
-    /// Computes the most recent occurrence strictly before the given time.
+    /// Computes the most recent occurrence strictly before the given time. With "starting now"
+    /// nothing occurs before the given time, so there is none.
     /// </summary>
     public static DateTimeOffset? PreviousFrom(ScheduleData data, DateTimeOffset now, TimeZoneInfo location)
     {
-        // Get anchor date for starting bound
-        DateOnly? anchorDate = data.Anchor is not null ? DateOnly.Parse(data.Anchor) : null;
+        if (data.Anchor == "now")
+        {
+            return null;
+        }
+        data = PinRelative(data, now, location);
+
+        // Get the instant for the starting bound
+        var start = AnchorStart(data, location);
+        var during = data.During;
 
         // Handle until clause - if now is after until, search from end of until date
         var searchFrom = now;
@@ -397,7 +538,7 @@ public static class Evaluator
 
         for (var i = 0; i < MaxIterations; i++)
         {
-            var candidate = PrevCandidate(data.Expr, searchFrom, location, data.Anchor, data.During);
+            var candidate = PrevCandidate(data.Expr, searchFrom, location, data.Anchor, data.DuringMonths(), data.Except);
             if (candidate is null)
             {
                 return null;
@@ -406,7 +547,7 @@ public static class Evaluator
             var t = candidate.Value;
 
             // Check if before anchor
-            if (anchorDate.HasValue && DateOnly.FromDateTime(t.DateTime) < anchorDate.Value)
+            if (start.HasValue && t < start.Value)
             {
                 return null;
             }
@@ -425,19 +566,18 @@ public static class Evaluator
             // Check exception list
             if (IsExcepted(DateOnly.FromDateTime(t.DateTime), data.Except))
             {
-                searchFrom = t;
+                // Go back before this day (or the whole excepted range) and retry
+                var runStart = ExceptedRunStart(DateOnly.FromDateTime(t.DateTime), data.Except);
+                searchFrom = AtTimeOnDate(runStart, new TimeOfDay(0, 0), location);
                 continue;
             }
 
             // Check during clause
-            if (!MatchesDuring(DateOnly.FromDateTime(t.DateTime), data.During))
+            if (!MatchesDuring(DateOnly.FromDateTime(t.DateTime), during))
             {
-                var prevMonth = PrevDuringMonth(DateOnly.FromDateTime(t.DateTime), data.During);
-                if (prevMonth is null)
-                {
-                    return null;
-                }
-                searchFrom = AtTimeOnDate(new DateOnly(prevMonth.Value.Year, prevMonth.Value.Month, 1).AddMonths(1), new TimeOfDay(0, 0), location);
+                // Skip back to the end of the previous during period
+                var prevEnd = PrevDuringEnd(DateOnly.FromDateTime(t.DateTime), during);
+                searchFrom = AtTimeOnDate(prevEnd.AddDays(1), new TimeOfDay(0, 0), location);
                 continue;
             }
 
@@ -447,30 +587,38 @@ public static class Evaluator
         return null;
     }
 
-    private static DateTimeOffset? NextCandidate(IScheduleExpr expr, DateTimeOffset now, TimeZoneInfo location, string? anchor, IReadOnlyList<MonthName>? during = null)
+    private static DateTimeOffset? NextCandidate(IScheduleExpr expr, DateTimeOffset now, TimeZoneInfo location, string? anchor, IReadOnlyList<MonthName>? during, IReadOnlyList<ExceptionSpec> except)
     {
         return expr switch
         {
             DayRepeat dr => NextDayRepeat(dr, now, location, anchor),
+            IntervalRepeat { Unit: IntervalUnit.Days } ir =>
+                NextDayRepeat(DayStepRepeat(ir), now, location, anchor),
             IntervalRepeat ir => NextIntervalRepeat(ir, now, location),
             WeekRepeat wr => NextWeekRepeat(wr, now, location, anchor),
-            MonthRepeat mr => NextMonthRepeat(mr, now, location, anchor, during),
+            MonthRepeat mr => NextMonthRepeat(mr, now, location, anchor, during, except),
             SingleDate sd => NextSingleDate(sd, now, location),
             YearRepeat yr => NextYearRepeat(yr, now, location, anchor),
+            IsoWeekRepeat iw => NextIsoWeekRepeat(iw, now, location),
+            WeekdayRepeat wr => NextWeekdayRepeat(wr, now, location, anchor),
             _ => null
         };
     }
 
-    private static DateTimeOffset? PrevCandidate(IScheduleExpr expr, DateTimeOffset now, TimeZoneInfo location, string? anchor, IReadOnlyList<MonthName>? during = null)
+    private static DateTimeOffset? PrevCandidate(IScheduleExpr expr, DateTimeOffset now, TimeZoneInfo location, string? anchor, IReadOnlyList<MonthName>? during, IReadOnlyList<ExceptionSpec> except)
     {
         return expr switch
         {
             DayRepeat dr => PrevDayRepeat(dr, now, location, anchor),
+            IntervalRepeat { Unit: IntervalUnit.Days } ir =>
+                PrevDayRepeat(DayStepRepeat(ir), now, location, anchor),
             IntervalRepeat ir => PrevIntervalRepeat(ir, now, location),
             WeekRepeat wr => PrevWeekRepeat(wr, now, location, anchor),
-            MonthRepeat mr => PrevMonthRepeat(mr, now, location, anchor),
+            MonthRepeat mr => PrevMonthRepeat(mr, now, location, anchor, except),
             SingleDate sd => PrevSingleDate(sd, now, location),
             YearRepeat yr => PrevYearRepeat(yr, now, location, anchor),
+            IsoWeekRepeat iw => PrevIsoWeekRepeat(iw, now, location),
+            WeekdayRepeat wr => PrevWeekdayRepeat(wr, now, location, anchor),
             _ => null
         };
     }
@@ -600,7 +748,8 @@ public static class Evaluator
         return null;
     }
 
-    private static DateTimeOffset? NextMonthRepeat(MonthRepeat mr, DateTimeOffset now, TimeZoneInfo location, string? anchor, IReadOnlyList<MonthName>? during = null)
+    // Finds the next month occurrence. Business-day targets skip over days exceptions remove.
+    private static DateTimeOffset? NextMonthRepeat(MonthRepeat mr, DateTimeOffset now, TimeZoneInfo location, string? anchor, IReadOnlyList<MonthName>? during, IReadOnlyList<ExceptionSpec> except)
     {
         var anchorDate = anchor is not null ? DateOnly.Parse(anchor) : EpochDate;
         var day = DateOnly.FromDateTime(now.DateTime);
@@ -638,7 +787,7 @@ public static class Evaluator
             }
 
             // Get target days for this month
-            var targetDays = GetTargetDaysInMonth(day.Year, day.Month, mr.Target);
+            var targetDays = GetTargetDaysInMonth(day.Year, day.Month, mr.Target, except);
 
             // For NearestWeekday with direction, don't skip based on day comparison
             // because the result can cross month boundaries (e.g., nearest to March 1st
@@ -691,6 +840,27 @@ public static class Evaluator
                 }
                 return null;
 
+            case DateSpecKind.NextWeekday:
+            {
+                // Today if a time is still ahead, otherwise within the next week
+                var dow = sd.DateSpec.Weekday!.Value.ToDayOfWeek();
+                var today = DateOnly.FromDateTime(now.DateTime);
+                for (var offset = 0; offset < 8; offset++)
+                {
+                    var day = today.AddDays(offset);
+                    if (day.DayOfWeek != dow)
+                    {
+                        continue;
+                    }
+                    var next = EarliestFutureTime(day, sd.Times, location, now);
+                    if (next.HasValue)
+                    {
+                        return next;
+                    }
+                }
+                return null;
+            }
+
             default:
                 return null;
         }
@@ -737,6 +907,23 @@ public static class Evaluator
         return null;
     }
 
+    private static DateTimeOffset? NextIsoWeekRepeat(IsoWeekRepeat iw, DateTimeOffset now, TimeZoneInfo location)
+    {
+        var startYear = ISOWeek.GetYear(now.DateTime);
+        for (var year = startYear; year < startYear + IsoYearHorizon; year++)
+        {
+            foreach (var d in IsoWeekDates(year, iw.Weeks, iw.Days))
+            {
+                var time = EarliestFutureTime(d, iw.Times, location, now);
+                if (time.HasValue)
+                {
+                    return time;
+                }
+            }
+        }
+        return null;
+    }
+
     // Previous occurrence methods
 
     private static DateTimeOffset? PrevDayRepeat(DayRepeat dr, DateTimeOffset now, TimeZoneInfo location, string? anchor)
@@ -859,7 +1046,7 @@ public static class Evaluator
         return null;
     }
 
-    private static DateTimeOffset? PrevMonthRepeat(MonthRepeat mr, DateTimeOffset now, TimeZoneInfo location, string? anchor)
+    private static DateTimeOffset? PrevMonthRepeat(MonthRepeat mr, DateTimeOffset now, TimeZoneInfo location, string? anchor, IReadOnlyList<ExceptionSpec> except)
     {
         var anchorDate = anchor is not null ? DateOnly.Parse(anchor) : EpochDate;
         var day = DateOnly.FromDateTime(now.DateTime);
@@ -879,7 +1066,7 @@ public static class Evaluator
                 }
             }
 
-            var targetDays = GetTargetDaysInMonth(day.Year, day.Month, mr.Target).OrderByDescending(d => d).ToList();
+            var targetDays = GetTargetDaysInMonth(day.Year, day.Month, mr.Target, except).OrderByDescending(d => d).ToList();
 
             foreach (var targetDay in targetDays)
             {
@@ -925,6 +1112,10 @@ public static class Evaluator
                 }
                 return null;
 
+            case DateSpecKind.NextWeekday:
+                // PreviousFrom pins "on <weekday>" to an ISO date first
+                return null;
+
             default:
                 return null;
         }
@@ -970,8 +1161,84 @@ public static class Evaluator
         return null;
     }
 
+    // Returns today if it is an aligned business day with a time still ahead, otherwise the
+    // next aligned business day.
+    private static DateTimeOffset? NextWeekdayRepeat(WeekdayRepeat wr, DateTimeOffset now, TimeZoneInfo location, string? anchor)
+    {
+        var today = DateOnly.FromDateTime(now.DateTime);
+        var anchorDate = anchor is not null ? DateOnly.Parse(anchor) : EpochDate;
+        var anchorIndex = BusinessDayIndex(anchorDate);
+        var todayIndex = BusinessDayIndex(today);
+        var isWeekday = IsBusinessDay(today);
+
+        if (isWeekday && FloorMod(todayIndex - anchorIndex, wr.Interval) == 0)
+        {
+            var time = EarliestFutureTime(today, wr.Times, location, now);
+            if (time.HasValue)
+            {
+                return time;
+            }
+        }
+        // A weekend already shares the following Monday's index
+        var after = isWeekday ? todayIndex + 1 : todayIndex;
+        var index = after + FloorMod(anchorIndex - after, wr.Interval);
+        return EarliestFutureTime(BusinessDayDate(index), wr.Times, location, now);
+    }
+
+    private static DateTimeOffset? PrevWeekdayRepeat(WeekdayRepeat wr, DateTimeOffset now, TimeZoneInfo location, string? anchor)
+    {
+        var today = DateOnly.FromDateTime(now.DateTime);
+        var anchorDate = anchor is not null ? DateOnly.Parse(anchor) : EpochDate;
+        var anchorIndex = BusinessDayIndex(anchorDate);
+        var todayIndex = BusinessDayIndex(today);
+
+        if (IsBusinessDay(today) && FloorMod(todayIndex - anchorIndex, wr.Interval) == 0)
+        {
+            var time = LatestPastTime(today, wr.Times, location, now);
+            if (time.HasValue)
+            {
+                return time;
+            }
+        }
+        // On a weekend the index is the following Monday's, so one back is Friday
+        var before = todayIndex - 1;
+        var index = before - FloorMod(before - anchorIndex, wr.Interval);
+        return LatestPastTime(BusinessDayDate(index), wr.Times, location, now);
+    }
+
+    private static DateTimeOffset? PrevIsoWeekRepeat(IsoWeekRepeat iw, DateTimeOffset now, TimeZoneInfo location)
+    {
+        var startYear = ISOWeek.GetYear(now.DateTime);
+        for (var year = startYear; year > startYear - IsoYearHorizon; year--)
+        {
+            var dates = IsoWeekDates(year, iw.Weeks, iw.Days);
+            for (var i = dates.Count - 1; i >= 0; i--)
+            {
+                var time = LatestPastTime(dates[i], iw.Times, location, now);
+                if (time.HasValue)
+                {
+                    return time;
+                }
+            }
+        }
+        return null;
+    }
+
     // Helper methods
 
+    // Returns the dates in an ISO week-numbering year on one of the days in one of the
+    // weeks, ascending. Week 53 yields nothing in years with only 52 weeks.
+    private static IReadOnlyList<DateOnly> IsoWeekDates(int year, IReadOnlyList<int> weeks, IReadOnlyList<Weekday> days)
+    {
+        var weeksInYear = ISOWeek.GetWeeksInYear(year);
+        return weeks
+            .Where(week => week <= weeksInYear)
+            .SelectMany(week => days.Select(wd => DateOnly.FromDateTime(ISOWeek.ToDateTime(year, week, wd.ToDayOfWeek()))))
+            .Distinct()
+            .Order()
+            .ToList();
+    }
+
     private static bool MatchesDayFilter(DateOnly d, DayFilter f)
     {
         var dow = d.DayOfWeek;
@@ -1063,11 +1330,13 @@ public static class Evaluator
         return new DateTimeOffset(dt, offset);
     }
 
-    private static IReadOnlyList<DateOnly> GetTargetDaysInMonth(int year, int month, MonthTarget target)
+    private static IReadOnlyList<DateOnly> GetTargetDaysInMonth(int year, int month, MonthTarget target, IReadOnlyList<ExceptionSpec> except)
     {
         return target.Kind switch
         {
             MonthTargetKind.LastDay => [LastDayOfMonth(year, month)],
+            MonthTargetKind.LastNDays => LastNDaysOfMonth(year, month, target.Count),
+            MonthTargetKind.BusinessDay => NthBusinessDay(year, month, target.Count, except) is { } bd ? [bd] : [],
             MonthTargetKind.LastWeekday => [LastWeekdayOfMonth(year, month)],
             MonthTargetKind.Days => target.ExpandDays()
                 .Select(day => TryCreateDate(year, month, day))
@@ -1082,10 +1351,26 @@ public static class Evaluator
                 NthWeekdayOfMonth(year, month, target.WeekdayValue!.Value, target.OrdinalValue!.Value) is { } ow
                     ? [ow]
                     : [],
+            MonthTargetKind.OrdinalWeekdays =>
+                OrdinalWeekdayDates(year, month, target.Ordinals!, target.WeekdayValue!.Value),
             _ => []
         };
     }
 
+    // Returns the distinct dates of several ordinal weekdays in a month, in order.
+    private static IReadOnlyList<DateOnly> OrdinalWeekdayDates(int year, int month, IReadOnlyList<OrdinalPosition> ordinals, Weekday weekday)
+    {
+        var dates = new List<DateOnly>();
+        foreach (var ordinal in ordinals)
+        {
+            if (NthWeekdayOfMonth(year, month, weekday, ordinal) is { } d && !dates.Contains(d))
+            {
+                dates.Add(d);
+            }
+        }
+        return dates;
+    }
+
     private static DateOnly? NthWeekdayOfMonth(int year, int month, Weekday weekday, OrdinalPosition ordinal)
     {
         if (ordinal == OrdinalPosition.Last)
@@ -1112,11 +1397,80 @@ public static class Evaluator
         return d;
     }
 
+    // Returns the number of business days (Monday-Friday) from the week of the epoch to the
+    // date. A weekend date shares the index of the following Monday.
+    private static int BusinessDayIndex(DateOnly date)
+    {
+        // 1970-01-01 was a Thursday, 3 days after the Monday that starts its week
+        var days = date.DayNumber - EpochDate.DayNumber + 3;
+        var dayOfWeek = FloorMod(days, 7);
+        var weeks = (days - dayOfWeek) / 7;
+        return weeks * 5 + Math.Min(dayOfWeek, 5);
+    }
+
+    // Returns the weekday with the given business day index.
+    private static DateOnly BusinessDayDate(int index)
+    {
+        var dayOfWeek = FloorMod(index, 5);
+        var weeks = (index - dayOfWeek) / 5;
+        return EpochDate.AddDays(weeks * 7 + dayOfWeek - 3);
+    }
+
+    // Reports whether the date is a business day an "every N weekdays" schedule fires on.
+    private static bool WeekdayIntervalAligned(DateOnly anchor, int interval, DateOnly date)
+    {
+        var offset = BusinessDayIndex(date) - BusinessDayIndex(anchor);
+        return IsBusinessDay(date) && offset >= 0 && offset % interval == 0;
+    }
+
+    private static bool IsBusinessDay(DateOnly date)
+        => date.DayOfWeek is >= DayOfWeek.Monday and <= DayOfWeek.Friday;
+
+    private static int FloorMod(int value, int divisor)
+    {
+        var mod = value % divisor;
+        return mod < 0 ? mod + divisor : mod;
+    }
+
     private static DateOnly LastDayOfMonth(int year, int month)
     {
         return new DateOnly(year, month, 1).AddMonths(1).AddDays(-1);
     }
 
+    // Returns the final n days of the month, ascending; the whole month if it is shorter.
+    private static IReadOnlyList<DateOnly> LastNDaysOfMonth(int year, int month, int n)
+    {
+        var last = LastDayOfMonth(year, month);
+        var first = Math.Max(1, last.Day - n + 1);
+        var days = new List<DateOnly>();
+        for (var day = first; day <= last.Day; day++)
+        {
+            days.Add(new DateOnly(year, month, day));
+        }
+        return days;
+    }
+
+    // Returns the nth business day (Mon-Fri, 1-indexed) of a month, not counting excepted days,
+    // or null if the month has fewer.
+    private static DateOnly? NthBusinessDay(int year, int month, int n, IReadOnlyList<ExceptionSpec> except)
+    {
+        var last = LastDayOfMonth(year, month);
+        var count = 0;
+        for (var d = new DateOnly(year, month, 1); d <= last; d = d.AddDays(1))
+        {
+            if (!IsBusinessDay(d) || IsExcepted(d, except))
+            {
+                continue;
+            }
+            count++;
+            if (count == n)
+            {
+                return d;
+            }
+        }
+        return null;
+    }
+
     private static DateOnly LastWeekdayOfMonth(int year, int month)
     {
         var d = LastDayOfMonth(year, month);
@@ -1245,70 +1599,180 @@ public static class Evaluator
                         return true;
                     }
                     break;
+                case ExceptionSpecKind.Weekday:
+                    if (WeekdayExtensions.FromDayOfWeek(d.DayOfWeek) == exc.Weekday!.Value)
+                    {
+                        return true;
+                    }
+                    break;
+                case ExceptionSpecKind.IsoRange:
+                case ExceptionSpecKind.NamedRange:
+                    if (RangeBounds(d, exc) is not null)
+                    {
+                        return true;
+                    }
+                    break;
             }
         }
         return false;
     }
 
-    private static bool MatchesDuring(DateOnly d, IReadOnlyList<MonthName> during)
+    // Returns the concrete first and last days of a range exception if it covers the date, or null.
+    private static (DateOnly Start, DateOnly End)? RangeBounds(DateOnly d, ExceptionSpec exc)
     {
-        if (during.Count == 0)
+        if (exc.Kind == ExceptionSpecKind.IsoRange)
         {
-            return true;
+            var start = DateOnly.Parse(exc.Date!);
+            var end = DateOnly.Parse(exc.EndDate!);
+            return d < start || d > end ? null : (start, end);
         }
-        foreach (var m in during)
+        if (exc.Kind != ExceptionSpecKind.NamedRange)
         {
-            if (d.Month == m.Number())
+            return null;
+        }
+        var startKey = exc.Month!.Value.Number() * 100 + exc.Day;
+        var endKey = exc.EndMonth!.Value.Number() * 100 + exc.EndDay;
+        var key = d.Month * 100 + d.Day;
+        int startYear;
+        int endYear;
+        if (startKey <= endKey)
+        {
+            if (key < startKey || key > endKey)
             {
-                return true;
+                return null;
             }
+            startYear = d.Year;
+            endYear = d.Year;
         }
-        return false;
+        else if (key >= startKey)
+        {
+            // Wraps into next year
+            startYear = d.Year;
+            endYear = d.Year + 1;
+        }
+        else if (key <= endKey)
+        {
+            startYear = d.Year - 1;
+            endYear = d.Year;
+        }
+        else
+        {
+            return null;
+        }
+        return (ClampedDate(startYear, exc.Month!.Value.Number(), exc.Day),
+            ClampedDate(endYear, exc.EndMonth!.Value.Number(), exc.EndDay));
     }
 
-    private static DateOnly NextDuringMonth(DateOnly d, IReadOnlyList<MonthName> during)
+    // Returns the date for month/day in year, with days past the month's end (feb 29 in a common
+    // year) moved back to its last day.
+    private static DateOnly ClampedDate(int year, int month, int day)
     {
-        var currentMonth = d.Month;
-
-        // Sort months
-        var months = during.Select(m => m.Number()).OrderBy(m => m).ToList();
+        var last = LastDayOfMonth(year, month);
+        return day > last.Day ? last : new DateOnly(year, month, day);
+    }
 
-        // Find next month after current
-        foreach (var m in months)
+    // Returns the last day of the excepted run the date falls in, so long ranges are skipped in
+    // one step rather than day by day.
+    private static DateOnly ExceptedRunEnd(DateOnly d, IReadOnlyList<ExceptionSpec> exceptions)
+    {
+        var end = d;
+        foreach (var exc in exceptions)
         {
-            if (m > currentMonth)
+            if (RangeBounds(d, exc) is { } range && range.End > end)
             {
-                return new DateOnly(d.Year, m, 1);
+                end = range.End;
             }
         }
+        return end;
+    }
 
-        // Wrap to first month of next year
-        return new DateOnly(d.Year + 1, months[0], 1);
+    // Returns the first day of the excepted run the date falls in.
+    private static DateOnly ExceptedRunStart(DateOnly d, IReadOnlyList<ExceptionSpec> exceptions)
+    {
+        var start = d;
+        foreach (var exc in exceptions)
+        {
+            if (RangeBounds(d, exc) is { } range && range.Start < start)
+            {
+                start = range.Start;
+            }
+        }
+        return start;
     }
 
-    private static DateOnly? PrevDuringMonth(DateOnly d, IReadOnlyList<MonthName> during)
+    // Checks if a date falls within any during specification. If during is empty, all match.
+    private static bool MatchesDuring(DateOnly d, IReadOnlyList<DuringSpec> during)
     {
-        var currentMonth = d.Month;
+        if (during.Count == 0)
+        {
+            return true;
+        }
+        var month = (MonthName)d.Month;
+        foreach (var spec in during)
+        {
+            if (spec.Contains(month, d.Day))
+            {
+                return true;
+            }
+        }
+        return false;
+    }
 
-        // Sort months in descending order
-        var months = during.Select(m => m.Number()).OrderByDescending(m => m).ToList();
+    // Returns the month-day in the given year, or the day after the month's end when the
+    // day does not exist that year (feb 29 in a common year).
+    private static DateOnly MonthDayInYear(int year, MonthName month, int day)
+    {
+        var last = LastDayOfMonth(year, month.Number());
+        if (day > last.Day)
+        {
+            return last.AddDays(1);
+        }
+        return new DateOnly(year, month.Number(), day);
+    }
 
-        // Find previous month before current
-        foreach (var m in months)
+    // Returns the first day of the next during period starting after d.
+    private static DateOnly NextDuringStart(DateOnly d, IReadOnlyList<DuringSpec> during)
+    {
+        DateOnly? best = null;
+        foreach (var spec in during)
         {
-            if (m < currentMonth)
+            for (var year = d.Year; year <= d.Year + 1; year++)
             {
-                return LastDayOfMonth(d.Year, m);
+                var start = MonthDayInYear(year, spec.Start, spec.FirstDay);
+                if (start > d)
+                {
+                    if (best is null || start < best.Value)
+                    {
+                        best = start;
+                    }
+                    break;
+                }
             }
         }
+        return best ?? d;
+    }
 
-        // Wrap to last month of previous year
-        if (months.Count > 0)
+    // Returns the last day of the previous during period ending before d.
+    private static DateOnly PrevDuringEnd(DateOnly d, IReadOnlyList<DuringSpec> during)
+    {
+        DateOnly? best = null;
+        foreach (var spec in during)
         {
-            return LastDayOfMonth(d.Year - 1, months[0]);
+            for (var year = d.Year; year >= d.Year - 1; year--)
+            {
+                var last = LastDayOfMonth(year, spec.End.Number());
+                var end = spec.LastDay < last.Day ? new DateOnly(year, spec.End.Number(), spec.LastDay) : last;
+                if (end < d)
+                {
+                    if (best is null || end > best.Value)
+                    {
+                        best = end;
+                    }
+                    break;
+                }
+            }
         }
-
-        return null;
+        return best ?? d;
     }
 
     private static DateOnly ResolveUntil(UntilSpec until, DateOnly now)
@@ -58,6 +58,20 @@ public sealed class Lexer
                 continue;
             }
 
+            // Cron shortcut: @daily
+            if (ch == '@')
+            {
+                tokens.Add(LexShortcut());
+                continue;
+            }
+
+            // Minutes past the hour: :MM
+            if (ch == ':')
+            {
+                tokens.Add(LexMinuteOffset());
+                continue;
+            }
+
             if (IsAlpha(ch))
             {
                 tokens.Add(LexWord());
@@ -70,6 +84,22 @@ public sealed class Lexer
         return tokens;
     }
 
+    // Reports whether the next word in the input is the given word.
+    private bool NextWordIs(string word)
+    {
+        var i = _pos;
+        while (i < _input.Length && IsWhitespace(_input[i]))
+        {
+            i++;
+        }
+        var end = i + word.Length;
+        if (end > _input.Length || !string.Equals(_input[i..end], word, StringComparison.OrdinalIgnoreCase))
+        {
+            return false;
+        }
+        return end == _input.Length || !(IsAlphanumeric(_input[end]) || _input[end] == '_');
+    }
+
     private void SkipWhitespace()
     {
         while (_pos < _input.Length && IsWhitespace(_input[_pos]))
@@ -94,6 +124,33 @@ public sealed class Lexer
         return Token.Timezone(tz, new Span(start, _pos));
     }
 
+    private Token LexShortcut()
+    {
+        var start = _pos;
+        _pos++; // skip '@'
+        while (_pos < _input.Length && IsAlphanumeric(_input[_pos]))
+        {
+            _pos++;
+        }
+        return Token.Keyword(TokenKind.Shortcut, new Span(start, _pos));
+    }
+
+    private Token LexMinuteOffset()
+    {
+        var start = _pos;
+        _pos++; // skip ':'
+        while (_pos < _input.Length && IsDigit(_input[_pos]))
+        {
+            _pos++;
+        }
+        var digits = _input[(start + 1).._pos];
+        if (digits.Length != 2 || int.Parse(digits) > 59)
+        {
+            throw HronException.Lex("expected two-digit minute (:00 to :59) after ':'", new Span(start, _pos), _input);
+        }
+        return Token.MinuteOffset(int.Parse(digits), new Span(start, _pos));
+    }
+
     private Token LexNumberOrTimeOrDate()
     {
         var start = _pos;
@@ -139,6 +196,11 @@ public sealed class Lexer
             {
                 var hour = int.Parse(digits);
                 var minute = int.Parse(minDigits);
+                var pm = LexMeridiem();
+                if (pm.HasValue)
+                {
+                    return MeridiemTime(hour, minute, pm.Value, start);
+                }
                 if (hour > 23 || minute > 59)
                 {
                     throw HronException.Lex("invalid time", new Span(start, _pos), _input);
@@ -147,6 +209,27 @@ public sealed class Lexer
             }
         }
 
+        // Check for 12-hour time without minutes: 9am, 5pm
+        if (digits.Length == 1 || digits.Length == 2)
+        {
+            var pm = LexMeridiem();
+            if (pm.HasValue)
+            {
+                return MeridiemTime(int.Parse(digits), 0, pm.Value, start);
+            }
+        }
+
+        // Check for decimal: 1.5
+        if (_pos + 1 < _input.Length && _input[_pos] == '.' && IsDigit(_input[_pos + 1]))
+        {
+            _pos++; // skip '.'
+            while (_pos < _input.Length && IsDigit(_input[_pos]))
+            {
+                _pos++;
+            }
+            return Token.Decimal(_input[start.._pos], new Span(start, _pos));
+        }
+
         var num = int.Parse(digits);
 
         // Check for ordinal suffix: st, nd, rd, th
@@ -163,19 +246,73 @@ public sealed class Lexer
         return Token.Number(num, new Span(start, _pos));
     }
 
+    /// <summary>
+    /// Consumes an am/pm suffix directly following a time. Returns whether the suffix was pm,
+    /// or null if there is no suffix.
+    /// </summary>
+    private bool? LexMeridiem()
+    {
+        if (_pos + 2 > _input.Length)
+        {
+            return null;
+        }
+        var suffix = _input[_pos..(_pos + 2)].ToLowerInvariant();
+        if (suffix is not ("am" or "pm"))
+        {
+            return null;
+        }
+        // Must not run into a longer word (e.g. "9amx")
+        if (_pos + 2 < _input.Length && IsAlphanumeric(_input[_pos + 2]))
+        {
+            return null;
+        }
+        _pos += 2;
+        return suffix == "pm";
+    }
+
+    /// <summary>
+    /// Converts a 12-hour clock time to a 24-hour time token.
+    /// </summary>
+    private Token MeridiemTime(int hour, int minute, bool pm, int start)
+    {
+        if (hour < 1 || hour > 12 || minute > 59)
+        {
+            throw HronException.Lex("invalid 12-hour time (hour must be 1-12)", new Span(start, _pos), _input);
+        }
+        var h = hour;
+        if (h == 12)
+        {
+            if (!pm)
+            {
+                h = 0;
+            }
+        }
+        else if (pm)
+        {
+            h += 12;
+        }
+        return Token.Time(h, minute, new Span(start, _pos));
+    }
+
     private Token LexWord()
     {
         var start = _pos;
-        while (_pos < _input.Length && (IsAlphanumeric(_input[_pos]) || _input[_pos] == '_'))
+        while (_pos < _input.Length && (IsAlphanumeric(_input[_pos]) || _input[_pos] == '_' || IsWordHyphen()))
         {
             _pos++;
         }
         var word = _input[start.._pos].ToLowerInvariant();
         var span = new Span(start, _pos);
 
+        // Check keyword map, then ordinal words ("first" through "thirty-first")
         if (!KeywordMap.TryGetValue(word, out var template))
         {
-            throw HronException.Lex($"unknown keyword '{word}'", span, _input);
+            var n = OrdinalWordValue(word);
+            if (n == 0)
+            {
+                throw HronException.Lex($"unknown keyword '{word}'", span, _input);
+            }
+            return Token.Ordinal(n <= 5 ? (OrdinalPosition)n : null, n, span);
         }
 
         // Create a new token with the actual span
@@ -183,19 +320,59 @@ public sealed class Lexer
         {
             TokenKind.DayName => Token.DayName(template.DayNameVal!.Value, span),
             TokenKind.MonthName => Token.MonthName(template.MonthNameVal!.Value, span),
-            TokenKind.Ordinal => Token.Ordinal(template.OrdinalVal!.Value, span),
             TokenKind.IntervalUnit => Token.IntervalUnit(template.UnitVal!.Value, span),
             _ => Token.Keyword(template.Kind, span)
         };
 
         if (template.Kind == TokenKind.In)
         {
-            _afterIn = true;
+            // "in the morning" and "in iso weeks" are not timezone clauses
+            _afterIn = !NextWordIs("the") && !NextWordIs("iso");
         }
 
         return result;
     }
 
+    // Reports whether the current character is a hyphen joining two words, as in "twenty-first".
+    private bool IsWordHyphen()
+        => _input[_pos] == '-' && _pos + 1 < _input.Length && IsAlpha(_input[_pos + 1]);
+
+    private static readonly string[] OrdinalUnits =
+        ["first", "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth", "ninth"];
+
+    private static readonly string[] OrdinalTeens =
+    [
+        "tenth", "eleventh", "twelfth", "thirteenth", "fourteenth",
+        "fifteenth", "sixteenth", "seventeenth", "eighteenth", "nineteenth"
+    ];
+
+    // Returns the day number of an ordinal word ("first" is 1, "thirty-first" is 31), or 0
+    // if the word is not an ordinal.
+    private static int OrdinalWordValue(string word)
+    {
+        switch (word)
+        {
+            case "twentieth":
+                return 20;
+            case "thirtieth":
+                return 30;
+            case "thirty-first":
+                return 31;
+        }
+        if (word.StartsWith("twenty-"))
+        {
+            var unit = Array.IndexOf(OrdinalUnits, word["twenty-".Length..]);
+            return unit < 0 ? 0 : 21 + unit;
+        }
+        var i = Array.IndexOf(OrdinalUnits, word);
+        if (i >= 0)
+        {
+            return 1 + i;
+        }
+        i = Array.IndexOf(OrdinalTeens, word);
+        return i < 0 ? 0 : 10 + i;
+    }
+
     private static bool IsDigit(char c) => c is >= '0' and <= '9';
     private static bool IsAlpha(char c) => (c >= 'a' && c <= 'z') || (c >= 'A' && c <= 'Z');
     private static bool IsAlphanumeric(char c) => IsAlpha(c) || IsDigit(c);
@@ -211,6 +388,9 @@ public sealed class Lexer
         ["at"] = Token.Keyword(TokenKind.At, DummySpan),
         ["from"] = Token.Keyword(TokenKind.From, DummySpan),
         ["to"] = Token.Keyword(TokenKind.To, DummySpan),
+        ["through"] = Token.Keyword(TokenKind.Through, DummySpan),
+        ["business"] = Token.Keyword(TokenKind.Business, DummySpan),
+        ["past"] = Token.Keyword(TokenKind.Past, DummySpan),
         ["in"] = Token.Keyword(TokenKind.In, DummySpan),
         ["of"] = Token.Keyword(TokenKind.Of, DummySpan),
         ["the"] = Token.Keyword(TokenKind.The, DummySpan),
@@ -231,24 +411,45 @@ public sealed class Lexer
         ["week"] = Token.Keyword(TokenKind.Weeks, DummySpan),
         ["month"] = Token.Keyword(TokenKind.Month, DummySpan),
         ["months"] = Token.Keyword(TokenKind.Month, DummySpan),
+        ["daily"] = Token.Keyword(TokenKind.Daily, DummySpan),
+        ["weekly"] = Token.Keyword(TokenKind.Weekly, DummySpan),
+        ["monthly"] = Token.Keyword(TokenKind.Monthly, DummySpan),
+        ["yearly"] = Token.Keyword(TokenKind.Yearly, DummySpan),
+        ["hourly"] = Token.Keyword(TokenKind.Hourly, DummySpan),
+        ["morning"] = Token.Keyword(TokenKind.Morning, DummySpan),
+        ["afternoon"] = Token.Keyword(TokenKind.Afternoon, DummySpan),
+        ["evening"] = Token.Keyword(TokenKind.Evening, DummySpan),
+        ["night"] = Token.Keyword(TokenKind.Night, DummySpan),
+        ["today"] = Token.Keyword(TokenKind.Today, DummySpan),
+        ["now"] = Token.Keyword(TokenKind.Now, DummySpan),
         ["nearest"] = Token.Keyword(TokenKind.Nearest, DummySpan),
         ["next"] = Token.Keyword(TokenKind.Next, DummySpan),
         ["previous"] = Token.Keyword(TokenKind.Previous, DummySpan),
+        ["between"] = Token.Keyword(TokenKind.Between, DummySpan),
+        ["and"] = Token.Keyword(TokenKind.And, DummySpan),
+        ["iso"] = Token.Keyword(TokenKind.Iso, DummySpan),
 
         // Day names
         ["monday"] = Token.DayName(Ast.Weekday.Monday, DummySpan),
+        ["mondays"] = Token.DayName(Ast.Weekday.Monday, DummySpan),
         ["mon"] = Token.DayName(Ast.Weekday.Monday, DummySpan),
         ["tuesday"] = Token.DayName(Ast.Weekday.Tuesday, DummySpan),
+        ["tuesdays"] = Token.DayName(Ast.Weekday.Tuesday, DummySpan),
         ["tue"] = Token.DayName(Ast.Weekday.Tuesday, DummySpan),
         ["wednesday"] = Token.DayName(Ast.Weekday.Wednesday, DummySpan),
+        ["wednesdays"] = Token.DayName(Ast.Weekday.Wednesday, DummySpan),
         ["wed"] = Token.DayName(Ast.Weekday.Wednesday, DummySpan),
         ["thursday"] = Token.DayName(Ast.Weekday.Thursday, DummySpan),
+        ["thursdays"] = Token.DayName(Ast.Weekday.Thursday, DummySpan),
         ["thu"] = Token.DayName(Ast.Weekday.Thursday, DummySpan),
         ["friday"] = Token.DayName(Ast.Weekday.Friday, DummySpan),
+        ["fridays"] = Token.DayName(Ast.Weekday.Friday, DummySpan),
         ["fri"] = Token.DayName(Ast.Weekday.Friday, DummySpan),
         ["saturday"] = Token.DayName(Ast.Weekday.Saturday, DummySpan),
+        ["saturdays"] = Token.DayName(Ast.Weekday.Saturday, DummySpan),
         ["sat"] = Token.DayName(Ast.Weekday.Saturday, DummySpan),
         ["sunday"] = Token.DayName(Ast.Weekday.Sunday, DummySpan),
+        ["sundays"] = Token.DayName(Ast.Weekday.Sunday, DummySpan),
         ["sun"] = Token.DayName(Ast.Weekday.Sunday, DummySpan),
 
         // Month names
@@ -276,13 +477,6 @@ public sealed class Lexer
         ["december"] = Token.MonthName(Ast.MonthName.December, DummySpan),
         ["dec"] = Token.MonthName(Ast.MonthName.December, DummySpan),
 
-        // Ordinals
-        ["first"] = Token.Ordinal(OrdinalPosition.First, DummySpan),
-        ["second"] = Token.Ordinal(OrdinalPosition.Second, DummySpan),
-        ["third"] = Token.Ordinal(OrdinalPosition.Third, DummySpan),
-        ["fourth"] = Token.Ordinal(OrdinalPosition.Fourth, DummySpan),
-        ["fifth"] = Token.Ordinal(OrdinalPosition.Fifth, DummySpan),
-
         // Interval units
         ["min"] = Token.IntervalUnit(Ast.IntervalUnit.Minutes, DummySpan),
         ["mins"] = Token.IntervalUnit(Ast.IntervalUnit.Minutes, DummySpan),
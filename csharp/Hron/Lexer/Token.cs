@@ -16,7 +16,8 @@ public sealed record Token(
     int TimeHour = 0,
     int TimeMinute = 0,
     string? IsoDateVal = null,
-    string? TimezoneVal = null)
+    string? TimezoneVal = null,
+    string? DecimalVal = null)
 {
     /// <summary>Creates a simple keyword token.</summary>
     public static Token Keyword(TokenKind kind, Span span)
@@ -30,9 +31,9 @@ public sealed record Token(
     public static Token MonthName(MonthName month, Span span)
         => new(TokenKind.MonthName, span, MonthNameVal: month);
 
-    /// <summary>Creates an ordinal token.</summary>
-    public static Token Ordinal(OrdinalPosition ord, Span span)
-        => new(TokenKind.Ordinal, span, OrdinalVal: ord);
+    /// <summary>Creates an ordinal word token ("first" through "thirty-first"); ord is null past "fifth".</summary>
+    public static Token Ordinal(OrdinalPosition? ord, int day, Span span)
+        => new(TokenKind.Ordinal, span, OrdinalVal: ord, NumberVal: day);
 
     /// <summary>Creates an interval unit token.</summary>
     public static Token IntervalUnit(IntervalUnit unit, Span span)
@@ -50,6 +51,10 @@ public sealed record Token(
     public static Token Time(int hour, int minute, Span span)
         => new(TokenKind.Time, span, TimeHour: hour, TimeMinute: minute);
 
+    /// <summary>Creates a minutes-past-the-hour token (e.g., ":15").</summary>
+    public static Token MinuteOffset(int minute, Span span)
+        => new(TokenKind.MinuteOffset, span, TimeMinute: minute);
+
     /// <summary>Creates an ISO date token.</summary>
     public static Token IsoDate(string date, Span span)
         => new(TokenKind.IsoDate, span, IsoDateVal: date);
@@ -58,6 +63,10 @@ public sealed record Token(
     public static Token Comma(Span span)
         => new(TokenKind.Comma, span);
 
+    /// <summary>Creates a decimal token from the literal as written (e.g., "1.5").</summary>
+    public static Token Decimal(string text, Span span)
+        => new(TokenKind.Decimal, span, DecimalVal: text);
+
     /// <summary>Creates a timezone token.</summary>
     public static Token Timezone(string tz, Span span)
         => new(TokenKind.Timezone, span, TimezoneVal: tz);
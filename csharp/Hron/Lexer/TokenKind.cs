@@ -11,6 +11,9 @@ public enum TokenKind
     At,
     From,
     To,
+    Through,
+    Business,
+    Past,
     In,
     Of,
     The,
@@ -28,6 +31,21 @@ public enum TokenKind
     Nearest,
     Next,
     Previous,
+    Between,
+    And,
+    Iso,
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+    Hourly,
+    Shortcut,
+    Morning,
+    Afternoon,
+    Evening,
+    Night,
+    Today,
+    Now,
 
     // Value-carrying tokens
     DayName,
@@ -35,8 +53,10 @@ public enum TokenKind
     Ordinal,
     IntervalUnit,
     Number,
+    Decimal,
     OrdinalNumber,
     Time,
+    MinuteOffset,
     IsoDate,
     Comma,
     Timezone
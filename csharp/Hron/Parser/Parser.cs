@@ -1,4 +1,5 @@
 using Hron.Ast;
+using Hron.Cron;
 using Hron.Lexer;
 
 namespace Hron.Parser;
@@ -46,7 +47,9 @@ public sealed class Parser
         IReadOnlyList<ExceptionSpec> except = [];
         UntilSpec? until = null;
         string? anchor = null;
-        IReadOnlyList<MonthName> during = [];
+        DateSpec? anchorNamed = null;
+        TimeOfDay? anchorTime = null;
+        IReadOnlyList<DuringSpec> during = [];
         string? timezone = null;
 
         while (_pos < _tokens.Count)
@@ -59,7 +62,7 @@ public sealed class Parser
                         throw ParseError("duplicate except clause", tok.Span);
                     if (until is not null)
                         throw ParseError("wrong clause order: until before except", tok.Span);
-                    if (anchor is not null)
+                    if (anchor is not null || anchorNamed is not null)
                         throw ParseError("wrong clause order: starting before except", tok.Span);
                     if (during.Count > 0)
                         throw ParseError("wrong clause order: during before except", tok.Span);
@@ -72,7 +75,7 @@ public sealed class Parser
                 case TokenKind.Until:
                     if (until is not null)
                         throw ParseError("duplicate until clause", tok.Span);
-                    if (anchor is not null)
+                    if (anchor is not null || anchorNamed is not null)
                         throw ParseError("wrong clause order: starting before until", tok.Span);
                     if (during.Count > 0)
                         throw ParseError("wrong clause order: during before until", tok.Span);
@@ -83,14 +86,31 @@ public sealed class Parser
                     break;
 
                 case TokenKind.Starting:
-                    if (anchor is not null)
+                    if (anchor is not null || anchorNamed is not null)
                         throw ParseError("duplicate starting clause", tok.Span);
                     if (during.Count > 0)
                         throw ParseError("wrong clause order: during before starting", tok.Span);
                     if (timezone is not null)
                         throw ParseError("wrong clause order: in before starting", tok.Span);
                     _pos++;
-                    anchor = ParseStarting();
+                    if (Check(TokenKind.MonthName))
+                    {
+                        var monthTok = Expect(TokenKind.MonthName);
+                        var dayTok = ParseDayNumber();
+                        ValidateNamedDate(monthTok.MonthNameVal!.Value, dayTok.NumberVal, dayTok.Span);
+                        anchorNamed = DateSpec.Named(monthTok.MonthNameVal!.Value, dayTok.NumberVal);
+                    }
+                    else
+                    {
+                        anchor = ParseStarting();
+                    }
+                    if (Check(TokenKind.At))
+                    {
+                        if (anchor == "now")
+                            throw ParseError("'starting now' already includes a time", Peek()!.Span);
+                        _pos++;
+                        anchorTime = ParseTime();
+                    }
                     break;
 
                 case TokenKind.During:
@@ -112,7 +132,63 @@ public sealed class Parser
             }
         }
 
-        return new ScheduleData(expr, timezone, except, until, anchor, during);
+        var data = new ScheduleData(expr, timezone, except, until, anchor, during)
+        {
+            AnchorNamed = anchorNamed,
+            AnchorTime = anchorTime,
+        };
+        CheckDayStepReachable(data);
+        return data;
+    }
+
+    // Rejects a day step whose filter excludes every aligned day. A step that is a multiple
+    // of 7 lands on the anchor's weekday every time, so a filter without that weekday never fires.
+    // With a relative anchor ("starting today", "starting now", or a month-day) that weekday
+    // depends on the evaluation date, so such a step needs a filter that lets every day through.
+    private void CheckDayStepReachable(ScheduleData data)
+    {
+        var (interval, filter) = data.Expr switch
+        {
+            DayRepeat dr => (dr.Interval, dr.Days),
+            IntervalRepeat { Unit: IntervalUnit.Days, DayFilter: { } df } ir => (ir.Interval, df),
+            _ => (0, (DayFilter?)null)
+        };
+        if (filter is null || interval % 7 != 0)
+        {
+            return;
+        }
+        var anchor = data.Anchor;
+        if (data.HasRelativeAnchor)
+        {
+            var everyDay = filter.Kind switch
+            {
+                DayFilterKind.Every => true,
+                DayFilterKind.Days => filter.Days.Distinct().Count() == 7,
+                _ => false
+            };
+            if (!everyDay)
+            {
+                throw ParseError(
+                    $"every {interval} days starting {data.AnchorText} lands on the weekday of the evaluation date, which the day filter may exclude",
+                    new Span(0, _input.Length));
+            }
+            return;
+        }
+        var anchorDate = anchor is not null ? DateOnly.Parse(anchor) : new DateOnly(1970, 1, 1);
+        var weekday = WeekdayExtensions.FromDayOfWeek(anchorDate.DayOfWeek);
+        var reachable = filter.Kind switch
+        {
+            DayFilterKind.Weekday => weekday.Number() <= 5,
+            DayFilterKind.Weekend => weekday.Number() > 5,
+            DayFilterKind.Days => filter.Days.Contains(weekday),
+            _ => true
+        };
+        if (!reachable)
+        {
+            throw ParseError(
+                $"every {interval} days always lands on a {weekday.ToDisplayString()}, which the day filter excludes",
+                new Span(0, _input.Length));
+        }
     }
 
     private IScheduleExpr ParseExpr()
@@ -127,10 +203,78 @@ public sealed class Parser
         {
             TokenKind.Every => ParseEveryExpr(),
             TokenKind.On => ParseSingleDate(),
-            _ => throw ParseError("expected 'every' or 'on'", tok.Span)
+            TokenKind.Daily or TokenKind.Weekly or TokenKind.Monthly or TokenKind.Yearly or TokenKind.Hourly => ParseFrequency(),
+            TokenKind.Shortcut => ParseShortcut(),
+            TokenKind.At => ParsePastEveryHour(),
+            _ => throw ParseError("expected 'every', 'on', 'at', or a frequency keyword", tok.Span)
+        };
+    }
+
+    /// <summary>
+    /// Cron @ shortcut: "@daily" is "every day at 00:00", and takes the same trailing clauses as that
+    /// form ("@daily in UTC").
+    /// </summary>
+    private IScheduleExpr ParseShortcut()
+    {
+        var tok = _tokens[_pos++];
+        var name = _input[tok.Span.Start..tok.Span.End].ToLowerInvariant();
+        return CronConverter.CronShortcutExpr(name)
+            ?? throw HronException.Parse(
+                $"unknown @ shortcut: {name}",
+                tok.Span,
+                _input,
+                "@yearly, @monthly, @weekly, @daily, @midnight, or @hourly");
+    }
+
+    /// <summary>
+    /// Bare shorthand: "daily", "weekly", "monthly", "yearly", or "hourly". Each maps to the canonical
+    /// "every ..." form with interval 1 and accepts what that form does. Without "on", weekly falls on
+    /// monday (the default week start), monthly on the 1st, and yearly on jan 1; without a window,
+    /// hourly runs all day.
+    /// </summary>
+    private IScheduleExpr ParseFrequency()
+    {
+        var kind = Peek()!.Kind;
+        var hasOn = PeekAhead(1, TokenKind.On);
+        switch (kind)
+        {
+            case TokenKind.Daily:
+                return ParseDayWithInterval(1);
+            case TokenKind.Weekly when hasOn:
+                return ParseWeeksWithInterval(1);
+            case TokenKind.Monthly when hasOn:
+                return ParseMonthWithInterval(1);
+            // "yearly in iso weeks ..." as well as "yearly on ..."
+            case TokenKind.Yearly when hasOn || (PeekAhead(1, TokenKind.In) && PeekAhead(2, TokenKind.Iso)):
+                return ParseYearWithInterval(1);
+            case TokenKind.Hourly:
+                return ParseHourly();
+        }
+
+        _pos++;
+        var times = ParseTimesOrMidnight();
+        return kind switch
+        {
+            TokenKind.Weekly => new WeekRepeat(1, [Weekday.Monday], times),
+            TokenKind.Monthly => new MonthRepeat(1, MonthTarget.Days([DayOfMonthSpec.Single(1)]), times),
+            _ => new YearRepeat(1, YearTarget.Date(MonthName.January, 1), times)
         };
     }
 
+    private bool PeekAhead(int offset, TokenKind kind)
+        => _pos + offset < _tokens.Count && _tokens[_pos + offset].Kind == kind;
+
+    // "hourly", "hourly at :MM", or "hourly from HH:MM to HH:MM", as after "every hour"
+    private IScheduleExpr ParseHourly()
+    {
+        _pos++;
+        if (Check(TokenKind.At) || Check(TokenKind.From) || Check(TokenKind.Between))
+        {
+            return ParseIntervalWindow(1, IntervalUnit.Hours);
+        }
+        return HourlyAt(1, 0, ParseOptionalDayFilter());
+    }
+
     private IScheduleExpr ParseEveryExpr()
     {
         Expect(TokenKind.Every);
@@ -144,9 +288,13 @@ public sealed class Parser
         return next.Kind switch
         {
             TokenKind.Number => ParseEveryNumber(),
+            // "every hour at :15", "every minute from 09:00 to 10:00"
+            TokenKind.IntervalUnit => ParseIntervalRepeat(1),
+            // "every 1.5 hours from ..."
+            TokenKind.Decimal => ParseFractionalInterval(),
             TokenKind.Day or TokenKind.Weekday or TokenKind.Weekend or TokenKind.DayName => ParseDayRepeat(),
             TokenKind.Weeks => ParseWeeksWithInterval(1),
-            TokenKind.Year => ParseYearRepeat(),
+            TokenKind.Year => ParseYearWithInterval(1),
             TokenKind.Month => ParseMonthRepeat(),
             _ => throw ParseError("unexpected token after 'every'", next.Span)
         };
@@ -172,27 +320,51 @@ public sealed class Parser
         {
             TokenKind.IntervalUnit => ParseIntervalRepeat(interval),
             TokenKind.Day => ParseDayWithInterval(interval),
+            TokenKind.Weekday => ParseWeekdaysWithInterval(interval),
             TokenKind.Weeks => ParseWeeksWithInterval(interval),
             TokenKind.Month => ParseMonthWithInterval(interval),
             TokenKind.Year => ParseYearWithInterval(interval),
-            _ => throw ParseError("expected unit (min/hours/day/weeks/month/year) after number", next.Span)
+            _ => throw ParseError("expected unit (min/hours/day/weekday/weeks/month/year) after number", next.Span)
         };
     }
 
+    // "every N days [on day_target] at ..."
     private IScheduleExpr ParseDayWithInterval(int interval)
     {
         _pos++;
+        // "every N days from HH:MM to HH:MM" - day-step interval window
+        if (Check(TokenKind.From) || Check(TokenKind.Between))
+        {
+            return ParseIntervalWindow(interval, IntervalUnit.Days);
+        }
         var days = DayFilter.Every();
-        var times = ParseAtTimes();
+        if (Check(TokenKind.On))
+        {
+            _pos++;
+            days = ParseDayFilter();
+        }
+        var times = ParseTimesOrMidnight();
         return new DayRepeat(interval, days, times);
     }
 
+    // "every N weekdays at ..." - every Nth business day
+    private IScheduleExpr ParseWeekdaysWithInterval(int interval)
+    {
+        _pos++;
+        var times = ParseTimesOrMidnight();
+        if (interval == 1)
+        {
+            return new DayRepeat(1, DayFilter.Weekday(), times);
+        }
+        return new WeekdayRepeat(interval, times);
+    }
+
     private IScheduleExpr ParseWeeksWithInterval(int interval)
     {
         _pos++;
         Expect(TokenKind.On);
         var weekDays = ParseDayList();
-        var times = ParseAtTimes();
+        var times = ParseTimesOrMidnight();
         return new WeekRepeat(interval, weekDays, times);
     }
 
@@ -202,26 +374,59 @@ public sealed class Parser
         Expect(TokenKind.On);
         Expect(TokenKind.The);
         var target = ParseMonthTarget();
-        var times = ParseAtTimes();
+        var times = ParseTimes();
         return new MonthRepeat(interval, target, times);
     }
 
     private IScheduleExpr ParseYearWithInterval(int interval)
     {
         _pos++;
+        if (Check(TokenKind.In))
+        {
+            return ParseIsoWeekRepeat(interval);
+        }
         Expect(TokenKind.On);
         var target = ParseYearTarget();
-        var times = ParseAtTimes();
+        var times = ParseTimes();
         return new YearRepeat(interval, target, times);
     }
 
     private IScheduleExpr ParseDayRepeat()
     {
+        if (Check(TokenKind.DayName))
+        {
+            var dayList = ParseDayList();
+            // "every monday every 2 weeks at ..." is a week repeat
+            if (Check(TokenKind.Every))
+            {
+                _pos++;
+                var interval = ParseWeekInterval();
+                return new WeekRepeat(interval, dayList, ParseTimesOrMidnight());
+            }
+            return new DayRepeat(1, DayFilter.SpecificDays(dayList).Canonical(), ParseTimesOrMidnight());
+        }
         var days = ParseDayFilter();
-        var times = ParseAtTimes();
+        var times = ParseTimesOrMidnight();
         return new DayRepeat(1, days, times);
     }
 
+    // "week" or "N weeks", after a day list and a second "every"
+    private int ParseWeekInterval()
+    {
+        var interval = 1;
+        if (Check(TokenKind.Number))
+        {
+            var numTok = _tokens[_pos++];
+            if (numTok.NumberVal == 0)
+            {
+                throw ParseError("zero interval", numTok.Span);
+            }
+            interval = numTok.NumberVal;
+        }
+        Expect(TokenKind.Weeks);
+        return interval;
+    }
+
     private DayFilter ParseDayFilter()
     {
         var tok = Peek();
@@ -235,7 +440,7 @@ public sealed class Parser
             TokenKind.Day => AdvanceAndReturn(DayFilter.Every()),
             TokenKind.Weekday => AdvanceAndReturn(DayFilter.Weekday()),
             TokenKind.Weekend => AdvanceAndReturn(DayFilter.Weekend()),
-            TokenKind.DayName => DayFilter.SpecificDays(ParseDayList()),
+            TokenKind.DayName => DayFilter.SpecificDays(ParseDayList()).Canonical(),
             _ => throw ParseError("expected day filter", tok.Span)
         };
     }
@@ -248,41 +453,182 @@ public sealed class Parser
 
     private IReadOnlyList<Weekday> ParseDayList()
     {
-        var days = new List<Weekday>();
-
-        var tok = Expect(TokenKind.DayName);
-        days.Add(tok.DayNameVal!.Value);
+        var days = new List<Weekday>(ParseDayRange());
 
         while (Check(TokenKind.Comma))
         {
             _pos++;
-            tok = Expect(TokenKind.DayName);
-            days.Add(tok.DayNameVal!.Value);
+            days.AddRange(ParseDayRange());
         }
 
         return days;
     }
 
+    // Parses a day name or a range of them: "monday to friday", "fri through mon" (wraps).
+    private IReadOnlyList<Weekday> ParseDayRange()
+    {
+        var start = Expect(TokenKind.DayName).DayNameVal!.Value;
+        if (!AtRangeSeparator())
+        {
+            return [start];
+        }
+        _pos++;
+        var end = Expect(TokenKind.DayName).DayNameVal!.Value;
+        var length = ((int)end - (int)start + 7) % 7 + 1;
+        var days = new List<Weekday>();
+        for (var i = 0; i < length; i++)
+        {
+            days.Add((Weekday)(((int)start - 1 + i) % 7 + 1));
+        }
+        return days;
+    }
+
+    // Reports whether the next token separates a range: "to", or its synonym "through".
+    // "through" is only accepted in ranges, never in "from ... to ..." windows.
+    private bool AtRangeSeparator() => Check(TokenKind.To) || Check(TokenKind.Through);
+
     private IScheduleExpr ParseIntervalRepeat(int interval)
     {
         var unitTok = Expect(TokenKind.IntervalUnit);
-        var unit = unitTok.UnitVal!.Value;
+        return ParseIntervalWindow(interval, unitTok.UnitVal!.Value);
+    }
+
+    // "every D hours ..." where D is a decimal: converted to whole minutes, so
+    // "every 1.5 hours" is the same schedule as "every 90 min".
+    private IScheduleExpr ParseFractionalInterval()
+    {
+        var tok = _tokens[_pos++];
+        var unitTok = Peek();
+        if (unitTok is null || unitTok.Kind != TokenKind.IntervalUnit || unitTok.UnitVal == IntervalUnit.Minutes)
+        {
+            throw ParseError("fractional intervals are only supported in hours", tok.Span);
+        }
+        _pos++;
+
+        var minutes = FractionalHoursToMinutes(tok.DecimalVal!);
+        if (minutes < 0)
+        {
+            throw ParseError("fractional hours must come to a whole number of minutes", tok.Span);
+        }
+        if (minutes == 0)
+        {
+            throw ParseError("interval must be at least 1", tok.Span);
+        }
+
+        if (minutes % 60 == 0)
+        {
+            return ParseIntervalWindow(minutes / 60, IntervalUnit.Hours);
+        }
+        return ParseIntervalWindow(minutes, IntervalUnit.Minutes);
+    }
+
+    // Converts decimal hours (1.5) to whole minutes, or returns -1 if they don't come to a
+    // whole number of minutes. A minute is 1/60 hour, so past two decimal places (ignoring
+    // trailing zeros) the hours never land on one.
+    private static int FractionalHoursToMinutes(string text)
+    {
+        var dot = text.IndexOf('.');
+        var whole = text[..dot];
+        var frac = text[(dot + 1)..].TrimEnd('0');
+        if (frac.Length > 2)
+        {
+            return -1;
+        }
+        if (!long.TryParse(whole + frac.PadRight(2, '0'), out var hundredths))
+        {
+            return -1;
+        }
+        if (hundredths * 60 % 100 != 0)
+        {
+            return -1;
+        }
+        return (int)(hundredths * 60 / 100);
+    }
 
-        Expect(TokenKind.From);
+    // Parses the window and optional day filter after the interval unit.
+    private IScheduleExpr ParseIntervalWindow(int interval, IntervalUnit unit)
+    {
+        // "every 2 hours at :15": all day, starting MM past midnight
+        if (unit == IntervalUnit.Hours && Check(TokenKind.At))
+        {
+            _pos++;
+            var minute = ParseMinutePast(false);
+            return HourlyAt(interval, minute, ParseOptionalDayFilter());
+        }
+
+        // "from HH:MM to HH:MM" or "between HH:MM and HH:MM"
+        var closing = TokenKind.To;
+        if (Check(TokenKind.Between))
+        {
+            _pos++;
+            closing = TokenKind.And;
+        }
+        else
+        {
+            Expect(TokenKind.From);
+        }
         var fromTime = ParseTime();
-        Expect(TokenKind.To);
+        Expect(closing);
         var toTime = ParseTime();
 
-        DayFilter? dayFilter = null;
-        if (Check(TokenKind.On))
+        return new IntervalRepeat(interval, unit, fromTime, toTime, ParseOptionalDayFilter());
+    }
+
+    // Optional "on day_target" after an interval window
+    private DayFilter? ParseOptionalDayFilter()
+    {
+        if (!Check(TokenKind.On))
         {
-            _pos++;
-            dayFilter = ParseDayFilterForInterval();
+            return null;
         }
+        _pos++;
+        return ParseDayFilterForInterval();
+    }
 
-        return new IntervalRepeat(interval, unit, fromTime, toTime, dayFilter);
+    // "at 15 past every hour", "at :15 past every hour"
+    private IScheduleExpr ParsePastEveryHour()
+    {
+        Expect(TokenKind.At);
+        var minute = ParseMinutePast(true);
+        Expect(TokenKind.Past);
+        Expect(TokenKind.Every);
+        var tok = Peek();
+        if (tok is null || tok.Kind != TokenKind.IntervalUnit || tok.UnitVal != IntervalUnit.Hours)
+        {
+            throw ParseError("expected 'hour'", tok?.Span ?? EndSpan());
+        }
+        _pos++;
+        return HourlyAt(1, minute, ParseOptionalDayFilter());
     }
 
+    // Minutes past the hour, as ":MM" or (with allowNumber) a bare number
+    private int ParseMinutePast(bool allowNumber)
+    {
+        var tok = Peek();
+        int minute;
+        if (tok is not null && tok.Kind == TokenKind.MinuteOffset)
+        {
+            minute = tok.TimeMinute;
+        }
+        else if (allowNumber && tok is not null && tok.Kind == TokenKind.Number && tok.NumberVal <= 59)
+        {
+            minute = tok.NumberVal;
+        }
+        else
+        {
+            throw ParseError("expected minutes past the hour (e.g. :15)", tok?.Span ?? EndSpan());
+        }
+        _pos++;
+        return minute;
+    }
+
+    /// <summary>
+    /// Builds an all-day hour step firing minute past the hour: "every hour at :15" is
+    /// "every 1 hour from 00:15 to 23:59".
+    /// </summary>
+    private static IntervalRepeat HourlyAt(int interval, int minute, DayFilter? dayFilter)
+        => new(interval, IntervalUnit.Hours, new TimeOfDay(0, minute), new TimeOfDay(23, 59), dayFilter);
+
     private DayFilter ParseDayFilterForInterval()
     {
         var tok = Peek();
@@ -296,7 +642,7 @@ public sealed class Parser
             TokenKind.Day => AdvanceAndReturn(DayFilter.Every()),
             TokenKind.Weekday => AdvanceAndReturn(DayFilter.Weekday()),
             TokenKind.Weekend => AdvanceAndReturn(DayFilter.Weekend()),
-            TokenKind.DayName => DayFilter.SpecificDays(ParseDayList()),
+            TokenKind.DayName => DayFilter.SpecificDays(ParseDayList()).Canonical(),
             _ => throw ParseError("expected day filter after 'on'", tok.Span)
         };
     }
@@ -308,7 +654,7 @@ public sealed class Parser
         Expect(TokenKind.The);
 
         var target = ParseMonthTarget();
-        var times = ParseAtTimes();
+        var times = ParseTimes();
 
         return new MonthRepeat(1, target, times);
     }
@@ -321,6 +667,12 @@ public sealed class Parser
             throw ParseError("unexpected end of input", EndSpan());
         }
 
+        // "the first monday", "the first and last friday", as opposed to "the first" (day 1)
+        if (tok.Kind is TokenKind.Ordinal or TokenKind.Last && OrdinalWeekdaysAhead())
+        {
+            return ParseOrdinalWeekdays();
+        }
+
         if (tok.Kind == TokenKind.Last)
         {
             _pos++;
@@ -330,25 +682,30 @@ public sealed class Parser
                 _pos++;
                 return MonthTarget.LastDay();
             }
-            if (next is not null && next.Kind == TokenKind.Weekday)
+            if (next is not null && next.Kind == TokenKind.Number)
             {
+                // "last N days"; "last 1 day" is the last day
+                var n = next.NumberVal;
+                if (n < 1 || n > 31)
+                {
+                    throw ParseError($"invalid day count: {n} (must be 1-31)", next.Span);
+                }
                 _pos++;
-                return MonthTarget.LastWeekday();
+                Expect(TokenKind.Day);
+                return n == 1 ? MonthTarget.LastDay() : MonthTarget.LastNDays(n);
             }
-            if (next is not null && next.Kind == TokenKind.DayName)
+            if (next is not null && next.Kind == TokenKind.Weekday)
             {
-                var weekday = _tokens[_pos++].DayNameVal!.Value;
-                return MonthTarget.OrdinalWeekday(OrdinalPosition.Last, weekday);
+                _pos++;
+                return MonthTarget.LastWeekday();
             }
             throw ParseError("expected 'day', 'weekday', or day name after 'last'", next?.Span ?? EndSpan());
         }
 
-        // Check for ordinal + day name (e.g., "first monday")
-        if (tok.Kind == TokenKind.Ordinal)
+        // "the first weekday", "the 3rd business day"
+        if (tok.Kind is TokenKind.Ordinal or TokenKind.OrdinalNumber && NextIsBusinessDay())
         {
-            var ordinal = _tokens[_pos++].OrdinalVal!.Value;
-            var dayTok = Expect(TokenKind.DayName);
-            return MonthTarget.OrdinalWeekday(ordinal, dayTok.DayNameVal!.Value);
+            return ParseBusinessDayTarget();
         }
 
         // Check for [next|previous] nearest weekday to <day>
@@ -383,16 +740,11 @@ public sealed class Parser
         Expect(TokenKind.Weekday);
         Expect(TokenKind.To);
 
-        var dayTok = Expect(TokenKind.OrdinalNumber);
-        var day = dayTok.NumberVal;
-        if (day < 1 || day > 31)
-        {
-            throw ParseError($"invalid day number {day} (must be 1-31)", dayTok.Span);
-        }
-
+        var day = ParseOrdinalDay("expected ordinal day number");
         return MonthTarget.NearestWeekday(day, direction);
     }
 
+    // Day specs are sorted and de-duplicated: "the 15th, 1st, 15th" is "the 1st, 15th"
     private IReadOnlyList<DayOfMonthSpec> ParseDayOfMonthSpecs()
     {
         var specs = new List<DayOfMonthSpec> { ParseDayOfMonthSpec() };
@@ -403,47 +755,188 @@ public sealed class Parser
             specs.Add(ParseDayOfMonthSpec());
         }
 
-        return specs;
+        return specs.OrderBy(DaySpecKey).DistinctBy(DaySpecKey).ToList();
     }
 
+    /// <summary>Ordering key for a day spec: by first day, then last day.</summary>
+    private static int DaySpecKey(DayOfMonthSpec spec) =>
+        spec.Kind == DayOfMonthSpecKind.Single
+            ? spec.Day * 100 + spec.Day
+            : spec.Start * 100 + spec.End;
+
     private DayOfMonthSpec ParseDayOfMonthSpec()
     {
-        var tok = Expect(TokenKind.OrdinalNumber);
-        var start = tok.NumberVal;
+        var startSpan = Peek()?.Span ?? EndSpan();
+        var start = ParseOrdinalDay("expected ordinal day number");
 
-        if (start < 1 || start > 31)
+        // "1st to 15th", "1st through 15th"
+        if (AtRangeSeparator())
         {
-            throw ParseError($"invalid day number {start} (must be 1-31)", tok.Span);
+            _pos++;
+            var end = ParseOrdinalDay("expected ordinal day number after 'to'");
+            if (start > end)
+            {
+                throw ParseError($"invalid day range: {start} to {end} (start must be <= end)", startSpan);
+            }
+            return DayOfMonthSpec.Range(start, end);
         }
 
-        if (Check(TokenKind.To))
+        return DayOfMonthSpec.Single(start);
+    }
+
+    // Parses an ordinal day of month, as digits (21st) or a word (twentieth).
+    private int ParseOrdinalDay(string expected)
+    {
+        var tok = Peek();
+        if (tok is null || (tok.Kind != TokenKind.OrdinalNumber && tok.Kind != TokenKind.Ordinal))
         {
-            _pos++;
-            var endTok = Expect(TokenKind.OrdinalNumber);
-            var end = endTok.NumberVal;
-            if (end < 1 || end > 31)
+            throw ParseError(expected, tok?.Span ?? EndSpan());
+        }
+        var day = tok.NumberVal;
+        if (day < 1 || day > 31)
+        {
+            throw ParseError($"invalid day number {day} (must be 1-31)", tok.Span);
+        }
+        _pos++;
+        return day;
+    }
+
+    // Parses an ordinal position word (first through fifth).
+    private OrdinalPosition ParseOrdinalPosition()
+    {
+        var tok = _tokens[_pos];
+        if (tok.OrdinalVal is null)
+        {
+            throw ParseError($"unknown ordinal '{_input[tok.Span.Start..tok.Span.End]}'", tok.Span);
+        }
+        _pos++;
+        return tok.OrdinalVal.Value;
+    }
+
+    // Reports whether a list of ordinals joined by "," or "and" ends in a day name.
+    private bool OrdinalWeekdaysAhead()
+    {
+        for (var i = _pos; i < _tokens.Count; i += 2)
+        {
+            if (_tokens[i].Kind is not (TokenKind.Ordinal or TokenKind.Last))
             {
-                throw ParseError($"invalid day number {end} (must be 1-31)", endTok.Span);
+                return false;
             }
-            if (start > end)
+            if (i + 1 >= _tokens.Count)
             {
-                throw ParseError($"invalid day range: {start} to {end} (start must be <= end)", tok.Span);
+                return false;
+            }
+            var next = _tokens[i + 1].Kind;
+            if (next == TokenKind.DayName)
+            {
+                return true;
+            }
+            if (next is not (TokenKind.Comma or TokenKind.And))
+            {
+                return false;
             }
-            return DayOfMonthSpec.Range(start, end);
         }
+        return false;
+    }
 
-        return DayOfMonthSpec.Single(start);
+    // <ordinal> [(, | and) <ordinal>]... <day_name>
+    private MonthTarget ParseOrdinalWeekdays()
+    {
+        var seen = new HashSet<OrdinalPosition> { ParseOrdinalOrLast() };
+        while (Check(TokenKind.Comma) || Check(TokenKind.And))
+        {
+            _pos++;
+            seen.Add(ParseOrdinalOrLast());
+        }
+        // OrdinalWeekdaysAhead guarantees a day name here
+        var weekday = _tokens[_pos++].DayNameVal!.Value;
+        // First through fifth, then last
+        var ordinals = seen.OrderBy(o => o == OrdinalPosition.Last ? int.MaxValue : (int)o).ToList();
+        if (ordinals.Count == 1)
+        {
+            return MonthTarget.OrdinalWeekday(ordinals[0], weekday);
+        }
+        return MonthTarget.OrdinalWeekdays(ordinals, weekday);
     }
 
-    private IScheduleExpr ParseYearRepeat()
+    private OrdinalPosition ParseOrdinalOrLast()
     {
-        Expect(TokenKind.Year);
-        Expect(TokenKind.On);
+        if (Check(TokenKind.Last))
+        {
+            _pos++;
+            return OrdinalPosition.Last;
+        }
+        return ParseOrdinalPosition();
+    }
 
-        var target = ParseYearTarget();
-        var times = ParseAtTimes();
+    // <ordinal> (weekday | business day)
+    private MonthTarget ParseBusinessDayTarget()
+    {
+        var span = Peek()!.Span;
+        var n = ParseOrdinalDay("expected ordinal before 'business day'");
+        // No month has more than 23 weekdays
+        if (n > 23)
+        {
+            throw ParseError($"invalid business day: {n} (must be 1-23)", span);
+        }
+        if (Check(TokenKind.Business))
+        {
+            _pos++;
+            Expect(TokenKind.Day);
+        }
+        else
+        {
+            Expect(TokenKind.Weekday);
+        }
+        return MonthTarget.BusinessDay(n);
+    }
+
+    // Reports whether the token after the current one starts "business day" or is "weekday".
+    private bool NextIsBusinessDay()
+        => _pos + 1 < _tokens.Count && _tokens[_pos + 1].Kind is TokenKind.Business or TokenKind.Weekday;
 
-        return new YearRepeat(1, target, times);
+    // Reports whether the token after the current one is a day name.
+    private bool NextIsDayName()
+        => _pos + 1 < _tokens.Count && _tokens[_pos + 1].Kind == TokenKind.DayName;
+
+    // iso_week_repeat: "every year in iso week[s] N[, N] on day_list [at HH:MM]"
+    private IScheduleExpr ParseIsoWeekRepeat(int interval)
+    {
+        if (interval != 1)
+        {
+            throw ParseError("ISO week schedules repeat every year; remove the interval", Peek()!.Span);
+        }
+        Expect(TokenKind.In);
+        Expect(TokenKind.Iso);
+        Expect(TokenKind.Weeks);
+
+        var weeks = new List<int> { ParseIsoWeekNumber() };
+        while (Check(TokenKind.Comma))
+        {
+            _pos++;
+            weeks.Add(ParseIsoWeekNumber());
+        }
+
+        Expect(TokenKind.On);
+        var days = ParseDayList();
+        var times = ParseTimesOrMidnight();
+        return new IsoWeekRepeat(weeks, days, times);
+    }
+
+    private int ParseIsoWeekNumber()
+    {
+        var tok = Peek();
+        if (tok is null || tok.Kind != TokenKind.Number)
+        {
+            throw ParseError("expected ISO week number", tok?.Span ?? EndSpan());
+        }
+        var week = tok.NumberVal;
+        if (week < 1 || week > 53)
+        {
+            throw ParseError($"invalid ISO week {week} (expected 1-53)", tok.Span);
+        }
+        _pos++;
+        return week;
     }
 
     private YearTarget ParseYearTarget()
@@ -501,19 +994,19 @@ public sealed class Parser
         }
 
         // "the first/second/... weekday of month"
-        if (tok.Kind == TokenKind.Ordinal)
+        if (tok.Kind == TokenKind.Ordinal && NextIsDayName())
         {
-            var ordinal = _tokens[_pos++].OrdinalVal!.Value;
-            var dayTok = Expect(TokenKind.DayName);
+            var ordinal = ParseOrdinalPosition();
+            var weekday = _tokens[_pos++].DayNameVal!.Value;
             Expect(TokenKind.Of);
             var monthTok = Expect(TokenKind.MonthName);
-            return YearTarget.OrdinalWeekday(ordinal, dayTok.DayNameVal!.Value, monthTok.MonthNameVal!.Value);
+            return YearTarget.OrdinalWeekday(ordinal, weekday, monthTok.MonthNameVal!.Value);
         }
 
-        // "the 15th of month"
-        if (tok.Kind == TokenKind.OrdinalNumber)
+        // "the 15th of month" or "the fifteenth of month"
+        if (tok.Kind == TokenKind.OrdinalNumber || tok.Kind == TokenKind.Ordinal)
         {
-            var day = _tokens[_pos++].NumberVal;
+            var day = ParseOrdinalDay("expected ordinal day number");
             Expect(TokenKind.Of);
             var monthTok = Expect(TokenKind.MonthName);
             ValidateNamedDate(monthTok.MonthNameVal!.Value, day, tok.Span);
@@ -527,8 +1020,19 @@ public sealed class Parser
     {
         Expect(TokenKind.On);
 
+        // "on friday at 17:00": the nearest upcoming friday, a rolling one-shot
+        if (Check(TokenKind.DayName))
+        {
+            var day = _tokens[_pos++].DayNameVal!.Value;
+            if (Check(TokenKind.Comma))
+            {
+                throw ParseError("a weekday cannot be combined with other dates; use 'every' for recurring days", Peek()!.Span);
+            }
+            return new SingleDate(DateSpec.NextWeekday(day), ParseTimes());
+        }
+
         var dateSpec = ParseDateSpec();
-        var times = ParseAtTimes();
+        var times = ParseTimes();
 
         return new SingleDate(dateSpec, times);
     }
@@ -562,12 +1066,50 @@ public sealed class Parser
         return DateSpec.Named(monthTok.MonthNameVal!.Value, dayTok.NumberVal);
     }
 
-    private IReadOnlyList<TimeOfDay> ParseAtTimes()
+    // times: "at HH:MM, ..." or "in the morning|afternoon|evening|night"
+    private IReadOnlyList<TimeOfDay> ParseTimes()
     {
+        if (Check(TokenKind.In))
+        {
+            _pos++;
+            Expect(TokenKind.The);
+            var tok = Peek();
+            var time = tok is null ? null : PeriodTime(tok.Kind);
+            if (time is null)
+            {
+                throw ParseError("expected morning, afternoon, evening, or night", tok?.Span ?? EndSpan());
+            }
+            _pos++;
+            return [time.Value];
+        }
         Expect(TokenKind.At);
         return ParseTimeList();
     }
 
+    // Day and week repeats may omit times: "every day", "every weekday in UTC" fire at
+    // 00:00. A bare "in" starts the timezone clause, not "in the ...".
+    private IReadOnlyList<TimeOfDay> ParseTimesOrMidnight()
+    {
+        var hasTimes = Check(TokenKind.At)
+            || (Check(TokenKind.In) && _pos + 1 < _tokens.Count && _tokens[_pos + 1].Kind == TokenKind.The);
+        if (hasTimes)
+        {
+            return ParseTimes();
+        }
+        return [new TimeOfDay(0, 0)];
+    }
+
+    // Maps input-only "in the <period>" sugar to the fixed time it fires at.
+    private static TimeOfDay? PeriodTime(TokenKind kind) => kind switch
+    {
+        TokenKind.Morning => new TimeOfDay(9, 0),
+        TokenKind.Afternoon => new TimeOfDay(14, 0),
+        TokenKind.Evening => new TimeOfDay(18, 0),
+        TokenKind.Night => new TimeOfDay(21, 0),
+        _ => null
+    };
+
+    // Times are sorted and de-duplicated: "at 17:00, 09:00, 09:00" is "at 09:00, 17:00"
     private IReadOnlyList<TimeOfDay> ParseTimeList()
     {
         var times = new List<TimeOfDay> { ParseTime() };
@@ -578,7 +1120,7 @@ public sealed class Parser
             times.Add(ParseTime());
         }
 
-        return times;
+        return times.Distinct().OrderBy(t => t.TotalMinutes).ToList();
     }
 
     private TimeOfDay ParseTime()
@@ -587,20 +1129,26 @@ public sealed class Parser
         return new TimeOfDay(tok.TimeHour, tok.TimeMinute);
     }
 
+    // "except [on] exception, ...": dates, day names, "weekday", "weekend"
     private IReadOnlyList<ExceptionSpec> ParseExceptions()
     {
-        var exceptions = new List<ExceptionSpec> { ParseExceptionSpec() };
+        if (Check(TokenKind.On))
+        {
+            _pos++;
+        }
+
+        var exceptions = new List<ExceptionSpec>(ParseExceptionSpec());
 
         while (Check(TokenKind.Comma))
         {
             _pos++;
-            exceptions.Add(ParseExceptionSpec());
+            exceptions.AddRange(ParseExceptionSpec());
         }
 
         return exceptions;
     }
 
-    private ExceptionSpec ParseExceptionSpec()
+    private IReadOnlyList<ExceptionSpec> ParseExceptionSpec()
     {
         var tok = Peek();
         if (tok is null)
@@ -608,19 +1156,59 @@ public sealed class Parser
             throw ParseError("unexpected end of input after 'except'", EndSpan());
         }
 
-        if (tok.Kind == TokenKind.IsoDate)
+        switch (tok.Kind)
         {
-            ValidateIsoDate(tok.IsoDateVal!, tok.Span);
-            _pos++;
-            return ExceptionSpec.Iso(tok.IsoDateVal!);
+            case TokenKind.IsoDate:
+                ValidateIsoDate(tok.IsoDateVal!, tok.Span);
+                _pos++;
+                if (!AtRangeSeparator())
+                {
+                    return [ExceptionSpec.Iso(tok.IsoDateVal!)];
+                }
+                _pos++;
+                var end = Peek();
+                if (end is null || end.Kind != TokenKind.IsoDate)
+                {
+                    throw ParseError("expected ISO date after 'to'", end?.Span ?? EndSpan());
+                }
+                ValidateIsoDate(end.IsoDateVal!, end.Span);
+                // ISO dates order lexically
+                if (string.CompareOrdinal(end.IsoDateVal, tok.IsoDateVal) < 0)
+                {
+                    throw ParseError($"exception range ends before {tok.IsoDateVal}", end.Span);
+                }
+                _pos++;
+                return [ExceptionSpec.IsoRange(tok.IsoDateVal!, end.IsoDateVal!)];
+            case TokenKind.DayName:
+                _pos++;
+                return [ExceptionSpec.DayOfWeek(tok.DayNameVal!.Value)];
+            case TokenKind.Weekday:
+                _pos++;
+                return WeekdayExceptions(Weekday.Monday, Weekday.Tuesday, Weekday.Wednesday, Weekday.Thursday, Weekday.Friday);
+            case TokenKind.Weekend:
+                _pos++;
+                return WeekdayExceptions(Weekday.Saturday, Weekday.Sunday);
+            case TokenKind.MonthName:
+                _pos++;
+                var dayTok = ParseDayNumber();
+                ValidateNamedDate(tok.MonthNameVal!.Value, dayTok.NumberVal, dayTok.Span);
+                if (!AtRangeSeparator())
+                {
+                    return [ExceptionSpec.Named(tok.MonthNameVal!.Value, dayTok.NumberVal)];
+                }
+                _pos++;
+                var endMonthTok = Expect(TokenKind.MonthName);
+                var endDayTok = ParseDayNumber();
+                ValidateNamedDate(endMonthTok.MonthNameVal!.Value, endDayTok.NumberVal, endDayTok.Span);
+                return [ExceptionSpec.NamedRange(tok.MonthNameVal!.Value, dayTok.NumberVal, endMonthTok.MonthNameVal!.Value, endDayTok.NumberVal)];
+            default:
+                throw ParseError("expected ISO date, month-day, or day name in exception", tok.Span);
         }
-
-        var monthTok = Expect(TokenKind.MonthName);
-        var dayTok = ParseDayNumber();
-        ValidateNamedDate(monthTok.MonthNameVal!.Value, dayTok.NumberVal, dayTok.Span);
-        return ExceptionSpec.Named(monthTok.MonthNameVal!.Value, dayTok.NumberVal);
     }
 
+    private static IReadOnlyList<ExceptionSpec> WeekdayExceptions(params Weekday[] days)
+        => days.Select(ExceptionSpec.DayOfWeek).ToList();
+
     private UntilSpec ParseUntil()
     {
         var tok = Peek();
@@ -657,24 +1245,72 @@ public sealed class Parser
             return tok.IsoDateVal!;
         }
 
-        throw ParseError("starting only accepts ISO dates", tok.Span);
+        if (tok.Kind is TokenKind.Today or TokenKind.Now)
+        {
+            _pos++;
+            return tok.Kind == TokenKind.Today ? "today" : "now";
+        }
+
+        throw ParseError("starting only accepts ISO dates, month-days, 'today', or 'now'", tok.Span);
     }
 
-    private IReadOnlyList<MonthName> ParseDuring()
+    // Specs are sorted by where they start and de-duplicated: "during mar, jan, mar" is
+    // "during jan, mar". Overlapping specs are not merged.
+    private IReadOnlyList<DuringSpec> ParseDuring()
     {
-        var months = new List<MonthName>();
-
-        var tok = Expect(TokenKind.MonthName);
-        months.Add(tok.MonthNameVal!.Value);
+        var specs = new List<DuringSpec> { ParseDuringSpec() };
 
         while (Check(TokenKind.Comma))
         {
             _pos++;
-            tok = Expect(TokenKind.MonthName);
-            months.Add(tok.MonthNameVal!.Value);
+            specs.Add(ParseDuringSpec());
         }
 
-        return months;
+        return specs.OrderBy(DuringSpecKey).DistinctBy(DuringSpecKey).ToList();
+    }
+
+    /// <summary>
+    /// Ordering key for a during spec: by first month-day, then last month-day. A month and
+    /// the date range covering the same days share a key.
+    /// </summary>
+    private static int DuringSpecKey(DuringSpec spec)
+    {
+        var first = spec.Start.Number() * 100 + spec.FirstDay;
+        var last = spec.End.Number() * 100 + spec.LastDay;
+        return first * 10000 + last;
+    }
+
+    // during_spec: "jun", "jun to aug" (wraps past december: "nov to feb"), or month-day
+    // bounds "jun 15 to aug 31". A bound without a day covers its whole month.
+    private DuringSpec ParseDuringSpec()
+    {
+        var start = Expect(TokenKind.MonthName).MonthNameVal!.Value;
+        var startDay = ParseDuringDay(start);
+        if (!AtRangeSeparator())
+        {
+            // "during jun 15" is that one day
+            return new DuringSpec(start, start, startDay, startDay);
+        }
+        _pos++;
+        var end = Expect(TokenKind.MonthName).MonthNameVal!.Value;
+        var endDay = ParseDuringDay(end);
+        if (startDay == 0 && endDay == 0)
+        {
+            return DuringSpec.Months(start, end);
+        }
+        return new DuringSpec(start, end, startDay == 0 ? 1 : startDay, endDay == 0 ? end.MaxDay() : endDay);
+    }
+
+    // Parses the optional day after a month name in during. Returns 0 when there is no day.
+    private int ParseDuringDay(MonthName month)
+    {
+        if (!Check(TokenKind.Number) && !Check(TokenKind.OrdinalNumber))
+        {
+            return 0;
+        }
+        var dayTok = ParseDayNumber();
+        ValidateNamedDate(month, dayTok.NumberVal, dayTok.Span);
+        return dayTok.NumberVal;
     }
 
     private string ParseTimezone()
@@ -690,11 +1326,9 @@ public sealed class Parser
 
     // Helper methods
 
-    private static readonly int[] MaxDays = [0, 31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
-
     private void ValidateNamedDate(MonthName month, int day, Span span)
     {
-        var maxDay = MaxDays[(int)month];
+        var maxDay = month.MaxDay();
         if (day < 1 || day > maxDay)
         {
             throw ParseError($"invalid day {day} for {month.ToDisplayString()} (max {maxDay})", span);
@@ -75,7 +75,8 @@ public sealed class Schedule
     }
 
     /// <summary>
-    /// Computes the next occurrence after the given time.
+    /// Computes the next occurrence after the given time. A starting date is both the interval
+    /// anchor and a lower bound, so when now is before it the result is on or after it.
     /// </summary>
     /// <param name="now">The reference time</param>
     /// <returns>The next occurrence, or null if none exists</returns>
@@ -114,6 +115,7 @@ public sealed class Schedule
     /// </summary>
     /// <param name="dateTime">The datetime to check</param>
     /// <returns>True if the datetime matches</returns>
+    /// <exception cref="HronException">For <c>starting today</c> / <c>starting now</c> and <c>on &lt;weekday&gt;</c>, which need an evaluation time that Matches does not take</exception>
     public bool Matches(DateTimeOffset dateTime)
     {
         var dtInTz = TimeZoneInfo.ConvertTime(dateTime, _zoneInfo);
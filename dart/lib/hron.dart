@@ -76,6 +76,8 @@ class Schedule {
   /// Returns the next occurrence at or after [now], or `null` if none exists.
   ///
   /// For schedules with an `until` clause, returns `null` after the end date.
+  /// A `starting` date is both the interval anchor and a lower bound, so when
+  /// [now] is before it the result is on or after it.
   TZDateTime? nextFrom(TZDateTime now) => eval_impl.nextFrom(_data, now);
 
   /// Returns the next [n] occurrences starting at or after [now].
@@ -91,6 +93,10 @@ class Schedule {
       eval_impl.previousFrom(_data, now);
 
   /// Returns `true` if [datetime] matches this schedule.
+  ///
+  /// Throws [HronError] for `starting today` / `starting now` and for
+  /// `on <weekday>`, which need an evaluation time that [matches] does not
+  /// take.
   bool matches(TZDateTime datetime) => eval_impl.matches(_data, datetime);
 
   /// Returns a lazy iterable of occurrences starting after [from].
@@ -74,6 +74,10 @@ enum MonthName {
 
   int get number => index + 1;
 
+  /// The month's longest length: February has 29 days.
+  int get maxDay =>
+      const [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31][index];
+
   static MonthName? tryParse(String s) => _monthMap[s.toLowerCase()];
 
   static MonthName fromNumber(int n) => MonthName.values[n - 1];
@@ -112,6 +116,10 @@ enum IntervalUnit {
 
   /// Hours.
   hours,
+
+  /// Days: the window is active on every Nth day (aligned like
+  /// "every N days at ...") and fires once, at the window start.
+  days,
 }
 
 /// Ordinal positions for expressions like "first Monday of every month".
@@ -205,8 +213,24 @@ class DaysTarget extends MonthTarget {
 
 class LastDayTarget extends MonthTarget {}
 
+/// The final N calendar days of each month (`the last 3 days`), N >= 2.
+/// Counts back from the month's real length; N beyond it covers the whole
+/// month.
+class LastNDaysTarget extends MonthTarget {
+  final int n;
+  LastNDaysTarget(this.n);
+}
+
 class LastWeekdayTarget extends MonthTarget {}
 
+/// The Nth monday-to-friday day of the month (`the first business day`,
+/// `the 3rd weekday`), N in 1-23. Excepted dates are not business days, so an
+/// `except` landing on it moves the occurrence to the next one.
+class BusinessDayTarget extends MonthTarget {
+  final int n;
+  BusinessDayTarget(this.n);
+}
+
 /// Nearest weekday to a given day of month.
 /// Standard (direction=null): never crosses month boundary (cron W compatibility).
 /// Directional (direction!=null): can cross month boundary.
@@ -223,6 +247,15 @@ class OrdinalWeekdayMonthTarget extends MonthTarget {
   OrdinalWeekdayMonthTarget(this.ordinal, this.weekday);
 }
 
+/// Several ordinal weekdays of the month (`the first and last friday`).
+/// Ordinals are sorted and distinct; a single ordinal is an
+/// [OrdinalWeekdayMonthTarget].
+class OrdinalWeekdaysMonthTarget extends MonthTarget {
+  final List<OrdinalPosition> ordinals;
+  final Weekday weekday;
+  OrdinalWeekdaysMonthTarget(this.ordinals, this.weekday);
+}
+
 /// Target specification for yearly schedules.
 sealed class YearTarget {}
 
@@ -264,6 +297,13 @@ class IsoDate extends DateSpec {
   IsoDate(this.date);
 }
 
+/// `on friday`: the nearest upcoming day with that weekday, resolved against
+/// the evaluation time rather than a fixed date.
+class NextWeekdayDate extends DateSpec {
+  final Weekday day;
+  NextWeekdayDate(this.day);
+}
+
 /// A date to exclude from a schedule (used in `except` clauses).
 sealed class ExceptionSpec {}
 
@@ -278,6 +318,34 @@ class IsoException extends ExceptionSpec {
   IsoException(this.date);
 }
 
+/// Excludes every occurrence of a day of the week.
+class WeekdayException extends ExceptionSpec {
+  final Weekday day;
+  WeekdayException(this.day);
+}
+
+/// One-off inclusive ISO date range: `2026-12-24 to 2026-12-31`.
+class IsoRangeException extends ExceptionSpec {
+  final String start;
+  final String end;
+  IsoRangeException(this.start, this.end);
+}
+
+/// Recurring inclusive range of named dates: `dec 24 to jan 2` matches every
+/// year, wrapping past december when the end comes first.
+class NamedRangeException extends ExceptionSpec {
+  final MonthName startMonth;
+  final int startDay;
+  final MonthName endMonth;
+  final int endDay;
+  NamedRangeException(
+    this.startMonth,
+    this.startDay,
+    this.endMonth,
+    this.endDay,
+  );
+}
+
 /// End date for a schedule (used in `until` clauses).
 sealed class UntilSpec {}
 
@@ -301,6 +369,8 @@ class NamedUntil extends UntilSpec {
 /// - [MonthRepeat]: "every month on the 1st at 9am"
 /// - [SingleDate]: "on Jan 1 at 12:00"
 /// - [YearRepeat]: "every year on Jan 1 at 12:00"
+/// - [IsoWeekRepeat]: "every year in iso week 1 on monday at 9am"
+/// - [WeekdayRepeat]: "every 3 weekdays at 9am"
 sealed class ScheduleExpr {}
 
 /// Schedule repeating at a time interval (e.g., "every 30 min from 9am to 5pm").
@@ -352,6 +422,39 @@ class YearRepeat extends ScheduleExpr {
   YearRepeat(this.interval, this.target, this.times);
 }
 
+/// Schedule on days in ISO 8601 week numbers 1-53 of every year
+/// (e.g., "every year in iso weeks 1, 27 on monday at 9am").
+class IsoWeekRepeat extends ScheduleExpr {
+  final List<int> weeks;
+  final List<Weekday> days;
+  final List<TimeOfDay> times;
+  IsoWeekRepeat(this.weeks, this.days, this.times);
+}
+
+/// Schedule on every Nth business day (e.g., "every 3 weekdays at 09:00").
+/// Only Monday through Friday count toward the interval, so weekends neither
+/// fire nor advance it. Days are counted from the `starting` anchor (or its
+/// next weekday), defaulting to 1970-01-01.
+class WeekdayRepeat extends ScheduleExpr {
+  final int interval;
+  final List<TimeOfDay> times;
+  WeekdayRepeat(this.interval, this.times);
+}
+
+/// A single month, inclusive month range, or inclusive month-day range in a
+/// `during` clause.
+///
+/// Wraps past December when start > end: `nov to feb` is nov, dec, jan, feb.
+/// Month forms leave the days null; an end of feb 29 means the last day of
+/// February in common years.
+class DuringSpec {
+  final MonthName start;
+  final MonthName end;
+  final int? startDay;
+  final int? endDay;
+  DuringSpec(this.start, this.end, [this.startDay, this.endDay]);
+}
+
 /// The complete parsed schedule with expression and modifiers.
 ///
 /// Contains the main [expr] plus optional clauses like timezone,
@@ -361,12 +464,35 @@ class ScheduleData {
   String? timezone;
   List<ExceptionSpec> except;
   UntilSpec? until;
+
+  /// An ISO date, or one of [relativeAnchors].
   String? anchor;
-  List<MonthName> during;
+
+  /// `starting jan 6`: resolved to the next such date at evaluation.
+  NamedDate? anchorNamed;
+
+  /// `starting ... at <time>`: nothing occurs before it on the anchor date.
+  TimeOfDay? anchorTime;
+  /// `during` as written: months, month ranges, and date ranges.
+  List<DuringSpec> during;
 
   ScheduleData(this.expr) : except = [], during = [];
+
+  /// Whether the anchor is resolved against the evaluation time: one of
+  /// [relativeAnchors], or a month-day.
+  bool get hasRelativeAnchor =>
+      anchorNamed != null || relativeAnchors.contains(anchor);
+
+  /// The starting anchor as written, without its time.
+  String? get anchorText {
+    final named = anchorNamed;
+    return named != null ? '${named.month.name} ${named.day}' : anchor;
+  }
 }
 
+/// `starting` words that resolve against the evaluation instant.
+const relativeAnchors = ['today', 'now'];
+
 // --- Helper functions ---
 
 List<int> expandDaySpec(DayOfMonthSpec spec) {
@@ -375,6 +501,62 @@ List<int> expandDaySpec(DayOfMonthSpec spec) {
   return [for (var d = range.start; d <= range.end; d++) d];
 }
 
+/// The first and last month-day a during spec covers. Whole months end on
+/// their longest day (feb 29).
+({int startDay, int endDay}) duringBounds(DuringSpec spec) =>
+    (startDay: spec.startDay ?? 1, endDay: spec.endDay ?? spec.end.maxDay);
+
+/// Whether the month-day falls within a during spec.
+bool duringContains(DuringSpec spec, MonthName month, int day) {
+  final (:startDay, :endDay) = duringBounds(spec);
+  final key = month.number * 100 + day;
+  final start = spec.start.number * 100 + startDay;
+  final end = spec.end.number * 100 + endDay;
+  if (start <= end) {
+    return start <= key && key <= end;
+  }
+  return key >= start || key <= end;
+}
+
+/// Whether a during spec covers whole months: `jun 1 to aug 31` is
+/// `jun to aug`.
+bool duringWholeMonths(DuringSpec spec) {
+  final (:startDay, :endDay) = duringBounds(spec);
+  return startDay == 1 && endDay == spec.end.maxDay;
+}
+
+/// The months a during spec touches, in calendar order from the start.
+List<MonthName> expandDuringSpec(DuringSpec spec) {
+  final (:startDay, :endDay) = duringBounds(spec);
+  final start = spec.start.number;
+  // `jun 15 to jun 10` wraps through every month
+  final length = spec.start == spec.end && startDay > endDay
+      ? 12
+      : (spec.end.number - start) % 12 + 1;
+  return [
+    for (var i = 0; i < length; i++)
+      MonthName.fromNumber((start - 1 + i) % 12 + 1),
+  ];
+}
+
+/// Months any `during` spec touches, for month-level skipping.
+List<MonthName> duringMonths(ScheduleData schedule) =>
+    schedule.during.expand(expandDuringSpec).toList();
+
+/// Sort and de-duplicate an explicit day list, collapsing one covering exactly
+/// the weekdays or all seven days into the equivalent keyword filter.
+/// `saturday, sunday` stays a list: `weekend` is a keyword of its own.
+DayFilter canonicalDayFilter(DayFilter filter) {
+  if (filter is! SpecificDays) return filter;
+  final days = filter.days.toSet().toList()
+    ..sort((a, b) => a.number.compareTo(b.number));
+  if (days.length == 7) return EveryDay();
+  if (days.length == 5 && days.every((d) => d.number <= 5)) {
+    return WeekdayFilter();
+  }
+  return SpecificDays(days);
+}
+
 List<int> expandMonthTarget(MonthTarget target) {
   if (target is DaysTarget) {
     return target.specs.expand(expandDaySpec).toList();
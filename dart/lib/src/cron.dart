@@ -12,11 +12,12 @@ String toCron(ScheduleData schedule) {
       'not expressible as cron (until clauses not supported)',
     );
   }
-  if (schedule.during.isNotEmpty) {
+  if (!schedule.during.every(duringWholeMonths)) {
     throw HronError.cron(
-      'not expressible as cron (during clauses not supported)',
+      'not expressible as cron (during dates narrower than whole months)',
     );
   }
+  final month = _monthField(duringMonths(schedule));
 
   final expr = schedule.expr;
 
@@ -31,14 +32,14 @@ String toCron(ScheduleData schedule) {
           'not expressible as cron (multi-day intervals not supported)',
         );
       }
-      if (times.length != 1) {
+      final fields = _timeFields(times);
+      if (fields == null) {
         throw HronError.cron(
           'not expressible as cron (multiple times not supported)',
         );
       }
-      final time = times[0];
       final dow = _dayFilterToCronDow(days);
-      return '${time.minute} ${time.hour} * * $dow';
+      return '${fields.minute} ${fields.hour} * $month $dow';
 
     case IntervalRepeat(
       interval: final interval,
@@ -47,11 +48,16 @@ String toCron(ScheduleData schedule) {
       to: final to,
       dayFilter: final dayFilter,
     ):
+      if (unit == IntervalUnit.days) {
+        throw HronError.cron(
+          'not expressible as cron (multi-day interval windows not supported)',
+        );
+      }
+      // Only expressible if window is full day (00:00 to 23:59). Hour steps
+      // may start past the hour: `from 00:15` fires at :15.
+      final offsetOk = from.minute == 0 || unit == IntervalUnit.hours;
       final fullDay =
-          from.hour == 0 &&
-          from.minute == 0 &&
-          to.hour == 23 &&
-          to.minute == 59;
+          from.hour == 0 && offsetOk && to.hour == 23 && to.minute == 59;
       if (!fullDay) {
         throw HronError.cron(
           'not expressible as cron (partial-day interval windows not supported)',
@@ -68,9 +74,12 @@ String toCron(ScheduleData schedule) {
             'not expressible as cron (*/$interval breaks at hour boundaries)',
           );
         }
-        return '*/$interval * * * *';
+        return '*/$interval * * $month *';
+      }
+      if (interval == 1 && from.minute != 0) {
+        return '${from.minute} * * $month *';
       }
-      return '0 */$interval * * *';
+      return '${from.minute} */$interval * $month *';
 
     case WeekRepeat():
       throw HronError.cron(
@@ -87,12 +96,12 @@ String toCron(ScheduleData schedule) {
           'not expressible as cron (multi-month intervals not supported)',
         );
       }
-      if (times.length != 1) {
+      final fields = _timeFields(times);
+      if (fields == null) {
         throw HronError.cron(
           'not expressible as cron (multiple times not supported)',
         );
       }
-      final time = times[0];
       if (target is DaysTarget) {
         final expanded = target.specs.expand((s) {
           if (s is SingleDay) return [s.day];
@@ -100,20 +109,37 @@ String toCron(ScheduleData schedule) {
           return [for (var d = r.start; d <= r.end; d++) d];
         }).toList();
         final dom = expanded.join(',');
-        return '${time.minute} ${time.hour} $dom * *';
+        return '${fields.minute} ${fields.hour} $dom $month *';
       }
       if (target is LastDayTarget) {
         throw HronError.cron(
           'not expressible as cron (last day of month not supported)',
         );
       }
+      if (target is LastNDaysTarget) {
+        throw HronError.cron(
+          'not expressible as cron (last days of month not supported)',
+        );
+      }
+      // The first business day is always the weekday nearest the 1st
+      if (target is BusinessDayTarget) {
+        if (target.n == 1) return '${fields.minute} ${fields.hour} 1W $month *';
+        throw HronError.cron(
+          'not expressible as cron (nth business day of month not supported)',
+        );
+      }
       if (target is NearestWeekdayTarget) {
         if (target.direction != null) {
           throw HronError.cron(
             'not expressible as cron (directional nearest weekday not supported)',
           );
         }
-        return '${time.minute} ${time.hour} ${target.day}W * *';
+        return '${fields.minute} ${fields.hour} ${target.day}W $month *';
+      }
+      if (target is OrdinalWeekdaysMonthTarget) {
+        throw HronError.cron(
+          'not expressible as cron (ordinal weekdays of month not supported)',
+        );
       }
       if (target is OrdinalWeekdayMonthTarget) {
         throw HronError.cron(
@@ -133,9 +159,55 @@ String toCron(ScheduleData schedule) {
       throw HronError.cron(
         'not expressible as cron (yearly schedules not supported in 5-field cron)',
       );
+
+    case WeekdayRepeat():
+      throw HronError.cron(
+        'not expressible as cron (business-day intervals not supported)',
+      );
+
+    case IsoWeekRepeat():
+      throw HronError.cron(
+        'not expressible as cron (cron has no week-of-year field)',
+      );
   }
 }
 
+/// Exact minute and hour fields for a list of times: `0 9,17`, `0,30 9`, or
+/// `0,30 9,17` when the times are every listed minute at every listed hour.
+/// `null` when comma fields would fire at times not in the list.
+({String minute, String hour})? _timeFields(List<TimeOfDay> times) {
+  final distinct = {for (final t in times) t.hour * 60 + t.minute};
+  final minutes = {for (final t in times) t.minute}.toList()..sort();
+  final hours = {for (final t in times) t.hour}.toList()..sort();
+  // Distinct times are a subset of the grid, so equal counts mean equal sets
+  if (minutes.length * hours.length != distinct.length) return null;
+  return (minute: minutes.join(','), hour: hours.join(','));
+}
+
+/// The cron month field for a `during` clause. Runs of three or more
+/// consecutive months collapse into a range.
+String _monthField(List<MonthName> during) {
+  final months = during.map((m) => m.number).toSet().toList()..sort();
+  if (months.isEmpty) return '*';
+  final parts = <String>[];
+  var i = 0;
+  while (i < months.length) {
+    var j = i;
+    while (j + 1 < months.length && months[j + 1] == months[j] + 1) {
+      j++;
+    }
+    if (j - i >= 2) {
+      parts.add('${months[i]}-${months[j]}');
+    } else {
+      for (var k = i; k <= j; k++) {
+        parts.add('${months[k]}');
+      }
+    }
+    i = j + 1;
+  }
+  return parts.join(',');
+}
+
 String _dayFilterToCronDow(DayFilter filter) {
   return switch (filter) {
     EveryDay() => '*',
@@ -158,7 +230,7 @@ ScheduleData fromCron(String cron) {
 
   // Handle @ shortcuts first
   if (trimmed.startsWith('@')) {
-    return _parseCronShortcut(trimmed);
+    return parseCronShortcut(trimmed);
   }
 
   final fields = trimmed.split(RegExp(r'\s+'));
@@ -222,61 +294,57 @@ ScheduleData fromCron(String cron) {
   if (intervalResult != null) return intervalResult;
 
   // Standard time-based cron
-  final minute = _parseSingleValue(minuteField, 'minute', 0, 59);
-  final hour = _parseSingleValue(hourField, 'hour', 0, 23);
-  final time = TimeOfDay(hour, minute);
+  final times = _parseCronTimes(minuteField, hourField);
 
   // DOM-based (monthly) - when DOM is specified and DOW is *
   if (domField != '*' && dowField == '*') {
     final target = _parseDomField(domField);
-    final schedule = ScheduleData(MonthRepeat(1, target, [time]));
+    final schedule = ScheduleData(MonthRepeat(1, target, times));
     schedule.during = during;
     return schedule;
   }
 
   // DOW-based (day repeat)
   final days = _parseCronDow(dowField);
-  final schedule = ScheduleData(DayRepeat(1, days, [time]));
+  final schedule = ScheduleData(DayRepeat(1, days, times));
   schedule.during = during;
   return schedule;
 }
 
 /// Parse @ shortcuts like @daily, @hourly, etc.
-ScheduleData _parseCronShortcut(String cron) {
-  switch (cron.toLowerCase()) {
-    case '@yearly':
-    case '@annually':
-      return ScheduleData(
-        YearRepeat(1, DateTarget(MonthName.jan, 1), [const TimeOfDay(0, 0)]),
-      );
-    case '@monthly':
-      return ScheduleData(
-        MonthRepeat(1, DaysTarget([SingleDay(1)]), [const TimeOfDay(0, 0)]),
-      );
-    case '@weekly':
-      return ScheduleData(
-        DayRepeat(1, SpecificDays([Weekday.sunday]), [const TimeOfDay(0, 0)]),
-      );
-    case '@daily':
-    case '@midnight':
-      return ScheduleData(DayRepeat(1, EveryDay(), [const TimeOfDay(0, 0)]));
-    case '@hourly':
-      return ScheduleData(
-        IntervalRepeat(
-          1,
-          IntervalUnit.hours,
-          const TimeOfDay(0, 0),
-          const TimeOfDay(23, 59),
-          null,
-        ),
-      );
-    default:
-      throw HronError.cron('unknown @ shortcut: $cron');
+ScheduleData parseCronShortcut(String cron) {
+  final expr = cronShortcutExpr(cron.toLowerCase());
+  if (expr == null) {
+    throw HronError.cron('unknown @ shortcut: $cron');
   }
+  return ScheduleData(expr);
+}
+
+/// The expression a lowercase @ shortcut stands for, if it is one.
+ScheduleExpr? cronShortcutExpr(String shortcut) {
+  const midnight = [TimeOfDay(0, 0)];
+  return switch (shortcut) {
+    '@yearly' || '@annually' => YearRepeat(
+      1,
+      DateTarget(MonthName.jan, 1),
+      midnight,
+    ),
+    '@monthly' => MonthRepeat(1, DaysTarget([SingleDay(1)]), midnight),
+    '@weekly' => DayRepeat(1, SpecificDays([Weekday.sunday]), midnight),
+    '@daily' || '@midnight' => DayRepeat(1, EveryDay(), midnight),
+    '@hourly' => IntervalRepeat(
+      1,
+      IntervalUnit.hours,
+      const TimeOfDay(0, 0),
+      const TimeOfDay(23, 59),
+      null,
+    ),
+    _ => null,
+  };
 }
 
-/// Parse month field into a `List<MonthName>` for the `during` clause.
-List<MonthName> _parseMonthField(String field) {
+/// Parse month field into `during` specs, one per month.
+List<DuringSpec> _parseMonthField(String field) {
   if (field == '*') return [];
 
   final months = <MonthName>[];
@@ -331,7 +399,7 @@ List<MonthName> _parseMonthField(String field) {
     }
   }
 
-  return months;
+  return [for (final m in months) DuringSpec(m, m)];
 }
 
 /// Parse a single month value (number 1-12 or name JAN-DEC).
@@ -376,7 +444,7 @@ ScheduleData? _tryParseNthWeekday(
   String hourField,
   String domField,
   String dowField,
-  List<MonthName> during,
+  List<DuringSpec> during,
 ) {
   // Check for # pattern (nth weekday of month)
   if (dowField.contains('#')) {
@@ -406,13 +474,10 @@ ScheduleData? _tryParseNthWeekday(
       throw HronError.cron('DOM must be * when using # for nth weekday');
     }
 
-    final minute = _parseSingleValue(minuteField, 'minute', 0, 59);
-    final hour = _parseSingleValue(hourField, 'hour', 0, 23);
+    final times = _parseCronTimes(minuteField, hourField);
 
     final schedule = ScheduleData(
-      MonthRepeat(1, OrdinalWeekdayMonthTarget(ordinal, weekday), [
-        TimeOfDay(hour, minute),
-      ]),
+      MonthRepeat(1, OrdinalWeekdayMonthTarget(ordinal, weekday), times),
     );
     schedule.during = during;
     return schedule;
@@ -428,13 +493,14 @@ ScheduleData? _tryParseNthWeekday(
       throw HronError.cron('DOM must be * when using nL for last weekday');
     }
 
-    final minute = _parseSingleValue(minuteField, 'minute', 0, 59);
-    final hour = _parseSingleValue(hourField, 'hour', 0, 23);
+    final times = _parseCronTimes(minuteField, hourField);
 
     final schedule = ScheduleData(
-      MonthRepeat(1, OrdinalWeekdayMonthTarget(OrdinalPosition.last, weekday), [
-        TimeOfDay(hour, minute),
-      ]),
+      MonthRepeat(
+        1,
+        OrdinalWeekdayMonthTarget(OrdinalPosition.last, weekday),
+        times,
+      ),
     );
     schedule.during = during;
     return schedule;
@@ -449,7 +515,7 @@ ScheduleData? _tryParseLastDay(
   String hourField,
   String domField,
   String dowField,
-  List<MonthName> during,
+  List<DuringSpec> during,
 ) {
   if (domField != 'L' && domField != 'LW') {
     return null;
@@ -459,14 +525,11 @@ ScheduleData? _tryParseLastDay(
     throw HronError.cron('DOW must be * when using L or LW in DOM');
   }
 
-  final minute = _parseSingleValue(minuteField, 'minute', 0, 59);
-  final hour = _parseSingleValue(hourField, 'hour', 0, 23);
+  final times = _parseCronTimes(minuteField, hourField);
 
   final target = domField == 'LW' ? LastWeekdayTarget() : LastDayTarget();
 
-  final schedule = ScheduleData(
-    MonthRepeat(1, target, [TimeOfDay(hour, minute)]),
-  );
+  final schedule = ScheduleData(MonthRepeat(1, target, times));
   schedule.during = during;
   return schedule;
 }
@@ -477,7 +540,7 @@ ScheduleData? _tryParseNearestWeekday(
   String hourField,
   String domField,
   String dowField,
-  List<MonthName> during,
+  List<DuringSpec> during,
 ) {
   if (!domField.endsWith('W') || domField == 'LW') {
     return null;
@@ -496,14 +559,11 @@ ScheduleData? _tryParseNearestWeekday(
     throw HronError.cron('W day must be 1-31, got $day');
   }
 
-  final minute = _parseSingleValue(minuteField, 'minute', 0, 59);
-  final hour = _parseSingleValue(hourField, 'hour', 0, 23);
+  final times = _parseCronTimes(minuteField, hourField);
 
   final target = NearestWeekdayTarget(day);
 
-  final schedule = ScheduleData(
-    MonthRepeat(1, target, [TimeOfDay(hour, minute)]),
-  );
+  final schedule = ScheduleData(MonthRepeat(1, target, times));
   schedule.during = during;
   return schedule;
 }
@@ -514,7 +574,7 @@ ScheduleData? _tryParseInterval(
   String hourField,
   String domField,
   String dowField,
-  List<MonthName> during,
+  List<DuringSpec> during,
 ) {
   // Minute interval: */N or range/N
   if (minuteField.contains('/')) {
@@ -615,11 +675,18 @@ ScheduleData? _tryParseInterval(
     }
   }
 
-  // Hour interval: 0 */N or 0 range/N
-  if (hourField.contains('/') && (minuteField == '0' || minuteField == '00')) {
+  // Hour interval: M */N, M range/N, or M * (every hour at minute M)
+  final minute = RegExp(r'^\d+$').hasMatch(minuteField)
+      ? int.parse(minuteField)
+      : null;
+  if (minute != null &&
+      minute <= 59 &&
+      (hourField.contains('/') || hourField == '*')) {
     final splitIdx = hourField.indexOf('/');
-    final rangePart = hourField.substring(0, splitIdx);
-    final stepStr = hourField.substring(splitIdx + 1);
+    final rangePart = splitIdx < 0
+        ? hourField
+        : hourField.substring(0, splitIdx);
+    final stepStr = splitIdx < 0 ? '1' : hourField.substring(splitIdx + 1);
 
     final interval = int.tryParse(stepStr);
     if (interval == null) {
@@ -656,14 +723,15 @@ ScheduleData? _tryParseInterval(
 
     if ((domField == '*' || domField == '?') &&
         (dowField == '*' || dowField == '?')) {
-      // Use :59 only for full day (00:00 to 23:59), otherwise use :00
-      final endMinute = (fromHour == 0 && toHour == 23) ? 59 : 0;
+      // Use :59 only for full day (00:00 to 23:59), otherwise end on the
+      // last firing
+      final endMinute = (fromHour == 0 && toHour == 23) ? 59 : minute;
 
       final schedule = ScheduleData(
         IntervalRepeat(
           interval,
           IntervalUnit.hours,
-          TimeOfDay(fromHour, 0),
+          TimeOfDay(fromHour, minute),
           TimeOfDay(toHour, endMinute),
           null,
         ),
@@ -837,29 +905,13 @@ DayFilter _parseCronDow(String field) {
     }
   }
 
-  // Check for special patterns
-  if (days.length == 5) {
-    final sorted = List<Weekday>.from(days)
-      ..sort((a, b) => a.number.compareTo(b.number));
-    if (_listEquals(sorted, [
-      Weekday.monday,
-      Weekday.tuesday,
-      Weekday.wednesday,
-      Weekday.thursday,
-      Weekday.friday,
-    ])) {
-      return WeekdayFilter();
-    }
+  // Cron has no weekend keyword, so 0,6 reads as the weekend
+  final canonical = canonicalDayFilter(SpecificDays(days));
+  if (canonical is SpecificDays &&
+      _listEquals(canonical.days, [Weekday.saturday, Weekday.sunday])) {
+    return WeekendFilter();
   }
-  if (days.length == 2) {
-    final sorted = List<Weekday>.from(days)
-      ..sort((a, b) => a.number.compareTo(b.number));
-    if (_listEquals(sorted, [Weekday.saturday, Weekday.sunday])) {
-      return WeekendFilter();
-    }
-  }
-
-  return SpecificDays(days);
+  return canonical;
 }
 
 bool _listEquals<T>(List<T> a, List<T> b) {
@@ -900,6 +952,22 @@ int _parseDowValueRaw(String s) {
   };
 }
 
+/// Parse the minute and hour fields into times. Either field may be a comma
+/// list; every hour pairs with every minute, so `0,30 9,17` gives four times.
+List<TimeOfDay> _parseCronTimes(String minuteField, String hourField) {
+  final minutes = _parseValueList(minuteField, 'minute', 0, 59);
+  final hours = _parseValueList(hourField, 'hour', 0, 23);
+  final keys = {
+    for (final h in hours)
+      for (final m in minutes) h * 60 + m,
+  }.toList()..sort();
+  return [for (final k in keys) TimeOfDay(k ~/ 60, k % 60)];
+}
+
+/// Parse a comma list of numeric values, each validated like a single value.
+List<int> _parseValueList(String field, String name, int min, int max) =>
+    field.split(',').map((v) => _parseSingleValue(v, name, min, max)).toList();
+
 /// Parse a single numeric value with validation.
 int _parseSingleValue(String field, String name, int min, int max) {
   final value = int.tryParse(field);
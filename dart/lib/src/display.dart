@@ -8,10 +8,16 @@ String display(ScheduleData schedule) {
     buf.write(' except ');
     buf.write(
       schedule.except
-          .map((exc) {
-            if (exc is NamedException) return '${exc.month.name} ${exc.day}';
-            return (exc as IsoException).date;
-          })
+          .map(
+            (exc) => switch (exc) {
+              NamedException() => '${exc.month.name} ${exc.day}',
+              IsoException() => exc.date,
+              WeekdayException() => exc.day.name,
+              IsoRangeException() => '${exc.start} to ${exc.end}',
+              NamedRangeException() =>
+                '${exc.startMonth.name} ${exc.startDay} to ${exc.endMonth.name} ${exc.endDay}',
+            },
+          )
           .join(', '),
     );
   }
@@ -26,13 +32,16 @@ String display(ScheduleData schedule) {
     }
   }
 
-  if (schedule.anchor != null) {
-    buf.write(' starting ${schedule.anchor}');
+  if (schedule.anchorText != null) {
+    buf.write(' starting ${schedule.anchorText}');
+  }
+  if (schedule.anchorTime != null) {
+    buf.write(' at ${schedule.anchorTime}');
   }
 
   if (schedule.during.isNotEmpty) {
     buf.write(' during ');
-    buf.write(schedule.during.map((m) => m.name).join(', '));
+    buf.write(schedule.during.map(_displayDuringSpec).join(', '));
   }
 
   if (schedule.timezone != null) {
@@ -42,6 +51,20 @@ String display(ScheduleData schedule) {
   return buf.toString();
 }
 
+String _displayDuringSpec(DuringSpec spec) {
+  final start = spec.start.name;
+  final end = spec.end.name;
+  final (:startDay, :endDay) = duringBounds(spec);
+  // Date ranges covering whole months print as months
+  if (duringWholeMonths(spec)) {
+    return spec.start == spec.end ? start : '$start to $end';
+  }
+  if (spec.start == spec.end && startDay == endDay) {
+    return '$start $startDay';
+  }
+  return '$start $startDay to $end $endDay';
+}
+
 String _displayExpr(ScheduleExpr expr) => switch (expr) {
   IntervalRepeat() => _displayInterval(expr),
   DayRepeat() => _displayDayRepeat(expr),
@@ -52,6 +75,10 @@ String _displayExpr(ScheduleExpr expr) => switch (expr) {
   MonthRepeat() => _displayMonthRepeat(expr),
   SingleDate() => _displaySingleDate(expr),
   YearRepeat() => _displayYearRepeat(expr),
+  WeekdayRepeat() =>
+    'every ${expr.interval} weekdays at ${_formatTimeList(expr.times)}',
+  IsoWeekRepeat() =>
+    'every year in iso weeks ${expr.weeks.join(', ')} on ${_formatDayList(expr.days)} at ${_formatTimeList(expr.times)}',
 };
 
 String _displayInterval(IntervalRepeat expr) {
@@ -65,7 +92,10 @@ String _displayInterval(IntervalRepeat expr) {
 
 String _displayDayRepeat(DayRepeat expr) {
   if (expr.interval > 1) {
-    return 'every ${expr.interval} days at ${_formatTimeList(expr.times)}';
+    final on = expr.days is EveryDay
+        ? ''
+        : 'on ${_displayDayFilter(expr.days)} ';
+    return 'every ${expr.interval} days ${on}at ${_formatTimeList(expr.times)}';
   }
   return 'every ${_displayDayFilter(expr.days)} at ${_formatTimeList(expr.times)}';
 }
@@ -77,6 +107,10 @@ String _displayMonthRepeat(MonthRepeat expr) {
     targetStr = _formatOrdinalDaySpecs(target.specs);
   } else if (target is LastDayTarget) {
     targetStr = 'last day';
+  } else if (target is LastNDaysTarget) {
+    targetStr = 'last ${target.n} days';
+  } else if (target is BusinessDayTarget) {
+    targetStr = '${target.n}${ordinalSuffix(target.n)} business day';
   } else if (target is LastWeekdayTarget) {
     targetStr = 'last weekday';
   } else if (target is NearestWeekdayTarget) {
@@ -89,6 +123,10 @@ String _displayMonthRepeat(MonthRepeat expr) {
         '${dirPrefix}nearest weekday to ${target.day}${ordinalSuffix(target.day)}';
   } else if (target is OrdinalWeekdayMonthTarget) {
     targetStr = '${target.ordinal.name} ${target.weekday.name}';
+  } else if (target is OrdinalWeekdaysMonthTarget) {
+    final ordinals = target.ordinals.map((o) => o.name).toList();
+    final rest = ordinals.sublist(0, ordinals.length - 1).join(', ');
+    targetStr = '$rest and ${ordinals.last} ${target.weekday.name}';
   } else {
     throw ArgumentError('unknown month target: ${target.runtimeType}');
   }
@@ -99,13 +137,11 @@ String _displayMonthRepeat(MonthRepeat expr) {
 }
 
 String _displaySingleDate(SingleDate expr) {
-  String dateStr;
-  final date = expr.date;
-  if (date is NamedDate) {
-    dateStr = '${date.month.name} ${date.day}';
-  } else {
-    dateStr = (date as IsoDate).date;
-  }
+  final dateStr = switch (expr.date) {
+    NamedDate(month: final month, day: final day) => '${month.name} $day',
+    IsoDate(date: final date) => date,
+    NextWeekdayDate(day: final day) => day.name,
+  };
   return 'on $dateStr at ${_formatTimeList(expr.times)}';
 }
 
@@ -135,7 +171,10 @@ String _displayDayFilter(DayFilter filter) => switch (filter) {
   EveryDay() => 'day',
   WeekdayFilter() => 'weekday',
   WeekendFilter() => 'weekend',
-  SpecificDays() => _formatDayList(filter.days),
+  SpecificDays() => switch (canonicalDayFilter(filter)) {
+    SpecificDays(days: final days) => _formatDayList(days),
+    final canonical => _displayDayFilter(canonical),
+  },
 };
 
 String _formatTimeList(List<TimeOfDay> times) =>
@@ -157,5 +196,8 @@ String _unitDisplay(int interval, IntervalUnit unit) {
   if (unit == IntervalUnit.min) {
     return interval == 1 ? 'minute' : 'min';
   }
+  if (unit == IntervalUnit.days) {
+    return interval == 1 ? 'day' : 'days';
+  }
   return interval == 1 ? 'hour' : 'hours';
 }
@@ -1,6 +1,9 @@
+import 'dart:math';
+
 import 'package:timezone/timezone.dart';
 
 import 'ast.dart';
+import 'error.dart';
 
 // =============================================================================
 // Iteration Safety Limits
@@ -104,6 +107,34 @@ DateTime _lastDayOfMonth(int year, int month) {
   return DateTime.utc(year, month + 1, 0);
 }
 
+/// The final [n] days of the month, ascending; the whole month if shorter.
+List<DateTime> _lastNDaysOfMonth(int year, int month, int n) {
+  final last = _lastDayOfMonth(year, month);
+  return [
+    for (var day = max(last.day - n + 1, 1); day <= last.day; day++)
+      DateTime.utc(year, month, day),
+  ];
+}
+
+/// The nth business day (Mon-Fri, 1-indexed) of a month, not counting excepted
+/// days. `null` if the month has fewer.
+DateTime? _nthBusinessDay(
+  int year,
+  int month,
+  int n,
+  List<ExceptionSpec> exceptions,
+) {
+  final last = _lastDayOfMonth(year, month);
+  var count = 0;
+  for (var day = 1; day <= last.day; day++) {
+    final d = DateTime.utc(year, month, day);
+    if (d.weekday > 5 || _isExcepted(d, exceptions)) continue;
+    count++;
+    if (count == n) return d;
+  }
+  return null;
+}
+
 DateTime _lastWeekdayOfMonth(int year, int month) {
   var d = _lastDayOfMonth(year, month);
   while (d.weekday == 6 || d.weekday == 7) {
@@ -112,6 +143,28 @@ DateTime _lastWeekdayOfMonth(int year, int month) {
   return d;
 }
 
+DateTime? _ordinalWeekday(
+  int year,
+  int month,
+  OrdinalPosition ordinal,
+  Weekday weekday,
+) {
+  if (ordinal == OrdinalPosition.last) {
+    return _lastWeekdayInMonth(year, month, weekday);
+  }
+  return _nthWeekdayOfMonth(year, month, weekday, ordinal.toN);
+}
+
+/// The distinct dates of several ordinal weekdays in a month.
+List<DateTime> _ordinalWeekdayDates(
+  int year,
+  int month,
+  List<OrdinalPosition> ordinals,
+  Weekday weekday,
+) => {
+  for (final o in ordinals) ?_ordinalWeekday(year, month, o, weekday),
+}.toList();
+
 DateTime? _nthWeekdayOfMonth(int year, int month, Weekday weekday, int n) {
   final targetDow = weekday.number;
   var d = DateTime.utc(year, month, 1);
@@ -202,6 +255,26 @@ final DateTime _epochDate = DateTime.utc(1970, 1, 1);
 
 int _daysBetween(DateTime a, DateTime b) => b.difference(a).inDays;
 
+/// Number of business days (Monday-Friday) from the week of the epoch to
+/// [date]. A weekend date shares the index of the following Monday.
+int _businessDayIndex(DateTime date) {
+  // 1970-01-01 was a Thursday, 3 days after the Monday that starts its week
+  final days = _daysBetween(_epochDate, date) + 3;
+  return (days / 7).floor() * 5 + min(_euclideanMod(days, 7), 5);
+}
+
+/// The weekday with the given business day index.
+DateTime _businessDayDate(int index) => _epochDate.add(
+  Duration(days: (index / 5).floor() * 7 + _euclideanMod(index, 5) - 3),
+);
+
+/// Whether [date] is a business day an `every N weekdays` schedule fires on.
+bool _weekdayIntervalAligned(DateTime? anchor, int interval, DateTime date) {
+  final offset =
+      _businessDayIndex(date) - _businessDayIndex(anchor ?? _epochDate);
+  return date.weekday <= 5 && offset >= 0 && offset % interval == 0;
+}
+
 int _monthsBetweenYM(DateTime a, DateTime b) =>
     (b.year * 12 + b.month) - (a.year * 12 + a.month);
 
@@ -212,41 +285,102 @@ int _weeksBetween(DateTime a, DateTime b) {
 
 bool _isExcepted(DateTime date, List<ExceptionSpec> exceptions) {
   for (final exc in exceptions) {
-    if (exc is NamedException) {
-      if (date.month == exc.month.number && date.day == exc.day) return true;
-    } else {
-      final iso = (exc as IsoException).date;
-      final excDate = _parseIsoDateUtc(iso);
-      if (date.year == excDate.year &&
-          date.month == excDate.month &&
-          date.day == excDate.day) {
-        return true;
-      }
+    switch (exc) {
+      case NamedException():
+        if (date.month == exc.month.number && date.day == exc.day) return true;
+      case IsoException():
+        final excDate = _parseIsoDateUtc(exc.date);
+        if (date.year == excDate.year &&
+            date.month == excDate.month &&
+            date.day == excDate.day) {
+          return true;
+        }
+      case WeekdayException():
+        if (date.weekday == exc.day.number) return true;
+      case IsoRangeException() || NamedRangeException():
+        if (_rangeBounds(date, exc) != null) return true;
     }
   }
   return false;
 }
 
+/// Concrete bounds of a range exception if it covers [date].
+({DateTime start, DateTime end})? _rangeBounds(
+  DateTime date,
+  ExceptionSpec exc,
+) {
+  switch (exc) {
+    case IsoRangeException():
+      final start = _parseIsoDateUtc(exc.start);
+      final end = _parseIsoDateUtc(exc.end);
+      if (date.isBefore(start) || date.isAfter(end)) return null;
+      return (start: start, end: end);
+    case NamedRangeException():
+      final startMonth = exc.startMonth.number;
+      final endMonth = exc.endMonth.number;
+      final startKey = startMonth * 100 + exc.startDay;
+      final endKey = endMonth * 100 + exc.endDay;
+      final key = date.month * 100 + date.day;
+      int startYear;
+      int endYear;
+      if (startKey <= endKey) {
+        if (key < startKey || key > endKey) return null;
+        startYear = date.year;
+        endYear = date.year;
+      } else if (key >= startKey) {
+        // Wraps into next year
+        startYear = date.year;
+        endYear = date.year + 1;
+      } else if (key <= endKey) {
+        startYear = date.year - 1;
+        endYear = date.year;
+      } else {
+        return null;
+      }
+      return (
+        start: _clampedDate(startYear, startMonth, exc.startDay),
+        end: _clampedDate(endYear, endMonth, exc.endDay),
+      );
+    default:
+      return null;
+  }
+}
+
+/// The date for `month day` in [year], with days past the month's end (feb 29
+/// in a common year) moved back to its last day.
+DateTime _clampedDate(int year, int month, int day) =>
+    DateTime.utc(year, month, min(day, _lastDayOfMonth(year, month).day));
+
 class _ParsedExceptions {
   final List<(int, int)> named; // (month_number, day)
   final List<DateTime> isoDates;
+  final Set<int> weekdays; // ISO day numbers, 1=Monday
+  final List<ExceptionSpec> ranges;
 
-  _ParsedExceptions(this.named, this.isoDates);
+  _ParsedExceptions(this.named, this.isoDates, this.weekdays, this.ranges);
 
   factory _ParsedExceptions.from(List<ExceptionSpec> exceptions) {
     final named = <(int, int)>[];
     final isoDates = <DateTime>[];
+    final weekdays = <int>{};
+    final ranges = <ExceptionSpec>[];
     for (final exc in exceptions) {
-      if (exc is NamedException) {
-        named.add((exc.month.number, exc.day));
-      } else {
-        isoDates.add(_parseIsoDateUtc((exc as IsoException).date));
+      switch (exc) {
+        case NamedException():
+          named.add((exc.month.number, exc.day));
+        case IsoException():
+          isoDates.add(_parseIsoDateUtc(exc.date));
+        case WeekdayException():
+          weekdays.add(exc.day.number);
+        case IsoRangeException() || NamedRangeException():
+          ranges.add(exc);
       }
     }
-    return _ParsedExceptions(named, isoDates);
+    return _ParsedExceptions(named, isoDates, weekdays, ranges);
   }
 
   bool isExcepted(DateTime date) {
+    if (weekdays.contains(date.weekday)) return true;
     for (final (m, d) in named) {
       if (date.month == m && date.day == d) return true;
     }
@@ -257,27 +391,63 @@ class _ParsedExceptions {
         return true;
       }
     }
-    return false;
+    return ranges.any((r) => _rangeBounds(date, r) != null);
+  }
+
+  /// The last day of the excepted run [date] falls in, so long ranges are
+  /// skipped in one step rather than day by day.
+  DateTime runEnd(DateTime date) {
+    var end = date;
+    for (final r in ranges) {
+      final bounds = _rangeBounds(date, r);
+      if (bounds != null && bounds.end.isAfter(end)) end = bounds.end;
+    }
+    return end;
+  }
+
+  /// The first day of the excepted run [date] falls in.
+  DateTime runStart(DateTime date) {
+    var start = date;
+    for (final r in ranges) {
+      final bounds = _rangeBounds(date, r);
+      if (bounds != null && bounds.start.isBefore(start)) start = bounds.start;
+    }
+    return start;
   }
 }
 
-bool _matchesDuring(DateTime date, List<MonthName> during) {
+/// Check if a date falls within any `during` spec. If `during` is empty, all
+/// dates match.
+bool _matchesDuring(DateTime date, List<DuringSpec> during) {
   if (during.isEmpty) return true;
-  return during.any((mn) => mn.number == date.month);
+  final month = MonthName.fromNumber(date.month);
+  return during.any((spec) => duringContains(spec, month, date.day));
 }
 
-/// Find the 1st of the next valid `during` month after `date`.
-DateTime _nextDuringMonth(DateTime date, List<MonthName> during) {
-  final currentMonth = date.month;
-  final months = during.map((mn) => mn.number).toList()..sort();
+/// The month-day in [year], or the day after the month's end when the day
+/// does not exist that year (feb 29 in a common year).
+DateTime _monthDayInYear(int year, MonthName month, int day) {
+  final last = _lastDayOfMonth(year, month.number);
+  if (day > last.day) return last.add(const Duration(days: 1));
+  return DateTime.utc(year, month.number, day);
+}
 
-  for (final m in months) {
-    if (m > currentMonth) {
-      return DateTime.utc(date.year, m, 1);
+/// Find the first day of the next `during` period starting after `date`.
+DateTime _nextDuringStart(DateTime date, List<DuringSpec> during) {
+  DateTime? best;
+  for (final spec in during) {
+    final startDay = duringBounds(spec).startDay;
+    for (final year in [date.year, date.year + 1]) {
+      final start = _monthDayInYear(year, spec.start, startDay);
+      if (start.isAfter(date)) {
+        if (best == null || start.isBefore(best)) {
+          best = start;
+        }
+        break;
+      }
     }
   }
-  // Wrap to first month of next year
-  return DateTime.utc(date.year + 1, months[0], 1);
+  return best ?? date;
 }
 
 DateTime _resolveUntil(UntilSpec until, TZDateTime now) {
@@ -338,26 +508,93 @@ TZDateTime? _latestPastAtTimes(
   return best;
 }
 
-/// Find the last day of the previous valid `during` month before `date`.
-DateTime? _prevDuringMonth(DateTime date, List<MonthName> during) {
-  final currentMonth = date.month;
-  final months = during.map((mn) => mn.number).toList()..sort((a, b) => b - a);
-
-  for (final m in months) {
-    if (m < currentMonth) {
-      return _lastDayOfMonth(date.year, m);
+/// Find the last day of the previous `during` period ending before `date`.
+DateTime? _prevDuringEnd(DateTime date, List<DuringSpec> during) {
+  DateTime? best;
+  for (final spec in during) {
+    final endDay = duringBounds(spec).endDay;
+    for (final year in [date.year, date.year - 1]) {
+      final last = _lastDayOfMonth(year, spec.end.number);
+      final end = DateTime.utc(year, last.month, min(endDay, last.day));
+      if (end.isBefore(date)) {
+        if (best == null || end.isAfter(best)) {
+          best = end;
+        }
+        break;
+      }
     }
   }
-  // Wrap to last month of previous year
-  if (months.isNotEmpty) {
-    return _lastDayOfMonth(date.year - 1, months[0]);
+  return best;
+}
+
+/// Pin a relative anchor to a date in the schedule timezone: `starting today`
+/// and `starting now` to `now`'s date, `starting jan 6` to the next jan 6 on or
+/// after it.
+ScheduleData _resolveAnchor(ScheduleData schedule, TZDateTime now) {
+  if (!schedule.hasRelativeAnchor) return schedule;
+  final loc = _getLocation(_resolveTz(schedule.timezone));
+  final nowInTz = TZDateTime.from(now, loc);
+  final today = DateTime.utc(nowInTz.year, nowInTz.month, nowInTz.day);
+  DateTime? anchor = today;
+  final named = schedule.anchorNamed;
+  if (named != null) {
+    anchor = null;
+    // Try up to 8 years forward (covers leap year cycles)
+    for (var y = 0; y < 8; y++) {
+      final d = DateTime.utc(today.year + y, named.month.number, named.day);
+      // Skip dates that overflow (feb 29 in a common year)
+      if (d.day == named.day && !d.isBefore(today)) {
+        anchor = d;
+        break;
+      }
+    }
+    if (anchor == null) {
+      throw HronError.eval('starting date ${schedule.anchorText} never occurs');
+    }
   }
-  return null;
+  return ScheduleData(schedule.expr)
+    ..timezone = schedule.timezone
+    ..except = schedule.except
+    ..until = schedule.until
+    ..anchor = anchor.toIso8601String().substring(0, 10)
+    ..anchorTime = schedule.anchorTime
+    ..during = schedule.during;
+}
+
+/// The instant nothing occurs before: the resolved anchor date at the
+/// `starting ... at` time, or at midnight.
+TZDateTime? _anchorStart(ScheduleData schedule, Location loc) {
+  if (schedule.anchor == null) return null;
+  final date = _parseIsoDateUtc(schedule.anchor!);
+  final time = schedule.anchorTime ?? const TimeOfDay(0, 0);
+  return _atTimeOnDate(date, time.hour, time.minute, loc);
+}
+
+/// Pin what the schedule resolves against the evaluation time, as seen from
+/// `now`: `on <weekday>` becomes the one date it names (the nearest such
+/// weekday with a time still ahead), and a relative anchor becomes its date.
+ScheduleData _pinRelative(ScheduleData schedule, TZDateTime now) {
+  schedule = _resolveAnchor(schedule, now);
+  final expr = schedule.expr;
+  if (expr is! SingleDate || expr.date is! NextWeekdayDate) return schedule;
+  final loc = _getLocation(_resolveTz(schedule.timezone));
+  final next = _nextSingleDate(expr.date, expr.times, loc, now);
+  if (next == null) return schedule;
+  final date = TZDateTime.from(next, loc).toIso8601String().substring(0, 10);
+  return ScheduleData(SingleDate(IsoDate(date), expr.times))
+    ..timezone = schedule.timezone
+    ..except = schedule.except
+    ..until = schedule.until
+    ..anchor = schedule.anchor
+    ..anchorNamed = schedule.anchorNamed
+    ..anchorTime = schedule.anchorTime
+    ..during = schedule.during;
 }
 
 // --- Public API ---
 
 TZDateTime? nextFrom(ScheduleData schedule, TZDateTime now) {
+  schedule = _resolveAnchor(schedule, now);
   final tzName = _resolveTz(schedule.timezone);
   final loc = _getLocation(tzName);
 
@@ -368,6 +605,7 @@ TZDateTime? nextFrom(ScheduleData schedule, TZDateTime now) {
   final parsedExceptions = _ParsedExceptions.from(schedule.except);
   final hasExceptions = schedule.except.isNotEmpty;
   final hasDuring = schedule.during.isNotEmpty;
+  final during = schedule.during;
   final needsTzConversion = untilDate != null || hasDuring || hasExceptions;
 
   // Check if expression is NearestWeekday with direction (can cross month boundaries)
@@ -378,14 +616,20 @@ TZDateTime? nextFrom(ScheduleData schedule, TZDateTime now) {
               .direction !=
           null;
 
+  // Nothing occurs before the starting anchor, so search from its start
   var current = now;
+  final start = _anchorStart(schedule, loc);
+  if (start != null && now.isBefore(start)) {
+    current = start.subtract(const Duration(microseconds: 1));
+  }
   for (var i = 0; i < 1000; i++) {
     final candidate = _nextExpr(
       schedule.expr,
       loc,
       schedule.anchor,
       current,
-      during: handlesDuringInternally ? schedule.during : const [],
+      during: handlesDuringInternally ? duringMonths(schedule) : const [],
+      exceptions: schedule.except,
     );
 
     if (candidate == null) return null;
@@ -406,9 +650,9 @@ TZDateTime? nextFrom(ScheduleData schedule, TZDateTime now) {
     // Skip this check for expressions that handle during internally (NearestWeekday with direction)
     if (hasDuring &&
         !handlesDuringInternally &&
-        !_matchesDuring(cDate!, schedule.during)) {
-      // Skip ahead to 1st of next valid during month
-      final skipTo = _nextDuringMonth(cDate, schedule.during);
+        !_matchesDuring(cDate!, during)) {
+      // Skip ahead to the start of the next during period
+      final skipTo = _nextDuringStart(cDate, during);
       current = TZDateTime(
         loc,
         skipTo.year,
@@ -420,7 +664,10 @@ TZDateTime? nextFrom(ScheduleData schedule, TZDateTime now) {
 
     // Apply except filter
     if (hasExceptions && parsedExceptions.isExcepted(cDate!)) {
-      final nextDay = cDate.add(const Duration(days: 1));
+      // Advance past this day (or the whole excepted range) and retry
+      final nextDay = parsedExceptions
+          .runEnd(cDate)
+          .add(const Duration(days: 1));
       current = TZDateTime(
         loc,
         nextDay.year,
@@ -442,6 +689,7 @@ TZDateTime? _nextExpr(
   String? anchor,
   TZDateTime now, {
   List<MonthName> during = const [],
+  List<ExceptionSpec> exceptions = const [],
 }) {
   return switch (expr) {
     DayRepeat() => _nextDayRepeat(
@@ -452,6 +700,15 @@ TZDateTime? _nextExpr(
       anchor,
       now,
     ),
+    // Day-step windows fire once, at the window start, on each aligned day
+    IntervalRepeat(unit: IntervalUnit.days) => _nextDayRepeat(
+      expr.interval,
+      expr.dayFilter ?? EveryDay(),
+      [expr.from],
+      loc,
+      anchor,
+      now,
+    ),
     IntervalRepeat() => _nextIntervalRepeat(
       expr.interval,
       expr.unit,
@@ -477,6 +734,7 @@ TZDateTime? _nextExpr(
       anchor,
       now,
       during: during,
+      exceptions: exceptions,
     ),
     SingleDate() => _nextSingleDate(expr.date, expr.times, loc, now),
     YearRepeat() => _nextYearRepeat(
@@ -487,10 +745,25 @@ TZDateTime? _nextExpr(
       anchor,
       now,
     ),
+    WeekdayRepeat() => _nextWeekdayRepeat(
+      expr.interval,
+      expr.times,
+      loc,
+      anchor,
+      now,
+    ),
+    IsoWeekRepeat() => _nextIsoWeekRepeat(
+      expr.weeks,
+      expr.days,
+      expr.times,
+      loc,
+      now,
+    ),
   };
 }
 
 List<TZDateTime> nextNFrom(ScheduleData schedule, TZDateTime now, int n) {
+  schedule = _pinRelative(schedule, now);
   final results = <TZDateTime>[];
   var current = now;
   for (var i = 0; i < n; i++) {
@@ -503,16 +776,18 @@ List<TZDateTime> nextNFrom(ScheduleData schedule, TZDateTime now, int n) {
 }
 
 TZDateTime? previousFrom(ScheduleData schedule, TZDateTime now) {
+  // Nothing occurs before `starting now`
+  if (schedule.anchor == 'now') return null;
+  schedule = _pinRelative(schedule, now);
   final tzName = _resolveTz(schedule.timezone);
   final loc = _getLocation(tzName);
 
-  final anchorDate = schedule.anchor != null
-      ? _parseIsoDateUtc(schedule.anchor!)
-      : null;
+  final anchorStart = _anchorStart(schedule, loc);
 
   final parsedExceptions = _ParsedExceptions.from(schedule.except);
   final hasExceptions = schedule.except.isNotEmpty;
   final hasDuring = schedule.during.isNotEmpty;
+  final during = schedule.during;
 
   // Handle until clause - if now is after until, search from end of until date
   var searchFrom = now;
@@ -531,6 +806,7 @@ TZDateTime? previousFrom(ScheduleData schedule, TZDateTime now) {
       loc,
       schedule.anchor,
       searchFrom,
+      exceptions: schedule.except,
     );
 
     if (candidate == null) return null;
@@ -540,7 +816,7 @@ TZDateTime? previousFrom(ScheduleData schedule, TZDateTime now) {
     final cDate = DateTime.utc(cInTz.year, cInTz.month, cInTz.day);
 
     // Check if before anchor
-    if (anchorDate != null && cDate.isBefore(anchorDate)) {
+    if (anchorStart != null && candidate.isBefore(anchorStart)) {
       return null;
     }
 
@@ -555,15 +831,17 @@ TZDateTime? previousFrom(ScheduleData schedule, TZDateTime now) {
 
     // Apply except filter
     if (hasExceptions && parsedExceptions.isExcepted(cDate)) {
-      searchFrom = candidate;
+      // Go back before this day (or the whole excepted range) and retry
+      final start = parsedExceptions.runStart(cDate);
+      searchFrom = TZDateTime(loc, start.year, start.month, start.day);
       continue;
     }
 
     // Apply during filter
-    if (hasDuring && !_matchesDuring(cDate, schedule.during)) {
-      final prevMonth = _prevDuringMonth(cDate, schedule.during);
-      if (prevMonth == null) return null;
-      final nextDay = prevMonth.add(const Duration(days: 1));
+    if (hasDuring && !_matchesDuring(cDate, during)) {
+      final prevEnd = _prevDuringEnd(cDate, during);
+      if (prevEnd == null) return null;
+      final nextDay = prevEnd.add(const Duration(days: 1));
       searchFrom = TZDateTime(loc, nextDay.year, nextDay.month, nextDay.day);
       continue;
     }
@@ -578,8 +856,9 @@ TZDateTime? _prevExpr(
   ScheduleExpr expr,
   Location loc,
   String? anchor,
-  TZDateTime now,
-) {
+  TZDateTime now, {
+  List<ExceptionSpec> exceptions = const [],
+}) {
   return switch (expr) {
     DayRepeat() => _prevDayRepeat(
       expr.interval,
@@ -589,6 +868,14 @@ TZDateTime? _prevExpr(
       anchor,
       now,
     ),
+    IntervalRepeat(unit: IntervalUnit.days) => _prevDayRepeat(
+      expr.interval,
+      expr.dayFilter ?? EveryDay(),
+      [expr.from],
+      loc,
+      anchor,
+      now,
+    ),
     IntervalRepeat() => _prevIntervalRepeat(
       expr.interval,
       expr.unit,
@@ -613,6 +900,7 @@ TZDateTime? _prevExpr(
       loc,
       anchor,
       now,
+      exceptions: exceptions,
     ),
     SingleDate() => _prevSingleDate(expr.date, expr.times, loc, now),
     YearRepeat() => _prevYearRepeat(
@@ -623,6 +911,20 @@ TZDateTime? _prevExpr(
       anchor,
       now,
     ),
+    WeekdayRepeat() => _prevWeekdayRepeat(
+      expr.interval,
+      expr.times,
+      loc,
+      anchor,
+      now,
+    ),
+    IsoWeekRepeat() => _prevIsoWeekRepeat(
+      expr.weeks,
+      expr.days,
+      expr.times,
+      loc,
+      now,
+    ),
   };
 }
 
@@ -751,14 +1053,16 @@ TZDateTime? _prevWeekRepeat(
   return null;
 }
 
+/// Business-day targets skip over days [exceptions] removes.
 TZDateTime? _prevMonthRepeat(
   int interval,
   MonthTarget target,
   List<TimeOfDay> times,
   Location loc,
   String? anchor,
-  TZDateTime now,
-) {
+  TZDateTime now, {
+  List<ExceptionSpec> exceptions = const [],
+}) {
   final nowInTz = TZDateTime.from(now, loc);
   var year = nowInTz.year;
   var month = nowInTz.month;
@@ -795,21 +1099,27 @@ TZDateTime? _prevMonthRepeat(
       }
     } else if (target is LastDayTarget) {
       dateCandidates.add(_lastDayOfMonth(year, month));
+    } else if (target is LastNDaysTarget) {
+      dateCandidates.addAll(_lastNDaysOfMonth(year, month, target.n));
+    } else if (target is BusinessDayTarget) {
+      final d = _nthBusinessDay(year, month, target.n, exceptions);
+      if (d != null) {
+        dateCandidates.add(d);
+      }
     } else if (target is NearestWeekdayTarget) {
       final d = _nearestWeekday(year, month, target.day, target.direction);
       if (d != null) {
         dateCandidates.add(d);
       }
     } else if (target is OrdinalWeekdayMonthTarget) {
-      DateTime? d;
-      if (target.ordinal == OrdinalPosition.last) {
-        d = _lastWeekdayInMonth(year, month, target.weekday);
-      } else {
-        d = _nthWeekdayOfMonth(year, month, target.weekday, target.ordinal.toN);
-      }
+      final d = _ordinalWeekday(year, month, target.ordinal, target.weekday);
       if (d != null) {
         dateCandidates.add(d);
       }
+    } else if (target is OrdinalWeekdaysMonthTarget) {
+      dateCandidates.addAll(
+        _ordinalWeekdayDates(year, month, target.ordinals, target.weekday),
+      );
     } else {
       dateCandidates.add(_lastWeekdayOfMonth(year, month));
     }
@@ -862,6 +1172,9 @@ TZDateTime? _prevSingleDate(
     return null;
   }
 
+  // `previousFrom` pins `on <weekday>` to an ISO date first
+  if (dateSpec is NextWeekdayDate) return null;
+
   return null;
 }
 
@@ -945,6 +1258,23 @@ TZDateTime? _prevYearRepeat(
 }
 
 bool matches(ScheduleData schedule, TZDateTime datetime) {
+  // There is no evaluation time to resolve a relative anchor against;
+  // resolving it against `datetime` would make every date its own anchor.
+  if (schedule.hasRelativeAnchor) {
+    throw HronError.eval(
+      "matches has no evaluation time to resolve 'starting "
+      "${schedule.anchorText}' against; pin the anchor to a date first",
+    );
+  }
+  // Nor an `on <weekday>` date: every such weekday would be the upcoming one
+  final expr = schedule.expr;
+  if (expr is SingleDate && expr.date is NextWeekdayDate) {
+    final day = (expr.date as NextWeekdayDate).day;
+    throw HronError.eval(
+      "matches has no evaluation time to resolve 'on ${day.name}' "
+      'against; use a date instead',
+    );
+  }
   final tzName = _resolveTz(schedule.timezone);
   final loc = _getLocation(tzName);
   final zdt = TZDateTime.from(datetime, loc);
@@ -952,6 +1282,9 @@ bool matches(ScheduleData schedule, TZDateTime datetime) {
 
   if (!_matchesDuring(date, schedule.during)) return false;
   if (_isExcepted(date, schedule.except)) return false;
+  // Nothing occurs before the starting anchor
+  final start = _anchorStart(schedule, loc);
+  if (start != null && datetime.isBefore(start)) return false;
 
   if (schedule.until != null) {
     final untilDate = _resolveUntil(schedule.until!, datetime);
@@ -984,6 +1317,22 @@ bool matches(ScheduleData schedule, TZDateTime datetime) {
       }
       return true;
 
+    case IntervalRepeat(
+      interval: final interval,
+      unit: IntervalUnit.days,
+      from: final from,
+      dayFilter: final dayFilter,
+    ):
+      if (dayFilter != null && !_matchesDayFilter(date, dayFilter)) {
+        return false;
+      }
+      if (!timeMatchesWithDst([from])) return false;
+      final anchorDate = schedule.anchor != null
+          ? _parseIsoDateUtc(schedule.anchor!)
+          : _epochDate;
+      final dayOffset = _daysBetween(anchorDate, date);
+      return dayOffset >= 0 && dayOffset % interval == 0;
+
     case IntervalRepeat(
       interval: final interval,
       unit: final unit,
@@ -1039,6 +1388,19 @@ bool matches(ScheduleData schedule, TZDateTime datetime) {
         final last = _lastDayOfMonth(date.year, date.month);
         return date.day == last.day;
       }
+      if (target is LastNDaysTarget) {
+        final last = _lastDayOfMonth(date.year, date.month);
+        return date.day > last.day - target.n;
+      }
+      if (target is BusinessDayTarget) {
+        final d = _nthBusinessDay(
+          date.year,
+          date.month,
+          target.n,
+          schedule.except,
+        );
+        return d != null && d.day == date.day;
+      }
       if (target is NearestWeekdayTarget) {
         final targetDate = _nearestWeekday(
           date.year,
@@ -1052,24 +1414,21 @@ bool matches(ScheduleData schedule, TZDateTime datetime) {
             date.day == targetDate.day;
       }
       if (target is OrdinalWeekdayMonthTarget) {
-        DateTime? targetDate;
-        if (target.ordinal == OrdinalPosition.last) {
-          targetDate = _lastWeekdayInMonth(
-            date.year,
-            date.month,
-            target.weekday,
-          );
-        } else {
-          targetDate = _nthWeekdayOfMonth(
-            date.year,
-            date.month,
-            target.weekday,
-            target.ordinal.toN,
-          );
-        }
+        final targetDate = _ordinalWeekday(
+          date.year,
+          date.month,
+          target.ordinal,
+          target.weekday,
+        );
         if (targetDate == null) return false;
         return date.day == targetDate.day;
       }
+      if (target is OrdinalWeekdaysMonthTarget) {
+        return target.ordinals.any((o) {
+          final d = _ordinalWeekday(date.year, date.month, o, target.weekday);
+          return d != null && d.day == date.day;
+        });
+      }
       final lastWd = _lastWeekdayOfMonth(date.year, date.month);
       return date.day == lastWd.day;
 
@@ -1084,6 +1443,10 @@ bool matches(ScheduleData schedule, TZDateTime datetime) {
       if (dateSpec is NamedDate) {
         return date.month == dateSpec.month.number && date.day == dateSpec.day;
       }
+      // `matches` rejects it up front; any day with that weekday can be it
+      if (dateSpec is NextWeekdayDate) {
+        return date.weekday == dateSpec.day.number;
+      }
       return false;
 
     case YearRepeat(
@@ -1100,6 +1463,22 @@ bool matches(ScheduleData schedule, TZDateTime datetime) {
         if (yearOffset < 0 || yearOffset % interval != 0) return false;
       }
       return _matchesYearTarget(target, date);
+
+    case WeekdayRepeat(interval: final interval, times: final times):
+      final anchorDate = schedule.anchor != null
+          ? _parseIsoDateUtc(schedule.anchor!)
+          : null;
+      if (!_weekdayIntervalAligned(anchorDate, interval, date)) return false;
+      return timeMatchesWithDst(times);
+
+    case IsoWeekRepeat(
+      weeks: final weeks,
+      days: final days,
+      times: final times,
+    ):
+      if (!weeks.contains(_isoWeekOf(date).week)) return false;
+      if (!days.any((d) => d.number == date.weekday)) return false;
+      return timeMatchesWithDst(times);
   }
 }
 
@@ -1166,7 +1545,8 @@ TZDateTime? _nextDayRepeat(
     return null;
   }
 
-  // Interval > 1: day intervals only apply to DayFilter::Every
+  // Interval > 1: the filter narrows the aligned days, skipping (not
+  // moving) any that fail it
   final anchorDate = anchor != null ? _parseIsoDateUtc(anchor) : _epochDate;
 
   // Find the next aligned day >= today
@@ -1177,8 +1557,10 @@ TZDateTime? _nextDayRepeat(
       : date.add(Duration(days: interval - alignedRemainder));
 
   for (var i = 0; i < 400; i++) {
-    final candidate = _earliestFutureAtTimes(cur, times, loc, now);
-    if (candidate != null) return candidate;
+    if (_matchesDayFilter(cur, days)) {
+      final candidate = _earliestFutureAtTimes(cur, times, loc, now);
+      if (candidate != null) return candidate;
+    }
     cur = cur.add(Duration(days: interval));
   }
 
@@ -1288,6 +1670,7 @@ TZDateTime? _nextWeekRepeat(
   return null;
 }
 
+/// Business-day targets skip over days [exceptions] removes.
 TZDateTime? _nextMonthRepeat(
   int interval,
   MonthTarget target,
@@ -1296,6 +1679,7 @@ TZDateTime? _nextMonthRepeat(
   String? anchor,
   TZDateTime now, {
   List<MonthName> during = const [],
+  List<ExceptionSpec> exceptions = const [],
 }) {
   final nowInTz = TZDateTime.from(now, loc);
   var year = nowInTz.year;
@@ -1351,21 +1735,27 @@ TZDateTime? _nextMonthRepeat(
       }
     } else if (target is LastDayTarget) {
       dateCandidates.add(_lastDayOfMonth(year, month));
+    } else if (target is LastNDaysTarget) {
+      dateCandidates.addAll(_lastNDaysOfMonth(year, month, target.n));
+    } else if (target is BusinessDayTarget) {
+      final d = _nthBusinessDay(year, month, target.n, exceptions);
+      if (d != null) {
+        dateCandidates.add(d);
+      }
     } else if (target is NearestWeekdayTarget) {
       final d = _nearestWeekday(year, month, target.day, target.direction);
       if (d != null) {
         dateCandidates.add(d);
       }
     } else if (target is OrdinalWeekdayMonthTarget) {
-      DateTime? d;
-      if (target.ordinal == OrdinalPosition.last) {
-        d = _lastWeekdayInMonth(year, month, target.weekday);
-      } else {
-        d = _nthWeekdayOfMonth(year, month, target.weekday, target.ordinal.toN);
-      }
+      final d = _ordinalWeekday(year, month, target.ordinal, target.weekday);
       if (d != null) {
         dateCandidates.add(d);
       }
+    } else if (target is OrdinalWeekdaysMonthTarget) {
+      dateCandidates.addAll(
+        _ordinalWeekdayDates(year, month, target.ordinals, target.weekday),
+      );
     } else {
       dateCandidates.add(_lastWeekdayOfMonth(year, month));
     }
@@ -1422,6 +1812,18 @@ TZDateTime? _nextSingleDate(
     return null;
   }
 
+  if (dateSpec is NextWeekdayDate) {
+    // Today if a time is still ahead, otherwise within the next week
+    final today = DateTime.utc(nowInTz.year, nowInTz.month, nowInTz.day);
+    for (var offset = 0; offset < 8; offset++) {
+      final date = today.add(Duration(days: offset));
+      if (date.weekday != dateSpec.day.number) continue;
+      final candidate = _earliestFutureAtTimes(date, times, loc, now);
+      if (candidate != null) return candidate;
+    }
+    return null;
+  }
+
   return null;
 }
 
@@ -1505,12 +1907,137 @@ TZDateTime? _nextYearRepeat(
   return null;
 }
 
+// ISO years searched for a matching week; week 53 only exists in long ISO
+// years.
+const _isoYearHorizon = 7;
+
+/// ISO 8601 week-numbering year and week of [date]. Weeks belong to the year
+/// of their Thursday.
+({int year, int week}) _isoWeekOf(DateTime date) {
+  final thursday = date.add(Duration(days: 4 - date.weekday));
+  final dayOfYear =
+      _daysBetween(DateTime.utc(thursday.year, 1, 1), thursday) + 1;
+  return (year: thursday.year, week: (dayOfYear - 1) ~/ 7 + 1);
+}
+
+/// Dates in ISO week-numbering [year] on one of [days] in one of [weeks],
+/// ascending. Week 53 yields nothing in years with only 52 weeks.
+List<DateTime> _isoWeekDates(int year, List<int> weeks, List<Weekday> days) {
+  // Jan 4 is always in ISO week 1
+  final jan4 = DateTime.utc(year, 1, 4);
+  final week1Monday = jan4.subtract(Duration(days: jan4.weekday - 1));
+  final dates = <DateTime>{};
+  for (final week in weeks) {
+    for (final wd in days) {
+      final d = week1Monday.add(
+        Duration(days: (week - 1) * 7 + wd.number - 1),
+      );
+      if (_isoWeekOf(d).year != year) continue;
+      dates.add(d);
+    }
+  }
+  return dates.toList()..sort();
+}
+
+TZDateTime? _nextIsoWeekRepeat(
+  List<int> weeks,
+  List<Weekday> days,
+  List<TimeOfDay> times,
+  Location loc,
+  TZDateTime now,
+) {
+  final nowInTz = TZDateTime.from(now, loc);
+  final startYear = _isoWeekOf(
+    DateTime.utc(nowInTz.year, nowInTz.month, nowInTz.day),
+  ).year;
+  for (var year = startYear; year < startYear + _isoYearHorizon; year++) {
+    for (final d in _isoWeekDates(year, weeks, days)) {
+      final candidate = _earliestFutureAtTimes(d, times, loc, now);
+      if (candidate != null) return candidate;
+    }
+  }
+  return null;
+}
+
+TZDateTime? _prevIsoWeekRepeat(
+  List<int> weeks,
+  List<Weekday> days,
+  List<TimeOfDay> times,
+  Location loc,
+  TZDateTime now,
+) {
+  final nowInTz = TZDateTime.from(now, loc);
+  final startYear = _isoWeekOf(
+    DateTime.utc(nowInTz.year, nowInTz.month, nowInTz.day),
+  ).year;
+  for (var year = startYear; year > startYear - _isoYearHorizon; year--) {
+    for (final d in _isoWeekDates(year, weeks, days).reversed) {
+      final candidate = _latestPastAtTimes(d, times, loc, now);
+      if (candidate != null) return candidate;
+    }
+  }
+  return null;
+}
+
+/// Today if it is an aligned business day with a time still ahead, otherwise
+/// the next aligned business day.
+TZDateTime? _nextWeekdayRepeat(
+  int interval,
+  List<TimeOfDay> times,
+  Location loc,
+  String? anchor,
+  TZDateTime now,
+) {
+  final nowInTz = TZDateTime.from(now, loc);
+  final today = DateTime.utc(nowInTz.year, nowInTz.month, nowInTz.day);
+  final anchorIndex = _businessDayIndex(
+    anchor != null ? _parseIsoDateUtc(anchor) : _epochDate,
+  );
+  final todayIndex = _businessDayIndex(today);
+  final isWeekday = today.weekday <= 5;
+
+  if (isWeekday && _euclideanMod(todayIndex - anchorIndex, interval) == 0) {
+    final candidate = _earliestFutureAtTimes(today, times, loc, now);
+    if (candidate != null) return candidate;
+  }
+  // A weekend already shares the following Monday's index
+  final after = isWeekday ? todayIndex + 1 : todayIndex;
+  final index = after + _euclideanMod(anchorIndex - after, interval);
+  return _earliestFutureAtTimes(_businessDayDate(index), times, loc, now);
+}
+
+TZDateTime? _prevWeekdayRepeat(
+  int interval,
+  List<TimeOfDay> times,
+  Location loc,
+  String? anchor,
+  TZDateTime now,
+) {
+  final nowInTz = TZDateTime.from(now, loc);
+  final today = DateTime.utc(nowInTz.year, nowInTz.month, nowInTz.day);
+  final anchorIndex = _businessDayIndex(
+    anchor != null ? _parseIsoDateUtc(anchor) : _epochDate,
+  );
+  final todayIndex = _businessDayIndex(today);
+
+  if (today.weekday <= 5 &&
+      _euclideanMod(todayIndex - anchorIndex, interval) == 0) {
+    final candidate = _latestPastAtTimes(today, times, loc, now);
+    if (candidate != null) return candidate;
+  }
+  // On a weekend the index is the following Monday's, so one back is Friday
+  final before = todayIndex - 1;
+  final index = before - _euclideanMod(before - anchorIndex, interval);
+  return _latestPastAtTimes(_businessDayDate(index), times, loc, now);
+}
+
 // --- Iterator functions ---
 
 /// Returns a lazy iterable of occurrences starting after [from].
 /// The iterator is unbounded for repeating schedules (will iterate forever unless limited),
 /// but respects the `until` clause if specified in the schedule.
 Iterable<TZDateTime> occurrences(ScheduleData schedule, TZDateTime from) sync* {
+  schedule = _pinRelative(schedule, from);
   var current = from;
   while (true) {
     final next = nextFrom(schedule, current);
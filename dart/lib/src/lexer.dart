@@ -1,3 +1,5 @@
+import 'dart:math';
+
 import 'ast.dart';
 import 'error.dart';
 
@@ -15,6 +17,12 @@ class FromToken extends TokenKind {}
 
 class ToToken extends TokenKind {}
 
+class ThroughToken extends TokenKind {}
+
+class BusinessToken extends TokenKind {}
+
+class PastToken extends TokenKind {}
+
 class InToken extends TokenKind {}
 
 class OfToken extends TokenKind {}
@@ -37,6 +45,12 @@ class NextToken extends TokenKind {}
 
 class PreviousToken extends TokenKind {}
 
+class BetweenToken extends TokenKind {}
+
+class AndToken extends TokenKind {}
+
+class IsoToken extends TokenKind {}
+
 class YearToken extends TokenKind {}
 
 class DayToken extends TokenKind {}
@@ -49,6 +63,27 @@ class WeeksToken extends TokenKind {}
 
 class MonthToken extends TokenKind {}
 
+class FrequencyToken extends TokenKind {
+  final String word;
+  FrequencyToken(this.word);
+}
+
+/// A cron shortcut, lowercase with the `@`: "@daily", "@hourly", ...
+class ShortcutToken extends TokenKind {
+  final String name;
+  ShortcutToken(this.name);
+}
+
+class PeriodToken extends TokenKind {
+  final String word;
+  PeriodToken(this.word);
+}
+
+class RelativeToken extends TokenKind {
+  final String word; // "today", "now"
+  RelativeToken(this.word);
+}
+
 class CommaToken extends TokenKind {}
 
 class DayNameToken extends TokenKind {
@@ -61,9 +96,11 @@ class MonthNameToken extends TokenKind {
   MonthNameToken(this.name);
 }
 
+/// A spelled-out ordinal ("first", "twenty-first") and its day number.
 class OrdinalToken extends TokenKind {
-  final OrdinalPosition name;
-  OrdinalToken(this.name);
+  final String word;
+  final int value;
+  OrdinalToken(this.word, this.value);
 }
 
 class IntervalUnitToken extends TokenKind {
@@ -76,6 +113,16 @@ class NumberToken extends TokenKind {
   NumberToken(this.value);
 }
 
+class DecimalToken extends TokenKind {
+  final String text;
+  DecimalToken(this.text);
+}
+
+class MinuteOffsetToken extends TokenKind {
+  final int minute;
+  MinuteOffsetToken(this.minute);
+}
+
 class OrdinalNumberToken extends TokenKind {
   final int value;
   OrdinalNumberToken(this.value);
@@ -142,6 +189,18 @@ class _Lexer {
         continue;
       }
 
+      // Cron shortcut: @daily
+      if (ch == '@') {
+        tokens.add(_lexShortcut());
+        continue;
+      }
+
+      // Minutes past the hour: :MM
+      if (ch == ':') {
+        tokens.add(_lexMinuteOffset());
+        continue;
+      }
+
       if (_isAlpha(ch)) {
         tokens.add(_lexWord());
         continue;
@@ -156,6 +215,16 @@ class _Lexer {
     return tokens;
   }
 
+  bool _nextWordIs(String word) {
+    final rest = input.substring(pos).trimLeft();
+    if (rest.length < word.length ||
+        rest.substring(0, word.length).toLowerCase() != word) {
+      return false;
+    }
+    return rest.length == word.length ||
+        !(_isAlphanumeric(rest[word.length]) || rest[word.length] == '_');
+  }
+
   void _skipWhitespace() {
     while (pos < input.length && _isWhitespace(input[pos])) {
       pos++;
@@ -179,6 +248,33 @@ class _Lexer {
     return Token(TimezoneToken(tz), Span(start, pos));
   }
 
+  Token _lexShortcut() {
+    final start = pos;
+    pos++; // skip '@'
+    while (pos < input.length && _isAlphanumeric(input[pos])) {
+      pos++;
+    }
+    final name = input.substring(start, pos).toLowerCase();
+    return Token(ShortcutToken(name), Span(start, pos));
+  }
+
+  Token _lexMinuteOffset() {
+    final start = pos;
+    pos++; // skip ':'
+    while (pos < input.length && _isDigit(input[pos])) {
+      pos++;
+    }
+    final digits = input.substring(start + 1, pos);
+    if (digits.length != 2 || int.parse(digits) > 59) {
+      throw HronError.lex(
+        "expected two-digit minute (:00 to :59) after ':'",
+        Span(start, max(pos, start + 1)),
+        input,
+      );
+    }
+    return Token(MinuteOffsetToken(int.parse(digits)), Span(start, pos));
+  }
+
   Token _lexNumberOrTimeOrDate() {
     final start = pos;
     final numStart = pos;
@@ -218,6 +314,10 @@ class _Lexer {
       if (minDigits.length == 2) {
         final hour = int.parse(digits);
         final minute = int.parse(minDigits);
+        final pm = _lexMeridiem();
+        if (pm != null) {
+          return _meridiemTime(hour, minute, pm, start);
+        }
         if (hour > 23 || minute > 59) {
           throw HronError.lex('invalid time', Span(start, pos), input);
         }
@@ -225,6 +325,25 @@ class _Lexer {
       }
     }
 
+    // 12-hour time without minutes: 9am, 5pm
+    if (digits.length == 1 || digits.length == 2) {
+      final pm = _lexMeridiem();
+      if (pm != null) {
+        return _meridiemTime(int.parse(digits), 0, pm, start);
+      }
+    }
+
+    // Decimal: 1.5
+    if (pos + 1 < input.length &&
+        input[pos] == '.' &&
+        _isDigit(input[pos + 1])) {
+      pos++; // skip '.'
+      while (pos < input.length && _isDigit(input[pos])) {
+        pos++;
+      }
+      return Token(DecimalToken(input.substring(start, pos)), Span(start, pos));
+    }
+
     final num = int.tryParse(digits);
     if (num == null) {
       throw HronError.lex('invalid number', Span(start, pos), input);
@@ -245,22 +364,64 @@ class _Lexer {
     return Token(NumberToken(num), Span(start, pos));
   }
 
+  /// Consumes an am/pm suffix directly following a time, returning whether it
+  /// was pm, or `null` if there is no suffix.
+  bool? _lexMeridiem() {
+    if (pos + 2 > input.length) return null;
+    final suffix = input.substring(pos, pos + 2).toLowerCase();
+    if (suffix != 'am' && suffix != 'pm') return null;
+    // Must not run into a longer word (e.g. "9amx")
+    if (pos + 2 < input.length && _isAlphanumeric(input[pos + 2])) {
+      return null;
+    }
+    pos += 2;
+    return suffix == 'pm';
+  }
+
+  /// Converts a 12-hour clock time to a 24-hour time token.
+  Token _meridiemTime(int hour, int minute, bool pm, int start) {
+    if (hour < 1 || hour > 12 || minute > 59) {
+      throw HronError.lex(
+        'invalid 12-hour time (hour must be 1-12)',
+        Span(start, pos),
+        input,
+      );
+    }
+    var h = hour;
+    if (h == 12) {
+      h = pm ? 12 : 0;
+    } else if (pm) {
+      h += 12;
+    }
+    return Token(TimeToken(h, minute), Span(start, pos));
+  }
+
   Token _lexWord() {
     final start = pos;
     while (pos < input.length &&
-        (_isAlphanumeric(input[pos]) || input[pos] == '_')) {
+        (_isAlphanumeric(input[pos]) ||
+            input[pos] == '_' ||
+            // hyphenated ordinals: "twenty-first"
+            (input[pos] == '-' &&
+                pos + 1 < input.length &&
+                _isAlpha(input[pos + 1])))) {
       pos++;
     }
     final word = input.substring(start, pos).toLowerCase();
     final span = Span(start, pos);
 
-    final kind = _keywordMap[word];
+    var kind = _keywordMap[word];
+    final ordinal = ordinalWordValue(word);
+    if (kind == null && ordinal != null) {
+      kind = OrdinalToken(word, ordinal);
+    }
     if (kind == null) {
       throw HronError.lex("unknown keyword '$word'", span, input);
     }
 
     if (kind is InToken) {
-      afterIn = true;
+      // "in the morning" and "in iso weeks" are not timezone clauses
+      afterIn = !_nextWordIs('the') && !_nextWordIs('iso');
     }
 
     return Token(kind, span);
@@ -273,6 +434,9 @@ String tokenKindType(TokenKind kind) => switch (kind) {
   AtToken() => 'at',
   FromToken() => 'from',
   ToToken() => 'to',
+  ThroughToken() => 'through',
+  BusinessToken() => 'business',
+  PastToken() => 'past',
   InToken() => 'in',
   OfToken() => 'of',
   TheToken() => 'the',
@@ -284,18 +448,26 @@ String tokenKindType(TokenKind kind) => switch (kind) {
   NearestToken() => 'nearest',
   NextToken() => 'next',
   PreviousToken() => 'previous',
+  BetweenToken() => 'between',
+  AndToken() => 'and',
+  IsoToken() => 'iso',
   YearToken() => 'year',
   DayToken() => 'day',
   WeekdayKeyToken() => 'weekday',
   WeekendKeyToken() => 'weekend',
   WeeksToken() => 'weeks',
   MonthToken() => 'month',
+  FrequencyToken() => 'frequency',
+  PeriodToken() => 'period',
+  RelativeToken() => 'relative',
   CommaToken() => 'comma',
   DayNameToken() => 'dayName',
   MonthNameToken() => 'monthName',
   OrdinalToken() => 'ordinal',
   IntervalUnitToken() => 'intervalUnit',
   NumberToken() => 'number',
+  DecimalToken() => 'decimal',
+  MinuteOffsetToken() => 'minuteOffset',
   OrdinalNumberToken() => 'ordinalNumber',
   TimeToken() => 'time',
   IsoDateToken() => 'isoDate',
@@ -308,6 +480,9 @@ final _keywordMap = <String, TokenKind>{
   'at': AtToken(),
   'from': FromToken(),
   'to': ToToken(),
+  'through': ThroughToken(),
+  'business': BusinessToken(),
+  'past': PastToken(),
   'in': InToken(),
   'of': OfToken(),
   'the': TheToken(),
@@ -319,6 +494,9 @@ final _keywordMap = <String, TokenKind>{
   'nearest': NearestToken(),
   'next': NextToken(),
   'previous': PreviousToken(),
+  'between': BetweenToken(),
+  'and': AndToken(),
+  'iso': IsoToken(),
   'year': YearToken(),
   'years': YearToken(),
   'day': DayToken(),
@@ -331,19 +509,37 @@ final _keywordMap = <String, TokenKind>{
   'week': WeeksToken(),
   'month': MonthToken(),
   'months': MonthToken(),
+  'daily': FrequencyToken('daily'),
+  'weekly': FrequencyToken('weekly'),
+  'monthly': FrequencyToken('monthly'),
+  'yearly': FrequencyToken('yearly'),
+  'hourly': FrequencyToken('hourly'),
+  'morning': PeriodToken('morning'),
+  'afternoon': PeriodToken('afternoon'),
+  'evening': PeriodToken('evening'),
+  'night': PeriodToken('night'),
+  'today': RelativeToken('today'),
+  'now': RelativeToken('now'),
   'monday': DayNameToken(Weekday.monday),
+  'mondays': DayNameToken(Weekday.monday),
   'mon': DayNameToken(Weekday.monday),
   'tuesday': DayNameToken(Weekday.tuesday),
+  'tuesdays': DayNameToken(Weekday.tuesday),
   'tue': DayNameToken(Weekday.tuesday),
   'wednesday': DayNameToken(Weekday.wednesday),
+  'wednesdays': DayNameToken(Weekday.wednesday),
   'wed': DayNameToken(Weekday.wednesday),
   'thursday': DayNameToken(Weekday.thursday),
+  'thursdays': DayNameToken(Weekday.thursday),
   'thu': DayNameToken(Weekday.thursday),
   'friday': DayNameToken(Weekday.friday),
+  'fridays': DayNameToken(Weekday.friday),
   'fri': DayNameToken(Weekday.friday),
   'saturday': DayNameToken(Weekday.saturday),
+  'saturdays': DayNameToken(Weekday.saturday),
   'sat': DayNameToken(Weekday.saturday),
   'sunday': DayNameToken(Weekday.sunday),
+  'sundays': DayNameToken(Weekday.sunday),
   'sun': DayNameToken(Weekday.sunday),
   'january': MonthNameToken(MonthName.jan),
   'jan': MonthNameToken(MonthName.jan),
@@ -368,11 +564,6 @@ final _keywordMap = <String, TokenKind>{
   'nov': MonthNameToken(MonthName.nov),
   'december': MonthNameToken(MonthName.dec),
   'dec': MonthNameToken(MonthName.dec),
-  'first': OrdinalToken(OrdinalPosition.first),
-  'second': OrdinalToken(OrdinalPosition.second),
-  'third': OrdinalToken(OrdinalPosition.third),
-  'fourth': OrdinalToken(OrdinalPosition.fourth),
-  'fifth': OrdinalToken(OrdinalPosition.fifth),
   'min': IntervalUnitToken(IntervalUnit.min),
   'mins': IntervalUnitToken(IntervalUnit.min),
   'minute': IntervalUnitToken(IntervalUnit.min),
@@ -383,6 +574,45 @@ final _keywordMap = <String, TokenKind>{
   'hrs': IntervalUnitToken(IntervalUnit.hours),
 };
 
+const _ordinalUnits = [
+  'first',
+  'second',
+  'third',
+  'fourth',
+  'fifth',
+  'sixth',
+  'seventh',
+  'eighth',
+  'ninth',
+];
+
+const _ordinalTeens = [
+  'tenth',
+  'eleventh',
+  'twelfth',
+  'thirteenth',
+  'fourteenth',
+  'fifteenth',
+  'sixteenth',
+  'seventeenth',
+  'eighteenth',
+  'nineteenth',
+];
+
+/// Day number of an ordinal word: `first` is 1, `thirty-first` is 31.
+int? ordinalWordValue(String word) {
+  if (word == 'twentieth') return 20;
+  if (word == 'thirtieth') return 30;
+  if (word == 'thirty-first') return 31;
+  if (word.startsWith('twenty-')) {
+    final unit = _ordinalUnits.indexOf(word.substring('twenty-'.length));
+    return unit == -1 ? null : 21 + unit;
+  }
+  if (_ordinalUnits.contains(word)) return 1 + _ordinalUnits.indexOf(word);
+  if (_ordinalTeens.contains(word)) return 10 + _ordinalTeens.indexOf(word);
+  return null;
+}
+
 bool _isDigit(String ch) => ch.codeUnitAt(0) >= 48 && ch.codeUnitAt(0) <= 57;
 
 bool _isAlpha(String ch) {
@@ -1,7 +1,17 @@
 import 'ast.dart';
+import 'cron.dart';
 import 'error.dart';
 import 'lexer.dart';
 
+// Input-only sugar: "in the <period>" fires at a fixed time and displays as
+// HH:MM.
+const _periodTimes = {
+  'morning': TimeOfDay(9, 0),
+  'afternoon': TimeOfDay(14, 0),
+  'evening': TimeOfDay(18, 0),
+  'night': TimeOfDay(21, 0),
+};
+
 class _Parser {
   final List<Token> tokens;
   final String input;
@@ -66,8 +76,18 @@ class _Parser {
     } else if (kind is OnToken) {
       advance();
       expr = _parseOn();
+    } else if (kind is FrequencyToken) {
+      expr = _parseFrequency();
+    } else if (kind is ShortcutToken) {
+      expr = _parseShortcut();
+    } else if (kind is AtToken) {
+      advance();
+      expr = _parsePastEveryHour();
     } else {
-      throw error("expected 'every' or 'on'", span);
+      throw error(
+        "expected 'every', 'on', 'at', or a frequency keyword",
+        span,
+      );
     }
 
     return _parseTrailingClauses(expr);
@@ -96,18 +116,36 @@ class _Parser {
         _validateIsoDate(k.date);
         schedule.anchor = k.date;
         advance();
+      } else if (k is MonthNameToken) {
+        advance();
+        final dayPos = currentSpan();
+        final day = _parseDayNumber(
+          'expected day number after month name in starting',
+        );
+        _validateNamedDate(k.name, day, dayPos);
+        schedule.anchorNamed = NamedDate(k.name, day);
+      } else if (k is RelativeToken) {
+        schedule.anchor = k.word;
+        advance();
       } else {
         throw error(
-          "expected ISO date (YYYY-MM-DD) after 'starting'",
+          "expected ISO date (YYYY-MM-DD), month-day, 'today', or 'now' after 'starting'",
           currentSpan(),
         );
       }
+      if (peekKind() is AtToken) {
+        if (schedule.anchor == 'now') {
+          throw error("'starting now' already includes a time", currentSpan());
+        }
+        advance();
+        schedule.anchorTime = _parseTime();
+      }
     }
 
     // during
     if (peekKind() is DuringToken) {
       advance();
-      schedule.during = _parseMonthList();
+      schedule.during = _parseDuringList();
     }
 
     // in <timezone>
@@ -122,24 +160,84 @@ class _Parser {
       }
     }
 
+    _checkDayStepsReachable(schedule);
     return schedule;
   }
 
+  /// Reject a day step whose filter excludes every aligned day. A step that is
+  /// a multiple of 7 lands on the anchor's weekday every time, so a filter
+  /// without that weekday never fires. With a relative anchor
+  /// (`starting today`, `starting now`, or a month-day) that weekday depends on
+  /// the evaluation date, so such a step needs a filter that lets every day
+  /// through.
+  void _checkDayStepsReachable(ScheduleData schedule) {
+    final (interval, filter) = switch (schedule.expr) {
+      DayRepeat(interval: final n, :final days) => (n, days),
+      IntervalRepeat(
+        interval: final n,
+        unit: IntervalUnit.days,
+        dayFilter: final DayFilter days,
+      ) =>
+        (n, days),
+      _ => (0, null),
+    };
+    if (filter == null || interval % 7 != 0) return;
+    final allowed = switch (filter) {
+      EveryDay() => Weekday.values,
+      WeekdayFilter() => Weekday.values.sublist(0, 5),
+      WeekendFilter() => const [Weekday.saturday, Weekday.sunday],
+      SpecificDays(days: final days) => days,
+    };
+    if (schedule.hasRelativeAnchor) {
+      if (allowed.toSet().length == 7) return;
+      throw HronError.parse(
+        'every $interval days starting ${schedule.anchorText} lands on the weekday of the evaluation date, which the day filter may exclude',
+        Span(0, input.length),
+        input,
+      );
+    }
+    final anchor = DateTime.parse(schedule.anchor ?? '1970-01-01');
+    final weekday = Weekday.fromNumber(anchor.weekday);
+    if (allowed.contains(weekday)) return;
+    throw HronError.parse(
+      'every $interval days always lands on a ${weekday.name}, which the day filter excludes',
+      Span(0, input.length),
+      input,
+    );
+  }
+
+  // "except [on] exception, ...": dates, day names, "weekday", "weekend"
   List<ExceptionSpec> _parseExceptionList() {
-    final exceptions = <ExceptionSpec>[_parseException()];
+    if (peekKind() is OnToken) {
+      advance();
+    }
+    final exceptions = _parseException();
     while (peekKind() is CommaToken) {
       advance();
-      exceptions.add(_parseException());
+      exceptions.addAll(_parseException());
     }
     return exceptions;
   }
 
-  ExceptionSpec _parseException() {
+  List<ExceptionSpec> _parseException() {
     final k = peekKind();
     if (k is IsoDateToken) {
       _validateIsoDate(k.date);
       advance();
-      return IsoException(k.date);
+      if (!_atRangeSeparator()) return [IsoException(k.date)];
+      advance();
+      final span = currentSpan();
+      final end = peekKind();
+      if (end is! IsoDateToken) {
+        throw error("expected ISO date after 'to'", span);
+      }
+      _validateIsoDate(end.date);
+      // ISO dates order lexically
+      if (end.date.compareTo(k.date) < 0) {
+        throw error('exception range ends before ${k.date}', span);
+      }
+      advance();
+      return [IsoRangeException(k.date, end.date)];
     }
     if (k is MonthNameToken) {
       advance();
@@ -147,9 +245,40 @@ class _Parser {
         'expected day number after month name in exception',
       );
       _validateNamedDate(k.name, day, currentSpan());
-      return NamedException(k.name, day);
+      if (!_atRangeSeparator()) return [NamedException(k.name, day)];
+      advance();
+      final end = peekKind();
+      if (end is! MonthNameToken) {
+        throw error("expected month name after 'to'", currentSpan());
+      }
+      advance();
+      final endDay = _parseDayNumber(
+        'expected day number after month name in exception',
+      );
+      _validateNamedDate(end.name, endDay, currentSpan());
+      return [NamedRangeException(k.name, day, end.name, endDay)];
+    }
+    if (k is DayNameToken) {
+      advance();
+      return [WeekdayException(k.name)];
+    }
+    if (k is WeekdayKeyToken) {
+      advance();
+      return [
+        for (final day in Weekday.values.sublist(0, 5)) WeekdayException(day),
+      ];
     }
-    throw error('expected ISO date or month-day in exception', currentSpan());
+    if (k is WeekendKeyToken) {
+      advance();
+      return [
+        WeekdayException(Weekday.saturday),
+        WeekdayException(Weekday.sunday),
+      ];
+    }
+    throw error(
+      'expected ISO date, month-day, or day name in exception',
+      currentSpan(),
+    );
   }
 
   UntilSpec _parseUntilSpec() {
@@ -202,6 +331,7 @@ class _Parser {
       return _parseYearRepeat(1);
     }
     if (k is DayToken) {
+      advance();
       return _parseDayRepeat(1, EveryDay());
     }
     if (k is WeekdayKeyToken) {
@@ -214,7 +344,14 @@ class _Parser {
     }
     if (k is DayNameToken) {
       final days = _parseDayList();
-      return _parseDayRepeat(1, SpecificDays(days));
+      // "every monday every 2 weeks at ..." is a week repeat
+      if (peekKind() is EveryToken) {
+        advance();
+        final interval = _parseWeekInterval();
+        final times = _parseTimesOrMidnight();
+        return WeekRepeat(interval, days, times);
+      }
+      return _parseDayRepeat(1, canonicalDayFilter(SpecificDays(days)));
     }
     if (k is WeeksToken) {
       advance();
@@ -224,9 +361,17 @@ class _Parser {
       advance();
       return _parseMonthRepeat(1);
     }
+    // "every hour at :15", "every minute from 09:00 to 10:00"
+    if (k is IntervalUnitToken) {
+      return _parseIntervalRepeat(1);
+    }
     if (k is NumberToken) {
       return _parseNumberRepeat();
     }
+    // "every 1.5 hours from ..."
+    if (k is DecimalToken) {
+      return _parseFractionalInterval();
+    }
 
     throw error(
       "expected day, weekday, weekend, week, year, day name, month, or number after 'every'",
@@ -234,12 +379,67 @@ class _Parser {
     );
   }
 
-  ScheduleExpr _parseDayRepeat(int interval, DayFilter days) {
-    if (days is EveryDay) {
-      consumeKind("'day'", (k) => k is DayToken);
+  // Cron `@` shortcut: "@daily" is "every day at 00:00", and takes the same
+  // trailing clauses as that form ("@daily in UTC").
+  ScheduleExpr _parseShortcut() {
+    final span = currentSpan();
+    final k = peekKind()! as ShortcutToken;
+    advance();
+    final expr = cronShortcutExpr(k.name);
+    if (expr == null) {
+      throw HronError.parse(
+        'unknown @ shortcut: ${k.name}',
+        span,
+        input,
+        suggestion:
+            '@yearly, @monthly, @weekly, @daily, @midnight, or @hourly',
+      );
     }
-    consumeKind("'at'", (k) => k is AtToken);
-    final times = _parseTimeList();
+    return expr;
+  }
+
+  // Bare shorthand: "daily", "weekly", "monthly", "yearly", or "hourly". Each
+  // maps to the canonical "every ..." form with interval 1 and accepts what
+  // that form does. Without "on", weekly falls on monday (the default week
+  // start), monthly on the 1st, and yearly on jan 1; without a window, hourly
+  // runs all day.
+  ScheduleExpr _parseFrequency() {
+    final k = peekKind()! as FrequencyToken;
+    advance();
+
+    final next = peekKind();
+    final hasOn = next is OnToken;
+    // "yearly in iso weeks ..." as well as "yearly on ..."
+    final yearlyRepeat =
+        hasOn ||
+        (next is InToken &&
+            pos + 1 < tokens.length &&
+            tokens[pos + 1].kind is IsoToken);
+    return switch (k.word) {
+      'daily' => _parseDayRepeat(1, EveryDay()),
+      'weekly' when hasOn => _parseWeekRepeat(1),
+      'weekly' => WeekRepeat(1, [Weekday.monday], _parseTimesOrMidnight()),
+      'monthly' when hasOn => _parseMonthRepeat(1),
+      'monthly' => MonthRepeat(
+        1,
+        DaysTarget([SingleDay(1)]),
+        _parseTimesOrMidnight(),
+      ),
+      'yearly' when yearlyRepeat => _parseYearRepeat(1),
+      'yearly' => YearRepeat(
+        1,
+        DateTarget(MonthName.jan, 1),
+        _parseTimesOrMidnight(),
+      ),
+      // "hourly at :15" and "hourly from 09:00 to 17:00" as after "every hour"
+      _ when next is AtToken || next is FromToken || next is BetweenToken =>
+        _parseIntervalWindow(1, IntervalUnit.hours),
+      _ => _hourlyAt(1, 0, _parseOptionalDayFilter()),
+    };
+  }
+
+  ScheduleExpr _parseDayRepeat(int interval, DayFilter days) {
+    final times = _parseTimesOrMidnight();
     return DayRepeat(interval, days, times);
   }
 
@@ -261,8 +461,27 @@ class _Parser {
     if (next is IntervalUnitToken) {
       return _parseIntervalRepeat(num);
     }
+    // "every N days from HH:MM to HH:MM" — day-step interval window
+    if (next is DayToken && _nextIsWindow()) {
+      advance();
+      return _parseIntervalWindow(num, IntervalUnit.days);
+    }
+    // "every N days [on day_target] at ..."
     if (next is DayToken) {
-      return _parseDayRepeat(num, EveryDay());
+      advance();
+      DayFilter days = EveryDay();
+      if (peekKind() is OnToken) {
+        advance();
+        days = _parseDayTarget();
+      }
+      return _parseDayRepeat(num, days);
+    }
+    // "every N weekdays at ..." — every Nth business day
+    if (next is WeekdayKeyToken) {
+      advance();
+      if (num == 1) return _parseDayRepeat(1, WeekdayFilter());
+      final times = _parseTimesOrMidnight();
+      return WeekdayRepeat(num, times);
     }
     if (next is MonthToken) {
       advance();
@@ -274,7 +493,7 @@ class _Parser {
     }
 
     throw error(
-      "expected 'weeks', 'days', 'months', 'years', 'min', 'minutes', 'hour', or 'hours' after number",
+      "expected 'weeks', 'days', 'weekdays', 'months', 'years', 'min', 'minutes', 'hour', or 'hours' after number",
       currentSpan(),
     );
   }
@@ -283,27 +502,123 @@ class _Parser {
     final k = peekKind()! as IntervalUnitToken;
     advance();
 
-    final unit = k.unit;
+    return _parseIntervalWindow(interval, k.unit);
+  }
+
+  // "every D hours ..." where D is a decimal: converted to whole minutes, so
+  // "every 1.5 hours" is the same schedule as "every 90 min"
+  ScheduleExpr _parseFractionalInterval() {
+    final span = currentSpan();
+    final k = peekKind();
+    if (k is! DecimalToken) throw error('expected number', span);
+    advance();
+
+    final next = peekKind();
+    if (next is! IntervalUnitToken || next.unit == IntervalUnit.min) {
+      throw error('fractional intervals are only supported in hours', span);
+    }
+    advance();
 
-    consumeKind("'from'", (k) => k is FromToken);
-    final from = _parseTime();
-    consumeKind("'to'", (k) => k is ToToken);
-    final to = _parseTime();
+    final minutes = _fractionalHoursToMinutes(k.text);
+    if (minutes == null) {
+      throw error(
+        'fractional hours must come to a whole number of minutes',
+        span,
+      );
+    }
+    if (minutes == 0) {
+      throw error('interval must be at least 1', span);
+    }
 
-    DayFilter? dayFilter;
-    if (peekKind() is OnToken) {
+    if (minutes % 60 == 0) {
+      return _parseIntervalWindow(minutes ~/ 60, IntervalUnit.hours);
+    }
+    return _parseIntervalWindow(minutes, IntervalUnit.min);
+  }
+
+  // The window and optional day filter after the interval unit
+  ScheduleExpr _parseIntervalWindow(int interval, IntervalUnit unit) {
+    // "every 2 hours at :15": all day, starting MM past midnight
+    if (unit == IntervalUnit.hours && peekKind() is AtToken) {
       advance();
-      dayFilter = _parseDayTarget();
+      final minute = _parseMinutePast();
+      return _hourlyAt(interval, minute, _parseOptionalDayFilter());
     }
 
+    // "from HH:MM to HH:MM" or "between HH:MM and HH:MM"
+    TimeOfDay from;
+    TimeOfDay to;
+    if (peekKind() is BetweenToken) {
+      advance();
+      from = _parseTime();
+      consumeKind("'and'", (k) => k is AndToken);
+      to = _parseTime();
+    } else {
+      consumeKind("'from' or 'between'", (k) => k is FromToken);
+      from = _parseTime();
+      consumeKind("'to'", (k) => k is ToToken);
+      to = _parseTime();
+    }
+
+    final dayFilter = _parseOptionalDayFilter();
     return IntervalRepeat(interval, unit, from, to, dayFilter);
   }
 
+  // Optional "on day_target" after an interval window
+  DayFilter? _parseOptionalDayFilter() {
+    if (peekKind() is! OnToken) return null;
+    advance();
+    return _parseDayTarget();
+  }
+
+  // "at 15 past every hour", "at :15 past every hour": "at" already consumed
+  ScheduleExpr _parsePastEveryHour() {
+    final minute = _parseMinutePast(allowNumber: true);
+    consumeKind("'past'", (k) => k is PastToken);
+    consumeKind("'every'", (k) => k is EveryToken);
+    final k = peekKind();
+    if (k is! IntervalUnitToken || k.unit != IntervalUnit.hours) {
+      throw error("expected 'hour'", currentSpan());
+    }
+    advance();
+    return _hourlyAt(1, minute, _parseOptionalDayFilter());
+  }
+
+  // Minutes past the hour, as ":MM" or (with [allowNumber]) a bare number
+  int _parseMinutePast({bool allowNumber = false}) {
+    final k = peekKind();
+    final int minute;
+    if (k is MinuteOffsetToken) {
+      minute = k.minute;
+    } else if (allowNumber && k is NumberToken && k.value <= 59) {
+      minute = k.value;
+    } else {
+      throw error('expected minutes past the hour (e.g. :15)', currentSpan());
+    }
+    advance();
+    return minute;
+  }
+
+  // "week" or "N weeks", after a day list and a second "every"
+  int _parseWeekInterval() {
+    final span = currentSpan();
+    final k = peekKind();
+    var interval = 1;
+    if (k is NumberToken) {
+      if (k.value == 0) {
+        throw error('interval must be at least 1', span);
+      }
+      interval = k.value;
+      advance();
+    }
+    consumeKind("'week' or 'weeks'", (k) => k is WeeksToken);
+    return interval;
+  }
+
   ScheduleExpr _parseWeekRepeat(int interval) {
     consumeKind("'on'", (k) => k is OnToken);
     final days = _parseDayList();
-    consumeKind("'at'", (k) => k is AtToken);
-    final times = _parseTimeList();
+    final times = _parseTimesOrMidnight();
     return WeekRepeat(interval, days, times);
   }
 
@@ -314,39 +629,39 @@ class _Parser {
     MonthTarget target;
     final k = peekKind();
 
-    if (k is LastToken) {
+    // "the first monday", "the first and last friday", as opposed to
+    // "the first" (day 1)
+    if ((k is OrdinalToken || k is LastToken) && _ordinalWeekdaysAhead()) {
+      target = _parseOrdinalWeekdays();
+    } else if (k is LastToken) {
       advance();
       final next = peekKind();
       if (next is DayToken) {
         advance();
         target = LastDayTarget();
+      } else if (next is NumberToken) {
+        // "last N days"; "last 1 day" is the last day
+        final n = next.value;
+        if (n < 1 || n > 31) {
+          throw error('invalid day count: $n (must be 1-31)', currentSpan());
+        }
+        advance();
+        consumeKind("'days'", (k) => k is DayToken);
+        target = n == 1 ? LastDayTarget() : LastNDaysTarget(n);
       } else if (next is WeekdayKeyToken) {
         advance();
         target = LastWeekdayTarget();
-      } else if (next is DayNameToken) {
-        final weekday = next.name;
-        advance();
-        target = OrdinalWeekdayMonthTarget(OrdinalPosition.last, weekday);
       } else {
         throw error(
           "expected 'day', 'weekday', or day name after 'last'",
           currentSpan(),
         );
       }
-    } else if (k is OrdinalToken) {
-      final ordinal = _parseOrdinalPosition();
-      final next = peekKind();
-      if (next is DayNameToken) {
-        final weekday = next.name;
-        advance();
-        target = OrdinalWeekdayMonthTarget(ordinal, weekday);
-      } else {
-        throw error(
-          'expected day name after ordinal in monthly expression',
-          currentSpan(),
-        );
-      }
-    } else if (k is OrdinalNumberToken) {
+    } else if ((k is OrdinalToken || k is OrdinalNumberToken) &&
+        _nextIsBusinessDay()) {
+      // "the first weekday", "the 3rd business day"
+      target = _parseBusinessDayTarget();
+    } else if (k is OrdinalToken || k is OrdinalNumberToken) {
       final specs = _parseOrdinalDayList();
       target = DaysTarget(specs);
     } else if (k is NextToken || k is PreviousToken || k is NearestToken) {
@@ -358,11 +673,59 @@ class _Parser {
       );
     }
 
-    consumeKind("'at'", (k) => k is AtToken);
-    final times = _parseTimeList();
+    final times = _parseTimes();
     return MonthRepeat(interval, target, times);
   }
 
+  // Whether a list of ordinals joined by "," or "and" ends in a day name
+  bool _ordinalWeekdaysAhead() {
+    var i = pos;
+    while (i < tokens.length) {
+      final ordinal = tokens[i].kind;
+      if (ordinal is! OrdinalToken && ordinal is! LastToken) return false;
+      i++;
+      if (i >= tokens.length) return false;
+      final next = tokens[i].kind;
+      if (next is DayNameToken) return true;
+      if (next is! CommaToken && next is! AndToken) return false;
+      i++;
+    }
+    return false;
+  }
+
+  MonthTarget _parseOrdinalWeekdays() {
+    final ordinals = {_parseOrdinalPosition()};
+    while (peekKind() is CommaToken || peekKind() is AndToken) {
+      advance();
+      ordinals.add(_parseOrdinalPosition());
+    }
+    final weekday = _parseDayName(
+      'expected day name after ordinal in monthly expression',
+    );
+    final sorted = ordinals.toList()..sort((a, b) => a.index - b.index);
+    if (sorted.length == 1) {
+      return OrdinalWeekdayMonthTarget(sorted.single, weekday);
+    }
+    return OrdinalWeekdaysMonthTarget(sorted, weekday);
+  }
+
+  /// Parse `<ordinal> (weekday | business day)`
+  MonthTarget _parseBusinessDayTarget() {
+    final span = currentSpan();
+    final n = _parseOrdinalDay("expected ordinal before 'business day'");
+    // No month has more than 23 weekdays
+    if (n > 23) {
+      throw error('invalid business day: $n (must be 1-23)', span);
+    }
+    if (peekKind() is BusinessToken) {
+      advance();
+      consumeKind("'day'", (k) => k is DayToken);
+    } else {
+      consumeKind("'weekday'", (k) => k is WeekdayKeyToken);
+    }
+    return BusinessDayTarget(n);
+  }
+
   /// Parse [next|previous] nearest weekday to `<day>`
   MonthTarget _parseNearestWeekdayTarget() {
     // Optional direction: "next" or "previous"
@@ -384,20 +747,55 @@ class _Parser {
     return NearestWeekdayTarget(day, direction);
   }
 
-  int _parseOrdinalDayNumber() {
+  int _parseOrdinalDayNumber() =>
+      _parseOrdinalDay('expected ordinal day number');
+
+  /// Parse an ordinal day of month, as digits (21st) or a word (twentieth).
+  int _parseOrdinalDay(String expected) {
     final k = peekKind();
+    final int val;
     if (k is OrdinalNumberToken) {
-      final val = k.value;
-      if (val < 1 || val > 31) {
-        throw error('invalid day number $val (must be 1-31)', currentSpan());
-      }
-      advance();
-      return val;
+      val = k.value;
+    } else if (k is OrdinalToken) {
+      val = k.value;
+    } else {
+      throw error(expected, currentSpan());
     }
-    throw error('expected ordinal day number', currentSpan());
+    if (val < 1 || val > 31) {
+      throw error('invalid day number $val (must be 1-31)', currentSpan());
+    }
+    advance();
+    return val;
+  }
+
+  bool _nextIsDayName() =>
+      pos + 1 < tokens.length && tokens[pos + 1].kind is DayNameToken;
+
+  bool _nextIsWindow() {
+    if (pos + 1 >= tokens.length) return false;
+    final next = tokens[pos + 1].kind;
+    return next is FromToken || next is BetweenToken;
+  }
+
+  bool _nextIsBusinessDay() {
+    if (pos + 1 >= tokens.length) return false;
+    final next = tokens[pos + 1].kind;
+    return next is BusinessToken || next is WeekdayKeyToken;
+  }
+
+  Weekday _parseWeekdayToken() {
+    final k = peekKind();
+    if (k is! DayNameToken) {
+      throw error('expected day name', currentSpan());
+    }
+    advance();
+    return k.name;
   }
 
   ScheduleExpr _parseYearRepeat(int interval) {
+    if (peekKind() is InToken) {
+      return _parseIsoWeekRepeat(interval);
+    }
     consumeKind("'on'", (k) => k is OnToken);
 
     YearTarget target;
@@ -419,11 +817,47 @@ class _Parser {
       );
     }
 
-    consumeKind("'at'", (k) => k is AtToken);
-    final times = _parseTimeList();
+    final times = _parseTimes();
     return YearRepeat(interval, target, times);
   }
 
+  // iso_week_repeat: "every year in iso week[s] N[, N] on day_list [at HH:MM]"
+  ScheduleExpr _parseIsoWeekRepeat(int interval) {
+    if (interval != 1) {
+      throw error(
+        'ISO week schedules repeat every year; remove the interval',
+        currentSpan(),
+      );
+    }
+    consumeKind("'in'", (k) => k is InToken);
+    consumeKind("'iso'", (k) => k is IsoToken);
+    consumeKind("'weeks'", (k) => k is WeeksToken);
+
+    final weeks = <int>[_parseIsoWeekNumber()];
+    while (peekKind() is CommaToken) {
+      advance();
+      weeks.add(_parseIsoWeekNumber());
+    }
+
+    consumeKind("'on'", (k) => k is OnToken);
+    final days = _parseDayList();
+    final times = _parseTimesOrMidnight();
+    return IsoWeekRepeat(weeks, days, times);
+  }
+
+  int _parseIsoWeekNumber() {
+    final span = currentSpan();
+    final k = peekKind();
+    if (k is! NumberToken) {
+      throw error('expected ISO week number', span);
+    }
+    if (k.value < 1 || k.value > 53) {
+      throw error('invalid ISO week ${k.value} (expected 1-53)', span);
+    }
+    advance();
+    return k.value;
+  }
+
   YearTarget _parseYearTargetAfterThe() {
     final k = peekKind();
 
@@ -449,26 +883,18 @@ class _Parser {
       );
     }
 
-    if (k is OrdinalToken) {
+    // "the first monday of march" or "the 15th of march"
+    if (k is OrdinalToken && _nextIsDayName()) {
       final ordinal = _parseOrdinalPosition();
-      final next = peekKind();
-      if (next is DayNameToken) {
-        final weekday = next.name;
-        advance();
-        consumeKind("'of'", (k) => k is OfToken);
-        final month = _parseMonthNameToken();
-        return OrdinalWeekdayTarget(ordinal, weekday, month);
-      }
-      throw error(
-        'expected day name after ordinal in yearly expression',
-        currentSpan(),
-      );
+      final weekday = _parseWeekdayToken();
+      consumeKind("'of'", (k) => k is OfToken);
+      final month = _parseMonthNameToken();
+      return OrdinalWeekdayTarget(ordinal, weekday, month);
     }
 
-    if (k is OrdinalNumberToken) {
-      final day = k.value;
+    if (k is OrdinalToken || k is OrdinalNumberToken) {
       final daySpan = currentSpan();
-      advance();
+      final day = _parseOrdinalDayNumber();
       consumeKind("'of'", (k) => k is OfToken);
       final month = _parseMonthNameToken();
       _validateNamedDate(month, day, daySpan);
@@ -495,8 +921,11 @@ class _Parser {
     final k = peekKind();
 
     if (k is OrdinalToken) {
+      if (k.value > 5) {
+        throw error("unknown ordinal '${k.word}'", span);
+      }
       advance();
-      return k.name;
+      return OrdinalPosition.values[k.value - 1];
     }
     if (k is LastToken) {
       advance();
@@ -509,28 +938,26 @@ class _Parser {
   }
 
   ScheduleExpr _parseOn() {
+    // "on friday at 17:00": the nearest upcoming friday, a rolling one-shot
+    if (peekKind() is DayNameToken) {
+      final day = _parseWeekdayToken();
+      if (peekKind() is CommaToken) {
+        throw error(
+          "a weekday cannot be combined with other dates; use 'every' for recurring days",
+          currentSpan(),
+        );
+      }
+      final times = _parseTimes();
+      return SingleDate(NextWeekdayDate(day), times);
+    }
+
     final date = _parseDateTarget();
-    consumeKind("'at'", (k) => k is AtToken);
-    final times = _parseTimeList();
+    final times = _parseTimes();
     return SingleDate(date, times);
   }
 
   void _validateNamedDate(MonthName month, int day, Span span) {
-    final maxDays = {
-      MonthName.jan: 31,
-      MonthName.feb: 29,
-      MonthName.mar: 31,
-      MonthName.apr: 30,
-      MonthName.may: 31,
-      MonthName.jun: 30,
-      MonthName.jul: 31,
-      MonthName.aug: 31,
-      MonthName.sep: 30,
-      MonthName.oct: 31,
-      MonthName.nov: 30,
-      MonthName.dec: 31,
-    };
-    final max = maxDays[month]!;
+    final max = month.maxDay;
     if (day < 1 || day > max) {
       throw error('invalid day $day for ${month.name} (max $max)', span);
     }
@@ -585,7 +1012,7 @@ class _Parser {
     }
     if (k is DayNameToken) {
       final days = _parseDayList();
-      return SpecificDays(days);
+      return canonicalDayFilter(SpecificDays(days));
     }
     throw error(
       "expected 'day', 'weekday', 'weekend', or day name",
@@ -594,58 +1021,63 @@ class _Parser {
   }
 
   List<Weekday> _parseDayList() {
+    final days = _parseDayRange('expected day name');
+    while (peekKind() is CommaToken) {
+      advance();
+      days.addAll(_parseDayRange("expected day name after ','"));
+    }
+    return days;
+  }
+
+  // A day name or a range of them: "monday to friday", "fri through mon"
+  // (wraps past sunday)
+  List<Weekday> _parseDayRange(String expected) {
+    final start = _parseDayName(expected);
+    if (!_atRangeSeparator()) return [start];
+    advance();
+    final end = _parseDayName("expected day name after 'to'");
+    final length = (end.number + 7 - start.number) % 7 + 1;
+    return [
+      for (var i = 0; i < length; i++)
+        Weekday.fromNumber((start.number - 1 + i) % 7 + 1),
+    ];
+  }
+
+  Weekday _parseDayName(String expected) {
     final k = peekKind();
     if (k is! DayNameToken) {
-      throw error('expected day name', currentSpan());
+      throw error(expected, currentSpan());
     }
-    final days = <Weekday>[k.name];
     advance();
+    return k.name;
+  }
 
-    while (peekKind() is CommaToken) {
-      advance();
-      final next = peekKind();
-      if (next is! DayNameToken) {
-        throw error("expected day name after ','", currentSpan());
-      }
-      days.add(next.name);
-      advance();
-    }
-    return days;
+  /// Whether the next token separates a range: `to`, or its synonym
+  /// `through`. `through` is only accepted in ranges, never in
+  /// `from ... to ...` windows.
+  bool _atRangeSeparator() {
+    final k = peekKind();
+    return k is ToToken || k is ThroughToken;
   }
 
+  // Day specs are sorted and de-duplicated: "the 15th, 1st, 15th" is
+  // "the 1st, 15th"
   List<DayOfMonthSpec> _parseOrdinalDayList() {
     final specs = <DayOfMonthSpec>[_parseOrdinalDaySpec()];
     while (peekKind() is CommaToken) {
       advance();
       specs.add(_parseOrdinalDaySpec());
     }
-    return specs;
+    return _sortedUnique(specs, _daySpecKey);
   }
 
   DayOfMonthSpec _parseOrdinalDaySpec() {
-    final k = peekKind();
-    if (k is! OrdinalNumberToken) {
-      throw error('expected ordinal day number', currentSpan());
-    }
-    final start = k.value;
-    final startSpan = currentSpan();
-    if (start < 1 || start > 31) {
-      throw error('invalid day number $start (must be 1-31)', startSpan);
-    }
-    advance();
+    final start = _parseOrdinalDayNumber();
 
-    if (peekKind() is ToToken) {
-      advance();
-      final next = peekKind();
-      if (next is! OrdinalNumberToken) {
-        throw error("expected ordinal day number after 'to'", currentSpan());
-      }
-      final end = next.value;
-      final endSpan = currentSpan();
-      if (end < 1 || end > 31) {
-        throw error('invalid day number $end (must be 1-31)', endSpan);
-      }
+    // "1st to 15th", "1st through 15th"
+    if (_atRangeSeparator()) {
       advance();
+      final end = _parseOrdinalDay("expected ordinal day number after 'to'");
       if (start > end) {
         throw error(
           'invalid day range: $start to $end (start must be <= end)',
@@ -658,22 +1090,99 @@ class _Parser {
     return SingleDay(start);
   }
 
-  List<MonthName> _parseMonthList() {
-    final months = <MonthName>[_parseMonthNameToken()];
+  // Specs are sorted by where they start and de-duplicated: "during mar,
+  // jan, mar" is "during jan, mar". Overlapping specs are not merged.
+  List<DuringSpec> _parseDuringList() {
+    final specs = <DuringSpec>[_parseDuringSpec()];
     while (peekKind() is CommaToken) {
       advance();
-      months.add(_parseMonthNameToken());
+      specs.add(_parseDuringSpec());
     }
-    return months;
+    return _sortedUnique(specs, _duringSpecKey);
   }
 
+  // during_spec: "jun", "jun to aug" (wraps past december: "nov to feb"),
+  // or month-day bounds "jun 15 to aug 31". A bound without a day covers
+  // its whole month.
+  DuringSpec _parseDuringSpec() {
+    final start = _parseMonthNameToken();
+    final startDay = _parseDuringDay(start);
+    if (!_atRangeSeparator()) {
+      // "during jun 15" is that one day
+      return DuringSpec(start, start, startDay, startDay);
+    }
+    advance();
+    final end = _parseMonthNameToken();
+    final endDay = _parseDuringDay(end);
+    if (startDay == null && endDay == null) {
+      return DuringSpec(start, end);
+    }
+    return DuringSpec(start, end, startDay ?? 1, endDay ?? end.maxDay);
+  }
+
+  /// Optional day after a month name in `during`, validated for the month.
+  int? _parseDuringDay(MonthName month) {
+    final k = peekKind();
+    if (k is! NumberToken && k is! OrdinalNumberToken) {
+      return null;
+    }
+    final daySpan = currentSpan();
+    final day = _parseDayNumber(
+      'expected day number after month name in during',
+    );
+    _validateNamedDate(month, day, daySpan);
+    return day;
+  }
+
+  // times: "at HH:MM, ..." or "in the morning|afternoon|evening|night"
+  List<TimeOfDay> _parseTimes() {
+    if (peekKind() is InToken) {
+      advance();
+      consumeKind("'the'", (k) => k is TheToken);
+      final k = peekKind();
+      if (k is! PeriodToken) {
+        throw error(
+          'expected morning, afternoon, evening, or night',
+          currentSpan(),
+        );
+      }
+      advance();
+      return [_periodTimes[k.word]!];
+    }
+    consumeKind("'at'", (k) => k is AtToken);
+    return _parseTimeList();
+  }
+
+  // Day and week repeats may omit times: "every day", "every weekday in UTC"
+  // fire at 00:00. A bare "in" starts the timezone clause, not "in the ...".
+  List<TimeOfDay> _parseTimesOrMidnight() {
+    final k = peekKind();
+    final hasTimes =
+        k is AtToken ||
+        (k is InToken &&
+            pos + 1 < tokens.length &&
+            tokens[pos + 1].kind is TheToken);
+    if (hasTimes) {
+      return _parseTimes();
+    }
+    return [const TimeOfDay(0, 0)];
+  }
+
+  // Times are sorted and de-duplicated: "at 17:00, 09:00, 09:00" is
+  // "at 09:00, 17:00"
   List<TimeOfDay> _parseTimeList() {
     final times = <TimeOfDay>[_parseTime()];
     while (peekKind() is CommaToken) {
       advance();
       times.add(_parseTime());
     }
-    return times;
+    times.sort(
+      (a, b) => a.hour != b.hour ? a.hour - b.hour : a.minute - b.minute,
+    );
+    return [
+      for (var i = 0; i < times.length; i++)
+        if (i == 0 || times[i] != times[i - 1]) times[i],
+    ];
   }
 
   TimeOfDay _parseTime() {
@@ -687,6 +1196,53 @@ class _Parser {
   }
 }
 
+/// Ordering key for a day spec: by first day, then last day.
+int _daySpecKey(DayOfMonthSpec spec) => switch (spec) {
+  SingleDay(:final day) => day * 100 + day,
+  DayRange(:final start, :final end) => start * 100 + end,
+};
+
+/// Ordering key for a during spec: by first month-day, then last month-day.
+/// A month and the date range covering the same days share a key.
+int _duringSpecKey(DuringSpec spec) {
+  final (:startDay, :endDay) = duringBounds(spec);
+  final first = spec.start.number * 100 + startDay;
+  final last = spec.end.number * 100 + endDay;
+  return first * 10000 + last;
+}
+
+/// Sort [items] by [key], keeping the first written of each run of equal
+/// keys.
+List<T> _sortedUnique<T>(List<T> items, int Function(T) key) {
+  final indexed = [for (final (i, item) in items.indexed) (i, key(item), item)];
+  indexed.sort((a, b) => a.$2 != b.$2 ? a.$2 - b.$2 : a.$1 - b.$1);
+  return [
+    for (var i = 0; i < indexed.length; i++)
+      if (i == 0 || indexed[i].$2 != indexed[i - 1].$2) indexed[i].$3,
+  ];
+}
+
+/// An all-day hour step firing [minute] past the hour: `every hour at :15` is
+/// `every 1 hour from 00:15 to 23:59`.
+ScheduleExpr _hourlyAt(int interval, int minute, DayFilter? dayFilter) =>
+    IntervalRepeat(
+      interval,
+      IntervalUnit.hours,
+      TimeOfDay(0, minute),
+      const TimeOfDay(23, 59),
+      dayFilter,
+    );
+
+/// Convert decimal hours (1.5) to whole minutes, or `null` if they don't come
+/// to a whole number of minutes.
+int? _fractionalHoursToMinutes(String text) {
+  final [whole, frac] = text.split('.');
+  final scaled = BigInt.parse(whole + frac) * BigInt.from(60);
+  final scale = BigInt.from(10).pow(frac.length);
+  if (scaled % scale != BigInt.zero) return null;
+  return (scaled ~/ scale).toInt();
+}
+
 ScheduleData parse(String input) {
   final tokens = tokenize(input);
 
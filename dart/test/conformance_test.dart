@@ -84,6 +84,31 @@ void main() {
       'timezone_clause',
       'combined_clauses',
       'case_insensitivity',
+      'ordinal_in_dates',
+      'twelve_hour_times',
+      'between_windows',
+      'frequency_keywords',
+      'time_of_day_keywords',
+      'time_list_normalization',
+      'default_midnight',
+      'weekday_exceptions',
+      'iso_weeks',
+      'during_dates',
+      'cron_shortcuts',
+      'ordinal_words',
+      'day_interval_filter',
+      'day_step_windows',
+      'last_n_days',
+      'day_list_canonicalization',
+      'fractional_hours',
+      'weekday_dates',
+      'weekday_interval',
+      'through_ranges',
+      'twelve_hour_windows',
+      'business_days',
+      'except_ranges',
+      'minutes_past_hour',
+      'ordinal_weekdays',
     ];
 
     final parseMap = spec['parse'] as Map<String, dynamic>;
@@ -208,6 +233,20 @@ void main() {
       test(name, () {
         final schedule = Schedule.parse(tc['expression'] as String);
         final dt = parseZoned(tc['datetime'] as String);
+        final errorContains = tc['error_contains'] as String?;
+        if (errorContains != null) {
+          expect(
+            () => schedule.matches(dt),
+            throwsA(
+              isA<HronError>().having(
+                (e) => e.message,
+                'message',
+                contains(errorContains),
+              ),
+            ),
+          );
+          return;
+        }
         expect(schedule.matches(dt), equals(tc['expected']));
       });
     }
@@ -341,6 +380,19 @@ void main() {
       }
     });
 
+    group('to_cron_during', () {
+      final tests =
+          (cronMap['to_cron_during'] as Map<String, dynamic>)['tests']
+              as List<dynamic>;
+      for (final tc in tests) {
+        final name = (tc['name'] ?? tc['hron']) as String;
+        test(name, () {
+          final schedule = Schedule.parse(tc['hron'] as String);
+          expect(schedule.toCron(), equals(tc['cron']));
+        });
+      }
+    });
+
     group('to_cron errors', () {
       final tests =
           (cronMap['to_cron_errors'] as Map<String, dynamic>)['tests']
@@ -299,6 +299,45 @@ void main() {
 
       expect(results.length, equals(1));
     });
+
+    test('upcoming weekday occurrence cannot be matched', () {
+      final schedule = Schedule.parse('on friday at 17:00 in UTC');
+      final from = parseZoned('2026-02-06T12:00:00+00:00[UTC]');
+
+      final results = schedule.occurrences(from).take(10).toList();
+
+      expect(results.length, equals(1));
+      expect(
+        () => schedule.matches(results.first),
+        throwsA(
+          isA<HronError>().having(
+            (e) => e.message,
+            'message',
+            contains('no evaluation time'),
+          ),
+        ),
+      );
+    });
+
+    test('matches rejects relative anchor', () {
+      final now = parseZoned('2026-02-06T12:00:00+00:00[UTC]');
+
+      for (final anchor in ['today', 'now']) {
+        final schedule = Schedule.parse(
+          'every 2 days at 09:00 starting $anchor in UTC',
+        );
+        expect(
+          () => schedule.matches(now),
+          throwsA(
+            isA<HronError>().having(
+              (e) => e.message,
+              'message',
+              contains('no evaluation time'),
+            ),
+          ),
+        );
+      }
+    });
   });
 
   // ===========================================================================
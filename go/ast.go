@@ -50,6 +50,39 @@ func (w Weekday) String() string {
 	return names[w]
 }
 
+// allWeekdays and allWeekend list the days "weekday" and "weekend" stand for.
+var (
+	allWeekdays = []Weekday{Monday, Tuesday, Wednesday, Thursday, Friday}
+	allWeekend  = []Weekday{Saturday, Sunday}
+)
+
+// canonicalDayFilter sorts and de-duplicates an explicit day list, collapsing
+// one covering exactly the weekdays or all seven days into the equivalent
+// keyword filter. "saturday, sunday" stays a list: "weekend" is a keyword of
+// its own.
+func canonicalDayFilter(f DayFilter) DayFilter {
+	if f.Kind != DayFilterKindDays {
+		return f
+	}
+	present := make(map[Weekday]bool)
+	for _, d := range f.Days {
+		present[d] = true
+	}
+	var days []Weekday
+	for n := 1; n <= 7; n++ {
+		if d, _ := WeekdayFromNumber(n); present[d] {
+			days = append(days, d)
+		}
+	}
+	if len(days) == 7 {
+		return NewDayFilterEvery()
+	}
+	if weekdaysEqual(days, allWeekdays) {
+		return NewDayFilterWeekday()
+	}
+	return NewDayFilterDays(days)
+}
+
 // WeekdayFromNumber returns a Weekday from an ISO 8601 day number.
 func WeekdayFromNumber(n int) (Weekday, bool) {
 	if n < 1 || n > 7 {
@@ -105,6 +138,16 @@ func (m MonthName) String() string {
 	return names[m]
 }
 
+// MaxDay returns the month's longest length: February has 29 days.
+func (m MonthName) MaxDay() int {
+	maxDays := map[MonthName]int{
+		Jan: 31, Feb: 29, Mar: 31, Apr: 30,
+		May: 31, Jun: 30, Jul: 31, Aug: 31,
+		Sep: 30, Oct: 31, Nov: 30, Dec: 31,
+	}
+	return maxDays[m]
+}
+
 // ParseMonthName parses a month name (case insensitive).
 func ParseMonthName(s string) (MonthName, bool) {
 	monthParse := map[string]MonthName{
@@ -125,17 +168,24 @@ func ParseMonthName(s string) (MonthName, bool) {
 	return m, ok
 }
 
-// IntervalUnit represents the unit of an interval (minutes or hours).
+// IntervalUnit represents the unit of an interval (minutes, hours or days).
 type IntervalUnit int
 
 const (
 	IntervalMin IntervalUnit = iota
 	IntervalHours
+	// IntervalDays is a day-granularity step: the window is active on every
+	// Nth day (aligned like "every N days at ...") and fires once, at the
+	// window start.
+	IntervalDays
 )
 
 func (u IntervalUnit) String() string {
-	if u == IntervalMin {
+	switch u {
+	case IntervalMin:
 		return "min"
+	case IntervalDays:
+		return "days"
 	}
 	return "hours"
 }
@@ -287,9 +337,12 @@ type MonthTargetKind int
 const (
 	MonthTargetKindDays MonthTargetKind = iota
 	MonthTargetKindLastDay
+	MonthTargetKindLastNDays
 	MonthTargetKindLastWeekday
 	MonthTargetKindNearestWeekday
 	MonthTargetKindOrdinalWeekday
+	MonthTargetKindBusinessDay
+	MonthTargetKindOrdinalWeekdays
 )
 
 // NearestDirection represents the direction for nearest weekday calculations.
@@ -307,11 +360,13 @@ const (
 // MonthTarget represents which day(s) within a month a schedule fires on.
 type MonthTarget struct {
 	Kind      MonthTargetKind
-	Specs     []DayOfMonthSpec // Only used when Kind == MonthTargetKindDays
-	Day       int              // Only used when Kind == MonthTargetKindNearestWeekday
-	Direction NearestDirection // Only used when Kind == MonthTargetKindNearestWeekday
-	Ordinal   OrdinalPosition  // Only used when Kind == MonthTargetKindOrdinalWeekday
-	Weekday   Weekday          // Only used when Kind == MonthTargetKindOrdinalWeekday
+	Specs     []DayOfMonthSpec  // Only used when Kind == MonthTargetKindDays
+	Day       int               // Only used when Kind == MonthTargetKindNearestWeekday
+	N         int               // Used when Kind == MonthTargetKindLastNDays or MonthTargetKindBusinessDay
+	Direction NearestDirection  // Only used when Kind == MonthTargetKindNearestWeekday
+	Ordinal   OrdinalPosition   // Only used when Kind == MonthTargetKindOrdinalWeekday
+	Ordinals  []OrdinalPosition // Only used when Kind == MonthTargetKindOrdinalWeekdays
+	Weekday   Weekday           // Used when Kind == MonthTargetKindOrdinalWeekday or MonthTargetKindOrdinalWeekdays
 }
 
 // NewDaysTarget creates a month target for specific days.
@@ -324,6 +379,21 @@ func NewLastDayTarget() MonthTarget {
 	return MonthTarget{Kind: MonthTargetKindLastDay}
 }
 
+// NewLastNDaysTarget creates a month target for the final n calendar days of
+// the month ("the last 3 days"), n >= 2. It counts back from the month's real
+// length; n beyond it covers the whole month.
+func NewLastNDaysTarget(n int) MonthTarget {
+	return MonthTarget{Kind: MonthTargetKindLastNDays, N: n}
+}
+
+// NewBusinessDayTarget creates a month target for the nth Monday-to-Friday day
+// of the month ("the first business day", "the 3rd weekday"), n in 1-23.
+// Excepted dates are not business days, so an except landing on it moves the
+// occurrence to the next one.
+func NewBusinessDayTarget(n int) MonthTarget {
+	return MonthTarget{Kind: MonthTargetKindBusinessDay, N: n}
+}
+
 // NewLastWeekdayTarget creates a month target for the last weekday of the month.
 func NewLastWeekdayTarget() MonthTarget {
 	return MonthTarget{Kind: MonthTargetKindLastWeekday}
@@ -339,6 +409,13 @@ func NewOrdinalWeekdayTarget(ordinal OrdinalPosition, weekday Weekday) MonthTarg
 	return MonthTarget{Kind: MonthTargetKindOrdinalWeekday, Ordinal: ordinal, Weekday: weekday}
 }
 
+// NewOrdinalWeekdaysTarget creates a month target for several ordinal weekdays
+// (e.g., the first and last friday). Ordinals are sorted and distinct; a single
+// ordinal is an ordinal weekday target.
+func NewOrdinalWeekdaysTarget(ordinals []OrdinalPosition, weekday Weekday) MonthTarget {
+	return MonthTarget{Kind: MonthTargetKindOrdinalWeekdays, Ordinals: ordinals, Weekday: weekday}
+}
+
 // ExpandDays returns all days specified by this target.
 func (m MonthTarget) ExpandDays() []int {
 	if m.Kind != MonthTargetKindDays {
@@ -400,14 +477,18 @@ type DateSpecKind int
 const (
 	DateSpecKindNamed DateSpecKind = iota
 	DateSpecKindISO
+	DateSpecKindNextWeekday
 )
 
 // DateSpec represents a date (either named like "feb 14" or ISO like "2026-03-15").
+// A next-weekday date ("on friday") is the nearest upcoming day with that
+// weekday, resolved against the evaluation time rather than a fixed date.
 type DateSpec struct {
-	Kind  DateSpecKind
-	Month MonthName // Used for named dates
-	Day   int       // Used for named dates
-	Date  string    // Used for ISO dates (YYYY-MM-DD)
+	Kind    DateSpecKind
+	Month   MonthName // Used for named dates
+	Day     int       // Used for named dates
+	Date    string    // Used for ISO dates (YYYY-MM-DD)
+	Weekday Weekday   // Used for next-weekday dates
 }
 
 // NewNamedDate creates a named date specification.
@@ -420,6 +501,11 @@ func NewISODate(date string) DateSpec {
 	return DateSpec{Kind: DateSpecKindISO, Date: date}
 }
 
+// NewNextWeekdayDate creates a next-weekday date specification.
+func NewNextWeekdayDate(weekday Weekday) DateSpec {
+	return DateSpec{Kind: DateSpecKindNextWeekday, Weekday: weekday}
+}
+
 // --- Exception spec ---
 
 // ExceptionSpecKind represents the type of exception specification.
@@ -428,14 +514,21 @@ type ExceptionSpecKind int
 const (
 	ExceptionSpecKindNamed ExceptionSpecKind = iota
 	ExceptionSpecKindISO
+	ExceptionSpecKindWeekday
+	ExceptionSpecKindISORange
+	ExceptionSpecKindNamedRange
 )
 
-// ExceptionSpec represents an exception date.
+// ExceptionSpec represents an exception date, date range, or day of the week.
 type ExceptionSpec struct {
-	Kind  ExceptionSpecKind
-	Month MonthName // Used for named exceptions
-	Day   int       // Used for named exceptions
-	Date  string    // Used for ISO exceptions (YYYY-MM-DD)
+	Kind     ExceptionSpecKind
+	Month    MonthName // Used for named exceptions and named range starts
+	Day      int       // Used for named exceptions and named range starts
+	Date     string    // Used for ISO exceptions and ISO range starts (YYYY-MM-DD)
+	Weekday  Weekday   // Used for weekday exceptions
+	EndMonth MonthName // Used for named range ends
+	EndDay   int       // Used for named range ends
+	EndDate  string    // Used for ISO range ends (YYYY-MM-DD)
 }
 
 // NewNamedException creates a named exception specification.
@@ -448,6 +541,24 @@ func NewISOException(date string) ExceptionSpec {
 	return ExceptionSpec{Kind: ExceptionSpecKindISO, Date: date}
 }
 
+// NewWeekdayException creates an exception for every occurrence of a day of the week.
+func NewWeekdayException(day Weekday) ExceptionSpec {
+	return ExceptionSpec{Kind: ExceptionSpecKindWeekday, Weekday: day}
+}
+
+// NewISORangeException creates a one-off inclusive ISO date range exception:
+// "2026-12-24 to 2026-12-31".
+func NewISORangeException(start, end string) ExceptionSpec {
+	return ExceptionSpec{Kind: ExceptionSpecKindISORange, Date: start, EndDate: end}
+}
+
+// NewNamedRangeException creates a recurring inclusive range of named dates:
+// "dec 24 to jan 2" matches every year, wrapping past december when the end
+// comes first.
+func NewNamedRangeException(startMonth MonthName, startDay int, endMonth MonthName, endDay int) ExceptionSpec {
+	return ExceptionSpec{Kind: ExceptionSpecKindNamedRange, Month: startMonth, Day: startDay, EndMonth: endMonth, EndDay: endDay}
+}
+
 // --- Until spec ---
 
 // UntilSpecKind represents the type of until specification.
@@ -488,9 +599,11 @@ const (
 	ScheduleExprKindMonth
 	ScheduleExprKindSingleDate
 	ScheduleExprKindYear
+	ScheduleExprKindISOWeek
+	ScheduleExprKindWeekday
 )
 
-// ScheduleExpr represents a schedule expression (one of the 6 variants).
+// ScheduleExpr represents a schedule expression (one of the 8 variants).
 type ScheduleExpr struct {
 	Kind ScheduleExprKind
 
@@ -518,6 +631,9 @@ type ScheduleExpr struct {
 
 	// YearRepeat fields
 	YearTarget YearTarget
+
+	// ISOWeekRepeat fields (days are in WeekDays)
+	ISOWeeks []int
 }
 
 // NewIntervalRepeat creates an interval repeat expression.
@@ -581,19 +697,130 @@ func NewYearRepeat(interval int, target YearTarget, times []TimeOfDay) ScheduleE
 	}
 }
 
+// NewISOWeekRepeat creates an expression for days in ISO 8601 week numbers 1-53 of every year.
+func NewISOWeekRepeat(weeks []int, days []Weekday, times []TimeOfDay) ScheduleExpr {
+	return ScheduleExpr{
+		Kind:     ScheduleExprKindISOWeek,
+		ISOWeeks: weeks,
+		WeekDays: days,
+		Times:    times,
+	}
+}
+
+// NewWeekdayRepeat creates an expression for every Nth business day ("every 3
+// weekdays at 09:00"). Only Monday through Friday count toward the interval, so
+// weekends neither fire nor advance it. Days are counted from the starting
+// anchor (or its next weekday), defaulting to 1970-01-01.
+func NewWeekdayRepeat(interval int, times []TimeOfDay) ScheduleExpr {
+	return ScheduleExpr{
+		Kind:     ScheduleExprKindWeekday,
+		Interval: interval,
+		Times:    times,
+	}
+}
+
+// --- During spec ---
+
+// DuringSpec represents a single month, inclusive month range, or inclusive
+// month-day range in a during clause.
+// Wraps past December when start > end: "nov to feb" is nov, dec, jan, feb.
+// Month forms leave the days at zero; an end of feb 29 means the last day of
+// February in common years.
+type DuringSpec struct {
+	Start    MonthName
+	End      MonthName
+	StartDay int // 0 for a whole-month bound
+	EndDay   int // 0 for a whole-month bound
+}
+
+// Bounds returns the first and last month-day this specification covers.
+// Whole months end on their longest day (feb 29).
+func (d DuringSpec) Bounds() (startDay, endDay int) {
+	startDay, endDay = d.StartDay, d.EndDay
+	if startDay == 0 {
+		startDay = 1
+	}
+	if endDay == 0 {
+		endDay = d.End.MaxDay()
+	}
+	return startDay, endDay
+}
+
+// Contains reports whether the month-day falls within this specification.
+func (d DuringSpec) Contains(month MonthName, day int) bool {
+	startDay, endDay := d.Bounds()
+	key := month.Number()*100 + day
+	start := d.Start.Number()*100 + startDay
+	end := d.End.Number()*100 + endDay
+	if start <= end {
+		return start <= key && key <= end
+	}
+	return key >= start || key <= end
+}
+
+// WholeMonths reports whether this specification covers whole months:
+// "jun 1 to aug 31" is "jun to aug".
+func (d DuringSpec) WholeMonths() bool {
+	startDay, endDay := d.Bounds()
+	return startDay == 1 && endDay == d.End.MaxDay()
+}
+
+// Expand returns the months this specification touches, in calendar order from the start.
+func (d DuringSpec) Expand() []MonthName {
+	startDay, endDay := d.Bounds()
+	length := d.End.Number() - d.Start.Number() + 1
+	// "jun 15 to jun 10" wraps through every month
+	if length <= 0 || (length == 1 && startDay > endDay) {
+		length += 12
+	}
+	months := make([]MonthName, length)
+	for i := range months {
+		n := d.Start.Number() + i
+		if n > 12 {
+			n -= 12
+		}
+		months[i] = MonthName(n)
+	}
+	return months
+}
+
 // --- Schedule data ---
 
 // ScheduleData represents the complete parsed schedule with all clauses.
 type ScheduleData struct {
-	Expr     ScheduleExpr
-	Timezone string
-	Except   []ExceptionSpec
-	Until    *UntilSpec
-	Anchor   string // ISO date string for starting clause
-	During   []MonthName
+	Expr        ScheduleExpr
+	Timezone    string
+	Except      []ExceptionSpec
+	Until       *UntilSpec
+	Anchor      string       // ISO date string for starting clause, or "today" / "now"
+	AnchorNamed *DateSpec    // "starting jan 6", resolved to the next such date at evaluation
+	AnchorTime  *TimeOfDay   // "starting ... at <time>"
+	During      []DuringSpec // During as written: months, month ranges, and date ranges
+}
+
+// isRelativeAnchor reports whether anchor is "today" or "now", which resolve
+// against each evaluation instant.
+func isRelativeAnchor(anchor string) bool {
+	return anchor == "today" || anchor == "now"
+}
+
+// hasRelativeAnchor reports whether the starting anchor is resolved against
+// the evaluation time: "today", "now", or a month-day.
+func hasRelativeAnchor(schedule *ScheduleData) bool {
+	return schedule.AnchorNamed != nil || isRelativeAnchor(schedule.Anchor)
 }
 
 // NewScheduleData creates a new schedule data with just the expression.
 func NewScheduleData(expr ScheduleExpr) *ScheduleData {
 	return &ScheduleData{Expr: expr}
 }
+
+// duringMonths returns the months any during spec touches, for month-level
+// skipping.
+func duringMonths(schedule *ScheduleData) []MonthName {
+	var months []MonthName
+	for _, spec := range schedule.During {
+		months = append(months, spec.Expand()...)
+	}
+	return months
+}
@@ -106,6 +106,7 @@ type PreviousFromTest struct {
 
 type CronSpec struct {
 	ToCron         ToCronGroup        `json:"to_cron"`
+	ToCronDuring   ToCronGroup        `json:"to_cron_during"`
 	ToCronErrors   ToCronErrorGroup   `json:"to_cron_errors"`
 	FromCron       FromCronGroup      `json:"from_cron"`
 	FromCronErrors FromCronErrorGroup `json:"from_cron_errors"`
@@ -534,7 +535,8 @@ func TestPreviousFrom(t *testing.T) {
 func TestToCron(t *testing.T) {
 	spec := loadSpec(t)
 
-	for _, tc := range spec.Cron.ToCron.Tests {
+	tests := append(spec.Cron.ToCron.Tests, spec.Cron.ToCronDuring.Tests...)
+	for _, tc := range tests {
 		t.Run(tc.Name, func(t *testing.T) {
 			s, err := ParseSchedule(tc.Hron)
 			if err != nil {
@@ -643,10 +645,11 @@ type MatchesGroup struct {
 }
 
 type MatchesTest struct {
-	Name       string `json:"name"`
-	Expression string `json:"expression"`
-	Datetime   string `json:"datetime"`
-	Expected   bool   `json:"expected"`
+	Name          string `json:"name"`
+	Expression    string `json:"expression"`
+	Datetime      string `json:"datetime"`
+	Expected      bool   `json:"expected"`
+	ErrorContains string `json:"error_contains"`
 }
 
 func TestMatches(t *testing.T) {
@@ -670,6 +673,17 @@ func TestMatches(t *testing.T) {
 				t.Fatalf("failed to parse datetime %q: %v", tc.Datetime, err)
 			}
 
+			if tc.ErrorContains != "" {
+				defer func() {
+					herr, ok := recover().(*HronError)
+					if !ok || !strings.Contains(herr.Message, tc.ErrorContains) {
+						t.Errorf("Matches(%q, %v): expected error containing %q, got %v", tc.Expression, dt, tc.ErrorContains, herr)
+					}
+				}()
+				s.Matches(dt)
+				return
+			}
+
 			got := s.Matches(dt)
 			if got != tc.Expected {
 				t.Errorf("Matches(%q, %v) = %v, want %v", tc.Expression, dt, got, tc.Expected)
@@ -15,9 +15,12 @@ func ToCron(schedule *ScheduleData) (string, error) {
 	if schedule.Until != nil {
 		return "", CronError("not expressible as cron (until clauses not supported)")
 	}
-	if len(schedule.During) > 0 {
-		return "", CronError("not expressible as cron (during clauses not supported)")
+	for _, spec := range schedule.During {
+		if !spec.WholeMonths() {
+			return "", CronError("not expressible as cron (during dates narrower than whole months)")
+		}
 	}
+	month := monthField(duringMonths(schedule))
 
 	expr := schedule.Expr
 
@@ -26,15 +29,21 @@ func ToCron(schedule *ScheduleData) (string, error) {
 		if expr.Interval > 1 {
 			return "", CronError("not expressible as cron (multi-day intervals not supported)")
 		}
-		if len(expr.Times) != 1 {
+		minute, hour, ok := timeFields(expr.Times)
+		if !ok {
 			return "", CronError("not expressible as cron (multiple times not supported)")
 		}
-		t := expr.Times[0]
 		dow := dayFilterToCronDOW(expr.Days)
-		return fmt.Sprintf("%d %d * * %s", t.Minute, t.Hour, dow), nil
+		return fmt.Sprintf("%s %s * %s %s", minute, hour, month, dow), nil
 
 	case ScheduleExprKindInterval:
-		fullDay := expr.FromTime.Hour == 0 && expr.FromTime.Minute == 0 && expr.ToTime.Hour == 23 && expr.ToTime.Minute == 59
+		if expr.Unit == IntervalDays {
+			return "", CronError("not expressible as cron (multi-day interval windows not supported)")
+		}
+		// Only expressible if window is full day (00:00 to 23:59). Hour steps
+		// may start past the hour: "from 00:15" fires at :15.
+		offsetOk := expr.FromTime.Minute == 0 || expr.Unit == IntervalHours
+		fullDay := expr.FromTime.Hour == 0 && offsetOk && expr.ToTime.Hour == 23 && expr.ToTime.Minute == 59
 		if !fullDay {
 			return "", CronError("not expressible as cron (partial-day interval windows not supported)")
 		}
@@ -45,10 +54,13 @@ func ToCron(schedule *ScheduleData) (string, error) {
 			if 60%expr.Interval != 0 {
 				return "", CronError(fmt.Sprintf("not expressible as cron (*/%d breaks at hour boundaries)", expr.Interval))
 			}
-			return fmt.Sprintf("*/%d * * * *", expr.Interval), nil
+			return fmt.Sprintf("*/%d * * %s *", expr.Interval, month), nil
 		}
 		// hours
-		return fmt.Sprintf("0 */%d * * *", expr.Interval), nil
+		if expr.Interval == 1 && expr.FromTime.Minute != 0 {
+			return fmt.Sprintf("%d * * %s *", expr.FromTime.Minute, month), nil
+		}
+		return fmt.Sprintf("%d */%d * %s *", expr.FromTime.Minute, expr.Interval, month), nil
 
 	case ScheduleExprKindWeek:
 		return "", CronError("not expressible as cron (multi-week intervals not supported)")
@@ -57,10 +69,10 @@ func ToCron(schedule *ScheduleData) (string, error) {
 		if expr.Interval > 1 {
 			return "", CronError("not expressible as cron (multi-month intervals not supported)")
 		}
-		if len(expr.Times) != 1 {
+		minute, hour, ok := timeFields(expr.Times)
+		if !ok {
 			return "", CronError("not expressible as cron (multiple times not supported)")
 		}
-		t := expr.Times[0]
 		switch expr.MonthTarget.Kind {
 		case MonthTargetKindDays:
 			var expanded []int
@@ -68,18 +80,28 @@ func ToCron(schedule *ScheduleData) (string, error) {
 				expanded = append(expanded, spec.Expand()...)
 			}
 			dom := formatIntList(expanded)
-			return fmt.Sprintf("%d %d %s * *", t.Minute, t.Hour, dom), nil
+			return fmt.Sprintf("%s %s %s %s *", minute, hour, dom, month), nil
 		case MonthTargetKindLastDay:
 			return "", CronError("not expressible as cron (last day of month not supported)")
+		case MonthTargetKindLastNDays:
+			return "", CronError("not expressible as cron (last days of month not supported)")
+		case MonthTargetKindBusinessDay:
+			// The first business day is always the weekday nearest the 1st
+			if expr.MonthTarget.N == 1 {
+				return fmt.Sprintf("%s %s 1W %s *", minute, hour, month), nil
+			}
+			return "", CronError("not expressible as cron (nth business day of month not supported)")
 		case MonthTargetKindLastWeekday:
 			return "", CronError("not expressible as cron (last weekday of month not supported)")
 		case MonthTargetKindNearestWeekday:
 			if expr.MonthTarget.Direction != NearestNone {
 				return "", CronError("not expressible as cron (directional nearest weekday not supported)")
 			}
-			return fmt.Sprintf("%d %d %dW * *", t.Minute, t.Hour, expr.MonthTarget.Day), nil
+			return fmt.Sprintf("%s %s %dW %s *", minute, hour, expr.MonthTarget.Day, month), nil
 		case MonthTargetKindOrdinalWeekday:
 			return "", CronError("not expressible as cron (ordinal weekday of month not supported)")
+		case MonthTargetKindOrdinalWeekdays:
+			return "", CronError("not expressible as cron (ordinal weekdays of month not supported)")
 		}
 
 	case ScheduleExprKindSingleDate:
@@ -87,11 +109,81 @@ func ToCron(schedule *ScheduleData) (string, error) {
 
 	case ScheduleExprKindYear:
 		return "", CronError("not expressible as cron (yearly schedules not supported in 5-field cron)")
+
+	case ScheduleExprKindWeekday:
+		return "", CronError("not expressible as cron (business-day intervals not supported)")
+
+	case ScheduleExprKindISOWeek:
+		return "", CronError("not expressible as cron (cron has no week-of-year field)")
 	}
 
 	return "", CronError(fmt.Sprintf("unknown expression type: %d", expr.Kind))
 }
 
+// timeFields returns exact minute and hour fields for a list of times: "0 9,17",
+// "0,30 9", or "0,30 9,17" when the times are every listed minute at every
+// listed hour. It reports false when comma fields would fire at times not in
+// the list.
+func timeFields(times []TimeOfDay) (minute, hour string, ok bool) {
+	distinct := make(map[int]bool)
+	minuteSet := make(map[int]bool)
+	hourSet := make(map[int]bool)
+	for _, t := range times {
+		distinct[t.TotalMinutes()] = true
+		minuteSet[t.Minute] = true
+		hourSet[t.Hour] = true
+	}
+	// Distinct times are a subset of the grid, so equal counts mean equal sets
+	if len(minuteSet)*len(hourSet) != len(distinct) {
+		return "", "", false
+	}
+	return formatIntSet(minuteSet), formatIntSet(hourSet), true
+}
+
+// formatIntSet formats a set of integers as a sorted comma list.
+func formatIntSet(set map[int]bool) string {
+	nums := make([]int, 0, len(set))
+	for n := range set {
+		nums = append(nums, n)
+	}
+	sort.Ints(nums)
+	return formatIntList(nums)
+}
+
+// monthField returns the cron month field for a during clause. Runs of three
+// or more consecutive months collapse into a range.
+func monthField(during []MonthName) string {
+	seen := make(map[int]bool)
+	var months []int
+	for _, m := range during {
+		if !seen[m.Number()] {
+			seen[m.Number()] = true
+			months = append(months, m.Number())
+		}
+	}
+	if len(months) == 0 {
+		return "*"
+	}
+	sort.Ints(months)
+
+	var parts []string
+	for i := 0; i < len(months); {
+		j := i
+		for j+1 < len(months) && months[j+1] == months[j]+1 {
+			j++
+		}
+		if j-i >= 2 {
+			parts = append(parts, fmt.Sprintf("%d-%d", months[i], months[j]))
+		} else {
+			for k := i; k <= j; k++ {
+				parts = append(parts, strconv.Itoa(months[k]))
+			}
+		}
+		i = j + 1
+	}
+	return strings.Join(parts, ",")
+}
+
 func dayFilterToCronDOW(f DayFilter) string {
 	switch f.Kind {
 	case DayFilterKindEvery:
@@ -191,15 +283,10 @@ func FromCron(cron string) (*ScheduleData, error) {
 	}
 
 	// Standard time-based cron
-	minute, err := parseSingleValue(minuteField, "minute", 0, 59)
-	if err != nil {
-		return nil, err
-	}
-	hour, err := parseSingleValue(hourField, "hour", 0, 23)
+	times, err := parseCronTimes(minuteField, hourField)
 	if err != nil {
 		return nil, err
 	}
-	t := TimeOfDay{hour, minute}
 
 	// DOM-based (monthly) - when DOM is specified and DOW is *
 	if domField != "*" && dowField == "*" {
@@ -207,7 +294,7 @@ func FromCron(cron string) (*ScheduleData, error) {
 		if err != nil {
 			return nil, err
 		}
-		schedule := NewScheduleData(NewMonthRepeat(1, target, []TimeOfDay{t}))
+		schedule := NewScheduleData(NewMonthRepeat(1, target, times))
 		schedule.During = during
 		return schedule, nil
 	}
@@ -217,31 +304,40 @@ func FromCron(cron string) (*ScheduleData, error) {
 	if err != nil {
 		return nil, err
 	}
-	schedule = NewScheduleData(NewDayRepeat(1, days, []TimeOfDay{t}))
+	schedule = NewScheduleData(NewDayRepeat(1, days, times))
 	schedule.During = during
 	return schedule, nil
 }
 
 // parseCronShortcut parses @ shortcuts like @daily, @hourly, etc.
 func parseCronShortcut(cron string) (*ScheduleData, error) {
-	switch strings.ToLower(cron) {
+	expr, ok := cronShortcutExpr(strings.ToLower(cron))
+	if !ok {
+		return nil, CronError(fmt.Sprintf("unknown @ shortcut: %s", cron))
+	}
+	return NewScheduleData(expr), nil
+}
+
+// cronShortcutExpr returns the expression a lowercase @ shortcut stands for, if it is one.
+func cronShortcutExpr(shortcut string) (ScheduleExpr, bool) {
+	switch shortcut {
 	case "@yearly", "@annually":
-		return NewScheduleData(NewYearRepeat(1, NewYearDateTarget(Jan, 1), []TimeOfDay{{0, 0}})), nil
+		return NewYearRepeat(1, NewYearDateTarget(Jan, 1), []TimeOfDay{{0, 0}}), true
 	case "@monthly":
-		return NewScheduleData(NewMonthRepeat(1, NewDaysTarget([]DayOfMonthSpec{NewSingleDay(1)}), []TimeOfDay{{0, 0}})), nil
+		return NewMonthRepeat(1, NewDaysTarget([]DayOfMonthSpec{NewSingleDay(1)}), []TimeOfDay{{0, 0}}), true
 	case "@weekly":
-		return NewScheduleData(NewDayRepeat(1, NewDayFilterDays([]Weekday{Sunday}), []TimeOfDay{{0, 0}})), nil
+		return NewDayRepeat(1, NewDayFilterDays([]Weekday{Sunday}), []TimeOfDay{{0, 0}}), true
 	case "@daily", "@midnight":
-		return NewScheduleData(NewDayRepeat(1, NewDayFilterEvery(), []TimeOfDay{{0, 0}})), nil
+		return NewDayRepeat(1, NewDayFilterEvery(), []TimeOfDay{{0, 0}}), true
 	case "@hourly":
-		return NewScheduleData(NewIntervalRepeat(1, IntervalHours, TimeOfDay{0, 0}, TimeOfDay{23, 59}, nil)), nil
+		return NewIntervalRepeat(1, IntervalHours, TimeOfDay{0, 0}, TimeOfDay{23, 59}, nil), true
 	default:
-		return nil, CronError(fmt.Sprintf("unknown @ shortcut: %s", cron))
+		return ScheduleExpr{}, false
 	}
 }
 
-// parseMonthField parses the month field into a []MonthName for the `during` clause.
-func parseMonthField(field string) ([]MonthName, error) {
+// parseMonthField parses the month field into `during` specs, one per month.
+func parseMonthField(field string) ([]DuringSpec, error) {
 	if field == "*" {
 		return nil, nil
 	}
@@ -314,7 +410,11 @@ func parseMonthField(field string) ([]MonthName, error) {
 		}
 	}
 
-	return months, nil
+	specs := make([]DuringSpec, len(months))
+	for i, m := range months {
+		specs[i] = DuringSpec{Start: m, End: m}
+	}
+	return specs, nil
 }
 
 // parseMonthValue parses a single month value (number 1-12 or name JAN-DEC).
@@ -338,7 +438,7 @@ func monthFromNumber(n int) (MonthName, error) {
 }
 
 // tryParseNthWeekday tries to parse nth weekday patterns like 1#1 (first Monday) or 5L (last Friday).
-func tryParseNthWeekday(minuteField, hourField, domField, dowField string, during []MonthName) (*ScheduleData, bool, error) {
+func tryParseNthWeekday(minuteField, hourField, domField, dowField string, during []DuringSpec) (*ScheduleData, bool, error) {
 	// Check for # pattern (nth weekday of month)
 	if strings.Contains(dowField, "#") {
 		dowStr, nthStr, _ := strings.Cut(dowField, "#")
@@ -375,17 +475,13 @@ func tryParseNthWeekday(minuteField, hourField, domField, dowField string, durin
 			return nil, false, CronError("DOM must be * when using # for nth weekday")
 		}
 
-		minute, err := parseSingleValue(minuteField, "minute", 0, 59)
-		if err != nil {
-			return nil, false, err
-		}
-		hour, err := parseSingleValue(hourField, "hour", 0, 23)
+		times, err := parseCronTimes(minuteField, hourField)
 		if err != nil {
 			return nil, false, err
 		}
 
 		target := NewOrdinalWeekdayTarget(ordinal, weekday)
-		schedule := NewScheduleData(NewMonthRepeat(1, target, []TimeOfDay{{hour, minute}}))
+		schedule := NewScheduleData(NewMonthRepeat(1, target, times))
 		schedule.During = during
 		return schedule, true, nil
 	}
@@ -406,17 +502,13 @@ func tryParseNthWeekday(minuteField, hourField, domField, dowField string, durin
 			return nil, false, CronError("DOM must be * when using nL for last weekday")
 		}
 
-		minute, err := parseSingleValue(minuteField, "minute", 0, 59)
-		if err != nil {
-			return nil, false, err
-		}
-		hour, err := parseSingleValue(hourField, "hour", 0, 23)
+		times, err := parseCronTimes(minuteField, hourField)
 		if err != nil {
 			return nil, false, err
 		}
 
 		target := NewOrdinalWeekdayTarget(Last, weekday)
-		schedule := NewScheduleData(NewMonthRepeat(1, target, []TimeOfDay{{hour, minute}}))
+		schedule := NewScheduleData(NewMonthRepeat(1, target, times))
 		schedule.During = during
 		return schedule, true, nil
 	}
@@ -425,7 +517,7 @@ func tryParseNthWeekday(minuteField, hourField, domField, dowField string, durin
 }
 
 // tryParseNearestWeekday tries to parse W (nearest weekday) patterns like 15W.
-func tryParseNearestWeekday(minuteField, hourField, domField, dowField string, during []MonthName) (*ScheduleData, bool, error) {
+func tryParseNearestWeekday(minuteField, hourField, domField, dowField string, during []DuringSpec) (*ScheduleData, bool, error) {
 	// Check for pattern like 15W, 1W, etc.
 	if !strings.HasSuffix(domField, "W") || domField == "LW" {
 		return nil, false, nil
@@ -445,23 +537,19 @@ func tryParseNearestWeekday(minuteField, hourField, domField, dowField string, d
 		return nil, false, CronError("DOW must be * when using W in DOM")
 	}
 
-	minute, err := parseSingleValue(minuteField, "minute", 0, 59)
-	if err != nil {
-		return nil, false, err
-	}
-	hour, err := parseSingleValue(hourField, "hour", 0, 23)
+	times, err := parseCronTimes(minuteField, hourField)
 	if err != nil {
 		return nil, false, err
 	}
 
 	target := NewNearestWeekdayTarget(day, NearestNone)
-	schedule := NewScheduleData(NewMonthRepeat(1, target, []TimeOfDay{{hour, minute}}))
+	schedule := NewScheduleData(NewMonthRepeat(1, target, times))
 	schedule.During = during
 	return schedule, true, nil
 }
 
 // tryParseLastDay tries to parse L (last day) or LW (last weekday) patterns.
-func tryParseLastDay(minuteField, hourField, domField, dowField string, during []MonthName) (*ScheduleData, bool, error) {
+func tryParseLastDay(minuteField, hourField, domField, dowField string, during []DuringSpec) (*ScheduleData, bool, error) {
 	if domField != "L" && domField != "LW" {
 		return nil, false, nil
 	}
@@ -470,11 +558,7 @@ func tryParseLastDay(minuteField, hourField, domField, dowField string, during [
 		return nil, false, CronError("DOW must be * when using L or LW in DOM")
 	}
 
-	minute, err := parseSingleValue(minuteField, "minute", 0, 59)
-	if err != nil {
-		return nil, false, err
-	}
-	hour, err := parseSingleValue(hourField, "hour", 0, 23)
+	times, err := parseCronTimes(minuteField, hourField)
 	if err != nil {
 		return nil, false, err
 	}
@@ -486,13 +570,13 @@ func tryParseLastDay(minuteField, hourField, domField, dowField string, during [
 		target = NewLastDayTarget()
 	}
 
-	schedule := NewScheduleData(NewMonthRepeat(1, target, []TimeOfDay{{hour, minute}}))
+	schedule := NewScheduleData(NewMonthRepeat(1, target, times))
 	schedule.During = during
 	return schedule, true, nil
 }
 
 // tryParseInterval tries to parse interval patterns: */N, range/N in minute or hour fields.
-func tryParseInterval(minuteField, hourField, domField, dowField string, during []MonthName) (*ScheduleData, bool, error) {
+func tryParseInterval(minuteField, hourField, domField, dowField string, during []DuringSpec) (*ScheduleData, bool, error) {
 	// Minute interval: */N or range/N
 	if strings.Contains(minuteField, "/") {
 		rangePart, stepStr, _ := strings.Cut(minuteField, "/")
@@ -591,9 +675,14 @@ func tryParseInterval(minuteField, hourField, domField, dowField string, during
 		}
 	}
 
-	// Hour interval: 0 */N or 0 range/N
-	if strings.Contains(hourField, "/") && (minuteField == "0" || minuteField == "00") {
+	// Hour interval: M */N, M range/N, or M * (every hour at minute M)
+	minute, err := strconv.Atoi(minuteField)
+	minuteOk := err == nil && strings.Trim(minuteField, "0123456789") == "" && minute <= 59
+	if minuteOk && (strings.Contains(hourField, "/") || hourField == "*") {
 		rangePart, stepStr, _ := strings.Cut(hourField, "/")
+		if stepStr == "" {
+			stepStr = "1"
+		}
 		interval, err := strconv.Atoi(stepStr)
 		if err != nil {
 			return nil, false, CronError("invalid hour interval value")
@@ -628,18 +717,19 @@ func tryParseInterval(minuteField, hourField, domField, dowField string, during
 		}
 
 		if (domField == "*" || domField == "?") && (dowField == "*" || dowField == "?") {
-			// Use :59 only for full day (00:00 to 23:59), otherwise use :00
+			// Use :59 only for full day (00:00 to 23:59), otherwise end on the
+			// last firing
 			var endMinute int
 			if fromHour == 0 && toHour == 23 {
 				endMinute = 59
 			} else {
-				endMinute = 0
+				endMinute = minute
 			}
 
 			schedule := NewScheduleData(NewIntervalRepeat(
 				interval,
 				IntervalHours,
-				TimeOfDay{fromHour, 0},
+				TimeOfDay{fromHour, minute},
 				TimeOfDay{toHour, endMinute},
 				nil,
 			))
@@ -839,31 +929,12 @@ func parseCronDOW(field string) (DayFilter, error) {
 		}
 	}
 
-	// Check for special patterns
-	if len(days) == 5 {
-		sorted := make([]Weekday, len(days))
-		copy(sorted, days)
-		sort.Slice(sorted, func(i, j int) bool {
-			return sorted[i].Number() < sorted[j].Number()
-		})
-		weekdays := []Weekday{Monday, Tuesday, Wednesday, Thursday, Friday}
-		if weekdaysEqual(sorted, weekdays) {
-			return NewDayFilterWeekday(), nil
-		}
-	}
-	if len(days) == 2 {
-		sorted := make([]Weekday, len(days))
-		copy(sorted, days)
-		sort.Slice(sorted, func(i, j int) bool {
-			return sorted[i].Number() < sorted[j].Number()
-		})
-		weekend := []Weekday{Saturday, Sunday}
-		if weekdaysEqual(sorted, weekend) {
-			return NewDayFilterWeekend(), nil
-		}
+	// Cron has no weekend keyword, so 0,6 reads as the weekend
+	canonical := canonicalDayFilter(NewDayFilterDays(days))
+	if canonical.Kind == DayFilterKindDays && weekdaysEqual(canonical.Days, allWeekend) {
+		return NewDayFilterWeekend(), nil
 	}
-
-	return NewDayFilterDays(days), nil
+	return canonical, nil
 }
 
 func weekdaysEqual(a, b []Weekday) bool {
@@ -940,6 +1011,49 @@ func cronDOWToWeekday(n int) (Weekday, error) {
 	return wd, nil
 }
 
+// parseCronTimes parses the minute and hour fields into times. Either field may
+// be a comma list; every hour pairs with every minute, so "0,30 9,17" gives four
+// times.
+func parseCronTimes(minuteField, hourField string) ([]TimeOfDay, error) {
+	minutes, err := parseValueList(minuteField, "minute", 0, 59)
+	if err != nil {
+		return nil, err
+	}
+	hours, err := parseValueList(hourField, "hour", 0, 23)
+	if err != nil {
+		return nil, err
+	}
+	var times []TimeOfDay
+	for _, h := range hours {
+		for _, m := range minutes {
+			times = append(times, TimeOfDay{h, m})
+		}
+	}
+	sort.Slice(times, func(i, j int) bool {
+		return times[i].TotalMinutes() < times[j].TotalMinutes()
+	})
+	deduped := times[:1]
+	for _, t := range times[1:] {
+		if t != deduped[len(deduped)-1] {
+			deduped = append(deduped, t)
+		}
+	}
+	return deduped, nil
+}
+
+// parseValueList parses a comma list of numeric values, each validated like a single value.
+func parseValueList(field, name string, min, max int) ([]int, error) {
+	var values []int
+	for _, v := range strings.Split(field, ",") {
+		value, err := parseSingleValue(v, name, min, max)
+		if err != nil {
+			return nil, err
+		}
+		values = append(values, value)
+	}
+	return values, nil
+}
+
 // parseSingleValue parses a single numeric value with validation.
 func parseSingleValue(field, name string, min, max int) (int, error) {
 	value, err := strconv.Atoi(field)
@@ -2,6 +2,7 @@ package hron
 
 import (
 	"fmt"
+	"strconv"
 	"strings"
 )
 
@@ -21,14 +22,18 @@ func Display(schedule *ScheduleData) string {
 		sb.WriteString(displayUntil(*schedule.Until))
 	}
 
-	if schedule.Anchor != "" {
+	if schedule.Anchor != "" || schedule.AnchorNamed != nil {
 		sb.WriteString(" starting ")
-		sb.WriteString(schedule.Anchor)
+		sb.WriteString(displayAnchor(schedule))
+	}
+	if schedule.AnchorTime != nil {
+		sb.WriteString(" at ")
+		sb.WriteString(schedule.AnchorTime.String())
 	}
 
 	if len(schedule.During) > 0 {
 		sb.WriteString(" during ")
-		sb.WriteString(displayMonthList(schedule.During))
+		sb.WriteString(displayDuringSpecs(schedule.During))
 	}
 
 	if schedule.Timezone != "" {
@@ -53,6 +58,10 @@ func displayExpr(expr ScheduleExpr) string {
 		return displaySingleDate(expr)
 	case ScheduleExprKindYear:
 		return displayYearRepeat(expr)
+	case ScheduleExprKindWeekday:
+		return fmt.Sprintf("every %d weekdays at %s", expr.Interval, formatTimeList(expr.Times))
+	case ScheduleExprKindISOWeek:
+		return displayISOWeekRepeat(expr)
 	default:
 		panic(fmt.Sprintf("unknown expression kind: %d", expr.Kind))
 	}
@@ -71,6 +80,9 @@ func displayIntervalRepeat(expr ScheduleExpr) string {
 
 func displayDayRepeat(expr ScheduleExpr) string {
 	if expr.Interval > 1 {
+		if expr.Days.Kind != DayFilterKindEvery {
+			return fmt.Sprintf("every %d days on %s at %s", expr.Interval, displayDayFilter(expr.Days), formatTimeList(expr.Times))
+		}
 		return fmt.Sprintf("every %d days at %s", expr.Interval, formatTimeList(expr.Times))
 	}
 	return fmt.Sprintf("every %s at %s", displayDayFilter(expr.Days), formatTimeList(expr.Times))
@@ -105,6 +117,14 @@ func displayYearRepeat(expr ScheduleExpr) string {
 	return fmt.Sprintf("every year on %s at %s", targetStr, formatTimeList(expr.Times))
 }
 
+func displayISOWeekRepeat(expr ScheduleExpr) string {
+	weeks := make([]string, len(expr.ISOWeeks))
+	for i, w := range expr.ISOWeeks {
+		weeks[i] = strconv.Itoa(w)
+	}
+	return fmt.Sprintf("every year in iso weeks %s on %s at %s", strings.Join(weeks, ", "), formatDayList(expr.WeekDays), formatTimeList(expr.Times))
+}
+
 func displayDayFilter(f DayFilter) string {
 	switch f.Kind {
 	case DayFilterKindEvery:
@@ -114,7 +134,11 @@ func displayDayFilter(f DayFilter) string {
 	case DayFilterKindWeekend:
 		return "weekend"
 	case DayFilterKindDays:
-		return formatDayList(f.Days)
+		canonical := canonicalDayFilter(f)
+		if canonical.Kind != DayFilterKindDays {
+			return displayDayFilter(canonical)
+		}
+		return formatDayList(canonical.Days)
 	default:
 		panic(fmt.Sprintf("unknown day filter kind: %d", f.Kind))
 	}
@@ -124,6 +148,10 @@ func displayMonthTarget(target MonthTarget) string {
 	switch target.Kind {
 	case MonthTargetKindLastDay:
 		return "last day"
+	case MonthTargetKindLastNDays:
+		return fmt.Sprintf("last %d days", target.N)
+	case MonthTargetKindBusinessDay:
+		return fmt.Sprintf("%s business day", ordinalNumber(target.N))
 	case MonthTargetKindLastWeekday:
 		return "last weekday"
 	case MonthTargetKindDays:
@@ -140,6 +168,13 @@ func displayMonthTarget(target MonthTarget) string {
 		return sb.String()
 	case MonthTargetKindOrdinalWeekday:
 		return fmt.Sprintf("%s %s", target.Ordinal.String(), target.Weekday.String())
+	case MonthTargetKindOrdinalWeekdays:
+		parts := make([]string, len(target.Ordinals)-1)
+		for i, o := range target.Ordinals[:len(target.Ordinals)-1] {
+			parts[i] = o.String()
+		}
+		last := target.Ordinals[len(target.Ordinals)-1]
+		return fmt.Sprintf("%s and %s %s", strings.Join(parts, ", "), last.String(), target.Weekday.String())
 	default:
 		panic(fmt.Sprintf("unknown month target kind: %d", target.Kind))
 	}
@@ -166,6 +201,8 @@ func displayDateSpec(spec DateSpec) string {
 		return fmt.Sprintf("%s %d", spec.Month.String(), spec.Day)
 	case DateSpecKindISO:
 		return spec.Date
+	case DateSpecKindNextWeekday:
+		return spec.Weekday.String()
 	default:
 		panic(fmt.Sprintf("unknown date spec kind: %d", spec.Kind))
 	}
@@ -179,6 +216,12 @@ func displayExceptions(exceptions []ExceptionSpec) string {
 			parts[i] = fmt.Sprintf("%s %d", exc.Month.String(), exc.Day)
 		case ExceptionSpecKindISO:
 			parts[i] = exc.Date
+		case ExceptionSpecKindWeekday:
+			parts[i] = exc.Weekday.String()
+		case ExceptionSpecKindISORange:
+			parts[i] = fmt.Sprintf("%s to %s", exc.Date, exc.EndDate)
+		case ExceptionSpecKindNamedRange:
+			parts[i] = fmt.Sprintf("%s %d to %s %d", exc.Month.String(), exc.Day, exc.EndMonth.String(), exc.EndDay)
 		default:
 			panic(fmt.Sprintf("unknown exception spec kind: %d", exc.Kind))
 		}
@@ -197,10 +240,29 @@ func displayUntil(until UntilSpec) string {
 	}
 }
 
-func displayMonthList(months []MonthName) string {
-	parts := make([]string, len(months))
-	for i, m := range months {
-		parts[i] = m.String()
+// displayAnchor renders the starting anchor as written, without its time.
+func displayAnchor(schedule *ScheduleData) string {
+	if schedule.AnchorNamed != nil {
+		return fmt.Sprintf("%s %d", schedule.AnchorNamed.Month.String(), schedule.AnchorNamed.Day)
+	}
+	return schedule.Anchor
+}
+
+func displayDuringSpecs(specs []DuringSpec) string {
+	parts := make([]string, len(specs))
+	for i, spec := range specs {
+		startDay, endDay := spec.Bounds()
+		// Date ranges covering whole months print as months
+		switch {
+		case spec.WholeMonths() && spec.Start == spec.End:
+			parts[i] = spec.Start.String()
+		case spec.WholeMonths():
+			parts[i] = fmt.Sprintf("%s to %s", spec.Start.String(), spec.End.String())
+		case spec.Start == spec.End && startDay == endDay:
+			parts[i] = fmt.Sprintf("%s %d", spec.Start.String(), startDay)
+		default:
+			parts[i] = fmt.Sprintf("%s %d to %s %d", spec.Start.String(), startDay, spec.End.String(), endDay)
+		}
 	}
 	return strings.Join(parts, ", ")
 }
@@ -262,6 +324,12 @@ func unitDisplay(interval int, unit IntervalUnit) string {
 		}
 		return "min"
 	}
+	if unit == IntervalDays {
+		if interval == 1 {
+			return "day"
+		}
+		return "days"
+	}
 	if interval == 1 {
 		return "hour"
 	}
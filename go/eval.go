@@ -1,7 +1,9 @@
 package hron
 
 import (
+	"fmt"
 	"iter"
+	"sort"
 	"time"
 )
 
@@ -61,8 +63,72 @@ import (
 
 const maxIterations = 1000
 
+// resolveAnchor pins a relative anchor to a date in loc: "starting today" and
+// "starting now" to now's date, "starting jan 6" to the next jan 6 on or after it.
+func resolveAnchor(schedule *ScheduleData, loc *time.Location, now time.Time) *ScheduleData {
+	if !hasRelativeAnchor(schedule) {
+		return schedule
+	}
+	resolved := *schedule
+	today := dateOnly(now.In(loc))
+	named := schedule.AnchorNamed
+	if named == nil {
+		resolved.Anchor = today.Format("2006-01-02")
+		return &resolved
+	}
+	// Try up to 8 years forward (covers leap year cycles)
+	for y := 0; y < 8; y++ {
+		d := time.Date(today.Year()+y, time.Month(named.Month.Number()), named.Day, 0, 0, 0, 0, time.UTC)
+		if d.Day() != named.Day {
+			continue // Invalid date (e.g. feb 29 in a non-leap year)
+		}
+		if !d.Before(today) {
+			resolved.Anchor = d.Format("2006-01-02")
+			resolved.AnchorNamed = nil
+			return &resolved
+		}
+	}
+	panic(EvalError(fmt.Sprintf("starting date %s never occurs", displayAnchor(schedule))))
+}
+
+// anchorStart returns the instant nothing occurs before: the resolved anchor
+// date at the "starting ... at" time, or at midnight.
+func anchorStart(schedule *ScheduleData, loc *time.Location) *time.Time {
+	if schedule.Anchor == "" {
+		return nil
+	}
+	d, err := parseISODate(schedule.Anchor)
+	if err != nil {
+		return nil
+	}
+	tod := TimeOfDay{0, 0}
+	if schedule.AnchorTime != nil {
+		tod = *schedule.AnchorTime
+	}
+	start := atTimeOnDate(d, tod, loc)
+	return &start
+}
+
+// pinRelative pins what the schedule resolves against the evaluation time, as
+// seen from now: "on <weekday>" becomes the one date it names (the nearest such
+// weekday with a time still ahead), and a relative anchor becomes its date.
+func pinRelative(schedule *ScheduleData, loc *time.Location, now time.Time) *ScheduleData {
+	schedule = resolveAnchor(schedule, loc, now)
+	if schedule.Expr.Kind != ScheduleExprKindSingleDate || schedule.Expr.DateSpec.Kind != DateSpecKindNextWeekday {
+		return schedule
+	}
+	next := nextSingleDate(schedule.Expr.DateSpec, schedule.Expr.Times, loc, now)
+	if next == nil {
+		return schedule
+	}
+	pinned := *schedule
+	pinned.Expr.DateSpec = NewISODate(next.In(loc).Format("2006-01-02"))
+	return &pinned
+}
+
 // nextFrom computes the next occurrence after now.
 func nextFrom(schedule *ScheduleData, loc *time.Location, now time.Time) *time.Time {
+	schedule = resolveAnchor(schedule, loc, now)
 	var untilDate *time.Time
 	if schedule.Until != nil {
 		ud := resolveUntil(*schedule.Until, now)
@@ -71,20 +137,25 @@ func nextFrom(schedule *ScheduleData, loc *time.Location, now time.Time) *time.T
 
 	hasExceptions := len(schedule.Except) > 0
 	hasDuring := len(schedule.During) > 0
+	during := schedule.During
 
 	// Check if expression is NearestWeekday with direction (can cross month boundaries)
 	handlesDuringInternally := schedule.Expr.Kind == ScheduleExprKindMonth &&
 		schedule.Expr.MonthTarget.Kind == MonthTargetKindNearestWeekday &&
 		schedule.Expr.MonthTarget.Direction != NearestNone
 
+	// Nothing occurs before the starting anchor, so search from its start
 	current := now
+	if start := anchorStart(schedule, loc); start != nil && now.Before(*start) {
+		current = start.Add(-time.Nanosecond)
+	}
 
 	for i := 0; i < maxIterations; i++ {
 		var candidate *time.Time
 		if handlesDuringInternally {
-			candidate = nextExprWithDuring(schedule.Expr, loc, schedule.Anchor, current, schedule.During)
+			candidate = nextExprWithDuring(schedule.Expr, loc, schedule.Anchor, current, duringMonths(schedule), schedule.Except)
 		} else {
-			candidate = nextExpr(schedule.Expr, loc, schedule.Anchor, current)
+			candidate = nextExpr(schedule.Expr, loc, schedule.Anchor, current, schedule.Except)
 		}
 		if candidate == nil {
 			return nil
@@ -99,8 +170,9 @@ func nextFrom(schedule *ScheduleData, loc *time.Location, now time.Time) *time.T
 
 		// Apply during filter
 		// Skip this check for expressions that handle during internally (NearestWeekday with direction)
-		if hasDuring && !handlesDuringInternally && !matchesDuring(cDate, schedule.During) {
-			skipTo := nextDuringMonth(cDate, schedule.During)
+		if hasDuring && !handlesDuringInternally && !matchesDuring(cDate, during) {
+			// Skip ahead to the start of the next during period
+			skipTo := nextDuringStart(cDate, during)
 			midnight := atTimeOnDate(skipTo, TimeOfDay{0, 0}, loc)
 			current = midnight.Add(-time.Second)
 			continue
@@ -108,7 +180,8 @@ func nextFrom(schedule *ScheduleData, loc *time.Location, now time.Time) *time.T
 
 		// Apply except filter
 		if hasExceptions && isExcepted(cDate, schedule.Except) {
-			nextDay := cDate.AddDate(0, 0, 1)
+			// Advance past this day (or the whole excepted range) and retry
+			nextDay := skipExceptionsForward(cDate, schedule.Except)
 			midnight := atTimeOnDate(nextDay, TimeOfDay{0, 0}, loc)
 			current = midnight.Add(-time.Second)
 			continue
@@ -121,25 +194,33 @@ func nextFrom(schedule *ScheduleData, loc *time.Location, now time.Time) *time.T
 }
 
 // nextExpr dispatches to the appropriate next function based on expression type.
-func nextExpr(expr ScheduleExpr, loc *time.Location, anchor string, now time.Time) *time.Time {
-	return nextExprWithDuring(expr, loc, anchor, now, nil)
+func nextExpr(expr ScheduleExpr, loc *time.Location, anchor string, now time.Time, exceptions []ExceptionSpec) *time.Time {
+	return nextExprWithDuring(expr, loc, anchor, now, nil, exceptions)
 }
 
 // nextExprWithDuring dispatches to the appropriate next function, passing during filter for special handling.
-func nextExprWithDuring(expr ScheduleExpr, loc *time.Location, anchor string, now time.Time, during []MonthName) *time.Time {
+func nextExprWithDuring(expr ScheduleExpr, loc *time.Location, anchor string, now time.Time, during []MonthName, exceptions []ExceptionSpec) *time.Time {
 	switch expr.Kind {
 	case ScheduleExprKindDay:
 		return nextDayRepeat(expr.Interval, expr.Days, expr.Times, loc, anchor, now)
 	case ScheduleExprKindInterval:
+		if expr.Unit == IntervalDays {
+			// Day-step windows fire once, at the window start, on each aligned day
+			return nextDayRepeat(expr.Interval, dayStepFilter(expr), []TimeOfDay{expr.FromTime}, loc, anchor, now)
+		}
 		return nextIntervalRepeat(expr.Interval, expr.Unit, expr.FromTime, expr.ToTime, expr.DayFilter, loc, now)
 	case ScheduleExprKindWeek:
 		return nextWeekRepeat(expr.Interval, expr.WeekDays, expr.Times, loc, anchor, now)
 	case ScheduleExprKindMonth:
-		return nextMonthRepeatWithDuring(expr.Interval, expr.MonthTarget, expr.Times, loc, anchor, now, during)
+		return nextMonthRepeatWithDuring(expr.Interval, expr.MonthTarget, expr.Times, loc, anchor, now, during, exceptions)
 	case ScheduleExprKindSingleDate:
 		return nextSingleDate(expr.DateSpec, expr.Times, loc, now)
 	case ScheduleExprKindYear:
 		return nextYearRepeat(expr.Interval, expr.YearTarget, expr.Times, loc, anchor, now)
+	case ScheduleExprKindISOWeek:
+		return nextISOWeekRepeat(expr.ISOWeeks, expr.WeekDays, expr.Times, loc, now)
+	case ScheduleExprKindWeekday:
+		return nextWeekdayRepeat(expr.Interval, expr.Times, loc, anchor, now)
 	default:
 		return nil
 	}
@@ -147,6 +228,7 @@ func nextExprWithDuring(expr ScheduleExpr, loc *time.Location, anchor string, no
 
 // nextNFrom computes the next n occurrences after now.
 func nextNFrom(schedule *ScheduleData, loc *time.Location, now time.Time, n int) []time.Time {
+	schedule = pinRelative(schedule, loc, now)
 	var results []time.Time
 	current := now
 
@@ -164,6 +246,19 @@ func nextNFrom(schedule *ScheduleData, loc *time.Location, now time.Time, n int)
 
 // matches checks if a datetime matches this schedule.
 func matches(schedule *ScheduleData, loc *time.Location, dt time.Time) bool {
+	// There is no evaluation time to resolve a relative anchor against;
+	// resolving it against dt would make every date its own anchor.
+	if hasRelativeAnchor(schedule) {
+		panic(EvalError(fmt.Sprintf(
+			"matches has no evaluation time to resolve 'starting %s' against; pin the anchor to a date first",
+			displayAnchor(schedule))))
+	}
+	// Nor an `on <weekday>` date: every such weekday would be the upcoming one
+	if schedule.Expr.Kind == ScheduleExprKindSingleDate && schedule.Expr.DateSpec.Kind == DateSpecKindNextWeekday {
+		panic(EvalError(fmt.Sprintf(
+			"matches has no evaluation time to resolve 'on %s' against; use a date instead",
+			schedule.Expr.DateSpec.Weekday)))
+	}
 	zdt := dt.In(loc)
 	d := dateOnly(zdt)
 
@@ -173,6 +268,10 @@ func matches(schedule *ScheduleData, loc *time.Location, dt time.Time) bool {
 	if isExcepted(d, schedule.Except) {
 		return false
 	}
+	// Nothing occurs before the starting anchor
+	if start := anchorStart(schedule, loc); start != nil && dt.Before(*start) {
+		return false
+	}
 
 	if schedule.Until != nil {
 		untilDate := resolveUntil(*schedule.Until, dt)
@@ -217,6 +316,17 @@ func matches(schedule *ScheduleData, loc *time.Location, dt time.Time) bool {
 		if schedule.Expr.DayFilter != nil && !matchesDayFilter(d, *schedule.Expr.DayFilter) {
 			return false
 		}
+		if schedule.Expr.Unit == IntervalDays {
+			if !timeMatchesWithDST([]TimeOfDay{schedule.Expr.FromTime}) {
+				return false
+			}
+			anchorDate := epochDate
+			if schedule.Anchor != "" {
+				anchorDate, _ = parseISODate(schedule.Anchor)
+			}
+			dayOffset := daysBetween(dateOnly(anchorDate), d)
+			return dayOffset >= 0 && dayOffset%schedule.Expr.Interval == 0
+		}
 		fromMinutes := schedule.Expr.FromTime.TotalMinutes()
 		toMinutes := schedule.Expr.ToTime.TotalMinutes()
 		currentMinutes := zdt.Hour()*60 + zdt.Minute()
@@ -278,6 +388,12 @@ func matches(schedule *ScheduleData, loc *time.Location, dt time.Time) bool {
 		case MonthTargetKindLastDay:
 			last := lastDayOfMonth(d.Year(), d.Month())
 			return d.Day() == last.Day()
+		case MonthTargetKindLastNDays:
+			last := lastDayOfMonth(d.Year(), d.Month())
+			return d.Day() > last.Day()-schedule.Expr.MonthTarget.N
+		case MonthTargetKindBusinessDay:
+			bd, ok := nthBusinessDay(d.Year(), d.Month(), schedule.Expr.MonthTarget.N, schedule.Except)
+			return ok && d.Day() == bd.Day()
 		case MonthTargetKindLastWeekday:
 			lwd := lastWeekdayOfMonth(d.Year(), d.Month())
 			return d.Day() == lwd.Day()
@@ -288,18 +404,18 @@ func matches(schedule *ScheduleData, loc *time.Location, dt time.Time) bool {
 			}
 			return d.Year() == nwd.Year() && d.Month() == nwd.Month() && d.Day() == nwd.Day()
 		case MonthTargetKindOrdinalWeekday:
-			var ordinalTarget time.Time
-			var ok bool
-			if schedule.Expr.MonthTarget.Ordinal == Last {
-				ordinalTarget = lastWeekdayInMonth(d.Year(), d.Month(), schedule.Expr.MonthTarget.Weekday)
-				ok = true
-			} else {
-				ordinalTarget, ok = nthWeekdayOfMonth(d.Year(), d.Month(), schedule.Expr.MonthTarget.Weekday, schedule.Expr.MonthTarget.Ordinal.ToN())
-			}
+			ordinalTarget, ok := ordinalWeekdayDate(d.Year(), d.Month(), schedule.Expr.MonthTarget.Ordinal, schedule.Expr.MonthTarget.Weekday)
 			if !ok {
 				return false
 			}
 			return d.Day() == ordinalTarget.Day()
+		case MonthTargetKindOrdinalWeekdays:
+			for _, od := range ordinalWeekdayDates(d.Year(), d.Month(), schedule.Expr.MonthTarget.Ordinals, schedule.Expr.MonthTarget.Weekday) {
+				if d.Day() == od.Day() {
+					return true
+				}
+			}
+			return false
 		}
 		return false
 
@@ -313,6 +429,9 @@ func matches(schedule *ScheduleData, loc *time.Location, dt time.Time) bool {
 			return d.Year() == isoTarget.Year() && d.Month() == isoTarget.Month() && d.Day() == isoTarget.Day()
 		case DateSpecKindNamed:
 			return int(d.Month()) == schedule.Expr.DateSpec.Month.Number() && d.Day() == schedule.Expr.DateSpec.Day
+		case DateSpecKindNextWeekday:
+			// matches rejects it up front; any day with that weekday can be it
+			return isoWeekday(d) == schedule.Expr.DateSpec.Weekday.Number()
 		}
 		return false
 
@@ -332,6 +451,40 @@ func matches(schedule *ScheduleData, loc *time.Location, dt time.Time) bool {
 			}
 		}
 		return matchesYearTarget(schedule.Expr.YearTarget, d)
+
+	case ScheduleExprKindWeekday:
+		anchorDate := epochDate
+		if schedule.Anchor != "" {
+			anchorDate, _ = parseISODate(schedule.Anchor)
+		}
+		if !weekdayIntervalAligned(anchorDate, schedule.Expr.Interval, d) {
+			return false
+		}
+		return timeMatchesWithDST(schedule.Expr.Times)
+
+	case ScheduleExprKindISOWeek:
+		_, week := d.ISOWeek()
+		found := false
+		for _, w := range schedule.Expr.ISOWeeks {
+			if w == week {
+				found = true
+				break
+			}
+		}
+		if !found {
+			return false
+		}
+		found = false
+		for _, wd := range schedule.Expr.WeekDays {
+			if wd.Number() == isoWeekday(d) {
+				found = true
+				break
+			}
+		}
+		if !found {
+			return false
+		}
+		return timeMatchesWithDST(schedule.Expr.Times)
 	}
 
 	return false
@@ -398,7 +551,8 @@ func nextDayRepeat(interval int, days DayFilter, times []TimeOfDay, loc *time.Lo
 		return nil
 	}
 
-	// Interval > 1: day intervals only apply to DayFilter::Every
+	// Interval > 1: the filter narrows the aligned days, skipping (not
+	// moving) any that fail it
 	anchorDate := epochDate
 	if anchor != "" {
 		anchorDate, _ = parseISODate(anchor)
@@ -416,9 +570,11 @@ func nextDayRepeat(interval int, days DayFilter, times []TimeOfDay, loc *time.Lo
 	}
 
 	for i := 0; i < 400; i++ {
-		candidate := earliestFutureAtTimes(alignedDate, times, loc, now)
-		if candidate != nil {
-			return candidate
+		if matchesDayFilter(alignedDate, days) {
+			candidate := earliestFutureAtTimes(alignedDate, times, loc, now)
+			if candidate != nil {
+				return candidate
+			}
 		}
 		alignedDate = alignedDate.AddDate(0, 0, interval)
 	}
@@ -426,6 +582,15 @@ func nextDayRepeat(interval int, days DayFilter, times []TimeOfDay, loc *time.Lo
 	return nil
 }
 
+// dayStepFilter returns the day filter of a day-step interval window, which
+// narrows the aligned days rather than shifting them.
+func dayStepFilter(expr ScheduleExpr) DayFilter {
+	if expr.DayFilter == nil {
+		return NewDayFilterEvery()
+	}
+	return *expr.DayFilter
+}
+
 func nextIntervalRepeat(interval int, unit IntervalUnit, fromTime, toTime TimeOfDay, dayFilter *DayFilter, loc *time.Location, now time.Time) *time.Time {
 	nowInTz := now.In(loc)
 	stepMinutes := interval
@@ -534,10 +699,12 @@ func nextWeekRepeat(interval int, days []Weekday, times []TimeOfDay, loc *time.L
 }
 
 func nextMonthRepeat(interval int, target MonthTarget, times []TimeOfDay, loc *time.Location, anchor string, now time.Time) *time.Time {
-	return nextMonthRepeatWithDuring(interval, target, times, loc, anchor, now, nil)
+	return nextMonthRepeatWithDuring(interval, target, times, loc, anchor, now, nil, nil)
 }
 
-func nextMonthRepeatWithDuring(interval int, target MonthTarget, times []TimeOfDay, loc *time.Location, anchor string, now time.Time, during []MonthName) *time.Time {
+// nextMonthRepeatWithDuring is nextMonthRepeat with the during filter applied.
+// Business-day targets skip over days exceptions remove.
+func nextMonthRepeatWithDuring(interval int, target MonthTarget, times []TimeOfDay, loc *time.Location, anchor string, now time.Time, during []MonthName, exceptions []ExceptionSpec) *time.Time {
 	nowInTz := now.In(loc)
 	year := nowInTz.Year()
 	month := int(nowInTz.Month())
@@ -604,6 +771,12 @@ func nextMonthRepeatWithDuring(interval int, target MonthTarget, times []TimeOfD
 			}
 		case MonthTargetKindLastDay:
 			dateCandidates = append(dateCandidates, lastDayOfMonth(year, time.Month(month)))
+		case MonthTargetKindLastNDays:
+			dateCandidates = append(dateCandidates, lastNDaysOfMonth(year, time.Month(month), target.N)...)
+		case MonthTargetKindBusinessDay:
+			if bd, ok := nthBusinessDay(year, time.Month(month), target.N, exceptions); ok {
+				dateCandidates = append(dateCandidates, bd)
+			}
 		case MonthTargetKindLastWeekday:
 			dateCandidates = append(dateCandidates, lastWeekdayOfMonth(year, time.Month(month)))
 		case MonthTargetKindNearestWeekday:
@@ -611,13 +784,11 @@ func nextMonthRepeatWithDuring(interval int, target MonthTarget, times []TimeOfD
 				dateCandidates = append(dateCandidates, nwd)
 			}
 		case MonthTargetKindOrdinalWeekday:
-			if target.Ordinal == Last {
-				dateCandidates = append(dateCandidates, lastWeekdayInMonth(year, time.Month(month), target.Weekday))
-			} else {
-				if od, ok := nthWeekdayOfMonth(year, time.Month(month), target.Weekday, target.Ordinal.ToN()); ok {
-					dateCandidates = append(dateCandidates, od)
-				}
+			if od, ok := ordinalWeekdayDate(year, time.Month(month), target.Ordinal, target.Weekday); ok {
+				dateCandidates = append(dateCandidates, od)
 			}
+		case MonthTargetKindOrdinalWeekdays:
+			dateCandidates = append(dateCandidates, ordinalWeekdayDates(year, time.Month(month), target.Ordinals, target.Weekday)...)
 		}
 
 		var best *time.Time
@@ -663,6 +834,20 @@ func nextSingleDate(dateSpec DateSpec, times []TimeOfDay, loc *time.Location, no
 			}
 		}
 		return nil
+	case DateSpecKindNextWeekday:
+		// Today if a time is still ahead, otherwise within the next week
+		today := dateOnly(nowInTz)
+		for offset := 0; offset < 8; offset++ {
+			d := today.AddDate(0, 0, offset)
+			if isoWeekday(d) != dateSpec.Weekday.Number() {
+				continue
+			}
+			candidate := earliestFutureAtTimes(d, times, loc, now)
+			if candidate != nil {
+				return candidate
+			}
+		}
+		return nil
 	}
 
 	return nil
@@ -727,6 +912,82 @@ func nextYearRepeat(interval int, target YearTarget, times []TimeOfDay, loc *tim
 	return nil
 }
 
+// isoYearHorizon is the number of ISO years searched for a matching week;
+// week 53 only exists in long ISO years.
+const isoYearHorizon = 7
+
+// isoWeekDates returns the dates in ISO week-numbering year on one of days in
+// one of weeks, ascending. Week 53 yields nothing in years with only 52 weeks.
+func isoWeekDates(year int, weeks []int, days []Weekday) []time.Time {
+	// Jan 4 is always in ISO week 1
+	jan4 := time.Date(year, time.January, 4, 0, 0, 0, 0, time.UTC)
+	week1Monday := jan4.AddDate(0, 0, 1-isoWeekday(jan4))
+
+	var dates []time.Time
+	for _, week := range weeks {
+		for _, wd := range days {
+			d := week1Monday.AddDate(0, 0, (week-1)*7+wd.Number()-1)
+			if y, _ := d.ISOWeek(); y != year {
+				continue
+			}
+			duplicate := false
+			for _, e := range dates {
+				if e.Equal(d) {
+					duplicate = true
+					break
+				}
+			}
+			if !duplicate {
+				dates = append(dates, d)
+			}
+		}
+	}
+	sort.Slice(dates, func(i, j int) bool {
+		return dates[i].Before(dates[j])
+	})
+	return dates
+}
+
+func nextISOWeekRepeat(weeks []int, days []Weekday, times []TimeOfDay, loc *time.Location, now time.Time) *time.Time {
+	startYear, _ := dateOnly(now.In(loc)).ISOWeek()
+	for year := startYear; year < startYear+isoYearHorizon; year++ {
+		for _, d := range isoWeekDates(year, weeks, days) {
+			candidate := earliestFutureAtTimes(d, times, loc, now)
+			if candidate != nil {
+				return candidate
+			}
+		}
+	}
+	return nil
+}
+
+// nextWeekdayRepeat returns today if it is an aligned business day with a time
+// still ahead, otherwise the next aligned business day.
+func nextWeekdayRepeat(interval int, times []TimeOfDay, loc *time.Location, anchor string, now time.Time) *time.Time {
+	today := dateOnly(now.In(loc))
+	anchorDate := epochDate
+	if anchor != "" {
+		anchorDate, _ = parseISODate(anchor)
+	}
+	anchorIndex := businessDayIndex(anchorDate)
+	todayIndex := businessDayIndex(today)
+	isWeekday := isoWeekday(today) <= 5
+
+	if isWeekday && euclideanMod(todayIndex-anchorIndex, interval) == 0 {
+		candidate := earliestFutureAtTimes(today, times, loc, now)
+		if candidate != nil {
+			return candidate
+		}
+	}
+	// A weekend already shares the following Monday's index
+	after := todayIndex
+	if isWeekday {
+		after++
+	}
+	index := after + euclideanMod(anchorIndex-after, interval)
+	return earliestFutureAtTimes(businessDayDate(index), times, loc, now)
+}
+
 // --- Iterator functions ---
 
 // Occurrences returns a lazy iterator of occurrences starting after `from`.
@@ -734,9 +995,10 @@ func nextYearRepeat(interval int, target YearTarget, times []TimeOfDay, loc *tim
 // but respects the `until` clause if specified in the schedule.
 func Occurrences(schedule *Schedule, from time.Time) iter.Seq[time.Time] {
 	return func(yield func(time.Time) bool) {
+		data := pinRelative(schedule.data, schedule.location, from)
 		current := from
 		for {
-			next := schedule.NextFrom(current)
+			next := nextFrom(data, schedule.location, current)
 			if next == nil {
 				return
 			}
@@ -766,15 +1028,22 @@ func Between(schedule *Schedule, from, to time.Time) iter.Seq[time.Time] {
 
 // --- Previous From ---
 
-// previousFrom computes the most recent occurrence strictly before now.
+// previousFrom computes the most recent occurrence strictly before now. With
+// "starting now" nothing occurs before now, so there is none.
 func previousFrom(schedule *ScheduleData, loc *time.Location, now time.Time) *time.Time {
+	if schedule.Anchor == "now" {
+		return nil
+	}
+	schedule = pinRelative(schedule, loc, now)
+	start := anchorStart(schedule, loc)
 	hasExceptions := len(schedule.Except) > 0
 	hasDuring := len(schedule.During) > 0
+	during := schedule.During
 
 	current := now
 
 	for i := 0; i < maxIterations; i++ {
-		candidate := prevExpr(schedule.Expr, loc, schedule.Anchor, current)
+		candidate := prevExpr(schedule.Expr, loc, schedule.Anchor, current, schedule.Except)
 		if candidate == nil {
 			return nil
 		}
@@ -782,11 +1051,8 @@ func previousFrom(schedule *ScheduleData, loc *time.Location, now time.Time) *ti
 		cDate := candidate.In(loc)
 
 		// Check starting anchor - if before anchor, no previous occurrence
-		if schedule.Anchor != "" {
-			anchorDate, _ := parseISODate(schedule.Anchor)
-			if dateOnly(cDate).Before(dateOnly(anchorDate)) {
-				return nil
-			}
+		if start != nil && candidate.Before(*start) {
+			return nil
 		}
 
 		// Apply until filter for previousFrom:
@@ -801,15 +1067,16 @@ func previousFrom(schedule *ScheduleData, loc *time.Location, now time.Time) *ti
 		}
 
 		// Apply during filter
-		if hasDuring && !matchesDuring(cDate, schedule.During) {
-			skipTo := prevDuringMonth(cDate, schedule.During)
+		if hasDuring && !matchesDuring(cDate, during) {
+			skipTo := prevDuringEnd(cDate, during)
 			current = atTimeOnDate(skipTo, TimeOfDay{23, 59}, loc).Add(time.Second)
 			continue
 		}
 
 		// Apply except filter
 		if hasExceptions && isExcepted(cDate, schedule.Except) {
-			prevDay := dateOnly(cDate).AddDate(0, 0, -1)
+			// Go back before this day (or the whole excepted range) and retry
+			prevDay := skipExceptionsBackward(cDate, schedule.Except)
 			current = atTimeOnDate(prevDay, TimeOfDay{23, 59}, loc).Add(time.Second)
 			continue
 		}
@@ -821,51 +1088,56 @@ func previousFrom(schedule *ScheduleData, loc *time.Location, now time.Time) *ti
 }
 
 // prevExpr dispatches to the appropriate prev function based on expression type.
-func prevExpr(expr ScheduleExpr, loc *time.Location, anchor string, now time.Time) *time.Time {
+func prevExpr(expr ScheduleExpr, loc *time.Location, anchor string, now time.Time, exceptions []ExceptionSpec) *time.Time {
 	switch expr.Kind {
 	case ScheduleExprKindDay:
 		return prevDayRepeat(expr.Interval, expr.Days, expr.Times, loc, anchor, now)
 	case ScheduleExprKindInterval:
+		if expr.Unit == IntervalDays {
+			return prevDayRepeat(expr.Interval, dayStepFilter(expr), []TimeOfDay{expr.FromTime}, loc, anchor, now)
+		}
 		return prevIntervalRepeat(expr.Interval, expr.Unit, expr.FromTime, expr.ToTime, expr.DayFilter, loc, now)
 	case ScheduleExprKindWeek:
 		return prevWeekRepeat(expr.Interval, expr.WeekDays, expr.Times, loc, anchor, now)
 	case ScheduleExprKindMonth:
-		return prevMonthRepeat(expr.Interval, expr.MonthTarget, expr.Times, loc, anchor, now)
+		return prevMonthRepeat(expr.Interval, expr.MonthTarget, expr.Times, loc, anchor, now, exceptions)
 	case ScheduleExprKindSingleDate:
 		return prevSingleDate(expr.DateSpec, expr.Times, loc, now)
 	case ScheduleExprKindYear:
 		return prevYearRepeat(expr.Interval, expr.YearTarget, expr.Times, loc, anchor, now)
+	case ScheduleExprKindISOWeek:
+		return prevISOWeekRepeat(expr.ISOWeeks, expr.WeekDays, expr.Times, loc, now)
+	case ScheduleExprKindWeekday:
+		return prevWeekdayRepeat(expr.Interval, expr.Times, loc, anchor, now)
 	default:
 		return nil
 	}
 }
 
-// prevDuringMonth finds the last day of the previous month in the during list.
-func prevDuringMonth(d time.Time, during []MonthName) time.Time {
-	duringSet := make(map[int]bool)
-	for _, mn := range during {
-		duringSet[mn.Number()] = true
-	}
-
-	year := d.Year()
-	month := int(d.Month()) - 1
-	if month < 1 {
-		month = 12
-		year--
-	}
-
-	for i := 0; i < 13; i++ {
-		if duringSet[month] {
-			return lastDayOfMonth(year, time.Month(month))
-		}
-		month--
-		if month < 1 {
-			month = 12
-			year--
+// prevDuringEnd returns the last day of the previous during period ending before d.
+func prevDuringEnd(d time.Time, during []DuringSpec) time.Time {
+	d = dateOnly(d)
+	var best *time.Time
+	for _, spec := range during {
+		_, endDay := spec.Bounds()
+		for _, year := range []int{d.Year(), d.Year() - 1} {
+			last := lastDayOfMonth(year, time.Month(spec.End.Number()))
+			end := last
+			if endDay < last.Day() {
+				end = time.Date(year, last.Month(), endDay, 0, 0, 0, 0, time.UTC)
+			}
+			if end.Before(d) {
+				if best == nil || end.After(*best) {
+					best = &end
+				}
+				break
+			}
 		}
 	}
-
-	return d.AddDate(0, 0, -1)
+	if best == nil {
+		return d
+	}
+	return *best
 }
 
 // latestPastAtTimes finds the latest time on date d that is strictly before now.
@@ -951,14 +1223,14 @@ func prevDayRepeat(interval int, days DayFilter, times []TimeOfDay, loc *time.Lo
 		alignedDate = d.AddDate(0, 0, -remainder)
 	}
 
-	for i := 0; i < 2; i++ {
-		candidate := latestPastAtTimes(alignedDate, times, loc, now)
-		if candidate != nil {
-			return candidate
-		}
-		latest := latestAtTimes(alignedDate, times, loc)
-		if latest != nil && latest.Before(now) {
-			return latest
+	// Eight aligned dates cover every weekday unless the interval is a
+	// multiple of 7, which the parser rejects when the filter can't match
+	for i := 0; i < 8; i++ {
+		if matchesDayFilter(alignedDate, days) {
+			candidate := latestPastAtTimes(alignedDate, times, loc, now)
+			if candidate != nil {
+				return candidate
+			}
 		}
 		alignedDate = alignedDate.AddDate(0, 0, -interval)
 	}
@@ -1081,7 +1353,7 @@ func prevWeekRepeat(interval int, days []Weekday, times []TimeOfDay, loc *time.L
 	return nil
 }
 
-func prevMonthRepeat(interval int, target MonthTarget, times []TimeOfDay, loc *time.Location, anchor string, now time.Time) *time.Time {
+func prevMonthRepeat(interval int, target MonthTarget, times []TimeOfDay, loc *time.Location, anchor string, now time.Time, exceptions []ExceptionSpec) *time.Time {
 	nowInTz := now.In(loc)
 	startDate := dateOnly(nowInTz)
 	year := nowInTz.Year()
@@ -1124,6 +1396,12 @@ func prevMonthRepeat(interval int, target MonthTarget, times []TimeOfDay, loc *t
 			}
 		case MonthTargetKindLastDay:
 			dateCandidates = append(dateCandidates, lastDayOfMonth(year, time.Month(month)))
+		case MonthTargetKindLastNDays:
+			dateCandidates = append(dateCandidates, lastNDaysOfMonth(year, time.Month(month), target.N)...)
+		case MonthTargetKindBusinessDay:
+			if bd, ok := nthBusinessDay(year, time.Month(month), target.N, exceptions); ok {
+				dateCandidates = append(dateCandidates, bd)
+			}
 		case MonthTargetKindLastWeekday:
 			dateCandidates = append(dateCandidates, lastWeekdayOfMonth(year, time.Month(month)))
 		case MonthTargetKindNearestWeekday:
@@ -1131,13 +1409,11 @@ func prevMonthRepeat(interval int, target MonthTarget, times []TimeOfDay, loc *t
 				dateCandidates = append(dateCandidates, nwd)
 			}
 		case MonthTargetKindOrdinalWeekday:
-			if target.Ordinal == Last {
-				dateCandidates = append(dateCandidates, lastWeekdayInMonth(year, time.Month(month), target.Weekday))
-			} else {
-				if od, ok := nthWeekdayOfMonth(year, time.Month(month), target.Weekday, target.Ordinal.ToN()); ok {
-					dateCandidates = append(dateCandidates, od)
-				}
+			if od, ok := ordinalWeekdayDate(year, time.Month(month), target.Ordinal, target.Weekday); ok {
+				dateCandidates = append(dateCandidates, od)
 			}
+		case MonthTargetKindOrdinalWeekdays:
+			dateCandidates = append(dateCandidates, ordinalWeekdayDates(year, time.Month(month), target.Ordinals, target.Weekday)...)
 		}
 
 		// Sort in reverse order for latest first
@@ -1216,6 +1492,9 @@ func prevSingleDate(dateSpec DateSpec, times []TimeOfDay, loc *time.Location, no
 			return latestAtTimes(lastYear, times, loc)
 		}
 		return nil
+	case DateSpecKindNextWeekday:
+		// previousFrom pins "on <weekday>" to an ISO date first
+		return nil
 	}
 
 	return nil
@@ -1289,3 +1568,38 @@ func prevYearRepeat(interval int, target YearTarget, times []TimeOfDay, loc *tim
 
 	return nil
 }
+
+func prevISOWeekRepeat(weeks []int, days []Weekday, times []TimeOfDay, loc *time.Location, now time.Time) *time.Time {
+	startYear, _ := dateOnly(now.In(loc)).ISOWeek()
+	for year := startYear; year > startYear-isoYearHorizon; year-- {
+		dates := isoWeekDates(year, weeks, days)
+		for i := len(dates) - 1; i >= 0; i-- {
+			candidate := latestPastAtTimes(dates[i], times, loc, now)
+			if candidate != nil {
+				return candidate
+			}
+		}
+	}
+	return nil
+}
+
+func prevWeekdayRepeat(interval int, times []TimeOfDay, loc *time.Location, anchor string, now time.Time) *time.Time {
+	today := dateOnly(now.In(loc))
+	anchorDate := epochDate
+	if anchor != "" {
+		anchorDate, _ = parseISODate(anchor)
+	}
+	anchorIndex := businessDayIndex(anchorDate)
+	todayIndex := businessDayIndex(today)
+
+	if isoWeekday(today) <= 5 && euclideanMod(todayIndex-anchorIndex, interval) == 0 {
+		candidate := latestPastAtTimes(today, times, loc, now)
+		if candidate != nil {
+			return candidate
+		}
+	}
+	// On a weekend the index is the following Monday's, so one back is Friday
+	before := todayIndex - 1
+	index := before - euclideanMod(before-anchorIndex, interval)
+	return latestAtTimes(businessDayDate(index), times, loc)
+}
@@ -77,6 +77,39 @@ func lastDayOfMonth(year int, month time.Month) time.Time {
 	return firstOfNext.AddDate(0, 0, -1)
 }
 
+// lastNDaysOfMonth returns the final n days of the month, ascending; the whole
+// month if it is shorter.
+func lastNDaysOfMonth(year int, month time.Month, n int) []time.Time {
+	last := lastDayOfMonth(year, month)
+	first := last.Day() - n + 1
+	if first < 1 {
+		first = 1
+	}
+	var dates []time.Time
+	for day := first; day <= last.Day(); day++ {
+		dates = append(dates, time.Date(year, month, day, 0, 0, 0, 0, time.UTC))
+	}
+	return dates
+}
+
+// nthBusinessDay returns the nth business day (Mon-Fri, 1-indexed) of a month,
+// not counting excepted days. It reports false if the month has fewer.
+func nthBusinessDay(year int, month time.Month, n int, exceptions []ExceptionSpec) (time.Time, bool) {
+	last := lastDayOfMonth(year, month)
+	count := 0
+	for day := 1; day <= last.Day(); day++ {
+		d := time.Date(year, month, day, 0, 0, 0, 0, time.UTC)
+		if isoWeekday(d) > 5 || isExcepted(d, exceptions) {
+			continue
+		}
+		count++
+		if count == n {
+			return d, true
+		}
+	}
+	return time.Time{}, false
+}
+
 // lastWeekdayOfMonth returns the last weekday (Mon-Fri) of the given month.
 func lastWeekdayOfMonth(year int, month time.Month) time.Time {
 	d := lastDayOfMonth(year, month)
@@ -124,6 +157,37 @@ func lastWeekdayInMonth(year int, month time.Month, weekday Weekday) time.Time {
 	return d
 }
 
+// ordinalWeekdayDate returns the date of an ordinal weekday in a month (e.g., the
+// second tuesday). Reports false if the month has no such occurrence.
+func ordinalWeekdayDate(year int, month time.Month, ordinal OrdinalPosition, weekday Weekday) (time.Time, bool) {
+	if ordinal == Last {
+		return lastWeekdayInMonth(year, month, weekday), true
+	}
+	return nthWeekdayOfMonth(year, month, weekday, ordinal.ToN())
+}
+
+// ordinalWeekdayDates returns the distinct dates of several ordinal weekdays in a month.
+func ordinalWeekdayDates(year int, month time.Month, ordinals []OrdinalPosition, weekday Weekday) []time.Time {
+	var dates []time.Time
+	for _, o := range ordinals {
+		d, ok := ordinalWeekdayDate(year, month, o, weekday)
+		if !ok {
+			continue
+		}
+		duplicate := false
+		for _, existing := range dates {
+			if existing.Equal(d) {
+				duplicate = true
+				break
+			}
+		}
+		if !duplicate {
+			dates = append(dates, d)
+		}
+	}
+	return dates
+}
+
 // weeksBetween returns the number of weeks between two dates.
 func weeksBetween(a, b time.Time) int {
 	days := int(b.Sub(a).Hours() / 24)
@@ -135,12 +199,45 @@ func daysBetween(a, b time.Time) int {
 	return int(b.Sub(a).Hours() / 24)
 }
 
+// euclideanMod returns a mod b in the range [0, b).
+func euclideanMod(a, b int) int {
+	return ((a % b) + b) % b
+}
+
+// businessDayIndex returns the number of business days (Monday-Friday) from
+// the week of the epoch to d. A weekend date shares the index of the following
+// Monday.
+func businessDayIndex(d time.Time) int {
+	// 1970-01-01 was a Thursday, 3 days after the Monday that starts its week
+	days := daysBetween(epochDate, dateOnly(d)) + 3
+	dayOfWeek := euclideanMod(days, 7)
+	weeks := (days - dayOfWeek) / 7
+	if dayOfWeek > 5 {
+		dayOfWeek = 5
+	}
+	return weeks*5 + dayOfWeek
+}
+
+// businessDayDate returns the weekday with the given business day index.
+func businessDayDate(index int) time.Time {
+	dayOfWeek := euclideanMod(index, 5)
+	weeks := (index - dayOfWeek) / 5
+	return epochDate.AddDate(0, 0, weeks*7+dayOfWeek-3)
+}
+
+// weekdayIntervalAligned reports whether d is a business day an "every N
+// weekdays" schedule fires on.
+func weekdayIntervalAligned(anchor time.Time, interval int, d time.Time) bool {
+	offset := businessDayIndex(d) - businessDayIndex(anchor)
+	return isoWeekday(d) <= 5 && offset >= 0 && offset%interval == 0
+}
+
 // monthsBetweenYM returns the number of months between two dates (based on year/month only).
 func monthsBetweenYM(a, b time.Time) int {
 	return (b.Year()*12 + int(b.Month())) - (a.Year()*12 + int(a.Month()))
 }
 
-// isExcepted checks if a date is in the exception list.
+// isExcepted checks if a date is in the exception list or falls on an excepted weekday.
 func isExcepted(d time.Time, exceptions []ExceptionSpec) bool {
 	for _, exc := range exceptions {
 		switch exc.Kind {
@@ -153,50 +250,133 @@ func isExcepted(d time.Time, exceptions []ExceptionSpec) bool {
 			if err == nil && d.Year() == excDate.Year() && d.Month() == excDate.Month() && d.Day() == excDate.Day() {
 				return true
 			}
+		case ExceptionSpecKindWeekday:
+			if isoWeekday(d) == exc.Weekday.Number() {
+				return true
+			}
+		case ExceptionSpecKindISORange, ExceptionSpecKindNamedRange:
+			if _, _, ok := rangeBounds(d, exc); ok {
+				return true
+			}
 		}
 	}
 	return false
 }
 
-// matchesDuring checks if a date falls within the specified months.
-func matchesDuring(d time.Time, during []MonthName) bool {
+// rangeBounds returns the concrete bounds of a range exception if it covers d.
+func rangeBounds(d time.Time, exc ExceptionSpec) (time.Time, time.Time, bool) {
+	d = dateOnly(d)
+	if exc.Kind == ExceptionSpecKindISORange {
+		start, err := parseISODate(exc.Date)
+		if err != nil {
+			return time.Time{}, time.Time{}, false
+		}
+		end, err := parseISODate(exc.EndDate)
+		if err != nil || d.Before(start) || d.After(end) {
+			return time.Time{}, time.Time{}, false
+		}
+		return start, end, true
+	}
+	startKey := exc.Month.Number()*100 + exc.Day
+	endKey := exc.EndMonth.Number()*100 + exc.EndDay
+	key := int(d.Month())*100 + d.Day()
+	var startYear, endYear int
+	switch {
+	case startKey <= endKey:
+		if key < startKey || key > endKey {
+			return time.Time{}, time.Time{}, false
+		}
+		startYear, endYear = d.Year(), d.Year()
+	case key >= startKey:
+		// Wraps into next year
+		startYear, endYear = d.Year(), d.Year()+1
+	case key <= endKey:
+		startYear, endYear = d.Year()-1, d.Year()
+	default:
+		return time.Time{}, time.Time{}, false
+	}
+	start := clampedDate(startYear, time.Month(exc.Month.Number()), exc.Day)
+	end := clampedDate(endYear, time.Month(exc.EndMonth.Number()), exc.EndDay)
+	return start, end, true
+}
+
+// clampedDate returns the date for month/day in year, with days past the
+// month's end (feb 29 in a common year) moved back to its last day.
+func clampedDate(year int, month time.Month, day int) time.Time {
+	last := lastDayOfMonth(year, month)
+	if day > last.Day() {
+		return last
+	}
+	return time.Date(year, month, day, 0, 0, 0, 0, time.UTC)
+}
+
+// skipExceptionsForward returns the day after the excepted run d falls in, so
+// long ranges are skipped in one step rather than day by day.
+func skipExceptionsForward(d time.Time, exceptions []ExceptionSpec) time.Time {
+	end := dateOnly(d)
+	for _, exc := range exceptions {
+		if _, rangeEnd, ok := rangeBounds(d, exc); ok && rangeEnd.After(end) {
+			end = rangeEnd
+		}
+	}
+	return end.AddDate(0, 0, 1)
+}
+
+// skipExceptionsBackward returns the day before the excepted run d falls in.
+func skipExceptionsBackward(d time.Time, exceptions []ExceptionSpec) time.Time {
+	start := dateOnly(d)
+	for _, exc := range exceptions {
+		if rangeStart, _, ok := rangeBounds(d, exc); ok && rangeStart.Before(start) {
+			start = rangeStart
+		}
+	}
+	return start.AddDate(0, 0, -1)
+}
+
+// matchesDuring checks if a date falls within any during specification.
+// If during is empty, all dates match.
+func matchesDuring(d time.Time, during []DuringSpec) bool {
 	if len(during) == 0 {
 		return true
 	}
-	for _, m := range during {
-		if int(d.Month()) == m.Number() {
+	for _, spec := range during {
+		if spec.Contains(MonthName(d.Month()), d.Day()) {
 			return true
 		}
 	}
 	return false
 }
 
-// nextDuringMonth returns the first day of the next allowed month.
-func nextDuringMonth(d time.Time, during []MonthName) time.Time {
-	currentMonth := int(d.Month())
-
-	// Sort months
-	months := make([]int, len(during))
-	for i, m := range during {
-		months[i] = m.Number()
+// monthDayInYear returns the month-day in year, or the day after the month's
+// end when the day does not exist that year (feb 29 in a common year).
+func monthDayInYear(year int, month MonthName, day int) time.Time {
+	last := lastDayOfMonth(year, time.Month(month.Number()))
+	if day > last.Day() {
+		return last.AddDate(0, 0, 1)
 	}
-	// Simple bubble sort for small slice
-	for i := 0; i < len(months)-1; i++ {
-		for j := i + 1; j < len(months); j++ {
-			if months[i] > months[j] {
-				months[i], months[j] = months[j], months[i]
+	return time.Date(year, time.Month(month.Number()), day, 0, 0, 0, 0, time.UTC)
+}
+
+// nextDuringStart returns the first day of the next during period starting after d.
+func nextDuringStart(d time.Time, during []DuringSpec) time.Time {
+	d = dateOnly(d)
+	var best *time.Time
+	for _, spec := range during {
+		startDay, _ := spec.Bounds()
+		for _, year := range []int{d.Year(), d.Year() + 1} {
+			start := monthDayInYear(year, spec.Start, startDay)
+			if start.After(d) {
+				if best == nil || start.Before(*best) {
+					best = &start
+				}
+				break
 			}
 		}
 	}
-
-	// Find next month after current
-	for _, m := range months {
-		if m > currentMonth {
-			return time.Date(d.Year(), time.Month(m), 1, 0, 0, 0, 0, time.UTC)
-		}
+	if best == nil {
+		return d
 	}
-	// Wrap to first month of next year
-	return time.Date(d.Year()+1, time.Month(months[0]), 1, 0, 0, 0, 0, time.UTC)
+	return *best
 }
 
 // resolveUntil converts an UntilSpec to a date.
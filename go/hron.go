@@ -87,7 +87,9 @@ func Validate(input string) bool {
 }
 
 // NextFrom computes the next occurrence after now.
-// Returns nil if there is no future occurrence.
+// Returns nil if there is no future occurrence. A starting date is both the
+// interval anchor and a lower bound, so when now is before it the result is on
+// or after it.
 func (s *Schedule) NextFrom(now time.Time) *time.Time {
 	return nextFrom(s.data, s.location, now)
 }
@@ -105,6 +107,8 @@ func (s *Schedule) PreviousFrom(now time.Time) *time.Time {
 }
 
 // Matches checks if a datetime matches this schedule.
+// It panics for `starting today` / `starting now` and for `on <weekday>`,
+// which need an evaluation time that Matches does not take.
 func (s *Schedule) Matches(dt time.Time) bool {
 	return matches(s.data, s.location, dt)
 }
@@ -297,6 +297,49 @@ func TestOccurrencesSingleDateTerminates(t *testing.T) {
 	}
 }
 
+func TestUpcomingWeekdayOccurrenceCannotBeMatched(t *testing.T) {
+	s, err := ParseSchedule("on friday at 17:00 in UTC")
+	if err != nil {
+		t.Fatalf("Parse failed: %v", err)
+	}
+
+	from, _ := time.Parse(time.RFC3339, "2026-02-06T12:00:00Z")
+
+	results := slices.Collect(s.Occurrences(from))
+
+	if len(results) != 1 {
+		t.Fatalf("expected 1 occurrence, got %d", len(results))
+	}
+	defer func() {
+		herr, ok := recover().(*HronError)
+		if !ok || herr.Kind != ErrorKindEval {
+			t.Errorf("expected eval error panic, got %v", herr)
+		}
+	}()
+	s.Matches(results[0])
+}
+
+func TestMatchesRejectsRelativeAnchor(t *testing.T) {
+	now, _ := time.Parse(time.RFC3339, "2026-02-06T12:00:00Z")
+
+	for _, anchor := range []string{"today", "now"} {
+		s, err := ParseSchedule("every 2 days at 09:00 starting " + anchor + " in UTC")
+		if err != nil {
+			t.Fatalf("Parse failed: %v", err)
+		}
+		func() {
+			defer func() {
+				r := recover()
+				herr, ok := r.(*HronError)
+				if !ok || herr.Kind != ErrorKindEval {
+					t.Errorf("starting %s: expected eval error panic, got %v", anchor, r)
+				}
+			}()
+			s.Matches(now)
+		}()
+	}
+}
+
 // =============================================================================
 // Timezone Handling
 // =============================================================================
@@ -41,6 +41,18 @@ const (
 	TokenNearest
 	TokenNext
 	TokenPrevious
+	TokenBetween
+	TokenAnd
+	TokenFrequency
+	TokenPeriod
+	TokenISO
+	TokenDecimal
+	TokenThrough
+	TokenBusiness
+	TokenPast
+	TokenMinuteOffset
+	TokenRelative
+	TokenShortcut
 )
 
 // Token represents a lexed token.
@@ -48,7 +60,7 @@ type Token struct {
 	Kind TokenKind
 	Span Span
 
-	// Value fields (only one is set based on Kind)
+	// Value fields (only one is set based on Kind; ordinals also set NumberVal)
 	DayNameVal   Weekday
 	MonthNameVal MonthName
 	OrdinalVal   OrdinalPosition
@@ -58,6 +70,12 @@ type Token struct {
 	TimeMinute   int
 	ISODateVal   string
 	TimezoneVal  string
+	FrequencyVal string
+	PeriodVal    string
+	RelativeVal  string
+	ShortcutVal  string
+	DecimalVal   string
+	MinuteVal    int
 }
 
 // lexer is the internal lexer state.
@@ -109,6 +127,22 @@ func (l *lexer) tokenize() ([]Token, error) {
 			continue
 		}
 
+		// Cron shortcut: @daily
+		if ch == '@' {
+			tokens = append(tokens, l.lexShortcut())
+			continue
+		}
+
+		// Minutes past the hour: :MM
+		if ch == ':' {
+			tok, err := l.lexMinuteOffset()
+			if err != nil {
+				return nil, err
+			}
+			tokens = append(tokens, tok)
+			continue
+		}
+
 		if isAlpha(ch) {
 			tok, err := l.lexWord()
 			if err != nil {
@@ -124,6 +158,15 @@ func (l *lexer) tokenize() ([]Token, error) {
 	return tokens, nil
 }
 
+// nextWordIs reports whether the next word in the input is word.
+func (l *lexer) nextWordIs(word string) bool {
+	rest := strings.TrimLeft(l.input[l.pos:], " \t\n\r")
+	if len(rest) < len(word) || strings.ToLower(rest[:len(word)]) != word {
+		return false
+	}
+	return len(rest) == len(word) || !(isAlphanumeric(rest[len(word)]) || rest[len(word)] == '_')
+}
+
 func (l *lexer) skipWhitespace() {
 	for l.pos < len(l.input) && isWhitespace(l.input[l.pos]) {
 		l.pos++
@@ -143,6 +186,30 @@ func (l *lexer) lexTimezone() (Token, error) {
 	return Token{Kind: TokenTimezone, Span: Span{start, l.pos}, TimezoneVal: tz}, nil
 }
 
+func (l *lexer) lexShortcut() Token {
+	start := l.pos
+	l.pos++ // skip '@'
+	for l.pos < len(l.input) && isAlphanumeric(l.input[l.pos]) {
+		l.pos++
+	}
+	name := strings.ToLower(l.input[start:l.pos])
+	return Token{Kind: TokenShortcut, Span: Span{start, l.pos}, ShortcutVal: name}
+}
+
+func (l *lexer) lexMinuteOffset() (Token, error) {
+	start := l.pos
+	l.pos++ // skip ':'
+	for l.pos < len(l.input) && isDigit(l.input[l.pos]) {
+		l.pos++
+	}
+	digits := l.input[start+1 : l.pos]
+	minute, err := strconv.Atoi(digits)
+	if len(digits) != 2 || err != nil || minute > 59 {
+		return Token{}, LexError("expected two-digit minute (:00 to :59) after ':'", Span{start, l.pos}, l.input)
+	}
+	return Token{Kind: TokenMinuteOffset, Span: Span{start, l.pos}, MinuteVal: minute}, nil
+}
+
 func (l *lexer) lexNumberOrTimeOrDate() (Token, error) {
 	start := l.pos
 
@@ -185,6 +252,9 @@ func (l *lexer) lexNumberOrTimeOrDate() (Token, error) {
 			if err != nil {
 				return Token{}, LexError("invalid time minute", Span{start, l.pos}, l.input)
 			}
+			if pm, ok := l.lexMeridiem(); ok {
+				return l.meridiemTime(hour, minute, pm, start)
+			}
 			if hour > 23 || minute > 59 {
 				return Token{}, LexError("invalid time", Span{start, l.pos}, l.input)
 			}
@@ -192,6 +262,23 @@ func (l *lexer) lexNumberOrTimeOrDate() (Token, error) {
 		}
 	}
 
+	// Check for 12-hour time without minutes: 9am, 5pm
+	if len(digits) == 1 || len(digits) == 2 {
+		if pm, ok := l.lexMeridiem(); ok {
+			hour, _ := strconv.Atoi(digits)
+			return l.meridiemTime(hour, 0, pm, start)
+		}
+	}
+
+	// Check for decimal: 1.5
+	if l.pos+1 < len(l.input) && l.input[l.pos] == '.' && isDigit(l.input[l.pos+1]) {
+		l.pos++ // skip '.'
+		for l.pos < len(l.input) && isDigit(l.input[l.pos]) {
+			l.pos++
+		}
+		return Token{Kind: TokenDecimal, Span: Span{start, l.pos}, DecimalVal: l.input[start:l.pos]}, nil
+	}
+
 	num, err := strconv.Atoi(digits)
 	if err != nil {
 		return Token{}, LexError("invalid number", Span{start, l.pos}, l.input)
@@ -209,29 +296,77 @@ func (l *lexer) lexNumberOrTimeOrDate() (Token, error) {
 	return Token{Kind: TokenNumber, Span: Span{start, l.pos}, NumberVal: num}, nil
 }
 
+// lexMeridiem consumes an am/pm suffix directly following a time.
+// It reports whether the suffix was pm, and whether there was a suffix at all.
+func (l *lexer) lexMeridiem() (bool, bool) {
+	if l.pos+2 > len(l.input) {
+		return false, false
+	}
+	suffix := strings.ToLower(l.input[l.pos : l.pos+2])
+	if suffix != "am" && suffix != "pm" {
+		return false, false
+	}
+	// Must not run into a longer word (e.g. "9amx")
+	if l.pos+2 < len(l.input) && isAlphanumeric(l.input[l.pos+2]) {
+		return false, false
+	}
+	l.pos += 2
+	return suffix == "pm", true
+}
+
+// meridiemTime converts a 12-hour clock time to a 24-hour time token.
+func (l *lexer) meridiemTime(hour, minute int, pm bool, start int) (Token, error) {
+	if hour < 1 || hour > 12 || minute > 59 {
+		return Token{}, LexError("invalid 12-hour time (hour must be 1-12)", Span{start, l.pos}, l.input)
+	}
+	h := hour
+	if h == 12 {
+		if !pm {
+			h = 0
+		}
+	} else if pm {
+		h += 12
+	}
+	return Token{Kind: TokenTime, Span: Span{start, l.pos}, TimeHour: h, TimeMinute: minute}, nil
+}
+
 func (l *lexer) lexWord() (Token, error) {
 	start := l.pos
-	for l.pos < len(l.input) && (isAlphanumeric(l.input[l.pos]) || l.input[l.pos] == '_') {
+	for l.pos < len(l.input) && (isAlphanumeric(l.input[l.pos]) || l.input[l.pos] == '_' || l.isWordHyphen()) {
 		l.pos++
 	}
 	word := strings.ToLower(l.input[start:l.pos])
 	span := Span{start, l.pos}
 
-	// Check keyword map
+	// Check keyword map, then ordinal words ("first" through "thirty-first")
 	tok, ok := keywordMap[word]
 	if !ok {
-		return Token{}, LexError("unknown keyword '"+word+"'", span, l.input)
+		n, isOrdinal := ordinalWordValue(word)
+		if !isOrdinal {
+			return Token{}, LexError("unknown keyword '"+word+"'", span, l.input)
+		}
+		tok = Token{Kind: TokenOrdinal, NumberVal: n}
+		if n <= int(Fifth) {
+			tok.OrdinalVal = OrdinalPosition(n)
+		}
 	}
 
 	tok.Span = span
 
 	if tok.Kind == TokenIn {
-		l.afterIn = true
+		// "in the morning" and "in iso weeks" are not timezone clauses
+		l.afterIn = !l.nextWordIs("the") && !l.nextWordIs("iso")
 	}
 
 	return tok, nil
 }
 
+// isWordHyphen reports whether the current byte is a hyphen joining two
+// words, as in "twenty-first".
+func (l *lexer) isWordHyphen() bool {
+	return l.input[l.pos] == '-' && l.pos+1 < len(l.input) && isAlpha(l.input[l.pos+1])
+}
+
 // keywordMap maps lowercase keywords to tokens.
 var keywordMap = map[string]Token{
 	"every":    {Kind: TokenEvery},
@@ -239,6 +374,9 @@ var keywordMap = map[string]Token{
 	"at":       {Kind: TokenAt},
 	"from":     {Kind: TokenFrom},
 	"to":       {Kind: TokenTo},
+	"through":  {Kind: TokenThrough},
+	"business": {Kind: TokenBusiness},
+	"past":     {Kind: TokenPast},
 	"in":       {Kind: TokenIn},
 	"of":       {Kind: TokenOf},
 	"the":      {Kind: TokenThe},
@@ -259,21 +397,41 @@ var keywordMap = map[string]Token{
 	"week":     {Kind: TokenWeeks},
 	"month":    {Kind: TokenMonth},
 	"months":   {Kind: TokenMonth},
+	"daily":    {Kind: TokenFrequency, FrequencyVal: "daily"},
+	"weekly":   {Kind: TokenFrequency, FrequencyVal: "weekly"},
+	"monthly":  {Kind: TokenFrequency, FrequencyVal: "monthly"},
+	"yearly":   {Kind: TokenFrequency, FrequencyVal: "yearly"},
+	"hourly":   {Kind: TokenFrequency, FrequencyVal: "hourly"},
+	// Times of day
+	"morning":   {Kind: TokenPeriod, PeriodVal: "morning"},
+	"afternoon": {Kind: TokenPeriod, PeriodVal: "afternoon"},
+	"evening":   {Kind: TokenPeriod, PeriodVal: "evening"},
+	"night":     {Kind: TokenPeriod, PeriodVal: "night"},
+	// Relative anchors
+	"today": {Kind: TokenRelative, RelativeVal: "today"},
+	"now":   {Kind: TokenRelative, RelativeVal: "now"},
 	// Day names
-	"monday":    {Kind: TokenDayName, DayNameVal: Monday},
-	"mon":       {Kind: TokenDayName, DayNameVal: Monday},
-	"tuesday":   {Kind: TokenDayName, DayNameVal: Tuesday},
-	"tue":       {Kind: TokenDayName, DayNameVal: Tuesday},
-	"wednesday": {Kind: TokenDayName, DayNameVal: Wednesday},
-	"wed":       {Kind: TokenDayName, DayNameVal: Wednesday},
-	"thursday":  {Kind: TokenDayName, DayNameVal: Thursday},
-	"thu":       {Kind: TokenDayName, DayNameVal: Thursday},
-	"friday":    {Kind: TokenDayName, DayNameVal: Friday},
-	"fri":       {Kind: TokenDayName, DayNameVal: Friday},
-	"saturday":  {Kind: TokenDayName, DayNameVal: Saturday},
-	"sat":       {Kind: TokenDayName, DayNameVal: Saturday},
-	"sunday":    {Kind: TokenDayName, DayNameVal: Sunday},
-	"sun":       {Kind: TokenDayName, DayNameVal: Sunday},
+	"monday":     {Kind: TokenDayName, DayNameVal: Monday},
+	"mondays":    {Kind: TokenDayName, DayNameVal: Monday},
+	"mon":        {Kind: TokenDayName, DayNameVal: Monday},
+	"tuesday":    {Kind: TokenDayName, DayNameVal: Tuesday},
+	"tuesdays":   {Kind: TokenDayName, DayNameVal: Tuesday},
+	"tue":        {Kind: TokenDayName, DayNameVal: Tuesday},
+	"wednesday":  {Kind: TokenDayName, DayNameVal: Wednesday},
+	"wednesdays": {Kind: TokenDayName, DayNameVal: Wednesday},
+	"wed":        {Kind: TokenDayName, DayNameVal: Wednesday},
+	"thursday":   {Kind: TokenDayName, DayNameVal: Thursday},
+	"thursdays":  {Kind: TokenDayName, DayNameVal: Thursday},
+	"thu":        {Kind: TokenDayName, DayNameVal: Thursday},
+	"friday":     {Kind: TokenDayName, DayNameVal: Friday},
+	"fridays":    {Kind: TokenDayName, DayNameVal: Friday},
+	"fri":        {Kind: TokenDayName, DayNameVal: Friday},
+	"saturday":   {Kind: TokenDayName, DayNameVal: Saturday},
+	"saturdays":  {Kind: TokenDayName, DayNameVal: Saturday},
+	"sat":        {Kind: TokenDayName, DayNameVal: Saturday},
+	"sunday":     {Kind: TokenDayName, DayNameVal: Sunday},
+	"sundays":    {Kind: TokenDayName, DayNameVal: Sunday},
+	"sun":        {Kind: TokenDayName, DayNameVal: Sunday},
 	// Month names
 	"january":   {Kind: TokenMonthName, MonthNameVal: Jan},
 	"jan":       {Kind: TokenMonthName, MonthNameVal: Jan},
@@ -298,16 +456,13 @@ var keywordMap = map[string]Token{
 	"nov":       {Kind: TokenMonthName, MonthNameVal: Nov},
 	"december":  {Kind: TokenMonthName, MonthNameVal: Dec},
 	"dec":       {Kind: TokenMonthName, MonthNameVal: Dec},
-	// Ordinals
-	"first":  {Kind: TokenOrdinal, OrdinalVal: First},
-	"second": {Kind: TokenOrdinal, OrdinalVal: Second},
-	"third":  {Kind: TokenOrdinal, OrdinalVal: Third},
-	"fourth": {Kind: TokenOrdinal, OrdinalVal: Fourth},
-	"fifth":  {Kind: TokenOrdinal, OrdinalVal: Fifth},
 	// Nearest weekday keywords
 	"nearest":  {Kind: TokenNearest},
 	"next":     {Kind: TokenNext},
 	"previous": {Kind: TokenPrevious},
+	"between":  {Kind: TokenBetween},
+	"and":      {Kind: TokenAnd},
+	"iso":      {Kind: TokenISO},
 	// Interval units
 	"min":     {Kind: TokenIntervalUnit, UnitVal: IntervalMin},
 	"mins":    {Kind: TokenIntervalUnit, UnitVal: IntervalMin},
@@ -319,6 +474,43 @@ var keywordMap = map[string]Token{
 	"hrs":     {Kind: TokenIntervalUnit, UnitVal: IntervalHours},
 }
 
+var ordinalUnits = []string{"first", "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth", "ninth"}
+
+var ordinalTeens = []string{"tenth", "eleventh", "twelfth", "thirteenth", "fourteenth", "fifteenth", "sixteenth", "seventeenth", "eighteenth", "nineteenth"}
+
+// ordinalWordValue returns the day number of an ordinal word: "first" is 1,
+// "thirty-first" is 31.
+func ordinalWordValue(word string) (int, bool) {
+	switch word {
+	case "twentieth":
+		return 20, true
+	case "thirtieth":
+		return 30, true
+	case "thirty-first":
+		return 31, true
+	}
+	if strings.HasPrefix(word, "twenty-") {
+		unit := strings.TrimPrefix(word, "twenty-")
+		for i, u := range ordinalUnits {
+			if u == unit {
+				return 21 + i, true
+			}
+		}
+		return 0, false
+	}
+	for i, u := range ordinalUnits {
+		if u == word {
+			return 1 + i, true
+		}
+	}
+	for i, t := range ordinalTeens {
+		if t == word {
+			return 10 + i, true
+		}
+	}
+	return 0, false
+}
+
 // Helper functions
 
 func isDigit(b byte) bool {
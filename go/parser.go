@@ -2,9 +2,21 @@ package hron
 
 import (
 	"fmt"
+	"sort"
+	"strconv"
+	"strings"
 	"time"
 )
 
+// periodTimes maps input-only "in the <period>" sugar to the fixed time it
+// fires at; it displays as HH:MM.
+var periodTimes = map[string]TimeOfDay{
+	"morning":   {9, 0},
+	"afternoon": {14, 0},
+	"evening":   {18, 0},
+	"night":     {21, 0},
+}
+
 // parser is the internal parser state.
 type parser struct {
 	tokens []Token
@@ -113,8 +125,15 @@ func (p *parser) parseExpression() (*ScheduleData, error) {
 	case TokenOn:
 		p.advance()
 		expr, err = p.parseOn()
+	case TokenFrequency:
+		expr, err = p.parseFrequency()
+	case TokenShortcut:
+		expr, err = p.parseShortcut()
+	case TokenAt:
+		p.advance()
+		expr, err = p.parsePastEveryHour()
 	default:
-		return nil, p.error("expected 'every' or 'on'", span)
+		return nil, p.error("expected 'every', 'on', 'at', or a frequency keyword", span)
 	}
 
 	if err != nil {
@@ -156,19 +175,46 @@ func (p *parser) parseTrailingClauses(expr ScheduleExpr) (*ScheduleData, error)
 			}
 			schedule.Anchor = p.peek().ISODateVal
 			p.advance()
+		} else if p.peekKind() == TokenMonthName {
+			month := p.peek().MonthNameVal
+			p.advance()
+			dayPos := p.currentSpan().Start
+			day, err := p.parseDayNumber("expected day number after month name in starting")
+			if err != nil {
+				return nil, err
+			}
+			if err := p.validateNamedDate(month, day, dayPos); err != nil {
+				return nil, err
+			}
+			named := NewNamedDate(month, day)
+			schedule.AnchorNamed = &named
+		} else if p.peekKind() == TokenRelative {
+			schedule.Anchor = p.peek().RelativeVal
+			p.advance()
 		} else {
-			return nil, p.error("expected ISO date (YYYY-MM-DD) after 'starting'", p.currentSpan())
+			return nil, p.error("expected ISO date (YYYY-MM-DD), month-day, 'today', or 'now' after 'starting'", p.currentSpan())
+		}
+		if p.peekKind() == TokenAt {
+			if schedule.Anchor == "now" {
+				return nil, p.error("'starting now' already includes a time", p.currentSpan())
+			}
+			p.advance()
+			t, err := p.parseTime()
+			if err != nil {
+				return nil, err
+			}
+			schedule.AnchorTime = &t
 		}
 	}
 
 	// during
 	if p.peekKind() == TokenDuring {
 		p.advance()
-		months, err := p.parseMonthList()
+		specs, err := p.parseDuringList()
 		if err != nil {
 			return nil, err
 		}
-		schedule.During = months
+		schedule.During = specs
 	}
 
 	// in <timezone>
@@ -182,58 +228,168 @@ func (p *parser) parseTrailingClauses(expr ScheduleExpr) (*ScheduleData, error)
 		}
 	}
 
+	if err := p.checkDayStepsReachable(schedule); err != nil {
+		return nil, err
+	}
 	return schedule, nil
 }
 
+// checkDayStepsReachable rejects a day step whose filter excludes every aligned
+// day. A step that is a multiple of 7 lands on the anchor's weekday every time,
+// so a filter without that weekday never fires. With "starting today" or
+// "starting now" that weekday depends on the evaluation date, so such a step
+// needs a filter that lets every day through.
+func (p *parser) checkDayStepsReachable(schedule *ScheduleData) error {
+	expr := schedule.Expr
+	var filter DayFilter
+	switch {
+	case expr.Kind == ScheduleExprKindDay:
+		filter = expr.Days
+	case expr.Kind == ScheduleExprKindInterval && expr.Unit == IntervalDays && expr.DayFilter != nil:
+		filter = *expr.DayFilter
+	default:
+		return nil
+	}
+	if expr.Interval%7 != 0 {
+		return nil
+	}
+	var allowed []Weekday
+	switch filter.Kind {
+	case DayFilterKindEvery:
+		return nil
+	case DayFilterKindWeekday:
+		allowed = allWeekdays
+	case DayFilterKindWeekend:
+		allowed = allWeekend
+	case DayFilterKindDays:
+		allowed = filter.Days
+	}
+	if hasRelativeAnchor(schedule) {
+		seen := make(map[Weekday]bool)
+		for _, day := range allowed {
+			seen[day] = true
+		}
+		if len(seen) == 7 {
+			return nil
+		}
+		return p.error(fmt.Sprintf("every %d days starting %s lands on the weekday of the evaluation date, which the day filter may exclude", expr.Interval, displayAnchor(schedule)), Span{0, len(p.input)})
+	}
+	anchor := epochDate
+	if schedule.Anchor != "" {
+		anchor, _ = parseISODate(schedule.Anchor)
+	}
+	weekday, _ := WeekdayFromNumber(isoWeekday(anchor))
+	for _, day := range allowed {
+		if day == weekday {
+			return nil
+		}
+	}
+	return p.error(fmt.Sprintf("every %d days always lands on a %s, which the day filter excludes", expr.Interval, weekday.String()), Span{0, len(p.input)})
+}
+
+// "except [on] exception, ...": dates, day names, "weekday", "weekend"
 func (p *parser) parseExceptionList() ([]ExceptionSpec, error) {
-	exc, err := p.parseException()
+	if p.peekKind() == TokenOn {
+		p.advance()
+	}
+	exceptions, err := p.parseException()
 	if err != nil {
 		return nil, err
 	}
-	exceptions := []ExceptionSpec{exc}
 
 	for p.peekKind() == TokenComma {
 		p.advance()
-		exc, err := p.parseException()
+		excs, err := p.parseException()
 		if err != nil {
 			return nil, err
 		}
-		exceptions = append(exceptions, exc)
+		exceptions = append(exceptions, excs...)
 	}
 
 	return exceptions, nil
 }
 
-func (p *parser) parseException() (ExceptionSpec, error) {
+func (p *parser) parseException() ([]ExceptionSpec, error) {
 	tok := p.peek()
 	if tok == nil {
-		return ExceptionSpec{}, p.errorAtEnd("expected exception date")
+		return nil, p.errorAtEnd("expected exception date")
 	}
 
 	switch tok.Kind {
 	case TokenISODate:
 		p.advance()
 		if err := p.validateIsoDate(tok.ISODateVal); err != nil {
-			return ExceptionSpec{}, err
+			return nil, err
+		}
+		if !p.atRangeSeparator() {
+			return []ExceptionSpec{NewISOException(tok.ISODateVal)}, nil
+		}
+		p.advance()
+		span := p.currentSpan()
+		end := p.peek()
+		if end == nil || end.Kind != TokenISODate {
+			return nil, p.error("expected ISO date after 'to'", span)
 		}
-		return NewISOException(tok.ISODateVal), nil
+		if err := p.validateIsoDate(end.ISODateVal); err != nil {
+			return nil, err
+		}
+		// ISO dates order lexically
+		if end.ISODateVal < tok.ISODateVal {
+			return nil, p.error(fmt.Sprintf("exception range ends before %s", tok.ISODateVal), span)
+		}
+		p.advance()
+		return []ExceptionSpec{NewISORangeException(tok.ISODateVal, end.ISODateVal)}, nil
 	case TokenMonthName:
 		month := tok.MonthNameVal
 		p.advance()
 		dayPos := p.currentSpan().Start
 		day, err := p.parseDayNumber("expected day number after month name in exception")
 		if err != nil {
-			return ExceptionSpec{}, err
+			return nil, err
 		}
 		if err := p.validateNamedDate(month, day, dayPos); err != nil {
-			return ExceptionSpec{}, err
+			return nil, err
 		}
-		return NewNamedException(month, day), nil
+		if !p.atRangeSeparator() {
+			return []ExceptionSpec{NewNamedException(month, day)}, nil
+		}
+		p.advance()
+		end := p.peek()
+		if end == nil || end.Kind != TokenMonthName {
+			return nil, p.error("expected month name after 'to'", p.currentSpan())
+		}
+		p.advance()
+		endPos := p.currentSpan().Start
+		endDay, err := p.parseDayNumber("expected day number after month name in exception")
+		if err != nil {
+			return nil, err
+		}
+		if err := p.validateNamedDate(end.MonthNameVal, endDay, endPos); err != nil {
+			return nil, err
+		}
+		return []ExceptionSpec{NewNamedRangeException(month, day, end.MonthNameVal, endDay)}, nil
+	case TokenDayName:
+		p.advance()
+		return []ExceptionSpec{NewWeekdayException(tok.DayNameVal)}, nil
+	case TokenWeekday:
+		p.advance()
+		return weekdayExceptions(allWeekdays), nil
+	case TokenWeekend:
+		p.advance()
+		return weekdayExceptions(allWeekend), nil
 	default:
-		return ExceptionSpec{}, p.error("expected ISO date or month-day in exception", p.currentSpan())
+		return nil, p.error("expected ISO date, month-day, or day name in exception", p.currentSpan())
 	}
 }
 
+func weekdayExceptions(days []Weekday) []ExceptionSpec {
+	exceptions := make([]ExceptionSpec, len(days))
+	for i, day := range days {
+		exceptions[i] = NewWeekdayException(day)
+	}
+	return exceptions
+}
+
 func (p *parser) parseUntilSpec() (UntilSpec, error) {
 	tok := p.peek()
 	if tok == nil {
@@ -272,12 +428,7 @@ func (p *parser) validateDayNumber(n int) error {
 }
 
 func (p *parser) validateNamedDate(month MonthName, day int, pos int) error {
-	maxDays := map[MonthName]int{
-		Jan: 31, Feb: 29, Mar: 31, Apr: 30,
-		May: 31, Jun: 30, Jul: 31, Aug: 31,
-		Sep: 30, Oct: 31, Nov: 30, Dec: 31,
-	}
-	max := maxDays[month]
+	max := month.MaxDay()
 	if day > max {
 		return p.error(fmt.Sprintf("invalid day %d for %s (max %d)", day, month, max), Span{pos, pos})
 	}
@@ -319,6 +470,7 @@ func (p *parser) parseEvery() (ScheduleExpr, error) {
 		p.advance()
 		return p.parseYearRepeat(1)
 	case TokenDay:
+		p.advance()
 		return p.parseDayRepeat(1, NewDayFilterEvery())
 	case TokenWeekday:
 		p.advance()
@@ -331,15 +483,34 @@ func (p *parser) parseEvery() (ScheduleExpr, error) {
 		if err != nil {
 			return ScheduleExpr{}, err
 		}
-		return p.parseDayRepeat(1, NewDayFilterDays(days))
+		// "every monday every 2 weeks at ..." is a week repeat
+		if p.peekKind() == TokenEvery {
+			p.advance()
+			interval, err := p.parseWeekInterval()
+			if err != nil {
+				return ScheduleExpr{}, err
+			}
+			times, err := p.parseTimesOrMidnight()
+			if err != nil {
+				return ScheduleExpr{}, err
+			}
+			return NewWeekRepeat(interval, days, times), nil
+		}
+		return p.parseDayRepeat(1, canonicalDayFilter(NewDayFilterDays(days)))
 	case TokenWeeks:
 		p.advance()
 		return p.parseWeekRepeat(1)
 	case TokenMonth:
 		p.advance()
 		return p.parseMonthRepeat(1)
+	case TokenIntervalUnit:
+		// "every hour at :15", "every minute from 09:00 to 10:00"
+		return p.parseIntervalRepeat(1)
 	case TokenNumber:
 		return p.parseNumberRepeat()
+	case TokenDecimal:
+		// "every 1.5 hours from ..."
+		return p.parseFractionalInterval()
 	default:
 		return ScheduleExpr{}, p.error(
 			"expected day, weekday, weekend, year, day name, month, or number after 'every'",
@@ -348,16 +519,72 @@ func (p *parser) parseEvery() (ScheduleExpr, error) {
 	}
 }
 
-func (p *parser) parseDayRepeat(interval int, days DayFilter) (ScheduleExpr, error) {
-	if days.Kind == DayFilterKindEvery {
-		if _, err := p.consume("'day'", TokenDay); err != nil {
+// Cron @ shortcut: "@daily" is "every day at 00:00", and takes the same trailing
+// clauses as that form ("@daily in UTC").
+func (p *parser) parseShortcut() (ScheduleExpr, error) {
+	tok := p.advance()
+	expr, ok := cronShortcutExpr(tok.ShortcutVal)
+	if !ok {
+		return ScheduleExpr{}, ParseError("unknown @ shortcut: "+tok.ShortcutVal, tok.Span, p.input, "@yearly, @monthly, @weekly, @daily, @midnight, or @hourly")
+	}
+	return expr, nil
+}
+
+// Bare shorthand: "daily", "weekly", "monthly", "yearly", or "hourly". Each
+// maps to the canonical "every ..." form with interval 1 and accepts what that
+// form does. Without "on", weekly falls on monday (the default week start),
+// monthly on the 1st, and yearly on jan 1; without a window, hourly runs all day.
+func (p *parser) parseFrequency() (ScheduleExpr, error) {
+	tok := p.advance()
+
+	next := p.peekKind()
+	hasOn := next == TokenOn
+	switch tok.FrequencyVal {
+	case "daily":
+		return p.parseDayRepeat(1, NewDayFilterEvery())
+	case "weekly":
+		if hasOn {
+			return p.parseWeekRepeat(1)
+		}
+		times, err := p.parseTimesOrMidnight()
+		if err != nil {
 			return ScheduleExpr{}, err
 		}
+		return NewWeekRepeat(1, []Weekday{Monday}, times), nil
+	case "monthly":
+		if hasOn {
+			return p.parseMonthRepeat(1)
+		}
+		times, err := p.parseTimesOrMidnight()
+		if err != nil {
+			return ScheduleExpr{}, err
+		}
+		return NewMonthRepeat(1, NewDaysTarget([]DayOfMonthSpec{NewSingleDay(1)}), times), nil
+	case "yearly":
+		// "yearly in iso weeks ..." as well as "yearly on ..."
+		if hasOn || (next == TokenIn && p.pos+1 < len(p.tokens) && p.tokens[p.pos+1].Kind == TokenISO) {
+			return p.parseYearRepeat(1)
+		}
+		times, err := p.parseTimesOrMidnight()
+		if err != nil {
+			return ScheduleExpr{}, err
+		}
+		return NewYearRepeat(1, NewYearDateTarget(Jan, 1), times), nil
+	default:
+		// "hourly at :15" and "hourly from 09:00 to 17:00" as after "every hour"
+		if next == TokenAt || next == TokenFrom || next == TokenBetween {
+			return p.parseIntervalWindow(1, IntervalHours)
+		}
+		dayFilter, err := p.parseOptionalDayFilter()
+		if err != nil {
+			return ScheduleExpr{}, err
+		}
+		return hourlyAt(1, 0, dayFilter), nil
 	}
-	if _, err := p.consume("'at'", TokenAt); err != nil {
-		return ScheduleExpr{}, err
-	}
-	times, err := p.parseTimeList()
+}
+
+func (p *parser) parseDayRepeat(interval int, days DayFilter) (ScheduleExpr, error) {
+	times, err := p.parseTimesOrMidnight()
 	if err != nil {
 		return ScheduleExpr{}, err
 	}
@@ -380,7 +607,34 @@ func (p *parser) parseNumberRepeat() (ScheduleExpr, error) {
 	case TokenIntervalUnit:
 		return p.parseIntervalRepeat(num)
 	case TokenDay:
-		return p.parseDayRepeat(num, NewDayFilterEvery())
+		// "every N days from HH:MM to HH:MM" - day-step interval window
+		if p.nextIsWindow() {
+			p.advance()
+			return p.parseIntervalWindow(num, IntervalDays)
+		}
+		// "every N days [on day_target] at ..."
+		p.advance()
+		days := NewDayFilterEvery()
+		if p.peekKind() == TokenOn {
+			p.advance()
+			var err error
+			days, err = p.parseDayTarget()
+			if err != nil {
+				return ScheduleExpr{}, err
+			}
+		}
+		return p.parseDayRepeat(num, days)
+	case TokenWeekday:
+		// "every N weekdays at ..." - every Nth business day
+		p.advance()
+		if num == 1 {
+			return p.parseDayRepeat(1, NewDayFilterWeekday())
+		}
+		times, err := p.parseTimesOrMidnight()
+		if err != nil {
+			return ScheduleExpr{}, err
+		}
+		return NewWeekdayRepeat(num, times), nil
 	case TokenMonth:
 		p.advance()
 		return p.parseMonthRepeat(num)
@@ -389,7 +643,7 @@ func (p *parser) parseNumberRepeat() (ScheduleExpr, error) {
 		return p.parseYearRepeat(num)
 	default:
 		return ScheduleExpr{}, p.error(
-			"expected 'weeks', 'min', 'minutes', 'hour', 'hours', 'day(s)', 'month(s)', or 'year(s)' after number",
+			"expected 'weeks', 'min', 'minutes', 'hour', 'hours', 'day(s)', 'weekday(s)', 'month(s)', or 'year(s)' after number",
 			p.currentSpan(),
 		)
 	}
@@ -399,15 +653,84 @@ func (p *parser) parseIntervalRepeat(interval int) (ScheduleExpr, error) {
 	tok := p.peek()
 	unit := tok.UnitVal
 	p.advance()
+	return p.parseIntervalWindow(interval, unit)
+}
+
+// "every D hours ..." where D is a decimal: converted to whole minutes, so
+// "every 1.5 hours" is the same schedule as "every 90 min"
+func (p *parser) parseFractionalInterval() (ScheduleExpr, error) {
+	span := p.currentSpan()
+	tok := p.peek()
+	p.advance()
+
+	if p.peekKind() != TokenIntervalUnit || p.peek().UnitVal == IntervalMin {
+		return ScheduleExpr{}, p.error("fractional intervals are only supported in hours", span)
+	}
+	p.advance()
 
-	if _, err := p.consume("'from'", TokenFrom); err != nil {
+	minutes, ok := fractionalHoursToMinutes(tok.DecimalVal)
+	if !ok {
+		return ScheduleExpr{}, p.error("fractional hours must come to a whole number of minutes", span)
+	}
+	if minutes == 0 {
+		return ScheduleExpr{}, p.error("interval must be at least 1", span)
+	}
+
+	if minutes%60 == 0 {
+		return p.parseIntervalWindow(minutes/60, IntervalHours)
+	}
+	return p.parseIntervalWindow(minutes, IntervalMin)
+}
+
+// fractionalHoursToMinutes converts decimal hours (1.5) to whole minutes, or
+// reports false if they don't come to a whole number of minutes. A minute is
+// 1/60 hour, so past two decimal places (ignoring trailing zeros) the hours
+// never land on one.
+func fractionalHoursToMinutes(text string) (int, bool) {
+	whole, frac, _ := strings.Cut(text, ".")
+	frac = strings.TrimRight(frac, "0")
+	if len(frac) > 2 {
+		return 0, false
+	}
+	for len(frac) < 2 {
+		frac += "0"
+	}
+	hundredths, err := strconv.Atoi(whole + frac)
+	if err != nil || hundredths*60%100 != 0 {
+		return 0, false
+	}
+	return hundredths * 60 / 100, true
+}
+
+// parseIntervalWindow parses the window and optional day filter after the interval unit.
+func (p *parser) parseIntervalWindow(interval int, unit IntervalUnit) (ScheduleExpr, error) {
+	// "every 2 hours at :15": all day, starting MM past midnight
+	if unit == IntervalHours && p.peekKind() == TokenAt {
+		p.advance()
+		minute, err := p.parseMinutePast(false)
+		if err != nil {
+			return ScheduleExpr{}, err
+		}
+		dayFilter, err := p.parseOptionalDayFilter()
+		if err != nil {
+			return ScheduleExpr{}, err
+		}
+		return hourlyAt(interval, minute, dayFilter), nil
+	}
+
+	// "from HH:MM to HH:MM" or "between HH:MM and HH:MM"
+	closing, closingKind := "'to'", TokenTo
+	if p.peekKind() == TokenBetween {
+		p.advance()
+		closing, closingKind = "'and'", TokenAnd
+	} else if _, err := p.consume("'from' or 'between'", TokenFrom); err != nil {
 		return ScheduleExpr{}, err
 	}
 	fromTime, err := p.parseTime()
 	if err != nil {
 		return ScheduleExpr{}, err
 	}
-	if _, err := p.consume("'to'", TokenTo); err != nil {
+	if _, err := p.consume(closing, closingKind); err != nil {
 		return ScheduleExpr{}, err
 	}
 	toTime, err := p.parseTime()
@@ -415,19 +738,88 @@ func (p *parser) parseIntervalRepeat(interval int) (ScheduleExpr, error) {
 		return ScheduleExpr{}, err
 	}
 
-	var dayFilter *DayFilter
-	if p.peekKind() == TokenOn {
-		p.advance()
-		df, err := p.parseDayTarget()
-		if err != nil {
-			return ScheduleExpr{}, err
-		}
-		dayFilter = &df
+	dayFilter, err := p.parseOptionalDayFilter()
+	if err != nil {
+		return ScheduleExpr{}, err
 	}
-
 	return NewIntervalRepeat(interval, unit, fromTime, toTime, dayFilter), nil
 }
 
+// Optional "on day_target" after an interval window
+func (p *parser) parseOptionalDayFilter() (*DayFilter, error) {
+	if p.peekKind() != TokenOn {
+		return nil, nil
+	}
+	p.advance()
+	df, err := p.parseDayTarget()
+	if err != nil {
+		return nil, err
+	}
+	return &df, nil
+}
+
+// "at 15 past every hour", "at :15 past every hour": "at" already consumed
+func (p *parser) parsePastEveryHour() (ScheduleExpr, error) {
+	minute, err := p.parseMinutePast(true)
+	if err != nil {
+		return ScheduleExpr{}, err
+	}
+	if _, err := p.consume("'past'", TokenPast); err != nil {
+		return ScheduleExpr{}, err
+	}
+	if _, err := p.consume("'every'", TokenEvery); err != nil {
+		return ScheduleExpr{}, err
+	}
+	if p.peekKind() != TokenIntervalUnit || p.peek().UnitVal != IntervalHours {
+		return ScheduleExpr{}, p.error("expected 'hour'", p.currentSpan())
+	}
+	p.advance()
+	dayFilter, err := p.parseOptionalDayFilter()
+	if err != nil {
+		return ScheduleExpr{}, err
+	}
+	return hourlyAt(1, minute, dayFilter), nil
+}
+
+// Minutes past the hour, as ":MM" or (with allowNumber) a bare number
+func (p *parser) parseMinutePast(allowNumber bool) (int, error) {
+	tok := p.peek()
+	var minute int
+	switch {
+	case tok != nil && tok.Kind == TokenMinuteOffset:
+		minute = tok.MinuteVal
+	case allowNumber && tok != nil && tok.Kind == TokenNumber && tok.NumberVal <= 59:
+		minute = tok.NumberVal
+	default:
+		return 0, p.error("expected minutes past the hour (e.g. :15)", p.currentSpan())
+	}
+	p.advance()
+	return minute, nil
+}
+
+// hourlyAt builds an all-day hour step firing minute past the hour:
+// "every hour at :15" is "every 1 hour from 00:15 to 23:59".
+func hourlyAt(interval, minute int, dayFilter *DayFilter) ScheduleExpr {
+	return NewIntervalRepeat(interval, IntervalHours, TimeOfDay{0, minute}, TimeOfDay{23, 59}, dayFilter)
+}
+
+// "week" or "N weeks", after a day list and a second "every"
+func (p *parser) parseWeekInterval() (int, error) {
+	span := p.currentSpan()
+	interval := 1
+	if p.peekKind() == TokenNumber {
+		if p.peek().NumberVal == 0 {
+			return 0, p.error("interval must be at least 1", span)
+		}
+		interval = p.peek().NumberVal
+		p.advance()
+	}
+	if _, err := p.consume("'week' or 'weeks'", TokenWeeks); err != nil {
+		return 0, err
+	}
+	return interval, nil
+}
+
 func (p *parser) parseWeekRepeat(interval int) (ScheduleExpr, error) {
 	if _, err := p.consume("'on'", TokenOn); err != nil {
 		return ScheduleExpr{}, err
@@ -436,10 +828,7 @@ func (p *parser) parseWeekRepeat(interval int) (ScheduleExpr, error) {
 	if err != nil {
 		return ScheduleExpr{}, err
 	}
-	if _, err := p.consume("'at'", TokenAt); err != nil {
-		return ScheduleExpr{}, err
-	}
-	times, err := p.parseTimeList()
+	times, err := p.parseTimesOrMidnight()
 	if err != nil {
 		return ScheduleExpr{}, err
 	}
@@ -456,45 +845,59 @@ func (p *parser) parseMonthRepeat(interval int) (ScheduleExpr, error) {
 
 	var target MonthTarget
 
-	switch p.peekKind() {
-	case TokenLast:
+	switch kind := p.peekKind(); {
+	case (kind == TokenOrdinal || kind == TokenLast) && p.ordinalWeekdaysAhead():
+		// "the first monday", "the first and last friday", as opposed to
+		// "the first" (day 1)
+		var err error
+		target, err = p.parseOrdinalWeekdays()
+		if err != nil {
+			return ScheduleExpr{}, err
+		}
+	case kind == TokenLast:
 		p.advance()
 		switch p.peekKind() {
 		case TokenDay:
 			p.advance()
 			target = NewLastDayTarget()
+		case TokenNumber:
+			// "last N days"; "last 1 day" is the last day
+			n := p.peek().NumberVal
+			if n < 1 || n > 31 {
+				return ScheduleExpr{}, p.error(fmt.Sprintf("invalid day count: %d (must be 1-31)", n), p.currentSpan())
+			}
+			p.advance()
+			if _, err := p.consume("'days'", TokenDay); err != nil {
+				return ScheduleExpr{}, err
+			}
+			if n == 1 {
+				target = NewLastDayTarget()
+			} else {
+				target = NewLastNDaysTarget(n)
+			}
 		case TokenWeekday:
 			p.advance()
 			target = NewLastWeekdayTarget()
-		case TokenDayName:
-			// "last monday" etc.
-			tok := p.peek()
-			weekday := tok.DayNameVal
-			p.advance()
-			target = NewOrdinalWeekdayTarget(Last, weekday)
 		default:
 			return ScheduleExpr{}, p.error("expected 'day', 'weekday', or day name after 'last'", p.currentSpan())
 		}
-	case TokenOrdinal:
-		// "first monday", "second tuesday", etc.
-		ordinal, err := p.parseOrdinalPosition()
-		if err != nil {
-			return ScheduleExpr{}, err
-		}
-		if p.peekKind() != TokenDayName {
-			return ScheduleExpr{}, p.error("expected day name after ordinal", p.currentSpan())
-		}
-		tok := p.peek()
-		weekday := tok.DayNameVal
-		p.advance()
-		target = NewOrdinalWeekdayTarget(ordinal, weekday)
-	case TokenOrdinalNumber:
-		specs, err := p.parseOrdinalDayList()
-		if err != nil {
-			return ScheduleExpr{}, err
+	case kind == TokenOrdinal || kind == TokenOrdinalNumber:
+		if p.nextIsBusinessDay() {
+			// "the first weekday", "the 3rd business day"
+			var err error
+			target, err = p.parseBusinessDayTarget()
+			if err != nil {
+				return ScheduleExpr{}, err
+			}
+		} else {
+			// "1st, 15th" or "first, fifteenth"
+			specs, err := p.parseOrdinalDayList()
+			if err != nil {
+				return ScheduleExpr{}, err
+			}
+			target = NewDaysTarget(specs)
 		}
-		target = NewDaysTarget(specs)
-	case TokenNext, TokenPrevious, TokenNearest:
+	case kind == TokenNext || kind == TokenPrevious || kind == TokenNearest:
 		var err error
 		target, err = p.parseNearestWeekdayTarget()
 		if err != nil {
@@ -507,16 +910,86 @@ func (p *parser) parseMonthRepeat(interval int) (ScheduleExpr, error) {
 		)
 	}
 
-	if _, err := p.consume("'at'", TokenAt); err != nil {
-		return ScheduleExpr{}, err
-	}
-	times, err := p.parseTimeList()
+	times, err := p.parseTimes()
 	if err != nil {
 		return ScheduleExpr{}, err
 	}
 	return NewMonthRepeat(interval, target, times), nil
 }
 
+// ordinalWeekdaysAhead reports whether a list of ordinals joined by "," or
+// "and" ends in a day name.
+func (p *parser) ordinalWeekdaysAhead() bool {
+	for i := p.pos; i < len(p.tokens); i += 2 {
+		if p.tokens[i].Kind != TokenOrdinal && p.tokens[i].Kind != TokenLast {
+			return false
+		}
+		if i+1 >= len(p.tokens) {
+			return false
+		}
+		switch p.tokens[i+1].Kind {
+		case TokenDayName:
+			return true
+		case TokenComma, TokenAnd:
+		default:
+			return false
+		}
+	}
+	return false
+}
+
+func (p *parser) parseOrdinalWeekdays() (MonthTarget, error) {
+	ordinal, err := p.parseOrdinalPosition()
+	if err != nil {
+		return MonthTarget{}, err
+	}
+	seen := map[OrdinalPosition]bool{ordinal: true}
+	for p.peekKind() == TokenComma || p.peekKind() == TokenAnd {
+		p.advance()
+		next, err := p.parseOrdinalPosition()
+		if err != nil {
+			return MonthTarget{}, err
+		}
+		seen[next] = true
+	}
+	weekday, err := p.parseDayName("expected day name after ordinal in monthly expression")
+	if err != nil {
+		return MonthTarget{}, err
+	}
+	var ordinals []OrdinalPosition
+	for o := First; o <= Last; o++ {
+		if seen[o] {
+			ordinals = append(ordinals, o)
+		}
+	}
+	if len(ordinals) == 1 {
+		return NewOrdinalWeekdayTarget(ordinals[0], weekday), nil
+	}
+	return NewOrdinalWeekdaysTarget(ordinals, weekday), nil
+}
+
+// <ordinal> (weekday | business day)
+func (p *parser) parseBusinessDayTarget() (MonthTarget, error) {
+	span := p.currentSpan()
+	n, err := p.parseOrdinalDay("expected ordinal before 'business day'")
+	if err != nil {
+		return MonthTarget{}, err
+	}
+	// No month has more than 23 weekdays
+	if n > 23 {
+		return MonthTarget{}, p.error(fmt.Sprintf("invalid business day: %d (must be 1-23)", n), span)
+	}
+	if p.peekKind() == TokenBusiness {
+		p.advance()
+		if _, err := p.consume("'day'", TokenDay); err != nil {
+			return MonthTarget{}, err
+		}
+	} else if _, err := p.consume("'weekday'", TokenWeekday); err != nil {
+		return MonthTarget{}, err
+	}
+	return NewBusinessDayTarget(n), nil
+}
+
 func (p *parser) parseNearestWeekdayTarget() (MonthTarget, error) {
 	// Optional direction: "next" or "previous"
 	direction := NearestNone
@@ -553,6 +1026,9 @@ func (p *parser) parseNearestWeekdayTarget() (MonthTarget, error) {
 }
 
 func (p *parser) parseYearRepeat(interval int) (ScheduleExpr, error) {
+	if p.peekKind() == TokenIn {
+		return p.parseISOWeekRepeat(interval)
+	}
 	if _, err := p.consume("'on'", TokenOn); err != nil {
 		return ScheduleExpr{}, err
 	}
@@ -587,14 +1063,67 @@ func (p *parser) parseYearRepeat(interval int) (ScheduleExpr, error) {
 		)
 	}
 
-	if _, err := p.consume("'at'", TokenAt); err != nil {
+	times, err := p.parseTimes()
+	if err != nil {
+		return ScheduleExpr{}, err
+	}
+	return NewYearRepeat(interval, target, times), nil
+}
+
+// iso_week_repeat: "every year in iso week[s] N[, N] on day_list [at HH:MM]"
+func (p *parser) parseISOWeekRepeat(interval int) (ScheduleExpr, error) {
+	if interval != 1 {
+		return ScheduleExpr{}, p.error("ISO week schedules repeat every year; remove the interval", p.currentSpan())
+	}
+	if _, err := p.consume("'in'", TokenIn); err != nil {
+		return ScheduleExpr{}, err
+	}
+	if _, err := p.consume("'iso'", TokenISO); err != nil {
+		return ScheduleExpr{}, err
+	}
+	if _, err := p.consume("'weeks'", TokenWeeks); err != nil {
 		return ScheduleExpr{}, err
 	}
-	times, err := p.parseTimeList()
+
+	week, err := p.parseISOWeekNumber()
 	if err != nil {
 		return ScheduleExpr{}, err
 	}
-	return NewYearRepeat(interval, target, times), nil
+	weeks := []int{week}
+	for p.peekKind() == TokenComma {
+		p.advance()
+		week, err := p.parseISOWeekNumber()
+		if err != nil {
+			return ScheduleExpr{}, err
+		}
+		weeks = append(weeks, week)
+	}
+
+	if _, err := p.consume("'on'", TokenOn); err != nil {
+		return ScheduleExpr{}, err
+	}
+	days, err := p.parseDayList()
+	if err != nil {
+		return ScheduleExpr{}, err
+	}
+	times, err := p.parseTimesOrMidnight()
+	if err != nil {
+		return ScheduleExpr{}, err
+	}
+	return NewISOWeekRepeat(weeks, days, times), nil
+}
+
+func (p *parser) parseISOWeekNumber() (int, error) {
+	span := p.currentSpan()
+	if p.peekKind() != TokenNumber {
+		return 0, p.error("expected ISO week number", span)
+	}
+	week := p.peek().NumberVal
+	if week < 1 || week > 53 {
+		return 0, p.error(fmt.Sprintf("invalid ISO week %d (expected 1-53)", week), span)
+	}
+	p.advance()
+	return week, nil
 }
 
 func (p *parser) parseYearTargetAfterThe() (YearTarget, error) {
@@ -631,12 +1160,13 @@ func (p *parser) parseYearTargetAfterThe() (YearTarget, error) {
 			)
 		}
 
-	case TokenOrdinal:
-		ordinal, err := p.parseOrdinalPosition()
-		if err != nil {
-			return YearTarget{}, err
-		}
-		if p.peekKind() == TokenDayName {
+	case TokenOrdinal, TokenOrdinalNumber:
+		// "the first monday of march" or "the 15th of march"
+		if p.peekKind() == TokenOrdinal && p.nextIsDayName() {
+			ordinal, err := p.parseOrdinalPosition()
+			if err != nil {
+				return YearTarget{}, err
+			}
 			tok := p.peek()
 			weekday := tok.DayNameVal
 			p.advance()
@@ -649,19 +1179,11 @@ func (p *parser) parseYearTargetAfterThe() (YearTarget, error) {
 			}
 			return NewYearOrdinalWeekdayTarget(ordinal, weekday, month), nil
 		}
-		return YearTarget{}, p.error(
-			"expected day name after ordinal in yearly expression",
-			p.currentSpan(),
-		)
-
-	case TokenOrdinalNumber:
-		tok := p.peek()
-		day := tok.NumberVal
-		if day < 1 || day > 31 {
-			return YearTarget{}, p.error(fmt.Sprintf("invalid day number %d (must be 1-31)", day), p.currentSpan())
-		}
 		dayPos := p.currentSpan().Start
-		p.advance()
+		day, err := p.parseOrdinalDay("expected ordinal day number")
+		if err != nil {
+			return YearTarget{}, err
+		}
 		if _, err := p.consume("'of'", TokenOf); err != nil {
 			return YearTarget{}, err
 		}
@@ -696,6 +1218,9 @@ func (p *parser) parseOrdinalPosition() (OrdinalPosition, error) {
 	switch p.peekKind() {
 	case TokenOrdinal:
 		tok := p.peek()
+		if tok.OrdinalVal == 0 {
+			return 0, p.error(fmt.Sprintf("unknown ordinal '%s'", p.input[span.Start:span.End]), span)
+		}
 		p.advance()
 		return tok.OrdinalVal, nil
 	case TokenLast:
@@ -707,14 +1232,25 @@ func (p *parser) parseOrdinalPosition() (OrdinalPosition, error) {
 }
 
 func (p *parser) parseOn() (ScheduleExpr, error) {
+	// "on friday at 17:00": the nearest upcoming friday, a rolling one-shot
+	if p.peekKind() == TokenDayName {
+		day := p.peek().DayNameVal
+		p.advance()
+		if p.peekKind() == TokenComma {
+			return ScheduleExpr{}, p.error("a weekday cannot be combined with other dates; use 'every' for recurring days", p.currentSpan())
+		}
+		times, err := p.parseTimes()
+		if err != nil {
+			return ScheduleExpr{}, err
+		}
+		return NewSingleDateExpr(NewNextWeekdayDate(day), times), nil
+	}
+
 	date, err := p.parseDateTarget()
 	if err != nil {
 		return ScheduleExpr{}, err
 	}
-	if _, err := p.consume("'at'", TokenAt); err != nil {
-		return ScheduleExpr{}, err
-	}
-	times, err := p.parseTimeList()
+	times, err := p.parseTimes()
 	if err != nil {
 		return ScheduleExpr{}, err
 	}
@@ -775,33 +1311,71 @@ func (p *parser) parseDayTarget() (DayFilter, error) {
 		if err != nil {
 			return DayFilter{}, err
 		}
-		return NewDayFilterDays(days), nil
+		return canonicalDayFilter(NewDayFilterDays(days)), nil
 	default:
 		return DayFilter{}, p.error("expected 'day', 'weekday', 'weekend', or day name", p.currentSpan())
 	}
 }
 
 func (p *parser) parseDayList() ([]Weekday, error) {
-	if p.peekKind() != TokenDayName {
-		return nil, p.error("expected day name", p.currentSpan())
+	days, err := p.parseDayRange("expected day name")
+	if err != nil {
+		return nil, err
 	}
-	tok := p.peek()
-	days := []Weekday{tok.DayNameVal}
-	p.advance()
 
 	for p.peekKind() == TokenComma {
 		p.advance()
-		if p.peekKind() != TokenDayName {
-			return nil, p.error("expected day name after ','", p.currentSpan())
+		more, err := p.parseDayRange("expected day name after ','")
+		if err != nil {
+			return nil, err
 		}
-		tok := p.peek()
-		days = append(days, tok.DayNameVal)
-		p.advance()
+		days = append(days, more...)
 	}
 
 	return days, nil
 }
 
+// parseDayRange parses a day name or a range of them: "monday to friday",
+// "fri through mon" (wraps past sunday).
+func (p *parser) parseDayRange(expected string) ([]Weekday, error) {
+	start, err := p.parseDayName(expected)
+	if err != nil {
+		return nil, err
+	}
+	if !p.atRangeSeparator() {
+		return []Weekday{start}, nil
+	}
+	p.advance()
+	end, err := p.parseDayName("expected day name after 'to'")
+	if err != nil {
+		return nil, err
+	}
+	length := euclideanMod(end.Number()-start.Number(), 7) + 1
+	days := make([]Weekday, length)
+	for i := range days {
+		days[i] = Weekday(euclideanMod(start.Number()-1+i, 7) + 1)
+	}
+	return days, nil
+}
+
+func (p *parser) parseDayName(expected string) (Weekday, error) {
+	if p.peekKind() != TokenDayName {
+		return 0, p.error(expected, p.currentSpan())
+	}
+	tok := p.peek()
+	p.advance()
+	return tok.DayNameVal, nil
+}
+
+// atRangeSeparator reports whether the next token separates a range: "to", or
+// its synonym "through". "through" is only accepted in ranges, never in
+// "from ... to ..." windows.
+func (p *parser) atRangeSeparator() bool {
+	return p.peekKind() == TokenTo || p.peekKind() == TokenThrough
+}
+
+// Day specs are sorted and de-duplicated: "the 15th, 1st, 15th" is
+// "the 1st, 15th"
 func (p *parser) parseOrdinalDayList() ([]DayOfMonthSpec, error) {
 	spec, err := p.parseOrdinalDaySpec()
 	if err != nil {
@@ -818,31 +1392,22 @@ func (p *parser) parseOrdinalDayList() ([]DayOfMonthSpec, error) {
 		specs = append(specs, spec)
 	}
 
-	return specs, nil
+	return sortedUnique(specs, daySpecKey), nil
 }
 
 func (p *parser) parseOrdinalDaySpec() (DayOfMonthSpec, error) {
-	if p.peekKind() != TokenOrdinalNumber {
-		return DayOfMonthSpec{}, p.error("expected ordinal day number", p.currentSpan())
-	}
-	tok := p.peek()
-	start := tok.NumberVal
-	if start < 1 || start > 31 {
-		return DayOfMonthSpec{}, p.error(fmt.Sprintf("invalid day number %d (must be 1-31)", start), p.currentSpan())
+	start, err := p.parseOrdinalDay("expected ordinal day number")
+	if err != nil {
+		return DayOfMonthSpec{}, err
 	}
-	p.advance()
 
-	if p.peekKind() == TokenTo {
+	// "1st to 15th", "1st through 15th"
+	if p.atRangeSeparator() {
 		p.advance()
-		if p.peekKind() != TokenOrdinalNumber {
-			return DayOfMonthSpec{}, p.error("expected ordinal day number after 'to'", p.currentSpan())
-		}
-		tok := p.peek()
-		end := tok.NumberVal
-		if end < 1 || end > 31 {
-			return DayOfMonthSpec{}, p.error(fmt.Sprintf("invalid day number %d (must be 1-31)", end), p.currentSpan())
+		end, err := p.parseOrdinalDay("expected ordinal day number after 'to'")
+		if err != nil {
+			return DayOfMonthSpec{}, err
 		}
-		p.advance()
 		if start > end {
 			return DayOfMonthSpec{}, p.error(fmt.Sprintf("invalid day range: %d to %d (start must be <= end)", start, end), p.currentSpan())
 		}
@@ -852,25 +1417,185 @@ func (p *parser) parseOrdinalDaySpec() (DayOfMonthSpec, error) {
 	return NewSingleDay(start), nil
 }
 
-func (p *parser) parseMonthList() ([]MonthName, error) {
-	month, err := p.parseMonthNameToken()
+// parseOrdinalDay parses an ordinal day of month, as digits (21st) or a word (twentieth).
+func (p *parser) parseOrdinalDay(expected string) (int, error) {
+	kind := p.peekKind()
+	if kind != TokenOrdinalNumber && kind != TokenOrdinal {
+		return 0, p.error(expected, p.currentSpan())
+	}
+	tok := p.peek()
+	n := tok.NumberVal
+	if n < 1 || n > 31 {
+		return 0, p.error(fmt.Sprintf("invalid day number %d (must be 1-31)", n), p.currentSpan())
+	}
+	p.advance()
+	return n, nil
+}
+
+// nextIsDayName reports whether the token after the current one is a day name.
+func (p *parser) nextIsDayName() bool {
+	return p.pos+1 < len(p.tokens) && p.tokens[p.pos+1].Kind == TokenDayName
+}
+
+// nextIsWindow reports whether the token after the current one starts an
+// interval window ("from" or "between").
+func (p *parser) nextIsWindow() bool {
+	if p.pos+1 >= len(p.tokens) {
+		return false
+	}
+	next := p.tokens[p.pos+1].Kind
+	return next == TokenFrom || next == TokenBetween
+}
+
+// nextIsBusinessDay reports whether the token after the current one starts
+// "business day" or is "weekday".
+func (p *parser) nextIsBusinessDay() bool {
+	if p.pos+1 >= len(p.tokens) {
+		return false
+	}
+	next := p.tokens[p.pos+1].Kind
+	return next == TokenBusiness || next == TokenWeekday
+}
+
+// Specs are sorted by where they start and de-duplicated: "during mar, jan,
+// mar" is "during jan, mar". Overlapping specs are not merged.
+func (p *parser) parseDuringList() ([]DuringSpec, error) {
+	spec, err := p.parseDuringSpec()
 	if err != nil {
 		return nil, err
 	}
-	months := []MonthName{month}
+	specs := []DuringSpec{spec}
 
 	for p.peekKind() == TokenComma {
 		p.advance()
-		month, err := p.parseMonthNameToken()
+		spec, err := p.parseDuringSpec()
 		if err != nil {
 			return nil, err
 		}
-		months = append(months, month)
+		specs = append(specs, spec)
+	}
+
+	return sortedUnique(specs, duringSpecKey), nil
+}
+
+// daySpecKey orders day specs by first day, then last day.
+func daySpecKey(spec DayOfMonthSpec) int {
+	if spec.Kind == DayOfMonthSpecKindSingle {
+		return spec.Day*100 + spec.Day
+	}
+	return spec.Start*100 + spec.End
+}
+
+// duringSpecKey orders during specs by first month-day, then last month-day.
+// A month and the date range covering the same days share a key.
+func duringSpecKey(spec DuringSpec) int {
+	startDay, endDay := spec.Bounds()
+	start := spec.Start.Number()*100 + startDay
+	end := spec.End.Number()*100 + endDay
+	return start*10000 + end
+}
+
+// sortedUnique sorts items by key, keeping the first of each run of equal keys.
+func sortedUnique[T any](items []T, key func(T) int) []T {
+	sort.SliceStable(items, func(i, j int) bool {
+		return key(items[i]) < key(items[j])
+	})
+	deduped := items[:1]
+	for _, item := range items[1:] {
+		if key(item) != key(deduped[len(deduped)-1]) {
+			deduped = append(deduped, item)
+		}
+	}
+	return deduped
+}
+
+// during_spec: "jun", "jun to aug" (wraps past december: "nov to feb"),
+// or month-day bounds "jun 15 to aug 31". A bound without a day covers
+// its whole month.
+func (p *parser) parseDuringSpec() (DuringSpec, error) {
+	start, err := p.parseMonthNameToken()
+	if err != nil {
+		return DuringSpec{}, err
+	}
+	startDay, err := p.parseDuringDay(start)
+	if err != nil {
+		return DuringSpec{}, err
+	}
+	if !p.atRangeSeparator() {
+		// "during jun 15" is that one day
+		return DuringSpec{Start: start, End: start, StartDay: startDay, EndDay: startDay}, nil
+	}
+	p.advance()
+	end, err := p.parseMonthNameToken()
+	if err != nil {
+		return DuringSpec{}, err
+	}
+	endDay, err := p.parseDuringDay(end)
+	if err != nil {
+		return DuringSpec{}, err
+	}
+	if startDay == 0 && endDay == 0 {
+		return DuringSpec{Start: start, End: end}, nil
+	}
+	if startDay == 0 {
+		startDay = 1
+	}
+	if endDay == 0 {
+		endDay = end.MaxDay()
+	}
+	return DuringSpec{Start: start, End: end, StartDay: startDay, EndDay: endDay}, nil
+}
+
+// parseDuringDay parses the optional day after a month name in during,
+// validated for the month. Returns 0 when there is no day.
+func (p *parser) parseDuringDay(month MonthName) (int, error) {
+	kind := p.peekKind()
+	if kind != TokenNumber && kind != TokenOrdinalNumber {
+		return 0, nil
+	}
+	dayPos := p.currentSpan().Start
+	day, err := p.parseDayNumber("expected day number after month name in during")
+	if err != nil {
+		return 0, err
+	}
+	if err := p.validateNamedDate(month, day, dayPos); err != nil {
+		return 0, err
+	}
+	return day, nil
+}
+
+// times: "at HH:MM, ..." or "in the morning|afternoon|evening|night"
+func (p *parser) parseTimes() ([]TimeOfDay, error) {
+	if p.peekKind() == TokenIn {
+		p.advance()
+		if _, err := p.consume("'the'", TokenThe); err != nil {
+			return nil, err
+		}
+		if p.peekKind() != TokenPeriod {
+			return nil, p.error("expected morning, afternoon, evening, or night", p.currentSpan())
+		}
+		tok := p.advance()
+		return []TimeOfDay{periodTimes[tok.PeriodVal]}, nil
 	}
+	if _, err := p.consume("'at'", TokenAt); err != nil {
+		return nil, err
+	}
+	return p.parseTimeList()
+}
 
-	return months, nil
+// Day and week repeats may omit times: "every day", "every weekday in UTC"
+// fire at 00:00. A bare "in" starts the timezone clause, not "in the ...".
+func (p *parser) parseTimesOrMidnight() ([]TimeOfDay, error) {
+	hasTimes := p.peekKind() == TokenAt ||
+		(p.peekKind() == TokenIn && p.pos+1 < len(p.tokens) && p.tokens[p.pos+1].Kind == TokenThe)
+	if hasTimes {
+		return p.parseTimes()
+	}
+	return []TimeOfDay{{0, 0}}, nil
 }
 
+// Times are sorted and de-duplicated: "at 17:00, 09:00, 09:00" is
+// "at 09:00, 17:00"
 func (p *parser) parseTimeList() ([]TimeOfDay, error) {
 	t, err := p.parseTime()
 	if err != nil {
@@ -887,7 +1612,16 @@ func (p *parser) parseTimeList() ([]TimeOfDay, error) {
 		times = append(times, t)
 	}
 
-	return times, nil
+	sort.Slice(times, func(i, j int) bool {
+		return times[i].TotalMinutes() < times[j].TotalMinutes()
+	})
+	deduped := times[:1]
+	for _, t := range times[1:] {
+		if t != deduped[len(deduped)-1] {
+			deduped = append(deduped, t)
+		}
+	}
+	return deduped, nil
 }
 
 func (p *parser) parseTime() (TimeOfDay, error) {
@@ -75,7 +75,8 @@ public final class Schedule {
   }
 
   /**
-   * Computes the next occurrence after the given time.
+   * Computes the next occurrence after the given time. A starting date is both the interval anchor
+   * and a lower bound, so when now is before it the result is on or after it.
    *
    * @param now the reference time
    * @return the next occurrence, or empty if none exists
@@ -114,6 +115,8 @@ public final class Schedule {
    *
    * @param datetime the datetime to check
    * @return true if the datetime matches
+   * @throws IllegalStateException for {@code starting today} / {@code starting now} and for {@code
+   *     on <weekday>}, which need an evaluation time that {@code matches} does not take
    */
   public boolean matches(ZonedDateTime datetime) {
     ZonedDateTime dtInTz = datetime.withZoneSameInstant(zoneId);
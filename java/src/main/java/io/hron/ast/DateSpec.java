@@ -1,21 +1,26 @@
 package io.hron.ast;
 
 /**
- * Represents a date specification (either named like "feb 14" or ISO like "2026-03-15").
+ * Represents a date specification (either named like "feb 14" or ISO like "2026-03-15"). A
+ * next-weekday date ("on friday") is the nearest upcoming day with that weekday, resolved against
+ * the evaluation time rather than a fixed date.
  *
  * @param kind the type of date specification
  * @param month the month (for NAMED)
  * @param day the day (for NAMED)
  * @param date the ISO date string (for ISO)
+ * @param weekday the weekday (for NEXT_WEEKDAY)
  */
-public record DateSpec(Kind kind, MonthName month, int day, String date) {
+public record DateSpec(Kind kind, MonthName month, int day, String date, Weekday weekday) {
 
   /** The type of date specification. */
   public enum Kind {
     /** A named date (e.g., feb 14). */
     NAMED,
     /** An ISO date (e.g., 2026-03-15). */
-    ISO
+    ISO,
+    /** The nearest upcoming day with a weekday (e.g., friday). */
+    NEXT_WEEKDAY
   }
 
   /**
@@ -26,7 +31,7 @@ public record DateSpec(Kind kind, MonthName month, int day, String date) {
    * @return a new named date specification
    */
   public static DateSpec named(MonthName month, int day) {
-    return new DateSpec(Kind.NAMED, month, day, null);
+    return new DateSpec(Kind.NAMED, month, day, null, null);
   }
 
   /**
@@ -36,6 +41,16 @@ public record DateSpec(Kind kind, MonthName month, int day, String date) {
    * @return a new ISO date specification
    */
   public static DateSpec iso(String date) {
-    return new DateSpec(Kind.ISO, null, 0, date);
+    return new DateSpec(Kind.ISO, null, 0, date, null);
+  }
+
+  /**
+   * Creates a next-weekday date specification.
+   *
+   * @param weekday the weekday
+   * @return a new next-weekday date specification
+   */
+  public static DateSpec nextWeekday(Weekday weekday) {
+    return new DateSpec(Kind.NEXT_WEEKDAY, null, 0, null, weekday);
   }
 }
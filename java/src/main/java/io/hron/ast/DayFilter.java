@@ -10,6 +10,9 @@ import java.util.List;
  */
 public record DayFilter(Kind kind, List<Weekday> days) {
 
+  private static final List<Weekday> WEEKDAYS =
+      List.of(Weekday.MONDAY, Weekday.TUESDAY, Weekday.WEDNESDAY, Weekday.THURSDAY, Weekday.FRIDAY);
+
   /** The type of day filter. */
   public enum Kind {
     /** Matches every day. */
@@ -58,4 +61,25 @@ public record DayFilter(Kind kind, List<Weekday> days) {
   public static DayFilter days(List<Weekday> days) {
     return new DayFilter(Kind.DAYS, List.copyOf(days));
   }
+
+  /**
+   * Returns the canonical form of this filter. An explicit day list is sorted and de-duplicated,
+   * and one covering exactly the weekdays or all seven days collapses into the equivalent keyword
+   * filter. "saturday, sunday" stays a list: "weekend" is a keyword of its own.
+   *
+   * @return the canonical filter
+   */
+  public DayFilter canonical() {
+    if (kind != Kind.DAYS) {
+      return this;
+    }
+    List<Weekday> sorted = days.stream().distinct().sorted().toList();
+    if (sorted.size() == 7) {
+      return every();
+    }
+    if (sorted.equals(WEEKDAYS)) {
+      return weekday();
+    }
+    return days(sorted);
+  }
 }
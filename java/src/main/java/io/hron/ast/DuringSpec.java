@@ -0,0 +1,101 @@
+package io.hron.ast;
+
+import java.util.ArrayList;
+import java.util.List;
+
+/**
+ * Represents a single month, inclusive month range, or inclusive month-day range in a during
+ * clause. Wraps past December when start is after end: "nov to feb" is nov, dec, jan, feb. Month
+ * forms leave the days at zero; an end of feb 29 means the last day of February in common years.
+ *
+ * @param start the first month
+ * @param end the last month (equal to start for a single month)
+ * @param startDay the first day in the start month, or 0 for a whole-month bound
+ * @param endDay the last day in the end month, or 0 for a whole-month bound
+ */
+public record DuringSpec(MonthName start, MonthName end, int startDay, int endDay) {
+
+  /**
+   * Creates a single month specification.
+   *
+   * @param month the month
+   * @return a new single month specification
+   */
+  public static DuringSpec month(MonthName month) {
+    return new DuringSpec(month, month, 0, 0);
+  }
+
+  /**
+   * Creates a whole-month range specification.
+   *
+   * @param start the first month
+   * @param end the last month
+   * @return a new month range specification
+   */
+  public static DuringSpec months(MonthName start, MonthName end) {
+    return new DuringSpec(start, end, 0, 0);
+  }
+
+  /**
+   * Returns the first day this specification covers in its start month.
+   *
+   * @return the first day
+   */
+  public int firstDay() {
+    return startDay == 0 ? 1 : startDay;
+  }
+
+  /**
+   * Returns the last day this specification covers in its end month. Whole months end on their
+   * longest day (feb 29).
+   *
+   * @return the last day
+   */
+  public int lastDay() {
+    return endDay == 0 ? end.maxDay() : endDay;
+  }
+
+  /**
+   * Checks whether a month-day falls within this specification.
+   *
+   * @param month the month
+   * @param day the day of the month
+   * @return true if the month-day is covered
+   */
+  public boolean contains(MonthName month, int day) {
+    int key = month.number() * 100 + day;
+    int first = start.number() * 100 + firstDay();
+    int last = end.number() * 100 + lastDay();
+    if (first <= last) {
+      return first <= key && key <= last;
+    }
+    return key >= first || key <= last;
+  }
+
+  /**
+   * Checks whether this specification covers whole months: "jun 1 to aug 31" is "jun to aug".
+   *
+   * @return true if both bounds fall on month boundaries
+   */
+  public boolean wholeMonths() {
+    return firstDay() == 1 && lastDay() == end.maxDay();
+  }
+
+  /**
+   * Returns the months this specification touches, in calendar order from the start.
+   *
+   * @return the touched months
+   */
+  public List<MonthName> expand() {
+    int length = end.number() - start.number() + 1;
+    // "jun 15 to jun 10" wraps through every month
+    if (length <= 0 || (length == 1 && firstDay() > lastDay())) {
+      length += 12;
+    }
+    List<MonthName> months = new ArrayList<>(length);
+    for (int i = 0; i < length; i++) {
+      months.add(MonthName.values()[(start.number() - 1 + i) % 12]);
+    }
+    return months;
+  }
+}
@@ -1,21 +1,39 @@
 package io.hron.ast;
 
 /**
- * Represents an exception date.
+ * Represents an exception date, date range, or day of the week.
  *
  * @param kind the type of exception specification
- * @param month the month (for NAMED)
- * @param day the day (for NAMED)
- * @param date the ISO date string (for ISO)
+ * @param month the month (for NAMED, and the start of NAMED_RANGE)
+ * @param day the day (for NAMED, and the start of NAMED_RANGE)
+ * @param date the ISO date string (for ISO, and the start of ISO_RANGE)
+ * @param weekday the day of the week (for WEEKDAY)
+ * @param endMonth the end month (for NAMED_RANGE)
+ * @param endDay the end day (for NAMED_RANGE)
+ * @param endDate the end ISO date string (for ISO_RANGE)
  */
-public record ExceptionSpec(Kind kind, MonthName month, int day, String date) {
+public record ExceptionSpec(
+    Kind kind,
+    MonthName month,
+    int day,
+    String date,
+    Weekday weekday,
+    MonthName endMonth,
+    int endDay,
+    String endDate) {
 
   /** The type of exception specification. */
   public enum Kind {
     /** A named exception (e.g., dec 25). */
     NAMED,
     /** An ISO exception (e.g., 2026-12-25). */
-    ISO
+    ISO,
+    /** A day-of-week exception (e.g., friday). */
+    WEEKDAY,
+    /** A one-off inclusive ISO date range (e.g., 2026-12-24 to 2026-12-31). */
+    ISO_RANGE,
+    /** A recurring inclusive range of named dates (e.g., dec 24 to jan 2). */
+    NAMED_RANGE
   }
 
   /**
@@ -26,7 +44,7 @@ public record ExceptionSpec(Kind kind, MonthName month, int day, String date) {
    * @return a new named exception specification
    */
   public static ExceptionSpec named(MonthName month, int day) {
-    return new ExceptionSpec(Kind.NAMED, month, day, null);
+    return new ExceptionSpec(Kind.NAMED, month, day, null, null, null, 0, null);
   }
 
   /**
@@ -36,6 +54,43 @@ public record ExceptionSpec(Kind kind, MonthName month, int day, String date) {
    * @return a new ISO exception specification
    */
   public static ExceptionSpec iso(String date) {
-    return new ExceptionSpec(Kind.ISO, null, 0, date);
+    return new ExceptionSpec(Kind.ISO, null, 0, date, null, null, 0, null);
+  }
+
+  /**
+   * Creates an exception for every occurrence of a day of the week.
+   *
+   * @param weekday the day of the week
+   * @return a new weekday exception specification
+   */
+  public static ExceptionSpec weekday(Weekday weekday) {
+    return new ExceptionSpec(Kind.WEEKDAY, null, 0, null, weekday, null, 0, null);
+  }
+
+  /**
+   * Creates a one-off inclusive ISO date range exception: "2026-12-24 to 2026-12-31".
+   *
+   * @param start the first excepted ISO date (YYYY-MM-DD)
+   * @param end the last excepted ISO date (YYYY-MM-DD)
+   * @return a new ISO range exception specification
+   */
+  public static ExceptionSpec isoRange(String start, String end) {
+    return new ExceptionSpec(Kind.ISO_RANGE, null, 0, start, null, null, 0, end);
+  }
+
+  /**
+   * Creates a recurring inclusive range of named dates: "dec 24 to jan 2" matches every year,
+   * wrapping past december when the end comes first.
+   *
+   * @param startMonth the first excepted month
+   * @param startDay the first excepted day
+   * @param endMonth the last excepted month
+   * @param endDay the last excepted day
+   * @return a new named range exception specification
+   */
+  public static ExceptionSpec namedRange(
+      MonthName startMonth, int startDay, MonthName endMonth, int endDay) {
+    return new ExceptionSpec(
+        Kind.NAMED_RANGE, startMonth, startDay, null, null, endMonth, endDay, null);
   }
 }
@@ -1,11 +1,16 @@
 package io.hron.ast;
 
-/** Represents the unit of an interval (minutes or hours). */
+/** Represents the unit of an interval (minutes, hours, or days). */
 public enum IntervalUnit {
   /** Minutes unit. */
   MINUTES("min"),
   /** Hours unit. */
-  HOURS("hours");
+  HOURS("hours"),
+  /**
+   * Days unit: the window is active on every Nth day (aligned like "every N days at ...") and
+   * fires once, at the window start.
+   */
+  DAYS("days");
 
   private final String displayName;
 
@@ -28,6 +33,7 @@ public enum IntervalUnit {
     return switch (this) {
       case MINUTES -> interval == 1 ? "minute" : "min";
       case HOURS -> interval == 1 ? "hour" : "hours";
+      case DAYS -> interval == 1 ? "day" : "days";
     };
   }
 }
@@ -0,0 +1,20 @@
+package io.hron.ast;
+
+import java.util.List;
+
+/**
+ * An ISO week repeat expression like "every year in iso weeks 1, 27 on monday at 9:00".
+ *
+ * @param weeks the ISO 8601 week numbers (1-53) to fire in
+ * @param days the days of the week to fire
+ * @param times the times of day to fire
+ */
+public record IsoWeekRepeat(List<Integer> weeks, List<Weekday> days, List<TimeOfDay> times)
+    implements ScheduleExpr {
+  /** Creates a new IsoWeekRepeat with defensive copy of lists. */
+  public IsoWeekRepeat {
+    weeks = List.copyOf(weeks);
+    days = List.copyOf(days);
+    times = List.copyOf(times);
+  }
+}
@@ -47,6 +47,19 @@ public enum MonthName {
     return monthNumber;
   }
 
+  /**
+   * Returns the month's longest length: February has 29 days.
+   *
+   * @return the maximum day of the month
+   */
+  public int maxDay() {
+    return switch (this) {
+      case FEBRUARY -> 29;
+      case APRIL, JUNE, SEPTEMBER, NOVEMBER -> 30;
+      default -> 31;
+    };
+  }
+
   @Override
   public String toString() {
     return displayName;
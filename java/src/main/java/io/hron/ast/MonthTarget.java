@@ -13,7 +13,10 @@ import java.util.List;
  * @param nearestDirection the direction for nearest weekday (only used when kind is
  *     NEAREST_WEEKDAY, may be null for standard cron W behavior)
  * @param ordinal the ordinal position (only used when kind is ORDINAL_WEEKDAY)
- * @param weekday the weekday (only used when kind is ORDINAL_WEEKDAY)
+ * @param ordinals the sorted, distinct ordinal positions (only used when kind is
+ *     ORDINAL_WEEKDAYS)
+ * @param weekday the weekday (only used when kind is ORDINAL_WEEKDAY or ORDINAL_WEEKDAYS)
+ * @param count the number of days (only used when kind is LAST_N_DAYS or BUSINESS_DAY)
  */
 public record MonthTarget(
     Kind kind,
@@ -21,7 +24,9 @@ public record MonthTarget(
     int nearestWeekdayDay,
     NearestDirection nearestDirection,
     OrdinalPosition ordinal,
-    Weekday weekday) {
+    List<OrdinalPosition> ordinals,
+    Weekday weekday,
+    int count) {
 
   /** The type of month target. */
   public enum Kind {
@@ -29,6 +34,8 @@ public record MonthTarget(
     DAYS,
     /** The last day of the month. */
     LAST_DAY,
+    /** The final N calendar days of the month (e.g., last 3 days). */
+    LAST_N_DAYS,
     /** The last weekday (Mon-Fri) of the month. */
     LAST_WEEKDAY,
     /**
@@ -37,7 +44,11 @@ public record MonthTarget(
      */
     NEAREST_WEEKDAY,
     /** An ordinal weekday of the month (e.g., first monday, last friday). */
-    ORDINAL_WEEKDAY
+    ORDINAL_WEEKDAY,
+    /** Several ordinal weekdays of the month (e.g., first and last friday). */
+    ORDINAL_WEEKDAYS,
+    /** The nth Monday-to-Friday day of the month (e.g., first business day). */
+    BUSINESS_DAY
   }
 
   /**
@@ -47,7 +58,7 @@ public record MonthTarget(
    * @return a new days target
    */
   public static MonthTarget days(List<DayOfMonthSpec> specs) {
-    return new MonthTarget(Kind.DAYS, List.copyOf(specs), 0, null, null, null);
+    return new MonthTarget(Kind.DAYS, List.copyOf(specs), 0, null, null, List.of(), null, 0);
   }
 
   /**
@@ -56,7 +67,30 @@ public record MonthTarget(
    * @return a new last-day target
    */
   public static MonthTarget lastDay() {
-    return new MonthTarget(Kind.LAST_DAY, List.of(), 0, null, null, null);
+    return new MonthTarget(Kind.LAST_DAY, List.of(), 0, null, null, List.of(), null, 0);
+  }
+
+  /**
+   * Creates a month target for the final n calendar days of the month ("the last 3 days"), n >= 2.
+   * It counts back from the month's real length; n beyond it covers the whole month.
+   *
+   * @param n the number of days
+   * @return a new last-n-days target
+   */
+  public static MonthTarget lastNDays(int n) {
+    return new MonthTarget(Kind.LAST_N_DAYS, List.of(), 0, null, null, List.of(), null, n);
+  }
+
+  /**
+   * Creates a month target for the nth Monday-to-Friday day of the month ("the first business
+   * day", "the 3rd weekday"), n in 1-23. Excepted dates are not business days, so an except landing
+   * on it moves the occurrence to the next one.
+   *
+   * @param n the business day number
+   * @return a new business-day target
+   */
+  public static MonthTarget businessDay(int n) {
+    return new MonthTarget(Kind.BUSINESS_DAY, List.of(), 0, null, null, List.of(), null, n);
   }
 
   /**
@@ -65,7 +99,7 @@ public record MonthTarget(
    * @return a new last-weekday target
    */
   public static MonthTarget lastWeekday() {
-    return new MonthTarget(Kind.LAST_WEEKDAY, List.of(), 0, null, null, null);
+    return new MonthTarget(Kind.LAST_WEEKDAY, List.of(), 0, null, null, List.of(), null, 0);
   }
 
   /**
@@ -76,7 +110,7 @@ public record MonthTarget(
    * @return a new nearest-weekday target
    */
   public static MonthTarget nearestWeekday(int day) {
-    return new MonthTarget(Kind.NEAREST_WEEKDAY, List.of(), day, null, null, null);
+    return new MonthTarget(Kind.NEAREST_WEEKDAY, List.of(), day, null, null, List.of(), null, 0);
   }
 
   /**
@@ -87,7 +121,8 @@ public record MonthTarget(
    * @return a new nearest-weekday target
    */
   public static MonthTarget nearestWeekday(int day, NearestDirection direction) {
-    return new MonthTarget(Kind.NEAREST_WEEKDAY, List.of(), day, direction, null, null);
+    return new MonthTarget(
+        Kind.NEAREST_WEEKDAY, List.of(), day, direction, null, List.of(), null, 0);
   }
 
   /**
@@ -98,7 +133,21 @@ public record MonthTarget(
    * @return a new ordinal weekday target
    */
   public static MonthTarget ordinalWeekday(OrdinalPosition ordinal, Weekday weekday) {
-    return new MonthTarget(Kind.ORDINAL_WEEKDAY, List.of(), 0, null, ordinal, weekday);
+    return new MonthTarget(
+        Kind.ORDINAL_WEEKDAY, List.of(), 0, null, ordinal, List.of(), weekday, 0);
+  }
+
+  /**
+   * Creates a month target for several ordinal weekdays (e.g., the first and last friday). A single
+   * ordinal is an ordinal weekday target instead.
+   *
+   * @param ordinals the sorted, distinct ordinal positions
+   * @param weekday the weekday
+   * @return a new ordinal weekdays target
+   */
+  public static MonthTarget ordinalWeekdays(List<OrdinalPosition> ordinals, Weekday weekday) {
+    return new MonthTarget(
+        Kind.ORDINAL_WEEKDAYS, List.of(), 0, null, null, List.copyOf(ordinals), weekday, 0);
   }
 
   /**
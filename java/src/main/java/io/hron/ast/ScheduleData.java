@@ -9,8 +9,11 @@ import java.util.List;
  * @param timezone the IANA timezone (may be null)
  * @param except the exception dates
  * @param until the until date (may be null)
- * @param anchor the anchor date for interval alignment (ISO string, may be null)
- * @param during the months during which the schedule applies
+ * @param anchor the anchor date for interval alignment (ISO string, "today", or "now"; may be null)
+ * @param anchorNamed a month-day anchor ("starting jan 6"), resolved to the next such date at
+ *     evaluation (may be null)
+ * @param anchorTime the time on the anchor date before which nothing occurs (may be null)
+ * @param during the during clause as written: months, month ranges, and date ranges
  */
 public record ScheduleData(
     ScheduleExpr expr,
@@ -18,13 +21,35 @@ public record ScheduleData(
     List<ExceptionSpec> except,
     UntilSpec until,
     String anchor,
-    List<MonthName> during) {
+    DateSpec anchorNamed,
+    TimeOfDay anchorTime,
+    List<DuringSpec> during) {
   /** Creates a new ScheduleData with defensive copies of lists. */
   public ScheduleData {
     except = except == null ? List.of() : List.copyOf(except);
     during = during == null ? List.of() : List.copyOf(during);
   }
 
+  /**
+   * Creates a new ScheduleData without a month-day anchor or anchor time.
+   *
+   * @param expr the schedule expression
+   * @param timezone the IANA timezone (may be null)
+   * @param except the exception dates
+   * @param until the until date (may be null)
+   * @param anchor the anchor date (ISO string, "today", or "now"; may be null)
+   * @param during the during clause
+   */
+  public ScheduleData(
+      ScheduleExpr expr,
+      String timezone,
+      List<ExceptionSpec> except,
+      UntilSpec until,
+      String anchor,
+      List<DuringSpec> during) {
+    this(expr, timezone, except, until, anchor, null, null, during);
+  }
+
   /**
    * Creates a new ScheduleData with just the expression.
    *
@@ -35,6 +60,37 @@ public record ScheduleData(
     return new ScheduleData(expr, null, List.of(), null, null, List.of());
   }
 
+  /**
+   * Returns the months any during spec touches, for month-level skipping.
+   *
+   * @return the months, in the order the specs list them
+   */
+  public List<MonthName> duringMonths() {
+    return during.stream().flatMap(spec -> spec.expand().stream()).toList();
+  }
+
+  /**
+   * Returns whether the anchor is resolved against the evaluation time: "today", "now", or a
+   * month-day.
+   *
+   * @return true for a relative anchor
+   */
+  public boolean hasRelativeAnchor() {
+    return anchorNamed != null || "today".equals(anchor) || "now".equals(anchor);
+  }
+
+  /**
+   * Returns the starting anchor as written, without its time.
+   *
+   * @return the anchor text, or null without a starting clause
+   */
+  public String anchorText() {
+    if (anchorNamed != null) {
+      return String.format("%s %d", anchorNamed.month(), anchorNamed.day());
+    }
+    return anchor;
+  }
+
   /**
    * Returns a copy with the specified timezone.
    *
@@ -42,7 +98,7 @@ public record ScheduleData(
    * @return a new ScheduleData with the updated timezone
    */
   public ScheduleData withTimezone(String timezone) {
-    return new ScheduleData(expr, timezone, except, until, anchor, during);
+    return new ScheduleData(expr, timezone, except, until, anchor, anchorNamed, anchorTime, during);
   }
 
   /**
@@ -52,7 +108,7 @@ public record ScheduleData(
    * @return a new ScheduleData with the updated exceptions
    */
   public ScheduleData withExcept(List<ExceptionSpec> except) {
-    return new ScheduleData(expr, timezone, except, until, anchor, during);
+    return new ScheduleData(expr, timezone, except, until, anchor, anchorNamed, anchorTime, during);
   }
 
   /**
@@ -62,7 +118,7 @@ public record ScheduleData(
    * @return a new ScheduleData with the updated until date
    */
   public ScheduleData withUntil(UntilSpec until) {
-    return new ScheduleData(expr, timezone, except, until, anchor, during);
+    return new ScheduleData(expr, timezone, except, until, anchor, anchorNamed, anchorTime, during);
   }
 
   /**
@@ -72,7 +128,7 @@ public record ScheduleData(
    * @return a new ScheduleData with the updated anchor
    */
   public ScheduleData withAnchor(String anchor) {
-    return new ScheduleData(expr, timezone, except, until, anchor, during);
+    return new ScheduleData(expr, timezone, except, until, anchor, null, anchorTime, during);
   }
 
   /**
@@ -82,6 +138,14 @@ public record ScheduleData(
    * @return a new ScheduleData with the updated during clause
    */
   public ScheduleData withDuring(List<MonthName> during) {
-    return new ScheduleData(expr, timezone, except, until, anchor, during);
+    return new ScheduleData(
+        expr,
+        timezone,
+        except,
+        until,
+        anchor,
+        anchorNamed,
+        anchorTime,
+        during.stream().map(DuringSpec::month).toList());
   }
 }
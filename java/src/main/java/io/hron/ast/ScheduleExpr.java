@@ -3,7 +3,7 @@ package io.hron.ast;
 /**
  * Sealed interface for schedule expressions.
  *
- * <p>There are 6 types of schedule expressions:
+ * <p>There are 8 types of schedule expressions:
  *
  * <ul>
  *   <li>{@link DayRepeat} - "every day at 9:00"
@@ -12,7 +12,16 @@ package io.hron.ast;
  *   <li>{@link MonthRepeat} - "every month on the 1st" or "every month on the first monday"
  *   <li>{@link SingleDate} - "on feb 14 at 9:00"
  *   <li>{@link YearRepeat} - "every year on dec 25"
+ *   <li>{@link IsoWeekRepeat} - "every year in iso week 1 on monday"
+ *   <li>{@link WeekdayRepeat} - "every 3 weekdays at 9:00"
  * </ul>
  */
 public sealed interface ScheduleExpr
-    permits DayRepeat, IntervalRepeat, WeekRepeat, MonthRepeat, SingleDate, YearRepeat {}
+    permits DayRepeat,
+        IntervalRepeat,
+        WeekRepeat,
+        MonthRepeat,
+        SingleDate,
+        YearRepeat,
+        IsoWeekRepeat,
+        WeekdayRepeat {}
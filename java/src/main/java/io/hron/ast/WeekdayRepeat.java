@@ -0,0 +1,18 @@
+package io.hron.ast;
+
+import java.util.List;
+
+/**
+ * A business-day interval expression like "every 3 weekdays at 9:00". Only Monday through Friday
+ * count toward the interval, so weekends neither fire nor advance it. Days are counted from the
+ * starting anchor (or its next weekday), defaulting to 1970-01-01.
+ *
+ * @param interval the number of business days between firings (2 or more)
+ * @param times the times of day to fire
+ */
+public record WeekdayRepeat(int interval, List<TimeOfDay> times) implements ScheduleExpr {
+  /** Creates a new WeekdayRepeat with defensive copy of times. */
+  public WeekdayRepeat {
+    times = List.copyOf(times);
+  }
+}
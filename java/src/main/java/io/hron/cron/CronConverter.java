@@ -3,7 +3,13 @@ package io.hron.cron;
 import io.hron.HronException;
 import io.hron.ast.*;
 import java.util.ArrayList;
+import java.util.Comparator;
+import java.util.HashSet;
 import java.util.List;
+import java.util.Optional;
+import java.util.Set;
+import java.util.SortedSet;
+import java.util.TreeSet;
 import java.util.stream.Collectors;
 
 /** Converts between hron expressions and 5-field cron expressions. */
@@ -24,42 +30,85 @@ public final class CronConverter {
     if (data.until() != null) {
       throw HronException.cron("not expressible as cron (until clauses not supported)");
     }
-    if (!data.during().isEmpty()) {
-      throw HronException.cron("not expressible as cron (during clauses not supported)");
+    for (DuringSpec spec : data.during()) {
+      if (!spec.wholeMonths()) {
+        throw HronException.cron(
+            "not expressible as cron (during dates narrower than whole months)");
+      }
     }
+    String month = monthField(data.duringMonths());
 
     return switch (data.expr()) {
-      case DayRepeat dr -> dayRepeatToCron(dr);
-      case IntervalRepeat ir -> intervalRepeatToCron(ir);
+      case DayRepeat dr -> dayRepeatToCron(dr, month);
+      case IntervalRepeat ir -> intervalRepeatToCron(ir, month);
       case WeekRepeat _ ->
           throw HronException.cron("not expressible as cron (multi-week intervals not supported)");
-      case MonthRepeat mr -> monthRepeatToCron(mr);
+      case MonthRepeat mr -> monthRepeatToCron(mr, month);
       case SingleDate _ ->
           throw HronException.cron("not expressible as cron (single dates are not repeating)");
       case YearRepeat _ ->
           throw HronException.cron(
               "not expressible as cron (yearly schedules not supported in 5-field cron)");
+      case IsoWeekRepeat _ ->
+          throw HronException.cron("not expressible as cron (cron has no week-of-year field)");
+      case WeekdayRepeat _ ->
+          throw HronException.cron(
+              "not expressible as cron (business-day intervals not supported)");
     };
   }
 
-  private static String dayRepeatToCron(DayRepeat dr) throws HronException {
+  /**
+   * Returns the cron month field for a during clause. Runs of three or more consecutive months
+   * collapse into a range.
+   */
+  private static String monthField(List<MonthName> during) {
+    List<Integer> months = during.stream().map(MonthName::number).distinct().sorted().toList();
+    if (months.isEmpty()) {
+      return "*";
+    }
+
+    List<String> parts = new ArrayList<>();
+    for (int i = 0; i < months.size(); ) {
+      int j = i;
+      while (j + 1 < months.size() && months.get(j + 1) == months.get(j) + 1) {
+        j++;
+      }
+      if (j - i >= 2) {
+        parts.add(months.get(i) + "-" + months.get(j));
+      } else {
+        for (int k = i; k <= j; k++) {
+          parts.add(String.valueOf(months.get(k)));
+        }
+      }
+      i = j + 1;
+    }
+    return String.join(",", parts);
+  }
+
+  private static String dayRepeatToCron(DayRepeat dr, String month) throws HronException {
     if (dr.interval() > 1) {
       throw HronException.cron("not expressible as cron (multi-day intervals not supported)");
     }
-    if (dr.times().size() != 1) {
+    String time = timeFields(dr.times());
+    if (time == null) {
       throw HronException.cron("not expressible as cron (multiple times not supported)");
     }
 
-    TimeOfDay t = dr.times().getFirst();
     String dow = dayFilterToCronDOW(dr.days());
 
-    return String.format("%d %d * * %s", t.minute(), t.hour(), dow);
+    return String.format("%s * %s %s", time, month, dow);
   }
 
-  private static String intervalRepeatToCron(IntervalRepeat ir) throws HronException {
+  private static String intervalRepeatToCron(IntervalRepeat ir, String month) throws HronException {
+    if (ir.unit() == IntervalUnit.DAYS) {
+      throw HronException.cron(
+          "not expressible as cron (multi-day interval windows not supported)");
+    }
+    // Hour steps may start past the hour: "from 00:15" fires at :15
+    boolean offsetOk = ir.fromTime().minute() == 0 || ir.unit() == IntervalUnit.HOURS;
     boolean fullDay =
         ir.fromTime().hour() == 0
-            && ir.fromTime().minute() == 0
+            && offsetOk
             && ir.toTime().hour() == 23
             && ir.toTime().minute() == 59;
 
@@ -76,31 +125,43 @@ public final class CronConverter {
         throw HronException.cron(
             "not expressible as cron (*/" + ir.interval() + " breaks at hour boundaries)");
       }
-      return String.format("*/%d * * * *", ir.interval());
+      return String.format("*/%d * * %s *", ir.interval(), month);
     }
 
     // Hours
-    return String.format("0 */%d * * *", ir.interval());
+    if (ir.interval() == 1 && ir.fromTime().minute() != 0) {
+      return String.format("%d * * %s *", ir.fromTime().minute(), month);
+    }
+    return String.format("%d */%d * %s *", ir.fromTime().minute(), ir.interval(), month);
   }
 
-  private static String monthRepeatToCron(MonthRepeat mr) throws HronException {
+  private static String monthRepeatToCron(MonthRepeat mr, String month) throws HronException {
     if (mr.interval() > 1) {
       throw HronException.cron("not expressible as cron (multi-month intervals not supported)");
     }
-    if (mr.times().size() != 1) {
+    String time = timeFields(mr.times());
+    if (time == null) {
       throw HronException.cron("not expressible as cron (multiple times not supported)");
     }
 
-    TimeOfDay t = mr.times().getFirst();
-
     return switch (mr.target().kind()) {
       case DAYS -> {
         List<Integer> days = mr.target().expandDays();
         String dom = formatIntList(days);
-        yield String.format("%d %d %s * *", t.minute(), t.hour(), dom);
+        yield String.format("%s %s %s *", time, dom, month);
       }
       case LAST_DAY ->
           throw HronException.cron("not expressible as cron (last day of month not supported)");
+      case LAST_N_DAYS ->
+          throw HronException.cron("not expressible as cron (last days of month not supported)");
+      case BUSINESS_DAY -> {
+        // The first business day is always the weekday nearest the 1st
+        if (mr.target().count() == 1) {
+          yield String.format("%s 1W %s *", time, month);
+        }
+        throw HronException.cron(
+            "not expressible as cron (nth business day of month not supported)");
+      }
       case LAST_WEEKDAY ->
           throw HronException.cron("not expressible as cron (last weekday of month not supported)");
       case NEAREST_WEEKDAY -> {
@@ -108,14 +169,38 @@ public final class CronConverter {
           throw HronException.cron(
               "not expressible as cron (directional nearest weekday not supported)");
         }
-        yield String.format("%d %d %dW * *", t.minute(), t.hour(), mr.target().nearestWeekdayDay());
+        yield String.format("%s %dW %s *", time, mr.target().nearestWeekdayDay(), month);
       }
       case ORDINAL_WEEKDAY ->
           throw HronException.cron(
               "not expressible as cron (ordinal weekday of month not supported)");
+      case ORDINAL_WEEKDAYS ->
+          throw HronException.cron(
+              "not expressible as cron (ordinal weekdays of month not supported)");
     };
   }
 
+  /**
+   * Returns the exact minute and hour fields for a list of times: "0 9,17", "0,30 9", or "0,30
+   * 9,17" when the times are every listed minute at every listed hour. Returns null when comma
+   * fields would fire at times not in the list.
+   */
+  private static String timeFields(List<TimeOfDay> times) {
+    Set<Integer> distinct = new HashSet<>();
+    SortedSet<Integer> minutes = new TreeSet<>();
+    SortedSet<Integer> hours = new TreeSet<>();
+    for (TimeOfDay t : times) {
+      distinct.add(t.totalMinutes());
+      minutes.add(t.minute());
+      hours.add(t.hour());
+    }
+    // Distinct times are a subset of the grid, so equal counts mean equal sets
+    if (minutes.size() * hours.size() != distinct.size()) {
+      return null;
+    }
+    return formatIntList(List.copyOf(minutes)) + " " + formatIntList(List.copyOf(hours));
+  }
+
   private static String dayFilterToCronDOW(DayFilter f) {
     return switch (f.kind()) {
       case EVERY -> "*";
@@ -167,7 +252,7 @@ public final class CronConverter {
     }
 
     // Parse month field into during clause
-    List<MonthName> during = parseMonthField(monthField);
+    List<DuringSpec> during = parseMonthField(monthField);
 
     // Check for special DOW patterns: nth weekday (#), last weekday (5L)
     ScheduleData nthResult = tryParseNthWeekday(minuteField, hourField, domField, dowField, during);
@@ -198,53 +283,60 @@ public final class CronConverter {
     }
 
     // Standard time-based cron
-    int minute = parseSingleValue(minuteField, "minute", 0, 59);
-    int hour = parseSingleValue(hourField, "hour", 0, 23);
-    TimeOfDay time = new TimeOfDay(hour, minute);
+    List<TimeOfDay> times = parseCronTimes(minuteField, hourField);
 
     // DOM-based (monthly) - when DOM is specified and DOW is *
     if (!domField.equals("*") && dowField.equals("*")) {
       MonthTarget target = parseDomField(domField);
-      ScheduleExpr expr = new MonthRepeat(1, target, List.of(time));
+      ScheduleExpr expr = new MonthRepeat(1, target, times);
       return new ScheduleData(expr, null, List.of(), null, null, during);
     }
 
     // DOW-based (day repeat)
     DayFilter days = parseCronDOW(dowField);
-    ScheduleExpr expr = new DayRepeat(1, days, List.of(time));
+    ScheduleExpr expr = new DayRepeat(1, days, times);
     return new ScheduleData(expr, null, List.of(), null, null, during);
   }
 
-  /** Parse @ shortcuts like @daily, @hourly, etc. */
-  private static ScheduleData parseCronShortcut(String cron) throws HronException {
-    String lower = cron.toLowerCase();
-    return switch (lower) {
-      case "@yearly", "@annually" ->
-          ScheduleData.of(
-              new YearRepeat(
-                  1, YearTarget.date(MonthName.JANUARY, 1), List.of(new TimeOfDay(0, 0))));
-      case "@monthly" ->
-          ScheduleData.of(
-              new MonthRepeat(
-                  1,
-                  MonthTarget.days(List.of(DayOfMonthSpec.single(1))),
-                  List.of(new TimeOfDay(0, 0))));
-      case "@weekly" ->
-          ScheduleData.of(
-              new DayRepeat(
-                  1, DayFilter.days(List.of(Weekday.SUNDAY)), List.of(new TimeOfDay(0, 0))));
-      case "@daily", "@midnight" ->
-          ScheduleData.of(new DayRepeat(1, DayFilter.every(), List.of(new TimeOfDay(0, 0))));
-      case "@hourly" ->
-          ScheduleData.of(
+  /**
+   * Converts a cron @ shortcut like "@daily" to a ScheduleData.
+   *
+   * @param cron the shortcut, including the leading '@'
+   * @return the schedule data
+   * @throws HronException if the shortcut is unknown
+   */
+  public static ScheduleData parseCronShortcut(String cron) throws HronException {
+    return cronShortcutExpr(cron.toLowerCase())
+        .map(ScheduleData::of)
+        .orElseThrow(() -> HronException.cron("unknown @ shortcut: " + cron));
+  }
+
+  /**
+   * Returns the expression a lowercase cron @ shortcut stands for.
+   *
+   * @param shortcut the lowercase shortcut, including the leading '@'
+   * @return the expression, or empty if the shortcut is unknown
+   */
+  public static Optional<ScheduleExpr> cronShortcutExpr(String shortcut) {
+    List<TimeOfDay> midnight = List.of(new TimeOfDay(0, 0));
+    ScheduleExpr expr =
+        switch (shortcut) {
+          case "@yearly", "@annually" ->
+              new YearRepeat(1, YearTarget.date(MonthName.JANUARY, 1), midnight);
+          case "@monthly" ->
+              new MonthRepeat(1, MonthTarget.days(List.of(DayOfMonthSpec.single(1))), midnight);
+          case "@weekly" -> new DayRepeat(1, DayFilter.days(List.of(Weekday.SUNDAY)), midnight);
+          case "@daily", "@midnight" -> new DayRepeat(1, DayFilter.every(), midnight);
+          case "@hourly" ->
               new IntervalRepeat(
-                  1, IntervalUnit.HOURS, new TimeOfDay(0, 0), new TimeOfDay(23, 59), null));
-      default -> throw HronException.cron("unknown @ shortcut: " + cron);
-    };
+                  1, IntervalUnit.HOURS, new TimeOfDay(0, 0), new TimeOfDay(23, 59), null);
+          default -> null;
+        };
+    return Optional.ofNullable(expr);
   }
 
-  /** Parse month field into a List of MonthName for the during clause. */
-  private static List<MonthName> parseMonthField(String field) throws HronException {
+  /** Parse month field into during specs, one per month. */
+  private static List<DuringSpec> parseMonthField(String field) throws HronException {
     if (field.equals("*")) {
       return List.of();
     }
@@ -304,7 +396,7 @@ public final class CronConverter {
       }
     }
 
-    return months;
+    return months.stream().map(DuringSpec::month).toList();
   }
 
   /** Parse a single month value (number 1-12 or name JAN-DEC). */
@@ -348,7 +440,7 @@ public final class CronConverter {
       String hourField,
       String domField,
       String dowField,
-      List<MonthName> during)
+      List<DuringSpec> during)
       throws HronException {
     // Check for # pattern (nth weekday of month)
     if (dowField.contains("#")) {
@@ -383,11 +475,10 @@ public final class CronConverter {
         throw HronException.cron("DOM must be * when using # for nth weekday");
       }
 
-      int minute = parseSingleValue(minuteField, "minute", 0, 59);
-      int hour = parseSingleValue(hourField, "hour", 0, 23);
+      List<TimeOfDay> times = parseCronTimes(minuteField, hourField);
 
       MonthTarget target = MonthTarget.ordinalWeekday(ordinal, weekday);
-      ScheduleExpr expr = new MonthRepeat(1, target, List.of(new TimeOfDay(hour, minute)));
+      ScheduleExpr expr = new MonthRepeat(1, target, times);
       return new ScheduleData(expr, null, List.of(), null, null, during);
     }
 
@@ -401,11 +492,10 @@ public final class CronConverter {
         throw HronException.cron("DOM must be * when using nL for last weekday");
       }
 
-      int minute = parseSingleValue(minuteField, "minute", 0, 59);
-      int hour = parseSingleValue(hourField, "hour", 0, 23);
+      List<TimeOfDay> times = parseCronTimes(minuteField, hourField);
 
       MonthTarget target = MonthTarget.ordinalWeekday(OrdinalPosition.LAST, weekday);
-      ScheduleExpr expr = new MonthRepeat(1, target, List.of(new TimeOfDay(hour, minute)));
+      ScheduleExpr expr = new MonthRepeat(1, target, times);
       return new ScheduleData(expr, null, List.of(), null, null, during);
     }
 
@@ -418,7 +508,7 @@ public final class CronConverter {
       String hourField,
       String domField,
       String dowField,
-      List<MonthName> during)
+      List<DuringSpec> during)
       throws HronException {
     if (!domField.equals("L") && !domField.equals("LW")) {
       return null;
@@ -428,12 +518,11 @@ public final class CronConverter {
       throw HronException.cron("DOW must be * when using L or LW in DOM");
     }
 
-    int minute = parseSingleValue(minuteField, "minute", 0, 59);
-    int hour = parseSingleValue(hourField, "hour", 0, 23);
+    List<TimeOfDay> times = parseCronTimes(minuteField, hourField);
 
     MonthTarget target = domField.equals("LW") ? MonthTarget.lastWeekday() : MonthTarget.lastDay();
 
-    ScheduleExpr expr = new MonthRepeat(1, target, List.of(new TimeOfDay(hour, minute)));
+    ScheduleExpr expr = new MonthRepeat(1, target, times);
     return new ScheduleData(expr, null, List.of(), null, null, during);
   }
 
@@ -443,7 +532,7 @@ public final class CronConverter {
       String hourField,
       String domField,
       String dowField,
-      List<MonthName> during)
+      List<DuringSpec> during)
       throws HronException {
     if (!domField.endsWith("W") || domField.equals("LW")) {
       return null;
@@ -465,11 +554,10 @@ public final class CronConverter {
       throw HronException.cron("W day must be 1-31, got " + day);
     }
 
-    int minute = parseSingleValue(minuteField, "minute", 0, 59);
-    int hour = parseSingleValue(hourField, "hour", 0, 23);
+    List<TimeOfDay> times = parseCronTimes(minuteField, hourField);
 
     MonthTarget target = MonthTarget.nearestWeekday(day);
-    ScheduleExpr expr = new MonthRepeat(1, target, List.of(new TimeOfDay(hour, minute)));
+    ScheduleExpr expr = new MonthRepeat(1, target, times);
     return new ScheduleData(expr, null, List.of(), null, null, during);
   }
 
@@ -479,7 +567,7 @@ public final class CronConverter {
       String hourField,
       String domField,
       String dowField,
-      List<MonthName> during)
+      List<DuringSpec> during)
       throws HronException {
     // Minute interval: */N or range/N
     if (minuteField.contains("/")) {
@@ -577,11 +665,13 @@ public final class CronConverter {
       }
     }
 
-    // Hour interval: 0 */N or 0 range/N
-    if (hourField.contains("/") && (minuteField.equals("0") || minuteField.equals("00"))) {
+    // Hour interval: M */N, M range/N, or M * (every hour at minute M)
+    boolean minuteOk = minuteField.matches("[0-9]+") && Integer.parseInt(minuteField) <= 59;
+    if (minuteOk && (hourField.contains("/") || hourField.equals("*"))) {
+      int minute = Integer.parseInt(minuteField);
       String[] parts = hourField.split("/", 2);
       String rangePart = parts[0];
-      String stepStr = parts[1];
+      String stepStr = parts.length > 1 ? parts[1] : "1";
 
       int interval;
       try {
@@ -620,14 +710,14 @@ public final class CronConverter {
 
       if ((domField.equals("*") || domField.equals("?"))
           && (dowField.equals("*") || dowField.equals("?"))) {
-        // Use :59 only for full day (00:00 to 23:59), otherwise use :00
-        int endMinute = (fromHour == 0 && toHour == 23) ? 59 : 0;
+        // Use :59 only for full day (00:00 to 23:59), otherwise end on the last firing
+        int endMinute = (fromHour == 0 && toHour == 23) ? 59 : minute;
 
         ScheduleExpr expr =
             new IntervalRepeat(
                 interval,
                 IntervalUnit.HOURS,
-                new TimeOfDay(fromHour, 0),
+                new TimeOfDay(fromHour, minute),
                 new TimeOfDay(toHour, endMinute),
                 null);
         return new ScheduleData(expr, null, List.of(), null, null, during);
@@ -793,27 +883,13 @@ public final class CronConverter {
       }
     }
 
-    // Check for special patterns
-    if (days.size() == 5) {
-      List<Weekday> sorted = new ArrayList<>(days);
-      sorted.sort((a, b) -> Integer.compare(a.number(), b.number()));
-      List<Weekday> weekdays =
-          List.of(
-              Weekday.MONDAY, Weekday.TUESDAY, Weekday.WEDNESDAY, Weekday.THURSDAY, Weekday.FRIDAY);
-      if (sorted.equals(weekdays)) {
-        return DayFilter.weekday();
-      }
+    // Cron has no weekend keyword, so 0,6 reads as the weekend
+    DayFilter canonical = DayFilter.days(days).canonical();
+    if (canonical.kind() == DayFilter.Kind.DAYS
+        && canonical.days().equals(List.of(Weekday.SATURDAY, Weekday.SUNDAY))) {
+      return DayFilter.weekend();
     }
-    if (days.size() == 2) {
-      List<Weekday> sorted = new ArrayList<>(days);
-      sorted.sort((a, b) -> Integer.compare(a.number(), b.number()));
-      List<Weekday> weekend = List.of(Weekday.SATURDAY, Weekday.SUNDAY);
-      if (sorted.equals(weekend)) {
-        return DayFilter.weekend();
-      }
-    }
-
-    return DayFilter.days(days);
+    return canonical;
   }
 
   /** Parse a DOW value (number 0-7 or name SUN-SAT), normalizing 7 to 0. */
@@ -862,6 +938,36 @@ public final class CronConverter {
     };
   }
 
+  /**
+   * Parses the minute and hour fields into times. Either field may be a comma list; every hour
+   * pairs with every minute, so "0,30 9,17" gives four times.
+   */
+  private static List<TimeOfDay> parseCronTimes(String minuteField, String hourField)
+      throws HronException {
+    List<Integer> minutes = parseValueList(minuteField, "minute", 0, 59);
+    List<Integer> hours = parseValueList(hourField, "hour", 0, 23);
+    List<TimeOfDay> times = new ArrayList<>();
+    for (int h : hours) {
+      for (int m : minutes) {
+        times.add(new TimeOfDay(h, m));
+      }
+    }
+    return times.stream()
+        .distinct()
+        .sorted(Comparator.comparingInt(TimeOfDay::totalMinutes))
+        .toList();
+  }
+
+  /** Parse a comma list of numeric values, each validated like a single value. */
+  private static List<Integer> parseValueList(String field, String name, int min, int max)
+      throws HronException {
+    List<Integer> values = new ArrayList<>();
+    for (String v : field.split(",", -1)) {
+      values.add(parseSingleValue(v, name, min, max));
+    }
+    return values;
+  }
+
   /** Parse a single numeric value with validation. */
   private static int parseSingleValue(String field, String name, int min, int max)
       throws HronException {
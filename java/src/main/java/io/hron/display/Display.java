@@ -29,14 +29,19 @@ public final class Display {
       sb.append(renderUntil(data.until()));
     }
 
-    if (data.anchor() != null && !data.anchor().isEmpty()) {
+    String anchor = data.anchorText();
+    if (anchor != null && !anchor.isEmpty()) {
       sb.append(" starting ");
-      sb.append(data.anchor());
+      sb.append(anchor);
+    }
+    if (data.anchorTime() != null) {
+      sb.append(" at ");
+      sb.append(data.anchorTime());
     }
 
     if (!data.during().isEmpty()) {
       sb.append(" during ");
-      sb.append(renderMonthList(data.during()));
+      sb.append(renderDuringSpecs(data.during()));
     }
 
     if (data.timezone() != null && !data.timezone().isEmpty()) {
@@ -55,11 +60,19 @@ public final class Display {
       case MonthRepeat mr -> renderMonthRepeat(mr);
       case SingleDate sd -> renderSingleDate(sd);
       case YearRepeat yr -> renderYearRepeat(yr);
+      case IsoWeekRepeat iw -> renderIsoWeekRepeat(iw);
+      case WeekdayRepeat wr ->
+          String.format("every %d weekdays at %s", wr.interval(), formatTimeList(wr.times()));
     };
   }
 
   private static String renderDayRepeat(DayRepeat dr) {
     if (dr.interval() > 1) {
+      if (dr.days().kind() != DayFilter.Kind.EVERY) {
+        return String.format(
+            "every %d days on %s at %s",
+            dr.interval(), renderDayFilter(dr.days()), formatTimeList(dr.times()));
+      }
       return String.format("every %d days at %s", dr.interval(), formatTimeList(dr.times()));
     }
     return String.format("every %s at %s", renderDayFilter(dr.days()), formatTimeList(dr.times()));
@@ -110,18 +123,32 @@ public final class Display {
     return String.format("every year on %s at %s", targetStr, formatTimeList(yr.times()));
   }
 
+  private static String renderIsoWeekRepeat(IsoWeekRepeat iw) {
+    String weeks = iw.weeks().stream().map(String::valueOf).collect(Collectors.joining(", "));
+    return String.format(
+        "every year in iso weeks %s on %s at %s",
+        weeks, formatDayList(iw.days()), formatTimeList(iw.times()));
+  }
+
   private static String renderDayFilter(DayFilter f) {
     return switch (f.kind()) {
       case EVERY -> "day";
       case WEEKDAY -> "weekday";
       case WEEKEND -> "weekend";
-      case DAYS -> formatDayList(f.days());
+      case DAYS -> {
+        DayFilter canonical = f.canonical();
+        yield canonical.kind() == DayFilter.Kind.DAYS
+            ? formatDayList(canonical.days())
+            : renderDayFilter(canonical);
+      }
     };
   }
 
   private static String renderMonthTarget(MonthTarget target) {
     return switch (target.kind()) {
       case LAST_DAY -> "last day";
+      case LAST_N_DAYS -> String.format("last %d days", target.count());
+      case BUSINESS_DAY -> ordinalNumber(target.count()) + " business day";
       case LAST_WEEKDAY -> "last weekday";
       case DAYS -> formatOrdinalDaySpecs(target.specs());
       case NEAREST_WEEKDAY -> {
@@ -137,6 +164,15 @@ public final class Display {
         yield sb.toString();
       }
       case ORDINAL_WEEKDAY -> String.format("%s %s", target.ordinal(), target.weekday());
+      case ORDINAL_WEEKDAYS -> {
+        List<OrdinalPosition> ordinals = target.ordinals();
+        String leading =
+            ordinals.subList(0, ordinals.size() - 1).stream()
+                .map(OrdinalPosition::toString)
+                .collect(Collectors.joining(", "));
+        yield String.format(
+            "%s and %s %s", leading, ordinals.get(ordinals.size() - 1), target.weekday());
+      }
     };
   }
 
@@ -155,6 +191,7 @@ public final class Display {
     return switch (spec.kind()) {
       case NAMED -> String.format("%s %d", spec.month(), spec.day());
       case ISO -> spec.date();
+      case NEXT_WEEKDAY -> spec.weekday().toString();
     };
   }
 
@@ -166,6 +203,10 @@ public final class Display {
     return switch (exc.kind()) {
       case NAMED -> String.format("%s %d", exc.month(), exc.day());
       case ISO -> exc.date();
+      case WEEKDAY -> exc.weekday().toString();
+      case ISO_RANGE -> String.format("%s to %s", exc.date(), exc.endDate());
+      case NAMED_RANGE ->
+          String.format("%s %d to %s %d", exc.month(), exc.day(), exc.endMonth(), exc.endDay());
     };
   }
 
@@ -176,8 +217,22 @@ public final class Display {
     };
   }
 
-  private static String renderMonthList(List<MonthName> months) {
-    return months.stream().map(MonthName::toString).collect(Collectors.joining(", "));
+  private static String renderDuringSpecs(List<DuringSpec> specs) {
+    return specs.stream().map(Display::renderDuringSpec).collect(Collectors.joining(", "));
+  }
+
+  private static String renderDuringSpec(DuringSpec spec) {
+    // Date ranges covering whole months print as months
+    if (spec.wholeMonths()) {
+      return spec.start() == spec.end()
+          ? spec.start().toString()
+          : spec.start() + " to " + spec.end();
+    }
+    if (spec.start() == spec.end() && spec.firstDay() == spec.lastDay()) {
+      return spec.start() + " " + spec.firstDay();
+    }
+    return String.format(
+        "%s %d to %s %d", spec.start(), spec.firstDay(), spec.end(), spec.lastDay());
   }
 
   private static String formatTimeList(List<TimeOfDay> times) {
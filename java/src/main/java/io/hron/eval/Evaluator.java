@@ -3,6 +3,7 @@ package io.hron.eval;
 import io.hron.ast.*;
 import java.time.*;
 import java.time.temporal.ChronoUnit;
+import java.time.temporal.IsoFields;
 import java.util.ArrayList;
 import java.util.Iterator;
 import java.util.List;
@@ -28,6 +29,7 @@ import java.util.stream.StreamSupport;
  *   <li>Week repeat: 54 weeks (covers one year + margin)
  *   <li>Month repeat: 24 * interval months (covers 2 years scaled by interval)
  *   <li>Year repeat: 8 * interval years (covers reasonable future horizon)
+ *   <li>ISO week repeat: 7 ISO years (week 53 only exists in long years)
  * </ul>
  *
  * <p>These limits are generous safety bounds. In practice, valid schedules find occurrences within
@@ -73,6 +75,9 @@ public final class Evaluator {
   /** Epoch Monday for week alignment. */
   private static final LocalDate EPOCH_MONDAY = LocalDate.of(1970, 1, 5);
 
+  /** ISO years searched for a matching week; week 53 only exists in long ISO years. */
+  private static final int ISO_YEAR_HORIZON = 7;
+
   private Evaluator() {}
 
   /**
@@ -85,6 +90,7 @@ public final class Evaluator {
    */
   public static Optional<ZonedDateTime> nextFrom(
       ScheduleData data, ZonedDateTime now, ZoneId location) {
+    data = resolveAnchor(data, now, location);
     // Check if expression is NearestWeekday with direction (can cross month boundaries)
     // For these expressions, during filter is handled inside nextMonthRepeat
     boolean handlesDuringInternally = false;
@@ -93,10 +99,18 @@ public final class Evaluator {
         && mr.target().nearestDirection() != null) {
       handlesDuringInternally = true;
     }
+    List<DuringSpec> during = data.during();
+
+    // Nothing occurs before the starting anchor, so search from its start
+    ZonedDateTime start = anchorStart(data, location);
+    if (start != null && now.isBefore(start)) {
+      now = start.minusNanos(1);
+    }
 
     for (int i = 0; i < MAX_ITERATIONS; i++) {
       Optional<ZonedDateTime> candidate =
-          nextCandidate(data.expr(), now, location, data.anchor(), data.during());
+          nextCandidate(
+              data.expr(), now, location, data.anchor(), data.duringMonths(), data.except());
       if (candidate.isEmpty()) {
         return Optional.empty();
       }
@@ -106,7 +120,9 @@ public final class Evaluator {
       // Apply modifiers
       // Check exception list
       if (isExcepted(t.toLocalDate(), data.except())) {
-        now = t;
+        // Advance past this day (or the whole excepted range) and retry
+        LocalDate runEnd = exceptedRunEnd(t.toLocalDate(), data.except());
+        now = ZonedDateTime.of(runEnd.plusDays(1), LocalTime.MIDNIGHT, location).minusNanos(1);
         continue;
       }
 
@@ -119,10 +135,10 @@ public final class Evaluator {
       }
 
       // Check during clause (skip for expressions that handle during internally)
-      if (!handlesDuringInternally && !matchesDuring(t.toLocalDate(), data.during())) {
-        // Skip to next month that matches
-        LocalDate nextMonth = nextDuringMonth(t.toLocalDate(), data.during());
-        now = ZonedDateTime.of(nextMonth, LocalTime.MIDNIGHT, location).minusNanos(1);
+      if (!handlesDuringInternally && !matchesDuring(t.toLocalDate(), during)) {
+        // Skip ahead to the start of the next during period
+        LocalDate nextStart = nextDuringStart(t.toLocalDate(), during);
+        now = ZonedDateTime.of(nextStart, LocalTime.MIDNIGHT, location).minusNanos(1);
         continue;
       }
 
@@ -143,6 +159,7 @@ public final class Evaluator {
    */
   public static List<ZonedDateTime> nextNFrom(
       ScheduleData data, ZonedDateTime now, int n, ZoneId location) {
+    data = pinRelative(data, now, location);
     List<ZonedDateTime> results = new ArrayList<>(n);
     ZonedDateTime current = now;
 
@@ -168,6 +185,7 @@ public final class Evaluator {
    */
   public static Stream<ZonedDateTime> occurrences(
       ScheduleData data, ZonedDateTime from, ZoneId location) {
+    ScheduleData pinned = pinRelative(data, from, location);
     Iterator<ZonedDateTime> iterator =
         new Iterator<>() {
           private ZonedDateTime current = from;
@@ -177,7 +195,7 @@ public final class Evaluator {
 
           private void computeNext() {
             if (!computed) {
-              Optional<ZonedDateTime> result = nextFrom(data, current, location);
+              Optional<ZonedDateTime> result = nextFrom(pinned, current, location);
               if (result.isPresent()) {
                 next = result.get();
                 current = next.plusMinutes(1);
@@ -234,6 +252,22 @@ public final class Evaluator {
    * @return true if the datetime matches
    */
   public static boolean matches(ScheduleData data, ZonedDateTime dt, ZoneId location) {
+    // There is no evaluation time to resolve a relative anchor against;
+    // resolving it against dt would make every date its own anchor.
+    if (data.hasRelativeAnchor()) {
+      throw new IllegalStateException(
+          "matches has no evaluation time to resolve 'starting "
+              + data.anchorText()
+              + "' against; pin the anchor to a date first");
+    }
+    // Nor an "on <weekday>" date: every such weekday would be the upcoming one
+    if (data.expr() instanceof SingleDate upcoming
+        && upcoming.dateSpec().kind() == DateSpec.Kind.NEXT_WEEKDAY) {
+      throw new IllegalStateException(
+          "matches has no evaluation time to resolve 'on "
+              + upcoming.dateSpec().weekday()
+              + "' against; use a date instead");
+    }
     ZonedDateTime zdt = dt.withZoneSameInstant(location);
     LocalDate date = zdt.toLocalDate();
 
@@ -247,6 +281,12 @@ public final class Evaluator {
       return false;
     }
 
+    // Nothing occurs before the starting anchor
+    ZonedDateTime start = anchorStart(data, location);
+    if (start != null && dt.isBefore(start)) {
+      return false;
+    }
+
     // Check until
     if (data.until() != null) {
       LocalDate untilDate = resolveUntil(data.until(), date);
@@ -271,6 +311,16 @@ public final class Evaluator {
         }
         yield true;
       }
+      case IntervalRepeat ir when ir.unit() == IntervalUnit.DAYS -> {
+        DayRepeat dr = dayStepRepeat(ir);
+        if (!matchesDayFilter(date, dr.days())
+            || !timeMatchesWithDst(date, dr.times(), location, dt)) {
+          yield false;
+        }
+        LocalDate anchorDate = data.anchor() != null ? LocalDate.parse(data.anchor()) : EPOCH_DATE;
+        long dayOffset = ChronoUnit.DAYS.between(anchorDate, date);
+        yield dayOffset >= 0 && dayOffset % dr.interval() == 0;
+      }
       case IntervalRepeat ir -> {
         if (ir.dayFilter() != null && !matchesDayFilter(date, ir.dayFilter())) {
           yield false;
@@ -311,7 +361,7 @@ public final class Evaluator {
             yield false;
           }
         }
-        yield matchesMonthTarget(date, mr.target());
+        yield matchesMonthTarget(date, mr.target(), data.except());
       }
       case SingleDate sd -> {
         if (!timeMatchesWithDst(date, sd.times(), location, dt)) {
@@ -322,6 +372,8 @@ public final class Evaluator {
           case NAMED ->
               date.getMonthValue() == sd.dateSpec().month().number()
                   && date.getDayOfMonth() == sd.dateSpec().day();
+          // matches rejects it up front; any day with that weekday can be it
+          case NEXT_WEEKDAY -> date.getDayOfWeek() == sd.dateSpec().weekday().toDayOfWeek();
         };
       }
       case YearRepeat yr -> {
@@ -340,9 +392,90 @@ public final class Evaluator {
         }
         yield matchesYearTarget(date, yr.target());
       }
+      case IsoWeekRepeat iw -> {
+        if (!iw.weeks().contains(date.get(IsoFields.WEEK_OF_WEEK_BASED_YEAR))) {
+          yield false;
+        }
+        if (!iw.days().contains(Weekday.fromDayOfWeek(date.getDayOfWeek()))) {
+          yield false;
+        }
+        yield timeMatchesWithDst(date, iw.times(), location, dt);
+      }
+      case WeekdayRepeat wr -> {
+        LocalDate anchorDate = data.anchor() != null ? LocalDate.parse(data.anchor()) : EPOCH_DATE;
+        if (!weekdayIntervalAligned(anchorDate, wr.interval(), date)) {
+          yield false;
+        }
+        yield timeMatchesWithDst(date, wr.times(), location, dt);
+      }
     };
   }
 
+  /**
+   * Pins a relative anchor to a date in the schedule zone: "starting today" and "starting now" to
+   * the date of {@code now}, "starting jan 6" to the next jan 6 on or after it.
+   */
+  private static ScheduleData resolveAnchor(ScheduleData data, ZonedDateTime now, ZoneId location) {
+    if (!data.hasRelativeAnchor()) {
+      return data;
+    }
+    LocalDate today = now.withZoneSameInstant(location).toLocalDate();
+    DateSpec named = data.anchorNamed();
+    if (named == null) {
+      return data.withAnchor(today.toString());
+    }
+    // Try up to 8 years forward (covers leap year cycles)
+    for (int y = 0; y < 8; y++) {
+      try {
+        LocalDate d = LocalDate.of(today.getYear() + y, named.month().number(), named.day());
+        if (!d.isBefore(today)) {
+          return data.withAnchor(d.toString());
+        }
+      } catch (DateTimeException e) {
+        // Invalid date, try next year
+      }
+    }
+    throw new IllegalStateException("starting date " + data.anchorText() + " never occurs");
+  }
+
+  /**
+   * Returns the instant nothing occurs before: the resolved anchor date at the "starting ... at"
+   * time, or at midnight.
+   */
+  private static ZonedDateTime anchorStart(ScheduleData data, ZoneId location) {
+    if (data.anchor() == null) {
+      return null;
+    }
+    TimeOfDay tod = data.anchorTime() != null ? data.anchorTime() : new TimeOfDay(0, 0);
+    return atTimeOnDate(LocalDate.parse(data.anchor()), tod, location);
+  }
+
+  /**
+   * Pins what the schedule resolves against the evaluation time, as seen from {@code now}: "on
+   * &lt;weekday&gt;" becomes the one date it names (the nearest such weekday with a time still
+   * ahead), and a relative anchor becomes its date.
+   */
+  private static ScheduleData pinRelative(ScheduleData data, ZonedDateTime now, ZoneId location) {
+    data = resolveAnchor(data, now, location);
+    if (!(data.expr() instanceof SingleDate sd)
+        || sd.dateSpec().kind() != DateSpec.Kind.NEXT_WEEKDAY) {
+      return data;
+    }
+    return nextSingleDate(sd, now.withZoneSameInstant(location), location)
+        .map(
+            next ->
+                new ScheduleData(
+                    new SingleDate(DateSpec.iso(next.toLocalDate().toString()), sd.times()),
+                    data.timezone(),
+                    data.except(),
+                    data.until(),
+                    data.anchor(),
+                    data.anchorNamed(),
+                    data.anchorTime(),
+                    data.during()))
+        .orElse(data);
+  }
+
   /** Checks if a time matches any of the scheduled times, accounting for DST gaps. */
   private static boolean timeMatchesWithDst(
       LocalDate date, List<TimeOfDay> times, ZoneId location, ZonedDateTime dt) {
@@ -362,10 +495,17 @@ public final class Evaluator {
   }
 
   /** Checks if a date matches a month target. */
-  private static boolean matchesMonthTarget(LocalDate date, MonthTarget target) {
+  private static boolean matchesMonthTarget(
+      LocalDate date, MonthTarget target, List<ExceptionSpec> except) {
     return switch (target.kind()) {
       case DAYS -> target.expandDays().contains(date.getDayOfMonth());
       case LAST_DAY -> date.equals(lastDayOfMonth(date.getYear(), date.getMonth()));
+      case LAST_N_DAYS ->
+          date.getDayOfMonth()
+              > lastDayOfMonth(date.getYear(), date.getMonth()).getDayOfMonth() - target.count();
+      case BUSINESS_DAY ->
+          nthBusinessDay(date.getYear(), date.getMonth(), target.count(), except)
+              .equals(Optional.of(date));
       case LAST_WEEKDAY -> date.equals(lastWeekdayOfMonth(date.getYear(), date.getMonth()));
       case NEAREST_WEEKDAY -> {
         Optional<LocalDate> nwd =
@@ -381,6 +521,9 @@ public final class Evaluator {
             nthWeekdayOfMonth(date.getYear(), date.getMonth(), target.weekday(), target.ordinal());
         yield ord.isPresent() && date.equals(ord.get());
       }
+      case ORDINAL_WEEKDAYS ->
+          ordinalWeekdayDates(date.getYear(), date.getMonth(), target.ordinals(), target.weekday())
+              .contains(date);
     };
   }
 
@@ -409,7 +552,8 @@ public final class Evaluator {
   }
 
   /**
-   * Computes the most recent occurrence strictly before the given time.
+   * Computes the most recent occurrence strictly before the given time. With "starting now" nothing
+   * occurs before the given time, so there is none.
    *
    * @param data the schedule data
    * @param now the reference time (exclusive upper bound)
@@ -418,8 +562,13 @@ public final class Evaluator {
    */
   public static Optional<ZonedDateTime> previousFrom(
       ScheduleData data, ZonedDateTime now, ZoneId location) {
-    // Get anchor date for starting bound
-    LocalDate anchorDate = data.anchor() != null ? LocalDate.parse(data.anchor()) : null;
+    if ("now".equals(data.anchor())) {
+      return Optional.empty();
+    }
+    data = pinRelative(data, now, location);
+    // Get the instant for the starting bound
+    ZonedDateTime start = anchorStart(data, location);
+    List<DuringSpec> during = data.during();
 
     // Handle until clause - if now is after until, search from end of until date
     ZonedDateTime searchFrom = now;
@@ -433,7 +582,8 @@ public final class Evaluator {
 
     for (int i = 0; i < MAX_ITERATIONS; i++) {
       Optional<ZonedDateTime> candidate =
-          prevCandidate(data.expr(), searchFrom, location, data.anchor(), data.during());
+          prevCandidate(
+              data.expr(), searchFrom, location, data.anchor(), data.duringMonths(), data.except());
       if (candidate.isEmpty()) {
         return Optional.empty();
       }
@@ -441,7 +591,7 @@ public final class Evaluator {
       ZonedDateTime t = candidate.get();
 
       // Check if before anchor
-      if (anchorDate != null && t.toLocalDate().isBefore(anchorDate)) {
+      if (start != null && t.isBefore(start)) {
         return Optional.empty();
       }
 
@@ -456,20 +606,17 @@ public final class Evaluator {
 
       // Check exception list
       if (isExcepted(t.toLocalDate(), data.except())) {
-        searchFrom = t;
+        // Go back before this day (or the whole excepted range) and retry
+        LocalDate runStart = exceptedRunStart(t.toLocalDate(), data.except());
+        searchFrom = ZonedDateTime.of(runStart, LocalTime.MIDNIGHT, location);
         continue;
       }
 
       // Check during clause
-      if (!matchesDuring(t.toLocalDate(), data.during())) {
-        // Skip to previous month that matches
-        LocalDate prevMonth = prevDuringMonth(t.toLocalDate(), data.during());
-        if (prevMonth == null) {
-          return Optional.empty();
-        }
-        searchFrom =
-            ZonedDateTime.of(
-                prevMonth.plusMonths(1).withDayOfMonth(1), LocalTime.MIDNIGHT, location);
+      if (!matchesDuring(t.toLocalDate(), during)) {
+        // Skip back to the end of the previous during period
+        LocalDate prevEnd = prevDuringEnd(t.toLocalDate(), during);
+        searchFrom = ZonedDateTime.of(prevEnd.plusDays(1), LocalTime.MIDNIGHT, location);
         continue;
       }
 
@@ -484,14 +631,19 @@ public final class Evaluator {
       ZonedDateTime now,
       ZoneId location,
       String anchor,
-      List<MonthName> during) {
+      List<MonthName> during,
+      List<ExceptionSpec> except) {
     return switch (expr) {
       case DayRepeat dr -> nextDayRepeat(dr, now, location, anchor);
+      case IntervalRepeat ir when ir.unit() == IntervalUnit.DAYS ->
+          nextDayRepeat(dayStepRepeat(ir), now, location, anchor);
       case IntervalRepeat ir -> nextIntervalRepeat(ir, now, location);
       case WeekRepeat wr -> nextWeekRepeat(wr, now, location, anchor);
-      case MonthRepeat mr -> nextMonthRepeat(mr, now, location, anchor, during);
+      case MonthRepeat mr -> nextMonthRepeat(mr, now, location, anchor, during, except);
       case SingleDate sd -> nextSingleDate(sd, now, location);
       case YearRepeat yr -> nextYearRepeat(yr, now, location, anchor);
+      case IsoWeekRepeat iw -> nextIsoWeekRepeat(iw, now, location);
+      case WeekdayRepeat wr -> nextWeekdayRepeat(wr, now, location, anchor);
     };
   }
 
@@ -500,17 +652,29 @@ public final class Evaluator {
       ZonedDateTime now,
       ZoneId location,
       String anchor,
-      List<MonthName> during) {
+      List<MonthName> during,
+      List<ExceptionSpec> except) {
     return switch (expr) {
       case DayRepeat dr -> prevDayRepeat(dr, now, location, anchor);
+      case IntervalRepeat ir when ir.unit() == IntervalUnit.DAYS ->
+          prevDayRepeat(dayStepRepeat(ir), now, location, anchor);
       case IntervalRepeat ir -> prevIntervalRepeat(ir, now, location);
       case WeekRepeat wr -> prevWeekRepeat(wr, now, location, anchor);
-      case MonthRepeat mr -> prevMonthRepeat(mr, now, location, anchor);
+      case MonthRepeat mr -> prevMonthRepeat(mr, now, location, anchor, except);
       case SingleDate sd -> prevSingleDate(sd, now, location);
       case YearRepeat yr -> prevYearRepeat(yr, now, location, anchor);
+      case IsoWeekRepeat iw -> prevIsoWeekRepeat(iw, now, location);
+      case WeekdayRepeat wr -> prevWeekdayRepeat(wr, now, location, anchor);
     };
   }
 
+  // A day-step interval window fires once, at the window start, on each aligned day: the day
+  // repeat with the window start as its only time.
+  private static DayRepeat dayStepRepeat(IntervalRepeat ir) {
+    DayFilter days = ir.dayFilter() != null ? ir.dayFilter() : DayFilter.every();
+    return new DayRepeat(ir.interval(), days, List.of(ir.fromTime()));
+  }
+
   private static Optional<ZonedDateTime> nextDayRepeat(
       DayRepeat dr, ZonedDateTime now, ZoneId location, String anchor) {
     LocalDate anchorDate = anchor != null ? LocalDate.parse(anchor) : EPOCH_DATE;
@@ -626,8 +790,14 @@ public final class Evaluator {
     return Optional.empty();
   }
 
+  /** Finds the next month occurrence. Business-day targets skip over days exceptions remove. */
   private static Optional<ZonedDateTime> nextMonthRepeat(
-      MonthRepeat mr, ZonedDateTime now, ZoneId location, String anchor, List<MonthName> during) {
+      MonthRepeat mr,
+      ZonedDateTime now,
+      ZoneId location,
+      String anchor,
+      List<MonthName> during,
+      List<ExceptionSpec> except) {
     LocalDate anchorDate = anchor != null ? LocalDate.parse(anchor) : EPOCH_DATE;
     LocalDate day = now.toLocalDate();
 
@@ -639,7 +809,7 @@ public final class Evaluator {
 
     for (int i = 0; i < MAX_ITERATIONS; i++) {
       // Check during filter for NearestWeekday with direction
-      if (applyDuringFilter && !matchesDuring(day.withDayOfMonth(1), during)) {
+      if (applyDuringFilter && !during.contains(MonthName.fromMonth(day.getMonth()))) {
         LocalDate nextMonth = nextDuringMonth(day, during);
         day = nextMonth;
         continue;
@@ -658,7 +828,8 @@ public final class Evaluator {
       }
 
       // Get target days for this month
-      List<LocalDate> targetDays = getTargetDaysInMonth(day.getYear(), day.getMonth(), mr.target());
+      List<LocalDate> targetDays =
+          getTargetDaysInMonth(day.getYear(), day.getMonth(), mr.target(), except);
 
       // For directional NearestWeekday, the result can be in a different month
       // (e.g., "previous nearest weekday to 1st" in March -> Feb 27)
@@ -707,6 +878,21 @@ public final class Evaluator {
         }
         return Optional.empty();
       }
+      case NEXT_WEEKDAY -> {
+        // Today if a time is still ahead, otherwise within the next week
+        DayOfWeek dow = sd.dateSpec().weekday().toDayOfWeek();
+        for (int offset = 0; offset < 8; offset++) {
+          LocalDate d = now.toLocalDate().plusDays(offset);
+          if (d.getDayOfWeek() != dow) {
+            continue;
+          }
+          Optional<ZonedDateTime> time = earliestFutureTime(d, sd.times(), location, now);
+          if (time.isPresent()) {
+            return time;
+          }
+        }
+        return Optional.empty();
+      }
     }
 
     return Optional.empty();
@@ -747,6 +933,20 @@ public final class Evaluator {
     return Optional.empty();
   }
 
+  private static Optional<ZonedDateTime> nextIsoWeekRepeat(
+      IsoWeekRepeat iw, ZonedDateTime now, ZoneId location) {
+    int startYear = now.toLocalDate().get(IsoFields.WEEK_BASED_YEAR);
+    for (int year = startYear; year < startYear + ISO_YEAR_HORIZON; year++) {
+      for (LocalDate d : isoWeekDates(year, iw.weeks(), iw.days())) {
+        Optional<ZonedDateTime> time = earliestFutureTime(d, iw.times(), location, now);
+        if (time.isPresent()) {
+          return time;
+        }
+      }
+    }
+    return Optional.empty();
+  }
+
   // Previous occurrence methods
 
   private static Optional<ZonedDateTime> prevDayRepeat(
@@ -864,7 +1064,11 @@ public final class Evaluator {
   }
 
   private static Optional<ZonedDateTime> prevMonthRepeat(
-      MonthRepeat mr, ZonedDateTime now, ZoneId location, String anchor) {
+      MonthRepeat mr,
+      ZonedDateTime now,
+      ZoneId location,
+      String anchor,
+      List<ExceptionSpec> except) {
     LocalDate anchorDate = anchor != null ? LocalDate.parse(anchor) : EPOCH_DATE;
     LocalDate day = now.toLocalDate();
 
@@ -883,7 +1087,8 @@ public final class Evaluator {
       }
 
       // Get target days for this month
-      List<LocalDate> targetDays = getTargetDaysInMonth(day.getYear(), day.getMonth(), mr.target());
+      List<LocalDate> targetDays =
+          getTargetDaysInMonth(day.getYear(), day.getMonth(), mr.target(), except);
 
       // Sort in reverse order for backwards search
       targetDays = new ArrayList<>(targetDays);
@@ -929,6 +1134,10 @@ public final class Evaluator {
         }
         return Optional.empty();
       }
+      case NEXT_WEEKDAY -> {
+        // previousFrom pins "on <weekday>" to an ISO date first
+        return Optional.empty();
+      }
     }
 
     return Optional.empty();
@@ -969,8 +1178,89 @@ public final class Evaluator {
     return Optional.empty();
   }
 
+  /**
+   * Returns today if it is an aligned business day with a time still ahead, otherwise the next
+   * aligned business day.
+   */
+  private static Optional<ZonedDateTime> nextWeekdayRepeat(
+      WeekdayRepeat wr, ZonedDateTime now, ZoneId location, String anchor) {
+    LocalDate today = now.toLocalDate();
+    LocalDate anchorDate = anchor != null ? LocalDate.parse(anchor) : EPOCH_DATE;
+    long anchorIndex = businessDayIndex(anchorDate);
+    long todayIndex = businessDayIndex(today);
+    boolean isWeekday = today.getDayOfWeek().getValue() <= 5;
+
+    if (isWeekday && Math.floorMod(todayIndex - anchorIndex, wr.interval()) == 0) {
+      Optional<ZonedDateTime> time = earliestFutureTime(today, wr.times(), location, now);
+      if (time.isPresent()) {
+        return time;
+      }
+    }
+    // A weekend already shares the following Monday's index
+    long after = isWeekday ? todayIndex + 1 : todayIndex;
+    long index = after + Math.floorMod(anchorIndex - after, wr.interval());
+    return earliestFutureTime(businessDayDate(index), wr.times(), location, now);
+  }
+
+  private static Optional<ZonedDateTime> prevWeekdayRepeat(
+      WeekdayRepeat wr, ZonedDateTime now, ZoneId location, String anchor) {
+    LocalDate today = now.toLocalDate();
+    LocalDate anchorDate = anchor != null ? LocalDate.parse(anchor) : EPOCH_DATE;
+    long anchorIndex = businessDayIndex(anchorDate);
+    long todayIndex = businessDayIndex(today);
+
+    if (today.getDayOfWeek().getValue() <= 5
+        && Math.floorMod(todayIndex - anchorIndex, wr.interval()) == 0) {
+      Optional<ZonedDateTime> time = latestPastTime(today, wr.times(), location, now);
+      if (time.isPresent()) {
+        return time;
+      }
+    }
+    // On a weekend the index is the following Monday's, so one back is Friday
+    long before = todayIndex - 1;
+    long index = before - Math.floorMod(before - anchorIndex, wr.interval());
+    return latestPastTime(businessDayDate(index), wr.times(), location, now);
+  }
+
+  private static Optional<ZonedDateTime> prevIsoWeekRepeat(
+      IsoWeekRepeat iw, ZonedDateTime now, ZoneId location) {
+    int startYear = now.toLocalDate().get(IsoFields.WEEK_BASED_YEAR);
+    for (int year = startYear; year > startYear - ISO_YEAR_HORIZON; year--) {
+      List<LocalDate> dates = isoWeekDates(year, iw.weeks(), iw.days());
+      for (int i = dates.size() - 1; i >= 0; i--) {
+        Optional<ZonedDateTime> time = latestPastTime(dates.get(i), iw.times(), location, now);
+        if (time.isPresent()) {
+          return time;
+        }
+      }
+    }
+    return Optional.empty();
+  }
+
   // Helper methods
 
+  /**
+   * Returns the dates in an ISO week-numbering year on one of the days in one of the weeks,
+   * ascending. Week 53 yields nothing in years with only 52 weeks.
+   */
+  private static List<LocalDate> isoWeekDates(int year, List<Integer> weeks, List<Weekday> days) {
+    // Jan 4 is always in ISO week 1
+    LocalDate jan4 = LocalDate.of(year, 1, 4);
+    LocalDate week1Monday = jan4.minusDays(jan4.getDayOfWeek().getValue() - 1);
+
+    List<LocalDate> dates = new ArrayList<>();
+    for (int week : weeks) {
+      for (Weekday wd : days) {
+        LocalDate d = week1Monday.plusDays((week - 1) * 7L + wd.number() - 1);
+        if (d.get(IsoFields.WEEK_BASED_YEAR) == year && !dates.contains(d)) {
+          dates.add(d);
+        }
+      }
+    }
+    dates.sort(null);
+    return dates;
+  }
+
   private static boolean matchesDayFilter(LocalDate d, DayFilter f) {
     DayOfWeek dow = d.getDayOfWeek();
     return switch (f.kind()) {
@@ -1042,9 +1332,12 @@ public final class Evaluator {
     return zdt;
   }
 
-  private static List<LocalDate> getTargetDaysInMonth(int year, Month month, MonthTarget target) {
+  private static List<LocalDate> getTargetDaysInMonth(
+      int year, Month month, MonthTarget target, List<ExceptionSpec> except) {
     return switch (target.kind()) {
       case LAST_DAY -> List.of(lastDayOfMonth(year, month));
+      case LAST_N_DAYS -> lastNDaysOfMonth(year, month, target.count());
+      case BUSINESS_DAY -> nthBusinessDay(year, month, target.count(), except).stream().toList();
       case LAST_WEEKDAY -> List.of(lastWeekdayOfMonth(year, month));
       case DAYS -> {
         List<LocalDate> days = new ArrayList<>();
@@ -1068,6 +1361,8 @@ public final class Evaluator {
             nthWeekdayOfMonth(year, month, target.weekday(), target.ordinal());
         yield result.map(List::of).orElse(List.of());
       }
+      case ORDINAL_WEEKDAYS ->
+          ordinalWeekdayDates(year, month, target.ordinals(), target.weekday());
     };
   }
 
@@ -1142,6 +1437,19 @@ public final class Evaluator {
     }
   }
 
+  /** Returns the distinct dates of several ordinal weekdays in a month, in order. */
+  private static List<LocalDate> ordinalWeekdayDates(
+      int year, Month month, List<OrdinalPosition> ordinals, Weekday weekday) {
+    List<LocalDate> dates = new ArrayList<>();
+    for (OrdinalPosition ordinal : ordinals) {
+      Optional<LocalDate> d = nthWeekdayOfMonth(year, month, weekday, ordinal);
+      if (d.isPresent() && !dates.contains(d.get())) {
+        dates.add(d.get());
+      }
+    }
+    return dates;
+  }
+
   private static Optional<LocalDate> nthWeekdayOfMonth(
       int year, Month month, Weekday weekday, OrdinalPosition ordinal) {
     if (ordinal == OrdinalPosition.LAST) {
@@ -1165,10 +1473,66 @@ public final class Evaluator {
     return Optional.of(d);
   }
 
+  /**
+   * Returns the number of business days (Monday-Friday) from the week of the epoch to the date. A
+   * weekend date shares the index of the following Monday.
+   */
+  private static long businessDayIndex(LocalDate date) {
+    // 1970-01-01 was a Thursday, 3 days after the Monday that starts its week
+    long days = ChronoUnit.DAYS.between(EPOCH_DATE, date) + 3;
+    long dayOfWeek = Math.floorMod(days, 7);
+    long weeks = (days - dayOfWeek) / 7;
+    return weeks * 5 + Math.min(dayOfWeek, 5);
+  }
+
+  /** Returns the weekday with the given business day index. */
+  private static LocalDate businessDayDate(long index) {
+    long dayOfWeek = Math.floorMod(index, 5);
+    long weeks = (index - dayOfWeek) / 5;
+    return EPOCH_DATE.plusDays(weeks * 7 + dayOfWeek - 3);
+  }
+
+  /** Reports whether the date is a business day an "every N weekdays" schedule fires on. */
+  private static boolean weekdayIntervalAligned(LocalDate anchor, int interval, LocalDate date) {
+    long offset = businessDayIndex(date) - businessDayIndex(anchor);
+    return date.getDayOfWeek().getValue() <= 5 && offset >= 0 && offset % interval == 0;
+  }
+
   private static LocalDate lastDayOfMonth(int year, Month month) {
     return LocalDate.of(year, month, 1).plusMonths(1).minusDays(1);
   }
 
+  /** Returns the final n days of the month, ascending; the whole month if it is shorter. */
+  private static List<LocalDate> lastNDaysOfMonth(int year, Month month, int n) {
+    LocalDate last = lastDayOfMonth(year, month);
+    int first = Math.max(1, last.getDayOfMonth() - n + 1);
+    List<LocalDate> days = new ArrayList<>();
+    for (int day = first; day <= last.getDayOfMonth(); day++) {
+      days.add(LocalDate.of(year, month, day));
+    }
+    return days;
+  }
+
+  /**
+   * Returns the nth business day (Mon-Fri, 1-indexed) of a month, not counting excepted days, or
+   * empty if the month has fewer.
+   */
+  private static Optional<LocalDate> nthBusinessDay(
+      int year, Month month, int n, List<ExceptionSpec> except) {
+    LocalDate last = lastDayOfMonth(year, month);
+    int count = 0;
+    for (LocalDate d = LocalDate.of(year, month, 1); !d.isAfter(last); d = d.plusDays(1)) {
+      if (d.getDayOfWeek().getValue() > 5 || isExcepted(d, except)) {
+        continue;
+      }
+      count++;
+      if (count == n) {
+        return Optional.of(d);
+      }
+    }
+    return Optional.empty();
+  }
+
   private static LocalDate lastWeekdayOfMonth(int year, Month month) {
     LocalDate d = lastDayOfMonth(year, month);
     while (d.getDayOfWeek() == DayOfWeek.SATURDAY || d.getDayOfWeek() == DayOfWeek.SUNDAY) {
@@ -1220,6 +1584,10 @@ public final class Evaluator {
         }
         yield d;
       }
+      case NEXT_WEEKDAY -> {
+        int ahead = spec.weekday().number() - now.getDayOfWeek().getValue();
+        yield now.plusDays(Math.floorMod(ahead, 7));
+      }
     };
   }
 
@@ -1246,23 +1614,158 @@ public final class Evaluator {
             return true;
           }
         }
+        case WEEKDAY -> {
+          if (Weekday.fromDayOfWeek(d.getDayOfWeek()) == exc.weekday()) {
+            return true;
+          }
+        }
+        case ISO_RANGE, NAMED_RANGE -> {
+          if (rangeBounds(d, exc) != null) {
+            return true;
+          }
+        }
       }
     }
     return false;
   }
 
-  private static boolean matchesDuring(LocalDate d, List<MonthName> during) {
+  /** The concrete first and last days of a range exception. */
+  private record DateRange(LocalDate start, LocalDate end) {}
+
+  /** Returns the concrete bounds of a range exception if it covers the date, or null. */
+  private static DateRange rangeBounds(LocalDate d, ExceptionSpec exc) {
+    if (exc.kind() == ExceptionSpec.Kind.ISO_RANGE) {
+      LocalDate start = LocalDate.parse(exc.date());
+      LocalDate end = LocalDate.parse(exc.endDate());
+      return d.isBefore(start) || d.isAfter(end) ? null : new DateRange(start, end);
+    }
+    if (exc.kind() != ExceptionSpec.Kind.NAMED_RANGE) {
+      return null;
+    }
+    int startKey = exc.month().number() * 100 + exc.day();
+    int endKey = exc.endMonth().number() * 100 + exc.endDay();
+    int key = d.getMonthValue() * 100 + d.getDayOfMonth();
+    int startYear;
+    int endYear;
+    if (startKey <= endKey) {
+      if (key < startKey || key > endKey) {
+        return null;
+      }
+      startYear = d.getYear();
+      endYear = d.getYear();
+    } else if (key >= startKey) {
+      // Wraps into next year
+      startYear = d.getYear();
+      endYear = d.getYear() + 1;
+    } else if (key <= endKey) {
+      startYear = d.getYear() - 1;
+      endYear = d.getYear();
+    } else {
+      return null;
+    }
+    return new DateRange(
+        clampedDate(startYear, exc.month().toMonth(), exc.day()),
+        clampedDate(endYear, exc.endMonth().toMonth(), exc.endDay()));
+  }
+
+  /**
+   * Returns the date for month/day in year, with days past the month's end (feb 29 in a common
+   * year) moved back to its last day.
+   */
+  private static LocalDate clampedDate(int year, Month month, int day) {
+    LocalDate last = lastDayOfMonth(year, month);
+    return day > last.getDayOfMonth() ? last : LocalDate.of(year, month, day);
+  }
+
+  /**
+   * Returns the last day of the excepted run the date falls in, so long ranges are skipped in one
+   * step rather than day by day.
+   */
+  private static LocalDate exceptedRunEnd(LocalDate d, List<ExceptionSpec> exceptions) {
+    LocalDate end = d;
+    for (ExceptionSpec exc : exceptions) {
+      DateRange range = rangeBounds(d, exc);
+      if (range != null && range.end().isAfter(end)) {
+        end = range.end();
+      }
+    }
+    return end;
+  }
+
+  /** Returns the first day of the excepted run the date falls in. */
+  private static LocalDate exceptedRunStart(LocalDate d, List<ExceptionSpec> exceptions) {
+    LocalDate start = d;
+    for (ExceptionSpec exc : exceptions) {
+      DateRange range = rangeBounds(d, exc);
+      if (range != null && range.start().isBefore(start)) {
+        start = range.start();
+      }
+    }
+    return start;
+  }
+
+  /** Checks if a date falls within any during specification. If during is empty, all match. */
+  private static boolean matchesDuring(LocalDate d, List<DuringSpec> during) {
     if (during.isEmpty()) {
       return true;
     }
-    for (MonthName m : during) {
-      if (d.getMonthValue() == m.number()) {
+    MonthName month = MonthName.fromMonth(d.getMonth());
+    for (DuringSpec spec : during) {
+      if (spec.contains(month, d.getDayOfMonth())) {
         return true;
       }
     }
     return false;
   }
 
+  /**
+   * Returns the month-day in the given year, or the day after the month's end when the day does not
+   * exist that year (feb 29 in a common year).
+   */
+  private static LocalDate monthDayInYear(int year, MonthName month, int day) {
+    LocalDate last = lastDayOfMonth(year, month.toMonth());
+    if (day > last.getDayOfMonth()) {
+      return last.plusDays(1);
+    }
+    return LocalDate.of(year, month.toMonth(), day);
+  }
+
+  /** Returns the first day of the next during period starting after d. */
+  private static LocalDate nextDuringStart(LocalDate d, List<DuringSpec> during) {
+    LocalDate best = null;
+    for (DuringSpec spec : during) {
+      for (int year = d.getYear(); year <= d.getYear() + 1; year++) {
+        LocalDate start = monthDayInYear(year, spec.start(), spec.firstDay());
+        if (start.isAfter(d)) {
+          if (best == null || start.isBefore(best)) {
+            best = start;
+          }
+          break;
+        }
+      }
+    }
+    return best != null ? best : d;
+  }
+
+  /** Returns the last day of the previous during period ending before d. */
+  private static LocalDate prevDuringEnd(LocalDate d, List<DuringSpec> during) {
+    LocalDate best = null;
+    for (DuringSpec spec : during) {
+      for (int year = d.getYear(); year >= d.getYear() - 1; year--) {
+        LocalDate last = lastDayOfMonth(year, spec.end().toMonth());
+        LocalDate end =
+            spec.lastDay() < last.getDayOfMonth() ? last.withDayOfMonth(spec.lastDay()) : last;
+        if (end.isBefore(d)) {
+          if (best == null || end.isAfter(best)) {
+            best = end;
+          }
+          break;
+        }
+      }
+    }
+    return best != null ? best : d;
+  }
+
   private static LocalDate nextDuringMonth(LocalDate d, List<MonthName> during) {
     int currentMonth = d.getMonthValue();
 
@@ -1280,30 +1783,6 @@ public final class Evaluator {
     return LocalDate.of(d.getYear() + 1, months.getFirst(), 1);
   }
 
-  private static LocalDate prevDuringMonth(LocalDate d, List<MonthName> during) {
-    int currentMonth = d.getMonthValue();
-
-    // Sort months in descending order
-    List<Integer> months = during.stream().map(MonthName::number).sorted((a, b) -> b - a).toList();
-
-    // Find previous month before current
-    for (int m : months) {
-      if (m < currentMonth) {
-        // Return last day of that month
-        LocalDate firstOfMonth = LocalDate.of(d.getYear(), m, 1);
-        return firstOfMonth.plusMonths(1).minusDays(1);
-      }
-    }
-
-    // Wrap to last month of previous year
-    if (!months.isEmpty()) {
-      LocalDate firstOfMonth = LocalDate.of(d.getYear() - 1, months.getFirst(), 1);
-      return firstOfMonth.plusMonths(1).minusDays(1);
-    }
-
-    return null;
-  }
-
   private static LocalDate resolveUntil(UntilSpec until, LocalDate now) {
     return switch (until.kind()) {
       case ISO -> LocalDate.parse(until.date());
@@ -61,6 +61,18 @@ public final class Lexer {
         continue;
       }
 
+      // Cron shortcut: @daily
+      if (ch == '@') {
+        tokens.add(lexShortcut());
+        continue;
+      }
+
+      // Minutes past the hour: :MM
+      if (ch == ':') {
+        tokens.add(lexMinuteOffset());
+        continue;
+      }
+
       if (isAlpha(ch)) {
         tokens.add(lexWord());
         continue;
@@ -73,6 +85,20 @@ public final class Lexer {
     return tokens;
   }
 
+  /** Reports whether the next word in the input is the given word. */
+  private boolean nextWordIs(String word) {
+    int i = pos;
+    while (i < input.length() && isWhitespace(input.charAt(i))) {
+      i++;
+    }
+    int end = i + word.length();
+    if (end > input.length() || !input.substring(i, end).equalsIgnoreCase(word)) {
+      return false;
+    }
+    return end == input.length()
+        || !(isAlphanumeric(input.charAt(end)) || input.charAt(end) == '_');
+  }
+
   private void skipWhitespace() {
     while (pos < input.length() && isWhitespace(input.charAt(pos))) {
       pos++;
@@ -92,6 +118,29 @@ public final class Lexer {
     return Token.timezone(tz, new Span(start, pos));
   }
 
+  private Token lexShortcut() {
+    int start = pos;
+    pos++; // skip '@'
+    while (pos < input.length() && isAlphanumeric(input.charAt(pos))) {
+      pos++;
+    }
+    return Token.keyword(TokenKind.SHORTCUT, new Span(start, pos));
+  }
+
+  private Token lexMinuteOffset() throws HronException {
+    int start = pos;
+    pos++; // skip ':'
+    while (pos < input.length() && isDigit(input.charAt(pos))) {
+      pos++;
+    }
+    String digits = input.substring(start + 1, pos);
+    if (digits.length() != 2 || Integer.parseInt(digits) > 59) {
+      throw HronException.lex(
+          "expected two-digit minute (:00 to :59) after ':'", new Span(start, pos), input);
+    }
+    return Token.minuteOffset(Integer.parseInt(digits), new Span(start, pos));
+  }
+
   private Token lexNumberOrTimeOrDate() throws HronException {
     int start = pos;
 
@@ -130,6 +179,10 @@ public final class Lexer {
       if (minDigits.length() == 2) {
         int hour = Integer.parseInt(digits);
         int minute = Integer.parseInt(minDigits);
+        Boolean pm = lexMeridiem();
+        if (pm != null) {
+          return meridiemTime(hour, minute, pm, start);
+        }
         if (hour > 23 || minute > 59) {
           throw HronException.lex("invalid time", new Span(start, pos), input);
         }
@@ -137,6 +190,23 @@ public final class Lexer {
       }
     }
 
+    // Check for 12-hour time without minutes: 9am, 5pm
+    if (digits.length() == 1 || digits.length() == 2) {
+      Boolean pm = lexMeridiem();
+      if (pm != null) {
+        return meridiemTime(Integer.parseInt(digits), 0, pm, start);
+      }
+    }
+
+    // Check for decimal: 1.5
+    if (pos + 1 < input.length() && input.charAt(pos) == '.' && isDigit(input.charAt(pos + 1))) {
+      pos++; // skip '.'
+      while (pos < input.length() && isDigit(input.charAt(pos))) {
+        pos++;
+      }
+      return Token.decimal(input.substring(start, pos), new Span(start, pos));
+    }
+
     int num = Integer.parseInt(digits);
 
     // Check for ordinal suffix: st, nd, rd, th
@@ -154,18 +224,61 @@ public final class Lexer {
     return Token.number(num, new Span(start, pos));
   }
 
+  /**
+   * Consumes an am/pm suffix directly following a time. Returns whether the suffix was pm, or null
+   * if there is no suffix.
+   */
+  private Boolean lexMeridiem() {
+    if (pos + 2 > input.length()) {
+      return null;
+    }
+    String suffix = input.substring(pos, pos + 2).toLowerCase();
+    if (!suffix.equals("am") && !suffix.equals("pm")) {
+      return null;
+    }
+    // Must not run into a longer word (e.g. "9amx")
+    if (pos + 2 < input.length() && isAlphanumeric(input.charAt(pos + 2))) {
+      return null;
+    }
+    pos += 2;
+    return suffix.equals("pm");
+  }
+
+  /** Converts a 12-hour clock time to a 24-hour time token. */
+  private Token meridiemTime(int hour, int minute, boolean pm, int start) throws HronException {
+    if (hour < 1 || hour > 12 || minute > 59) {
+      throw HronException.lex(
+          "invalid 12-hour time (hour must be 1-12)", new Span(start, pos), input);
+    }
+    int h = hour;
+    if (h == 12) {
+      if (!pm) {
+        h = 0;
+      }
+    } else if (pm) {
+      h += 12;
+    }
+    return Token.time(h, minute, new Span(start, pos));
+  }
+
   private Token lexWord() throws HronException {
     int start = pos;
     while (pos < input.length()
-        && (isAlphanumeric(input.charAt(pos)) || input.charAt(pos) == '_')) {
+        && (isAlphanumeric(input.charAt(pos)) || input.charAt(pos) == '_' || isWordHyphen())) {
       pos++;
     }
     String word = input.substring(start, pos).toLowerCase();
     Span span = new Span(start, pos);
 
+    // Check keyword map, then ordinal words ("first" through "thirty-first")
     Token tok = KEYWORD_MAP.get(word);
     if (tok == null) {
-      throw HronException.lex("unknown keyword '" + word + "'", span, input);
+      int n = ordinalWordValue(word);
+      if (n == 0) {
+        throw HronException.lex("unknown keyword '" + word + "'", span, input);
+      }
+      OrdinalPosition ord = n <= 5 ? OrdinalPosition.values()[n - 1] : null;
+      return Token.ordinal(ord, n, span);
     }
 
     // Create a new token with the actual span
@@ -173,18 +286,67 @@ public final class Lexer {
         switch (tok.kind()) {
           case DAY_NAME -> Token.dayName(tok.dayNameVal(), span);
           case MONTH_NAME -> Token.monthName(tok.monthNameVal(), span);
-          case ORDINAL -> Token.ordinal(tok.ordinalVal(), span);
           case INTERVAL_UNIT -> Token.intervalUnit(tok.unitVal(), span);
           default -> Token.keyword(tok.kind(), span);
         };
 
     if (tok.kind() == TokenKind.IN) {
-      afterIn = true;
+      // "in the morning" and "in iso weeks" are not timezone clauses
+      afterIn = !nextWordIs("the") && !nextWordIs("iso");
     }
 
     return result;
   }
 
+  /** Reports whether the current character is a hyphen joining two words, as in "twenty-first". */
+  private boolean isWordHyphen() {
+    return input.charAt(pos) == '-' && pos + 1 < input.length() && isAlpha(input.charAt(pos + 1));
+  }
+
+  private static final List<String> ORDINAL_UNITS =
+      List.of(
+          "first", "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth", "ninth");
+
+  private static final List<String> ORDINAL_TEENS =
+      List.of(
+          "tenth",
+          "eleventh",
+          "twelfth",
+          "thirteenth",
+          "fourteenth",
+          "fifteenth",
+          "sixteenth",
+          "seventeenth",
+          "eighteenth",
+          "nineteenth");
+
+  /**
+   * Returns the day number of an ordinal word ("first" is 1, "thirty-first" is 31), or 0 if the
+   * word is not an ordinal.
+   */
+  private static int ordinalWordValue(String word) {
+    switch (word) {
+      case "twentieth":
+        return 20;
+      case "thirtieth":
+        return 30;
+      case "thirty-first":
+        return 31;
+      default:
+        break;
+    }
+    if (word.startsWith("twenty-")) {
+      int i = ORDINAL_UNITS.indexOf(word.substring("twenty-".length()));
+      return i < 0 ? 0 : 21 + i;
+    }
+    int i = ORDINAL_UNITS.indexOf(word);
+    if (i >= 0) {
+      return 1 + i;
+    }
+    i = ORDINAL_TEENS.indexOf(word);
+    return i < 0 ? 0 : 10 + i;
+  }
+
   private static boolean isDigit(char c) {
     return c >= '0' && c <= '9';
   }
@@ -214,6 +376,9 @@ public final class Lexer {
             Map.entry("at", Token.keyword(TokenKind.AT, DUMMY_SPAN)),
             Map.entry("from", Token.keyword(TokenKind.FROM, DUMMY_SPAN)),
             Map.entry("to", Token.keyword(TokenKind.TO, DUMMY_SPAN)),
+            Map.entry("through", Token.keyword(TokenKind.THROUGH, DUMMY_SPAN)),
+            Map.entry("business", Token.keyword(TokenKind.BUSINESS, DUMMY_SPAN)),
+            Map.entry("past", Token.keyword(TokenKind.PAST, DUMMY_SPAN)),
             Map.entry("in", Token.keyword(TokenKind.IN, DUMMY_SPAN)),
             Map.entry("of", Token.keyword(TokenKind.OF, DUMMY_SPAN)),
             Map.entry("the", Token.keyword(TokenKind.THE, DUMMY_SPAN)),
@@ -234,24 +399,45 @@ public final class Lexer {
             Map.entry("week", Token.keyword(TokenKind.WEEKS, DUMMY_SPAN)),
             Map.entry("month", Token.keyword(TokenKind.MONTH, DUMMY_SPAN)),
             Map.entry("months", Token.keyword(TokenKind.MONTH, DUMMY_SPAN)),
+            Map.entry("daily", Token.keyword(TokenKind.DAILY, DUMMY_SPAN)),
+            Map.entry("weekly", Token.keyword(TokenKind.WEEKLY, DUMMY_SPAN)),
+            Map.entry("monthly", Token.keyword(TokenKind.MONTHLY, DUMMY_SPAN)),
+            Map.entry("yearly", Token.keyword(TokenKind.YEARLY, DUMMY_SPAN)),
+            Map.entry("hourly", Token.keyword(TokenKind.HOURLY, DUMMY_SPAN)),
+            Map.entry("morning", Token.keyword(TokenKind.MORNING, DUMMY_SPAN)),
+            Map.entry("afternoon", Token.keyword(TokenKind.AFTERNOON, DUMMY_SPAN)),
+            Map.entry("evening", Token.keyword(TokenKind.EVENING, DUMMY_SPAN)),
+            Map.entry("night", Token.keyword(TokenKind.NIGHT, DUMMY_SPAN)),
+            Map.entry("today", Token.keyword(TokenKind.TODAY, DUMMY_SPAN)),
+            Map.entry("now", Token.keyword(TokenKind.NOW, DUMMY_SPAN)),
             Map.entry("nearest", Token.keyword(TokenKind.NEAREST, DUMMY_SPAN)),
             Map.entry("next", Token.keyword(TokenKind.NEXT, DUMMY_SPAN)),
             Map.entry("previous", Token.keyword(TokenKind.PREVIOUS, DUMMY_SPAN)),
+            Map.entry("between", Token.keyword(TokenKind.BETWEEN, DUMMY_SPAN)),
+            Map.entry("and", Token.keyword(TokenKind.AND, DUMMY_SPAN)),
+            Map.entry("iso", Token.keyword(TokenKind.ISO, DUMMY_SPAN)),
 
             // Day names
             Map.entry("monday", Token.dayName(Weekday.MONDAY, DUMMY_SPAN)),
+            Map.entry("mondays", Token.dayName(Weekday.MONDAY, DUMMY_SPAN)),
             Map.entry("mon", Token.dayName(Weekday.MONDAY, DUMMY_SPAN)),
             Map.entry("tuesday", Token.dayName(Weekday.TUESDAY, DUMMY_SPAN)),
+            Map.entry("tuesdays", Token.dayName(Weekday.TUESDAY, DUMMY_SPAN)),
             Map.entry("tue", Token.dayName(Weekday.TUESDAY, DUMMY_SPAN)),
             Map.entry("wednesday", Token.dayName(Weekday.WEDNESDAY, DUMMY_SPAN)),
+            Map.entry("wednesdays", Token.dayName(Weekday.WEDNESDAY, DUMMY_SPAN)),
             Map.entry("wed", Token.dayName(Weekday.WEDNESDAY, DUMMY_SPAN)),
             Map.entry("thursday", Token.dayName(Weekday.THURSDAY, DUMMY_SPAN)),
+            Map.entry("thursdays", Token.dayName(Weekday.THURSDAY, DUMMY_SPAN)),
             Map.entry("thu", Token.dayName(Weekday.THURSDAY, DUMMY_SPAN)),
             Map.entry("friday", Token.dayName(Weekday.FRIDAY, DUMMY_SPAN)),
+            Map.entry("fridays", Token.dayName(Weekday.FRIDAY, DUMMY_SPAN)),
             Map.entry("fri", Token.dayName(Weekday.FRIDAY, DUMMY_SPAN)),
             Map.entry("saturday", Token.dayName(Weekday.SATURDAY, DUMMY_SPAN)),
+            Map.entry("saturdays", Token.dayName(Weekday.SATURDAY, DUMMY_SPAN)),
             Map.entry("sat", Token.dayName(Weekday.SATURDAY, DUMMY_SPAN)),
             Map.entry("sunday", Token.dayName(Weekday.SUNDAY, DUMMY_SPAN)),
+            Map.entry("sundays", Token.dayName(Weekday.SUNDAY, DUMMY_SPAN)),
             Map.entry("sun", Token.dayName(Weekday.SUNDAY, DUMMY_SPAN)),
 
             // Month names
@@ -279,13 +465,6 @@ public final class Lexer {
             Map.entry("december", Token.monthName(MonthName.DECEMBER, DUMMY_SPAN)),
             Map.entry("dec", Token.monthName(MonthName.DECEMBER, DUMMY_SPAN)),
 
-            // Ordinals
-            Map.entry("first", Token.ordinal(OrdinalPosition.FIRST, DUMMY_SPAN)),
-            Map.entry("second", Token.ordinal(OrdinalPosition.SECOND, DUMMY_SPAN)),
-            Map.entry("third", Token.ordinal(OrdinalPosition.THIRD, DUMMY_SPAN)),
-            Map.entry("fourth", Token.ordinal(OrdinalPosition.FOURTH, DUMMY_SPAN)),
-            Map.entry("fifth", Token.ordinal(OrdinalPosition.FIFTH, DUMMY_SPAN)),
-
             // Interval units
             Map.entry("min", Token.intervalUnit(IntervalUnit.MINUTES, DUMMY_SPAN)),
             Map.entry("mins", Token.intervalUnit(IntervalUnit.MINUTES, DUMMY_SPAN)),
@@ -15,11 +15,12 @@ import io.hron.ast.Weekday;
  * @param monthNameVal the month value (for MONTH_NAME tokens)
  * @param ordinalVal the ordinal value (for ORDINAL tokens)
  * @param unitVal the interval unit value (for INTERVAL_UNIT tokens)
- * @param numberVal the number value (for NUMBER and ORDINAL_NUMBER tokens)
+ * @param numberVal the number value (for NUMBER, ORDINAL_NUMBER, and ORDINAL tokens)
  * @param timeHour the hour (for TIME tokens)
- * @param timeMinute the minute (for TIME tokens)
+ * @param timeMinute the minute (for TIME and MINUTE_OFFSET tokens)
  * @param isoDateVal the ISO date string (for ISO_DATE tokens)
  * @param timezoneVal the timezone string (for TIMEZONE tokens)
+ * @param decimalVal the decimal literal text (for DECIMAL tokens)
  */
 public record Token(
     TokenKind kind,
@@ -32,7 +33,8 @@ public record Token(
     int timeHour,
     int timeMinute,
     String isoDateVal,
-    String timezoneVal) {
+    String timezoneVal,
+    String decimalVal) {
   /**
    * Creates a simple keyword token.
    *
@@ -41,7 +43,7 @@ public record Token(
    * @return a new keyword token
    */
   public static Token keyword(TokenKind kind, Span span) {
-    return new Token(kind, span, null, null, null, null, 0, 0, 0, null, null);
+    return new Token(kind, span, null, null, null, null, 0, 0, 0, null, null, null);
   }
 
   /**
@@ -52,7 +54,7 @@ public record Token(
    * @return a new day name token
    */
   public static Token dayName(Weekday day, Span span) {
-    return new Token(TokenKind.DAY_NAME, span, day, null, null, null, 0, 0, 0, null, null);
+    return new Token(TokenKind.DAY_NAME, span, day, null, null, null, 0, 0, 0, null, null, null);
   }
 
   /**
@@ -63,18 +65,20 @@ public record Token(
    * @return a new month name token
    */
   public static Token monthName(MonthName month, Span span) {
-    return new Token(TokenKind.MONTH_NAME, span, null, month, null, null, 0, 0, 0, null, null);
+    return new Token(
+        TokenKind.MONTH_NAME, span, null, month, null, null, 0, 0, 0, null, null, null);
   }
 
   /**
-   * Creates an ordinal token.
+   * Creates an ordinal word token ("first" through "thirty-first").
    *
-   * @param ord the ordinal position
+   * @param ord the ordinal position, or null past "fifth"
+   * @param day the day number the word names
    * @param span the source span
    * @return a new ordinal token
    */
-  public static Token ordinal(OrdinalPosition ord, Span span) {
-    return new Token(TokenKind.ORDINAL, span, null, null, ord, null, 0, 0, 0, null, null);
+  public static Token ordinal(OrdinalPosition ord, int day, Span span) {
+    return new Token(TokenKind.ORDINAL, span, null, null, ord, null, day, 0, 0, null, null, null);
   }
 
   /**
@@ -85,7 +89,8 @@ public record Token(
    * @return a new interval unit token
    */
   public static Token intervalUnit(IntervalUnit unit, Span span) {
-    return new Token(TokenKind.INTERVAL_UNIT, span, null, null, null, unit, 0, 0, 0, null, null);
+    return new Token(
+        TokenKind.INTERVAL_UNIT, span, null, null, null, unit, 0, 0, 0, null, null, null);
   }
 
   /**
@@ -96,7 +101,7 @@ public record Token(
    * @return a new number token
    */
   public static Token number(int value, Span span) {
-    return new Token(TokenKind.NUMBER, span, null, null, null, null, value, 0, 0, null, null);
+    return new Token(TokenKind.NUMBER, span, null, null, null, null, value, 0, 0, null, null, null);
   }
 
   /**
@@ -108,7 +113,7 @@ public record Token(
    */
   public static Token ordinalNumber(int value, Span span) {
     return new Token(
-        TokenKind.ORDINAL_NUMBER, span, null, null, null, null, value, 0, 0, null, null);
+        TokenKind.ORDINAL_NUMBER, span, null, null, null, null, value, 0, 0, null, null, null);
   }
 
   /**
@@ -120,7 +125,8 @@ public record Token(
    * @return a new time token
    */
   public static Token time(int hour, int minute, Span span) {
-    return new Token(TokenKind.TIME, span, null, null, null, null, 0, hour, minute, null, null);
+    return new Token(
+        TokenKind.TIME, span, null, null, null, null, 0, hour, minute, null, null, null);
   }
 
   /**
@@ -131,7 +137,7 @@ public record Token(
    * @return a new ISO date token
    */
   public static Token isoDate(String date, Span span) {
-    return new Token(TokenKind.ISO_DATE, span, null, null, null, null, 0, 0, 0, date, null);
+    return new Token(TokenKind.ISO_DATE, span, null, null, null, null, 0, 0, 0, date, null, null);
   }
 
   /**
@@ -141,7 +147,19 @@ public record Token(
    * @return a new comma token
    */
   public static Token comma(Span span) {
-    return new Token(TokenKind.COMMA, span, null, null, null, null, 0, 0, 0, null, null);
+    return new Token(TokenKind.COMMA, span, null, null, null, null, 0, 0, 0, null, null, null);
+  }
+
+  /**
+   * Creates a minutes-past-the-hour token.
+   *
+   * @param minute the minute (0-59)
+   * @param span the source span
+   * @return a new minute offset token
+   */
+  public static Token minuteOffset(int minute, Span span) {
+    return new Token(
+        TokenKind.MINUTE_OFFSET, span, null, null, null, null, 0, 0, minute, null, null, null);
   }
 
   /**
@@ -152,6 +170,17 @@ public record Token(
    * @return a new timezone token
    */
   public static Token timezone(String tz, Span span) {
-    return new Token(TokenKind.TIMEZONE, span, null, null, null, null, 0, 0, 0, null, tz);
+    return new Token(TokenKind.TIMEZONE, span, null, null, null, null, 0, 0, 0, null, tz, null);
+  }
+
+  /**
+   * Creates a decimal token.
+   *
+   * @param text the decimal literal as written (e.g., "1.5")
+   * @param span the source span
+   * @return a new decimal token
+   */
+  public static Token decimal(String text, Span span) {
+    return new Token(TokenKind.DECIMAL, span, null, null, null, null, 0, 0, 0, null, null, text);
   }
 }
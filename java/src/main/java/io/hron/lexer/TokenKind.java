@@ -13,6 +13,12 @@ public enum TokenKind {
   FROM,
   /** The "to" keyword. */
   TO,
+  /** The "through" keyword, a synonym of "to" in ranges. */
+  THROUGH,
+  /** The "business" keyword. */
+  BUSINESS,
+  /** The "past" keyword. */
+  PAST,
   /** The "in" keyword. */
   IN,
   /** The "of" keyword. */
@@ -47,6 +53,36 @@ public enum TokenKind {
   NEXT,
   /** The "previous" keyword. */
   PREVIOUS,
+  /** The "between" keyword. */
+  BETWEEN,
+  /** The "and" keyword. */
+  AND,
+  /** The "iso" keyword. */
+  ISO,
+  /** The "daily" frequency keyword. */
+  DAILY,
+  /** The "weekly" frequency keyword. */
+  WEEKLY,
+  /** The "monthly" frequency keyword. */
+  MONTHLY,
+  /** The "yearly" frequency keyword. */
+  YEARLY,
+  /** The "hourly" frequency keyword. */
+  HOURLY,
+  /** A cron shortcut (e.g., "@daily"); the name is the token's source text. */
+  SHORTCUT,
+  /** The "morning" time-of-day keyword. */
+  MORNING,
+  /** The "afternoon" time-of-day keyword. */
+  AFTERNOON,
+  /** The "evening" time-of-day keyword. */
+  EVENING,
+  /** The "night" time-of-day keyword. */
+  NIGHT,
+  /** The "today" relative anchor keyword. */
+  TODAY,
+  /** The "now" relative anchor keyword. */
+  NOW,
 
   // Value-carrying tokens
   /** A day-of-week name (e.g., "monday"). */
@@ -59,10 +95,14 @@ public enum TokenKind {
   INTERVAL_UNIT,
   /** A numeric literal. */
   NUMBER,
+  /** A decimal literal (e.g., "1.5"). */
+  DECIMAL,
   /** An ordinal number (e.g., "1st", "15th"). */
   ORDINAL_NUMBER,
   /** A time literal (e.g., "09:00"). */
   TIME,
+  /** Minutes past the hour (e.g., ":15"). */
+  MINUTE_OFFSET,
   /** An ISO date (e.g., "2024-01-15"). */
   ISO_DATE,
   /** A comma separator. */
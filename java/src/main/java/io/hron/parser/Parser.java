@@ -3,13 +3,19 @@ package io.hron.parser;
 import io.hron.HronException;
 import io.hron.Span;
 import io.hron.ast.*;
+import io.hron.cron.CronConverter;
 import io.hron.lexer.Lexer;
 import io.hron.lexer.Token;
 import io.hron.lexer.TokenKind;
 import java.time.LocalDate;
 import java.time.format.DateTimeParseException;
 import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.Comparator;
+import java.util.EnumSet;
 import java.util.List;
+import java.util.Set;
+import java.util.function.ToIntFunction;
 
 /** Recursive descent parser for hron expressions. */
 public final class Parser {
@@ -50,7 +56,9 @@ public final class Parser {
     List<ExceptionSpec> except = List.of();
     UntilSpec until = null;
     String anchor = null;
-    List<MonthName> during = List.of();
+    DateSpec anchorNamed = null;
+    TimeOfDay anchorTime = null;
+    List<DuringSpec> during = List.of();
     String timezone = null;
 
     while (pos < tokens.size()) {
@@ -63,7 +71,7 @@ public final class Parser {
           if (until != null) {
             throw parseError("wrong clause order: until before except", tok.span());
           }
-          if (anchor != null) {
+          if (anchor != null || anchorNamed != null) {
             throw parseError("wrong clause order: starting before except", tok.span());
           }
           if (!during.isEmpty()) {
@@ -79,7 +87,7 @@ public final class Parser {
           if (until != null) {
             throw parseError("duplicate until clause", tok.span());
           }
-          if (anchor != null) {
+          if (anchor != null || anchorNamed != null) {
             throw parseError("wrong clause order: starting before until", tok.span());
           }
           if (!during.isEmpty()) {
@@ -92,7 +100,7 @@ public final class Parser {
           until = parseUntil();
         }
         case STARTING -> {
-          if (anchor != null) {
+          if (anchor != null || anchorNamed != null) {
             throw parseError("duplicate starting clause", tok.span());
           }
           if (!during.isEmpty()) {
@@ -102,7 +110,21 @@ public final class Parser {
             throw parseError("wrong clause order: in before starting", tok.span());
           }
           pos++;
-          anchor = parseStarting();
+          if (check(TokenKind.MONTH_NAME)) {
+            Token monthTok = expect(TokenKind.MONTH_NAME);
+            Token dayTok = parseDayNumber();
+            validateNamedDate(monthTok.monthNameVal(), dayTok.numberVal(), dayTok.span());
+            anchorNamed = DateSpec.named(monthTok.monthNameVal(), dayTok.numberVal());
+          } else {
+            anchor = parseStarting();
+          }
+          if (check(TokenKind.AT)) {
+            if ("now".equals(anchor)) {
+              throw parseError("'starting now' already includes a time", peek().span());
+            }
+            pos++;
+            anchorTime = parseTime();
+          }
         }
         case DURING -> {
           if (!during.isEmpty()) {
@@ -122,7 +144,74 @@ public final class Parser {
       }
     }
 
-    return new ScheduleData(expr, timezone, except, until, anchor, during);
+    ScheduleData data =
+        new ScheduleData(expr, timezone, except, until, anchor, anchorNamed, anchorTime, during);
+    checkDayStepReachable(data);
+    return data;
+  }
+
+  /**
+   * Rejects a day step whose filter excludes every aligned day. A step that is a multiple of 7
+   * lands on the anchor's weekday every time, so a filter without that weekday never fires. With a
+   * relative anchor ({@code starting today}, {@code starting now}, or a month-day) that weekday
+   * depends on the evaluation date, so such a step needs a filter that lets every day through.
+   */
+  private void checkDayStepReachable(ScheduleData data) throws HronException {
+    int interval;
+    DayFilter filter;
+    switch (data.expr()) {
+      case DayRepeat dr -> {
+        interval = interval;
+        filter = filter;
+      }
+      case IntervalRepeat ir when ir.unit() == IntervalUnit.DAYS && ir.dayFilter() != null -> {
+        interval = ir.interval();
+        filter = ir.dayFilter();
+      }
+      default -> {
+        return;
+      }
+    }
+    if (interval % 7 != 0) {
+      return;
+    }
+    String anchor = data.anchor();
+    if (data.hasRelativeAnchor()) {
+      boolean everyDay =
+          switch (filter.kind()) {
+            case EVERY -> true;
+            case WEEKDAY, WEEKEND -> false;
+            case DAYS -> filter.days().stream().distinct().count() == 7;
+          };
+      if (!everyDay) {
+        throw parseError(
+            "every "
+                + interval
+                + " days starting "
+                + data.anchorText()
+                + " lands on the weekday of the evaluation date, which the day filter may exclude",
+            new Span(0, input.length()));
+      }
+      return;
+    }
+    LocalDate anchorDate = anchor != null ? LocalDate.parse(anchor) : LocalDate.of(1970, 1, 1);
+    Weekday weekday = Weekday.fromDayOfWeek(anchorDate.getDayOfWeek());
+    boolean reachable =
+        switch (filter.kind()) {
+          case EVERY -> true;
+          case WEEKDAY -> weekday.number() <= 5;
+          case WEEKEND -> weekday.number() > 5;
+          case DAYS -> filter.days().contains(weekday);
+        };
+    if (!reachable) {
+      throw parseError(
+          "every "
+              + interval
+              + " days always lands on a "
+              + weekday
+              + ", which the day filter excludes",
+          new Span(0, input.length()));
+    }
   }
 
   private ScheduleExpr parseExpr() throws HronException {
@@ -134,10 +223,74 @@ public final class Parser {
     return switch (tok.kind()) {
       case EVERY -> parseEveryExpr();
       case ON -> parseSingleDate();
-      default -> throw parseError("expected 'every' or 'on'", tok.span());
+      case DAILY, WEEKLY, MONTHLY, YEARLY, HOURLY -> parseFrequency();
+      case SHORTCUT -> parseShortcut();
+      case AT -> {
+        pos++;
+        yield parsePastEveryHour();
+      }
+      default ->
+          throw parseError("expected 'every', 'on', 'at', or a frequency keyword", tok.span());
+    };
+  }
+
+  /**
+   * Cron @ shortcut: "@daily" is "every day at 00:00", and takes the same trailing clauses as that
+   * form ("@daily in UTC").
+   */
+  private ScheduleExpr parseShortcut() throws HronException {
+    Token tok = tokens.get(pos++);
+    String name = input.substring(tok.span().start(), tok.span().end()).toLowerCase();
+    return CronConverter.cronShortcutExpr(name)
+        .orElseThrow(
+            () ->
+                HronException.parse(
+                    "unknown @ shortcut: " + name,
+                    tok.span(),
+                    input,
+                    "@yearly, @monthly, @weekly, @daily, @midnight, or @hourly"));
+  }
+
+  /**
+   * Bare shorthand: "daily", "weekly", "monthly", "yearly", or "hourly". Each maps to the canonical
+   * "every ..." form with interval 1 and accepts what that form does. Without "on", weekly falls on
+   * monday (the default week start), monthly on the 1st, and yearly on jan 1; without a window,
+   * hourly runs all day.
+   */
+  private ScheduleExpr parseFrequency() throws HronException {
+    Token tok = tokens.get(pos++);
+
+    boolean hasOn = check(TokenKind.ON);
+    return switch (tok.kind()) {
+      case DAILY -> new DayRepeat(1, DayFilter.every(), parseTimesOrMidnight());
+      case WEEKLY ->
+          hasOn
+              ? parseWeekRepeat()
+              : new WeekRepeat(1, List.of(Weekday.MONDAY), parseTimesOrMidnight());
+      case MONTHLY ->
+          hasOn
+              ? parseMonthRepeat()
+              : new MonthRepeat(
+                  1, MonthTarget.days(List.of(DayOfMonthSpec.single(1))), parseTimesOrMidnight());
+      // "yearly in iso weeks ..." as well as "yearly on ..."
+      case YEARLY ->
+          hasOn || (check(TokenKind.IN) && nextIs(TokenKind.ISO))
+              ? parseYearRepeat(1)
+              : new YearRepeat(1, YearTarget.date(MonthName.JANUARY, 1), parseTimesOrMidnight());
+      default -> {
+        // "hourly at :15" and "hourly from 09:00 to 17:00" as after "every hour"
+        if (check(TokenKind.AT) || check(TokenKind.FROM) || check(TokenKind.BETWEEN)) {
+          yield parseIntervalWindow(1, IntervalUnit.HOURS);
+        }
+        yield hourlyAt(1, 0, parseOptionalDayFilter());
+      }
     };
   }
 
+  private boolean nextIs(TokenKind kind) {
+    return pos + 1 < tokens.size() && tokens.get(pos + 1).kind() == kind;
+  }
+
   private ScheduleExpr parseEveryExpr() throws HronException {
     expect(TokenKind.EVERY);
 
@@ -148,10 +301,23 @@ public final class Parser {
 
     return switch (next.kind()) {
       case NUMBER -> parseEveryNumber();
+      // "every hour at :15", "every minute from 09:00 to 10:00"
+      case INTERVAL_UNIT -> parseIntervalRepeat(1);
+      // "every 1.5 hours from ..."
+      case DECIMAL -> parseFractionalInterval();
       case DAY, WEEKDAY, WEEKEND, DAY_NAME -> parseDayRepeat();
-      case WEEKS -> parseWeekRepeat();
-      case YEAR -> parseYearRepeat();
-      case MONTH -> parseMonthRepeat();
+      case WEEKS -> {
+        pos++;
+        yield parseWeekRepeat();
+      }
+      case YEAR -> {
+        pos++;
+        yield parseYearRepeat(1);
+      }
+      case MONTH -> {
+        pos++;
+        yield parseMonthRepeat();
+      }
       default -> throw parseError("unexpected token after 'every'", next.span());
     };
   }
@@ -171,18 +337,36 @@ public final class Parser {
 
     return switch (next.kind()) {
       case INTERVAL_UNIT -> parseIntervalRepeat(interval);
+      case DAY when nextIsWindow() -> {
+        // "every N days from HH:MM to HH:MM" - day-step interval window
+        pos++;
+        yield parseIntervalWindow(interval, IntervalUnit.DAYS);
+      }
       case DAY -> {
+        // "every N days [on day_target] at ..."
         pos++;
-        // Normalize "every 1 day" -> "every day"
-        var days = interval == 1 ? DayFilter.every() : null;
-        var times = parseAtTimes();
-        yield new DayRepeat(interval, days != null ? days : DayFilter.every(), times);
+        DayFilter days = DayFilter.every();
+        if (check(TokenKind.ON)) {
+          pos++;
+          days = parseDayFilter();
+        }
+        var times = parseTimesOrMidnight();
+        yield new DayRepeat(interval, days, times);
+      }
+      case WEEKDAY -> {
+        // "every N weekdays at ..." - every Nth business day
+        pos++;
+        var times = parseTimesOrMidnight();
+        if (interval == 1) {
+          yield new DayRepeat(1, DayFilter.weekday(), times);
+        }
+        yield new WeekdayRepeat(interval, times);
       }
       case WEEKS -> {
         pos++;
         expect(TokenKind.ON);
         var weekDays = parseDayList();
-        var times = parseAtTimes();
+        var times = parseTimesOrMidnight();
         yield new WeekRepeat(interval, weekDays, times);
       }
       case MONTH -> {
@@ -190,28 +374,49 @@ public final class Parser {
         expect(TokenKind.ON);
         expect(TokenKind.THE);
         var target = parseMonthTarget();
-        var times = parseAtTimes();
+        var times = parseTimes();
         yield new MonthRepeat(interval, target, times);
       }
       case YEAR -> {
         pos++;
-        expect(TokenKind.ON);
-        var target = parseYearTarget();
-        var times = parseAtTimes();
-        yield new YearRepeat(interval, target, times);
+        yield parseYearRepeat(interval);
       }
       default ->
           throw parseError(
-              "expected unit (min/hours/day/weeks/month/year) after number", next.span());
+              "expected unit (min/hours/day/weekday/weeks/month/year) after number", next.span());
     };
   }
 
   private ScheduleExpr parseDayRepeat() throws HronException {
+    if (check(TokenKind.DAY_NAME)) {
+      List<Weekday> dayList = parseDayList();
+      // "every monday every 2 weeks at ..." is a week repeat
+      if (check(TokenKind.EVERY)) {
+        pos++;
+        int interval = parseWeekInterval();
+        return new WeekRepeat(interval, dayList, parseTimesOrMidnight());
+      }
+      return new DayRepeat(1, DayFilter.days(dayList).canonical(), parseTimesOrMidnight());
+    }
     DayFilter days = parseDayFilter();
-    List<TimeOfDay> times = parseAtTimes();
+    List<TimeOfDay> times = parseTimesOrMidnight();
     return new DayRepeat(1, days, times);
   }
 
+  // "week" or "N weeks", after a day list and a second "every"
+  private int parseWeekInterval() throws HronException {
+    int interval = 1;
+    if (check(TokenKind.NUMBER)) {
+      Token numTok = tokens.get(pos++);
+      if (numTok.numberVal() == 0) {
+        throw parseError("zero interval", numTok.span());
+      }
+      interval = numTok.numberVal();
+    }
+    expect(TokenKind.WEEKS);
+    return interval;
+  }
+
   private DayFilter parseDayFilter() throws HronException {
     Token tok = peek();
     if (tok == null) {
@@ -231,42 +436,177 @@ public final class Parser {
         pos++;
         yield DayFilter.weekend();
       }
-      case DAY_NAME -> DayFilter.days(parseDayList());
+      case DAY_NAME -> DayFilter.days(parseDayList()).canonical();
       default -> throw parseError("expected day filter", tok.span());
     };
   }
 
   private List<Weekday> parseDayList() throws HronException {
-    List<Weekday> days = new ArrayList<>();
-
-    Token tok = expect(TokenKind.DAY_NAME);
-    days.add(tok.dayNameVal());
+    List<Weekday> days = new ArrayList<>(parseDayRange());
 
     while (check(TokenKind.COMMA)) {
       pos++;
-      tok = expect(TokenKind.DAY_NAME);
-      days.add(tok.dayNameVal());
+      days.addAll(parseDayRange());
     }
 
     return days;
   }
 
+  /** Parses a day name or a range of them: "monday to friday", "fri through mon" (wraps). */
+  private List<Weekday> parseDayRange() throws HronException {
+    Weekday start = expect(TokenKind.DAY_NAME).dayNameVal();
+    if (!atRangeSeparator()) {
+      return List.of(start);
+    }
+    pos++;
+    Weekday end = expect(TokenKind.DAY_NAME).dayNameVal();
+    int length = Math.floorMod(end.number() - start.number(), 7) + 1;
+    List<Weekday> days = new ArrayList<>();
+    for (int i = 0; i < length; i++) {
+      days.add(Weekday.values()[(start.number() - 1 + i) % 7]);
+    }
+    return days;
+  }
+
+  /**
+   * Reports whether the next token separates a range: "to", or its synonym "through". "through" is
+   * only accepted in ranges, never in "from ... to ..." windows.
+   */
+  private boolean atRangeSeparator() {
+    return check(TokenKind.TO) || check(TokenKind.THROUGH);
+  }
+
   private ScheduleExpr parseIntervalRepeat(int interval) throws HronException {
     Token unitTok = expect(TokenKind.INTERVAL_UNIT);
-    IntervalUnit unit = unitTok.unitVal();
+    return parseIntervalWindow(interval, unitTok.unitVal());
+  }
 
-    expect(TokenKind.FROM);
+  /**
+   * "every D hours ..." where D is a decimal: converted to whole minutes, so "every 1.5 hours" is
+   * the same schedule as "every 90 min".
+   */
+  private ScheduleExpr parseFractionalInterval() throws HronException {
+    Token tok = tokens.get(pos++);
+    Token unitTok = peek();
+    if (unitTok == null
+        || unitTok.kind() != TokenKind.INTERVAL_UNIT
+        || unitTok.unitVal() == IntervalUnit.MINUTES) {
+      throw parseError("fractional intervals are only supported in hours", tok.span());
+    }
+    pos++;
+
+    int minutes = fractionalHoursToMinutes(tok.decimalVal());
+    if (minutes < 0) {
+      throw parseError("fractional hours must come to a whole number of minutes", tok.span());
+    }
+    if (minutes == 0) {
+      throw parseError("interval must be at least 1", tok.span());
+    }
+
+    if (minutes % 60 == 0) {
+      return parseIntervalWindow(minutes / 60, IntervalUnit.HOURS);
+    }
+    return parseIntervalWindow(minutes, IntervalUnit.MINUTES);
+  }
+
+  /**
+   * Converts decimal hours (1.5) to whole minutes, or returns -1 if they don't come to a whole
+   * number of minutes. A minute is 1/60 hour, so past two decimal places (ignoring trailing zeros)
+   * the hours never land on one.
+   */
+  private static int fractionalHoursToMinutes(String text) {
+    int dot = text.indexOf('.');
+    String whole = text.substring(0, dot);
+    String frac = text.substring(dot + 1).replaceAll("0+$", "");
+    if (frac.length() > 2) {
+      return -1;
+    }
+    long hundredths;
+    try {
+      hundredths = Long.parseLong(whole + (frac + "00").substring(0, 2));
+    } catch (NumberFormatException e) {
+      return -1;
+    }
+    if (hundredths * 60 % 100 != 0) {
+      return -1;
+    }
+    return (int) (hundredths * 60 / 100);
+  }
+
+  // Parses the window and optional day filter after the interval unit.
+  private ScheduleExpr parseIntervalWindow(int interval, IntervalUnit unit) throws HronException {
+    // "every 2 hours at :15": all day, starting MM past midnight
+    if (unit == IntervalUnit.HOURS && check(TokenKind.AT)) {
+      pos++;
+      int minute = parseMinutePast(false);
+      return hourlyAt(interval, minute, parseOptionalDayFilter());
+    }
+
+    // "from HH:MM to HH:MM" or "between HH:MM and HH:MM"
+    TokenKind closing = TokenKind.TO;
+    if (check(TokenKind.BETWEEN)) {
+      pos++;
+      closing = TokenKind.AND;
+    } else {
+      expect(TokenKind.FROM);
+    }
     TimeOfDay fromTime = parseTime();
-    expect(TokenKind.TO);
+    expect(closing);
     TimeOfDay toTime = parseTime();
 
-    DayFilter dayFilter = null;
-    if (check(TokenKind.ON)) {
-      pos++;
-      dayFilter = parseDayFilterForInterval();
+    return new IntervalRepeat(interval, unit, fromTime, toTime, parseOptionalDayFilter());
+  }
+
+  // Optional "on day_target" after an interval window
+  private DayFilter parseOptionalDayFilter() throws HronException {
+    if (!check(TokenKind.ON)) {
+      return null;
     }
+    pos++;
+    return parseDayFilterForInterval();
+  }
 
-    return new IntervalRepeat(interval, unit, fromTime, toTime, dayFilter);
+  // "at 15 past every hour", "at :15 past every hour": "at" already consumed
+  private ScheduleExpr parsePastEveryHour() throws HronException {
+    int minute = parseMinutePast(true);
+    expect(TokenKind.PAST);
+    expect(TokenKind.EVERY);
+    Token tok = peek();
+    if (tok == null
+        || tok.kind() != TokenKind.INTERVAL_UNIT
+        || tok.unitVal() != IntervalUnit.HOURS) {
+      throw parseError("expected 'hour'", tok != null ? tok.span() : endSpan());
+    }
+    pos++;
+    return hourlyAt(1, minute, parseOptionalDayFilter());
+  }
+
+  // Minutes past the hour, as ":MM" or (with allowNumber) a bare number
+  private int parseMinutePast(boolean allowNumber) throws HronException {
+    Token tok = peek();
+    int minute;
+    if (tok != null && tok.kind() == TokenKind.MINUTE_OFFSET) {
+      minute = tok.timeMinute();
+    } else if (allowNumber
+        && tok != null
+        && tok.kind() == TokenKind.NUMBER
+        && tok.numberVal() <= 59) {
+      minute = tok.numberVal();
+    } else {
+      throw parseError(
+          "expected minutes past the hour (e.g. :15)", tok != null ? tok.span() : endSpan());
+    }
+    pos++;
+    return minute;
+  }
+
+  /**
+   * Builds an all-day hour step firing minute past the hour: "every hour at :15" is "every 1 hour
+   * from 00:15 to 23:59".
+   */
+  private static IntervalRepeat hourlyAt(int interval, int minute, DayFilter dayFilter) {
+    return new IntervalRepeat(
+        interval, IntervalUnit.HOURS, new TimeOfDay(0, minute), new TimeOfDay(23, 59), dayFilter);
   }
 
   private DayFilter parseDayFilterForInterval() throws HronException {
@@ -288,28 +628,26 @@ public final class Parser {
         pos++;
         yield DayFilter.weekend();
       }
-      case DAY_NAME -> DayFilter.days(parseDayList());
+      case DAY_NAME -> DayFilter.days(parseDayList()).canonical();
       default -> throw parseError("expected day filter after 'on'", tok.span());
     };
   }
 
   private ScheduleExpr parseWeekRepeat() throws HronException {
-    expect(TokenKind.WEEKS);
     expect(TokenKind.ON);
 
     var weekDays = parseDayList();
-    var times = parseAtTimes();
+    var times = parseTimesOrMidnight();
 
     return new WeekRepeat(1, weekDays, times);
   }
 
   private ScheduleExpr parseMonthRepeat() throws HronException {
-    expect(TokenKind.MONTH);
     expect(TokenKind.ON);
     expect(TokenKind.THE);
 
     MonthTarget target = parseMonthTarget();
-    List<TimeOfDay> times = parseAtTimes();
+    List<TimeOfDay> times = parseTimes();
 
     return new MonthRepeat(1, target, times);
   }
@@ -320,32 +658,40 @@ public final class Parser {
       throw parseError("unexpected end of input", endSpan());
     }
 
+    // "the first monday", "the first and last friday", as opposed to "the first" (day 1)
+    if ((tok.kind() == TokenKind.ORDINAL || tok.kind() == TokenKind.LAST)
+        && ordinalWeekdaysAhead()) {
+      return parseOrdinalWeekdays();
+    }
+
     if (tok.kind() == TokenKind.LAST) {
       pos++;
       Token next = peek();
       if (next != null && next.kind() == TokenKind.DAY) {
         pos++;
         return MonthTarget.lastDay();
+      } else if (next != null && next.kind() == TokenKind.NUMBER) {
+        // "last N days"; "last 1 day" is the last day
+        int n = next.numberVal();
+        if (n < 1 || n > 31) {
+          throw parseError("invalid day count: " + n + " (must be 1-31)", next.span());
+        }
+        pos++;
+        expect(TokenKind.DAY);
+        return n == 1 ? MonthTarget.lastDay() : MonthTarget.lastNDays(n);
       } else if (next != null && next.kind() == TokenKind.WEEKDAY) {
         pos++;
         return MonthTarget.lastWeekday();
-      } else if (next != null && next.kind() == TokenKind.DAY_NAME) {
-        Weekday weekday = next.dayNameVal();
-        pos++;
-        return MonthTarget.ordinalWeekday(OrdinalPosition.LAST, weekday);
       }
       throw parseError(
           "expected 'day', 'weekday', or day name after 'last'",
           next != null ? next.span() : endSpan());
     }
 
-    // Check for ordinal + day_name (e.g., "first monday")
-    if (tok.kind() == TokenKind.ORDINAL) {
-      OrdinalPosition ordinal = tok.ordinalVal();
-      pos++;
-      Token dayTok = expect(TokenKind.DAY_NAME);
-      Weekday weekday = dayTok.dayNameVal();
-      return MonthTarget.ordinalWeekday(ordinal, weekday);
+    // "the first weekday", "the 3rd business day"
+    if ((tok.kind() == TokenKind.ORDINAL || tok.kind() == TokenKind.ORDINAL_NUMBER)
+        && nextIsBusinessDay()) {
+      return parseBusinessDayTarget();
     }
 
     // Check for [next|previous] nearest weekday to <day>
@@ -360,6 +706,69 @@ public final class Parser {
     return MonthTarget.days(specs);
   }
 
+  // Reports whether a list of ordinals joined by "," or "and" ends in a day name.
+  private boolean ordinalWeekdaysAhead() {
+    for (int i = pos; i < tokens.size(); i += 2) {
+      TokenKind kind = tokens.get(i).kind();
+      if (kind != TokenKind.ORDINAL && kind != TokenKind.LAST) {
+        return false;
+      }
+      if (i + 1 >= tokens.size()) {
+        return false;
+      }
+      TokenKind next = tokens.get(i + 1).kind();
+      if (next == TokenKind.DAY_NAME) {
+        return true;
+      }
+      if (next != TokenKind.COMMA && next != TokenKind.AND) {
+        return false;
+      }
+    }
+    return false;
+  }
+
+  // <ordinal> [(, | and) <ordinal>]... <day_name>
+  private MonthTarget parseOrdinalWeekdays() throws HronException {
+    Set<OrdinalPosition> seen = EnumSet.noneOf(OrdinalPosition.class);
+    seen.add(parseOrdinalOrLast());
+    while (check(TokenKind.COMMA) || check(TokenKind.AND)) {
+      pos++;
+      seen.add(parseOrdinalOrLast());
+    }
+    // ordinalWeekdaysAhead guarantees a day name here
+    Weekday weekday = tokens.get(pos++).dayNameVal();
+    List<OrdinalPosition> ordinals = List.copyOf(seen);
+    if (ordinals.size() == 1) {
+      return MonthTarget.ordinalWeekday(ordinals.get(0), weekday);
+    }
+    return MonthTarget.ordinalWeekdays(ordinals, weekday);
+  }
+
+  private OrdinalPosition parseOrdinalOrLast() throws HronException {
+    if (check(TokenKind.LAST)) {
+      pos++;
+      return OrdinalPosition.LAST;
+    }
+    return parseOrdinalPosition();
+  }
+
+  // <ordinal> (weekday | business day)
+  private MonthTarget parseBusinessDayTarget() throws HronException {
+    Span span = peek().span();
+    int n = parseOrdinalDay("expected ordinal before 'business day'");
+    // No month has more than 23 weekdays
+    if (n > 23) {
+      throw parseError("invalid business day: " + n + " (must be 1-23)", span);
+    }
+    if (check(TokenKind.BUSINESS)) {
+      pos++;
+      expect(TokenKind.DAY);
+    } else {
+      expect(TokenKind.WEEKDAY);
+    }
+    return MonthTarget.businessDay(n);
+  }
+
   /**
    * Parses [next|previous] nearest weekday to <ordinal_day>.
    *
@@ -382,15 +791,11 @@ public final class Parser {
     expect(TokenKind.WEEKDAY);
     expect(TokenKind.TO);
 
-    Token dayTok = expect(TokenKind.ORDINAL_NUMBER);
-    int day = dayTok.numberVal();
-    if (day < 1 || day > 31) {
-      throw parseError("invalid day number " + day + " (must be 1-31)", dayTok.span());
-    }
-
+    int day = parseOrdinalDay("expected ordinal day number");
     return MonthTarget.nearestWeekday(day, direction);
   }
 
+  // Day specs are sorted and de-duplicated: "the 15th, 1st, 15th" is "the 1st, 15th"
   private List<DayOfMonthSpec> parseDayOfMonthSpecs() throws HronException {
     List<DayOfMonthSpec> specs = new ArrayList<>();
     specs.add(parseDayOfMonthSpec());
@@ -400,24 +805,24 @@ public final class Parser {
       specs.add(parseDayOfMonthSpec());
     }
 
-    return specs;
+    return sortedUnique(specs, Parser::daySpecKey);
   }
 
-  private DayOfMonthSpec parseDayOfMonthSpec() throws HronException {
-    Token tok = expect(TokenKind.ORDINAL_NUMBER);
-    int start = tok.numberVal();
+  /** Ordering key for a day spec: by first day, then last day. */
+  private static int daySpecKey(DayOfMonthSpec spec) {
+    return spec.kind() == DayOfMonthSpec.Kind.SINGLE
+        ? spec.day() * 100 + spec.day()
+        : spec.start() * 100 + spec.end();
+  }
 
-    if (start < 1 || start > 31) {
-      throw parseError("invalid day number " + start + " (must be 1-31)", tok.span());
-    }
+  private DayOfMonthSpec parseDayOfMonthSpec() throws HronException {
+    Token tok = peek();
+    int start = parseOrdinalDay("expected ordinal day number");
 
-    if (check(TokenKind.TO)) {
+    // "1st to 15th", "1st through 15th"
+    if (atRangeSeparator()) {
       pos++;
-      Token endTok = expect(TokenKind.ORDINAL_NUMBER);
-      int end = endTok.numberVal();
-      if (end < 1 || end > 31) {
-        throw parseError("invalid day number " + end + " (must be 1-31)", endTok.span());
-      }
+      int end = parseOrdinalDay("expected ordinal day number after 'to'");
       if (start > end) {
         throw parseError(
             "invalid day range: " + start + " to " + end + " (start must be <= end)", tok.span());
@@ -428,14 +833,101 @@ public final class Parser {
     return DayOfMonthSpec.single(start);
   }
 
-  private ScheduleExpr parseYearRepeat() throws HronException {
-    expect(TokenKind.YEAR);
+  // Parses an ordinal day of month, as digits (21st) or a word (twentieth).
+  private int parseOrdinalDay(String expected) throws HronException {
+    Token tok = peek();
+    if (tok == null
+        || (tok.kind() != TokenKind.ORDINAL_NUMBER && tok.kind() != TokenKind.ORDINAL)) {
+      throw parseError(expected, tok != null ? tok.span() : endSpan());
+    }
+    int day = tok.numberVal();
+    if (day < 1 || day > 31) {
+      throw parseError("invalid day number " + day + " (must be 1-31)", tok.span());
+    }
+    pos++;
+    return day;
+  }
+
+  // Parses an ordinal position word (first through fifth).
+  private OrdinalPosition parseOrdinalPosition() throws HronException {
+    Token tok = tokens.get(pos);
+    if (tok.ordinalVal() == null) {
+      throw parseError(
+          "unknown ordinal '" + input.substring(tok.span().start(), tok.span().end()) + "'",
+          tok.span());
+    }
+    pos++;
+    return tok.ordinalVal();
+  }
+
+  // Reports whether the token after the current one starts "business day" or is "weekday".
+  private boolean nextIsBusinessDay() {
+    if (pos + 1 >= tokens.size()) {
+      return false;
+    }
+    TokenKind next = tokens.get(pos + 1).kind();
+    return next == TokenKind.BUSINESS || next == TokenKind.WEEKDAY;
+  }
+
+  // Reports whether the token after the current one is a day name.
+  private boolean nextIsDayName() {
+    return pos + 1 < tokens.size() && tokens.get(pos + 1).kind() == TokenKind.DAY_NAME;
+  }
+
+  // Reports whether the token after the current one starts an interval window.
+  private boolean nextIsWindow() {
+    if (pos + 1 >= tokens.size()) {
+      return false;
+    }
+    TokenKind next = tokens.get(pos + 1).kind();
+    return next == TokenKind.FROM || next == TokenKind.BETWEEN;
+  }
+
+  private ScheduleExpr parseYearRepeat(int interval) throws HronException {
+    if (check(TokenKind.IN)) {
+      return parseIsoWeekRepeat(interval);
+    }
     expect(TokenKind.ON);
 
     YearTarget target = parseYearTarget();
-    List<TimeOfDay> times = parseAtTimes();
+    List<TimeOfDay> times = parseTimes();
 
-    return new YearRepeat(1, target, times);
+    return new YearRepeat(interval, target, times);
+  }
+
+  // iso_week_repeat: "every year in iso week[s] N[, N] on day_list [at HH:MM]"
+  private ScheduleExpr parseIsoWeekRepeat(int interval) throws HronException {
+    if (interval != 1) {
+      throw parseError("ISO week schedules repeat every year; remove the interval", peek().span());
+    }
+    expect(TokenKind.IN);
+    expect(TokenKind.ISO);
+    expect(TokenKind.WEEKS);
+
+    List<Integer> weeks = new ArrayList<>();
+    weeks.add(parseIsoWeekNumber());
+    while (check(TokenKind.COMMA)) {
+      pos++;
+      weeks.add(parseIsoWeekNumber());
+    }
+
+    expect(TokenKind.ON);
+    List<Weekday> days = parseDayList();
+    List<TimeOfDay> times = parseTimesOrMidnight();
+    return new IsoWeekRepeat(weeks, days, times);
+  }
+
+  private int parseIsoWeekNumber() throws HronException {
+    Token tok = peek();
+    if (tok == null || tok.kind() != TokenKind.NUMBER) {
+      throw parseError("expected ISO week number", tok != null ? tok.span() : endSpan());
+    }
+    int week = tok.numberVal();
+    if (week < 1 || week > 53) {
+      throw parseError("invalid ISO week " + week + " (expected 1-53)", tok.span());
+    }
+    pos++;
+    return week;
   }
 
   private YearTarget parseYearTarget() throws HronException {
@@ -485,17 +977,17 @@ public final class Parser {
     }
 
     // "the first/second/... weekday of month"
-    if (tok.kind() == TokenKind.ORDINAL) {
-      OrdinalPosition ordinal = tokens.get(pos++).ordinalVal();
-      Token dayTok = expect(TokenKind.DAY_NAME);
+    if (tok.kind() == TokenKind.ORDINAL && nextIsDayName()) {
+      OrdinalPosition ordinal = parseOrdinalPosition();
+      Weekday weekday = tokens.get(pos++).dayNameVal();
       expect(TokenKind.OF);
       Token monthTok = expect(TokenKind.MONTH_NAME);
-      return YearTarget.ordinalWeekday(ordinal, dayTok.dayNameVal(), monthTok.monthNameVal());
+      return YearTarget.ordinalWeekday(ordinal, weekday, monthTok.monthNameVal());
     }
 
-    // "the 15th of month"
-    if (tok.kind() == TokenKind.ORDINAL_NUMBER) {
-      int day = tokens.get(pos++).numberVal();
+    // "the 15th of month" or "the fifteenth of month"
+    if (tok.kind() == TokenKind.ORDINAL_NUMBER || tok.kind() == TokenKind.ORDINAL) {
+      int day = parseOrdinalDay("expected ordinal day number");
       expect(TokenKind.OF);
       Token monthTok = expect(TokenKind.MONTH_NAME);
       validateNamedDate(monthTok.monthNameVal(), day, tok.span());
@@ -508,8 +1000,19 @@ public final class Parser {
   private ScheduleExpr parseSingleDate() throws HronException {
     expect(TokenKind.ON);
 
+    // "on friday at 17:00": the nearest upcoming friday, a rolling one-shot
+    if (check(TokenKind.DAY_NAME)) {
+      Weekday day = tokens.get(pos++).dayNameVal();
+      if (check(TokenKind.COMMA)) {
+        throw parseError(
+            "a weekday cannot be combined with other dates; use 'every' for recurring days",
+            peek().span());
+      }
+      return new SingleDate(DateSpec.nextWeekday(day), parseTimes());
+    }
+
     DateSpec dateSpec = parseDateSpec();
-    List<TimeOfDay> times = parseAtTimes();
+    List<TimeOfDay> times = parseTimes();
 
     return new SingleDate(dateSpec, times);
   }
@@ -540,11 +1043,52 @@ public final class Parser {
     return DateSpec.named(monthTok.monthNameVal(), dayTok.numberVal());
   }
 
-  private List<TimeOfDay> parseAtTimes() throws HronException {
+  // times: "at HH:MM, ..." or "in the morning|afternoon|evening|night"
+  private List<TimeOfDay> parseTimes() throws HronException {
+    if (check(TokenKind.IN)) {
+      pos++;
+      expect(TokenKind.THE);
+      Token tok = peek();
+      TimeOfDay time = tok == null ? null : periodTime(tok.kind());
+      if (time == null) {
+        throw parseError(
+            "expected morning, afternoon, evening, or night", tok != null ? tok.span() : endSpan());
+      }
+      pos++;
+      return List.of(time);
+    }
     expect(TokenKind.AT);
     return parseTimeList();
   }
 
+  /**
+   * Day and week repeats may omit times: "every day", "every weekday in UTC" fire at 00:00. A bare
+   * "in" starts the timezone clause, not "in the ...".
+   */
+  private List<TimeOfDay> parseTimesOrMidnight() throws HronException {
+    boolean hasTimes =
+        check(TokenKind.AT)
+            || (check(TokenKind.IN)
+                && pos + 1 < tokens.size()
+                && tokens.get(pos + 1).kind() == TokenKind.THE);
+    if (hasTimes) {
+      return parseTimes();
+    }
+    return List.of(new TimeOfDay(0, 0));
+  }
+
+  /** Maps input-only "in the <period>" sugar to the fixed time it fires at. */
+  private static TimeOfDay periodTime(TokenKind kind) {
+    return switch (kind) {
+      case MORNING -> new TimeOfDay(9, 0);
+      case AFTERNOON -> new TimeOfDay(14, 0);
+      case EVENING -> new TimeOfDay(18, 0);
+      case NIGHT -> new TimeOfDay(21, 0);
+      default -> null;
+    };
+  }
+
+  // Times are sorted and de-duplicated: "at 17:00, 09:00, 09:00" is "at 09:00, 17:00"
   private List<TimeOfDay> parseTimeList() throws HronException {
     List<TimeOfDay> times = new ArrayList<>();
     times.add(parseTime());
@@ -554,7 +1098,10 @@ public final class Parser {
       times.add(parseTime());
     }
 
-    return times;
+    return times.stream()
+        .distinct()
+        .sorted(Comparator.comparingInt(TimeOfDay::totalMinutes))
+        .toList();
   }
 
   private TimeOfDay parseTime() throws HronException {
@@ -562,34 +1109,87 @@ public final class Parser {
     return new TimeOfDay(tok.timeHour(), tok.timeMinute());
   }
 
+  // "except [on] exception, ...": dates, day names, "weekday", "weekend"
   private List<ExceptionSpec> parseExceptions() throws HronException {
+    if (check(TokenKind.ON)) {
+      pos++;
+    }
+
     List<ExceptionSpec> exceptions = new ArrayList<>();
 
-    exceptions.add(parseExceptionSpec());
+    exceptions.addAll(parseExceptionSpec());
     while (check(TokenKind.COMMA)) {
       pos++;
-      exceptions.add(parseExceptionSpec());
+      exceptions.addAll(parseExceptionSpec());
     }
 
     return exceptions;
   }
 
-  private ExceptionSpec parseExceptionSpec() throws HronException {
+  private List<ExceptionSpec> parseExceptionSpec() throws HronException {
     Token tok = peek();
     if (tok == null) {
       throw parseError("unexpected end of input after 'except'", endSpan());
     }
 
-    if (tok.kind() == TokenKind.ISO_DATE) {
-      validateIsoDate(tok.isoDateVal(), tok.span());
-      pos++;
-      return ExceptionSpec.iso(tok.isoDateVal());
+    switch (tok.kind()) {
+      case ISO_DATE -> {
+        validateIsoDate(tok.isoDateVal(), tok.span());
+        pos++;
+        if (!atRangeSeparator()) {
+          return List.of(ExceptionSpec.iso(tok.isoDateVal()));
+        }
+        pos++;
+        Token end = peek();
+        if (end == null || end.kind() != TokenKind.ISO_DATE) {
+          throw parseError("expected ISO date after 'to'", end != null ? end.span() : endSpan());
+        }
+        validateIsoDate(end.isoDateVal(), end.span());
+        // ISO dates order lexically
+        if (end.isoDateVal().compareTo(tok.isoDateVal()) < 0) {
+          throw parseError("exception range ends before " + tok.isoDateVal(), end.span());
+        }
+        pos++;
+        return List.of(ExceptionSpec.isoRange(tok.isoDateVal(), end.isoDateVal()));
+      }
+      case DAY_NAME -> {
+        pos++;
+        return List.of(ExceptionSpec.weekday(tok.dayNameVal()));
+      }
+      case WEEKDAY -> {
+        pos++;
+        return weekdayExceptions(
+            Weekday.MONDAY, Weekday.TUESDAY, Weekday.WEDNESDAY, Weekday.THURSDAY, Weekday.FRIDAY);
+      }
+      case WEEKEND -> {
+        pos++;
+        return weekdayExceptions(Weekday.SATURDAY, Weekday.SUNDAY);
+      }
+      case MONTH_NAME -> {
+        pos++;
+        Token dayTok = parseDayNumber();
+        validateNamedDate(tok.monthNameVal(), dayTok.numberVal(), dayTok.span());
+        if (!atRangeSeparator()) {
+          return List.of(ExceptionSpec.named(tok.monthNameVal(), dayTok.numberVal()));
+        }
+        pos++;
+        Token endMonthTok = expect(TokenKind.MONTH_NAME);
+        Token endDayTok = parseDayNumber();
+        validateNamedDate(endMonthTok.monthNameVal(), endDayTok.numberVal(), endDayTok.span());
+        return List.of(
+            ExceptionSpec.namedRange(
+                tok.monthNameVal(),
+                dayTok.numberVal(),
+                endMonthTok.monthNameVal(),
+                endDayTok.numberVal()));
+      }
+      default ->
+          throw parseError("expected ISO date, month-day, or day name in exception", tok.span());
     }
+  }
 
-    Token monthTok = expect(TokenKind.MONTH_NAME);
-    Token dayTok = parseDayNumber();
-    validateNamedDate(monthTok.monthNameVal(), dayTok.numberVal(), dayTok.span());
-    return ExceptionSpec.named(monthTok.monthNameVal(), dayTok.numberVal());
+  private static List<ExceptionSpec> weekdayExceptions(Weekday... days) {
+    return Arrays.stream(days).map(ExceptionSpec::weekday).toList();
   }
 
   private UntilSpec parseUntil() throws HronException {
@@ -621,23 +1221,77 @@ public final class Parser {
       pos++;
       return tok.isoDateVal();
     }
+    if (tok.kind() == TokenKind.TODAY || tok.kind() == TokenKind.NOW) {
+      pos++;
+      return tok.kind() == TokenKind.TODAY ? "today" : "now";
+    }
 
-    throw parseError("starting only accepts ISO dates", tok.span());
+    throw parseError("starting only accepts ISO dates, month-days, 'today', or 'now'", tok.span());
   }
 
-  private List<MonthName> parseDuring() throws HronException {
-    List<MonthName> months = new ArrayList<>();
-
-    Token tok = expect(TokenKind.MONTH_NAME);
-    months.add(tok.monthNameVal());
+  // Specs are sorted by where they start and de-duplicated: "during mar, jan, mar" is "during
+  // jan, mar". Overlapping specs are not merged.
+  private List<DuringSpec> parseDuring() throws HronException {
+    List<DuringSpec> specs = new ArrayList<>();
+    specs.add(parseDuringSpec());
 
     while (check(TokenKind.COMMA)) {
       pos++;
-      tok = expect(TokenKind.MONTH_NAME);
-      months.add(tok.monthNameVal());
+      specs.add(parseDuringSpec());
     }
 
-    return months;
+    return sortedUnique(specs, Parser::duringSpecKey);
+  }
+
+  /**
+   * Ordering key for a during spec: by first month-day, then last month-day. A month and the date
+   * range covering the same days share a key.
+   */
+  private static int duringSpecKey(DuringSpec spec) {
+    int first = spec.start().number() * 100 + spec.firstDay();
+    int last = spec.end().number() * 100 + spec.lastDay();
+    return first * 10000 + last;
+  }
+
+  /** Sorts items by key, keeping the first of each run of equal keys. */
+  private static <T> List<T> sortedUnique(List<T> items, ToIntFunction<T> key) {
+    List<T> sorted = items.stream().sorted(Comparator.comparingInt(key)).toList();
+    List<T> unique = new ArrayList<>();
+    for (T item : sorted) {
+      if (unique.isEmpty() || key.applyAsInt(unique.getLast()) != key.applyAsInt(item)) {
+        unique.add(item);
+      }
+    }
+    return unique;
+  }
+
+  // during_spec: "jun", "jun to aug" (wraps past december: "nov to feb"), or month-day bounds
+  // "jun 15 to aug 31". A bound without a day covers its whole month.
+  private DuringSpec parseDuringSpec() throws HronException {
+    MonthName start = expect(TokenKind.MONTH_NAME).monthNameVal();
+    int startDay = parseDuringDay(start);
+    if (!atRangeSeparator()) {
+      // "during jun 15" is that one day
+      return new DuringSpec(start, start, startDay, startDay);
+    }
+    pos++;
+    MonthName end = expect(TokenKind.MONTH_NAME).monthNameVal();
+    int endDay = parseDuringDay(end);
+    if (startDay == 0 && endDay == 0) {
+      return DuringSpec.months(start, end);
+    }
+    return new DuringSpec(
+        start, end, startDay == 0 ? 1 : startDay, endDay == 0 ? end.maxDay() : endDay);
+  }
+
+  /** Parses the optional day after a month name in during. Returns 0 when there is no day. */
+  private int parseDuringDay(MonthName month) throws HronException {
+    if (!check(TokenKind.NUMBER) && !check(TokenKind.ORDINAL_NUMBER)) {
+      return 0;
+    }
+    Token dayTok = parseDayNumber();
+    validateNamedDate(month, dayTok.numberVal(), dayTok.span());
+    return dayTok.numberVal();
   }
 
   private String parseTimezone() throws HronException {
@@ -651,10 +1305,8 @@ public final class Parser {
 
   // Helper methods
 
-  private static final int[] MAX_DAYS = {0, 31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31};
-
   private void validateNamedDate(MonthName month, int day, Span span) throws HronException {
-    int maxDay = MAX_DAYS[month.number()];
+    int maxDay = month.maxDay();
     if (day < 1 || day > maxDay) {
       throw parseError("invalid day " + day + " for " + month + " (max " + maxDay + ")", span);
     }
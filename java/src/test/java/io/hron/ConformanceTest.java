@@ -260,7 +260,8 @@ public class ConformanceTest {
       String name = "matches/" + tc.get("name").asText();
       String expression = tc.get("expression").asText();
       String datetimeStr = tc.get("datetime").asText();
-      boolean expected = tc.get("expected").asBoolean();
+      JsonNode errorContains = tc.get("error_contains");
+      boolean expected = tc.path("expected").asBoolean();
 
       tests.add(
           DynamicTest.dynamicTest(
@@ -268,6 +269,14 @@ public class ConformanceTest {
               () -> {
                 Schedule s = Schedule.parse(expression);
                 ZonedDateTime datetime = parseZonedDateTime(datetimeStr);
+                if (errorContains != null) {
+                  IllegalStateException e =
+                      assertThrows(IllegalStateException.class, () -> s.matches(datetime));
+                  assertTrue(
+                      e.getMessage().contains(errorContains.asText()),
+                      "matches() error for: " + expression + " was: " + e.getMessage());
+                  return;
+                }
                 boolean result = s.matches(datetime);
                 assertEquals(
                     expected, result, "matches() for: " + expression + " at " + datetimeStr);
@@ -403,7 +412,9 @@ public class ConformanceTest {
   @TestFactory
   Stream<DynamicTest> toCronTests() {
     List<DynamicTest> tests = new ArrayList<>();
-    JsonNode cronTests = SPEC.get("cron").get("to_cron").get("tests");
+    List<JsonNode> cronTests = new ArrayList<>();
+    SPEC.get("cron").get("to_cron").get("tests").forEach(cronTests::add);
+    SPEC.get("cron").get("to_cron_during").get("tests").forEach(cronTests::add);
 
     for (JsonNode tc : cronTests) {
       String name = tc.get("name").asText();
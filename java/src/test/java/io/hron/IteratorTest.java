@@ -282,6 +282,32 @@ class IteratorTest {
     assertEquals(1, results.size());
   }
 
+  @Test
+  void upcomingWeekdayOccurrenceCannotBeMatched() throws HronException {
+    Schedule schedule = Schedule.parse("on friday at 17:00 in UTC");
+    ZonedDateTime from = parseZoned("2026-02-06T12:00:00+00:00[UTC]");
+
+    List<ZonedDateTime> results =
+        schedule.occurrences(from).limit(10).collect(Collectors.toList());
+
+    assertEquals(1, results.size());
+    IllegalStateException e =
+        assertThrows(IllegalStateException.class, () -> schedule.matches(results.get(0)));
+    assertTrue(e.getMessage().contains("no evaluation time"));
+  }
+
+  @Test
+  void matchesRejectsRelativeAnchor() throws HronException {
+    ZonedDateTime now = parseZoned("2026-02-06T12:00:00+00:00[UTC]");
+
+    for (String anchor : List.of("today", "now")) {
+      Schedule schedule = Schedule.parse("every 2 days at 09:00 starting " + anchor + " in UTC");
+      IllegalStateException e =
+          assertThrows(IllegalStateException.class, () -> schedule.matches(now));
+      assertTrue(e.getMessage().contains("no evaluation time"));
+    }
+  }
+
   // =========================================================================
   // Timezone Handling
   // =========================================================================
@@ -4,6 +4,7 @@ from collections.abc import Iterator
 from datetime import datetime
 
 from ._ast import (
+    BusinessDayTarget,
     DateSpec,
     DayFilter,
     DayFilterDays,
@@ -14,22 +15,29 @@ from ._ast import (
     DayRange,
     DayRepeat,
     DaysTarget,
+    DuringSpec,
     ExceptionSpec,
     IntervalRepeat,
     IntervalUnit,
     IsoDate,
     IsoException,
+    IsoRangeException,
     IsoUntil,
+    IsoWeekRepeat,
     LastDayTarget,
+    LastNDaysTarget,
     LastWeekdayTarget,
     MonthName,
     MonthRepeat,
     MonthTarget,
     NamedDate,
+    NextWeekday,
     NamedException,
+    NamedRangeException,
     NamedUntil,
     OrdinalPosition,
     OrdinalWeekdayTarget,
+    OrdinalWeekdaysTarget,
     ScheduleData,
     ScheduleExpr,
     SingleDateExpr,
@@ -37,6 +45,8 @@ from ._ast import (
     TimeOfDay,
     UntilSpec,
     Weekday,
+    WeekdayException,
+    WeekdayRepeat,
     WeekRepeat,
     YearDateTarget,
     YearDayOfMonthTarget,
@@ -80,6 +90,11 @@ class Schedule:
             return False
 
     def next_from(self, now: datetime) -> datetime | None:
+        """Compute the next occurrence strictly after `now`.
+
+        A `starting` date is both the interval anchor and a lower bound, so when `now`
+        is before it the result is on or after it.
+        """
         return _next_from(self._data, now)
 
     def next_n_from(self, now: datetime, n: int) -> list[datetime]:
@@ -94,6 +109,11 @@ class Schedule:
         return _previous_from(self._data, now)
 
     def matches(self, dt: datetime) -> bool:
+        """Check if a datetime matches this schedule.
+
+        Raises HronError for `starting today` / `starting now`, which need an
+        evaluation time that `matches` does not take.
+        """
         return _matches(self._data, dt)
 
     def occurrences(self, from_: datetime) -> Iterator[datetime]:
@@ -152,7 +172,9 @@ __all__ = [
     "MonthTarget",
     "DaysTarget",
     "LastDayTarget",
+    "LastNDaysTarget",
     "LastWeekdayTarget",
+    "BusinessDayTarget",
     "YearTarget",
     "YearDateTarget",
     "YearOrdinalWeekdayTarget",
@@ -160,18 +182,26 @@ __all__ = [
     "YearLastWeekdayTarget",
     "DateSpec",
     "NamedDate",
+    "NextWeekday",
     "IsoDate",
     "ExceptionSpec",
     "NamedException",
     "IsoException",
+    "WeekdayException",
+    "IsoRangeException",
+    "NamedRangeException",
     "UntilSpec",
     "IsoUntil",
     "NamedUntil",
     "IntervalRepeat",
     "DayRepeat",
+    "WeekdayRepeat",
     "WeekRepeat",
     "MonthRepeat",
     "OrdinalWeekdayTarget",
+    "OrdinalWeekdaysTarget",
     "SingleDateExpr",
     "YearRepeat",
+    "IsoWeekRepeat",
+    "DuringSpec",
 ]
@@ -93,10 +93,19 @@ class MonthName(Enum):
     def number(self) -> int:
         return _MONTH_NUMBERS[self]
 
+    @property
+    def max_day(self) -> int:
+        """The month's longest length: February has 29 days."""
+        return _MONTH_MAX_DAYS[self]
+
     @classmethod
     def try_parse(cls, s: str) -> MonthName | None:
         return _MONTH_PARSE.get(s.lower())
 
+    @classmethod
+    def from_number(cls, n: int) -> MonthName | None:
+        return _NUMBER_TO_MONTH.get(n)
+
     def __str__(self) -> str:
         return self.value
 
@@ -116,6 +125,23 @@ _MONTH_NUMBERS = {
     MonthName.DEC: 12,
 }
 
+_NUMBER_TO_MONTH = {v: k for k, v in _MONTH_NUMBERS.items()}
+
+_MONTH_MAX_DAYS = {
+    MonthName.JAN: 31,
+    MonthName.FEB: 29,
+    MonthName.MAR: 31,
+    MonthName.APR: 30,
+    MonthName.MAY: 31,
+    MonthName.JUN: 30,
+    MonthName.JUL: 31,
+    MonthName.AUG: 31,
+    MonthName.SEP: 30,
+    MonthName.OCT: 31,
+    MonthName.NOV: 30,
+    MonthName.DEC: 31,
+}
+
 _MONTH_PARSE: dict[str, MonthName] = {
     "january": MonthName.JAN,
     "jan": MonthName.JAN,
@@ -146,6 +172,9 @@ _MONTH_PARSE: dict[str, MonthName] = {
 class IntervalUnit(Enum):
     MIN = "min"
     HOURS = "hours"
+    # Day-granularity step: the window is active on every Nth day (aligned
+    # like "every N days at ...") and fires once, at the window start.
+    DAYS = "days"
 
     def __str__(self) -> str:
         return self.value
@@ -250,11 +279,30 @@ class LastDayTarget:
     pass
 
 
+@dataclass(frozen=True, slots=True)
+class LastNDaysTarget:
+    """The final N calendar days of each month (`the last 3 days`), N >= 2.
+
+    Counts back from the month's real length; N beyond it covers the whole month.
+    """
+
+    n: int
+
+
 @dataclass(frozen=True, slots=True)
 class LastWeekdayTarget:
     pass
 
 
+@dataclass(frozen=True, slots=True)
+class BusinessDayTarget:
+    """The Nth monday-to-friday day of the month (`the first business day`,
+    `the 3rd weekday`), N in 1-23. Excepted dates are not business days, so
+    an `except` landing on it moves the occurrence to the next one."""
+
+    n: int
+
+
 @dataclass(frozen=True, slots=True)
 class NearestWeekdayTarget:
     """Nearest weekday to a given day of month.
@@ -273,8 +321,24 @@ class OrdinalWeekdayTarget:
     weekday: Weekday
 
 
+@dataclass(frozen=True, slots=True)
+class OrdinalWeekdaysTarget:
+    """Several ordinal weekdays of the month (`the first and last friday`).
+    Ordinals are sorted and distinct; a single ordinal is an OrdinalWeekdayTarget."""
+
+    ordinals: tuple[OrdinalPosition, ...]
+    weekday: Weekday
+
+
 MonthTarget = (
-    DaysTarget | LastDayTarget | LastWeekdayTarget | NearestWeekdayTarget | OrdinalWeekdayTarget
+    DaysTarget
+    | LastDayTarget
+    | LastNDaysTarget
+    | LastWeekdayTarget
+    | NearestWeekdayTarget
+    | OrdinalWeekdayTarget
+    | OrdinalWeekdaysTarget
+    | BusinessDayTarget
 )
 
 
@@ -318,13 +382,24 @@ class NamedDate:
     month: MonthName
     day: int
 
+    def __str__(self) -> str:
+        return f"{self.month} {self.day}"
+
 
 @dataclass(frozen=True, slots=True)
 class IsoDate:
     date: str
 
 
-DateSpec = NamedDate | IsoDate
+@dataclass(frozen=True, slots=True)
+class NextWeekday:
+    """`on friday`: the nearest upcoming day with that weekday, resolved
+    against the evaluation time rather than a fixed date."""
+
+    day: Weekday
+
+
+DateSpec = NamedDate | IsoDate | NextWeekday
 
 
 # --- Exception ---
@@ -341,7 +416,33 @@ class IsoException:
     date: str
 
 
-ExceptionSpec = NamedException | IsoException
+@dataclass(frozen=True, slots=True)
+class WeekdayException:
+    day: Weekday
+
+
+@dataclass(frozen=True, slots=True)
+class IsoRangeException:
+    """One-off inclusive ISO date range: `2026-12-24 to 2026-12-31`."""
+
+    start: str
+    end: str
+
+
+@dataclass(frozen=True, slots=True)
+class NamedRangeException:
+    """Recurring inclusive range of named dates: `dec 24 to jan 2` matches
+    every year, wrapping past december when the end comes first."""
+
+    start_month: MonthName
+    start_day: int
+    end_month: MonthName
+    end_day: int
+
+
+ExceptionSpec = (
+    NamedException | IsoException | WeekdayException | IsoRangeException | NamedRangeException
+)
 
 
 # --- Until spec ---
@@ -380,6 +481,19 @@ class DayRepeat:
     times: tuple[TimeOfDay, ...]
 
 
+@dataclass(frozen=True, slots=True)
+class WeekdayRepeat:
+    """`every 3 weekdays at 09:00`: every Nth business day.
+
+    Only Monday through Friday count toward the interval, so weekends neither
+    fire nor advance it. Days are counted from the `starting` anchor (or its
+    next weekday), defaulting to 1970-01-01.
+    """
+
+    interval: int
+    times: tuple[TimeOfDay, ...]
+
+
 @dataclass(frozen=True, slots=True)
 class WeekRepeat:
     interval: int
@@ -407,7 +521,86 @@ class YearRepeat:
     times: tuple[TimeOfDay, ...]
 
 
-ScheduleExpr = IntervalRepeat | DayRepeat | WeekRepeat | MonthRepeat | SingleDateExpr | YearRepeat
+@dataclass(frozen=True, slots=True)
+class IsoWeekRepeat:
+    """Days in ISO 8601 week numbers 1-53 of every year."""
+
+    weeks: tuple[int, ...]
+    days: tuple[Weekday, ...]
+    times: tuple[TimeOfDay, ...]
+
+
+ScheduleExpr = (
+    IntervalRepeat
+    | DayRepeat
+    | WeekdayRepeat
+    | WeekRepeat
+    | MonthRepeat
+    | SingleDateExpr
+    | YearRepeat
+    | IsoWeekRepeat
+)
+
+
+# --- During spec ---
+
+
+@dataclass(frozen=True, slots=True)
+class DuringSpec:
+    """A single month, inclusive month range, or inclusive month-day range in a
+    `during` clause.
+
+    Wraps past December when start > end: `nov to feb` is nov, dec, jan, feb.
+    Month forms leave the days unset; an end of feb 29 means the last day of
+    February in common years.
+    """
+
+    start: MonthName
+    end: MonthName
+    start_day: int | None = None
+    end_day: int | None = None
+
+    def expand(self) -> tuple[MonthName, ...]:
+        """The months the spec touches, in calendar order from the start."""
+        (start, start_day), (end, end_day) = self.bounds()
+        if start == end and start_day > end_day:
+            # `jun 15 to jun 10` wraps through every month
+            length = 12
+        else:
+            length = (end.number - start.number) % 12 + 1
+        return tuple(_NUMBER_TO_MONTH[(start.number - 1 + i) % 12 + 1] for i in range(length))
+
+    def bounds(self) -> tuple[tuple[MonthName, int], tuple[MonthName, int]]:
+        """The first and last month-day the spec covers. Whole months end on
+        their longest day (feb 29)."""
+        start_day = 1 if self.start_day is None else self.start_day
+        end_day = self.end.max_day if self.end_day is None else self.end_day
+        return (self.start, start_day), (self.end, end_day)
+
+    def contains(self, month: MonthName, day: int) -> bool:
+        """Whether the month-day falls within the spec."""
+        (start_month, start_day), (end_month, end_day) = self.bounds()
+        key = (month.number, day)
+        start = (start_month.number, start_day)
+        end = (end_month.number, end_day)
+        if start <= end:
+            return start <= key <= end
+        return key >= start or key <= end
+
+    @property
+    def whole_months(self) -> bool:
+        """Whether the spec covers whole months: `jun 1 to aug 31` is `jun to aug`."""
+        (_, start_day), (end, end_day) = self.bounds()
+        return start_day == 1 and end_day == end.max_day
+
+    def __str__(self) -> str:
+        (start, start_day), (end, end_day) = self.bounds()
+        # Date ranges covering whole months print as months
+        if self.whole_months:
+            return str(start) if start == end else f"{start} to {end}"
+        if (start, start_day) == (end, end_day):
+            return f"{start} {start_day}"
+        return f"{start} {start_day} to {end} {end_day}"
 
 
 # --- Schedule data (top-level) ---
@@ -419,8 +612,18 @@ class ScheduleData:
     timezone: str | None = None
     except_: tuple[ExceptionSpec, ...] = ()
     until: UntilSpec | None = None
+    # ISO date, or "today" / "now", resolved against each evaluation instant
     anchor: str | None = None
-    during: tuple[MonthName, ...] = ()
+    # `starting jan 6`, resolved against each evaluation instant
+    anchor_named: NamedDate | None = None
+    # Nothing occurs before this time on the anchor date
+    anchor_time: TimeOfDay | None = None
+    # `during` as written: months, month ranges, and date ranges
+    during: tuple[DuringSpec, ...] = ()
+
+    def during_months(self) -> tuple[MonthName, ...]:
+        """Months any `during` spec touches, for month-level skipping."""
+        return tuple(m for spec in self.during for m in spec.expand())
 
 
 def new_schedule_data(expr: ScheduleExpr) -> ScheduleData:
@@ -440,6 +643,13 @@ ALL_WEEKDAYS: tuple[Weekday, ...] = (
 
 ALL_WEEKEND: tuple[Weekday, ...] = (Weekday.SATURDAY, Weekday.SUNDAY)
 
+RELATIVE_ANCHORS: tuple[str, ...] = ("today", "now")
+
+
+def has_relative_anchor(schedule: ScheduleData) -> bool:
+    """Whether the `starting` anchor resolves against the evaluation instant."""
+    return schedule.anchor_named is not None or schedule.anchor in RELATIVE_ANCHORS
+
 
 def expand_day_spec(spec: DayOfMonthSpec) -> list[int]:
     match spec:
@@ -458,3 +668,17 @@ def expand_month_target(target: MonthTarget) -> list[int]:
             return result
         case _:
             return []
+
+
+def canonical_day_filter(f: DayFilter) -> DayFilter:
+    """Sort and de-duplicate an explicit day list, collapsing one covering
+    exactly the weekdays or all seven days into the equivalent keyword filter.
+    `saturday, sunday` stays a list: `weekend` is a keyword of its own."""
+    if not isinstance(f, DayFilterDays):
+        return f
+    days = tuple(sorted(set(f.days), key=lambda d: d.number))
+    if len(days) == 7:
+        return DayFilterEvery()
+    if days == ALL_WEEKDAYS:
+        return DayFilterWeekday()
+    return DayFilterDays(days)
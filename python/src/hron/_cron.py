@@ -1,8 +1,8 @@
 from __future__ import annotations
 
 from ._ast import (
-    ALL_WEEKDAYS,
     ALL_WEEKEND,
+    BusinessDayTarget,
     DayFilter,
     DayFilterDays,
     DayFilterEvery,
@@ -11,23 +11,30 @@ from ._ast import (
     DayRange,
     DayRepeat,
     DaysTarget,
+    DuringSpec,
     IntervalRepeat,
     IntervalUnit,
+    IsoWeekRepeat,
     LastDayTarget,
+    LastNDaysTarget,
     LastWeekdayTarget,
     MonthName,
     MonthRepeat,
     NearestWeekdayTarget,
     OrdinalPosition,
     OrdinalWeekdayTarget,
+    OrdinalWeekdaysTarget,
     ScheduleData,
+    ScheduleExpr,
     SingleDateExpr,
     SingleDay,
     TimeOfDay,
     Weekday,
+    WeekdayRepeat,
     WeekRepeat,
     YearDateTarget,
     YearRepeat,
+    canonical_day_filter,
     new_schedule_data,
 )
 from ._error import HronError
@@ -38,8 +45,9 @@ def to_cron(schedule: ScheduleData) -> str:
         raise HronError.cron("not expressible as cron (except clauses not supported)")
     if schedule.until:
         raise HronError.cron("not expressible as cron (until clauses not supported)")
-    if schedule.during:
-        raise HronError.cron("not expressible as cron (during clauses not supported)")
+    if not all(spec.whole_months for spec in schedule.during):
+        raise HronError.cron("not expressible as cron (during dates narrower than whole months)")
+    month = _month_field(schedule.during_months())
 
     expr = schedule.expr
 
@@ -47,11 +55,12 @@ def to_cron(schedule: ScheduleData) -> str:
         case DayRepeat(interval=interval, days=days, times=times):
             if interval > 1:
                 raise HronError.cron("not expressible as cron (multi-day intervals not supported)")
-            if len(times) != 1:
+            fields = _time_fields(times)
+            if fields is None:
                 raise HronError.cron("not expressible as cron (multiple times not supported)")
-            time = times[0]
+            minute, hour = fields
             dow = _day_filter_to_cron_dow(days)
-            return f"{time.minute} {time.hour} * * {dow}"
+            return f"{minute} {hour} * {month} {dow}"
 
         case IntervalRepeat(
             interval=interval,
@@ -60,7 +69,14 @@ def to_cron(schedule: ScheduleData) -> str:
             to_time=tt,
             day_filter=df,
         ):
-            full_day = ft.hour == 0 and ft.minute == 0 and tt.hour == 23 and tt.minute == 59
+            if unit == IntervalUnit.DAYS:
+                raise HronError.cron(
+                    "not expressible as cron (multi-day interval windows not supported)"
+                )
+            # Only expressible if window is full day (00:00 to 23:59). Hour
+            # steps may start past the hour: `from 00:15` fires at :15.
+            offset_ok = ft.minute == 0 or unit == IntervalUnit.HOURS
+            full_day = ft.hour == 0 and offset_ok and tt.hour == 23 and tt.minute == 59
             if not full_day:
                 raise HronError.cron(
                     "not expressible as cron (partial-day interval windows not supported)"
@@ -74,9 +90,11 @@ def to_cron(schedule: ScheduleData) -> str:
                     raise HronError.cron(
                         f"not expressible as cron (*/{interval} breaks at hour boundaries)"
                     )
-                return f"*/{interval} * * * *"
+                return f"*/{interval} * * {month} *"
             # hours
-            return f"0 */{interval} * * *"
+            if interval == 1 and ft.minute != 0:
+                return f"{ft.minute} * * {month} *"
+            return f"{ft.minute} */{interval} * {month} *"
 
         case WeekRepeat():
             raise HronError.cron("not expressible as cron (multi-week intervals not supported)")
@@ -86,9 +104,10 @@ def to_cron(schedule: ScheduleData) -> str:
                 raise HronError.cron(
                     "not expressible as cron (multi-month intervals not supported)"
                 )
-            if len(times) != 1:
+            fields = _time_fields(times)
+            if fields is None:
                 raise HronError.cron("not expressible as cron (multiple times not supported)")
-            time = times[0]
+            minute, hour = fields
             match target:
                 case DaysTarget(specs=specs):
                     expanded: list[int] = []
@@ -100,11 +119,22 @@ def to_cron(schedule: ScheduleData) -> str:
                                 for d in range(start, end + 1):
                                     expanded.append(d)
                     dom = ",".join(str(d) for d in expanded)
-                    return f"{time.minute} {time.hour} {dom} * *"
+                    return f"{minute} {hour} {dom} {month} *"
                 case LastDayTarget():
                     raise HronError.cron(
                         "not expressible as cron (last day of month not supported)"
                     )
+                case LastNDaysTarget():
+                    raise HronError.cron(
+                        "not expressible as cron (last days of month not supported)"
+                    )
+                # The first business day is always the weekday nearest the 1st
+                case BusinessDayTarget(n=1):
+                    return f"{minute} {hour} 1W {month} *"
+                case BusinessDayTarget():
+                    raise HronError.cron(
+                        "not expressible as cron (nth business day of month not supported)"
+                    )
                 case LastWeekdayTarget():
                     raise HronError.cron(
                         "not expressible as cron (last weekday of month not supported)"
@@ -114,11 +144,15 @@ def to_cron(schedule: ScheduleData) -> str:
                         raise HronError.cron(
                             "not expressible as cron (directional nearest weekday not supported)"
                         )
-                    return f"{time.minute} {time.hour} {day}W * *"
+                    return f"{minute} {hour} {day}W {month} *"
                 case OrdinalWeekdayTarget():
                     raise HronError.cron(
                         "not expressible as cron (ordinal weekday of month not supported)"
                     )
+                case OrdinalWeekdaysTarget():
+                    raise HronError.cron(
+                        "not expressible as cron (ordinal weekdays of month not supported)"
+                    )
                 case _:
                     raise HronError.cron("not expressible as cron (unknown month target)")
 
@@ -130,9 +164,48 @@ def to_cron(schedule: ScheduleData) -> str:
                 "not expressible as cron (yearly schedules not supported in 5-field cron)"
             )
 
+        case IsoWeekRepeat():
+            raise HronError.cron("not expressible as cron (cron has no week-of-year field)")
+
+        case WeekdayRepeat():
+            raise HronError.cron("not expressible as cron (business-day intervals not supported)")
+
     raise HronError.cron(f"unknown expression type: {type(expr)}")  # pragma: no cover
 
 
+def _time_fields(times: tuple[TimeOfDay, ...]) -> tuple[str, str] | None:
+    """Exact minute and hour fields for a list of times: `0 9,17`, `0,30 9`, or
+    `0,30 9,17` when the times are every listed minute at every listed hour.
+    None when comma fields would fire at times not in the list."""
+    distinct = {(t.hour, t.minute) for t in times}
+    minutes = sorted({minute for _, minute in distinct})
+    hours = sorted({hour for hour, _ in distinct})
+    # Distinct times are a subset of the grid, so equal counts mean equal sets
+    if len(minutes) * len(hours) != len(distinct):
+        return None
+    return ",".join(str(m) for m in minutes), ",".join(str(h) for h in hours)
+
+
+def _month_field(during: tuple[MonthName, ...]) -> str:
+    """The cron month field for a `during` clause. Runs of three or more
+    consecutive months collapse into a range."""
+    months = sorted({m.number for m in during})
+    if not months:
+        return "*"
+    parts: list[str] = []
+    i = 0
+    while i < len(months):
+        j = i
+        while j + 1 < len(months) and months[j + 1] == months[j] + 1:
+            j += 1
+        if j - i >= 2:
+            parts.append(f"{months[i]}-{months[j]}")
+        else:
+            parts.extend(str(n) for n in months[i : j + 1])
+        i = j + 1
+    return ",".join(parts)
+
+
 def _day_filter_to_cron_dow(f: DayFilter) -> str:
     match f:
         case DayFilterEvery():
@@ -157,7 +230,7 @@ def from_cron(cron: str) -> ScheduleData:
 
     # Handle @ shortcuts first
     if trimmed.startswith("@"):
-        return _parse_cron_shortcut(trimmed)
+        return parse_cron_shortcut(trimmed)
 
     fields = trimmed.split()
     if len(fields) != 5:
@@ -198,76 +271,60 @@ def from_cron(cron: str) -> ScheduleData:
         return interval_result
 
     # Standard time-based cron
-    minute = _parse_single_value(minute_field, "minute", 0, 59)
-    hour = _parse_single_value(hour_field, "hour", 0, 23)
-    time = TimeOfDay(hour, minute)
+    times = _parse_cron_times(minute_field, hour_field)
 
     # DOM-based (monthly) - when DOM is specified and DOW is *
     if dom_field != "*" and dow_field == "*":
         target = _parse_dom_field(dom_field)
-        schedule = new_schedule_data(MonthRepeat(interval=1, target=target, times=(time,)))
+        schedule = new_schedule_data(MonthRepeat(interval=1, target=target, times=times))
         schedule.during = during
         return schedule
 
     # DOW-based (day repeat)
     days = _parse_cron_dow(dow_field)
-    schedule = new_schedule_data(DayRepeat(interval=1, days=days, times=(time,)))
+    schedule = new_schedule_data(DayRepeat(interval=1, days=days, times=times))
     schedule.during = during
     return schedule
 
 
-def _parse_cron_shortcut(cron: str) -> ScheduleData:
+def parse_cron_shortcut(cron: str) -> ScheduleData:
     """Parse @ shortcuts like @daily, @hourly, etc."""
-    lower = cron.lower()
-    match lower:
+    expr = cron_shortcut_expr(cron.lower())
+    if expr is None:
+        raise HronError.cron(f"unknown @ shortcut: {cron}")
+    return new_schedule_data(expr)
+
+
+def cron_shortcut_expr(shortcut: str) -> ScheduleExpr | None:
+    """The expression a lowercase @ shortcut stands for, if it is one."""
+    midnight = (TimeOfDay(0, 0),)
+    match shortcut:
         case "@yearly" | "@annually":
-            return new_schedule_data(
-                YearRepeat(
-                    interval=1,
-                    target=YearDateTarget(month=MonthName.JAN, day=1),
-                    times=(TimeOfDay(0, 0),),
-                )
+            return YearRepeat(
+                interval=1,
+                target=YearDateTarget(month=MonthName.JAN, day=1),
+                times=midnight,
             )
         case "@monthly":
-            return new_schedule_data(
-                MonthRepeat(
-                    interval=1,
-                    target=DaysTarget((SingleDay(1),)),
-                    times=(TimeOfDay(0, 0),),
-                )
-            )
+            return MonthRepeat(interval=1, target=DaysTarget((SingleDay(1),)), times=midnight)
         case "@weekly":
-            return new_schedule_data(
-                DayRepeat(
-                    interval=1,
-                    days=DayFilterDays((Weekday.SUNDAY,)),
-                    times=(TimeOfDay(0, 0),),
-                )
-            )
+            return DayRepeat(interval=1, days=DayFilterDays((Weekday.SUNDAY,)), times=midnight)
         case "@daily" | "@midnight":
-            return new_schedule_data(
-                DayRepeat(
-                    interval=1,
-                    days=DayFilterEvery(),
-                    times=(TimeOfDay(0, 0),),
-                )
-            )
+            return DayRepeat(interval=1, days=DayFilterEvery(), times=midnight)
         case "@hourly":
-            return new_schedule_data(
-                IntervalRepeat(
-                    interval=1,
-                    unit=IntervalUnit.HOURS,
-                    from_time=TimeOfDay(0, 0),
-                    to_time=TimeOfDay(23, 59),
-                    day_filter=None,
-                )
+            return IntervalRepeat(
+                interval=1,
+                unit=IntervalUnit.HOURS,
+                from_time=TimeOfDay(0, 0),
+                to_time=TimeOfDay(23, 59),
+                day_filter=None,
             )
         case _:
-            raise HronError.cron(f"unknown @ shortcut: {cron}")
+            return None
 
 
-def _parse_month_field(field: str) -> tuple[MonthName, ...]:
-    """Parse month field into a tuple of MonthName for the `during` clause."""
+def _parse_month_field(field: str) -> tuple[DuringSpec, ...]:
+    """Parse month field into `during` specs, one per month."""
     if field == "*":
         return ()
 
@@ -314,7 +371,7 @@ def _parse_month_field(field: str) -> tuple[MonthName, ...]:
             # Single month
             months.append(_parse_month_value(part))
 
-    return tuple(months)
+    return tuple(DuringSpec(m, m) for m in months)
 
 
 def _parse_month_value(s: str) -> MonthName:
@@ -368,7 +425,7 @@ def _try_parse_nth_weekday(
     hour_field: str,
     dom_field: str,
     dow_field: str,
-    during: tuple[MonthName, ...],
+    during: tuple[DuringSpec, ...],
 ) -> ScheduleData | None:
     """Try to parse nth weekday patterns like 1#1 (first Monday) or 5L (last Friday)."""
     # Check for # pattern (nth weekday of month)
@@ -388,8 +445,7 @@ def _try_parse_nth_weekday(
         if dom_field != "*" and dom_field != "?":
             raise HronError.cron("DOM must be * when using # for nth weekday")
 
-        minute = _parse_single_value(minute_field, "minute", 0, 59)
-        hour = _parse_single_value(hour_field, "hour", 0, 23)
+        times = _parse_cron_times(minute_field, hour_field)
 
         ordinal_map: dict[int, OrdinalPosition] = {
             1: OrdinalPosition.FIRST,
@@ -403,7 +459,7 @@ def _try_parse_nth_weekday(
             MonthRepeat(
                 interval=1,
                 target=OrdinalWeekdayTarget(ordinal=ordinal_map[nth], weekday=weekday),
-                times=(TimeOfDay(hour, minute),),
+                times=times,
             )
         )
         schedule.during = during
@@ -418,14 +474,13 @@ def _try_parse_nth_weekday(
         if dom_field != "*" and dom_field != "?":
             raise HronError.cron("DOM must be * when using nL for last weekday")
 
-        minute = _parse_single_value(minute_field, "minute", 0, 59)
-        hour = _parse_single_value(hour_field, "hour", 0, 23)
+        times = _parse_cron_times(minute_field, hour_field)
 
         schedule = new_schedule_data(
             MonthRepeat(
                 interval=1,
                 target=OrdinalWeekdayTarget(ordinal=OrdinalPosition.LAST, weekday=weekday),
-                times=(TimeOfDay(hour, minute),),
+                times=times,
             )
         )
         schedule.during = during
@@ -439,7 +494,7 @@ def _try_parse_last_day(
     hour_field: str,
     dom_field: str,
     dow_field: str,
-    during: tuple[MonthName, ...],
+    during: tuple[DuringSpec, ...],
 ) -> ScheduleData | None:
     """Try to parse L (last day) or LW (last weekday) patterns."""
     if dom_field != "L" and dom_field != "LW":
@@ -448,8 +503,7 @@ def _try_parse_last_day(
     if dow_field != "*" and dow_field != "?":
         raise HronError.cron("DOW must be * when using L or LW in DOM")
 
-    minute = _parse_single_value(minute_field, "minute", 0, 59)
-    hour = _parse_single_value(hour_field, "hour", 0, 23)
+    times = _parse_cron_times(minute_field, hour_field)
 
     target = LastWeekdayTarget() if dom_field == "LW" else LastDayTarget()
 
@@ -457,7 +511,7 @@ def _try_parse_last_day(
         MonthRepeat(
             interval=1,
             target=target,
-            times=(TimeOfDay(hour, minute),),
+            times=times,
         )
     )
     schedule.during = during
@@ -469,7 +523,7 @@ def _try_parse_nearest_weekday(
     hour_field: str,
     dom_field: str,
     dow_field: str,
-    during: tuple[MonthName, ...],
+    during: tuple[DuringSpec, ...],
 ) -> ScheduleData | None:
     """Try to parse W (nearest weekday) patterns: 15W, 1W, etc."""
     if not dom_field.endswith("W") or dom_field == "LW":
@@ -487,8 +541,7 @@ def _try_parse_nearest_weekday(
     if day < 1 or day > 31:
         raise HronError.cron(f"W day must be 1-31, got {day}")
 
-    minute = _parse_single_value(minute_field, "minute", 0, 59)
-    hour = _parse_single_value(hour_field, "hour", 0, 23)
+    times = _parse_cron_times(minute_field, hour_field)
 
     target = NearestWeekdayTarget(day=day, direction=None)
 
@@ -496,7 +549,7 @@ def _try_parse_nearest_weekday(
         MonthRepeat(
             interval=1,
             target=target,
-            times=(TimeOfDay(hour, minute),),
+            times=times,
         )
     )
     schedule.during = during
@@ -508,7 +561,7 @@ def _try_parse_interval(
     hour_field: str,
     dom_field: str,
     dow_field: str,
-    during: tuple[MonthName, ...],
+    during: tuple[DuringSpec, ...],
 ) -> ScheduleData | None:
     """Try to parse interval patterns: */N, range/N in minute or hour fields."""
     # Minute interval: */N or range/N
@@ -587,9 +640,12 @@ def _try_parse_interval(
             schedule.during = during
             return schedule
 
-    # Hour interval: 0 */N or 0 range/N
-    if "/" in hour_field and minute_field in ("0", "00"):
-        range_part, step_str = hour_field.split("/", 1)
+    # Hour interval: M */N, M range/N, or M * (every hour at minute M)
+    minute_ok = minute_field.isdigit() and int(minute_field) <= 59
+    if minute_ok and ("/" in hour_field or hour_field == "*"):
+        minute = int(minute_field)
+        range_part, _, step_str = hour_field.partition("/")
+        step_str = step_str or "1"
 
         try:
             interval = int(step_str)
@@ -617,14 +673,15 @@ def _try_parse_interval(
             to_hour = 23
 
         if (dom_field == "*" or dom_field == "?") and (dow_field == "*" or dow_field == "?"):
-            # Use :59 only for full day (00:00 to 23:59), otherwise use :00
-            end_minute = 59 if from_hour == 0 and to_hour == 23 else 0
+            # Use :59 only for full day (00:00 to 23:59), otherwise end on
+            # the last firing
+            end_minute = 59 if from_hour == 0 and to_hour == 23 else minute
 
             schedule = new_schedule_data(
                 IntervalRepeat(
                     interval=interval,
                     unit=IntervalUnit.HOURS,
-                    from_time=TimeOfDay(from_hour, 0),
+                    from_time=TimeOfDay(from_hour, minute),
                     to_time=TimeOfDay(to_hour, end_minute),
                     day_filter=None,
                 )
@@ -765,20 +822,11 @@ def _parse_cron_dow(field: str) -> DayFilter:
             dow = _parse_dow_value(part)
             days.append(_cron_dow_to_weekday(dow))
 
-    # Check for special patterns
-    if len(days) == 5:
-        sorted_days = sorted(days, key=lambda d: d.number)
-        sorted_weekdays = sorted(ALL_WEEKDAYS, key=lambda d: d.number)
-        if sorted_days == list(sorted_weekdays):
-            return DayFilterWeekday()
-
-    if len(days) == 2:
-        sorted_days = sorted(days, key=lambda d: d.number)
-        sorted_weekend = sorted(ALL_WEEKEND, key=lambda d: d.number)
-        if sorted_days == list(sorted_weekend):
-            return DayFilterWeekend()
-
-    return DayFilterDays(tuple(days))
+    # Cron has no weekend keyword, so 0,6 reads as the weekend
+    canonical = canonical_day_filter(DayFilterDays(tuple(days)))
+    if isinstance(canonical, DayFilterDays) and canonical.days == ALL_WEEKEND:
+        return DayFilterWeekend()
+    return canonical
 
 
 def _parse_dow_value(s: str) -> int:
@@ -834,6 +882,20 @@ def _cron_dow_to_weekday(n: int) -> Weekday:
     return result
 
 
+def _parse_cron_times(minute_field: str, hour_field: str) -> tuple[TimeOfDay, ...]:
+    """Parse the minute and hour fields into times. Either field may be a comma
+    list; every hour pairs with every minute, so `0,30 9,17` gives four times."""
+    minutes = _parse_value_list(minute_field, "minute", 0, 59)
+    hours = _parse_value_list(hour_field, "hour", 0, 23)
+    pairs = sorted({(hour, minute) for hour in hours for minute in minutes})
+    return tuple(TimeOfDay(hour, minute) for hour, minute in pairs)
+
+
+def _parse_value_list(field: str, name: str, min_val: int, max_val: int) -> list[int]:
+    """Parse a comma list of numeric values, each validated like a single value."""
+    return [_parse_single_value(value, name, min_val, max_val) for value in field.split(",")]
+
+
 def _parse_single_value(field: str, name: str, min_val: int, max_val: int) -> int:
     """Parse a single numeric value with validation."""
     try:
@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 from ._ast import (
+    BusinessDayTarget,
     DayFilter,
     DayFilterDays,
     DayFilterEvery,
@@ -13,27 +14,36 @@ from ._ast import (
     IntervalUnit,
     IsoDate,
     IsoException,
+    IsoRangeException,
     IsoUntil,
+    IsoWeekRepeat,
     LastDayTarget,
+    LastNDaysTarget,
     LastWeekdayTarget,
     MonthRepeat,
     NamedDate,
+    NextWeekday,
     NamedException,
+    NamedRangeException,
     NamedUntil,
     NearestDirection,
     NearestWeekdayTarget,
     OrdinalWeekdayTarget,
+    OrdinalWeekdaysTarget,
     ScheduleData,
     ScheduleExpr,
     SingleDateExpr,
     SingleDay,
     TimeOfDay,
+    WeekdayException,
+    WeekdayRepeat,
     WeekRepeat,
     YearDateTarget,
     YearDayOfMonthTarget,
     YearLastWeekdayTarget,
     YearOrdinalWeekdayTarget,
     YearRepeat,
+    canonical_day_filter,
 )
 
 
@@ -48,6 +58,14 @@ def display(schedule: ScheduleData) -> str:
                     parts.append(f"{m} {d}")
                 case IsoException(date=d):
                     parts.append(d)
+                case WeekdayException(day=d):
+                    parts.append(str(d))
+                case IsoRangeException(start=start, end=end):
+                    parts.append(f"{start} to {end}")
+                case NamedRangeException(
+                    start_month=sm, start_day=sd, end_month=em, end_day=ed
+                ):
+                    parts.append(f"{sm} {sd} to {em} {ed}")
                 case _:
                     raise ValueError(f"unknown exception type: {type(exc)}")
         out += " except " + ", ".join(parts)
@@ -63,9 +81,13 @@ def display(schedule: ScheduleData) -> str:
 
     if schedule.anchor:
         out += f" starting {schedule.anchor}"
+    elif schedule.anchor_named:
+        out += f" starting {schedule.anchor_named}"
+    if schedule.anchor_time:
+        out += f" at {schedule.anchor_time}"
 
     if schedule.during:
-        out += " during " + ", ".join(str(m) for m in schedule.during)
+        out += " during " + ", ".join(str(spec) for spec in schedule.during)
 
     if schedule.timezone:
         out += f" in {schedule.timezone}"
@@ -84,9 +106,20 @@ def _display_expr(expr: ScheduleExpr) -> str:
 
         case DayRepeat(interval=interval, days=days, times=times):
             if interval > 1:
-                return f"every {interval} days at {_format_time_list(times)}"
+                out = f"every {interval} days "
+                if not isinstance(days, DayFilterEvery):
+                    out += f"on {_display_day_filter(days)} "
+                return out + f"at {_format_time_list(times)}"
             return f"every {_display_day_filter(days)} at {_format_time_list(times)}"
 
+        case WeekdayRepeat(interval=interval, times=times):
+            return f"every {interval} weekdays at {_format_time_list(times)}"
+
+        case IsoWeekRepeat(weeks=weeks, days=days, times=times):
+            week_str = ", ".join(str(w) for w in weeks)
+            day_str = ", ".join(str(d) for d in days)
+            return f"every year in iso weeks {week_str} on {day_str} at {_format_time_list(times)}"
+
         case WeekRepeat(interval=interval, days=days, times=times):
             day_str = ", ".join(str(d) for d in days)
             if interval > 1:
@@ -99,6 +132,10 @@ def _display_expr(expr: ScheduleExpr) -> str:
                     target_str = _format_ordinal_day_specs(specs)
                 case LastDayTarget():
                     target_str = "last day"
+                case LastNDaysTarget(n=n):
+                    target_str = f"last {n} days"
+                case BusinessDayTarget(n=n):
+                    target_str = f"{n}{_ordinal_suffix(n)} business day"
                 case LastWeekdayTarget():
                     target_str = "last weekday"
                 case NearestWeekdayTarget(day=day, direction=direction):
@@ -110,6 +147,9 @@ def _display_expr(expr: ScheduleExpr) -> str:
                     target_str = f"{prefix}nearest weekday to {day}{_ordinal_suffix(day)}"
                 case OrdinalWeekdayTarget(ordinal=ordinal, weekday=weekday):
                     target_str = f"{ordinal} {weekday}"
+                case OrdinalWeekdaysTarget(ordinals=ordinals, weekday=weekday):
+                    *rest, last = (str(o) for o in ordinals)
+                    target_str = f"{', '.join(rest)} and {last} {weekday}"
                 case _:
                     raise ValueError(f"unknown month target: {type(target)}")
             if interval > 1:
@@ -122,6 +162,8 @@ def _display_expr(expr: ScheduleExpr) -> str:
                     date_str = f"{m} {d}"
                 case IsoDate(date=d):
                     date_str = d
+                case NextWeekday(day=day):
+                    date_str = str(day)
                 case _:
                     raise ValueError(f"unknown date spec: {type(date_spec)}")
             return f"on {date_str} at {_format_time_list(times)}"
@@ -154,8 +196,11 @@ def _display_day_filter(f: DayFilter) -> str:
             return "weekday"
         case DayFilterWeekend():
             return "weekend"
-        case DayFilterDays(days=days):
-            return ", ".join(str(d) for d in days)
+        case DayFilterDays():
+            canonical = canonical_day_filter(f)
+            if not isinstance(canonical, DayFilterDays):
+                return _display_day_filter(canonical)
+            return ", ".join(str(d) for d in canonical.days)
         case _:
             raise ValueError(f"unknown day filter: {type(f)}")
 
@@ -193,4 +238,6 @@ def _ordinal_suffix(n: int) -> str:
 def _unit_display(interval: int, unit: IntervalUnit) -> str:
     if unit == IntervalUnit.MIN:
         return "minute" if interval == 1 else "min"
+    if unit == IntervalUnit.DAYS:
+        return "day" if interval == 1 else "days"
     return "hour" if interval == 1 else "hours"
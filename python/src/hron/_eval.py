@@ -3,10 +3,12 @@ from __future__ import annotations
 import calendar
 import contextlib
 from collections.abc import Iterator
+from dataclasses import replace
 from datetime import date, datetime, time, timedelta
 from zoneinfo import ZoneInfo
 
 from ._ast import (
+    BusinessDayTarget,
     DateSpec,
     DayFilter,
     DayFilterDays,
@@ -15,30 +17,39 @@ from ._ast import (
     DayFilterWeekend,
     DayRepeat,
     DaysTarget,
+    DuringSpec,
     ExceptionSpec,
     IntervalRepeat,
     IntervalUnit,
     IsoDate,
     IsoException,
+    IsoRangeException,
     IsoUntil,
+    IsoWeekRepeat,
     LastDayTarget,
+    LastNDaysTarget,
     LastWeekdayTarget,
     MonthName,
     MonthRepeat,
     MonthTarget,
     NamedDate,
+    NextWeekday,
     NamedException,
+    NamedRangeException,
     NamedUntil,
     NearestDirection,
     NearestWeekdayTarget,
     OrdinalPosition,
     OrdinalWeekdayTarget,
+    OrdinalWeekdaysTarget,
     ScheduleData,
     ScheduleExpr,
     SingleDateExpr,
     TimeOfDay,
     UntilSpec,
     Weekday,
+    WeekdayException,
+    WeekdayRepeat,
     WeekRepeat,
     YearDateTarget,
     YearDayOfMonthTarget,
@@ -47,7 +58,9 @@ from ._ast import (
     YearRepeat,
     YearTarget,
     expand_month_target,
+    has_relative_anchor,
 )
+from ._error import HronError
 
 # =============================================================================
 # Iteration Safety Limits
@@ -154,6 +167,17 @@ def _last_weekday_of_month(year: int, month: int) -> date:
     return d
 
 
+def _nth_business_day(
+    year: int, month: int, n: int, exceptions: tuple[ExceptionSpec, ...] = ()
+) -> date | None:
+    """The nth business day (Mon-Fri, 1-indexed) of a month, not counting
+    excepted days. None if the month has fewer."""
+    last = _last_day_of_month(year, month).day
+    days = (date(year, month, d) for d in range(1, last + 1))
+    business = [d for d in days if d.isoweekday() <= 5 and not _is_excepted(d, exceptions)]
+    return business[n - 1] if n <= len(business) else None
+
+
 def _nth_weekday_of_month(year: int, month: int, weekday: Weekday, n: int) -> date | None:
     target_dow = weekday.number
     d = date(year, month, 1)
@@ -174,6 +198,14 @@ def _last_weekday_in_month(year: int, month: int, weekday: Weekday) -> date:
     return d
 
 
+def _ordinal_weekday_in_month(
+    year: int, month: int, ordinal: OrdinalPosition, weekday: Weekday
+) -> date | None:
+    if ordinal == OrdinalPosition.LAST:
+        return _last_weekday_in_month(year, month, weekday)
+    return _nth_weekday_of_month(year, month, weekday, ordinal.to_n())
+
+
 def _nearest_weekday(
     year: int, month: int, target_day: int, direction: NearestDirection | None
 ) -> date | None:
@@ -259,6 +291,25 @@ def _months_between_ym(a: date, b: date) -> int:
     return b.year * 12 + b.month - (a.year * 12 + a.month)
 
 
+def _business_day_index(d: date) -> int:
+    """Number of business days (Monday-Friday) from the week of the epoch to
+    `d`. A weekend date shares the index of the following Monday."""
+    # 1970-01-01 was a Thursday, 3 days after the Monday that starts its week
+    days = _days_between(_EPOCH_DATE, d) + 3
+    return days // 7 * 5 + min(days % 7, 5)
+
+
+def _business_day_date(index: int) -> date:
+    """The weekday with the given business day index."""
+    return _EPOCH_DATE + timedelta(days=index // 5 * 7 + index % 5 - 3)
+
+
+def _weekday_interval_aligned(anchor: date | None, interval: int, d: date) -> bool:
+    """Check whether `d` is a business day an `every N weekdays` schedule fires on."""
+    offset = _business_day_index(d) - _business_day_index(anchor or _EPOCH_DATE)
+    return d.isoweekday() <= 5 and offset >= 0 and offset % interval == 0
+
+
 def _is_excepted(d: date, exceptions: tuple[ExceptionSpec, ...]) -> bool:
     for exc in exceptions:
         match exc:
@@ -269,6 +320,12 @@ def _is_excepted(d: date, exceptions: tuple[ExceptionSpec, ...]) -> bool:
                 exc_date = date.fromisoformat(iso_str)
                 if d == exc_date:
                     return True
+            case WeekdayException(day=wd):
+                if d.isoweekday() == wd.number:
+                    return True
+            case IsoRangeException() | NamedRangeException():
+                if _range_bounds(d, exc) is not None:
+                    return True
     return False
 
 
@@ -276,41 +333,115 @@ def _is_excepted_parsed(
     d: date,
     named: list[tuple[int, int]],
     iso_dates: list[date],
+    weekdays: set[int],
+    ranges: list[IsoRangeException | NamedRangeException],
 ) -> bool:
-    return any(d.month == m and d.day == day for m, day in named) or any(
-        d == iso_d for iso_d in iso_dates
+    return (
+        d.isoweekday() in weekdays
+        or any(d.month == m and d.day == day for m, day in named)
+        or any(d == iso_d for iso_d in iso_dates)
+        or any(_range_bounds(d, r) is not None for r in ranges)
     )
 
 
 def _parse_exceptions(
     exceptions: tuple[ExceptionSpec, ...],
-) -> tuple[list[tuple[int, int]], list[date]]:
+) -> tuple[
+    list[tuple[int, int]], list[date], set[int], list[IsoRangeException | NamedRangeException]
+]:
     named: list[tuple[int, int]] = []
     iso_dates: list[date] = []
+    weekdays: set[int] = set()
+    ranges: list[IsoRangeException | NamedRangeException] = []
     for exc in exceptions:
         match exc:
             case NamedException(month=m, day=day):
                 named.append((m.number, day))
             case IsoException(date=iso_str):
                 iso_dates.append(date.fromisoformat(iso_str))
-    return named, iso_dates
+            case WeekdayException(day=wd):
+                weekdays.add(wd.number)
+            case IsoRangeException() | NamedRangeException():
+                ranges.append(exc)
+    return named, iso_dates, weekdays, ranges
+
+
+def _range_bounds(
+    d: date, exc: IsoRangeException | NamedRangeException
+) -> tuple[date, date] | None:
+    """Concrete bounds of a range exception if it covers `d`."""
+    match exc:
+        case IsoRangeException(start=start_str, end=end_str):
+            start, end = date.fromisoformat(start_str), date.fromisoformat(end_str)
+            return (start, end) if start <= d <= end else None
+        case NamedRangeException(start_month=sm, start_day=sd, end_month=em, end_day=ed):
+            start_key, end_key, key = (sm.number, sd), (em.number, ed), (d.month, d.day)
+            if start_key <= end_key:
+                if not start_key <= key <= end_key:
+                    return None
+                start_year, end_year = d.year, d.year
+            elif key >= start_key:
+                start_year, end_year = d.year, d.year + 1  # wraps into next year
+            elif key <= end_key:
+                start_year, end_year = d.year - 1, d.year
+            else:
+                return None
+            return _clamped_date(start_year, *start_key), _clamped_date(end_year, *end_key)
+
 
+def _clamped_date(year: int, month: int, day: int) -> date:
+    """The date for `month day` in `year`, with days past the month's end
+    (feb 29 in a common year) moved back to its last day."""
+    return date(year, month, min(day, _last_day_of_month(year, month).day))
 
-def _matches_during(d: date, during: tuple[MonthName, ...]) -> bool:
+
+def _skip_exceptions_forward(
+    d: date, ranges: list[IsoRangeException | NamedRangeException]
+) -> date:
+    """The day after the excepted run `d` falls in, so long ranges are
+    skipped in one step rather than day by day."""
+    ends = [b[1] for r in ranges if (b := _range_bounds(d, r)) is not None]
+    return max(ends, default=d) + timedelta(days=1)
+
+
+def _skip_exceptions_backward(
+    d: date, ranges: list[IsoRangeException | NamedRangeException]
+) -> date:
+    """The day before the excepted run `d` falls in."""
+    starts = [b[0] for r in ranges if (b := _range_bounds(d, r)) is not None]
+    return min(starts, default=d) - timedelta(days=1)
+
+
+def _matches_during(d: date, during: tuple[DuringSpec, ...]) -> bool:
+    """Check if a date falls within any `during` spec. If `during` is empty,
+    all dates match."""
     if not during:
         return True
-    return any(mn.number == d.month for mn in during)
+    month = MonthName.from_number(d.month)
+    assert month is not None
+    return any(spec.contains(month, d.day) for spec in during)
+
 
+def _month_day_in_year(year: int, month: MonthName, day: int) -> date:
+    """The month-day in `year`, or the day after the month's end when the day
+    does not exist that year (feb 29 in a common year)."""
+    last = _last_day_of_month(year, month.number)
+    if day > last.day:
+        return last + timedelta(days=1)
+    return date(year, month.number, day)
 
-def _next_during_month(d: date, during: tuple[MonthName, ...]) -> date:
-    current_month = d.month
-    months = sorted(mn.number for mn in during)
 
-    for m in months:
-        if m > current_month:
-            return date(d.year, m, 1)
-    # Wrap to first month of next year
-    return date(d.year + 1, months[0], 1)
+def _next_during_start(d: date, during: tuple[DuringSpec, ...]) -> date:
+    """Find the first day of the next `during` period starting after `d`."""
+    starts: list[date] = []
+    for spec in during:
+        (month, day), _ = spec.bounds()
+        for year in (d.year, d.year + 1):
+            start = _month_day_in_year(year, month, day)
+            if start > d:
+                starts.append(start)
+                break
+    return min(starts, default=d)
 
 
 def _resolve_until(until: UntilSpec, now: datetime) -> date:
@@ -346,14 +477,64 @@ def _earliest_future_at_times(
 # --- Public API ---
 
 
+def _resolve_anchor(schedule: ScheduleData, now: datetime) -> ScheduleData:
+    """Pin a relative anchor to a date in the schedule timezone: `starting today`
+    and `starting now` to `now`'s date, `starting jan 6` to the next jan 6 on or
+    after it."""
+    if not has_relative_anchor(schedule):
+        return schedule
+    tz = _resolve_tz(schedule.timezone)
+    today = now.astimezone(tz).date()
+    named = schedule.anchor_named
+    if named is None:
+        return replace(schedule, anchor=today.isoformat())
+    # Try up to 8 years forward (covers leap year cycles)
+    for y in range(8):
+        try:
+            d = date(today.year + y, named.month.number, named.day)
+        except ValueError:
+            continue
+        if d >= today:
+            return replace(schedule, anchor=d.isoformat(), anchor_named=None)
+    raise HronError.eval(f"starting date {named} never occurs")
+
+
+def _anchor_start(schedule: ScheduleData, tz: ZoneInfo) -> datetime | None:
+    """The instant nothing occurs before: the resolved anchor date at the
+    `starting ... at` time, or at midnight."""
+    if not schedule.anchor:
+        return None
+    return _at_time_on_date(
+        date.fromisoformat(schedule.anchor), schedule.anchor_time or TimeOfDay(0, 0), tz
+    )
+
+
+def _pin_relative(schedule: ScheduleData, now: datetime) -> ScheduleData:
+    """Pin what the schedule resolves against the evaluation time, as seen from
+    `now`: `on <weekday>` becomes the one date it names (the nearest such weekday
+    with a time still ahead), and a relative anchor becomes its date."""
+    schedule = _resolve_anchor(schedule, now)
+    expr = schedule.expr
+    if not isinstance(expr, SingleDateExpr) or not isinstance(expr.date, NextWeekday):
+        return schedule
+    tz = _resolve_tz(schedule.timezone)
+    nxt = _next_single_date(expr.date, expr.times, tz, now)
+    if nxt is None:
+        return schedule
+    pinned = IsoDate(nxt.astimezone(tz).date().isoformat())
+    return replace(schedule, expr=replace(expr, date=pinned))
+
+
 def next_from(schedule: ScheduleData, now: datetime) -> datetime | None:
+    schedule = _resolve_anchor(schedule, now)
     tz = _resolve_tz(schedule.timezone)
 
     until_date = _resolve_until(schedule.until, now) if schedule.until else None
 
-    named_exc, iso_exc = _parse_exceptions(schedule.except_)
+    named_exc, iso_exc, weekday_exc, range_exc = _parse_exceptions(schedule.except_)
     has_exceptions = len(schedule.except_) > 0
     has_during = len(schedule.during) > 0
+    during = schedule.during
 
     # Check if expression is NearestWeekday with direction (can cross month boundaries)
     handles_during_internally = (
@@ -362,9 +543,16 @@ def next_from(schedule: ScheduleData, now: datetime) -> datetime | None:
         and schedule.expr.target.direction is not None
     )
 
+    # Nothing occurs before the starting anchor, so search from its start
     current = now
+    start = _anchor_start(schedule, tz)
+    if start is not None and now < start:
+        current = start - timedelta(microseconds=1)
+
     for _ in range(1000):
-        candidate = _next_expr(schedule.expr, tz, schedule.anchor, current, schedule.during)
+        candidate = _next_expr(
+            schedule.expr, tz, schedule.anchor, current, schedule.during_months(), schedule.except_
+        )
 
         if candidate is None:
             return None
@@ -377,16 +565,19 @@ def next_from(schedule: ScheduleData, now: datetime) -> datetime | None:
 
         # Apply during filter
         # Skip for expressions that handle during internally (NearestWeekday w/ direction)
-        during_mismatch = has_during and not _matches_during(c_date, schedule.during)
+        during_mismatch = has_during and not _matches_during(c_date, during)
         if during_mismatch and not handles_during_internally:
-            skip_to = _next_during_month(c_date, schedule.during)
+            skip_to = _next_during_start(c_date, during)
             midnight = _at_time_on_date(skip_to, TimeOfDay(0, 0), tz)
             current = midnight - timedelta(seconds=1)
             continue
 
         # Apply except filter
-        if has_exceptions and _is_excepted_parsed(c_date, named_exc, iso_exc):
-            next_day = c_date + timedelta(days=1)
+        if has_exceptions and _is_excepted_parsed(
+            c_date, named_exc, iso_exc, weekday_exc, range_exc
+        ):
+            # Advance past this day (or the whole excepted range) and retry
+            next_day = _skip_exceptions_forward(c_date, range_exc)
             midnight = _at_time_on_date(next_day, TimeOfDay(0, 0), tz)
             current = midnight - timedelta(seconds=1)
             continue
@@ -402,10 +593,17 @@ def _next_expr(
     anchor: str | None,
     now: datetime,
     during: tuple[MonthName, ...] = (),
+    exceptions: tuple[ExceptionSpec, ...] = (),
 ) -> datetime | None:
     match expr:
         case DayRepeat(interval=interval, days=days, times=times):
             return _next_day_repeat(interval, days, times, tz, anchor, now)
+        # Day-step windows fire once, at the window start, on each aligned day
+        case IntervalRepeat(
+            interval=interval, unit=IntervalUnit.DAYS, from_time=ft, day_filter=df
+        ):
+            days = DayFilterEvery() if df is None else df
+            return _next_day_repeat(interval, days, (ft,), tz, anchor, now)
         case IntervalRepeat(
             interval=interval,
             unit=unit,
@@ -417,15 +615,22 @@ def _next_expr(
         case WeekRepeat(interval=interval, days=days, times=times):
             return _next_week_repeat(interval, days, times, tz, anchor, now)
         case MonthRepeat(interval=interval, target=target, times=times):
-            return _next_month_repeat(interval, target, times, tz, anchor, now, during)
+            return _next_month_repeat(
+                interval, target, times, tz, anchor, now, during, exceptions
+            )
         case SingleDateExpr(date=date_spec, times=times):
             return _next_single_date(date_spec, times, tz, now)
         case YearRepeat(interval=interval, target=target, times=times):
             return _next_year_repeat(interval, target, times, tz, anchor, now)
+        case IsoWeekRepeat(weeks=weeks, days=days, times=times):
+            return _next_iso_week_repeat(weeks, days, times, tz, now)
+        case WeekdayRepeat(interval=interval, times=times):
+            return _next_weekday_repeat(interval, times, tz, anchor, now)
     return None  # pragma: no cover
 
 
 def next_n_from(schedule: ScheduleData, now: datetime, n: int) -> list[datetime]:
+    schedule = _pin_relative(schedule, now)
     results: list[datetime] = []
     current = now
     for _ in range(n):
@@ -438,6 +643,21 @@ def next_n_from(schedule: ScheduleData, now: datetime, n: int) -> list[datetime]
 
 
 def matches(schedule: ScheduleData, dt: datetime) -> bool:
+    # There is no evaluation time to resolve a relative anchor against;
+    # resolving it against `dt` would make every date its own anchor.
+    if has_relative_anchor(schedule):
+        anchor = schedule.anchor or schedule.anchor_named
+        raise HronError.eval(
+            f"matches has no evaluation time to resolve 'starting {anchor}' "
+            "against; pin the anchor to a date first"
+        )
+    # Nor an `on <weekday>` date: every such weekday would be the upcoming one
+    expr = schedule.expr
+    if isinstance(expr, SingleDateExpr) and isinstance(expr.date, NextWeekday):
+        raise HronError.eval(
+            f"matches has no evaluation time to resolve 'on {expr.date.day.value}' "
+            "against; use a date instead"
+        )
     tz = _resolve_tz(schedule.timezone)
     zdt = dt.astimezone(tz)
     d = zdt.date()
@@ -446,6 +666,10 @@ def matches(schedule: ScheduleData, dt: datetime) -> bool:
         return False
     if _is_excepted(d, schedule.except_):
         return False
+    # Nothing occurs before the starting anchor
+    start = _anchor_start(schedule, tz)
+    if start is not None and dt < start:
+        return False
 
     if schedule.until:
         until_date = _resolve_until(schedule.until, dt)
@@ -477,6 +701,17 @@ def matches(schedule: ScheduleData, dt: datetime) -> bool:
                 return day_offset >= 0 and day_offset % interval == 0
             return True
 
+        case IntervalRepeat(
+            interval=interval, unit=IntervalUnit.DAYS, from_time=ft, day_filter=df
+        ):
+            if df is not None and not _matches_day_filter(d, df):
+                return False
+            if not time_matches_with_dst((ft,)):
+                return False
+            anchor_date = date.fromisoformat(schedule.anchor) if schedule.anchor else _EPOCH_DATE
+            day_offset = _days_between(anchor_date, d)
+            return day_offset >= 0 and day_offset % interval == 0
+
         case IntervalRepeat(
             interval=interval,
             unit=unit,
@@ -522,6 +757,11 @@ def matches(schedule: ScheduleData, dt: datetime) -> bool:
                 case LastDayTarget():
                     last = _last_day_of_month(d.year, d.month)
                     return d == last
+                case LastNDaysTarget(n=n):
+                    last = _last_day_of_month(d.year, d.month)
+                    return d.day > last.day - n
+                case BusinessDayTarget(n=n):
+                    return _nth_business_day(d.year, d.month, n, schedule.except_) == d
                 case LastWeekdayTarget():
                     lwd = _last_weekday_of_month(d.year, d.month)
                     return d == lwd
@@ -529,16 +769,12 @@ def matches(schedule: ScheduleData, dt: datetime) -> bool:
                     target_date = _nearest_weekday(d.year, d.month, target_day, direction)
                     return target_date is not None and d == target_date
                 case OrdinalWeekdayTarget(ordinal=ordinal, weekday=weekday):
-                    ordinal_target: date | None
-                    if ordinal == OrdinalPosition.LAST:
-                        ordinal_target = _last_weekday_in_month(d.year, d.month, weekday)
-                    else:
-                        ordinal_target = _nth_weekday_of_month(
-                            d.year, d.month, weekday, ordinal.to_n()
-                        )
-                    if ordinal_target is None:
-                        return False
-                    return d == ordinal_target
+                    return _ordinal_weekday_in_month(d.year, d.month, ordinal, weekday) == d
+                case OrdinalWeekdaysTarget(ordinals=ordinals, weekday=weekday):
+                    return any(
+                        _ordinal_weekday_in_month(d.year, d.month, o, weekday) == d
+                        for o in ordinals
+                    )
 
         case SingleDateExpr(date=date_spec, times=times):
             if not time_matches_with_dst(times):
@@ -549,6 +785,9 @@ def matches(schedule: ScheduleData, dt: datetime) -> bool:
                     return d == iso_target
                 case NamedDate(month=m, day=day_num):
                     return d.month == m.number and d.day == day_num
+                # `matches` rejects it up front; any day with that weekday can be it
+                case NextWeekday(day=day):
+                    return d.isoweekday() == day.number
             return False  # pragma: no cover
 
         case YearRepeat(interval=interval, target=target, times=times):
@@ -565,6 +804,19 @@ def matches(schedule: ScheduleData, dt: datetime) -> bool:
                     return False
             return _matches_year_target(target, d)
 
+        case WeekdayRepeat(interval=interval, times=times):
+            anchor_date = date.fromisoformat(schedule.anchor) if schedule.anchor else None
+            if not _weekday_interval_aligned(anchor_date, interval, d):
+                return False
+            return time_matches_with_dst(times)
+
+        case IsoWeekRepeat(weeks=weeks, days=days, times=times):
+            if d.isocalendar().week not in weeks:
+                return False
+            if not any(wd.number == d.isoweekday() for wd in days):
+                return False
+            return time_matches_with_dst(times)
+
     return False  # pragma: no cover
 
 
@@ -623,7 +875,8 @@ def _next_day_repeat(
 
         return None
 
-    # Interval > 1: day intervals only apply to DayFilter::Every
+    # Interval > 1: the filter narrows the aligned days, skipping (not
+    # moving) any that fail it
     anchor_date = date.fromisoformat(anchor) if anchor else _EPOCH_DATE
 
     # Find the next aligned day >= today
@@ -632,9 +885,10 @@ def _next_day_repeat(
     aligned_date = d if remainder == 0 else d + timedelta(days=interval - remainder)
 
     for _ in range(400):
-        candidate = _earliest_future_at_times(aligned_date, times, tz, now)
-        if candidate:
-            return candidate
+        if _matches_day_filter(aligned_date, days):
+            candidate = _earliest_future_at_times(aligned_date, times, tz, now)
+            if candidate:
+                return candidate
         aligned_date += timedelta(days=interval)
 
     return None
@@ -738,7 +992,9 @@ def _next_month_repeat(
     anchor: str | None,
     now: datetime,
     during: tuple[MonthName, ...] = (),
+    exceptions: tuple[ExceptionSpec, ...] = (),
 ) -> datetime | None:
+    """Business-day targets skip over days `exceptions` removes."""
     now_in_tz = now.astimezone(tz)
     year = now_in_tz.year
     month = now_in_tz.month
@@ -787,19 +1043,29 @@ def _next_month_repeat(
                             date_candidates.append(date(year, month, day_num))
             case LastDayTarget():
                 date_candidates.append(_last_day_of_month(year, month))
+            case LastNDaysTarget(n=n):
+                last = _last_day_of_month(year, month)
+                for day_num in range(max(last.day - n + 1, 1), last.day + 1):
+                    date_candidates.append(date(year, month, day_num))
             case LastWeekdayTarget():
                 date_candidates.append(_last_weekday_of_month(year, month))
+            case BusinessDayTarget(n=n):
+                business_day = _nth_business_day(year, month, n, exceptions)
+                if business_day is not None:
+                    date_candidates.append(business_day)
             case NearestWeekdayTarget(day=target_day, direction=direction):
                 nearest_date = _nearest_weekday(year, month, target_day, direction)
                 if nearest_date is not None:
                     date_candidates.append(nearest_date)
             case OrdinalWeekdayTarget(ordinal=ordinal, weekday=weekday):
-                if ordinal == OrdinalPosition.LAST:
-                    ordinal_date: date | None = _last_weekday_in_month(year, month, weekday)
-                else:
-                    ordinal_date = _nth_weekday_of_month(year, month, weekday, ordinal.to_n())
+                ordinal_date = _ordinal_weekday_in_month(year, month, ordinal, weekday)
                 if ordinal_date is not None:
                     date_candidates.append(ordinal_date)
+            case OrdinalWeekdaysTarget(ordinals=ordinals, weekday=weekday):
+                for o in ordinals:
+                    ordinal_date = _ordinal_weekday_in_month(year, month, o, weekday)
+                    if ordinal_date is not None and ordinal_date not in date_candidates:
+                        date_candidates.append(ordinal_date)
 
         best: datetime | None = None
         for dc in date_candidates:
@@ -841,6 +1107,17 @@ def _next_single_date(
                 except ValueError:
                     pass
             return None
+        case NextWeekday(day=weekday):
+            # Today if a time is still ahead, otherwise within the next week
+            today = now_in_tz.date()
+            for offset in range(8):
+                d = today + timedelta(days=offset)
+                if d.isoweekday() != weekday.number:
+                    continue
+                candidate = _earliest_future_at_times(d, times, tz, now)
+                if candidate:
+                    return candidate
+            return None
 
     return None  # pragma: no cover
 
@@ -897,6 +1174,63 @@ def _next_year_repeat(
     return None
 
 
+# ISO years searched for a matching week; week 53 only exists in long ISO years.
+_ISO_YEAR_HORIZON = 7
+
+
+def _iso_week_dates(year: int, weeks: tuple[int, ...], days: tuple[Weekday, ...]) -> list[date]:
+    """Dates in ISO week-numbering `year` on one of `days` in one of `weeks`, ascending.
+    Week 53 yields nothing in years with only 52 weeks."""
+    dates: set[date] = set()
+    for week in weeks:
+        for wd in days:
+            try:
+                dates.add(date.fromisocalendar(year, week, wd.number))
+            except ValueError:
+                continue
+    return sorted(dates)
+
+
+def _next_iso_week_repeat(
+    weeks: tuple[int, ...],
+    days: tuple[Weekday, ...],
+    times: tuple[TimeOfDay, ...],
+    tz: ZoneInfo,
+    now: datetime,
+) -> datetime | None:
+    start_year = now.astimezone(tz).date().isocalendar().year
+    for year in range(start_year, start_year + _ISO_YEAR_HORIZON):
+        for d in _iso_week_dates(year, weeks, days):
+            candidate = _earliest_future_at_times(d, times, tz, now)
+            if candidate is not None:
+                return candidate
+    return None
+
+
+def _next_weekday_repeat(
+    interval: int,
+    times: tuple[TimeOfDay, ...],
+    tz: ZoneInfo,
+    anchor: str | None,
+    now: datetime,
+) -> datetime | None:
+    """Today if it is an aligned business day with a time still ahead,
+    otherwise the next aligned business day."""
+    today = now.astimezone(tz).date()
+    anchor_index = _business_day_index(date.fromisoformat(anchor) if anchor else _EPOCH_DATE)
+    today_index = _business_day_index(today)
+    is_weekday = today.isoweekday() <= 5
+
+    if is_weekday and (today_index - anchor_index) % interval == 0:
+        candidate = _earliest_future_at_times(today, times, tz, now)
+        if candidate is not None:
+            return candidate
+    # A weekend already shares the following Monday's index
+    after = today_index + 1 if is_weekday else today_index
+    index = after + (anchor_index - after) % interval
+    return _earliest_future_at_times(_business_day_date(index), times, tz, now)
+
+
 # --- Iterator functions ---
 
 
@@ -906,6 +1240,7 @@ def occurrences(schedule: ScheduleData, from_: datetime) -> Iterator[datetime]:
     The iterator is unbounded for repeating schedules (will iterate forever unless limited),
     but respects the `until` clause if specified in the schedule.
     """
+    schedule = _pin_relative(schedule, from_)
     current = from_
     while True:
         nxt = next_from(schedule, current)
@@ -934,18 +1269,24 @@ def previous_from(schedule: ScheduleData, now: datetime) -> datetime | None:
     """Compute the most recent occurrence strictly before `now`.
 
     Returns None if no previous occurrence exists (e.g., before a starting anchor
-    or for single dates in the future).
+    or for single dates in the future). With `starting now` nothing occurs
+    before `now`, so there is never a previous occurrence.
     """
+    if schedule.anchor == "now":
+        return None
+    schedule = _pin_relative(schedule, now)
     tz = _resolve_tz(schedule.timezone)
     anchor = schedule.anchor
+    start = _anchor_start(schedule, tz)
 
-    named_exc, iso_exc = _parse_exceptions(schedule.except_)
+    named_exc, iso_exc, weekday_exc, range_exc = _parse_exceptions(schedule.except_)
     has_exceptions = len(schedule.except_) > 0
     has_during = len(schedule.during) > 0
+    during = schedule.during
 
     current = now
     for _ in range(1000):
-        candidate = _prev_expr(schedule.expr, tz, anchor, current)
+        candidate = _prev_expr(schedule.expr, tz, anchor, current, schedule.except_)
 
         if candidate is None:
             return None
@@ -953,10 +1294,8 @@ def previous_from(schedule: ScheduleData, now: datetime) -> datetime | None:
         c_date = candidate.astimezone(tz).date()
 
         # Check starting anchor - if before anchor, no previous occurrence
-        if anchor:
-            anchor_date = date.fromisoformat(anchor)
-            if c_date < anchor_date:
-                return None
+        if start is not None and candidate < start:
+            return None
 
         # Apply until filter for previousFrom:
         # If candidate is after until, search earlier
@@ -968,14 +1307,17 @@ def previous_from(schedule: ScheduleData, now: datetime) -> datetime | None:
                 continue
 
         # Apply during filter
-        if has_during and not _matches_during(c_date, schedule.during):
-            skip_to = _prev_during_month(c_date, schedule.during)
+        if has_during and not _matches_during(c_date, during):
+            skip_to = _prev_during_end(c_date, during)
             current = _at_time_on_date(skip_to, TimeOfDay(23, 59), tz) + timedelta(seconds=1)
             continue
 
         # Apply except filter
-        if has_exceptions and _is_excepted_parsed(c_date, named_exc, iso_exc):
-            prev_day = c_date - timedelta(days=1)
+        if has_exceptions and _is_excepted_parsed(
+            c_date, named_exc, iso_exc, weekday_exc, range_exc
+        ):
+            # Go back before this day (or the whole excepted range) and retry
+            prev_day = _skip_exceptions_backward(c_date, range_exc)
             current = _at_time_on_date(prev_day, TimeOfDay(23, 59), tz) + timedelta(seconds=1)
             continue
 
@@ -989,10 +1331,16 @@ def _prev_expr(
     tz: ZoneInfo,
     anchor: str | None,
     now: datetime,
+    exceptions: tuple[ExceptionSpec, ...] = (),
 ) -> datetime | None:
     match expr:
         case DayRepeat(interval=interval, days=days, times=times):
             return _prev_day_repeat(interval, days, times, tz, anchor, now)
+        case IntervalRepeat(
+            interval=interval, unit=IntervalUnit.DAYS, from_time=ft, day_filter=df
+        ):
+            days = DayFilterEvery() if df is None else df
+            return _prev_day_repeat(interval, days, (ft,), tz, anchor, now)
         case IntervalRepeat(
             interval=interval,
             unit=unit,
@@ -1004,33 +1352,30 @@ def _prev_expr(
         case WeekRepeat(interval=interval, days=days, times=times):
             return _prev_week_repeat(interval, days, times, tz, anchor, now)
         case MonthRepeat(interval=interval, target=target, times=times):
-            return _prev_month_repeat(interval, target, times, tz, anchor, now)
+            return _prev_month_repeat(interval, target, times, tz, anchor, now, exceptions)
         case SingleDateExpr(date=date_spec, times=times):
             return _prev_single_date(date_spec, times, tz, now)
         case YearRepeat(interval=interval, target=target, times=times):
             return _prev_year_repeat(interval, target, times, tz, anchor, now)
+        case IsoWeekRepeat(weeks=weeks, days=days, times=times):
+            return _prev_iso_week_repeat(weeks, days, times, tz, now)
+        case WeekdayRepeat(interval=interval, times=times):
+            return _prev_weekday_repeat(interval, times, tz, anchor, now)
     return None  # pragma: no cover
 
 
-def _prev_during_month(d: date, during: tuple[MonthName, ...]) -> date:
-    """Find the last day of the previous month in the during list."""
-    during_months = sorted((mn.number for mn in during), reverse=True)
-    year = d.year
-    month = d.month - 1
-    if month < 1:
-        month = 12
-        year -= 1
-
-    # Search backwards through months to find one in the during list
-    for _ in range(13):
-        if month in during_months:
-            return _last_day_of_month(year, month)
-        month -= 1
-        if month < 1:
-            month = 12
-            year -= 1
-
-    return d - timedelta(days=1)
+def _prev_during_end(d: date, during: tuple[DuringSpec, ...]) -> date:
+    """Find the last day of the previous `during` period ending before `d`."""
+    ends: list[date] = []
+    for spec in during:
+        _, (month, day) = spec.bounds()
+        for year in (d.year, d.year - 1):
+            last = _last_day_of_month(year, month.number)
+            end = last.replace(day=min(day, last.day))
+            if end < d:
+                ends.append(end)
+                break
+    return max(ends, default=d)
 
 
 def _latest_past_at_times(
@@ -1095,13 +1440,13 @@ def _prev_day_repeat(
     remainder = offset % interval
     aligned_date = d if remainder == 0 else d - timedelta(days=remainder)
 
-    for _ in range(2):
-        candidate = _latest_past_at_times(aligned_date, times, tz, now)
-        if candidate is not None:
-            return candidate
-        latest = _latest_at_times(aligned_date, times, tz)
-        if latest is not None and latest < now:
-            return latest
+    # Eight aligned dates cover every weekday unless the interval is a
+    # multiple of 7, which the parser rejects when the filter can't match
+    for _ in range(8):
+        if _matches_day_filter(aligned_date, days):
+            candidate = _latest_past_at_times(aligned_date, times, tz, now)
+            if candidate is not None:
+                return candidate
         aligned_date -= timedelta(days=interval)
 
     return None
@@ -1207,6 +1552,7 @@ def _prev_month_repeat(
     tz: ZoneInfo,
     anchor: str | None,
     now: datetime,
+    exceptions: tuple[ExceptionSpec, ...] = (),
 ) -> datetime | None:
     now_in_tz = now.astimezone(tz)
     start_date = now_in_tz.date()
@@ -1240,19 +1586,29 @@ def _prev_month_repeat(
                             date_candidates.append(date(year, month, day_num))
             case LastDayTarget():
                 date_candidates.append(_last_day_of_month(year, month))
+            case LastNDaysTarget(n=n):
+                last = _last_day_of_month(year, month)
+                for day_num in range(max(last.day - n + 1, 1), last.day + 1):
+                    date_candidates.append(date(year, month, day_num))
             case LastWeekdayTarget():
                 date_candidates.append(_last_weekday_of_month(year, month))
+            case BusinessDayTarget(n=n):
+                business_day = _nth_business_day(year, month, n, exceptions)
+                if business_day is not None:
+                    date_candidates.append(business_day)
             case NearestWeekdayTarget(day=target_day, direction=direction):
                 nearest_date = _nearest_weekday(year, month, target_day, direction)
                 if nearest_date is not None:
                     date_candidates.append(nearest_date)
             case OrdinalWeekdayTarget(ordinal=ordinal, weekday=weekday):
-                if ordinal == OrdinalPosition.LAST:
-                    ordinal_date: date | None = _last_weekday_in_month(year, month, weekday)
-                else:
-                    ordinal_date = _nth_weekday_of_month(year, month, weekday, ordinal.to_n())
+                ordinal_date = _ordinal_weekday_in_month(year, month, ordinal, weekday)
                 if ordinal_date is not None:
                     date_candidates.append(ordinal_date)
+            case OrdinalWeekdaysTarget(ordinals=ordinals, weekday=weekday):
+                for o in ordinals:
+                    ordinal_date = _ordinal_weekday_in_month(year, month, o, weekday)
+                    if ordinal_date is not None and ordinal_date not in date_candidates:
+                        date_candidates.append(ordinal_date)
 
         # Sort in reverse order for latest first
         for dc in sorted(date_candidates, reverse=True):
@@ -1316,6 +1672,9 @@ def _prev_single_date(
             if target_date is not None:
                 return _latest_at_times(target_date, times, tz)
             return None
+        # `previous_from` pins `on <weekday>` to an ISO date first
+        case NextWeekday():
+            return None
 
     return None  # pragma: no cover
 
@@ -1378,3 +1737,40 @@ def _prev_year_repeat(
                     return candidate
 
     return None
+
+
+def _prev_weekday_repeat(
+    interval: int,
+    times: tuple[TimeOfDay, ...],
+    tz: ZoneInfo,
+    anchor: str | None,
+    now: datetime,
+) -> datetime | None:
+    today = now.astimezone(tz).date()
+    anchor_index = _business_day_index(date.fromisoformat(anchor) if anchor else _EPOCH_DATE)
+    today_index = _business_day_index(today)
+
+    if today.isoweekday() <= 5 and (today_index - anchor_index) % interval == 0:
+        candidate = _latest_past_at_times(today, times, tz, now)
+        if candidate is not None:
+            return candidate
+    # On a weekend the index is the following Monday's, so one back is Friday
+    before = today_index - 1
+    index = before - (before - anchor_index) % interval
+    return _latest_at_times(_business_day_date(index), times, tz)
+
+
+def _prev_iso_week_repeat(
+    weeks: tuple[int, ...],
+    days: tuple[Weekday, ...],
+    times: tuple[TimeOfDay, ...],
+    tz: ZoneInfo,
+    now: datetime,
+) -> datetime | None:
+    start_year = now.astimezone(tz).date().isocalendar().year
+    for year in range(start_year, start_year - _ISO_YEAR_HORIZON, -1):
+        for d in reversed(_iso_week_dates(year, weeks, days)):
+            candidate = _latest_past_at_times(d, times, tz, now)
+            if candidate is not None:
+                return candidate
+    return None
@@ -2,7 +2,7 @@ from __future__ import annotations
 
 from dataclasses import dataclass
 
-from ._ast import IntervalUnit, MonthName, OrdinalPosition, Weekday
+from ._ast import IntervalUnit, MonthName, Weekday
 from ._error import HronError, Span
 
 # --- Token kinds ---
@@ -33,6 +33,21 @@ class TTo:
     pass
 
 
+@dataclass(frozen=True, slots=True)
+class TThrough:
+    pass
+
+
+@dataclass(frozen=True, slots=True)
+class TBusiness:
+    pass
+
+
+@dataclass(frozen=True, slots=True)
+class TPast:
+    pass
+
+
 @dataclass(frozen=True, slots=True)
 class TIn:
     pass
@@ -88,6 +103,21 @@ class TPrevious:
     pass
 
 
+@dataclass(frozen=True, slots=True)
+class TBetween:
+    pass
+
+
+@dataclass(frozen=True, slots=True)
+class TAnd:
+    pass
+
+
+@dataclass(frozen=True, slots=True)
+class TIso:
+    pass
+
+
 @dataclass(frozen=True, slots=True)
 class TYear:
     pass
@@ -118,6 +148,26 @@ class TMonth:
     pass
 
 
+@dataclass(frozen=True, slots=True)
+class TFrequency:
+    word: str  # "daily", "weekly", "monthly", "yearly", "hourly"
+
+
+@dataclass(frozen=True, slots=True)
+class TShortcut:
+    name: str  # "@daily", "@hourly", ... (lowercase, with the "@")
+
+
+@dataclass(frozen=True, slots=True)
+class TPeriod:
+    word: str  # "morning", "afternoon", "evening", "night"
+
+
+@dataclass(frozen=True, slots=True)
+class TRelative:
+    word: str  # "today", "now"
+
+
 @dataclass(frozen=True, slots=True)
 class TDayName:
     name: Weekday
@@ -130,7 +180,7 @@ class TMonthName:
 
 @dataclass(frozen=True, slots=True)
 class TOrdinal:
-    name: OrdinalPosition
+    word: str
 
 
 @dataclass(frozen=True, slots=True)
@@ -143,6 +193,16 @@ class TNumber:
     value: int
 
 
+@dataclass(frozen=True, slots=True)
+class TDecimal:
+    text: str  # 1.5 — raw text, converted by the parser
+
+
+@dataclass(frozen=True, slots=True)
+class TMinuteOffset:
+    minute: int  # :15 — minutes past the hour
+
+
 @dataclass(frozen=True, slots=True)
 class TOrdinalNumber:
     value: int
@@ -175,6 +235,9 @@ TokenKind = (
     | TAt
     | TFrom
     | TTo
+    | TThrough
+    | TBusiness
+    | TPast
     | TIn
     | TOf
     | TThe
@@ -186,17 +249,26 @@ TokenKind = (
     | TNearest
     | TNext
     | TPrevious
+    | TBetween
+    | TAnd
+    | TIso
     | TYear
     | TDay
     | TWeekday
     | TWeekend
     | TWeeks
     | TMonth
+    | TFrequency
+    | TShortcut
+    | TPeriod
+    | TRelative
     | TDayName
     | TMonthName
     | TOrdinal
     | TIntervalUnit
     | TNumber
+    | TDecimal
+    | TMinuteOffset
     | TOrdinalNumber
     | TTime
     | TIsoDate
@@ -219,6 +291,9 @@ _KEYWORD_MAP: dict[str, TokenKind] = {
     "at": TAt(),
     "from": TFrom(),
     "to": TTo(),
+    "through": TThrough(),
+    "business": TBusiness(),
+    "past": TPast(),
     "in": TIn(),
     "of": TOf(),
     "the": TThe(),
@@ -230,6 +305,9 @@ _KEYWORD_MAP: dict[str, TokenKind] = {
     "nearest": TNearest(),
     "next": TNext(),
     "previous": TPrevious(),
+    "between": TBetween(),
+    "and": TAnd(),
+    "iso": TIso(),
     "year": TYear(),
     "years": TYear(),
     "day": TDay(),
@@ -242,20 +320,38 @@ _KEYWORD_MAP: dict[str, TokenKind] = {
     "week": TWeeks(),
     "month": TMonth(),
     "months": TMonth(),
+    "daily": TFrequency("daily"),
+    "weekly": TFrequency("weekly"),
+    "monthly": TFrequency("monthly"),
+    "yearly": TFrequency("yearly"),
+    "hourly": TFrequency("hourly"),
+    "morning": TPeriod("morning"),
+    "afternoon": TPeriod("afternoon"),
+    "evening": TPeriod("evening"),
+    "night": TPeriod("night"),
+    "today": TRelative("today"),
+    "now": TRelative("now"),
     # Day names
     "monday": TDayName(Weekday.MONDAY),
+    "mondays": TDayName(Weekday.MONDAY),
     "mon": TDayName(Weekday.MONDAY),
     "tuesday": TDayName(Weekday.TUESDAY),
+    "tuesdays": TDayName(Weekday.TUESDAY),
     "tue": TDayName(Weekday.TUESDAY),
     "wednesday": TDayName(Weekday.WEDNESDAY),
+    "wednesdays": TDayName(Weekday.WEDNESDAY),
     "wed": TDayName(Weekday.WEDNESDAY),
     "thursday": TDayName(Weekday.THURSDAY),
+    "thursdays": TDayName(Weekday.THURSDAY),
     "thu": TDayName(Weekday.THURSDAY),
     "friday": TDayName(Weekday.FRIDAY),
+    "fridays": TDayName(Weekday.FRIDAY),
     "fri": TDayName(Weekday.FRIDAY),
     "saturday": TDayName(Weekday.SATURDAY),
+    "saturdays": TDayName(Weekday.SATURDAY),
     "sat": TDayName(Weekday.SATURDAY),
     "sunday": TDayName(Weekday.SUNDAY),
+    "sundays": TDayName(Weekday.SUNDAY),
     "sun": TDayName(Weekday.SUNDAY),
     # Month names
     "january": TMonthName(MonthName.JAN),
@@ -281,12 +377,6 @@ _KEYWORD_MAP: dict[str, TokenKind] = {
     "nov": TMonthName(MonthName.NOV),
     "december": TMonthName(MonthName.DEC),
     "dec": TMonthName(MonthName.DEC),
-    # Ordinals
-    "first": TOrdinal(OrdinalPosition.FIRST),
-    "second": TOrdinal(OrdinalPosition.SECOND),
-    "third": TOrdinal(OrdinalPosition.THIRD),
-    "fourth": TOrdinal(OrdinalPosition.FOURTH),
-    "fifth": TOrdinal(OrdinalPosition.FIFTH),
     # Interval units
     "min": TIntervalUnit(IntervalUnit.MIN),
     "mins": TIntervalUnit(IntervalUnit.MIN),
@@ -329,6 +419,16 @@ class _Lexer:
                 tokens.append(self._lex_number_or_time_or_date())
                 continue
 
+            # Minutes past the hour: :MM
+            # Cron shortcut: @daily
+            if ch == "@":
+                tokens.append(self._lex_shortcut())
+                continue
+
+            if ch == ":":
+                tokens.append(self._lex_minute_offset())
+                continue
+
             if ch.isascii() and ch.isalpha():
                 tokens.append(self._lex_word())
                 continue
@@ -341,6 +441,13 @@ class _Lexer:
 
         return tokens
 
+    def _next_word_is(self, word: str) -> bool:
+        rest = self._input[self._pos :].lstrip()
+        if rest[: len(word)].lower() != word:
+            return False
+        after = rest[len(word) : len(word) + 1]
+        return not (after.isascii() and after.isalnum() or after == "_")
+
     def _skip_whitespace(self) -> None:
         while self._pos < len(self._input) and self._input[self._pos] in " \t\n\r":
             self._pos += 1
@@ -359,6 +466,29 @@ class _Lexer:
             )
         return Token(TTimezone(tz), Span(start, self._pos))
 
+    def _lex_shortcut(self) -> Token:
+        start = self._pos
+        self._pos += 1  # skip '@'
+        while self._pos < len(self._input) and (
+            self._input[self._pos].isascii() and self._input[self._pos].isalnum()
+        ):
+            self._pos += 1
+        return Token(TShortcut(self._input[start : self._pos].lower()), Span(start, self._pos))
+
+    def _lex_minute_offset(self) -> Token:
+        start = self._pos
+        self._pos += 1  # skip ':'
+        while self._pos < len(self._input) and self._input[self._pos].isdigit():
+            self._pos += 1
+        digits = self._input[start + 1 : self._pos]
+        if len(digits) != 2 or int(digits) > 59:
+            raise HronError.lex(
+                "expected two-digit minute (:00 to :59) after ':'",
+                Span(start, max(self._pos, start + 1)),
+                self._input,
+            )
+        return Token(TMinuteOffset(int(digits)), Span(start, self._pos))
+
     def _lex_number_or_time_or_date(self) -> Token:
         start = self._pos
         num_start = self._pos
@@ -391,10 +521,30 @@ class _Lexer:
             if len(min_digits) == 2:
                 hour = int(digits)
                 minute = int(min_digits)
+                pm = self._lex_meridiem()
+                if pm is not None:
+                    return self._meridiem_time(hour, minute, pm, start)
                 if hour > 23 or minute > 59:
                     raise HronError.lex("invalid time", Span(start, self._pos), self._input)
                 return Token(TTime(hour, minute), Span(start, self._pos))
 
+        # Check for 12-hour time without minutes: 9am, 5pm
+        if len(digits) <= 2:
+            pm = self._lex_meridiem()
+            if pm is not None:
+                return self._meridiem_time(int(digits), 0, pm, start)
+
+        # Check for decimal: 1.5
+        if (
+            self._pos + 1 < len(self._input)
+            and self._input[self._pos] == "."
+            and self._input[self._pos + 1].isdigit()
+        ):
+            self._pos += 1  # skip '.'
+            while self._pos < len(self._input) and self._input[self._pos].isdigit():
+                self._pos += 1
+            return Token(TDecimal(self._input[start : self._pos]), Span(start, self._pos))
+
         num = int(digits)
 
         # Check for ordinal suffix: st, nd, rd, th
@@ -406,25 +556,104 @@ class _Lexer:
 
         return Token(TNumber(num), Span(start, self._pos))
 
+    def _lex_meridiem(self) -> bool | None:
+        """Consume an am/pm suffix directly following a time, returning whether it was pm."""
+        suffix = self._input[self._pos : self._pos + 2].lower()
+        if suffix not in ("am", "pm"):
+            return None
+        # Must not run into a longer word (e.g. "9amx")
+        after = self._input[self._pos + 2 : self._pos + 3]
+        if after.isascii() and after.isalnum():
+            return None
+        self._pos += 2
+        return suffix == "pm"
+
+    def _meridiem_time(self, hour: int, minute: int, pm: bool, start: int) -> Token:
+        """Convert a 12-hour clock time to a 24-hour time token."""
+        if hour < 1 or hour > 12 or minute > 59:
+            raise HronError.lex(
+                "invalid 12-hour time (hour must be 1-12)",
+                Span(start, self._pos),
+                self._input,
+            )
+        if hour == 12:
+            hour = 12 if pm else 0
+        elif pm:
+            hour += 12
+        return Token(TTime(hour, minute), Span(start, self._pos))
+
     def _lex_word(self) -> Token:
         start = self._pos
         while self._pos < len(self._input) and (
             (self._input[self._pos].isascii() and self._input[self._pos].isalnum())
             or self._input[self._pos] == "_"
+            # hyphenated ordinals: "twenty-first"
+            or (
+                self._input[self._pos] == "-"
+                and self._pos + 1 < len(self._input)
+                and self._input[self._pos + 1].isascii()
+                and self._input[self._pos + 1].isalpha()
+            )
         ):
             self._pos += 1
         word = self._input[start : self._pos].lower()
         span = Span(start, self._pos)
 
         kind = _KEYWORD_MAP.get(word)
+        if kind is None and ordinal_word_value(word) is not None:
+            kind = TOrdinal(word)
         if kind is None:
             raise HronError.lex(f"unknown keyword '{word}'", span, self._input)
 
         if isinstance(kind, TIn):
-            self._after_in = True
+            # "in the morning" and "in iso weeks" are not timezone clauses
+            self._after_in = not self._next_word_is("the") and not self._next_word_is("iso")
 
         return Token(kind, span)
 
 
 def tokenize(input_text: str) -> list[Token]:
     return _Lexer(input_text).tokenize()
+
+
+_ORDINAL_UNITS = (
+    "first",
+    "second",
+    "third",
+    "fourth",
+    "fifth",
+    "sixth",
+    "seventh",
+    "eighth",
+    "ninth",
+)
+_ORDINAL_TEENS = (
+    "tenth",
+    "eleventh",
+    "twelfth",
+    "thirteenth",
+    "fourteenth",
+    "fifteenth",
+    "sixteenth",
+    "seventeenth",
+    "eighteenth",
+    "nineteenth",
+)
+
+
+def ordinal_word_value(word: str) -> int | None:
+    """Day number of an ordinal word: ``first`` is 1, ``thirty-first`` is 31."""
+    if word == "twentieth":
+        return 20
+    if word == "thirtieth":
+        return 30
+    if word == "thirty-first":
+        return 31
+    if word.startswith("twenty-"):
+        unit = word[len("twenty-") :]
+        return 21 + _ORDINAL_UNITS.index(unit) if unit in _ORDINAL_UNITS else None
+    if word in _ORDINAL_UNITS:
+        return 1 + _ORDINAL_UNITS.index(word)
+    if word in _ORDINAL_TEENS:
+        return 10 + _ORDINAL_TEENS.index(word)
+    return None
@@ -1,8 +1,14 @@
 from __future__ import annotations
 
 import datetime
+from collections.abc import Callable
+from decimal import Decimal
+from typing import TypeVar
 
 from ._ast import (
+    ALL_WEEKDAYS,
+    ALL_WEEKEND,
+    BusinessDayTarget,
     DateSpec,
     DayFilter,
     DayFilterDays,
@@ -13,23 +19,31 @@ from ._ast import (
     DayRange,
     DayRepeat,
     DaysTarget,
+    DuringSpec,
     ExceptionSpec,
     IntervalRepeat,
+    IntervalUnit,
     IsoDate,
     IsoException,
+    IsoRangeException,
     IsoUntil,
+    IsoWeekRepeat,
     LastDayTarget,
+    LastNDaysTarget,
     LastWeekdayTarget,
     MonthName,
     MonthRepeat,
     MonthTarget,
     NamedDate,
+    NextWeekday,
     NamedException,
+    NamedRangeException,
     NamedUntil,
     NearestDirection,
     NearestWeekdayTarget,
     OrdinalPosition,
     OrdinalWeekdayTarget,
+    OrdinalWeekdaysTarget,
     ScheduleData,
     ScheduleExpr,
     SingleDateExpr,
@@ -37,29 +51,41 @@ from ._ast import (
     TimeOfDay,
     UntilSpec,
     Weekday,
+    WeekdayException,
+    WeekdayRepeat,
     WeekRepeat,
     YearDateTarget,
     YearDayOfMonthTarget,
     YearLastWeekdayTarget,
     YearOrdinalWeekdayTarget,
     YearRepeat,
+    canonical_day_filter,
+    has_relative_anchor,
     new_schedule_data,
 )
+from ._cron import cron_shortcut_expr
 from ._error import HronError, Span
 from ._lexer import (
+    TAnd,
     TAt,
+    TBetween,
+    TBusiness,
     TComma,
     TDay,
     TDayName,
+    TDecimal,
     TDuring,
     TEvery,
     TExcept,
+    TFrequency,
     TFrom,
     TIn,
     TIntervalUnit,
+    TIso,
     TIsoDate,
     TLast,
     TMonth,
+    TMinuteOffset,
     TMonthName,
     TNearest,
     TNext,
@@ -70,21 +96,47 @@ from ._lexer import (
     TOn,
     TOrdinal,
     TOrdinalNumber,
+    TPast,
+    TPeriod,
     TPrevious,
+    TRelative,
+    TShortcut,
     TStarting,
     TThe,
     TTime,
     TTimezone,
+    TThrough,
     TTo,
     TUntil,
     TWeekday,
     TWeekend,
     TWeeks,
     TYear,
+    ordinal_word_value,
     tokenize,
 )
 
 
+_T = TypeVar("_T")
+
+# Input-only sugar: "in the <period>" fires at a fixed time and displays as HH:MM.
+_PERIOD_TIMES: dict[str, TimeOfDay] = {
+    "morning": TimeOfDay(9, 0),
+    "afternoon": TimeOfDay(14, 0),
+    "evening": TimeOfDay(18, 0),
+    "night": TimeOfDay(21, 0),
+}
+
+
+_ORDINAL_POSITIONS: dict[str, OrdinalPosition] = {
+    "first": OrdinalPosition.FIRST,
+    "second": OrdinalPosition.SECOND,
+    "third": OrdinalPosition.THIRD,
+    "fourth": OrdinalPosition.FOURTH,
+    "fifth": OrdinalPosition.FIFTH,
+}
+
+
 class _Parser:
     def __init__(self, tokens: list[Token], input_text: str) -> None:
         self._tokens = tokens
@@ -149,8 +201,15 @@ class _Parser:
             case TOn():
                 self.advance()
                 expr = self._parse_on()
+            case TFrequency():
+                expr = self._parse_frequency()
+            case TShortcut():
+                expr = self._parse_shortcut()
+            case TAt():
+                self.advance()
+                expr = self._parse_past_every_hour()
             case _:
-                raise self._error("expected 'every' or 'on'", span)
+                raise self._error("expected 'every', 'on', 'at', or a frequency keyword", span)
 
         return self._parse_trailing_clauses(expr)
 
@@ -175,15 +234,31 @@ class _Parser:
                 self._validate_iso_date(k.date)
                 schedule.anchor = k.date
                 self.advance()
+            elif isinstance(k, TMonthName):
+                month = k.name
+                self.advance()
+                day_pos = self.current_span().start
+                day = self._parse_day_number("expected day number after month name in starting")
+                self._validate_named_date(month, day, day_pos)
+                schedule.anchor_named = NamedDate(month, day)
+            elif isinstance(k, TRelative):
+                schedule.anchor = k.word
+                self.advance()
             else:
                 raise self._error(
-                    "expected ISO date (YYYY-MM-DD) after 'starting'", self.current_span()
+                    "expected ISO date (YYYY-MM-DD), month-day, 'today', or 'now' after 'starting'",
+                    self.current_span(),
                 )
+            if isinstance(self.peek_kind(), TAt):
+                if schedule.anchor == "now":
+                    raise self._error("'starting now' already includes a time", self.current_span())
+                self.advance()
+                schedule.anchor_time = self._parse_time()
 
         # during
         if isinstance(self.peek_kind(), TDuring):
             self.advance()
-            schedule.during = tuple(self._parse_month_list())
+            schedule.during = tuple(self._parse_during_list())
 
         # in <timezone>
         if isinstance(self.peek_kind(), TIn):
@@ -195,13 +270,69 @@ class _Parser:
             else:
                 raise self._error("expected timezone after 'in'", self.current_span())
 
+        self._check_day_steps_reachable(schedule)
         return schedule
 
+    def _check_day_steps_reachable(self, schedule: ScheduleData) -> None:
+        """Reject a day step whose filter excludes every aligned day. A step that
+        is a multiple of 7 lands on the anchor's weekday every time, so a
+        filter without that weekday never fires. With `starting today` or
+        `starting now` that weekday depends on the evaluation date, so such a
+        step needs a filter that lets every day through."""
+        expr = schedule.expr
+        match expr:
+            case DayRepeat():
+                days = expr.days
+            case IntervalRepeat(unit=IntervalUnit.DAYS, day_filter=filter_) if filter_:
+                days = filter_
+            case _:
+                return
+        if expr.interval % 7 != 0:
+            return
+        match days:
+            case DayFilterEvery():
+                allowed: tuple[Weekday, ...] = tuple(Weekday)
+            case DayFilterWeekday():
+                allowed = ALL_WEEKDAYS
+            case DayFilterWeekend():
+                allowed = ALL_WEEKEND
+            case DayFilterDays():
+                allowed = days.days
+        if has_relative_anchor(schedule):
+            if set(allowed) == set(Weekday):
+                return
+            relative = schedule.anchor or schedule.anchor_named
+            raise HronError.parse(
+                f"every {expr.interval} days starting {relative} lands on"
+                " the weekday of the evaluation date, which the day filter may"
+                " exclude",
+                Span(0, len(self._input)),
+                self._input,
+            )
+        anchor = (
+            datetime.date.fromisoformat(schedule.anchor)
+            if schedule.anchor
+            else datetime.date(1970, 1, 1)
+        )
+        weekday = Weekday.from_number(anchor.isoweekday())
+        assert weekday is not None
+        if weekday in allowed:
+            return
+        raise HronError.parse(
+            f"every {expr.interval} days always lands on a {weekday.value},"
+            " which the day filter excludes",
+            Span(0, len(self._input)),
+            self._input,
+        )
+
+    # "except [on] exception, ...": dates, day names, "weekday", "weekend"
     def _parse_exception_list(self) -> list[ExceptionSpec]:
-        exceptions: list[ExceptionSpec] = [self._parse_exception()]
+        if isinstance(self.peek_kind(), TOn):
+            self.advance()
+        exceptions: list[ExceptionSpec] = self._parse_exception()
         while isinstance(self.peek_kind(), TComma):
             self.advance()
-            exceptions.append(self._parse_exception())
+            exceptions.extend(self._parse_exception())
         return exceptions
 
     def _validate_iso_date(self, date_str: str) -> None:
@@ -210,20 +341,53 @@ class _Parser:
         except ValueError:
             raise self._error(f"invalid date: {date_str}", self.current_span()) from None
 
-    def _parse_exception(self) -> ExceptionSpec:
+    def _parse_exception(self) -> list[ExceptionSpec]:
         k = self.peek_kind()
         if isinstance(k, TIsoDate):
             self._validate_iso_date(k.date)
             self.advance()
-            return IsoException(k.date)
+            if not self._at_range_separator():
+                return [IsoException(k.date)]
+            self.advance()
+            span = self.current_span()
+            end = self.peek_kind()
+            if not isinstance(end, TIsoDate):
+                raise self._error("expected ISO date after 'to'", span)
+            self._validate_iso_date(end.date)
+            # ISO dates order lexically
+            if end.date < k.date:
+                raise self._error(f"exception range ends before {k.date}", span)
+            self.advance()
+            return [IsoRangeException(k.date, end.date)]
         if isinstance(k, TMonthName):
             month = k.name
             self.advance()
             day_pos = self.current_span().start
             day = self._parse_day_number("expected day number after month name in exception")
             self._validate_named_date(month, day, day_pos)
-            return NamedException(month, day)
-        raise self._error("expected ISO date or month-day in exception", self.current_span())
+            if not self._at_range_separator():
+                return [NamedException(month, day)]
+            self.advance()
+            end_month = self.peek_kind()
+            if not isinstance(end_month, TMonthName):
+                raise self._error("expected month name after 'to'", self.current_span())
+            self.advance()
+            end_pos = self.current_span().start
+            end_day = self._parse_day_number("expected day number after month name in exception")
+            self._validate_named_date(end_month.name, end_day, end_pos)
+            return [NamedRangeException(month, day, end_month.name, end_day)]
+        if isinstance(k, TDayName):
+            self.advance()
+            return [WeekdayException(k.name)]
+        if isinstance(k, TWeekday):
+            self.advance()
+            return [WeekdayException(d) for d in ALL_WEEKDAYS]
+        if isinstance(k, TWeekend):
+            self.advance()
+            return [WeekdayException(d) for d in ALL_WEEKEND]
+        raise self._error(
+            "expected ISO date, month-day, or day name in exception", self.current_span()
+        )
 
     def _parse_until_spec(self) -> UntilSpec:
         k = self.peek_kind()
@@ -241,21 +405,7 @@ class _Parser:
         raise self._error("expected ISO date or month-day after 'until'", self.current_span())
 
     def _validate_named_date(self, month: MonthName, day: int, pos: int) -> None:
-        max_days = {
-            MonthName.JAN: 31,
-            MonthName.FEB: 29,
-            MonthName.MAR: 31,
-            MonthName.APR: 30,
-            MonthName.MAY: 31,
-            MonthName.JUN: 30,
-            MonthName.JUL: 31,
-            MonthName.AUG: 31,
-            MonthName.SEP: 30,
-            MonthName.OCT: 31,
-            MonthName.NOV: 30,
-            MonthName.DEC: 31,
-        }
-        mx = max_days[month]
+        mx = month.max_day
         if day > mx:
             raise self._error(
                 f"invalid day {day} for {month.value} (max {mx})",
@@ -294,6 +444,7 @@ class _Parser:
                 self.advance()
                 return self._parse_year_repeat(1)
             case TDay():
+                self.advance()
                 return self._parse_day_repeat(1, DayFilterEvery())
             case TWeekday():
                 self.advance()
@@ -303,15 +454,27 @@ class _Parser:
                 return self._parse_day_repeat(1, DayFilterWeekend())
             case TDayName():
                 days = self._parse_day_list()
-                return self._parse_day_repeat(1, DayFilterDays(tuple(days)))
+                # "every monday every 2 weeks at ..." is a week repeat
+                if isinstance(self.peek_kind(), TEvery):
+                    self.advance()
+                    interval = self._parse_week_interval()
+                    times = self._parse_times_or_midnight()
+                    return WeekRepeat(interval, tuple(days), tuple(times))
+                return self._parse_day_repeat(1, canonical_day_filter(DayFilterDays(tuple(days))))
             case TWeeks():
                 self.advance()
                 return self._parse_week_repeat(1)
             case TMonth():
                 self.advance()
                 return self._parse_month_repeat(1)
+            # "every hour at :15", "every minute from 09:00 to 10:00"
+            case TIntervalUnit():
+                return self._parse_interval_repeat(1)
             case TNumber():
                 return self._parse_number_repeat()
+            # "every 1.5 hours from ..."
+            case TDecimal():
+                return self._parse_fractional_interval()
             case _:
                 raise self._error(
                     "expected day, weekday, weekend, year, week, day name, month,"
@@ -319,11 +482,69 @@ class _Parser:
                     self.current_span(),
                 )
 
+    # Bare shorthand: "daily at ...", "weekly on ...", "monthly on ...", "yearly on ...",
+    # or "hourly". Each maps to the canonical "every ..." form with interval 1.
+    # Cron `@` shortcut: "@daily" is "every day at 00:00", and takes the same trailing
+    # clauses as that form ("@daily in UTC").
+    def _parse_shortcut(self) -> ScheduleExpr:
+        span = self.current_span()
+        k = self.peek_kind()
+        assert isinstance(k, TShortcut)
+        self.advance()
+        expr = cron_shortcut_expr(k.name)
+        if expr is None:
+            raise HronError.parse(
+                f"unknown @ shortcut: {k.name}",
+                span,
+                self._input,
+                "@yearly, @monthly, @weekly, @daily, @midnight, or @hourly",
+            )
+        return expr
+
+    # Bare shorthand: "daily", "weekly", "monthly", "yearly", or "hourly". Each maps to the
+    # canonical "every ..." form with interval 1 and accepts what that form does. Without
+    # "on", weekly falls on monday (the default week start), monthly on the 1st, and yearly
+    # on jan 1; without a window, hourly runs all day.
+    def _parse_frequency(self) -> ScheduleExpr:
+        k = self.peek_kind()
+        assert isinstance(k, TFrequency)
+        self.advance()
+
+        has_on = isinstance(self.peek_kind(), TOn)
+        match k.word:
+            case "daily":
+                return self._parse_day_repeat(1, DayFilterEvery())
+            case "weekly" if has_on:
+                return self._parse_week_repeat(1)
+            case "weekly":
+                times = self._parse_times_or_midnight()
+                return WeekRepeat(1, (Weekday.MONDAY,), tuple(times))
+            case "monthly" if has_on:
+                return self._parse_month_repeat(1)
+            case "monthly":
+                times = self._parse_times_or_midnight()
+                return MonthRepeat(1, DaysTarget((SingleDay(1),)), tuple(times))
+            # "yearly in iso weeks ..." as well as "yearly on ..."
+            case "yearly" if has_on or self._next_is_iso_weeks():
+                return self._parse_year_repeat(1)
+            case "yearly":
+                times = self._parse_times_or_midnight()
+                return YearRepeat(1, YearDateTarget(MonthName.JAN, 1), tuple(times))
+            # "hourly at :15" and "hourly from 09:00 to 17:00" as after "every hour"
+            case _ if isinstance(self.peek_kind(), (TAt, TFrom, TBetween)):
+                return self._parse_interval_window(1, IntervalUnit.HOURS)
+            case _:
+                return _hourly_at(1, 0, self._parse_optional_day_filter())
+
+    def _next_is_iso_weeks(self) -> bool:
+        return (
+            isinstance(self.peek_kind(), TIn)
+            and self._pos + 1 < len(self._tokens)
+            and isinstance(self._tokens[self._pos + 1].kind, TIso)
+        )
+
     def _parse_day_repeat(self, interval: int, days: DayFilter) -> ScheduleExpr:
-        if isinstance(days, DayFilterEvery):
-            self._consume("'day'", TDay)
-        self._consume("'at'", TAt)
-        times = self._parse_time_list()
+        times = self._parse_times_or_midnight()
         return DayRepeat(interval, days, tuple(times))
 
     def _parse_number_repeat(self) -> ScheduleExpr:
@@ -342,8 +563,25 @@ class _Parser:
                 return self._parse_week_repeat(num)
             case TIntervalUnit():
                 return self._parse_interval_repeat(num)
+            # "every N days from HH:MM to HH:MM" — day-step interval window
+            case TDay() if self._next_is_window():
+                self.advance()
+                return self._parse_interval_window(num, IntervalUnit.DAYS)
+            # "every N days [on day_target] at ..."
             case TDay():
-                return self._parse_day_repeat(num, DayFilterEvery())
+                self.advance()
+                days: DayFilter = DayFilterEvery()
+                if isinstance(self.peek_kind(), TOn):
+                    self.advance()
+                    days = self._parse_day_target()
+                return self._parse_day_repeat(num, days)
+            # "every N weekdays at ..." — every Nth business day
+            case TWeekday():
+                self.advance()
+                if num == 1:
+                    return self._parse_day_repeat(1, DayFilterWeekday())
+                times = self._parse_times_or_midnight()
+                return WeekdayRepeat(num, tuple(times))
             case TMonth():
                 self.advance()
                 return self._parse_month_repeat(num)
@@ -353,7 +591,7 @@ class _Parser:
             case _:
                 raise self._error(
                     "expected 'weeks', 'min', 'minutes', 'hour', 'hours',"
-                    " 'day(s)', 'month(s)', or 'year(s)' after number",
+                    " 'day(s)', 'weekday(s)', 'month(s)', or 'year(s)' after number",
                     self.current_span(),
                 )
 
@@ -362,24 +600,101 @@ class _Parser:
         assert isinstance(k, TIntervalUnit)
         unit = k.unit
         self.advance()
+        return self._parse_interval_window(interval, unit)
 
-        self._consume("'from'", TFrom)
-        from_time = self._parse_time()
-        self._consume("'to'", TTo)
-        to_time = self._parse_time()
+    # "every D hours ..." where D is a decimal: converted to whole minutes, so
+    # "every 1.5 hours" is the same schedule as "every 90 min"
+    def _parse_fractional_interval(self) -> ScheduleExpr:
+        span = self.current_span()
+        k = self.peek_kind()
+        assert isinstance(k, TDecimal)
+        self.advance()
 
-        day_filter: DayFilter | None = None
-        if isinstance(self.peek_kind(), TOn):
+        nk = self.peek_kind()
+        if not (isinstance(nk, TIntervalUnit) and nk.unit == IntervalUnit.HOURS):
+            raise self._error("fractional intervals are only supported in hours", span)
+        self.advance()
+
+        minutes = _fractional_hours_to_minutes(k.text)
+        if minutes is None:
+            raise self._error("fractional hours must come to a whole number of minutes", span)
+        if minutes == 0:
+            raise self._error("interval must be at least 1", span)
+
+        if minutes % 60 == 0:
+            return self._parse_interval_window(minutes // 60, IntervalUnit.HOURS)
+        return self._parse_interval_window(minutes, IntervalUnit.MIN)
+
+    # The window and optional day filter after the interval unit
+    def _parse_interval_window(self, interval: int, unit: IntervalUnit) -> ScheduleExpr:
+        # "every 2 hours at :15": all day, starting MM past midnight
+        if unit == IntervalUnit.HOURS and isinstance(self.peek_kind(), TAt):
+            self.advance()
+            minute = self._parse_minute_past()
+            return _hourly_at(interval, minute, self._parse_optional_day_filter())
+
+        # "from HH:MM to HH:MM" or "between HH:MM and HH:MM"
+        if isinstance(self.peek_kind(), TBetween):
             self.advance()
-            day_filter = self._parse_day_target()
+            from_time = self._parse_time()
+            self._consume("'and'", TAnd)
+            to_time = self._parse_time()
+        else:
+            self._consume("'from' or 'between'", TFrom)
+            from_time = self._parse_time()
+            self._consume("'to'", TTo)
+            to_time = self._parse_time()
 
+        day_filter = self._parse_optional_day_filter()
         return IntervalRepeat(interval, unit, from_time, to_time, day_filter)
 
+    # Optional "on day_target" after an interval window
+    def _parse_optional_day_filter(self) -> DayFilter | None:
+        if isinstance(self.peek_kind(), TOn):
+            self.advance()
+            return self._parse_day_target()
+        return None
+
+    # "at 15 past every hour", "at :15 past every hour": "at" already consumed
+    def _parse_past_every_hour(self) -> ScheduleExpr:
+        minute = self._parse_minute_past(allow_number=True)
+        self._consume("'past'", TPast)
+        self._consume("'every'", TEvery)
+        k = self.peek_kind()
+        if not (isinstance(k, TIntervalUnit) and k.unit == IntervalUnit.HOURS):
+            raise self._error("expected 'hour'", self.current_span())
+        self.advance()
+        return _hourly_at(1, minute, self._parse_optional_day_filter())
+
+    # Minutes past the hour, as ":MM" or (with `allow_number`) a bare number
+    def _parse_minute_past(self, allow_number: bool = False) -> int:
+        k = self.peek_kind()
+        if isinstance(k, TMinuteOffset):
+            minute = k.minute
+        elif allow_number and isinstance(k, TNumber) and k.value <= 59:
+            minute = k.value
+        else:
+            raise self._error("expected minutes past the hour (e.g. :15)", self.current_span())
+        self.advance()
+        return minute
+
+    def _parse_week_interval(self) -> int:
+        """Parse "week" or "N weeks", after a day list and a second "every"."""
+        span = self.current_span()
+        k = self.peek_kind()
+        interval = 1
+        if isinstance(k, TNumber):
+            if k.value == 0:
+                raise self._error("interval must be at least 1", span)
+            interval = k.value
+            self.advance()
+        self._consume("'week' or 'weeks'", TWeeks)
+        return interval
+
     def _parse_week_repeat(self, interval: int) -> ScheduleExpr:
         self._consume("'on'", TOn)
         days = self._parse_day_list()
-        self._consume("'at'", TAt)
-        times = self._parse_time_list()
+        times = self._parse_times_or_midnight()
         return WeekRepeat(interval, tuple(days), tuple(times))
 
     def _parse_month_repeat(self, interval: int) -> ScheduleExpr:
@@ -388,33 +703,35 @@ class _Parser:
 
         k = self.peek_kind()
 
-        if isinstance(k, TLast):
+        target: MonthTarget
+        # "the first monday", "the first and last friday", as opposed to "the first" (day 1)
+        if isinstance(k, (TOrdinal, TLast)) and self._ordinal_weekdays_ahead():
+            target = self._parse_ordinal_weekdays()
+        elif isinstance(k, TLast):
             self.advance()
             nk = self.peek_kind()
             if isinstance(nk, TDay):
                 self.advance()
-                target: MonthTarget = LastDayTarget()
+                target = LastDayTarget()
+            # "last N days"; "last 1 day" is the last day
+            elif isinstance(nk, TNumber):
+                n = nk.value
+                if n < 1 or n > 31:
+                    raise self._error(f"invalid day count: {n} (must be 1-31)", self.current_span())
+                self.advance()
+                self._consume("'days'", TDay)
+                target = LastDayTarget() if n == 1 else LastNDaysTarget(n)
             elif isinstance(nk, TWeekday):
                 self.advance()
                 target = LastWeekdayTarget()
-            elif isinstance(nk, TDayName):
-                weekday = nk.name
-                self.advance()
-                target = OrdinalWeekdayTarget(OrdinalPosition.LAST, weekday)
             else:
                 raise self._error(
                     "expected 'day', 'weekday', or day name after 'last'", self.current_span()
                 )
-        elif isinstance(k, TOrdinal):
-            ordinal = self._parse_ordinal_position()
-            nk = self.peek_kind()
-            if isinstance(nk, TDayName):
-                weekday = nk.name
-                self.advance()
-                target = OrdinalWeekdayTarget(ordinal, weekday)
-            else:
-                raise self._error("expected day name after ordinal", self.current_span())
-        elif isinstance(k, TOrdinalNumber):
+        # "the first weekday", "the 3rd business day"
+        elif isinstance(k, (TOrdinal, TOrdinalNumber)) and self._next_is_business_day():
+            target = self._parse_business_day_target()
+        elif isinstance(k, (TOrdinal, TOrdinalNumber)):
             specs = self._parse_ordinal_day_list()
             target = DaysTarget(tuple(specs))
         elif isinstance(k, (TNext, TPrevious, TNearest)):
@@ -426,10 +743,48 @@ class _Parser:
                 self.current_span(),
             )
 
-        self._consume("'at'", TAt)
-        times = self._parse_time_list()
+        times = self._parse_times()
         return MonthRepeat(interval, target, tuple(times))
 
+    def _ordinal_weekdays_ahead(self) -> bool:
+        """Whether a list of ordinals joined by `,` or `and` ends in a day name."""
+        i = self._pos
+        while True:
+            if not (i < len(self._tokens) and isinstance(self._tokens[i].kind, (TOrdinal, TLast))):
+                return False
+            i += 1
+            if i < len(self._tokens) and isinstance(self._tokens[i].kind, TDayName):
+                return True
+            if not (i < len(self._tokens) and isinstance(self._tokens[i].kind, (TComma, TAnd))):
+                return False
+            i += 1
+
+    def _parse_ordinal_weekdays(self) -> OrdinalWeekdayTarget | OrdinalWeekdaysTarget:
+        ordinals = [self._parse_ordinal_position()]
+        while isinstance(self.peek_kind(), (TComma, TAnd)):
+            self.advance()
+            ordinals.append(self._parse_ordinal_position())
+        weekday = self._parse_day_name("expected day name after ordinal in monthly expression")
+        positions = list(OrdinalPosition)
+        ordinals = sorted(set(ordinals), key=positions.index)
+        if len(ordinals) == 1:
+            return OrdinalWeekdayTarget(ordinals[0], weekday)
+        return OrdinalWeekdaysTarget(tuple(ordinals), weekday)
+
+    def _parse_business_day_target(self) -> BusinessDayTarget:
+        """Parse <ordinal> (weekday | business day)."""
+        span = self.current_span()
+        n = self._parse_ordinal_day("expected ordinal before 'business day'")
+        # No month has more than 23 weekdays
+        if n > 23:
+            raise self._error(f"invalid business day: {n} (must be 1-23)", span)
+        if isinstance(self.peek_kind(), TBusiness):
+            self.advance()
+            self._consume("'day'", TDay)
+        else:
+            self._consume("'weekday'", TWeekday)
+        return BusinessDayTarget(n)
+
     def _parse_nearest_weekday_target(self) -> NearestWeekdayTarget:
         """Parse [next|previous] nearest weekday to <day>."""
         k = self.peek_kind()
@@ -450,18 +805,42 @@ class _Parser:
         return NearestWeekdayTarget(day, direction)
 
     def _parse_ordinal_day_number(self) -> int:
+        return self._parse_ordinal_day("expected ordinal day number")
+
+    def _parse_ordinal_day(self, expected: str) -> int:
+        """Parse an ordinal day of month, as digits (21st) or a word (twenty-first)."""
         k = self.peek_kind()
         if isinstance(k, TOrdinalNumber):
-            if k.value < 1 or k.value > 31:
-                raise self._error(
-                    f"invalid day number {k.value} (must be 1-31)",
-                    self.current_span(),
-                )
-            self.advance()
-            return k.value
-        raise self._error("expected ordinal day number", self.current_span())
+            n: int | None = k.value
+        elif isinstance(k, TOrdinal):
+            n = ordinal_word_value(k.word)
+        else:
+            n = None
+        if n is None:
+            raise self._error(expected, self.current_span())
+        if n < 1 or n > 31:
+            raise self._error(f"invalid day number {n} (must be 1-31)", self.current_span())
+        self.advance()
+        return n
+
+    def _next_is_day_name(self) -> bool:
+        return self._pos + 1 < len(self._tokens) and isinstance(
+            self._tokens[self._pos + 1].kind, TDayName
+        )
+
+    def _next_is_window(self) -> bool:
+        return self._pos + 1 < len(self._tokens) and isinstance(
+            self._tokens[self._pos + 1].kind, (TFrom, TBetween)
+        )
+
+    def _next_is_business_day(self) -> bool:
+        return self._pos + 1 < len(self._tokens) and isinstance(
+            self._tokens[self._pos + 1].kind, (TBusiness, TWeekday)
+        )
 
     def _parse_year_repeat(self, interval: int) -> ScheduleExpr:
+        if isinstance(self.peek_kind(), TIn):
+            return self._parse_iso_week_repeat(interval)
         self._consume("'on'", TOn)
 
         k = self.peek_kind()
@@ -482,10 +861,39 @@ class _Parser:
                 self.current_span(),
             )
 
-        self._consume("'at'", TAt)
-        times = self._parse_time_list()
+        times = self._parse_times()
         return YearRepeat(interval, target, tuple(times))
 
+    # iso_week_repeat: "every year in iso week[s] N[, N] on day_list [at HH:MM]"
+    def _parse_iso_week_repeat(self, interval: int) -> ScheduleExpr:
+        if interval != 1:
+            raise self._error(
+                "ISO week schedules repeat every year; remove the interval", self.current_span()
+            )
+        self._consume("'in'", TIn)
+        self._consume("'iso'", TIso)
+        self._consume("'weeks'", TWeeks)
+
+        weeks = [self._parse_iso_week_number()]
+        while isinstance(self.peek_kind(), TComma):
+            self.advance()
+            weeks.append(self._parse_iso_week_number())
+
+        self._consume("'on'", TOn)
+        days = self._parse_day_list()
+        times = self._parse_times_or_midnight()
+        return IsoWeekRepeat(tuple(weeks), tuple(days), tuple(times))
+
+    def _parse_iso_week_number(self) -> int:
+        span = self.current_span()
+        k = self.peek_kind()
+        if not isinstance(k, TNumber):
+            raise self._error("expected ISO week number", span)
+        if not 1 <= k.value <= 53:
+            raise self._error(f"invalid ISO week {k.value} (expected 1-53)", span)
+        self.advance()
+        return k.value
+
     def _parse_year_target_after_the(
         self,
     ) -> YearDateTarget | YearOrdinalWeekdayTarget | YearDayOfMonthTarget | YearLastWeekdayTarget:
@@ -510,26 +918,19 @@ class _Parser:
                 self.current_span(),
             )
 
-        if isinstance(k, TOrdinal):
+        # "the first monday of march" or "the 15th of march"
+        if isinstance(k, TOrdinal) and self._next_is_day_name():
             ordinal = self._parse_ordinal_position()
             nk = self.peek_kind()
-            if isinstance(nk, TDayName):
-                weekday = nk.name
-                self.advance()
-                self._consume("'of'", TOf)
-                month = self._parse_month_name_token()
-                return YearOrdinalWeekdayTarget(ordinal, weekday, month)
-            raise self._error(
-                "expected day name after ordinal in yearly expression",
-                self.current_span(),
-            )
+            assert isinstance(nk, TDayName)
+            self.advance()
+            self._consume("'of'", TOf)
+            month = self._parse_month_name_token()
+            return YearOrdinalWeekdayTarget(ordinal, nk.name, month)
 
-        if isinstance(k, TOrdinalNumber):
-            day = k.value
-            if day < 1 or day > 31:
-                raise self._error(f"invalid day number {day} (must be 1-31)", self.current_span())
+        if isinstance(k, (TOrdinal, TOrdinalNumber)):
             day_pos = self.current_span().start
-            self.advance()
+            day = self._parse_ordinal_day("expected ordinal day number")
             self._consume("'of'", TOf)
             month = self._parse_month_name_token()
             self._validate_named_date(month, day, day_pos)
@@ -551,17 +952,32 @@ class _Parser:
         span = self.current_span()
         k = self.peek_kind()
         if isinstance(k, TOrdinal):
+            position = _ORDINAL_POSITIONS.get(k.word)
+            if position is None:
+                raise self._error(f"unknown ordinal '{k.word}'", span)
             self.advance()
-            return k.name
+            return position
         if isinstance(k, TLast):
             self.advance()
             return OrdinalPosition.LAST
         raise self._error("expected ordinal (first, second, third, fourth, fifth, last)", span)
 
     def _parse_on(self) -> ScheduleExpr:
+        # "on friday at 17:00": the nearest upcoming friday, a rolling one-shot
+        k = self.peek_kind()
+        if isinstance(k, TDayName):
+            self.advance()
+            if isinstance(self.peek_kind(), TComma):
+                raise self._error(
+                    "a weekday cannot be combined with other dates;"
+                    " use 'every' for recurring days",
+                    self.current_span(),
+                )
+            times = self._parse_times()
+            return SingleDateExpr(NextWeekday(k.name), tuple(times))
+
         date = self._parse_date_target()
-        self._consume("'at'", TAt)
-        times = self._parse_time_list()
+        times = self._parse_times()
         return SingleDateExpr(date, tuple(times))
 
     def _parse_date_target(self) -> DateSpec:
@@ -593,7 +1009,7 @@ class _Parser:
                 return DayFilterWeekend()
             case TDayName():
                 days = self._parse_day_list()
-                return DayFilterDays(tuple(days))
+                return canonical_day_filter(DayFilterDays(tuple(days)))
             case _:
                 raise self._error(
                     "expected 'day', 'weekday', 'weekend', or day name",
@@ -601,46 +1017,55 @@ class _Parser:
                 )
 
     def _parse_day_list(self) -> list[Weekday]:
+        days = self._parse_day_range("expected day name")
+        while isinstance(self.peek_kind(), TComma):
+            self.advance()
+            days.extend(self._parse_day_range("expected day name after ','"))
+        return days
+
+    # A day name or a range of them: "monday to friday", "fri through mon"
+    # (wraps past sunday)
+    def _parse_day_range(self, expected: str) -> list[Weekday]:
+        start = self._parse_day_name(expected)
+        if not self._at_range_separator():
+            return [start]
+        self.advance()
+        end = self._parse_day_name("expected day name after 'to'")
+        length = (end.number + 7 - start.number) % 7 + 1
+        days: list[Weekday] = []
+        for i in range(length):
+            day = Weekday.from_number((start.number - 1 + i) % 7 + 1)
+            assert day is not None
+            days.append(day)
+        return days
+
+    def _parse_day_name(self, expected: str) -> Weekday:
         k = self.peek_kind()
         if not isinstance(k, TDayName):
-            raise self._error("expected day name", self.current_span())
-        days: list[Weekday] = [k.name]
+            raise self._error(expected, self.current_span())
         self.advance()
+        return k.name
 
-        while isinstance(self.peek_kind(), TComma):
-            self.advance()
-            nk = self.peek_kind()
-            if not isinstance(nk, TDayName):
-                raise self._error("expected day name after ','", self.current_span())
-            days.append(nk.name)
-            self.advance()
-        return days
+    def _at_range_separator(self) -> bool:
+        """Whether the next token separates a range: `to`, or its synonym `through`.
+        `through` is only accepted in ranges, never in `from ... to ...` windows."""
+        return isinstance(self.peek_kind(), (TTo, TThrough))
 
+    # Day specs are sorted and de-duplicated: "the 15th, 1st, 15th" is "the 1st, 15th"
     def _parse_ordinal_day_list(self) -> list[DayOfMonthSpec]:
         specs: list[DayOfMonthSpec] = [self._parse_ordinal_day_spec()]
         while isinstance(self.peek_kind(), TComma):
             self.advance()
             specs.append(self._parse_ordinal_day_spec())
-        return specs
+        return _sorted_unique(specs, _day_spec_key)
 
     def _parse_ordinal_day_spec(self) -> DayOfMonthSpec:
-        k = self.peek_kind()
-        if not isinstance(k, TOrdinalNumber):
-            raise self._error("expected ordinal day number", self.current_span())
-        start = k.value
-        if start < 1 or start > 31:
-            raise self._error(f"invalid day number {start} (must be 1-31)", self.current_span())
-        self.advance()
+        start = self._parse_ordinal_day("expected ordinal day number")
 
-        if isinstance(self.peek_kind(), TTo):
-            self.advance()
-            nk = self.peek_kind()
-            if not isinstance(nk, TOrdinalNumber):
-                raise self._error("expected ordinal day number after 'to'", self.current_span())
-            end = nk.value
-            if end < 1 or end > 31:
-                raise self._error(f"invalid day number {end} (must be 1-31)", self.current_span())
+        # "1st to 15th", "1st through 15th"
+        if self._at_range_separator():
             self.advance()
+            end = self._parse_ordinal_day("expected ordinal day number after 'to'")
             if start > end:
                 raise self._error(
                     f"invalid day range: {start} to {end} (start must be <= end)",
@@ -650,19 +1075,80 @@ class _Parser:
 
         return SingleDay(start)
 
-    def _parse_month_list(self) -> list[MonthName]:
-        months: list[MonthName] = [self._parse_month_name_token()]
+    # Specs are sorted by where they start and de-duplicated: "during mar, jan, mar"
+    # is "during jan, mar". Overlapping specs are not merged.
+    def _parse_during_list(self) -> list[DuringSpec]:
+        specs: list[DuringSpec] = [self._parse_during_spec()]
         while isinstance(self.peek_kind(), TComma):
             self.advance()
-            months.append(self._parse_month_name_token())
-        return months
+            specs.append(self._parse_during_spec())
+        return _sorted_unique(specs, _during_spec_key)
+
+    # during_spec: "jun", "jun to aug" (wraps past december: "nov to feb"),
+    # or month-day bounds "jun 15 to aug 31". A bound without a day covers
+    # its whole month.
+    def _parse_during_spec(self) -> DuringSpec:
+        start = self._parse_month_name_token()
+        start_day = self._parse_during_day(start)
+        if not self._at_range_separator():
+            # "during jun 15" is that one day
+            return DuringSpec(start, start, start_day, start_day)
+        self.advance()
+        end = self._parse_month_name_token()
+        end_day = self._parse_during_day(end)
+        if start_day is None and end_day is None:
+            return DuringSpec(start, end)
+        return DuringSpec(
+            start,
+            end,
+            1 if start_day is None else start_day,
+            end.max_day if end_day is None else end_day,
+        )
+
+    def _parse_during_day(self, month: MonthName) -> int | None:
+        """Optional day after a month name in `during`, validated for the month."""
+        if not isinstance(self.peek_kind(), (TNumber, TOrdinalNumber)):
+            return None
+        day_pos = self.current_span().start
+        day = self._parse_day_number("expected day number after month name in during")
+        self._validate_named_date(month, day, day_pos)
+        return day
+
+    # times: "at HH:MM, ..." or "in the morning|afternoon|evening|night"
+    def _parse_times(self) -> list[TimeOfDay]:
+        if isinstance(self.peek_kind(), TIn):
+            self.advance()
+            self._consume("'the'", TThe)
+            k = self.peek_kind()
+            if not isinstance(k, TPeriod):
+                raise self._error(
+                    "expected morning, afternoon, evening, or night", self.current_span()
+                )
+            self.advance()
+            return [_PERIOD_TIMES[k.word]]
+        self._consume("'at'", TAt)
+        return self._parse_time_list()
+
+    # Day and week repeats may omit times: "every day", "every weekday in UTC"
+    # fire at 00:00. A bare "in" starts the timezone clause, not "in the ...".
+    def _parse_times_or_midnight(self) -> list[TimeOfDay]:
+        k = self.peek_kind()
+        has_times = isinstance(k, TAt) or (
+            isinstance(k, TIn)
+            and self._pos + 1 < len(self._tokens)
+            and isinstance(self._tokens[self._pos + 1].kind, TThe)
+        )
+        if has_times:
+            return self._parse_times()
+        return [TimeOfDay(0, 0)]
 
+    # Times are sorted and de-duplicated: "at 17:00, 09:00, 09:00" is "at 09:00, 17:00"
     def _parse_time_list(self) -> list[TimeOfDay]:
         times: list[TimeOfDay] = [self._parse_time()]
         while isinstance(self.peek_kind(), TComma):
             self.advance()
             times.append(self._parse_time())
-        return times
+        return sorted(set(times), key=lambda t: (t.hour, t.minute))
 
     def _parse_time(self) -> TimeOfDay:
         span = self.current_span()
@@ -673,6 +1159,45 @@ class _Parser:
         raise self._error("expected time (HH:MM)", span)
 
 
+def _day_spec_key(spec: DayOfMonthSpec) -> tuple[int, int]:
+    """Ordering key: by first day, then last day."""
+    if isinstance(spec, SingleDay):
+        return (spec.day, spec.day)
+    return (spec.start, spec.end)
+
+
+def _during_spec_key(spec: DuringSpec) -> tuple[int, int, int, int]:
+    """Ordering key: by first month-day, then last month-day. A month and the
+    date range covering the same days share a key."""
+    (start, start_day), (end, end_day) = spec.bounds()
+    return (start.number, start_day, end.number, end_day)
+
+
+def _sorted_unique(items: list[_T], key: Callable[[_T], tuple[int, ...]]) -> list[_T]:
+    """Sort `items` by `key`, keeping the first of each run of equal keys."""
+    unique: dict[tuple[int, ...], _T] = {}
+    for item in sorted(items, key=key):
+        unique.setdefault(key(item), item)
+    return list(unique.values())
+
+
+def _hourly_at(interval: int, minute: int, day_filter: DayFilter | None) -> IntervalRepeat:
+    """An all-day hour step firing `minute` past the hour: `every hour at :15`
+    is `every 1 hour from 00:15 to 23:59`."""
+    return IntervalRepeat(
+        interval, IntervalUnit.HOURS, TimeOfDay(0, minute), TimeOfDay(23, 59), day_filter
+    )
+
+
+def _fractional_hours_to_minutes(text: str) -> int | None:
+    """Convert decimal hours (1.5) to whole minutes, or None if they don't
+    come to a whole number of minutes."""
+    minutes = Decimal(text) * 60
+    if minutes != minutes.to_integral_value():
+        return None
+    return int(minutes)
+
+
 def parse(input_text: str) -> ScheduleData:
     tokens = tokenize(input_text)
 
@@ -7,6 +7,8 @@ from datetime import datetime
 from pathlib import Path
 from zoneinfo import ZoneInfo
 
+import pytest
+
 from hron import HronError, Schedule
 
 _api_spec_path = Path(__file__).parent.parent.parent / "spec" / "api.json"
@@ -64,6 +66,12 @@ class TestInstanceMethods:
         result = self._schedule.matches(self._now)
         assert isinstance(result, bool)
 
+    def test_matches_rejects_relative_anchor(self) -> None:
+        for anchor in ("today", "now"):
+            schedule = Schedule.parse(f"every 2 days at 09:00 starting {anchor} in UTC")
+            with pytest.raises(HronError, match="no evaluation time"):
+                schedule.matches(self._now)
+
     def test_to_cron(self) -> None:
         cron = self._schedule.to_cron()
         assert isinstance(cron, str)
@@ -35,6 +35,31 @@ _PARSE_SECTIONS = [
     "timezone_clause",
     "combined_clauses",
     "case_insensitivity",
+    "ordinal_in_dates",
+    "twelve_hour_times",
+    "between_windows",
+    "frequency_keywords",
+    "time_of_day_keywords",
+    "time_list_normalization",
+    "default_midnight",
+    "weekday_exceptions",
+    "iso_weeks",
+    "during_dates",
+    "cron_shortcuts",
+    "ordinal_words",
+    "day_interval_filter",
+    "day_step_windows",
+    "last_n_days",
+    "day_list_canonicalization",
+    "fractional_hours",
+    "weekday_dates",
+    "weekday_interval",
+    "through_ranges",
+    "twelve_hour_windows",
+    "business_days",
+    "except_ranges",
+    "minutes_past_hour",
+    "ordinal_weekdays",
 ]
 
 
@@ -147,6 +172,10 @@ _MATCHES_IDS = [t[0] for t in _MATCHES_TESTS]
 def test_eval_matches(name: str, tc: dict) -> None:  # type: ignore[type-arg]
     schedule = Schedule.parse(tc["expression"])
     dt = parse_zoned(tc["datetime"])
+    if "error_contains" in tc:
+        with pytest.raises(HronError, match=tc["error_contains"]):
+            schedule.matches(dt)
+        return
     result = schedule.matches(dt)
     assert result == tc["expected"]
 
@@ -257,7 +286,9 @@ def test_eval_errors(name: str, expression: str) -> None:
 # ===========================================================================
 
 _TO_CRON_TESTS = [
-    (tc.get("name", tc["hron"]), tc["hron"], tc["cron"]) for tc in _spec["cron"]["to_cron"]["tests"]
+    (tc.get("name", tc["hron"]), tc["hron"], tc["cron"])
+    for section in ("to_cron", "to_cron_during")
+    for tc in _spec["cron"][section]["tests"]
 ]
 _TO_CRON_IDS = [t[0] for t in _TO_CRON_TESTS]
 
@@ -16,7 +16,7 @@ from zoneinfo import ZoneInfo
 
 import pytest
 
-from hron import Schedule
+from hron import HronError, Schedule
 
 
 def parse_zoned(s: str) -> datetime:
@@ -318,6 +318,16 @@ class TestEdgeCases:
 
         assert len(results) == 1
 
+    def test_upcoming_weekday_occurrence_cannot_be_matched(self) -> None:
+        schedule = Schedule.parse("on friday at 17:00 in UTC")
+        from_dt = parse_zoned("2026-02-06T12:00:00+00:00[UTC]")
+
+        results = list(itertools.islice(schedule.occurrences(from_dt), 10))
+
+        assert len(results) == 1
+        with pytest.raises(HronError, match="no evaluation time"):
+            schedule.matches(results[0])
+
 
 # =============================================================================
 # Timezone Handling
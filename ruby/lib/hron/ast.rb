@@ -82,6 +82,12 @@ module Hron
 
     NUMBER_TO_MONTH = NUMBERS.invert.freeze
 
+    # Each month's longest length: February has 29 days.
+    MAX_DAYS = {
+      JAN => 31, FEB => 29, MAR => 31, APR => 30, MAY => 31, JUN => 30,
+      JUL => 31, AUG => 31, SEP => 30, OCT => 31, NOV => 30, DEC => 31
+    }.freeze
+
     PARSE_MAP = {
       "january" => JAN, "jan" => JAN,
       "february" => FEB, "feb" => FEB,
@@ -105,6 +111,10 @@ module Hron
       NUMBER_TO_MONTH[n]
     end
 
+    def self.max_day(month)
+      MAX_DAYS[month]
+    end
+
     def self.try_parse(s)
       PARSE_MAP[s.downcase]
     end
@@ -114,10 +124,13 @@ module Hron
     end
   end
 
-  # Interval unit (minutes or hours)
+  # Interval unit (minutes, hours or days)
   module IntervalUnit
     MIN = :min
     HOURS = :hours
+    # Day-granularity step: the window is active on every Nth day (aligned
+    # like "every N days at ...") and fires once, at the window start.
+    DAYS = :days
 
     def self.to_s(unit)
       unit.to_s
@@ -133,6 +146,8 @@ module Hron
     FIFTH = :fifth
     LAST = :last
 
+    ALL = [FIRST, SECOND, THIRD, FOURTH, FIFTH, LAST].freeze
+
     TO_N = {
       FIRST => 1, SECOND => 2, THIRD => 3, FOURTH => 4, FIFTH => 5
     }.freeze
@@ -176,9 +191,19 @@ module Hron
 
   DaysTarget = Data.define(:specs) # specs: Array<DayOfMonthSpec>
   LastDayTarget = Data.define
+  # The final n calendar days of each month (`the last 3 days`), n >= 2.
+  # Counts back from the month's real length; n beyond it covers the whole month.
+  LastNDaysTarget = Data.define(:n)
   LastWeekdayTarget = Data.define
+  # The Nth monday-to-friday day of the month (`the first business day`,
+  # `the 3rd weekday`), n in 1-23. Excepted dates are not business days, so an
+  # `except` landing on it moves the occurrence to the next one.
+  BusinessDayTarget = Data.define(:n)
   NearestWeekdayTarget = Data.define(:day, :direction) # day: 1-31, direction: nil or NearestDirection
   OrdinalWeekdayTarget = Data.define(:ordinal, :weekday)
+  # Several ordinal weekdays of the month (`the first and last friday`).
+  # Ordinals are sorted and distinct; a single ordinal is an OrdinalWeekdayTarget.
+  OrdinalWeekdaysTarget = Data.define(:ordinals, :weekday)
 
   # --- Year target variants ---
 
@@ -191,11 +216,20 @@ module Hron
 
   NamedDate = Data.define(:month, :day)
   IsoDate = Data.define(:date) # date: String (YYYY-MM-DD)
+  # `on friday`: the nearest upcoming day with that weekday, resolved against
+  # the evaluation time rather than a fixed date.
+  NextWeekdayDate = Data.define(:day)
 
   # --- Exception spec variants ---
 
   NamedException = Data.define(:month, :day)
   IsoException = Data.define(:date)
+  WeekdayException = Data.define(:day) # excludes every occurrence of a day of the week
+  # One-off inclusive ISO date range: `2026-12-24 to 2026-12-31`.
+  IsoRangeException = Data.define(:start, :end_date)
+  # Recurring inclusive range of named dates: `dec 24 to jan 2` matches every
+  # year, wrapping past december when the end comes first.
+  NamedRangeException = Data.define(:start_month, :start_day, :end_month, :end_day)
 
   # --- Until spec variants ---
 
@@ -210,13 +244,47 @@ module Hron
   MonthRepeat = Data.define(:interval, :target, :times)
   SingleDateExpr = Data.define(:date, :times)
   YearRepeat = Data.define(:interval, :target, :times)
+  IsoWeekRepeat = Data.define(:weeks, :days, :times) # weeks: Array<Integer> (ISO weeks 1-53)
+  # Every Nth business day: only Monday through Friday count toward the
+  # interval, counted from the `starting` anchor (default 1970-01-01).
+  WeekdayRepeat = Data.define(:interval, :times)
+
+  # --- During spec ---
+
+  # A single month, inclusive month range, or inclusive month-day range in a
+  # `during` clause. Wraps past December when start > end: `nov to feb` is
+  # nov, dec, jan, feb. Month forms leave the days nil; an end of feb 29 means
+  # the last day of February in common years.
+  DuringSpec = Data.define(:start, :end_month, :start_day, :end_day) do # end_month to avoid Ruby keyword
+    def initialize(start:, end_month:, start_day: nil, end_day: nil)
+      super
+    end
+  end
 
   # --- Schedule data (top-level) ---
 
-  ScheduleData = Data.define(:expr, :timezone, :except, :until, :anchor, :during) do
-    def initialize(expr:, timezone: nil, except: [], until: nil, anchor: nil, during: [])
+  # `starting` words that resolve against the evaluation instant.
+  RELATIVE_ANCHORS = %w[today now].freeze
+
+  # during holds `during` as written: months, month ranges, and date ranges.
+  # anchor is an ISO date or one of RELATIVE_ANCHORS; anchor_named is a
+  # `starting jan 6` NamedDate, resolved to the next such date at evaluation;
+  # anchor_time is the `starting ... at` time.
+  ScheduleData = Data.define(:expr, :timezone, :except, :until, :anchor, :anchor_named, :anchor_time, :during) do
+    def initialize(expr:, timezone: nil, except: [], until: nil, anchor: nil, anchor_named: nil, anchor_time: nil, during: [])
       super
     end
+
+    # Whether the anchor is resolved against the evaluation time: one of
+    # RELATIVE_ANCHORS, or a month-day.
+    def relative_anchor?
+      !anchor_named.nil? || RELATIVE_ANCHORS.include?(anchor)
+    end
+
+    # The starting anchor as written, without its time.
+    def anchor_text
+      anchor_named ? "#{anchor_named.month} #{anchor_named.day}" : anchor
+    end
   end
 
   # --- Helper functions ---
@@ -232,6 +300,60 @@ module Hron
     end
   end
 
+  # The first and last month-day a during spec covers. Whole months end on
+  # their longest day (feb 29).
+  def self.during_bounds(spec)
+    [spec.start_day || 1, spec.end_day || MonthName.max_day(spec.end_month)]
+  end
+
+  # Whether the month-day falls within a during spec.
+  def self.during_contains(spec, month, day)
+    start_day, end_day = during_bounds(spec)
+    key = (MonthName.number(month) * 100) + day
+    first = (MonthName.number(spec.start) * 100) + start_day
+    last = (MonthName.number(spec.end_month) * 100) + end_day
+    return key.between?(first, last) if first <= last
+
+    key >= first || key <= last
+  end
+
+  # Whether a during spec covers whole months: `jun 1 to aug 31` is `jun to aug`.
+  def self.during_whole_months(spec)
+    start_day, end_day = during_bounds(spec)
+    start_day == 1 && end_day == MonthName.max_day(spec.end_month)
+  end
+
+  # The months a during spec touches, in calendar order from the start.
+  def self.expand_during_spec(spec)
+    start_day, end_day = during_bounds(spec)
+    start = MonthName.number(spec.start)
+    # `jun 15 to jun 10` wraps through every month
+    length = if spec.start == spec.end_month && start_day > end_day
+      12
+    else
+      ((MonthName.number(spec.end_month) - start) % 12) + 1
+    end
+    (0...length).map { |i| MonthName.from_number(((start - 1 + i) % 12) + 1) }
+  end
+
+  # Months any `during` spec touches, for month-level skipping.
+  def self.during_months(schedule)
+    schedule.during.flat_map { |spec| expand_during_spec(spec) }
+  end
+
+  # Sort and de-duplicate an explicit day list, collapsing one covering exactly
+  # the weekdays or all seven days into the equivalent keyword filter.
+  # `saturday, sunday` stays a list: `weekend` is a keyword of its own.
+  def self.canonical_day_filter(filter)
+    return filter unless filter.is_a?(DayFilterDays)
+
+    days = filter.days.uniq.sort_by { |d| Weekday.number(d) }
+    return DayFilterEvery.new if days.length == 7
+    return DayFilterWeekday.new if days == Weekday::WEEKDAYS
+
+    DayFilterDays.new(days)
+  end
+
   def self.expand_month_target(target)
     case target
     when DaysTarget
@@ -22,21 +22,33 @@ module Hron
 
       raise HronError.cron("not expressible as cron (until clauses not supported)") if schedule.until
 
-      raise HronError.cron("not expressible as cron (during clauses not supported)") unless schedule.during.empty?
+      unless schedule.during.all? { |spec| Hron.during_whole_months(spec) }
+        raise HronError.cron("not expressible as cron (during dates narrower than whole months)")
+      end
+
+      month = month_field(Hron.during_months(schedule))
 
       expr = schedule.expr
 
       case expr
       when DayRepeat
         raise HronError.cron("not expressible as cron (multi-day intervals not supported)") if expr.interval > 1
-        raise HronError.cron("not expressible as cron (multiple times not supported)") if expr.times.length != 1
+        fields = time_fields(expr.times)
+        raise HronError.cron("not expressible as cron (multiple times not supported)") unless fields
 
-        time = expr.times[0]
+        minute, hour = fields
         dow = day_filter_to_cron_dow(expr.days)
-        "#{time.minute} #{time.hour} * * #{dow}"
+        "#{minute} #{hour} * #{month} #{dow}"
 
       when IntervalRepeat
-        full_day = expr.from_time.hour.zero? && expr.from_time.minute.zero? &&
+        if expr.unit == IntervalUnit::DAYS
+          raise HronError.cron("not expressible as cron (multi-day interval windows not supported)")
+        end
+
+        # Only expressible if window is full day (00:00 to 23:59). Hour steps
+        # may start past the hour: "from 00:15" fires at :15.
+        offset_ok = expr.from_time.minute.zero? || expr.unit == IntervalUnit::HOURS
+        full_day = expr.from_time.hour.zero? && offset_ok &&
           expr.to_time.hour == 23 && expr.to_time.minute == 59
         raise HronError.cron("not expressible as cron (partial-day interval windows not supported)") unless full_day
 
@@ -47,9 +59,11 @@ module Hron
             raise HronError.cron("not expressible as cron (*/#{expr.interval} breaks at hour boundaries)")
           end
 
-          "*/#{expr.interval} * * * *"
+          "*/#{expr.interval} * * #{month} *"
+        elsif expr.interval == 1 && !expr.from_time.minute.zero?
+          "#{expr.from_time.minute} * * #{month} *"
         else
-          "0 */#{expr.interval} * * *"
+          "#{expr.from_time.minute} */#{expr.interval} * #{month} *"
         end
 
       when WeekRepeat
@@ -57,9 +71,10 @@ module Hron
 
       when MonthRepeat
         raise HronError.cron("not expressible as cron (multi-month intervals not supported)") if expr.interval > 1
-        raise HronError.cron("not expressible as cron (multiple times not supported)") if expr.times.length != 1
+        fields = time_fields(expr.times)
+        raise HronError.cron("not expressible as cron (multiple times not supported)") unless fields
 
-        time = expr.times[0]
+        minute, hour = fields
         case expr.target
         when DaysTarget
           expanded = []
@@ -72,9 +87,16 @@ module Hron
             end
           end
           dom = expanded.join(",")
-          "#{time.minute} #{time.hour} #{dom} * *"
+          "#{minute} #{hour} #{dom} #{month} *"
         when LastDayTarget
           raise HronError.cron("not expressible as cron (last day of month not supported)")
+        when LastNDaysTarget
+          raise HronError.cron("not expressible as cron (last days of month not supported)")
+        when BusinessDayTarget
+          # The first business day is always the weekday nearest the 1st
+          raise HronError.cron("not expressible as cron (nth business day of month not supported)") if expr.target.n != 1
+
+          "#{minute} #{hour} 1W #{month} *"
         when LastWeekdayTarget
           raise HronError.cron("not expressible as cron (last weekday of month not supported)")
         when NearestWeekdayTarget
@@ -82,7 +104,9 @@ module Hron
             raise HronError.cron("not expressible as cron (directional nearest weekday not supported)")
           end
 
-          "#{time.minute} #{time.hour} #{expr.target.day}W * *"
+          "#{minute} #{hour} #{expr.target.day}W #{month} *"
+        when OrdinalWeekdaysTarget
+          raise HronError.cron("not expressible as cron (ordinal weekdays of month not supported)")
         when OrdinalWeekdayTarget
           raise HronError.cron("not expressible as cron (ordinal weekday of month not supported)")
         else
@@ -95,11 +119,51 @@ module Hron
       when YearRepeat
         raise HronError.cron("not expressible as cron (yearly schedules not supported in 5-field cron)")
 
+      when WeekdayRepeat
+        raise HronError.cron("not expressible as cron (business-day intervals not supported)")
+
+      when IsoWeekRepeat
+        raise HronError.cron("not expressible as cron (cron has no week-of-year field)")
+
       else
         raise HronError.cron("unknown expression type: #{expr.class}")
       end
     end
 
+    # Exact minute and hour fields for a list of times: "0 9,17", "0,30 9", or
+    # "0,30 9,17" when the times are every listed minute at every listed hour.
+    # nil when comma fields would fire at times not in the list.
+    def self.time_fields(times)
+      distinct = times.map { |t| (t.hour * 60) + t.minute }.uniq
+      minutes = times.map(&:minute).uniq.sort
+      hours = times.map(&:hour).uniq.sort
+      # Distinct times are a subset of the grid, so equal counts mean equal sets
+      return nil if minutes.length * hours.length != distinct.length
+
+      [minutes.join(","), hours.join(",")]
+    end
+
+    # The cron month field for a `during` clause. Runs of three or more
+    # consecutive months collapse into a range.
+    def self.month_field(during)
+      months = during.map { |m| MonthName.number(m) }.uniq.sort
+      return "*" if months.empty?
+
+      parts = []
+      i = 0
+      while i < months.length
+        j = i
+        j += 1 while j + 1 < months.length && months[j + 1] == months[j] + 1
+        if j - i >= 2
+          parts << "#{months[i]}-#{months[j]}"
+        else
+          (i..j).each { |k| parts << months[k].to_s }
+        end
+        i = j + 1
+      end
+      parts.join(",")
+    end
+
     def self.day_filter_to_cron_dow(filter)
       case filter
       when DayFilterEvery
@@ -162,15 +226,13 @@ module Hron
       return result if result
 
       # Standard time-based cron
-      minute = parse_single_value(minute_field, "minute", 0, 59)
-      hour = parse_single_value(hour_field, "hour", 0, 23)
-      time = TimeOfDay.new(hour, minute)
+      times = parse_cron_times(minute_field, hour_field)
 
       # DOM-based (monthly) - when DOM is specified and DOW is *
       if dom_field != "*" && dow_field == "*"
         target = parse_dom_field(dom_field)
         return ScheduleData.new(
-          expr: MonthRepeat.new(1, target, [time]),
+          expr: MonthRepeat.new(1, target, times),
           during: during
         )
       end
@@ -178,62 +240,36 @@ module Hron
       # DOW-based (day repeat)
       days = parse_cron_dow(dow_field)
       ScheduleData.new(
-        expr: DayRepeat.new(1, days, [time]),
+        expr: DayRepeat.new(1, days, times),
         during: during
       )
     end
 
     # Parse @ shortcuts like @daily, @hourly, etc.
     def self.parse_cron_shortcut(cron_str)
-      case cron_str.downcase
+      expr = cron_shortcut_expr(cron_str.downcase)
+      raise HronError.cron("unknown @ shortcut: #{cron_str}") unless expr
+
+      ScheduleData.new(expr: expr)
+    end
+
+    # The expression a lowercase @ shortcut stands for, or nil if it is not one.
+    def self.cron_shortcut_expr(shortcut)
+      case shortcut
       when "@yearly", "@annually"
-        ScheduleData.new(
-          expr: YearRepeat.new(
-            1,
-            YearDateTarget.new(MonthName::JAN, 1),
-            [TimeOfDay.new(0, 0)]
-          )
-        )
+        YearRepeat.new(1, YearDateTarget.new(MonthName::JAN, 1), [TimeOfDay.new(0, 0)])
       when "@monthly"
-        ScheduleData.new(
-          expr: MonthRepeat.new(
-            1,
-            DaysTarget.new([SingleDay.new(1)]),
-            [TimeOfDay.new(0, 0)]
-          )
-        )
+        MonthRepeat.new(1, DaysTarget.new([SingleDay.new(1)]), [TimeOfDay.new(0, 0)])
       when "@weekly"
-        ScheduleData.new(
-          expr: DayRepeat.new(
-            1,
-            DayFilterDays.new([Weekday::SUNDAY]),
-            [TimeOfDay.new(0, 0)]
-          )
-        )
+        DayRepeat.new(1, DayFilterDays.new([Weekday::SUNDAY]), [TimeOfDay.new(0, 0)])
       when "@daily", "@midnight"
-        ScheduleData.new(
-          expr: DayRepeat.new(
-            1,
-            DayFilterEvery.new,
-            [TimeOfDay.new(0, 0)]
-          )
-        )
+        DayRepeat.new(1, DayFilterEvery.new, [TimeOfDay.new(0, 0)])
       when "@hourly"
-        ScheduleData.new(
-          expr: IntervalRepeat.new(
-            1,
-            IntervalUnit::HOURS,
-            TimeOfDay.new(0, 0),
-            TimeOfDay.new(23, 59),
-            nil
-          )
-        )
-      else
-        raise HronError.cron("unknown @ shortcut: #{cron_str}")
+        IntervalRepeat.new(1, IntervalUnit::HOURS, TimeOfDay.new(0, 0), TimeOfDay.new(23, 59), nil)
       end
     end
 
-    # Parse month field into a Vec<MonthName> for the `during` clause.
+    # Parse month field into `during` specs, one per month.
     def self.parse_month_field(field)
       return [] if field == "*"
 
@@ -281,7 +317,7 @@ module Hron
         end
       end
 
-      months
+      months.map { |m| DuringSpec.new(m, m) }
     end
 
     # Parse a single month value (number 1-12 or name JAN-DEC).
@@ -329,11 +365,10 @@ module Hron
 
         raise HronError.cron("DOM must be * when using # for nth weekday") if dom_field != "*" && dom_field != "?"
 
-        minute = parse_single_value(minute_field, "minute", 0, 59)
-        hour = parse_single_value(hour_field, "hour", 0, 23)
+        times = parse_cron_times(minute_field, hour_field)
 
         return ScheduleData.new(
-          expr: MonthRepeat.new(1, OrdinalWeekdayTarget.new(ordinal, weekday), [TimeOfDay.new(hour, minute)]),
+          expr: MonthRepeat.new(1, OrdinalWeekdayTarget.new(ordinal, weekday), times),
           during: during
         )
       end
@@ -346,11 +381,10 @@ module Hron
 
         raise HronError.cron("DOM must be * when using nL for last weekday") if dom_field != "*" && dom_field != "?"
 
-        minute = parse_single_value(minute_field, "minute", 0, 59)
-        hour = parse_single_value(hour_field, "hour", 0, 23)
+        times = parse_cron_times(minute_field, hour_field)
 
         return ScheduleData.new(
-          expr: MonthRepeat.new(1, OrdinalWeekdayTarget.new(OrdinalPosition::LAST, weekday), [TimeOfDay.new(hour, minute)]),
+          expr: MonthRepeat.new(1, OrdinalWeekdayTarget.new(OrdinalPosition::LAST, weekday), times),
           during: during
         )
       end
@@ -364,13 +398,12 @@ module Hron
 
       raise HronError.cron("DOW must be * when using L or LW in DOM") if dow_field != "*" && dow_field != "?"
 
-      minute = parse_single_value(minute_field, "minute", 0, 59)
-      hour = parse_single_value(hour_field, "hour", 0, 23)
+      times = parse_cron_times(minute_field, hour_field)
 
       target = (dom_field == "LW") ? LastWeekdayTarget.new : LastDayTarget.new
 
       ScheduleData.new(
-        expr: MonthRepeat.new(1, target, [TimeOfDay.new(hour, minute)]),
+        expr: MonthRepeat.new(1, target, times),
         during: during
       )
     end
@@ -389,13 +422,12 @@ module Hron
       end
       raise HronError.cron("W day must be 1-31, got #{day}") if day < 1 || day > 31
 
-      minute = parse_single_value(minute_field, "minute", 0, 59)
-      hour = parse_single_value(hour_field, "hour", 0, 23)
+      times = parse_cron_times(minute_field, hour_field)
 
       target = NearestWeekdayTarget.new(day, nil)
 
       ScheduleData.new(
-        expr: MonthRepeat.new(1, target, [TimeOfDay.new(hour, minute)]),
+        expr: MonthRepeat.new(1, target, times),
         during: during
       )
     end
@@ -495,9 +527,11 @@ module Hron
         end
       end
 
-      # Hour interval: 0 */N or 0 range/N
-      if hour_field.include?("/") && (minute_field == "0" || minute_field == "00")
+      # Hour interval: M */N, M range/N, or M * (every hour at minute M)
+      minute = minute_field.match?(/\A\d+\z/) ? minute_field.to_i : nil
+      if minute && minute <= 59 && (hour_field.include?("/") || hour_field == "*")
         range_part, step_str = hour_field.split("/", 2)
+        step_str ||= "1"
         interval = begin
           Integer(step_str)
         rescue
@@ -531,14 +565,15 @@ module Hron
         end
 
         if (dom_field == "*" || dom_field == "?") && (dow_field == "*" || dow_field == "?")
-          # Use :59 only for full day (00:00 to 23:59), otherwise use :00
-          end_minute = (from_hour == 0 && to_hour == 23) ? 59 : 0
+          # Use :59 only for full day (00:00 to 23:59), otherwise end on the
+          # last firing
+          end_minute = (from_hour == 0 && to_hour == 23) ? 59 : minute
 
           return ScheduleData.new(
             expr: IntervalRepeat.new(
               interval,
               IntervalUnit::HOURS,
-              TimeOfDay.new(from_hour, 0),
+              TimeOfDay.new(from_hour, minute),
               TimeOfDay.new(to_hour, end_minute),
               nil
             ),
@@ -689,17 +724,11 @@ module Hron
         end
       end
 
-      # Check for special patterns
-      if days.length == 5
-        sorted = days.sort_by { |d| Weekday.number(d) }
-        return DayFilterWeekday.new if sorted == Weekday::WEEKDAYS
-      end
-      if days.length == 2
-        sorted = days.sort_by { |d| Weekday.number(d) }
-        return DayFilterWeekend.new if sorted == Weekday::WEEKEND
-      end
+      # Cron has no weekend keyword, so 0,6 reads as the weekend
+      canonical = Hron.canonical_day_filter(DayFilterDays.new(days))
+      return DayFilterWeekend.new if canonical.is_a?(DayFilterDays) && canonical.days == Weekday::WEEKEND
 
-      DayFilterDays.new(days)
+      canonical
     end
 
     # Parse a DOW value (number 0-7 or name SUN-SAT), normalizing 7 to 0.
@@ -732,6 +761,20 @@ module Hron
       result
     end
 
+    # Parse the minute and hour fields into times. Either field may be a comma
+    # list; every hour pairs with every minute, so "0,30 9,17" gives four times.
+    def self.parse_cron_times(minute_field, hour_field)
+      minutes = parse_value_list(minute_field, "minute", 0, 59)
+      hours = parse_value_list(hour_field, "hour", 0, 23)
+      keys = hours.product(minutes).map { |h, m| (h * 60) + m }.uniq.sort
+      keys.map { |k| TimeOfDay.new(k / 60, k % 60) }
+    end
+
+    # Parse a comma list of numeric values, each validated like a single value.
+    def self.parse_value_list(field, name, min, max)
+      field.split(",", -1).map { |v| parse_single_value(v, name, min, max) }
+    end
+
     # Parse a single numeric value with validation.
     def self.parse_single_value(field, name, min, max)
       value = begin
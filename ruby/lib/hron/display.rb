@@ -15,6 +15,12 @@ module Hron
             "#{exc.month} #{exc.day}"
           when IsoException
             exc.date
+          when WeekdayException
+            exc.day.to_s
+          when IsoRangeException
+            "#{exc.start} to #{exc.end_date}"
+          when NamedRangeException
+            "#{exc.start_month} #{exc.start_day} to #{exc.end_month} #{exc.end_day}"
           else
             raise "unknown exception type: #{exc.class}"
           end
@@ -33,15 +39,32 @@ module Hron
         end
       end
 
-      out += " starting #{schedule.anchor}" if schedule.anchor
+      out += " starting #{schedule.anchor_text}" if schedule.anchor_text
+      out += " at #{schedule.anchor_time}" if schedule.anchor_time
 
-      out += " during #{schedule.during.join(", ")}" unless schedule.during.empty?
+      unless schedule.during.empty?
+        parts = schedule.during.map { |spec| display_during_spec(spec) }
+        out += " during #{parts.join(", ")}"
+      end
 
       out += " in #{schedule.timezone}" if schedule.timezone
 
       out
     end
 
+    def self.display_during_spec(spec)
+      start_day, end_day = Hron.during_bounds(spec)
+      # Date ranges covering whole months print as months
+      if Hron.during_whole_months(spec)
+        return spec.start.to_s if spec.start == spec.end_month
+
+        return "#{spec.start} to #{spec.end_month}"
+      end
+      return "#{spec.start} #{start_day}" if spec.start == spec.end_month && start_day == end_day
+
+      "#{spec.start} #{start_day} to #{spec.end_month} #{end_day}"
+    end
+
     def self.display_expr(expr)
       case expr
       when IntervalRepeat
@@ -52,7 +75,8 @@ module Hron
 
       when DayRepeat
         if expr.interval > 1
-          "every #{expr.interval} days at #{format_time_list(expr.times)}"
+          on = expr.days.is_a?(DayFilterEvery) ? "" : "on #{display_day_filter(expr.days)} "
+          "every #{expr.interval} days #{on}at #{format_time_list(expr.times)}"
         else
           "every #{display_day_filter(expr.days)} at #{format_time_list(expr.times)}"
         end
@@ -71,6 +95,10 @@ module Hron
           format_ordinal_day_specs(expr.target.specs)
         when LastDayTarget
           "last day"
+        when LastNDaysTarget
+          "last #{expr.target.n} days"
+        when BusinessDayTarget
+          "#{expr.target.n}#{ordinal_suffix(expr.target.n)} business day"
         when LastWeekdayTarget
           "last weekday"
         when NearestWeekdayTarget
@@ -86,6 +114,9 @@ module Hron
           "#{prefix}nearest weekday to #{day}#{ordinal_suffix(day)}"
         when OrdinalWeekdayTarget
           "#{expr.target.ordinal} #{expr.target.weekday}"
+        when OrdinalWeekdaysTarget
+          *rest, last = expr.target.ordinals
+          "#{rest.join(", ")} and #{last} #{expr.target.weekday}"
         else
           raise "unknown month target: #{expr.target.class}"
         end
@@ -101,6 +132,8 @@ module Hron
           "#{expr.date.month} #{expr.date.day}"
         when IsoDate
           expr.date.date
+        when NextWeekdayDate
+          expr.date.day.to_s
         else
           raise "unknown date type: #{expr.date.class}"
         end
@@ -125,6 +158,12 @@ module Hron
           "every year on #{target_str} at #{format_time_list(expr.times)}"
         end
 
+      when WeekdayRepeat
+        "every #{expr.interval} weekdays at #{format_time_list(expr.times)}"
+
+      when IsoWeekRepeat
+        "every year in iso weeks #{expr.weeks.join(", ")} on #{expr.days.join(", ")} at #{format_time_list(expr.times)}"
+
       else
         raise "unknown expression type: #{expr.class}"
       end
@@ -139,7 +178,10 @@ module Hron
       when DayFilterWeekend
         "weekend"
       when DayFilterDays
-        filter.days.join(", ")
+        canonical = Hron.canonical_day_filter(filter)
+        return display_day_filter(canonical) unless canonical.is_a?(DayFilterDays)
+
+        canonical.days.join(", ")
       else
         raise "unknown day filter: #{filter.class}"
       end
@@ -180,6 +222,8 @@ module Hron
     def self.unit_display(interval, unit)
       if unit == IntervalUnit::MIN
         (interval == 1) ? "minute" : "min"
+      elsif unit == IntervalUnit::DAYS
+        (interval == 1) ? "day" : "days"
       else
         (interval == 1) ? "hour" : "hours"
       end
@@ -157,6 +157,20 @@ module Hron
       Date.new(year, month, -1)
     end
 
+    # The final n days of the month, ascending; the whole month if shorter.
+    def self.last_n_days_of_month(year, month, n)
+      last = last_day_of_month(year, month)
+      ([last.day - n + 1, 1].max..last.day).map { |day| Date.new(year, month, day) }
+    end
+
+    # The nth business day (Mon-Fri, 1-indexed) of a month, not counting
+    # excepted days. nil if the month has fewer.
+    def self.nth_business_day(year, month, n, exceptions)
+      last = last_day_of_month(year, month)
+      days = (1..last.day).map { |day| Date.new(year, month, day) }
+      days.reject { |d| d.cwday > 5 || is_excepted(d, exceptions) }[n - 1]
+    end
+
     def self.last_weekday_of_month(year, month)
       d = last_day_of_month(year, month)
       d -= 1 while d.cwday >= 6
@@ -216,6 +230,17 @@ module Hron
       end
     end
 
+    def self.ordinal_weekday(year, month, ordinal, weekday)
+      return last_weekday_in_month(year, month, weekday) if ordinal == OrdinalPosition::LAST
+
+      nth_weekday_of_month(year, month, weekday, OrdinalPosition.to_n(ordinal))
+    end
+
+    # The distinct dates of several ordinal weekdays in a month.
+    def self.ordinal_weekday_dates(year, month, ordinals, weekday)
+      ordinals.filter_map { |o| ordinal_weekday(year, month, o, weekday) }.uniq
+    end
+
     def self.nth_weekday_of_month(year, month, weekday, n)
       target_dow = Weekday.number(weekday)
       d = Date.new(year, month, 1)
@@ -241,6 +266,25 @@ module Hron
       (b - a).to_i
     end
 
+    # Number of business days (Monday-Friday) from the week of the epoch to d.
+    # A weekend date shares the index of the following Monday.
+    def self.business_day_index(d)
+      # 1970-01-01 was a Thursday, 3 days after the Monday that starts its week
+      days = days_between(EPOCH_DATE, d) + 3
+      ((days / 7) * 5) + [days % 7, 5].min
+    end
+
+    # The weekday with the given business day index.
+    def self.business_day_date(index)
+      EPOCH_DATE + (((index / 5) * 7) + (index % 5) - 3)
+    end
+
+    # Whether d is a business day an `every N weekdays` schedule fires on.
+    def self.weekday_interval_aligned(anchor_date, interval, d)
+      offset = business_day_index(d) - business_day_index(anchor_date || EPOCH_DATE)
+      d.cwday <= 5 && offset >= 0 && (offset % interval).zero?
+    end
+
     def self.months_between_ym(a, b)
       (b.year * 12) + b.month - ((a.year * 12) + a.month)
     end
@@ -252,27 +296,93 @@ module Hron
           d.month == MonthName.number(exc.month) && d.day == exc.day
         when IsoException
           d == Date.parse(exc.date)
+        when WeekdayException
+          d.cwday == Weekday.number(exc.day)
+        when IsoRangeException, NamedRangeException
+          !range_bounds(d, exc).nil?
         else
           false
         end
       end
     end
 
+    # Concrete [start, end] bounds of a range exception if it covers d.
+    def self.range_bounds(d, exc)
+      case exc
+      when IsoRangeException
+        start = Date.parse(exc.start)
+        finish = Date.parse(exc.end_date)
+        return nil if d < start || d > finish
+
+        [start, finish]
+      when NamedRangeException
+        start_month = MonthName.number(exc.start_month)
+        end_month = MonthName.number(exc.end_month)
+        start_key = (start_month * 100) + exc.start_day
+        end_key = (end_month * 100) + exc.end_day
+        key = (d.month * 100) + d.day
+        if start_key <= end_key
+          return nil if key < start_key || key > end_key
+
+          start_year = d.year
+          end_year = d.year
+        elsif key >= start_key
+          # Wraps into next year
+          start_year = d.year
+          end_year = d.year + 1
+        elsif key <= end_key
+          start_year = d.year - 1
+          end_year = d.year
+        else
+          return nil
+        end
+        [clamped_date(start_year, start_month, exc.start_day), clamped_date(end_year, end_month, exc.end_day)]
+      end
+    end
+
+    # The date for `month day` in year, with days past the month's end (feb 29
+    # in a common year) moved back to its last day.
+    def self.clamped_date(year, month, day)
+      Date.new(year, month, [day, last_day_of_month(year, month).day].min)
+    end
+
+    # The last day of the excepted run d falls in, so long ranges are skipped
+    # in one step rather than day by day.
+    def self.excepted_run_end(d, exceptions)
+      exceptions.filter_map { |exc| range_bounds(d, exc)&.last }.push(d).max
+    end
+
+    # The first day of the excepted run d falls in.
+    def self.excepted_run_start(d, exceptions)
+      exceptions.filter_map { |exc| range_bounds(d, exc)&.first }.push(d).min
+    end
+
+    # Check if a date falls within any `during` spec. If `during` is empty,
+    # all dates match.
     def self.matches_during(d, during)
       return true if during.empty?
 
-      during.any? { |mn| MonthName.number(mn) == d.month }
+      month = MonthName.from_number(d.month)
+      during.any? { |spec| Hron.during_contains(spec, month, d.day) }
     end
 
-    def self.next_during_month(d, during)
-      months = during.map { |mn| MonthName.number(mn) }.sort
+    # The month-day in year, or the day after the month's end when the day
+    # does not exist that year (feb 29 in a common year).
+    def self.month_day_in_year(year, month, day)
+      last = last_day_of_month(year, MonthName.number(month))
+      return last + 1 if day > last.day
 
-      months.each do |m|
-        return Date.new(d.year, m, 1) if m > d.month
-      end
+      Date.new(year, last.month, day)
+    end
 
-      # Wrap to first month of next year
-      Date.new(d.year + 1, months[0], 1)
+    # Find the first day of the next `during` period starting after d.
+    def self.next_during_start(d, during)
+      starts = during.map do |spec|
+        start_day = Hron.during_bounds(spec)[0]
+        start = month_day_in_year(d.year, spec.start, start_day)
+        (start > d) ? start : month_day_in_year(d.year + 1, spec.start, start_day)
+      end
+      starts.min
     end
 
     def self.resolve_until(until_spec, now)
@@ -313,36 +423,100 @@ module Hron
       best
     end
 
-    def self.prev_during_month(d, during)
-      months = during.map { |mn| MonthName.number(mn) }.sort.reverse
-
-      months.each do |m|
-        return last_day_of_month(d.year, m) if m < d.month
+    # Dates in ISO week-numbering year on one of days in one of weeks,
+    # ascending. Week 53 yields nothing in years with only 52 weeks.
+    def self.iso_week_dates(year, weeks, days)
+      dates = weeks.product(days).filter_map do |week, wd|
+        dow = Weekday.number(wd)
+        Date.commercial(year, week, dow) if Date.valid_commercial?(year, week, dow)
       end
+      dates.uniq.sort
+    end
 
-      # Wrap to last month of previous year
-      return last_day_of_month(d.year - 1, months[0]) unless months.empty?
-
-      nil
+    # Find the last day of the previous `during` period ending before d.
+    def self.prev_during_end(d, during)
+      ends = during.map do |spec|
+        end_day = Hron.during_bounds(spec)[1]
+        finish = [d.year, d.year - 1].map do |year|
+          last = last_day_of_month(year, MonthName.number(spec.end_month))
+          Date.new(year, last.month, [end_day, last.day].min)
+        end
+        (finish[0] < d) ? finish[0] : finish[1]
+      end
+      ends.max
     end
   end
 
   # Main evaluator class
   class Evaluator
+    # ISO years searched for a matching week; week 53 only exists in long ISO
+    # years.
+    ISO_YEAR_HORIZON = 7
+
+    # Pin a relative anchor to a date in the schedule timezone: `starting today`
+    # and `starting now` to now's date, `starting jan 6` to the next jan 6 on or
+    # after it.
+    def self.resolve_anchor(schedule, now)
+      return schedule unless schedule.relative_anchor?
+
+      tz = TzResolver.resolve(schedule.timezone)
+      today = tz.utc_to_local(now.utc).to_date
+      named = schedule.anchor_named
+      return schedule.with(anchor: today.iso8601) unless named
+
+      # Try up to 8 years forward (covers leap year cycles)
+      8.times do |y|
+        d = Date.new(today.year + y, MonthName.number(named.month), named.day)
+        return schedule.with(anchor: d.iso8601, anchor_named: nil) if d >= today
+      rescue ArgumentError
+        next
+      end
+      raise HronError.eval("starting date #{schedule.anchor_text} never occurs")
+    end
+
+    # The instant nothing occurs before: the resolved anchor date at the
+    # `starting ... at` time, or at midnight.
+    def self.anchor_start(schedule, tz)
+      return nil unless schedule.anchor
+
+      EvalHelpers.at_time_on_date(Date.parse(schedule.anchor), schedule.anchor_time || TimeOfDay.new(0, 0), tz)
+    end
+
+    # Pin what the schedule resolves against the evaluation time, as seen from
+    # now: `on <weekday>` becomes the one date it names (the nearest such
+    # weekday with a time still ahead), and a relative anchor becomes its date.
+    def self.pin_relative(schedule, now)
+      schedule = resolve_anchor(schedule, now)
+      expr = schedule.expr
+      return schedule unless expr.is_a?(SingleDateExpr) && expr.date.is_a?(NextWeekdayDate)
+
+      tz = TzResolver.resolve(schedule.timezone)
+      nxt = next_single_date(expr.date, expr.times, tz, now)
+      return schedule unless nxt
+
+      date = tz.utc_to_local(nxt.utc).to_date.iso8601
+      schedule.with(expr: expr.with(date: IsoDate.new(date)))
+    end
+
     def self.next_from(schedule, now)
+      schedule = resolve_anchor(schedule, now)
       tz = TzResolver.resolve(schedule.timezone)
       until_date = schedule.until ? EvalHelpers.resolve_until(schedule.until, now) : nil
       has_exceptions = !schedule.except.empty?
       has_during = !schedule.during.empty?
+      during = schedule.during
 
       # Check if expression is NearestWeekday with direction (can cross month boundaries)
       handles_during_internally = schedule.expr.is_a?(MonthRepeat) &&
         schedule.expr.target.is_a?(NearestWeekdayTarget) &&
         !schedule.expr.target.direction.nil?
 
+      # Nothing occurs before the starting anchor, so search from its start
       current = now
+      start = anchor_start(schedule, tz)
+      current = start - Rational(1, 1_000_000_000) if start && now < start
       1000.times do
-        candidate = next_expr(schedule.expr, tz, schedule.anchor, current, schedule.during)
+        candidate = next_expr(schedule.expr, tz, schedule.anchor, current, Hron.during_months(schedule), schedule.except)
         return nil unless candidate
 
         c_date = candidate.to_date
@@ -352,8 +526,9 @@ module Hron
 
         # Apply during filter
         # Skip this check for expressions that handle during internally (NearestWeekday with direction)
-        if has_during && !handles_during_internally && !EvalHelpers.matches_during(c_date, schedule.during)
-          skip_to = EvalHelpers.next_during_month(c_date, schedule.during)
+        if has_during && !handles_during_internally && !EvalHelpers.matches_during(c_date, during)
+          # Skip ahead to the start of the next during period
+          skip_to = EvalHelpers.next_during_start(c_date, during)
           midnight = EvalHelpers.at_time_on_date(skip_to, TimeOfDay.new(0, 0), tz)
           current = midnight - 1
           next
@@ -361,7 +536,8 @@ module Hron
 
         # Apply except filter
         if has_exceptions && EvalHelpers.is_excepted(c_date, schedule.except)
-          next_day = c_date + 1
+          # Advance past this day (or the whole excepted range) and retry
+          next_day = EvalHelpers.excepted_run_end(c_date, schedule.except) + 1
           midnight = EvalHelpers.at_time_on_date(next_day, TimeOfDay.new(0, 0), tz)
           current = midnight - 1
           next
@@ -374,6 +550,7 @@ module Hron
     end
 
     def self.next_n_from(schedule, now, n)
+      schedule = pin_relative(schedule, now)
       results = []
       current = now
       n.times do
@@ -387,10 +564,15 @@ module Hron
     end
 
     def self.previous_from(schedule, now)
+      # Nothing occurs before `starting now`
+      return nil if schedule.anchor == "now"
+
+      schedule = pin_relative(schedule, now)
       tz = TzResolver.resolve(schedule.timezone)
-      anchor_date = schedule.anchor ? Date.parse(schedule.anchor) : nil
+      starting_at = anchor_start(schedule, tz)
       has_exceptions = !schedule.except.empty?
       has_during = !schedule.during.empty?
+      during = schedule.during
 
       # Handle until clause - if now is after until, search from end of until date
       search_from = now
@@ -404,13 +586,13 @@ module Hron
       end
 
       1000.times do
-        candidate = prev_expr(schedule.expr, tz, schedule.anchor, search_from)
+        candidate = prev_expr(schedule.expr, tz, schedule.anchor, search_from, schedule.except)
         return nil unless candidate
 
         c_date = candidate.to_date
 
         # Check if before anchor
-        return nil if anchor_date && c_date < anchor_date
+        return nil if starting_at && candidate < starting_at
 
         # Check until date - should not return occurrences after until
         if schedule.until
@@ -423,16 +605,18 @@ module Hron
 
         # Apply except filter
         if has_exceptions && EvalHelpers.is_excepted(c_date, schedule.except)
-          search_from = candidate
+          # Go back before this day (or the whole excepted range) and retry
+          start = EvalHelpers.excepted_run_start(c_date, schedule.except)
+          search_from = EvalHelpers.at_time_on_date(start, TimeOfDay.new(0, 0), tz)
           next
         end
 
         # Apply during filter
-        if has_during && !EvalHelpers.matches_during(c_date, schedule.during)
-          prev_month = EvalHelpers.prev_during_month(c_date, schedule.during)
-          return nil unless prev_month
+        if has_during && !EvalHelpers.matches_during(c_date, during)
+          prev_end = EvalHelpers.prev_during_end(c_date, during)
+          return nil unless prev_end
 
-          next_day = prev_month + 1
+          next_day = prev_end + 1
           search_from = EvalHelpers.at_time_on_date(next_day, TimeOfDay.new(0, 0), tz)
           next
         end
@@ -443,23 +627,37 @@ module Hron
       nil
     end
 
-    def self.prev_expr(expr, tz, anchor, now)
+    def self.prev_expr(expr, tz, anchor, now, exceptions = [])
       case expr
       when DayRepeat
         prev_day_repeat(expr.interval, expr.days, expr.times, tz, anchor, now)
       when IntervalRepeat
-        prev_interval_repeat(expr.interval, expr.unit, expr.from_time, expr.to_time, expr.day_filter, tz, now)
+        if expr.unit == IntervalUnit::DAYS
+          prev_day_repeat(expr.interval, day_step_filter(expr), [expr.from_time], tz, anchor, now)
+        else
+          prev_interval_repeat(expr.interval, expr.unit, expr.from_time, expr.to_time, expr.day_filter, tz, now)
+        end
       when WeekRepeat
         prev_week_repeat(expr.interval, expr.days, expr.times, tz, anchor, now)
       when MonthRepeat
-        prev_month_repeat(expr.interval, expr.target, expr.times, tz, anchor, now)
+        prev_month_repeat(expr.interval, expr.target, expr.times, tz, anchor, now, exceptions)
       when SingleDateExpr
         prev_single_date(expr.date, expr.times, tz, now)
       when YearRepeat
         prev_year_repeat(expr.interval, expr.target, expr.times, tz, anchor, now)
+      when WeekdayRepeat
+        prev_weekday_repeat(expr.interval, expr.times, tz, anchor, now)
+      when IsoWeekRepeat
+        prev_iso_week_repeat(expr.weeks, expr.days, expr.times, tz, now)
       end
     end
 
+    # The filter of a day-step window narrows the aligned days rather than
+    # shifting them
+    def self.day_step_filter(expr)
+      expr.day_filter || DayFilterEvery.new
+    end
+
     def self.prev_day_repeat(interval, days, times, tz, anchor, now)
       now_local = tz.utc_to_local(now.utc)
       d = now_local.to_date
@@ -557,7 +755,8 @@ module Hron
       nil
     end
 
-    def self.prev_month_repeat(interval, target, times, tz, anchor, now)
+    # Business-day targets skip over days the exceptions remove.
+    def self.prev_month_repeat(interval, target, times, tz, anchor, now, exceptions = [])
       now_local = tz.utc_to_local(now.utc)
       year = now_local.year
       month = now_local.month
@@ -596,18 +795,21 @@ module Hron
           end
         when LastDayTarget
           date_candidates << EvalHelpers.last_day_of_month(year, month)
+        when LastNDaysTarget
+          date_candidates.concat(EvalHelpers.last_n_days_of_month(year, month, target.n))
+        when BusinessDayTarget
+          bd = EvalHelpers.nth_business_day(year, month, target.n, exceptions)
+          date_candidates << bd if bd
         when LastWeekdayTarget
           date_candidates << EvalHelpers.last_weekday_of_month(year, month)
         when NearestWeekdayTarget
           nw = EvalHelpers.nearest_weekday(year, month, target.day, target.direction)
           date_candidates << nw if nw
         when OrdinalWeekdayTarget
-          ordinal_date = if target.ordinal == OrdinalPosition::LAST
-            EvalHelpers.last_weekday_in_month(year, month, target.weekday)
-          else
-            EvalHelpers.nth_weekday_of_month(year, month, target.weekday, OrdinalPosition.to_n(target.ordinal))
-          end
+          ordinal_date = EvalHelpers.ordinal_weekday(year, month, target.ordinal, target.weekday)
           date_candidates << ordinal_date if ordinal_date
+        when OrdinalWeekdaysTarget
+          date_candidates.concat(EvalHelpers.ordinal_weekday_dates(year, month, target.ordinals, target.weekday))
         end
 
         # Sort in descending order for backwards search
@@ -647,6 +849,9 @@ module Hron
           end
         end
         nil
+      when NextWeekdayDate
+        # `previous_from` pins `on <weekday>` to an ISO date first
+        nil
       end
     end
 
@@ -675,9 +880,23 @@ module Hron
       nil
     end
 
+    def self.prev_iso_week_repeat(weeks, days, times, tz, now)
+      start_year = tz.utc_to_local(now.utc).to_date.cwyear
+
+      ISO_YEAR_HORIZON.times do |y|
+        EvalHelpers.iso_week_dates(start_year - y, weeks, days).reverse_each do |d|
+          candidate = EvalHelpers.latest_past_at_times(d, times, tz, now)
+          return candidate if candidate
+        end
+      end
+
+      nil
+    end
+
     # Returns a lazy Enumerator of occurrences starting after `from`.
     # The iterator respects the `until` clause if specified.
     def self.occurrences(schedule, from)
+      schedule = pin_relative(schedule, from)
       Enumerator.new do |yielder|
         current = from
         loop do
@@ -702,40 +921,66 @@ module Hron
     end
 
     def self.matches(schedule, dt)
+      # There is no evaluation time to resolve a relative anchor against;
+      # resolving it against dt would make every date its own anchor.
+      if schedule.relative_anchor?
+        raise HronError.eval("matches has no evaluation time to resolve 'starting #{schedule.anchor_text}' " \
+                             "against; pin the anchor to a date first")
+      end
+
+      # Nor an `on <weekday>` date: every such weekday would be the upcoming one
+      expr = schedule.expr
+      if expr.is_a?(SingleDateExpr) && expr.date.is_a?(NextWeekdayDate)
+        raise HronError.eval("matches has no evaluation time to resolve 'on #{expr.date.day}' " \
+                             "against; use a date instead")
+      end
+
       tz = TzResolver.resolve(schedule.timezone)
       # Convert to local time in the target timezone
       dt_local = tz.utc_to_local(dt.utc)
       d = dt_local.to_date
 
-      return false if !schedule.during.empty? && !EvalHelpers.matches_during(d, schedule.during)
+      return false unless EvalHelpers.matches_during(d, schedule.during)
       return false if EvalHelpers.is_excepted(d, schedule.except)
+      # Nothing occurs before the starting anchor
+      start = anchor_start(schedule, tz)
+      return false if start && dt < start
 
       if schedule.until
         until_date = EvalHelpers.resolve_until(schedule.until, dt)
         return false if d > until_date
       end
 
-      matches_expr(schedule.expr, schedule.anchor, d, dt_local, tz)
+      matches_expr(schedule.expr, schedule.anchor, d, dt_local, tz, schedule.except)
     end
 
-    def self.next_expr(expr, tz, anchor, now, during = [])
+    def self.next_expr(expr, tz, anchor, now, during = [], exceptions = [])
       case expr
       when DayRepeat
         next_day_repeat(expr.interval, expr.days, expr.times, tz, anchor, now)
       when IntervalRepeat
-        next_interval_repeat(expr.interval, expr.unit, expr.from_time, expr.to_time, expr.day_filter, tz, now)
+        # Day-step windows fire once, at the window start, on each aligned day
+        if expr.unit == IntervalUnit::DAYS
+          next_day_repeat(expr.interval, day_step_filter(expr), [expr.from_time], tz, anchor, now)
+        else
+          next_interval_repeat(expr.interval, expr.unit, expr.from_time, expr.to_time, expr.day_filter, tz, now)
+        end
       when WeekRepeat
         next_week_repeat(expr.interval, expr.days, expr.times, tz, anchor, now)
       when MonthRepeat
-        next_month_repeat(expr.interval, expr.target, expr.times, tz, anchor, now, during)
+        next_month_repeat(expr.interval, expr.target, expr.times, tz, anchor, now, during, exceptions)
       when SingleDateExpr
         next_single_date(expr.date, expr.times, tz, now)
       when YearRepeat
         next_year_repeat(expr.interval, expr.target, expr.times, tz, anchor, now)
+      when WeekdayRepeat
+        next_weekday_repeat(expr.interval, expr.times, tz, anchor, now)
+      when IsoWeekRepeat
+        next_iso_week_repeat(expr.weeks, expr.days, expr.times, tz, now)
       end
     end
 
-    def self.matches_expr(expr, anchor, d, dt, tz)
+    def self.matches_expr(expr, anchor, d, dt, tz, exceptions = [])
       time_matches = ->(times) { time_matches_with_dst(times, d, dt, tz) }
 
       case expr
@@ -753,6 +998,14 @@ module Hron
       when IntervalRepeat
         return false if expr.day_filter && !EvalHelpers.matches_day_filter(d, expr.day_filter)
 
+        if expr.unit == IntervalUnit::DAYS
+          return false unless time_matches.call([expr.from_time])
+
+          anchor_date = anchor ? Date.parse(anchor) : EPOCH_DATE
+          day_offset = EvalHelpers.days_between(anchor_date, d)
+          return day_offset >= 0 && (day_offset % expr.interval).zero?
+        end
+
         from_minutes = (expr.from_time.hour * 60) + expr.from_time.minute
         to_minutes = (expr.to_time.hour * 60) + expr.to_time.minute
         current_minutes = (dt.hour * 60) + dt.min
@@ -779,7 +1032,7 @@ module Hron
           month_offset = EvalHelpers.months_between_ym(anchor_date, d)
           return false if month_offset.negative? || (month_offset % expr.interval) != 0
         end
-        matches_month_target(expr.target, d)
+        matches_month_target(expr.target, d, exceptions)
 
       when SingleDateExpr
         return false unless time_matches.call(expr.times)
@@ -796,6 +1049,18 @@ module Hron
         end
         matches_year_target(expr.target, d)
 
+      when WeekdayRepeat
+        anchor_date = anchor ? Date.parse(anchor) : nil
+        return false unless EvalHelpers.weekday_interval_aligned(anchor_date, expr.interval, d)
+
+        time_matches.call(expr.times)
+
+      when IsoWeekRepeat
+        return false unless expr.weeks.include?(d.cweek)
+        return false unless expr.days.any? { |wd| Weekday.number(wd) == d.cwday }
+
+        time_matches.call(expr.times)
+
       else
         false
       end
@@ -813,25 +1078,27 @@ module Hron
       end
     end
 
-    def self.matches_month_target(target, d)
+    def self.matches_month_target(target, d, exceptions = [])
       case target
       when DaysTarget
         expanded = Hron.expand_month_target(target)
         expanded.include?(d.day)
       when LastDayTarget
         d == EvalHelpers.last_day_of_month(d.year, d.month)
+      when LastNDaysTarget
+        d.day > EvalHelpers.last_day_of_month(d.year, d.month).day - target.n
+      when BusinessDayTarget
+        d == EvalHelpers.nth_business_day(d.year, d.month, target.n, exceptions)
       when LastWeekdayTarget
         d == EvalHelpers.last_weekday_of_month(d.year, d.month)
       when NearestWeekdayTarget
         target_date = EvalHelpers.nearest_weekday(d.year, d.month, target.day, target.direction)
         target_date && d == target_date
       when OrdinalWeekdayTarget
-        ordinal_date = if target.ordinal == OrdinalPosition::LAST
-          EvalHelpers.last_weekday_in_month(d.year, d.month, target.weekday)
-        else
-          EvalHelpers.nth_weekday_of_month(d.year, d.month, target.weekday, OrdinalPosition.to_n(target.ordinal))
-        end
+        ordinal_date = EvalHelpers.ordinal_weekday(d.year, d.month, target.ordinal, target.weekday)
         ordinal_date && d == ordinal_date
+      when OrdinalWeekdaysTarget
+        EvalHelpers.ordinal_weekday_dates(d.year, d.month, target.ordinals, target.weekday).include?(d)
       else
         false
       end
@@ -843,6 +1110,9 @@ module Hron
         d == Date.parse(date_spec.date)
       when NamedDate
         d.month == MonthName.number(date_spec.month) && d.day == date_spec.day
+      when NextWeekdayDate
+        # matches rejects it up front; any day with that weekday can be it
+        d.cwday == Weekday.number(date_spec.day)
       else
         false
       end
@@ -895,15 +1165,18 @@ module Hron
         return nil
       end
 
-      # Interval > 1
+      # Interval > 1: the filter narrows the aligned days, skipping (not
+      # moving) any that fail it
       anchor_date = anchor ? Date.parse(anchor) : EPOCH_DATE
       offset = EvalHelpers.days_between(anchor_date, d)
       remainder = offset % interval
       aligned_date = remainder.zero? ? d : d + (interval - remainder)
 
       400.times do
-        candidate = EvalHelpers.earliest_future_at_times(aligned_date, times, tz, now)
-        return candidate if candidate
+        if EvalHelpers.matches_day_filter(aligned_date, days)
+          candidate = EvalHelpers.earliest_future_at_times(aligned_date, times, tz, now)
+          return candidate if candidate
+        end
 
         aligned_date += interval
       end
@@ -994,7 +1267,8 @@ module Hron
       nil
     end
 
-    def self.next_month_repeat(interval, target, times, tz, anchor, now, during = [])
+    # Business-day targets skip over days the exceptions remove.
+    def self.next_month_repeat(interval, target, times, tz, anchor, now, during = [], exceptions = [])
       now_local = tz.utc_to_local(now.utc)
       year = now_local.year
       month = now_local.month
@@ -1049,18 +1323,21 @@ module Hron
           end
         when LastDayTarget
           date_candidates << EvalHelpers.last_day_of_month(year, month)
+        when LastNDaysTarget
+          date_candidates.concat(EvalHelpers.last_n_days_of_month(year, month, target.n))
+        when BusinessDayTarget
+          bd = EvalHelpers.nth_business_day(year, month, target.n, exceptions)
+          date_candidates << bd if bd
         when LastWeekdayTarget
           date_candidates << EvalHelpers.last_weekday_of_month(year, month)
         when NearestWeekdayTarget
           nw = EvalHelpers.nearest_weekday(year, month, target.day, target.direction)
           date_candidates << nw if nw
         when OrdinalWeekdayTarget
-          ordinal_date = if target.ordinal == OrdinalPosition::LAST
-            EvalHelpers.last_weekday_in_month(year, month, target.weekday)
-          else
-            EvalHelpers.nth_weekday_of_month(year, month, target.weekday, OrdinalPosition.to_n(target.ordinal))
-          end
+          ordinal_date = EvalHelpers.ordinal_weekday(year, month, target.ordinal, target.weekday)
           date_candidates << ordinal_date if ordinal_date
+        when OrdinalWeekdaysTarget
+          date_candidates.concat(EvalHelpers.ordinal_weekday_dates(year, month, target.ordinals, target.weekday))
         end
 
         best = nil
@@ -1099,6 +1376,17 @@ module Hron
           end
         end
         nil
+      when NextWeekdayDate
+        # Today if a time is still ahead, otherwise within the next week
+        today = tz.utc_to_local(now.utc).to_date
+        8.times do |offset|
+          d = today + offset
+          next unless d.cwday == Weekday.number(date_spec.day)
+
+          candidate = EvalHelpers.earliest_future_at_times(d, times, tz, now)
+          return candidate if candidate
+        end
+        nil
       end
     end
 
@@ -1127,6 +1415,54 @@ module Hron
       nil
     end
 
+    def self.next_iso_week_repeat(weeks, days, times, tz, now)
+      start_year = tz.utc_to_local(now.utc).to_date.cwyear
+
+      ISO_YEAR_HORIZON.times do |y|
+        EvalHelpers.iso_week_dates(start_year + y, weeks, days).each do |d|
+          candidate = EvalHelpers.earliest_future_at_times(d, times, tz, now)
+          return candidate if candidate
+        end
+      end
+
+      nil
+    end
+
+    # Today if it is an aligned business day with a time still ahead,
+    # otherwise the next aligned business day.
+    def self.next_weekday_repeat(interval, times, tz, anchor, now)
+      today = tz.utc_to_local(now.utc).to_date
+      anchor_index = EvalHelpers.business_day_index(anchor ? Date.parse(anchor) : EPOCH_DATE)
+      today_index = EvalHelpers.business_day_index(today)
+      is_weekday = today.cwday <= 5
+
+      if is_weekday && ((today_index - anchor_index) % interval).zero?
+        candidate = EvalHelpers.earliest_future_at_times(today, times, tz, now)
+        return candidate if candidate
+      end
+
+      # A weekend already shares the following Monday's index
+      after = is_weekday ? today_index + 1 : today_index
+      index = after + ((anchor_index - after) % interval)
+      EvalHelpers.earliest_future_at_times(EvalHelpers.business_day_date(index), times, tz, now)
+    end
+
+    def self.prev_weekday_repeat(interval, times, tz, anchor, now)
+      today = tz.utc_to_local(now.utc).to_date
+      anchor_index = EvalHelpers.business_day_index(anchor ? Date.parse(anchor) : EPOCH_DATE)
+      today_index = EvalHelpers.business_day_index(today)
+
+      if today.cwday <= 5 && ((today_index - anchor_index) % interval).zero?
+        candidate = EvalHelpers.latest_past_at_times(today, times, tz, now)
+        return candidate if candidate
+      end
+
+      # On a weekend the index is the following Monday's, so one back is Friday
+      before = today_index - 1
+      index = before - ((before - anchor_index) % interval)
+      EvalHelpers.latest_past_at_times(EvalHelpers.business_day_date(index), times, tz, now)
+    end
+
     def self.compute_year_target_date(target, year)
       case target
       when YearDateTarget
@@ -11,6 +11,9 @@ module Hron
     AT = :at
     FROM = :from
     TO = :to
+    THROUGH = :through
+    BUSINESS = :business
+    PAST = :past
     IN = :in
     OF = :of
     THE = :the
@@ -23,6 +26,9 @@ module Hron
     NEAREST = :nearest
     NEXT = :next
     PREVIOUS = :previous
+    BETWEEN = :between
+    AND = :and
+    ISO = :iso
     DAY = :day
     WEEKDAY_KW = :weekday_kw
     WEEKEND_KW = :weekend_kw
@@ -34,13 +40,19 @@ module Hron
   # Token types with values
   TDayName = Data.define(:name)
   TMonthName = Data.define(:name)
-  TOrdinal = Data.define(:position)
+  TOrdinal = Data.define(:word, :value) # spelled-out ordinal ("first", "twenty-first") and its day number
   TIntervalUnit = Data.define(:unit)
   TNumber = Data.define(:value)
+  TDecimal = Data.define(:text)
+  TMinuteOffset = Data.define(:minute) # ":15", minutes past the hour
   TOrdinalNumber = Data.define(:value)
   TTime = Data.define(:hour, :minute)
   TIsoDate = Data.define(:date)
   TTimezone = Data.define(:tz)
+  TFrequency = Data.define(:word)
+  TShortcut = Data.define(:name) # "@daily", lowercase with the "@"
+  TPeriod = Data.define(:word)
+  TRelative = Data.define(:word) # "today", "now"
 
   # Token with kind and span
   Token = Data.define(:kind, :span)
@@ -52,6 +64,9 @@ module Hron
     "at" => TokenKind::AT,
     "from" => TokenKind::FROM,
     "to" => TokenKind::TO,
+    "through" => TokenKind::THROUGH,
+    "business" => TokenKind::BUSINESS,
+    "past" => TokenKind::PAST,
     "in" => TokenKind::IN,
     "of" => TokenKind::OF,
     "the" => TokenKind::THE,
@@ -65,6 +80,9 @@ module Hron
     "nearest" => TokenKind::NEAREST,
     "next" => TokenKind::NEXT,
     "previous" => TokenKind::PREVIOUS,
+    "between" => TokenKind::BETWEEN,
+    "and" => TokenKind::AND,
+    "iso" => TokenKind::ISO,
     "day" => TokenKind::DAY,
     "days" => TokenKind::DAY,
     "weekday" => TokenKind::WEEKDAY_KW,
@@ -75,20 +93,41 @@ module Hron
     "week" => TokenKind::WEEKS,
     "month" => TokenKind::MONTH,
     "months" => TokenKind::MONTH,
+    # Frequency keywords
+    "daily" => TFrequency.new("daily"),
+    "weekly" => TFrequency.new("weekly"),
+    "monthly" => TFrequency.new("monthly"),
+    "yearly" => TFrequency.new("yearly"),
+    "hourly" => TFrequency.new("hourly"),
+    # Times of day
+    "morning" => TPeriod.new("morning"),
+    "afternoon" => TPeriod.new("afternoon"),
+    "evening" => TPeriod.new("evening"),
+    "night" => TPeriod.new("night"),
+    # Relative anchors
+    "today" => TRelative.new("today"),
+    "now" => TRelative.new("now"),
     # Day names
     "monday" => TDayName.new(Weekday::MONDAY),
+    "mondays" => TDayName.new(Weekday::MONDAY),
     "mon" => TDayName.new(Weekday::MONDAY),
     "tuesday" => TDayName.new(Weekday::TUESDAY),
+    "tuesdays" => TDayName.new(Weekday::TUESDAY),
     "tue" => TDayName.new(Weekday::TUESDAY),
     "wednesday" => TDayName.new(Weekday::WEDNESDAY),
+    "wednesdays" => TDayName.new(Weekday::WEDNESDAY),
     "wed" => TDayName.new(Weekday::WEDNESDAY),
     "thursday" => TDayName.new(Weekday::THURSDAY),
+    "thursdays" => TDayName.new(Weekday::THURSDAY),
     "thu" => TDayName.new(Weekday::THURSDAY),
     "friday" => TDayName.new(Weekday::FRIDAY),
+    "fridays" => TDayName.new(Weekday::FRIDAY),
     "fri" => TDayName.new(Weekday::FRIDAY),
     "saturday" => TDayName.new(Weekday::SATURDAY),
+    "saturdays" => TDayName.new(Weekday::SATURDAY),
     "sat" => TDayName.new(Weekday::SATURDAY),
     "sunday" => TDayName.new(Weekday::SUNDAY),
+    "sundays" => TDayName.new(Weekday::SUNDAY),
     "sun" => TDayName.new(Weekday::SUNDAY),
     # Month names
     "january" => TMonthName.new(MonthName::JAN),
@@ -114,12 +153,6 @@ module Hron
     "nov" => TMonthName.new(MonthName::NOV),
     "december" => TMonthName.new(MonthName::DEC),
     "dec" => TMonthName.new(MonthName::DEC),
-    # Ordinals
-    "first" => TOrdinal.new(OrdinalPosition::FIRST),
-    "second" => TOrdinal.new(OrdinalPosition::SECOND),
-    "third" => TOrdinal.new(OrdinalPosition::THIRD),
-    "fourth" => TOrdinal.new(OrdinalPosition::FOURTH),
-    "fifth" => TOrdinal.new(OrdinalPosition::FIFTH),
     # Interval units
     "min" => TIntervalUnit.new(IntervalUnit::MIN),
     "mins" => TIntervalUnit.new(IntervalUnit::MIN),
@@ -131,6 +164,27 @@ module Hron
     "hrs" => TIntervalUnit.new(IntervalUnit::HOURS)
   }.freeze
 
+  ORDINAL_UNITS = %w[first second third fourth fifth sixth seventh eighth ninth].freeze
+  ORDINAL_TEENS = %w[
+    tenth eleventh twelfth thirteenth fourteenth fifteenth sixteenth seventeenth eighteenth nineteenth
+  ].freeze
+
+  # Day number of an ordinal word: `first` is 1, `thirty-first` is 31.
+  def self.ordinal_word_value(word)
+    return 20 if word == "twentieth"
+    return 30 if word == "thirtieth"
+    return 31 if word == "thirty-first"
+
+    if word.start_with?("twenty-")
+      unit = ORDINAL_UNITS.index(word.delete_prefix("twenty-"))
+      return unit && 21 + unit
+    end
+    return 1 + ORDINAL_UNITS.index(word) if ORDINAL_UNITS.include?(word)
+    return 10 + ORDINAL_TEENS.index(word) if ORDINAL_TEENS.include?(word)
+
+    nil
+  end
+
   # Lexer class
   class Lexer
     def initialize(input)
@@ -165,6 +219,18 @@ module Hron
           next
         end
 
+        # Cron shortcut: @daily
+        if ch == "@"
+          tokens << lex_shortcut
+          next
+        end
+
+        # Minutes past the hour: :MM
+        if ch == ":"
+          tokens << lex_minute_offset
+          next
+        end
+
         if ch.match?(/[a-zA-Z]/)
           tokens << lex_word
           next
@@ -177,6 +243,13 @@ module Hron
 
     private
 
+    def next_word_is?(word)
+      rest = @input[@pos..].lstrip
+      return false unless rest[0, word.length]&.downcase == word
+
+      rest.length == word.length || !rest[word.length].match?(/\w/)
+    end
+
     def skip_whitespace
       @pos += 1 while @pos < @input.length && @input[@pos].match?(/\s/)
     end
@@ -191,6 +264,25 @@ module Hron
       Token.new(TTimezone.new(tz), Span.new(start, @pos))
     end
 
+    def lex_shortcut
+      start = @pos
+      @pos += 1 # skip '@'
+      @pos += 1 while @pos < @input.length && @input[@pos].match?(/[a-zA-Z0-9]/)
+      Token.new(TShortcut.new(@input[start...@pos].downcase), Span.new(start, @pos))
+    end
+
+    def lex_minute_offset
+      start = @pos
+      @pos += 1 # skip ':'
+      @pos += 1 while @pos < @input.length && @input[@pos].match?(/\d/)
+      digits = @input[(start + 1)...@pos]
+      if digits.length != 2 || digits.to_i > 59
+        raise HronError.lex("expected two-digit minute (:00 to :59) after ':'", Span.new(start, [@pos, start + 1].max), @input)
+      end
+
+      Token.new(TMinuteOffset.new(digits.to_i), Span.new(start, @pos))
+    end
+
     def lex_number_or_time_or_date
       start = @pos
       @pos += 1 while @pos < @input.length && @input[@pos].match?(/\d/)
@@ -218,12 +310,28 @@ module Hron
         if min_digits.length == 2
           hour = digits.to_i
           minute = min_digits.to_i
+          pm = lex_meridiem
+          return meridiem_time(hour, minute, pm, start) unless pm.nil?
+
           raise HronError.lex("invalid time", Span.new(start, @pos), @input) if hour > 23 || minute > 59
 
           return Token.new(TTime.new(hour, minute), Span.new(start, @pos))
         end
       end
 
+      # 12-hour time without minutes: 9am, 5pm
+      if digits.length.between?(1, 2)
+        pm = lex_meridiem
+        return meridiem_time(digits.to_i, 0, pm, start) unless pm.nil?
+      end
+
+      # Decimal: 1.5
+      if @pos + 1 < @input.length && @input[@pos] == "." && @input[@pos + 1].match?(/\d/)
+        @pos += 1 # skip '.'
+        @pos += 1 while @pos < @input.length && @input[@pos].match?(/\d/)
+        return Token.new(TDecimal.new(@input[start...@pos]), Span.new(start, @pos))
+      end
+
       num = digits.to_i
 
       # Check for ordinal suffix: st, nd, rd, th
@@ -238,16 +346,49 @@ module Hron
       Token.new(TNumber.new(num), Span.new(start, @pos))
     end
 
+    # Consume an am/pm suffix directly following a time. Returns whether it
+    # was pm, or nil if there is no suffix.
+    def lex_meridiem
+      return nil if @pos + 2 > @input.length
+
+      suffix = @input[@pos, 2].downcase
+      return nil unless %w[am pm].include?(suffix)
+      # Must not run into a longer word (e.g. "9amx")
+      return nil if @pos + 2 < @input.length && @input[@pos + 2].match?(/\w/)
+
+      @pos += 2
+      suffix == "pm"
+    end
+
+    # Convert a 12-hour clock time to a 24-hour time token.
+    def meridiem_time(hour, minute, pm, start)
+      if hour < 1 || hour > 12 || minute > 59
+        raise HronError.lex("invalid 12-hour time (hour must be 1-12)", Span.new(start, @pos), @input)
+      end
+
+      h = if hour == 12
+        pm ? 12 : 0
+      else
+        pm ? hour + 12 : hour
+      end
+      Token.new(TTime.new(h, minute), Span.new(start, @pos))
+    end
+
     def lex_word
       start = @pos
-      @pos += 1 while @pos < @input.length && @input[@pos].match?(/\w/)
+      # Hyphenated ordinals: "twenty-first"
+      @pos += 1 while @pos < @input.length &&
+        (@input[@pos].match?(/\w/) || (@input[@pos] == "-" && @input[@pos + 1]&.match?(/[a-zA-Z]/)))
       word = @input[start...@pos].downcase
       span = Span.new(start, @pos)
 
       kind = KEYWORD_MAP[word]
+      ordinal = Hron.ordinal_word_value(word)
+      kind = TOrdinal.new(word, ordinal) if kind.nil? && ordinal
       raise HronError.lex("unknown keyword '#{word}'", span, @input) if kind.nil?
 
-      @after_in = true if kind == TokenKind::IN
+      # "in the morning" and "in iso weeks" are not timezone clauses
+      @after_in = !next_word_is?("the") && !next_word_is?("iso") if kind == TokenKind::IN
 
       Token.new(kind, span)
     end
@@ -2,12 +2,22 @@
 
 require "date"
 require_relative "ast"
+require_relative "cron"
 require_relative "error"
 require_relative "lexer"
 
 module Hron
   # Parser for hron expressions
   class Parser
+    # Input-only sugar: "in the <period>" fires at a fixed time and displays as
+    # HH:MM.
+    PERIOD_TIMES = {
+      "morning" => TimeOfDay.new(9, 0),
+      "afternoon" => TimeOfDay.new(14, 0),
+      "evening" => TimeOfDay.new(18, 0),
+      "night" => TimeOfDay.new(21, 0)
+    }.freeze
+
     def initialize(tokens, input)
       @tokens = tokens
       @pos = 0
@@ -92,8 +102,15 @@ module Hron
       when TokenKind::ON
         advance
         expr = parse_on
+      when TFrequency
+        expr = parse_frequency
+      when TShortcut
+        expr = parse_shortcut
+      when TokenKind::AT
+        advance
+        expr = parse_past_every_hour
       else
-        raise error("expected 'every' or 'on'", span)
+        raise error("expected 'every', 'on', 'at', or a frequency keyword", span)
       end
 
       parse_trailing_clauses(expr)
@@ -132,17 +149,38 @@ module Hron
       if peek_kind == TokenKind::STARTING
         advance
         k = peek_kind
-        raise error("expected ISO date (YYYY-MM-DD) after 'starting'", current_span) unless k.is_a?(TIsoDate)
+        anchor = nil
+        anchor_named = nil
+        if k.is_a?(TRelative)
+          anchor = k.word
+          advance
+        elsif k.is_a?(TMonthName)
+          advance
+          day = parse_day_number("expected day number after month name in starting")
+          validate_named_date(k.name, day)
+          anchor_named = NamedDate.new(k.name, day)
+        else
+          raise error("expected ISO date (YYYY-MM-DD), month-day, 'today', or 'now' after 'starting'", current_span) unless k.is_a?(TIsoDate)
 
-        validate_iso_date(k.date)
-        anchor = k.date
-        advance
+          validate_iso_date(k.date)
+          anchor = k.date
+          advance
+        end
+        anchor_time = nil
+        if peek_kind == TokenKind::AT
+          raise error("'starting now' already includes a time", current_span) if anchor == "now"
+
+          advance
+          anchor_time = parse_time
+        end
         schedule = ScheduleData.new(
           expr: schedule.expr,
           timezone: schedule.timezone,
           except: schedule.except,
           until: schedule.until,
           anchor: anchor,
+          anchor_named: anchor_named,
+          anchor_time: anchor_time,
           during: schedule.during
         )
 
@@ -157,7 +195,9 @@ module Hron
           except: schedule.except,
           until: schedule.until,
           anchor: schedule.anchor,
-          during: parse_month_list
+          anchor_named: schedule.anchor_named,
+          anchor_time: schedule.anchor_time,
+          during: parse_during_list
         )
       end
 
@@ -175,19 +215,64 @@ module Hron
           except: schedule.except,
           until: schedule.until,
           anchor: schedule.anchor,
+          anchor_named: schedule.anchor_named,
+          anchor_time: schedule.anchor_time,
           during: schedule.during
         )
 
       end
 
+      check_day_steps_reachable(schedule)
       schedule
     end
 
+    # Reject a day step whose filter excludes every aligned day. A step that is
+    # a multiple of 7 lands on the anchor's weekday every time, so a filter
+    # without that weekday never fires. With a relative anchor (`starting today`,
+    # `starting now`, or a month-day) that weekday depends on the evaluation
+    # date, so such a step needs a filter that lets every day through.
+    def check_day_steps_reachable(schedule)
+      expr = schedule.expr
+      filter = case expr
+      when DayRepeat then expr.days
+      when IntervalRepeat then expr.day_filter if expr.unit == IntervalUnit::DAYS
+      end
+      return unless filter && (expr.interval % 7).zero?
+
+      allowed = case filter
+      when DayFilterEvery then Weekday::ALL
+      when DayFilterWeekday then Weekday::WEEKDAYS
+      when DayFilterWeekend then Weekday::WEEKEND
+      when DayFilterDays then filter.days
+      end
+      if schedule.relative_anchor?
+        return if allowed.uniq.length == 7
+
+        raise HronError.parse(
+          "every #{expr.interval} days starting #{schedule.anchor_text} lands on the weekday of the evaluation date, " \
+          "which the day filter may exclude",
+          Span.new(0, @input.length),
+          @input
+        )
+      end
+
+      weekday = Weekday.from_number(Date.parse(schedule.anchor || "1970-01-01").cwday)
+      return if allowed.include?(weekday)
+
+      raise HronError.parse(
+        "every #{expr.interval} days always lands on a #{weekday}, which the day filter excludes",
+        Span.new(0, @input.length),
+        @input
+      )
+    end
+
+    # "except [on] exception, ...": dates, day names, "weekday", "weekend"
     def parse_exception_list
-      exceptions = [parse_exception]
+      advance if peek_kind == TokenKind::ON
+      exceptions = parse_exception
       while peek_kind == TokenKind::COMMA
         advance
-        exceptions << parse_exception
+        exceptions.concat(parse_exception)
       end
       exceptions
     end
@@ -199,20 +284,7 @@ module Hron
     end
 
     def validate_named_date(month, day)
-      max = case month
-      when MonthName::JAN then 31
-      when MonthName::FEB then 29
-      when MonthName::MAR then 31
-      when MonthName::APR then 30
-      when MonthName::MAY then 31
-      when MonthName::JUN then 30
-      when MonthName::JUL then 31
-      when MonthName::AUG then 31
-      when MonthName::SEP then 30
-      when MonthName::OCT then 31
-      when MonthName::NOV then 30
-      when MonthName::DEC then 31
-      end
+      max = MonthName.max_day(month)
       return if day.between?(1, max)
 
       raise error("invalid day #{day} for #{month} (max #{max})", current_span)
@@ -223,16 +295,49 @@ module Hron
       if k.is_a?(TIsoDate)
         validate_iso_date(k.date)
         advance
-        return IsoException.new(k.date)
+        return [IsoException.new(k.date)] unless at_range_separator?
+
+        advance
+        span = current_span
+        finish = peek_kind
+        raise error("expected ISO date after 'to'", span) unless finish.is_a?(TIsoDate)
+
+        validate_iso_date(finish.date)
+        # ISO dates order lexically
+        raise error("exception range ends before #{k.date}", span) if finish.date < k.date
+
+        advance
+        return [IsoRangeException.new(k.date, finish.date)]
       end
       if k.is_a?(TMonthName)
         month = k.name
         advance
         day = parse_day_number("expected day number after month name in exception")
         validate_named_date(month, day)
-        return NamedException.new(month, day)
+        return [NamedException.new(month, day)] unless at_range_separator?
+
+        advance
+        finish = peek_kind
+        raise error("expected month name after 'to'", current_span) unless finish.is_a?(TMonthName)
+
+        advance
+        end_day = parse_day_number("expected day number after month name in exception")
+        validate_named_date(finish.name, end_day)
+        return [NamedRangeException.new(month, day, finish.name, end_day)]
+      end
+      if k.is_a?(TDayName)
+        advance
+        return [WeekdayException.new(k.name)]
       end
-      raise error("expected ISO date or month-day in exception", current_span)
+      if k == TokenKind::WEEKDAY_KW
+        advance
+        return Weekday::WEEKDAYS.map { |day| WeekdayException.new(day) }
+      end
+      if k == TokenKind::WEEKEND_KW
+        advance
+        return Weekday::WEEKEND.map { |day| WeekdayException.new(day) }
+      end
+      raise error("expected ISO date, month-day, or day name in exception", current_span)
     end
 
     def parse_until_spec
@@ -284,6 +389,7 @@ module Hron
         advance
         parse_year_repeat(1)
       when TokenKind::DAY
+        advance
         parse_day_repeat(1, DayFilterEvery.new)
       when TokenKind::WEEKDAY_KW
         advance
@@ -293,15 +399,27 @@ module Hron
         parse_day_repeat(1, DayFilterWeekend.new)
       when TDayName
         days = parse_day_list
-        parse_day_repeat(1, DayFilterDays.new(days))
+        # "every monday every 2 weeks at ..." is a week repeat
+        if peek_kind == TokenKind::EVERY
+          advance
+          interval = parse_week_interval
+          return WeekRepeat.new(interval, days, parse_times_or_midnight)
+        end
+        parse_day_repeat(1, Hron.canonical_day_filter(DayFilterDays.new(days)))
       when TokenKind::MONTH
         advance
         parse_month_repeat(1)
       when TokenKind::WEEKS
         advance
         parse_week_repeat(1)
+      when TIntervalUnit
+        # "every hour at :15", "every minute from 09:00 to 10:00"
+        parse_interval_repeat(1)
       when TNumber
         parse_number_repeat
+      when TDecimal
+        # "every 1.5 hours from ..."
+        parse_fractional_interval
       else
         raise error(
           "expected day, weekday, weekend, year, day name, month, or number after 'every'",
@@ -310,10 +428,61 @@ module Hron
       end
     end
 
+    # Cron `@` shortcut: "@daily" is "every day at 00:00", and takes the same
+    # trailing clauses as that form ("@daily in UTC").
+    def parse_shortcut
+      span = current_span
+      k = peek_kind
+      advance
+      expr = Cron.cron_shortcut_expr(k.name)
+      unless expr
+        raise HronError.parse("unknown @ shortcut: #{k.name}", span, @input,
+          suggestion: "@yearly, @monthly, @weekly, @daily, @midnight, or @hourly")
+      end
+
+      expr
+    end
+
+    # Bare shorthand: "daily", "weekly", "monthly", "yearly", or "hourly". Each
+    # maps to the canonical "every ..." form with interval 1 and accepts what
+    # that form does. Without "on", weekly falls on monday (the default week
+    # start), monthly on the 1st, and yearly on jan 1; without a window, hourly
+    # runs all day.
+    def parse_frequency
+      k = peek_kind
+      advance
+
+      nk = peek_kind
+      has_on = nk == TokenKind::ON
+      case k.word
+      when "daily"
+        parse_day_repeat(1, DayFilterEvery.new)
+      when "weekly"
+        return parse_week_repeat(1) if has_on
+
+        WeekRepeat.new(1, [Weekday::MONDAY], parse_times_or_midnight)
+      when "monthly"
+        return parse_month_repeat(1) if has_on
+
+        MonthRepeat.new(1, DaysTarget.new([SingleDay.new(1)]), parse_times_or_midnight)
+      when "yearly"
+        # "yearly in iso weeks ..." as well as "yearly on ..."
+        iso_weeks = nk == TokenKind::IN && @tokens[@pos + 1]&.kind == TokenKind::ISO
+        return parse_year_repeat(1) if has_on || iso_weeks
+
+        YearRepeat.new(1, YearDateTarget.new(MonthName::JAN, 1), parse_times_or_midnight)
+      else
+        # "hourly at :15" and "hourly from 09:00 to 17:00" as after "every hour"
+        if [TokenKind::AT, TokenKind::FROM, TokenKind::BETWEEN].include?(nk)
+          return parse_interval_window(1, IntervalUnit::HOURS)
+        end
+
+        hourly_at(1, 0, parse_optional_day_filter)
+      end
+    end
+
     def parse_day_repeat(interval, days)
-      consume_keyword("'day'", TokenKind::DAY) if days.is_a?(DayFilterEvery)
-      consume_keyword("'at'", TokenKind::AT)
-      times = parse_time_list
+      times = parse_times_or_midnight
       DayRepeat.new(interval, days, times)
     end
 
@@ -333,7 +502,25 @@ module Hron
       when TIntervalUnit
         parse_interval_repeat(num)
       when TokenKind::DAY
-        parse_day_repeat(num, DayFilterEvery.new)
+        advance
+        # "every N days from HH:MM to HH:MM" - day-step interval window
+        if [TokenKind::FROM, TokenKind::BETWEEN].include?(peek_kind)
+          return parse_interval_window(num, IntervalUnit::DAYS)
+        end
+
+        # "every N days [on day_target] at ..."
+        days = DayFilterEvery.new
+        if peek_kind == TokenKind::ON
+          advance
+          days = parse_day_target
+        end
+        parse_day_repeat(num, days)
+      when TokenKind::WEEKDAY_KW
+        # "every N weekdays at ..." - every Nth business day
+        advance
+        return parse_day_repeat(1, DayFilterWeekday.new) if num == 1
+
+        WeekdayRepeat.new(num, parse_times_or_midnight)
       when TokenKind::MONTH
         advance
         parse_month_repeat(num)
@@ -342,7 +529,7 @@ module Hron
         parse_year_repeat(num)
       else
         raise error(
-          "expected 'weeks', 'min', 'minutes', 'hour', 'hours', 'day(s)', 'month(s)', or 'year(s)' after number",
+          "expected 'weeks', 'min', 'minutes', 'hour', 'hours', 'day(s)', 'weekday(s)', 'month(s)', or 'year(s)' after number",
           current_span
         )
       end
@@ -350,28 +537,127 @@ module Hron
 
     def parse_interval_repeat(interval)
       k = peek_kind
-      unit = k.unit
       advance
 
-      consume_keyword("'from'", TokenKind::FROM)
-      from_time = parse_time
-      consume_keyword("'to'", TokenKind::TO)
-      to_time = parse_time
+      parse_interval_window(interval, k.unit)
+    end
+
+    # "every D hours ..." where D is a decimal: converted to whole minutes, so
+    # "every 1.5 hours" is the same schedule as "every 90 min"
+    def parse_fractional_interval
+      span = current_span
+      k = peek_kind
+      advance
+
+      nk = peek_kind
+      unless nk.is_a?(TIntervalUnit) && nk.unit == IntervalUnit::HOURS
+        raise error("fractional intervals are only supported in hours", span)
+      end
+      advance
+
+      minutes = fractional_hours_to_minutes(k.text)
+      raise error("fractional hours must come to a whole number of minutes", span) if minutes.nil?
+      raise error("interval must be at least 1", span) if minutes.zero?
 
-      day_filter = nil
-      if peek_kind == TokenKind::ON
+      return parse_interval_window(minutes / 60, IntervalUnit::HOURS) if (minutes % 60).zero?
+
+      parse_interval_window(minutes, IntervalUnit::MIN)
+    end
+
+    # Convert decimal hours (1.5) to whole minutes, or nil if they don't come
+    # to a whole number of minutes.
+    def fractional_hours_to_minutes(text)
+      whole, frac = text.split(".")
+      scaled = Integer(whole + frac, 10) * 60
+      scale = 10**frac.length
+      return nil unless (scaled % scale).zero?
+
+      scaled / scale
+    end
+
+    # The window and optional day filter after the interval unit
+    def parse_interval_window(interval, unit)
+      # "every 2 hours at :15": all day, starting MM past midnight
+      if unit == IntervalUnit::HOURS && peek_kind == TokenKind::AT
+        advance
+        minute = parse_minute_past
+        return hourly_at(interval, minute, parse_optional_day_filter)
+      end
+
+      # "from HH:MM to HH:MM" or "between HH:MM and HH:MM"
+      if peek_kind == TokenKind::BETWEEN
         advance
-        day_filter = parse_day_target
+        from_time = parse_time
+        consume_keyword("'and'", TokenKind::AND)
+      else
+        consume_keyword("'from' or 'between'", TokenKind::FROM)
+        from_time = parse_time
+        consume_keyword("'to'", TokenKind::TO)
       end
+      to_time = parse_time
+
+      IntervalRepeat.new(interval, unit, from_time, to_time, parse_optional_day_filter)
+    end
+
+    # Optional "on day_target" after an interval window
+    def parse_optional_day_filter
+      return nil unless peek_kind == TokenKind::ON
 
-      IntervalRepeat.new(interval, unit, from_time, to_time, day_filter)
+      advance
+      parse_day_target
+    end
+
+    # "at 15 past every hour", "at :15 past every hour": "at" already consumed
+    def parse_past_every_hour
+      minute = parse_minute_past(allow_number: true)
+      consume_keyword("'past'", TokenKind::PAST)
+      consume_keyword("'every'", TokenKind::EVERY)
+      k = peek_kind
+      raise error("expected 'hour'", current_span) unless k.is_a?(TIntervalUnit) && k.unit == IntervalUnit::HOURS
+
+      advance
+      hourly_at(1, minute, parse_optional_day_filter)
+    end
+
+    # Minutes past the hour, as ":MM" or (with allow_number) a bare number
+    def parse_minute_past(allow_number: false)
+      k = peek_kind
+      minute = if k.is_a?(TMinuteOffset)
+        k.minute
+      elsif allow_number && k.is_a?(TNumber) && k.value <= 59
+        k.value
+      else
+        raise error("expected minutes past the hour (e.g. :15)", current_span)
+      end
+      advance
+      minute
+    end
+
+    # An all-day hour step firing minute past the hour: "every hour at :15" is
+    # "every 1 hour from 00:15 to 23:59".
+    def hourly_at(interval, minute, day_filter)
+      IntervalRepeat.new(interval, IntervalUnit::HOURS, TimeOfDay.new(0, minute), TimeOfDay.new(23, 59), day_filter)
+    end
+
+    # "week" or "N weeks", after a day list and a second "every"
+    def parse_week_interval
+      span = current_span
+      k = peek_kind
+      interval = 1
+      if k.is_a?(TNumber)
+        raise error("interval must be at least 1", span) if k.value.zero?
+
+        interval = k.value
+        advance
+      end
+      consume_keyword("'week' or 'weeks'", TokenKind::WEEKS)
+      interval
     end
 
     def parse_week_repeat(interval)
       consume_keyword("'on'", TokenKind::ON)
       days = parse_day_list
-      consume_keyword("'at'", TokenKind::AT)
-      times = parse_time_list
+      times = parse_times_or_midnight
       WeekRepeat.new(interval, days, times)
     end
 
@@ -381,31 +667,34 @@ module Hron
 
       k = peek_kind
 
-      if k == TokenKind::LAST
+      # "the first monday", "the first and last friday", as opposed to
+      # "the first" (day 1)
+      if (k.is_a?(TOrdinal) || k == TokenKind::LAST) && ordinal_weekdays_ahead?
+        target = parse_ordinal_weekdays
+      elsif k == TokenKind::LAST
         advance
         nk = peek_kind
         if nk == TokenKind::DAY
           advance
           target = LastDayTarget.new
+        elsif nk.is_a?(TNumber)
+          # "last N days"; "last 1 day" is the last day
+          n = nk.value
+          raise error("invalid day count: #{n} (must be 1-31)", current_span) if n < 1 || n > 31
+
+          advance
+          consume_keyword("'days'", TokenKind::DAY)
+          target = (n == 1) ? LastDayTarget.new : LastNDaysTarget.new(n)
         elsif nk == TokenKind::WEEKDAY_KW
           advance
           target = LastWeekdayTarget.new
-        elsif nk.is_a?(TDayName)
-          weekday = nk.name
-          advance
-          target = OrdinalWeekdayTarget.new(OrdinalPosition::LAST, weekday)
         else
           raise error("expected 'day', 'weekday', or day name after 'last'", current_span)
         end
-      elsif k.is_a?(TOrdinal)
-        ordinal = parse_ordinal_position
-        nk = peek_kind
-        raise error("expected day name after ordinal", current_span) unless nk.is_a?(TDayName)
-
-        weekday = nk.name
-        advance
-        target = OrdinalWeekdayTarget.new(ordinal, weekday)
-      elsif k.is_a?(TOrdinalNumber)
+      elsif (k.is_a?(TOrdinal) || k.is_a?(TOrdinalNumber)) && next_is_business_day?
+        # "the first weekday", "the 3rd business day"
+        target = parse_business_day_target
+      elsif k.is_a?(TOrdinal) || k.is_a?(TOrdinalNumber)
         specs = parse_ordinal_day_list
         target = DaysTarget.new(specs)
       elsif k == TokenKind::NEXT || k == TokenKind::PREVIOUS || k == TokenKind::NEAREST
@@ -414,12 +703,61 @@ module Hron
         raise error("expected ordinal day (1st, 15th), 'last', ordinal (first..fifth), or '[next|previous] nearest' after 'the'", current_span)
       end
 
-      consume_keyword("'at'", TokenKind::AT)
-      times = parse_time_list
+      times = parse_times
       MonthRepeat.new(interval, target, times)
     end
 
+    # Whether a list of ordinals joined by "," or "and" ends in a day name
+    def ordinal_weekdays_ahead?
+      i = @pos
+      while i < @tokens.length
+        ordinal = @tokens[i].kind
+        return false unless ordinal.is_a?(TOrdinal) || ordinal == TokenKind::LAST
+
+        i += 1
+        return false if i >= @tokens.length
+
+        nk = @tokens[i].kind
+        return true if nk.is_a?(TDayName)
+        return false unless nk == TokenKind::COMMA || nk == TokenKind::AND
+
+        i += 1
+      end
+      false
+    end
+
+    def parse_ordinal_weekdays
+      ordinals = [parse_ordinal_position]
+      while peek_kind == TokenKind::COMMA || peek_kind == TokenKind::AND
+        advance
+        ordinals << parse_ordinal_position
+      end
+      weekday = parse_day_name("expected day name after ordinal in monthly expression")
+      sorted = ordinals.uniq.sort_by { |o| OrdinalPosition::ALL.index(o) }
+      return OrdinalWeekdayTarget.new(sorted.first, weekday) if sorted.length == 1
+
+      OrdinalWeekdaysTarget.new(sorted, weekday)
+    end
+
+    # Parse <ordinal> (weekday | business day)
+    def parse_business_day_target
+      span = current_span
+      n = parse_ordinal_day("expected ordinal before 'business day'")
+      # No month has more than 23 weekdays
+      raise error("invalid business day: #{n} (must be 1-23)", span) if n > 23
+
+      if peek_kind == TokenKind::BUSINESS
+        advance
+        consume_keyword("'day'", TokenKind::DAY)
+      else
+        consume_keyword("'weekday'", TokenKind::WEEKDAY_KW)
+      end
+      BusinessDayTarget.new(n)
+    end
+
     def parse_year_repeat(interval)
+      return parse_iso_week_repeat(interval) if peek_kind == TokenKind::IN
+
       consume_keyword("'on'", TokenKind::ON)
 
       k = peek_kind
@@ -437,11 +775,40 @@ module Hron
         raise error("expected month name or 'the' after 'every year on'", current_span)
       end
 
-      consume_keyword("'at'", TokenKind::AT)
-      times = parse_time_list
+      times = parse_times
       YearRepeat.new(interval, target, times)
     end
 
+    # iso_week_repeat: "every year in iso week[s] N[, N] on day_list [at HH:MM]"
+    def parse_iso_week_repeat(interval)
+      raise error("ISO week schedules repeat every year; remove the interval", current_span) if interval != 1
+
+      consume_keyword("'in'", TokenKind::IN)
+      consume_keyword("'iso'", TokenKind::ISO)
+      consume_keyword("'weeks'", TokenKind::WEEKS)
+
+      weeks = [parse_iso_week_number]
+      while peek_kind == TokenKind::COMMA
+        advance
+        weeks << parse_iso_week_number
+      end
+
+      consume_keyword("'on'", TokenKind::ON)
+      days = parse_day_list
+      times = parse_times_or_midnight
+      IsoWeekRepeat.new(weeks, days, times)
+    end
+
+    def parse_iso_week_number
+      span = current_span
+      k = peek_kind
+      raise error("expected ISO week number", span) unless k.is_a?(TNumber)
+      raise error("invalid ISO week #{k.value} (expected 1-53)", span) if k.value < 1 || k.value > 53
+
+      advance
+      k.value
+    end
+
     def parse_year_target_after_the
       k = peek_kind
 
@@ -464,22 +831,17 @@ module Hron
         raise error("expected 'weekday' or day name after 'last' in yearly expression", current_span)
       end
 
-      if k.is_a?(TOrdinal)
+      # "the first monday of march" or "the 15th of march"
+      if k.is_a?(TOrdinal) && next_is_day_name?
         ordinal = parse_ordinal_position
-        nk = peek_kind
-        if nk.is_a?(TDayName)
-          weekday = nk.name
-          advance
-          consume_keyword("'of'", TokenKind::OF)
-          month = parse_month_name_token
-          return YearOrdinalWeekdayTarget.new(ordinal, weekday, month)
-        end
-        raise error("expected day name after ordinal in yearly expression", current_span)
+        weekday = parse_weekday_token
+        consume_keyword("'of'", TokenKind::OF)
+        month = parse_month_name_token
+        return YearOrdinalWeekdayTarget.new(ordinal, weekday, month)
       end
 
-      if k.is_a?(TOrdinalNumber)
-        day = k.value
-        advance
+      if k.is_a?(TOrdinal) || k.is_a?(TOrdinalNumber)
+        day = parse_ordinal_day("expected ordinal day number")
         consume_keyword("'of'", TokenKind::OF)
         month = parse_month_name_token
         validate_named_date(month, day)
@@ -502,8 +864,10 @@ module Hron
       span = current_span
       k = peek_kind
       if k.is_a?(TOrdinal)
+        raise error("unknown ordinal '#{k.word}'", span) if k.value > 5
+
         advance
-        return k.position
+        return OrdinalPosition::TO_N.key(k.value)
       end
       if k == TokenKind::LAST
         advance
@@ -512,10 +876,49 @@ module Hron
       raise error("expected ordinal (first, second, third, fourth, fifth, last)", span)
     end
 
+    # Parse an ordinal day of month, as digits (21st) or a word (twentieth).
+    def parse_ordinal_day(expected)
+      k = peek_kind
+      raise error(expected, current_span) unless k.is_a?(TOrdinalNumber) || k.is_a?(TOrdinal)
+
+      val = k.value
+      raise error("invalid day number #{val} (must be 1-31)", current_span) if val < 1 || val > 31
+
+      advance
+      val
+    end
+
+    def next_is_day_name?
+      @tokens[@pos + 1]&.kind.is_a?(TDayName)
+    end
+
+    def next_is_business_day?
+      kind = @tokens[@pos + 1]&.kind
+      kind == TokenKind::BUSINESS || kind == TokenKind::WEEKDAY_KW
+    end
+
+    def parse_weekday_token
+      k = peek_kind
+      raise error("expected day name", current_span) unless k.is_a?(TDayName)
+
+      advance
+      k.name
+    end
+
     def parse_on
+      # "on friday at 17:00": the nearest upcoming friday, a rolling one-shot
+      if peek_kind.is_a?(TDayName)
+        day = parse_weekday_token
+        if peek_kind == TokenKind::COMMA
+          raise error("a weekday cannot be combined with other dates; use 'every' for recurring days", current_span)
+        end
+
+        times = parse_times
+        return SingleDateExpr.new(NextWeekdayDate.new(day), times)
+      end
+
       date = parse_date_target
-      consume_keyword("'at'", TokenKind::AT)
-      times = parse_time_list
+      times = parse_times
       SingleDateExpr.new(date, times)
     end
 
@@ -550,57 +953,76 @@ module Hron
         DayFilterWeekend.new
       when TDayName
         days = parse_day_list
-        DayFilterDays.new(days)
+        Hron.canonical_day_filter(DayFilterDays.new(days))
       else
         raise error("expected 'day', 'weekday', 'weekend', or day name", current_span)
       end
     end
 
     def parse_day_list
-      k = peek_kind
-      raise error("expected day name", current_span) unless k.is_a?(TDayName)
-
-      days = [k.name]
-      advance
-
+      days = parse_day_range("expected day name")
       while peek_kind == TokenKind::COMMA
         advance
-        nk = peek_kind
-        raise error("expected day name after ','", current_span) unless nk.is_a?(TDayName)
-
-        days << nk.name
-        advance
+        days.concat(parse_day_range("expected day name after ','"))
       end
       days
     end
 
+    # A day name or a range of them: "monday to friday", "fri through mon"
+    # (wraps past sunday)
+    def parse_day_range(expected)
+      start = parse_day_name(expected)
+      return [start] unless at_range_separator?
+
+      advance
+      finish = parse_day_name("expected day name after 'to'")
+      start_num = Weekday.number(start)
+      length = ((Weekday.number(finish) + 7 - start_num) % 7) + 1
+      (0...length).map { |i| Weekday.from_number(((start_num - 1 + i) % 7) + 1) }
+    end
+
+    def parse_day_name(expected)
+      k = peek_kind
+      raise error(expected, current_span) unless k.is_a?(TDayName)
+
+      advance
+      k.name
+    end
+
+    # Whether the next token separates a range: 'to', or its synonym
+    # 'through'. 'through' is only accepted in ranges, never in
+    # 'from ... to ...' windows.
+    def at_range_separator?
+      kind = peek_kind
+      kind == TokenKind::TO || kind == TokenKind::THROUGH
+    end
+
+    # Day specs are sorted and de-duplicated: "the 15th, 1st, 15th" is
+    # "the 1st, 15th"
     def parse_ordinal_day_list
       specs = [parse_ordinal_day_spec]
       while peek_kind == TokenKind::COMMA
         advance
         specs << parse_ordinal_day_spec
       end
-      specs
+      sorted_unique(specs) { |spec| day_spec_key(spec) }
     end
 
-    def parse_ordinal_day_spec
-      k = peek_kind
-      raise error("expected ordinal day number", current_span) unless k.is_a?(TOrdinalNumber)
-
-      start = k.value
-      span = current_span
-      raise error("invalid day number #{start} (must be 1-31)", span) if start < 1 || start > 31
-      advance
+    # Ordering key for a day spec: by first day, then last day.
+    def day_spec_key(spec)
+      case spec
+      when SingleDay then [spec.day, spec.day]
+      when DayRange then [spec.start, spec.end_day]
+      end
+    end
 
-      if peek_kind == TokenKind::TO
-        advance
-        nk = peek_kind
-        raise error("expected ordinal day number after 'to'", current_span) unless nk.is_a?(TOrdinalNumber)
+    def parse_ordinal_day_spec
+      start = parse_ordinal_day("expected ordinal day number")
 
-        end_day = nk.value
-        end_span = current_span
-        raise error("invalid day number #{end_day} (must be 1-31)", end_span) if end_day < 1 || end_day > 31
+      # "1st to 15th", "1st through 15th"
+      if at_range_separator?
         advance
+        end_day = parse_ordinal_day("expected ordinal day number after 'to'")
         raise error("invalid day range: #{start} to #{end_day} (start must be <= end)", current_span) if start > end_day
         return DayRange.new(start, end_day)
       end
@@ -625,37 +1047,98 @@ module Hron
       consume_keyword("'weekday'", TokenKind::WEEKDAY_KW)
       consume_keyword("'to'", TokenKind::TO)
 
-      # Parse the day number (ordinal like 15th)
-      k = peek_kind
-      if k.is_a?(TOrdinalNumber)
-        day = k.value
-        if day < 1 || day > 31
-          raise error("invalid day number #{day} (must be 1-31)", current_span)
-        end
-        advance
-      else
-        raise error("expected ordinal day number after 'to'", current_span)
-      end
+      # Parse the day number (ordinal like 15th or fifteenth)
+      day = parse_ordinal_day("expected ordinal day number after 'to'")
 
       NearestWeekdayTarget.new(day, direction)
     end
 
-    def parse_month_list
-      months = [parse_month_name_token]
+    # Specs are sorted by where they start and de-duplicated: "during mar,
+    # jan, mar" is "during jan, mar". Overlapping specs are not merged.
+    def parse_during_list
+      specs = [parse_during_spec]
       while peek_kind == TokenKind::COMMA
         advance
-        months << parse_month_name_token
+        specs << parse_during_spec
+      end
+      sorted_unique(specs) { |spec| during_spec_key(spec) }
+    end
+
+    # Ordering key for a during spec: by first month-day, then last month-day.
+    # A month and the date range covering the same days share a key.
+    def during_spec_key(spec)
+      start_day, end_day = Hron.during_bounds(spec)
+      [MonthName.number(spec.start), start_day, MonthName.number(spec.end_month), end_day]
+    end
+
+    # Sort items by the block's key, keeping the first written of each run of
+    # equal keys.
+    def sorted_unique(items, &key)
+      items.each_with_index.sort_by { |item, i| [key.call(item), i] }.map(&:first).uniq(&key)
+    end
+
+    # during_spec: "jun", "jun to aug" (wraps past december: "nov to feb"),
+    # or month-day bounds "jun 15 to aug 31". A bound without a day covers
+    # its whole month.
+    def parse_during_spec
+      start = parse_month_name_token
+      start_day = parse_during_day(start)
+      # "during jun 15" is that one day
+      return DuringSpec.new(start, start, start_day, start_day) unless at_range_separator?
+
+      advance
+      end_month = parse_month_name_token
+      end_day = parse_during_day(end_month)
+      return DuringSpec.new(start, end_month) if start_day.nil? && end_day.nil?
+
+      DuringSpec.new(start, end_month, start_day || 1, end_day || MonthName.max_day(end_month))
+    end
+
+    # Optional day after a month name in `during`, validated for the month.
+    def parse_during_day(month)
+      k = peek_kind
+      return nil unless k.is_a?(TNumber) || k.is_a?(TOrdinalNumber)
+
+      day = parse_day_number("expected day number after month name in during")
+      validate_named_date(month, day)
+      day
+    end
+
+    # times: "at HH:MM, ..." or "in the morning|afternoon|evening|night"
+    def parse_times
+      if peek_kind == TokenKind::IN
+        advance
+        consume_keyword("'the'", TokenKind::THE)
+        k = peek_kind
+        raise error("expected morning, afternoon, evening, or night", current_span) unless k.is_a?(TPeriod)
+
+        advance
+        return [PERIOD_TIMES[k.word]]
       end
-      months
+      consume_keyword("'at'", TokenKind::AT)
+      parse_time_list
+    end
+
+    # Day and week repeats may omit times: "every day", "every weekday in UTC"
+    # fire at 00:00. A bare "in" starts the timezone clause, not "in the ...".
+    def parse_times_or_midnight
+      k = peek_kind
+      has_times = k == TokenKind::AT ||
+        (k == TokenKind::IN && @tokens[@pos + 1]&.kind == TokenKind::THE)
+      return parse_times if has_times
+
+      [TimeOfDay.new(0, 0)]
     end
 
+    # Times are sorted and de-duplicated: "at 17:00, 09:00, 09:00" is
+    # "at 09:00, 17:00"
     def parse_time_list
       times = [parse_time]
       while peek_kind == TokenKind::COMMA
         advance
         times << parse_time
       end
-      times
+      times.sort_by { |t| [t.hour, t.minute] }.uniq
     end
 
     def parse_time
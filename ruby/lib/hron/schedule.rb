@@ -32,7 +32,9 @@ module Hron
       false
     end
 
-    # Get the next occurrence from the given time
+    # Get the next occurrence from the given time. A `starting` date is both
+    # the interval anchor and a lower bound, so when now is before it the
+    # result is on or after it.
     def next_from(now)
       Evaluator.next_from(@data, now)
     end
@@ -47,7 +49,9 @@ module Hron
       Evaluator.previous_from(@data, now)
     end
 
-    # Check if the schedule matches the given datetime
+    # Check if the schedule matches the given datetime. Raises HronError for
+    # `starting today` / `starting now` and for `on <weekday>`, which need an
+    # evaluation time that matches does not take.
     def matches(dt)
       Evaluator.matches(@data, dt)
     end
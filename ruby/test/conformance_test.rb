@@ -20,6 +20,31 @@ class ConformanceTest < Minitest::Test
     timezone_clause
     combined_clauses
     case_insensitivity
+    ordinal_in_dates
+    twelve_hour_times
+    between_windows
+    frequency_keywords
+    time_of_day_keywords
+    time_list_normalization
+    default_midnight
+    weekday_exceptions
+    iso_weeks
+    during_dates
+    cron_shortcuts
+    ordinal_words
+    day_interval_filter
+    day_step_windows
+    last_n_days
+    day_list_canonicalization
+    fractional_hours
+    weekday_dates
+    weekday_interval
+    through_ranges
+    twelve_hour_windows
+    business_days
+    except_ranges
+    minutes_past_hour
+    ordinal_weekdays
   ].freeze
 
   # Dynamically discover eval sections (skip non-test entries)
@@ -150,6 +175,11 @@ class ConformanceTest < Minitest::Test
     define_method("test_matches_#{test_name.gsub(/[^a-zA-Z0-9_]/, "_")}") do
       schedule = Hron::Schedule.parse(tc["expression"])
       dt = TestHelper.parse_zoned(tc["datetime"])
+      if tc["error_contains"]
+        err = assert_raises(Hron::HronError) { schedule.matches(dt) }
+        assert_includes err.message, tc["error_contains"]
+        next
+      end
       result = schedule.matches(dt)
       assert_equal tc["expected"], result
     end
@@ -239,6 +269,15 @@ class ConformanceTest < Minitest::Test
     end
   end
 
+  # to_cron_during tests
+  SPEC["cron"]["to_cron_during"]["tests"].each do |tc|
+    test_name = tc["name"] || tc["hron"]
+    define_method("test_to_cron_during_#{test_name.gsub(/[^a-zA-Z0-9_]/, "_")}") do
+      schedule = Hron::Schedule.parse(tc["hron"])
+      assert_equal tc["cron"], schedule.to_cron
+    end
+  end
+
   # to_cron error tests
   SPEC["cron"]["to_cron_errors"]["tests"].each do |tc|
     test_name = tc["name"] || tc["hron"]
@@ -282,6 +282,27 @@ class IteratorTest < Minitest::Test
     assert_equal 1, results.length
   end
 
+  def test_upcoming_weekday_occurrence_cannot_be_matched
+    schedule = Hron::Schedule.parse("on friday at 17:00 in UTC")
+    from = TestHelper.parse_zoned("2026-02-06T12:00:00+00:00[UTC]")
+
+    results = schedule.occurrences(from).first(10)
+
+    assert_equal 1, results.length
+    err = assert_raises(Hron::HronError) { schedule.matches(results.first) }
+    assert_includes err.message, "no evaluation time"
+  end
+
+  def test_matches_rejects_relative_anchor
+    now = TestHelper.parse_zoned("2026-02-06T12:00:00+00:00[UTC]")
+
+    %w[today now].each do |anchor|
+      schedule = Hron::Schedule.parse("every 2 days at 09:00 starting #{anchor} in UTC")
+      err = assert_raises(Hron::HronError) { schedule.matches(now) }
+      assert_includes err.message, "no evaluation time"
+    end
+  end
+
   # ===========================================================================
   # Timezone Handling
   # ===========================================================================
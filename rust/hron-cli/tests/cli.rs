@@ -2,7 +2,7 @@ use assert_cmd::Command;
 use predicates::prelude::*;
 
 fn hron() -> Command {
-    Command::cargo_bin("hron").unwrap()
+    assert_cmd::cargo::cargo_bin_cmd!("hron")
 }
 
 // ============================================================
@@ -281,19 +281,19 @@ fn test_parse_json_during() {
 #[test]
 fn test_to_cron_multi_time_fails() {
     hron()
-        .args(["--to-cron", "every day at 9:00, 17:00"])
+        .args(["--to-cron", "every day at 9:00, 17:30"])
         .assert()
         .failure()
         .stderr(predicate::str::contains("not expressible"));
 }
 
 #[test]
-fn test_to_cron_during_fails() {
+fn test_to_cron_during() {
     hron()
         .args(["--to-cron", "every day at 9:00 during jan"])
         .assert()
-        .failure()
-        .stderr(predicate::str::contains("not expressible"));
+        .success()
+        .stdout(predicate::str::contains("0 9 * 1 *"));
 }
 
 #[test]
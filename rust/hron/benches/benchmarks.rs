@@ -78,6 +78,12 @@ fn bench_eval(c: &mut Criterion) {
         b.iter(|| interval_repeat.next_from(black_box(&now)).unwrap());
     });
 
+    // matches, with the input already in the schedule's zone
+    let no_tz = Schedule::parse("every weekday at 12:00").unwrap();
+    group.bench_function("matches", |b| {
+        b.iter(|| no_tz.matches(black_box(&now)).unwrap());
+    });
+
     group.finish();
 }
 
@@ -97,6 +103,14 @@ fn bench_display(c: &mut Criterion) {
         b.iter(|| black_box(&schedule).to_string());
     });
 
+    let mut buf = String::with_capacity(128);
+    group.bench_function("write_canonical_reused_buffer", |b| {
+        b.iter(|| {
+            buf.clear();
+            black_box(&schedule).write_canonical(&mut buf).unwrap();
+        });
+    });
+
     group.finish();
 }
 
@@ -32,6 +32,31 @@ fn main() {
         "timezone_clause",
         "combined_clauses",
         "case_insensitivity",
+        "ordinal_in_dates",
+        "time_of_day_keywords",
+        "time_list_normalization",
+        "default_midnight",
+        "weekday_exceptions",
+        "iso_weeks",
+        "during_dates",
+        "cron_shortcuts",
+        "ordinal_words",
+        "day_interval_filter",
+        "day_step_windows",
+        "last_n_days",
+        "day_list_canonicalization",
+        "fractional_hours",
+        "weekday_dates",
+        "weekday_interval",
+        "through_ranges",
+        "twelve_hour_windows",
+        "business_days",
+        "except_ranges",
+        "minutes_past_hour",
+        "ordinal_weekdays",
+        "twelve_hour_times",
+        "between_windows",
+        "frequency_keywords",
     ] {
         for (i, case) in iter_tests(&parse[section]).enumerate() {
             let name = test_name(case, i);
@@ -134,14 +159,17 @@ fn main() {
 
     // --- Cron ---
     let cron = &spec["cron"];
-    for (i, case) in iter_tests(&cron["to_cron"]).enumerate() {
-        let name = test_name(case, i);
-        emit_flat(
-            &mut f,
-            &format!("cron_to_cron_{name}"),
-            "run_cron_to_cron",
-            i,
-        );
+    for section in ["to_cron", "to_cron_during"] {
+        for (i, case) in iter_tests(&cron[section]).enumerate() {
+            let name = test_name(case, i);
+            emit(
+                &mut f,
+                &format!("cron_{section}_{name}"),
+                "run_cron_to_cron",
+                section,
+                i,
+            );
+        }
     }
     for (i, case) in iter_tests(&cron["to_cron_errors"]).enumerate() {
         let name = test_name(case, i);
@@ -1,16 +1,41 @@
+use crate::error::{ScheduleError, Span};
+use crate::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// A parsed hron schedule: expression + optional modifiers.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Equality is structural: two schedules are equal when their ASTs are equal.
+/// Spellings that parse to the same AST (e.g. `every weekday at 9:00` and
+/// `every weekdays at 09:00`) compare equal. Parsing also canonicalizes day
+/// lists, so `every fri, mon, tue, wed, thu` equals `every weekday`; ASTs
+/// built by hand may not be, so compare [`Schedule::canonical_form`]s to
+/// treat those as equal. `during` compares as written; canonical forms
+/// agree whenever two `during` clauses cover the same days, so
+/// `during jun to aug` and `during jun, jul, aug` normalize alike.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 pub struct Schedule {
     pub(crate) expr: ScheduleExpr,
     pub(crate) timezone: Option<String>,
     pub(crate) except: Vec<Exception>,
     pub(crate) until: Option<UntilSpec>,
-    pub(crate) anchor: Option<jiff::civil::Date>,
-    pub(crate) during: Vec<MonthName>,
+    pub(crate) anchor: Option<CivilDate>,
+    /// `starting today` / `starting now` / `starting jan 6`, resolved against
+    /// the evaluation instant.
+    pub(crate) anchor_relative: Option<RelativeAnchor>,
+    /// `starting ... at 09:00`: nothing occurs before this time on the anchor
+    /// date. Alignment still counts from the anchor date.
+    pub(crate) anchor_time: Option<TimeOfDay>,
+    /// `during` months, month ranges, and date ranges, sorted by start and
+    /// de-duplicated. Date ranges narrow `during` to the day.
+    pub(crate) during: Vec<DuringSpec>,
+    /// Interpret times in the caller's timezone when no `in` clause is given.
+    pub(crate) floating: bool,
+    /// First day of the week for multi-week interval alignment.
+    pub(crate) week_start: WeekStart,
+    /// Days that `weekend` covers.
+    pub(crate) weekend: WeekendDays,
 }
 
 impl Schedule {
@@ -22,16 +47,44 @@ impl Schedule {
             except: Vec::new(),
             until: None,
             anchor: None,
+            anchor_relative: None,
+            anchor_time: None,
             during: Vec::new(),
+            floating: false,
+            week_start: WeekStart::Monday,
+            weekend: WeekendDays::SATURDAY_SUNDAY,
         }
     }
+
+    /// Months any `during` spec touches, for month-level skipping.
+    pub(crate) fn during_months(&self) -> Vec<MonthName> {
+        self.during.iter().flat_map(|s| s.expand()).collect()
+    }
+
+    /// Whether `during` starts or ends any period partway through a month.
+    pub(crate) fn during_has_dates(&self) -> bool {
+        self.during
+            .iter()
+            .any(|spec| matches!(spec.collapsed(), DuringSpec::Dates(..)))
+    }
 }
 
 /// The core schedule expression (what repeats).
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 pub enum ScheduleExpr {
-    /// `every 30 min from 09:00 to 17:00 [on weekdays]`
+    /// `every 30 min from 09:00 to 17:00 [on weekdays]`, `every 3 days from 06:00 to 22:00`
+    ///
+    /// When `to` is earlier than `from` (`from 22:00 to 06:00`) the window
+    /// wraps past midnight into the next day; `day_filter` applies to the day
+    /// the window starts.
+    ///
+    /// With [`IntervalUnit::Days`], the window fires once per aligned day, at
+    /// `from`; `to` only bounds the window for [`Schedule::window_contains`].
+    /// `day_filter` narrows the aligned days rather than shifting them: an
+    /// aligned day that fails the filter is skipped, and the next window is
+    /// N days later. A step that is a multiple of 7 always lands on the same
+    /// weekday, so parsing rejects a filter that excludes it.
     IntervalRepeat {
         interval: u32,
         unit: IntervalUnit,
@@ -39,39 +92,192 @@ pub enum ScheduleExpr {
         to: TimeOfDay,
         day_filter: Option<DayFilter>,
     },
-    /// `every day at 09:00`, `every 2 days at 09:00`
+    /// `every day at 09:00`, `every 2 days at 09:00`, `every 3 days on weekdays at 09:00`
+    ///
+    /// Every time fires on the matched day itself: `at 23:30, 00:30` fires at
+    /// 00:30 and then 23:30 on each date, with no carry into the next day.
+    ///
+    /// With an interval above 1, `days` narrows the aligned days rather than
+    /// shifting them: an aligned day that fails the filter is skipped, and
+    /// the next occurrence is N days later. As with day-step windows, parsing
+    /// rejects a filter that no aligned day can pass.
     DayRepeat {
         interval: u32,
         days: DayFilter,
         times: Vec<TimeOfDay>,
     },
+    /// `every 3 weekdays at 09:00`: every Nth business day.
+    ///
+    /// Only Monday through Friday count toward the interval, so weekends
+    /// neither fire nor advance it. Days are counted from the `starting`
+    /// anchor (or its next weekday), defaulting to 1970-01-01. Unlike
+    /// `every 3 days on weekdays`, which skips aligned days that fall on a
+    /// weekend, this fires exactly every third business day.
+    WeekdayRepeat {
+        interval: u32,
+        times: Vec<TimeOfDay>,
+    },
     /// `every 2 weeks on monday at 09:00`
     WeekRepeat {
         interval: u32,
         days: Vec<Weekday>,
         times: Vec<TimeOfDay>,
     },
-    /// `every month on the 1st at 09:00`, `every 2 months on the 1st at 09:00`
+    /// `every month on the 1st at 09:00`, `every 2 months on the 1st at 09:00`,
+    /// `every month on the 1st, 15th at 09:00 on weekdays`
+    ///
+    /// `day_filter` intersects with the target: a target date that fails the
+    /// filter is skipped, not moved. (Cron instead ORs day-of-month with
+    /// day-of-week when both are restricted.)
     MonthRepeat {
         interval: u32,
         target: MonthTarget,
         times: Vec<TimeOfDay>,
+        day_filter: Option<DayFilter>,
     },
     /// `on feb 14 at 9:00, 17:00`
     SingleDate {
         date: DateSpec,
         times: Vec<TimeOfDay>,
     },
+    /// `on feb 14, dec 25 at 9:00`: two or more dates sharing times. A
+    /// single date parses as [`SingleDate`](Self::SingleDate).
+    MultiDate {
+        dates: Vec<DateSpec>,
+        times: Vec<TimeOfDay>,
+    },
     /// `every year on dec 25 at 00:00`, `every 2 years on dec 25 at 00:00`
     YearRepeat {
         interval: u32,
         target: YearTarget,
         times: Vec<TimeOfDay>,
     },
+    /// `every year in iso weeks 1, 14 on monday at 09:00`
+    ///
+    /// Weeks are ISO 8601 week numbers (1-53, weeks start on Monday, week 1
+    /// holds the year's first Thursday). Week 53 exists only in long ISO
+    /// years and is skipped in others.
+    IsoWeekRepeat {
+        weeks: Vec<u8>,
+        days: Vec<Weekday>,
+        times: Vec<TimeOfDay>,
+    },
+}
+
+impl ScheduleExpr {
+    /// The variant of this expression as a fieldless discriminant, for
+    /// switching without matching every field.
+    pub fn kind(&self) -> ExprKind {
+        match self {
+            Self::IntervalRepeat { .. } => ExprKind::IntervalRepeat,
+            Self::DayRepeat { .. } => ExprKind::DayRepeat,
+            Self::WeekdayRepeat { .. } => ExprKind::WeekdayRepeat,
+            Self::WeekRepeat { .. } => ExprKind::WeekRepeat,
+            Self::MonthRepeat { .. } => ExprKind::MonthRepeat,
+            Self::SingleDate { .. } => ExprKind::SingleDate,
+            Self::MultiDate { .. } => ExprKind::MultiDate,
+            Self::YearRepeat { .. } => ExprKind::YearRepeat,
+            Self::IsoWeekRepeat { .. } => ExprKind::IsoWeekRepeat,
+        }
+    }
+
+    /// Repeat interval (`every N ...`). `None` for [`SingleDate`](Self::SingleDate),
+    /// [`MultiDate`](Self::MultiDate), and [`IsoWeekRepeat`](Self::IsoWeekRepeat),
+    /// which take no interval.
+    pub fn interval(&self) -> Option<u32> {
+        match self {
+            Self::IntervalRepeat { interval, .. }
+            | Self::DayRepeat { interval, .. }
+            | Self::WeekdayRepeat { interval, .. }
+            | Self::WeekRepeat { interval, .. }
+            | Self::MonthRepeat { interval, .. }
+            | Self::YearRepeat { interval, .. } => Some(*interval),
+            Self::SingleDate { .. } | Self::MultiDate { .. } | Self::IsoWeekRepeat { .. } => None,
+        }
+    }
+
+    /// Times of day from the `at` clause. `None` for
+    /// [`IntervalRepeat`](Self::IntervalRepeat), which uses a `from`-`to` window.
+    pub fn times(&self) -> Option<&[TimeOfDay]> {
+        match self {
+            Self::DayRepeat { times, .. }
+            | Self::WeekdayRepeat { times, .. }
+            | Self::WeekRepeat { times, .. }
+            | Self::MonthRepeat { times, .. }
+            | Self::SingleDate { times, .. }
+            | Self::MultiDate { times, .. }
+            | Self::YearRepeat { times, .. }
+            | Self::IsoWeekRepeat { times, .. } => Some(times),
+            Self::IntervalRepeat { .. } => None,
+        }
+    }
+
+    pub(crate) fn times_mut(&mut self) -> Option<&mut Vec<TimeOfDay>> {
+        match self {
+            Self::DayRepeat { times, .. }
+            | Self::WeekdayRepeat { times, .. }
+            | Self::WeekRepeat { times, .. }
+            | Self::MonthRepeat { times, .. }
+            | Self::SingleDate { times, .. }
+            | Self::MultiDate { times, .. }
+            | Self::YearRepeat { times, .. }
+            | Self::IsoWeekRepeat { times, .. } => Some(times),
+            Self::IntervalRepeat { .. } => None,
+        }
+    }
+
+    /// Day filter (`on weekdays`, `every monday`, ...), if the expression has one.
+    pub fn day_filter(&self) -> Option<&DayFilter> {
+        match self {
+            Self::DayRepeat { days, .. } => Some(days),
+            Self::WeekdayRepeat { .. } => Some(&DayFilter::Weekday),
+            Self::IntervalRepeat { day_filter, .. } | Self::MonthRepeat { day_filter, .. } => {
+                day_filter.as_ref()
+            }
+            Self::WeekRepeat { .. }
+            | Self::SingleDate { .. }
+            | Self::MultiDate { .. }
+            | Self::YearRepeat { .. }
+            | Self::IsoWeekRepeat { .. } => None,
+        }
+    }
+}
+
+/// Discriminant of [`ScheduleExpr`], returned by [`ScheduleExpr::kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ExprKind {
+    IntervalRepeat,
+    DayRepeat,
+    WeekdayRepeat,
+    WeekRepeat,
+    MonthRepeat,
+    SingleDate,
+    MultiDate,
+    YearRepeat,
+    IsoWeekRepeat,
+}
+
+impl ExprKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::IntervalRepeat => "interval_repeat",
+            Self::DayRepeat => "day_repeat",
+            Self::WeekdayRepeat => "weekday_repeat",
+            Self::WeekRepeat => "week_repeat",
+            Self::MonthRepeat => "month_repeat",
+            Self::SingleDate => "single_date",
+            Self::MultiDate => "multi_date",
+            Self::YearRepeat => "year_repeat",
+            Self::IsoWeekRepeat => "iso_week_repeat",
+        }
+    }
 }
 
 /// Exception date for `except` clause.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
@@ -80,10 +286,80 @@ pub enum Exception {
     Named { month: MonthName, day: u8 },
     /// One-off ISO date: `2026-12-25`.
     Iso(String),
+    /// Every occurrence of a weekday: `saturday`. `except weekends` parses
+    /// into one of these per day.
+    Weekday(Weekday),
+    /// One-off inclusive ISO date range: `2026-12-24 to 2026-12-31`.
+    IsoRange(String, String),
+    /// Recurring inclusive range of named dates: `dec 24 to jan 2` matches
+    /// every year, wrapping past december when the end comes first.
+    NamedRange {
+        start_month: MonthName,
+        start_day: u8,
+        end_month: MonthName,
+        end_day: u8,
+    },
+}
+
+/// Relative `starting` anchor.
+///
+/// Resolved against the `now` passed to each evaluation, not the moment the
+/// schedule was parsed, so results depend on when they are evaluated: `every 2
+/// days starting today` fires today on every call. Operations without a `now`,
+/// such as `matches`, return an error instead. Iterators resolve it once,
+/// against their start.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RelativeAnchor {
+    /// `starting today`: anchors to the evaluation date.
+    Today,
+    /// `starting now`: anchors to the evaluation date, and nothing before the
+    /// evaluation instant occurs (`previous_from` finds no occurrence).
+    Now,
+    /// `starting jan 6`: anchors to the next such date on or after the
+    /// evaluation date, the way `until jan 6` resolves.
+    Named { month: MonthName, day: u8 },
+}
+
+/// A trailing clause of a schedule expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Clause {
+    Except,
+    Until,
+    Starting,
+    During,
+    /// The `in <timezone>` clause.
+    Timezone,
+}
+
+/// Where each trailing clause sits in the parsed input, from
+/// [`Schedule::parse_with_spans`]. Each span runs from the clause keyword
+/// to the end of its last token.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClauseSpans {
+    pub(crate) except: Option<Span>,
+    pub(crate) until: Option<Span>,
+    pub(crate) starting: Option<Span>,
+    pub(crate) during: Option<Span>,
+    pub(crate) timezone: Option<Span>,
+}
+
+impl ClauseSpans {
+    /// The byte range of `clause`, or `None` if the input has no such clause.
+    pub fn clause_span(&self, clause: Clause) -> Option<Span> {
+        match clause {
+            Clause::Except => self.except,
+            Clause::Until => self.until,
+            Clause::Starting => self.starting,
+            Clause::During => self.during,
+            Clause::Timezone => self.timezone,
+        }
+    }
 }
 
 /// Until spec for `until` clause.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
@@ -92,10 +368,15 @@ pub enum UntilSpec {
     Iso(String),
     /// Named date: `dec 31` — resolves to next occurrence from current year.
     Named { month: MonthName, day: u8 },
+    /// Occurrence count: `until 10 times`. The count runs from the `starting`
+    /// date, so occurrences between the anchor and the evaluation time use up
+    /// the budget. Evaluation needs a fixed anchor date: without one the count
+    /// would restart from every evaluation time.
+    Count(u32),
 }
 
 /// Year target for yearly expressions.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
@@ -115,12 +396,57 @@ pub enum YearTarget {
 }
 
 /// Time of day (hours and minutes).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct TimeOfDay {
     pub hour: u8,
     pub minute: u8,
 }
 
+/// Concrete times for the `in the morning` / `afternoon` / `evening` / `night`
+/// input sugar, and for day and week repeats written without `at`. The
+/// keyword is not kept in the AST, so these display as `HH:MM`.
+impl TimeOfDay {
+    pub const MIDNIGHT: TimeOfDay = TimeOfDay { hour: 0, minute: 0 };
+    pub const MORNING: TimeOfDay = TimeOfDay { hour: 9, minute: 0 };
+    pub const AFTERNOON: TimeOfDay = TimeOfDay {
+        hour: 14,
+        minute: 0,
+    };
+    pub const EVENING: TimeOfDay = TimeOfDay {
+        hour: 18,
+        minute: 0,
+    };
+    pub const NIGHT: TimeOfDay = TimeOfDay {
+        hour: 21,
+        minute: 0,
+    };
+
+    /// Time from minutes past midnight: `540` is 09:00. `None` past 23:59.
+    pub fn from_minutes(minutes: u16) -> Option<TimeOfDay> {
+        (minutes < 24 * 60).then_some(TimeOfDay {
+            hour: (minutes / 60) as u8,
+            minute: (minutes % 60) as u8,
+        })
+    }
+
+    /// Minutes past midnight: 09:00 is `540`.
+    pub fn to_minutes(self) -> u16 {
+        self.hour as u16 * 60 + self.minute as u16
+    }
+
+    /// Minutes from `self` forward to `to`, running past midnight when `to` is
+    /// earlier in the day: 22:00 to 02:00 is `240`.
+    pub fn minutes_until(self, to: TimeOfDay) -> u16 {
+        (to.to_minutes() + 24 * 60 - self.to_minutes()) % (24 * 60)
+    }
+
+    /// Whether this time falls in the inclusive window `from`-`to`, which
+    /// wraps past midnight when `to` is before `from`.
+    pub fn in_window(self, from: TimeOfDay, to: TimeOfDay) -> bool {
+        from.minutes_until(self) <= from.minutes_until(to)
+    }
+}
+
 #[cfg(feature = "serde")]
 impl Serialize for TimeOfDay {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
@@ -153,7 +479,7 @@ impl<'de> Deserialize<'de> for TimeOfDay {
 }
 
 /// Day filter for day-repeat and interval expressions.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
@@ -164,6 +490,37 @@ pub enum DayFilter {
     Days(Vec<Weekday>),
 }
 
+impl DayFilter {
+    /// Whether the filter lets `day` through, with `weekend` covering the
+    /// schedule's configured days.
+    pub(crate) fn contains(&self, day: Weekday, weekend: WeekendDays) -> bool {
+        match self {
+            DayFilter::Every => true,
+            DayFilter::Weekday => day.number() <= 5,
+            DayFilter::Weekend => weekend.contains(day),
+            DayFilter::Days(days) => days.contains(&day),
+        }
+    }
+
+    /// Sort and de-duplicate an explicit day list, collapsing one covering
+    /// exactly the weekdays or all seven days into the equivalent keyword
+    /// filter. `saturday, sunday` stays a list: `weekend` follows
+    /// [`WeekendDays`], a written-out list does not.
+    pub(crate) fn canonical(&self) -> DayFilter {
+        let DayFilter::Days(days) = self else {
+            return self.clone();
+        };
+        let mut set: Vec<u8> = days.iter().map(|d| d.number()).collect();
+        set.sort();
+        set.dedup();
+        match set.as_slice() {
+            [1, 2, 3, 4, 5, 6, 7] => DayFilter::Every,
+            [1, 2, 3, 4, 5] => DayFilter::Weekday,
+            _ => DayFilter::Days(set.into_iter().filter_map(Weekday::from_number).collect()),
+        }
+    }
+}
+
 /// Weekday with custom serde (lowercase string).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Weekday {
@@ -201,6 +558,7 @@ impl Weekday {
         }
     }
 
+    #[cfg(feature = "eval")]
     pub(crate) fn to_jiff(self) -> jiff::civil::Weekday {
         match self {
             Self::Monday => jiff::civil::Weekday::Monday,
@@ -213,6 +571,7 @@ impl Weekday {
         }
     }
 
+    #[cfg(feature = "eval")]
     pub(crate) fn from_jiff(wd: jiff::civil::Weekday) -> Self {
         match wd {
             jiff::civil::Weekday::Monday => Self::Monday,
@@ -281,6 +640,22 @@ impl<'de> Deserialize<'de> for Weekday {
     }
 }
 
+/// Accepts full and three-letter day names, case-insensitively.
+impl core::str::FromStr for Weekday {
+    type Err = ScheduleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_weekday(s).ok_or_else(|| {
+            ScheduleError::parse(
+                format!("unknown weekday: {s}"),
+                Span::new(0, s.len()),
+                s,
+                None,
+            )
+        })
+    }
+}
+
 pub(crate) fn parse_weekday(s: &str) -> Option<Weekday> {
     match s.to_lowercase().as_str() {
         "monday" | "mon" => Some(Weekday::Monday),
@@ -295,7 +670,7 @@ pub(crate) fn parse_weekday(s: &str) -> Option<Weekday> {
 }
 
 /// A single day or range of days in a monthly target.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
@@ -312,10 +687,18 @@ impl DayOfMonthSpec {
             DayOfMonthSpec::Range(start, end) => (*start..=*end).collect(),
         }
     }
+
+    /// Ordering key: by first day, then last day.
+    pub(crate) fn sort_key(&self) -> (u8, u8) {
+        match *self {
+            DayOfMonthSpec::Single(d) => (d, d),
+            DayOfMonthSpec::Range(start, end) => (start, end),
+        }
+    }
 }
 
 /// Direction for nearest weekday (hron extension beyond cron W).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
@@ -327,14 +710,24 @@ pub enum NearestDirection {
 }
 
 /// Month target for month-repeat expressions.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum MonthTarget {
+    /// `the 1st, 15th`, `the 1st to 5th`. Parsing sorts and de-duplicates
+    /// the specs.
     Days(Vec<DayOfMonthSpec>),
     LastDay,
+    /// The final N calendar days of each month (`the last 3 days`), N >= 2.
+    /// Counts back from the month's real length; N beyond it covers the
+    /// whole month.
+    LastNDays(u8),
     LastWeekday,
+    /// The Nth monday-to-friday day of the month (`the first business day`,
+    /// `the 3rd weekday`), N in 1-23. Excepted dates are not business days,
+    /// so an `except` landing on it moves the occurrence to the next one.
+    BusinessDay(u8),
     /// Nearest weekday to a given day of month.
     /// Standard (None): never crosses month boundary (cron W compatibility).
     /// Directional (Some): can cross month boundary.
@@ -347,6 +740,14 @@ pub enum MonthTarget {
         ordinal: OrdinalPosition,
         weekday: Weekday,
     },
+    /// Several ordinals of one weekday: `first and last friday`,
+    /// `second, fourth tuesday`. Parsing sorts and de-duplicates the
+    /// ordinals and keeps at least two; a single one is
+    /// [`OrdinalWeekday`](Self::OrdinalWeekday).
+    OrdinalWeekdays {
+        ordinals: Vec<OrdinalPosition>,
+        weekday: Weekday,
+    },
 }
 
 impl MonthTarget {
@@ -360,7 +761,7 @@ impl MonthTarget {
 }
 
 /// Ordinal position (first through fifth, or last).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[non_exhaustive]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
@@ -369,6 +770,10 @@ pub enum OrdinalPosition {
     Second,
     Third,
     Fourth,
+    /// Only months with five of the weekday fire (roughly four per year);
+    /// other months are skipped. Use [`Last`](Self::Last) to fire every
+    /// month instead: a fifth occurrence is always the last one. There is no
+    /// fallback from `Fifth` to `Last` in short months.
     Fifth,
     Last,
 }
@@ -386,18 +791,134 @@ impl OrdinalPosition {
     }
 }
 
+/// A proleptic Gregorian calendar date, as written in `YYYY-MM-DD` form.
+///
+/// The AST stores dates with this type rather than `jiff::civil::Date` so
+/// parsing does not depend on the `eval` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CivilDate {
+    year: i16,
+    month: u8,
+    day: u8,
+}
+
+impl CivilDate {
+    /// Build a date, or `None` if it does not exist (`2026-02-30`) or the year
+    /// is outside `0..=9999`.
+    pub fn new(year: i16, month: u8, day: u8) -> Option<Self> {
+        if !(0..=9999).contains(&year) || !(1..=12).contains(&month) || day == 0 {
+            return None;
+        }
+        (day <= days_in_month(year, month)).then_some(Self { year, month, day })
+    }
+
+    pub fn year(self) -> i16 {
+        self.year
+    }
+
+    pub fn month(self) -> u8 {
+        self.month
+    }
+
+    pub fn day(self) -> u8 {
+        self.day
+    }
+
+    /// Day of the week, counted from 1970-01-01 (a Thursday) so parsing can
+    /// check weekdays without the `eval` feature.
+    pub(crate) fn weekday(self) -> Weekday {
+        // Howard Hinnant's days_from_civil, with March as the first month
+        let year = self.year as i64 - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year = (153 * ((self.month as i64 + 9) % 12) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        let days = era * 146_097 + day_of_era - 719_468;
+        Weekday::from_number(((days + 3).rem_euclid(7) + 1) as u8).unwrap()
+    }
+
+    #[cfg(feature = "eval")]
+    pub(crate) fn to_jiff(self) -> jiff::civil::Date {
+        jiff::civil::date(self.year, self.month as i8, self.day as i8)
+    }
+}
+
+fn days_in_month(year: i16, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(feature = "eval")]
+impl From<jiff::civil::Date> for CivilDate {
+    fn from(date: jiff::civil::Date) -> Self {
+        Self {
+            year: date.year(),
+            month: date.month() as u8,
+            day: date.day() as u8,
+        }
+    }
+}
+
+/// Accepts `YYYY-MM-DD` only.
+impl core::str::FromStr for CivilDate {
+    type Err = ScheduleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let field = |range: core::ops::Range<usize>| {
+            s.get(range)
+                .filter(|f| f.bytes().all(|b| b.is_ascii_digit()))
+                .and_then(|f| f.parse::<i16>().ok())
+        };
+        let b = s.as_bytes();
+        let date = if b.len() == 10 && b[4] == b'-' && b[7] == b'-' {
+            match (field(0..4), field(5..7), field(8..10)) {
+                (Some(y), Some(m), Some(d)) => CivilDate::new(y, m as u8, d as u8),
+                _ => None,
+            }
+        } else {
+            None
+        };
+        date.ok_or_else(|| {
+            ScheduleError::parse(format!("invalid date: {s}"), Span::new(0, s.len()), s, None)
+        })
+    }
+}
+
 /// Date specification for single-date expressions.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum DateSpec {
-    Named { month: MonthName, day: u8 },
+    Named {
+        month: MonthName,
+        day: u8,
+    },
     Iso(String),
+    /// `on friday`: the nearest upcoming day with that weekday, resolved
+    /// against the evaluation time rather than a fixed date. Evaluation pins
+    /// it to that one date, so it fires once, unlike `every friday`.
+    NextWeekday(Weekday),
+}
+
+impl DateSpec {
+    /// Sort key for date lists: named dates by month and day, then ISO dates
+    /// in calendar order.
+    pub(crate) fn sort_key(&self) -> (u8, u8, u8, &str) {
+        match self {
+            DateSpec::Named { month, day } => (0, month.number(), *day, ""),
+            DateSpec::Iso(date) => (1, 0, 0, date),
+            DateSpec::NextWeekday(day) => (2, day.number(), 0, ""),
+        }
+    }
 }
 
 /// Month name.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum MonthName {
@@ -433,6 +954,15 @@ impl MonthName {
         }
     }
 
+    /// The longest the month can be: February counts 29 days.
+    pub fn max_day(self) -> u8 {
+        match self {
+            Self::February => 29,
+            Self::April | Self::June | Self::September | Self::November => 30,
+            _ => 31,
+        }
+    }
+
     pub fn number(self) -> u8 {
         match self {
             Self::January => 1,
@@ -449,6 +979,165 @@ impl MonthName {
             Self::December => 12,
         }
     }
+
+    pub fn from_number(n: u8) -> Option<Self> {
+        match n {
+            1 => Some(Self::January),
+            2 => Some(Self::February),
+            3 => Some(Self::March),
+            4 => Some(Self::April),
+            5 => Some(Self::May),
+            6 => Some(Self::June),
+            7 => Some(Self::July),
+            8 => Some(Self::August),
+            9 => Some(Self::September),
+            10 => Some(Self::October),
+            11 => Some(Self::November),
+            12 => Some(Self::December),
+            _ => None,
+        }
+    }
+}
+
+/// A single month, inclusive month range, or inclusive month-day range in a
+/// `during` clause.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum DuringSpec {
+    Single(MonthName),
+    /// Wraps past December when start > end: `nov to feb` is nov, dec, jan, feb.
+    Range(MonthName, MonthName),
+    /// `jun 15 to aug 31`. Wraps past December like `Range`; an end of feb 29
+    /// means the last day of February in common years.
+    Dates((MonthName, u8), (MonthName, u8)),
+}
+
+impl DuringSpec {
+    /// Expand into the months the spec touches, in calendar order from the
+    /// range start. A date range touches its partial first and last months.
+    pub fn expand(&self) -> Vec<MonthName> {
+        let (start, end) = match self {
+            DuringSpec::Single(m) => return vec![*m],
+            DuringSpec::Range(start, end) => (*start, *end),
+            DuringSpec::Dates((start, start_day), (end, end_day)) => {
+                if start == end && start_day > end_day {
+                    // `jun 15 to jun 10` wraps through every month
+                    return (0..12)
+                        .filter_map(|i| MonthName::from_number((start.number() - 1 + i) % 12 + 1))
+                        .collect();
+                }
+                (*start, *end)
+            }
+        };
+        let len = (end.number() + 12 - start.number()) % 12 + 1;
+        (0..len)
+            .filter_map(|i| MonthName::from_number((start.number() - 1 + i) % 12 + 1))
+            .collect()
+    }
+
+    /// The first and last month-day the spec covers. Whole months end on
+    /// their longest day (feb 29).
+    pub fn bounds(&self) -> ((MonthName, u8), (MonthName, u8)) {
+        match *self {
+            DuringSpec::Single(m) => ((m, 1), (m, m.max_day())),
+            DuringSpec::Range(start, end) => ((start, 1), (end, end.max_day())),
+            DuringSpec::Dates(start, end) => (start, end),
+        }
+    }
+
+    /// Ordering key: by first month-day, then last month-day. A month and
+    /// the date range covering the same days share a key.
+    pub(crate) fn sort_key(&self) -> (u8, u8, u8, u8) {
+        let ((start_month, start_day), (end_month, end_day)) = self.bounds();
+        (start_month.number(), start_day, end_month.number(), end_day)
+    }
+
+    /// Whether the month-day falls within the spec.
+    pub fn contains(&self, month: MonthName, day: u8) -> bool {
+        let ((start_month, start_day), (end_month, end_day)) = self.bounds();
+        let key = (month.number(), day);
+        let start = (start_month.number(), start_day);
+        let end = (end_month.number(), end_day);
+        if start <= end {
+            start <= key && key <= end
+        } else {
+            key >= start || key <= end
+        }
+    }
+
+    /// The sorted, shortest spec list covering the same days as `specs`:
+    /// overlapping and touching specs merge, so `jun, jul to aug` becomes
+    /// `jun to aug`.
+    pub(crate) fn merged(specs: &[DuringSpec]) -> Vec<DuringSpec> {
+        if specs.is_empty() {
+            return Vec::new();
+        }
+        // Every month-day, feb 29 included, in calendar order
+        let days: Vec<(MonthName, u8)> = (1..=12)
+            .filter_map(MonthName::from_number)
+            .flat_map(|m| (1..=m.max_day()).map(move |d| (m, d)))
+            .collect();
+        let covered: Vec<bool> = days
+            .iter()
+            .map(|&(m, d)| specs.iter().any(|s| s.contains(m, d)))
+            .collect();
+        let Some(gap) = covered.iter().position(|&c| !c) else {
+            return vec![DuringSpec::Range(MonthName::January, MonthName::December)];
+        };
+        // Scan from just past an uncovered day so a run wrapping past
+        // December stays whole; the scan ends on that day, closing every run.
+        let n = days.len();
+        let mut runs = Vec::new();
+        let mut start = None;
+        for k in 1..=n {
+            let i = (gap + k) % n;
+            match (covered[i], start) {
+                (true, None) => start = Some(i),
+                (false, Some(s)) => {
+                    runs.push((s, (i + n - 1) % n));
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        runs.sort();
+        runs.into_iter()
+            .map(|(s, e)| DuringSpec::Dates(days[s], days[e]).collapsed())
+            .collect()
+    }
+
+    /// Rewrite a date range covering whole months as the equivalent month
+    /// form: `jun 1 to aug 31` becomes `jun to aug`.
+    pub fn collapsed(self) -> Self {
+        match self {
+            DuringSpec::Dates((start, 1), (end, end_day)) if end_day == end.max_day() => {
+                if start == end {
+                    DuringSpec::Single(start)
+                } else {
+                    DuringSpec::Range(start, end)
+                }
+            }
+            other => other,
+        }
+    }
+}
+
+/// Accepts full and three-letter month names, case-insensitively.
+impl core::str::FromStr for MonthName {
+    type Err = ScheduleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_month_name(s).ok_or_else(|| {
+            ScheduleError::parse(
+                format!("unknown month: {s}"),
+                Span::new(0, s.len()),
+                s,
+                None,
+            )
+        })
+    }
 }
 
 pub(crate) fn parse_month_name(s: &str) -> Option<MonthName> {
@@ -470,13 +1159,16 @@ pub(crate) fn parse_month_name(s: &str) -> Option<MonthName> {
 }
 
 /// Interval unit.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum IntervalUnit {
     Minutes,
     Hours,
+    /// Day-granularity step: the window is active on every Nth day (aligned
+    /// like `every N days at ...`) and fires once, at the window start.
+    Days,
 }
 
 impl IntervalUnit {
@@ -484,6 +1176,417 @@ impl IntervalUnit {
         match self {
             Self::Minutes => "min",
             Self::Hours => "hours",
+            Self::Days => "days",
+        }
+    }
+}
+
+/// First day of the week, for aligning `every N weeks`.
+///
+/// Only the week boundary moves: `every 2 weeks on sun, mon` fires on the
+/// same weekdays either way, but with [`Sunday`](Self::Sunday) the sunday
+/// shares a week with the following monday instead of the preceding one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WeekStart {
+    #[default]
+    Monday,
+    Sunday,
+}
+
+/// The days [`DayFilter::Weekend`] covers.
+///
+/// Only `weekend` moves: `weekday` stays monday through friday, and day
+/// lists such as `fri, sat` keep their literal days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WeekendDays(u8);
+
+impl WeekendDays {
+    /// Saturday and sunday, the default.
+    pub const SATURDAY_SUNDAY: Self = Self(1 << 6 | 1 << 7);
+    /// Friday and saturday, common across much of the Middle East.
+    pub const FRIDAY_SATURDAY: Self = Self(1 << 5 | 1 << 6);
+
+    /// A weekend of the given days, or `None` when `days` is empty.
+    /// Duplicates are ignored.
+    pub fn new(days: &[Weekday]) -> Option<Self> {
+        let bits = days.iter().fold(0, |bits, d| bits | 1 << d.number());
+        (bits != 0).then_some(Self(bits))
+    }
+
+    pub fn contains(self, day: Weekday) -> bool {
+        self.0 & 1 << day.number() != 0
+    }
+
+    /// `filter` with `weekend` spelled out as these days, for output that
+    /// cannot carry the setting (cron, JSON). The default weekend is kept.
+    pub(crate) fn expand(self, filter: &DayFilter) -> DayFilter {
+        match filter {
+            DayFilter::Weekend if self != Self::SATURDAY_SUNDAY => DayFilter::Days(self.days()),
+            _ => filter.clone(),
+        }
+    }
+
+    /// The weekend days, monday first.
+    pub fn days(self) -> Vec<Weekday> {
+        (1..=7)
+            .filter_map(Weekday::from_number)
+            .filter(|d| self.contains(*d))
+            .collect()
+    }
+}
+
+impl Default for WeekendDays {
+    fn default() -> Self {
+        Self::SATURDAY_SUNDAY
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weekday_and_month_from_str_and_display() {
+        assert_eq!("Mon".parse::<Weekday>().unwrap(), Weekday::Monday);
+        assert_eq!("sunday".parse::<Weekday>().unwrap(), Weekday::Sunday);
+        assert_eq!(Weekday::Friday.to_string(), "friday");
+        assert!("funday".parse::<Weekday>().is_err());
+
+        assert_eq!("DEC".parse::<MonthName>().unwrap(), MonthName::December);
+        assert_eq!("may".parse::<MonthName>().unwrap(), MonthName::May);
+        assert_eq!(MonthName::March.to_string(), "mar");
+        let err = "smarch".parse::<MonthName>().unwrap_err();
+        assert_eq!(err.to_string(), "unknown month: smarch");
+    }
+
+    #[test]
+    fn test_civil_date_weekday() {
+        for (y, m, d, weekday) in [
+            (1970, 1, 1, Weekday::Thursday),
+            (2000, 2, 29, Weekday::Tuesday),
+            (2026, 2, 6, Weekday::Friday),
+            (2026, 3, 1, Weekday::Sunday),
+            (0, 1, 1, Weekday::Saturday),
+            (9999, 12, 31, Weekday::Friday),
+        ] {
+            assert_eq!(
+                CivilDate::new(y, m, d).unwrap().weekday(),
+                weekday,
+                "{y}-{m}-{d}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_civil_date_from_str_and_display() {
+        let date: CivilDate = "2024-02-29".parse().unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (2024, 2, 29));
+        assert_eq!(date.to_string(), "2024-02-29");
+        assert_eq!(
+            CivilDate::new(2026, 1, 5).unwrap().to_string(),
+            "2026-01-05"
+        );
+        #[cfg(feature = "eval")]
+        assert_eq!(
+            CivilDate::from(jiff::civil::date(2000, 2, 29)),
+            CivilDate::new(2000, 2, 29).unwrap()
+        );
+
+        assert!("2026-02-29".parse::<CivilDate>().is_err());
+        assert!("1900-02-29".parse::<CivilDate>().is_err());
+        assert!("2026-13-01".parse::<CivilDate>().is_err());
+        assert!("2026-04-31".parse::<CivilDate>().is_err());
+        assert!("2026-1-05".parse::<CivilDate>().is_err());
+        assert!("+026-01-05".parse::<CivilDate>().is_err());
+    }
+
+    #[test]
+    fn test_time_of_day_windows() {
+        let t = |hour, minute| TimeOfDay { hour, minute };
+        assert_eq!(t(9, 0).minutes_until(t(17, 30)), 510);
+        assert_eq!(t(22, 0).minutes_until(t(2, 0)), 240);
+        assert_eq!(t(9, 0).minutes_until(t(9, 0)), 0);
+
+        assert!(t(9, 0).in_window(t(9, 0), t(17, 0)));
+        assert!(t(17, 0).in_window(t(9, 0), t(17, 0)));
+        assert!(!t(17, 1).in_window(t(9, 0), t(17, 0)));
+        assert!(t(23, 30).in_window(t(22, 0), t(2, 0)));
+        assert!(t(1, 0).in_window(t(22, 0), t(2, 0)));
+        assert!(!t(12, 0).in_window(t(22, 0), t(2, 0)));
+        assert!(t(3, 0) < t(3, 1) && t(3, 59) < t(4, 0));
+    }
+
+    #[test]
+    fn test_time_of_day_minutes() {
+        assert_eq!(TimeOfDay::from_minutes(540), Some(TimeOfDay::MORNING));
+        assert_eq!(TimeOfDay::from_minutes(0), Some(TimeOfDay::MIDNIGHT));
+        assert_eq!(
+            TimeOfDay::from_minutes(1439),
+            Some(TimeOfDay {
+                hour: 23,
+                minute: 59
+            })
+        );
+        assert_eq!(TimeOfDay::from_minutes(1440), None);
+        for m in 0..1440 {
+            assert_eq!(TimeOfDay::from_minutes(m).unwrap().to_minutes(), m);
+        }
+    }
+
+    #[test]
+    fn test_month_spec_range_expands() {
+        let spec = DuringSpec::Range(MonthName::June, MonthName::August);
+        assert_eq!(
+            spec.expand(),
+            vec![MonthName::June, MonthName::July, MonthName::August]
+        );
+    }
+
+    #[test]
+    fn test_month_spec_range_wraps_year_end() {
+        let spec = DuringSpec::Range(MonthName::November, MonthName::February);
+        assert_eq!(
+            spec.expand(),
+            vec![
+                MonthName::November,
+                MonthName::December,
+                MonthName::January,
+                MonthName::February
+            ]
+        );
+    }
+
+    #[test]
+    fn test_month_spec_single_month_range() {
+        let spec = DuringSpec::Range(MonthName::May, MonthName::May);
+        assert_eq!(spec.expand(), vec![MonthName::May]);
+    }
+
+    #[test]
+    fn test_canonical_full_week_collapses_to_every() {
+        let filter = DayFilter::Days(vec![
+            Weekday::Sunday,
+            Weekday::Monday,
+            Weekday::Tuesday,
+            Weekday::Wednesday,
+            Weekday::Thursday,
+            Weekday::Friday,
+            Weekday::Saturday,
+        ]);
+        assert_eq!(filter.canonical(), DayFilter::Every);
+    }
+
+    #[test]
+    fn test_canonical_weekdays_collapse_to_weekday() {
+        let filter = DayFilter::Days(Weekday::all_weekdays());
+        assert_eq!(filter.canonical(), DayFilter::Weekday);
+    }
+
+    #[test]
+    fn test_canonical_weekend_days_stay_days() {
+        let filter = DayFilter::Days(vec![Weekday::Sunday, Weekday::Saturday]);
+        assert_eq!(
+            filter.canonical(),
+            DayFilter::Days(vec![Weekday::Saturday, Weekday::Sunday])
+        );
+    }
+
+    #[test]
+    fn test_canonical_ignores_duplicates() {
+        let filter = DayFilter::Days(vec![
+            Weekday::Friday,
+            Weekday::Thursday,
+            Weekday::Monday,
+            Weekday::Tuesday,
+            Weekday::Wednesday,
+            Weekday::Friday,
+        ]);
+        assert_eq!(filter.canonical(), DayFilter::Weekday);
+    }
+
+    #[test]
+    fn test_canonical_six_days_stays_days() {
+        let days = vec![
+            Weekday::Monday,
+            Weekday::Tuesday,
+            Weekday::Wednesday,
+            Weekday::Thursday,
+            Weekday::Friday,
+            Weekday::Saturday,
+        ];
+        let filter = DayFilter::Days(days.clone());
+        assert_eq!(filter.canonical(), DayFilter::Days(days));
+    }
+
+    #[test]
+    fn test_canonical_sorts_and_dedups_days() {
+        let filter = DayFilter::Days(vec![Weekday::Friday, Weekday::Monday, Weekday::Friday]);
+        assert_eq!(
+            filter.canonical(),
+            DayFilter::Days(vec![Weekday::Monday, Weekday::Friday])
+        );
+    }
+
+    #[test]
+    fn test_canonical_keywords_unchanged() {
+        assert_eq!(DayFilter::Every.canonical(), DayFilter::Every);
+        assert_eq!(DayFilter::Weekday.canonical(), DayFilter::Weekday);
+        assert_eq!(DayFilter::Weekend.canonical(), DayFilter::Weekend);
+    }
+
+    #[test]
+    fn test_canonical_form_schedule_equality() {
+        let explicit = crate::parser::parse("every fri, mon, tue, wed, thu at 09:00").unwrap();
+        let keyword = crate::parser::parse("every weekday at 09:00").unwrap();
+        assert_eq!(explicit, keyword);
+        let built = Schedule::new(ScheduleExpr::DayRepeat {
+            interval: 1,
+            days: DayFilter::Days(Weekday::all_weekdays()),
+            times: vec![TimeOfDay::MORNING],
+        });
+        assert_ne!(built, keyword);
+        assert_eq!(built.canonical_form(), keyword.canonical_form());
+    }
+
+    #[test]
+    fn test_normalize() {
+        let mut s = crate::parser::parse(
+            "every month on the 20th, 1st to 5th, 1st to 5th at 09:00 except 2026-07-04, dec 25, sunday, dec 25 during jul, jan to feb",
+        )
+        .unwrap();
+        s.normalize();
+        assert_eq!(
+            s.to_string(),
+            "every month on the 1st to 5th, 20th at 09:00 except sunday, dec 25, 2026-07-04 during jan to feb, jul"
+        );
+        assert_eq!(
+            s.during_months(),
+            vec![MonthName::January, MonthName::February, MonthName::July]
+        );
+
+        let mut built = Schedule::new(ScheduleExpr::WeekRepeat {
+            interval: 2,
+            days: vec![Weekday::Friday, Weekday::Monday, Weekday::Friday],
+            times: vec![TimeOfDay::EVENING, TimeOfDay::MORNING, TimeOfDay::EVENING],
+        });
+        built.normalize();
+        assert_eq!(
+            built,
+            crate::parser::parse("every 2 weeks on monday, friday at 09:00, 18:00").unwrap()
+        );
+
+        // Normalizing is idempotent
+        let once = s.clone();
+        s.normalize();
+        assert_eq!(s, once);
+    }
+
+    #[test]
+    fn test_canonical_form_interval_day_filter() {
+        let explicit =
+            crate::parser::parse("every 30 min from 09:00 to 17:00 on mon, tue, wed, thu, fri")
+                .unwrap();
+        let keyword = crate::parser::parse("every 30 min from 09:00 to 17:00 on weekday").unwrap();
+        assert_eq!(explicit.canonical_form(), keyword);
+    }
+
+    #[test]
+    fn test_canonical_form_day_and_weekday_differ() {
+        let every = crate::parser::parse("every day at 09:00").unwrap();
+        let weekday = crate::parser::parse("every weekday at 09:00").unwrap();
+        assert_ne!(every.canonical_form(), weekday.canonical_form());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_schedule_hash_dedup() {
+        let set: std::collections::HashSet<Schedule> = [
+            "every weekday at 09:00",
+            "every mon, tue, wed, thu, fri at 09:00",
+            "every weekday at 9:00",
+            "every day at 09:00",
+        ]
+        .iter()
+        .map(|s| crate::parser::parse(s).unwrap().canonical_form())
+        .collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_canonical_form_during_by_covered_days() {
+        use core::hash::BuildHasher;
+        let hasher = std::collections::hash_map::RandomState::new();
+        let canonical = |s| crate::parser::parse(s).unwrap().canonical_form();
+        let range = canonical("every day at 09:00 during jun to aug");
+        for same in [
+            "every day at 09:00 during jun, jul, aug",
+            "every day at 09:00 during jun 1 to aug 31",
+            "every day at 09:00 during jul, jun to jul, aug",
+            "every day at 09:00 during jun 1 to jul 10, jul 5 to aug",
+        ] {
+            assert_eq!(range, canonical(same), "{same}");
+            assert_eq!(
+                hasher.hash_one(&range),
+                hasher.hash_one(canonical(same)),
+                "{same}"
+            );
         }
+        assert_ne!(
+            range,
+            canonical("every day at 09:00 during jun 15 to aug 31")
+        );
+        assert_ne!(range, canonical("every day at 09:00 during jun, aug"));
+        assert_eq!(
+            canonical("every day at 09:00 during jan, dec, jun 15 to jun 20, nov").to_string(),
+            "every day at 09:00 during jun 15 to jun 20, nov to jan"
+        );
+        assert_eq!(
+            canonical("every day at 09:00 during dec 20 to jan 5, jan 6 to jan 10").to_string(),
+            "every day at 09:00 during dec 20 to jan 10"
+        );
+    }
+
+    #[test]
+    fn test_expr_kind() {
+        let cases = [
+            ("every 30 min from 09:00 to 17:00", ExprKind::IntervalRepeat),
+            ("every weekday at 09:00", ExprKind::DayRepeat),
+            ("every 2 weeks on monday at 09:00", ExprKind::WeekRepeat),
+            ("every month on the 1st at 09:00", ExprKind::MonthRepeat),
+            ("on 2026-03-15 at 09:00", ExprKind::SingleDate),
+            ("on feb 14, 2026-03-15 at 09:00", ExprKind::MultiDate),
+            ("every year on dec 25 at 00:00", ExprKind::YearRepeat),
+            (
+                "every year in iso weeks 1 on monday at 09:00",
+                ExprKind::IsoWeekRepeat,
+            ),
+        ];
+        for (input, kind) in cases {
+            assert_eq!(crate::parser::parse(input).unwrap().expr.kind(), kind);
+        }
+        assert_eq!(ExprKind::SingleDate.as_str(), "single_date");
+    }
+
+    #[test]
+    fn test_expr_accessors() {
+        let expr = crate::parser::parse("every 15 min from 09:00 to 17:00 on weekdays")
+            .unwrap()
+            .expr;
+        assert_eq!(expr.interval(), Some(15));
+        assert_eq!(expr.times(), None);
+        assert_eq!(expr.day_filter(), Some(&DayFilter::Weekday));
+
+        let expr = crate::parser::parse("on 2026-03-15 at 09:00, 17:00")
+            .unwrap()
+            .expr;
+        assert_eq!(expr.interval(), None);
+        assert_eq!(expr.times().map(|t| t.len()), Some(2));
+        assert_eq!(expr.day_filter(), None);
+
+        let expr = crate::parser::parse("every 2 days at 09:00").unwrap().expr;
+        assert_eq!(expr.interval(), Some(2));
+        assert_eq!(expr.day_filter(), Some(&DayFilter::Every));
     }
 }
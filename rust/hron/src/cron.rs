@@ -1,8 +1,84 @@
 use crate::ast::*;
 use crate::error::ScheduleError;
+use crate::prelude::*;
 
 /// Convert a Schedule to a 5-field cron expression (minute hour dom month dow).
+/// A `during` clause becomes the month field.
 pub fn to_cron(schedule: &Schedule) -> Result<String, ScheduleError> {
+    cron_fields(schedule, false)
+}
+
+/// Like [`to_cron`], but with symbolic month and day-of-week names
+/// (`0 9 * JAN,JUL MON-FRI`) instead of numbers.
+pub fn to_cron_named(schedule: &Schedule) -> Result<String, ScheduleError> {
+    cron_fields(schedule, true)
+}
+
+/// The fields of a cron-expressible schedule as bit sets: bit `n` is set
+/// when value `n` matches. A wall-clock time in the schedule's timezone
+/// matches when its bit is set in all five masks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CronBitmasks {
+    /// Minutes 0-59.
+    pub minutes: u64,
+    /// Hours 0-23.
+    pub hours: u32,
+    /// Days of the month 1-31 (bit 0 is unused).
+    pub dom: u32,
+    /// Months 1-12 (bit 0 is unused).
+    pub months: u16,
+    /// Days of the week, sunday = 0 through saturday = 6.
+    pub dow: u8,
+}
+
+/// Bit masks for the fields [`to_cron`] produces. `None` when the schedule
+/// isn't cron-expressible, or needs a nearest-weekday (`W`) day of month,
+/// which depends on the month's calendar.
+pub fn to_bitmasks(schedule: &Schedule) -> Option<CronBitmasks> {
+    let cron = to_cron(schedule).ok()?;
+    let fields: Vec<&str> = cron.split_whitespace().collect();
+    let [minute, hour, dom, month, dow] = fields[..] else {
+        return None;
+    };
+    Some(CronBitmasks {
+        minutes: field_mask(minute, 0, 59)?,
+        hours: field_mask(hour, 0, 23)? as u32,
+        dom: field_mask(dom, 1, 31)? as u32,
+        months: field_mask(month, 1, 12)? as u16,
+        dow: field_mask(dow, 0, 6)? as u8,
+    })
+}
+
+/// Set bits for a numeric cron field: comma lists of `*`, `N`, `A-B`, each
+/// with an optional `/step`.
+fn field_mask(field: &str, min: u8, max: u8) -> Option<u64> {
+    let mut mask = 0u64;
+    for item in field.split(',') {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => (range, step.parse::<u8>().ok().filter(|&s| s > 0)?),
+            None => (item, 1),
+        };
+        let (start, end) = match range {
+            "*" => (min, max),
+            _ => match range.split_once('-') {
+                Some((a, b)) => (a.parse().ok()?, b.parse().ok()?),
+                None => {
+                    let n = range.parse().ok()?;
+                    (n, if step > 1 { max } else { n })
+                }
+            },
+        };
+        if start < min || end > max || start > end {
+            return None;
+        }
+        for value in (start..=end).step_by(step as usize) {
+            mask |= 1 << value;
+        }
+    }
+    Some(mask)
+}
+
+fn cron_fields(schedule: &Schedule, named: bool) -> Result<String, ScheduleError> {
     if !schedule.except.is_empty() {
         return Err(ScheduleError::cron(
             "not expressible as cron (except clauses not supported)",
@@ -13,11 +89,12 @@ pub fn to_cron(schedule: &Schedule) -> Result<String, ScheduleError> {
             "not expressible as cron (until clauses not supported)",
         ));
     }
-    if !schedule.during.is_empty() {
+    if schedule.during_has_dates() {
         return Err(ScheduleError::cron(
-            "not expressible as cron (during clauses not supported)",
+            "not expressible as cron (during dates narrower than whole months)",
         ));
     }
+    let month = month_field(&schedule.during_months(), named);
     match &schedule.expr {
         ScheduleExpr::DayRepeat {
             interval,
@@ -29,16 +106,19 @@ pub fn to_cron(schedule: &Schedule) -> Result<String, ScheduleError> {
                     "not expressible as cron (multi-day intervals not supported)",
                 ));
             }
-            if times.len() != 1 {
+            let Some((minute, hour)) = time_fields(times) else {
                 return Err(ScheduleError::cron(
                     "not expressible as cron (multiple times not supported)",
                 ));
-            }
-            let time = &times[0];
-            let dow = day_filter_to_cron_dow(days)?;
-            Ok(format!("{} {} * * {}", time.minute, time.hour, dow))
+            };
+            let dow = day_filter_to_cron_dow(&schedule.weekend.expand(days), named)?;
+            Ok(format!("{minute} {hour} * {month} {dow}"))
         }
 
+        ScheduleExpr::WeekdayRepeat { .. } => Err(ScheduleError::cron(
+            "not expressible as cron (business-day intervals not supported)",
+        )),
+
         ScheduleExpr::IntervalRepeat {
             interval,
             unit,
@@ -46,8 +126,15 @@ pub fn to_cron(schedule: &Schedule) -> Result<String, ScheduleError> {
             to,
             day_filter,
         } => {
-            // Only expressible if window is full day (00:00 to 23:59)
-            let full_day = from.hour == 0 && from.minute == 0 && to.hour == 23 && to.minute == 59;
+            if *unit == IntervalUnit::Days {
+                return Err(ScheduleError::cron(
+                    "not expressible as cron (multi-day interval windows not supported)",
+                ));
+            }
+            // Only expressible if window is full day (00:00 to 23:59). Hour
+            // steps may start past the hour: `from 00:15` fires at :15.
+            let offset_ok = from.minute == 0 || *unit == IntervalUnit::Hours;
+            let full_day = from.hour == 0 && offset_ok && to.hour == 23 && to.minute == 59;
             if !full_day {
                 return Err(ScheduleError::cron(
                     "not expressible as cron (partial-day interval windows not supported)",
@@ -66,9 +153,13 @@ pub fn to_cron(schedule: &Schedule) -> Result<String, ScheduleError> {
                             "not expressible as cron (*/{interval} breaks at hour boundaries)"
                         )));
                     }
-                    Ok(format!("*/{interval} * * * *"))
+                    Ok(format!("*/{interval} * * {month} *"))
+                }
+                IntervalUnit::Hours if *interval == 1 && from.minute != 0 => {
+                    Ok(format!("{} * * {month} *", from.minute))
                 }
-                IntervalUnit::Hours => Ok(format!("0 */{interval} * * *")),
+                IntervalUnit::Hours => Ok(format!("{} */{interval} * {month} *", from.minute)),
+                IntervalUnit::Days => unreachable!("day-step intervals rejected above"),
             }
         }
 
@@ -80,18 +171,23 @@ pub fn to_cron(schedule: &Schedule) -> Result<String, ScheduleError> {
             interval,
             target,
             times,
+            day_filter,
         } => {
+            if day_filter.is_some() {
+                return Err(ScheduleError::cron(
+                    "not expressible as cron (cron ORs day-of-month with day-of-week)",
+                ));
+            }
             if *interval > 1 {
                 return Err(ScheduleError::cron(
                     "not expressible as cron (multi-month intervals not supported)",
                 ));
             }
-            if times.len() != 1 {
+            let Some((minute, hour)) = time_fields(times) else {
                 return Err(ScheduleError::cron(
                     "not expressible as cron (multiple times not supported)",
                 ));
-            }
-            let time = &times[0];
+            };
             match target {
                 MonthTarget::Days(_) => {
                     let expanded = target.expand_days();
@@ -100,11 +196,14 @@ pub fn to_cron(schedule: &Schedule) -> Result<String, ScheduleError> {
                         .map(|d| d.to_string())
                         .collect::<Vec<_>>()
                         .join(",");
-                    Ok(format!("{} {} {} * *", time.minute, time.hour, dom))
+                    Ok(format!("{minute} {hour} {dom} {month} *"))
                 }
                 MonthTarget::LastDay => Err(ScheduleError::cron(
                     "not expressible as cron (last day of month not supported)",
                 )),
+                MonthTarget::LastNDays(_) => Err(ScheduleError::cron(
+                    "not expressible as cron (last days of month not supported)",
+                )),
                 MonthTarget::LastWeekday => Err(ScheduleError::cron(
                     "not expressible as cron (last weekday of month not supported)",
                 )),
@@ -114,11 +213,19 @@ pub fn to_cron(schedule: &Schedule) -> Result<String, ScheduleError> {
                             "not expressible as cron (directional nearest weekday not supported)",
                         ));
                     }
-                    Ok(format!("{} {} {}W * *", time.minute, time.hour, day))
+                    Ok(format!("{minute} {hour} {day}W {month} *"))
                 }
                 MonthTarget::OrdinalWeekday { .. } => Err(ScheduleError::cron(
                     "not expressible as cron (ordinal weekday of month not supported)",
                 )),
+                MonthTarget::OrdinalWeekdays { .. } => Err(ScheduleError::cron(
+                    "not expressible as cron (ordinal weekdays of month not supported)",
+                )),
+                // The first business day is always the weekday nearest the 1st
+                MonthTarget::BusinessDay(1) => Ok(format!("{minute} {hour} 1W {month} *")),
+                MonthTarget::BusinessDay(_) => Err(ScheduleError::cron(
+                    "not expressible as cron (nth business day of month not supported)",
+                )),
             }
         }
 
@@ -126,25 +233,586 @@ pub fn to_cron(schedule: &Schedule) -> Result<String, ScheduleError> {
             "not expressible as cron (single dates are not repeating)",
         )),
 
+        ScheduleExpr::MultiDate { .. } => Err(ScheduleError::cron(
+            "not expressible as cron (dates are not repeating)",
+        )),
+
         ScheduleExpr::YearRepeat { .. } => Err(ScheduleError::cron(
             "not expressible as cron (yearly schedules not supported in 5-field cron)",
         )),
+
+        ScheduleExpr::IsoWeekRepeat { .. } => Err(ScheduleError::cron(
+            "not expressible as cron (cron has no week-of-year field)",
+        )),
+    }
+}
+
+/// Every reason `to_cron` rejects the schedule, in the words of its errors
+/// (`"until clauses not supported"`). Empty when the schedule converts.
+pub fn cron_blockers(schedule: &Schedule) -> Vec<&'static str> {
+    let mut blockers = Vec::new();
+    if !schedule.except.is_empty() {
+        blockers.push("except clauses not supported");
+    }
+    if schedule.until.is_some() {
+        blockers.push("until clauses not supported");
+    }
+    if schedule.during_has_dates() {
+        blockers.push("during dates narrower than whole months");
+    }
+    match &schedule.expr {
+        ScheduleExpr::DayRepeat {
+            interval, times, ..
+        } => {
+            if *interval > 1 {
+                blockers.push("multi-day intervals not supported");
+            }
+            if time_fields(times).is_none() {
+                blockers.push("multiple times not supported");
+            }
+        }
+        ScheduleExpr::WeekdayRepeat { .. } => blockers.push("business-day intervals not supported"),
+        ScheduleExpr::IntervalRepeat {
+            interval,
+            unit,
+            from,
+            to,
+            day_filter,
+        } => {
+            if *unit == IntervalUnit::Days {
+                blockers.push("multi-day interval windows not supported");
+            }
+            let offset_ok = from.minute == 0 || *unit == IntervalUnit::Hours;
+            let full_day = from.hour == 0 && offset_ok && to.hour == 23 && to.minute == 59;
+            if !full_day {
+                blockers.push("partial-day interval windows not supported");
+            }
+            if day_filter.is_some() {
+                blockers.push("interval with day filter not supported");
+            }
+            if *unit == IntervalUnit::Minutes && 60 % interval != 0 {
+                blockers.push("minute interval breaks at hour boundaries");
+            }
+        }
+        ScheduleExpr::WeekRepeat { .. } => blockers.push("multi-week intervals not supported"),
+        ScheduleExpr::MonthRepeat {
+            interval,
+            target,
+            times,
+            day_filter,
+        } => {
+            if day_filter.is_some() {
+                blockers.push("cron ORs day-of-month with day-of-week");
+            }
+            if *interval > 1 {
+                blockers.push("multi-month intervals not supported");
+            }
+            if time_fields(times).is_none() {
+                blockers.push("multiple times not supported");
+            }
+            match target {
+                MonthTarget::Days(_)
+                | MonthTarget::NearestWeekday {
+                    direction: None, ..
+                } => {}
+                MonthTarget::LastDay => blockers.push("last day of month not supported"),
+                MonthTarget::LastNDays(_) => blockers.push("last days of month not supported"),
+                MonthTarget::LastWeekday => blockers.push("last weekday of month not supported"),
+                MonthTarget::NearestWeekday { .. } => {
+                    blockers.push("directional nearest weekday not supported")
+                }
+                MonthTarget::OrdinalWeekday { .. } => {
+                    blockers.push("ordinal weekday of month not supported")
+                }
+                MonthTarget::OrdinalWeekdays { .. } => {
+                    blockers.push("ordinal weekdays of month not supported")
+                }
+                MonthTarget::BusinessDay(1) => {}
+                MonthTarget::BusinessDay(_) => {
+                    blockers.push("nth business day of month not supported")
+                }
+            }
+        }
+        ScheduleExpr::SingleDate { .. } => blockers.push("single dates are not repeating"),
+        ScheduleExpr::MultiDate { .. } => blockers.push("dates are not repeating"),
+        ScheduleExpr::YearRepeat { .. } => {
+            blockers.push("yearly schedules not supported in 5-field cron")
+        }
+        ScheduleExpr::IsoWeekRepeat { .. } => blockers.push("cron has no week-of-year field"),
+    }
+    blockers
+}
+
+/// Convert a Schedule to the closest 5-field cron expression, with a warning
+/// for each way the cron diverges from the schedule. Schedules that `to_cron`
+/// accepts convert exactly with no warnings.
+pub fn to_cron_lossy(schedule: &Schedule) -> (String, Vec<String>) {
+    if let Ok(cron) = to_cron(schedule) {
+        return (cron, Vec::new());
+    }
+
+    let mut warnings = Vec::new();
+    if !schedule.except.is_empty() {
+        warnings.push("except dates dropped (cron has no exclusions)".to_string());
+    }
+    if schedule.until.is_some() {
+        warnings.push("until dropped (cron has no end)".to_string());
+    }
+    if schedule.anchor.is_some() || schedule.anchor_relative.is_some() {
+        warnings.push("starting anchor dropped (cron steps align to the calendar)".to_string());
+    }
+    if schedule.during_has_dates() {
+        warnings
+            .push("during widened to whole months (cron months have no day bounds)".to_string());
+    }
+    let during = month_field(&schedule.during_months(), false);
+
+    let cron = lossy_fields(&schedule.expr, schedule.weekend, &during, &mut warnings);
+    (cron, warnings)
+}
+
+fn lossy_fields(
+    expr: &ScheduleExpr,
+    weekend: WeekendDays,
+    during: &str,
+    warnings: &mut Vec<String>,
+) -> String {
+    match expr {
+        ScheduleExpr::DayRepeat {
+            interval,
+            days,
+            times,
+        } => {
+            let (minute, hour) = lossy_times(times, warnings);
+            let dom = if *interval > 1 {
+                warnings.push(format!(
+                    "every {interval} days approximated as */{interval} day-of-month, which restarts each month"
+                ));
+                if *days != DayFilter::Every {
+                    warnings.push(
+                        "cron ORs day-of-month with day-of-week, so the day filter widens"
+                            .to_string(),
+                    );
+                }
+                format!("*/{interval}")
+            } else {
+                "*".to_string()
+            };
+            format!(
+                "{minute} {hour} {dom} {during} {}",
+                cron_dow(&weekend.expand(days))
+            )
+        }
+
+        ScheduleExpr::WeekdayRepeat { interval, times } => {
+            warnings.push(format!(
+                "every {interval} weekdays approximated as every weekday"
+            ));
+            let (minute, hour) = lossy_times(times, warnings);
+            format!(
+                "{minute} {hour} * {during} {}",
+                cron_dow(&DayFilter::Weekday)
+            )
+        }
+
+        ScheduleExpr::IntervalRepeat {
+            interval,
+            unit,
+            from,
+            to,
+            day_filter,
+        } => {
+            let dow = day_filter
+                .as_ref()
+                .map_or("*".to_string(), |df| cron_dow(&weekend.expand(df)));
+            let wraps = to < from;
+            if wraps && day_filter.is_some() {
+                warnings.push(
+                    "day filter applies to the calendar day in cron, not the day the window starts"
+                        .to_string(),
+                );
+            }
+            let step_hours = match unit {
+                IntervalUnit::Days => {
+                    warnings.push(format!(
+                        "every {interval} days approximated as */{interval} day-of-month at {from}, which restarts each month"
+                    ));
+                    return format!("{} {} */{interval} {during} {dow}", from.minute, from.hour);
+                }
+                IntervalUnit::Minutes if *interval < 60 => {
+                    if 60 % interval != 0 {
+                        warnings.push(format!(
+                            "cron */{interval} fires at {} each hour, not true {interval}-min intervals",
+                            generate_cron_minute_fires(*interval)
+                        ));
+                    }
+                    let full_day =
+                        from.hour == 0 && from.minute == 0 && to.hour == 23 && to.minute == 59;
+                    let hours = if full_day {
+                        "*".to_string()
+                    } else {
+                        if from.minute != 0 || to.minute != 59 {
+                            warnings.push(format!(
+                                "window {from} to {to} rounded to whole hours ({:02}:00 to {:02}:59)",
+                                from.hour, to.hour
+                            ));
+                        }
+                        if wraps {
+                            format!("{}-23,0-{}", from.hour, to.hour)
+                        } else {
+                            format!("{}-{}", from.hour, to.hour)
+                        }
+                    };
+                    return format!("*/{interval} {hours} * {during} {dow}");
+                }
+                IntervalUnit::Minutes => {
+                    let hours = ((*interval + 30) / 60).max(1);
+                    if interval % 60 != 0 {
+                        warnings.push(format!(
+                            "every {interval} min approximated as every {hours} hours"
+                        ));
+                    }
+                    hours
+                }
+                IntervalUnit::Hours => *interval,
+            };
+            // Enumerate the hourly slots of one window, spilling past midnight when it wraps
+            let start = from.to_minutes() as u32;
+            let mut end = to.to_minutes() as u32;
+            if wraps {
+                end += 24 * 60;
+            }
+            let slots: Vec<u8> = (start..=end)
+                .step_by(step_hours as usize * 60)
+                .map(|m| ((m / 60) % 24) as u8)
+                .collect();
+            format!("{} {} * {during} {dow}", from.minute, join_numbers(&slots))
+        }
+
+        ScheduleExpr::WeekRepeat {
+            interval,
+            days,
+            times,
+        } => {
+            if *interval > 1 {
+                warnings.push(format!("every {interval} weeks approximated as every week"));
+            }
+            let (minute, hour) = lossy_times(times, warnings);
+            let dow = cron_dow(&DayFilter::Days(days.clone()));
+            format!("{minute} {hour} * {during} {dow}")
+        }
+
+        ScheduleExpr::MonthRepeat {
+            interval,
+            target,
+            times,
+            day_filter,
+        } => {
+            if day_filter.is_some() {
+                warnings.push(
+                    "day filter dropped (cron ORs day-of-month with day-of-week)".to_string(),
+                );
+            }
+            let month = if *interval > 1 {
+                warnings.push(format!(
+                    "every {interval} months approximated as */{interval}, which counts from january"
+                ));
+                if during == "*" {
+                    format!("*/{interval}")
+                } else {
+                    during.to_string()
+                }
+            } else {
+                during.to_string()
+            };
+            let (minute, hour) = lossy_times(times, warnings);
+            let (dom, dow) = match target {
+                MonthTarget::Days(_) => (join_numbers(&target.expand_days()), "*".to_string()),
+                MonthTarget::LastDay => {
+                    warnings.push("uses the non-standard L extension".to_string());
+                    ("L".to_string(), "*".to_string())
+                }
+                MonthTarget::LastNDays(n) => {
+                    let first = 32 - n;
+                    warnings.push(format!(
+                        "last {n} days approximated as {first}-31, which ignores month length"
+                    ));
+                    (format!("{first}-31"), "*".to_string())
+                }
+                MonthTarget::LastWeekday => {
+                    warnings.push("uses the non-standard LW extension".to_string());
+                    ("LW".to_string(), "*".to_string())
+                }
+                MonthTarget::NearestWeekday { day, direction } => {
+                    if direction.is_some() {
+                        warnings.push(
+                            "directional nearest weekday approximated as W, which never crosses a month boundary"
+                                .to_string(),
+                        );
+                    }
+                    (format!("{day}W"), "*".to_string())
+                }
+                MonthTarget::OrdinalWeekday { ordinal, weekday } => (
+                    "*".to_string(),
+                    lossy_nth_weekday(*ordinal, *weekday, warnings),
+                ),
+                MonthTarget::OrdinalWeekdays { ordinals, weekday } => {
+                    warnings.push(format!(
+                        "multiple ordinal weekdays approximated as the {} only",
+                        ordinals[0].as_str()
+                    ));
+                    (
+                        "*".to_string(),
+                        lossy_nth_weekday(ordinals[0], *weekday, warnings),
+                    )
+                }
+                MonthTarget::BusinessDay(n) => {
+                    if *n > 1 {
+                        warnings.push(format!(
+                            "business day {n} approximated as {n}W, the weekday nearest day {n}"
+                        ));
+                    }
+                    (format!("{n}W"), "*".to_string())
+                }
+            };
+            format!("{minute} {hour} {dom} {month} {dow}")
+        }
+
+        ScheduleExpr::SingleDate {
+            date: DateSpec::NextWeekday(day),
+            times,
+        } => {
+            warnings.push("upcoming weekday approximated as a weekly schedule".to_string());
+            let (minute, hour) = lossy_times(times, warnings);
+            let dow = cron_dow(&DayFilter::Days(vec![*day]));
+            format!("{minute} {hour} * {during} {dow}")
+        }
+
+        ScheduleExpr::SingleDate { date, times } => {
+            warnings.push("single date approximated as a yearly schedule".to_string());
+            let (month, day) = date_month_day(date);
+            lossy_year_fields(times, &day.to_string(), month, "*", during, warnings)
+        }
+
+        ScheduleExpr::MultiDate { dates, times } => {
+            warnings.push("dates approximated as a yearly schedule".to_string());
+            let (mut months, mut days): (Vec<u8>, Vec<u8>) =
+                dates.iter().map(date_month_day).unzip();
+            months.sort();
+            months.dedup();
+            days.sort();
+            days.dedup();
+            if months.len() > 1 && days.len() > 1 {
+                warnings.push(
+                    "dates combined into day and month lists (cron fires on every pairing)"
+                        .to_string(),
+                );
+            }
+            if during != "*" {
+                warnings
+                    .push("during dropped for a schedule that already names its month".to_string());
+            }
+            let (minute, hour) = lossy_times(times, warnings);
+            format!(
+                "{minute} {hour} {} {} *",
+                join_numbers(&days),
+                join_numbers(&months)
+            )
+        }
+
+        ScheduleExpr::YearRepeat {
+            interval,
+            target,
+            times,
+        } => {
+            if *interval > 1 {
+                warnings.push(format!("every {interval} years approximated as every year"));
+            }
+            match target {
+                YearTarget::Date { month, day } | YearTarget::DayOfMonth { day, month } => {
+                    lossy_year_fields(
+                        times,
+                        &day.to_string(),
+                        month.number(),
+                        "*",
+                        during,
+                        warnings,
+                    )
+                }
+                YearTarget::OrdinalWeekday {
+                    ordinal,
+                    weekday,
+                    month,
+                } => {
+                    let dow = lossy_nth_weekday(*ordinal, *weekday, warnings);
+                    lossy_year_fields(times, "*", month.number(), &dow, during, warnings)
+                }
+                YearTarget::LastWeekday { month } => {
+                    warnings.push("uses the non-standard LW extension".to_string());
+                    lossy_year_fields(times, "LW", month.number(), "*", during, warnings)
+                }
+            }
+        }
+
+        ScheduleExpr::IsoWeekRepeat { days, times, .. } => {
+            warnings.push("ISO weeks dropped (cron has no week-of-year field)".to_string());
+            let (minute, hour) = lossy_times(times, warnings);
+            let dow = cron_dow(&DayFilter::Days(days.clone()));
+            format!("{minute} {hour} * {during} {dow}")
+        }
     }
 }
 
-fn day_filter_to_cron_dow(filter: &DayFilter) -> Result<String, ScheduleError> {
+/// Fields for a once-a-year schedule. `during` cannot narrow a single month, so it is dropped.
+/// Month and day of a date, falling back to jan 1 for an unparseable ISO date
+/// or a relative weekday.
+fn date_month_day(date: &DateSpec) -> (u8, u8) {
+    match date {
+        DateSpec::Named { month, day } => (month.number(), *day),
+        DateSpec::Iso(iso) => match iso.parse::<CivilDate>() {
+            Ok(d) => (d.month(), d.day()),
+            Err(_) => (1, 1),
+        },
+        DateSpec::NextWeekday(_) => (1, 1),
+    }
+}
+
+fn lossy_year_fields(
+    times: &[TimeOfDay],
+    dom: &str,
+    month: u8,
+    dow: &str,
+    during: &str,
+    warnings: &mut Vec<String>,
+) -> String {
+    if during != "*" {
+        warnings.push("during dropped for a schedule that already names its month".to_string());
+    }
+    let (minute, hour) = lossy_times(times, warnings);
+    format!("{minute} {hour} {dom} {month} {dow}")
+}
+
+/// Exact minute and hour fields for a list of times: `0 9,17`, `0,30 9`, or
+/// `0,30 9,17` when the times are every listed minute at every listed hour.
+/// `None` when comma fields would fire at times not in the list.
+fn time_fields(times: &[TimeOfDay]) -> Option<(String, String)> {
+    let mut distinct = times.to_vec();
+    distinct.sort();
+    distinct.dedup();
+    let mut minutes: Vec<u8> = distinct.iter().map(|t| t.minute).collect();
+    minutes.sort();
+    minutes.dedup();
+    let mut hours: Vec<u8> = distinct.iter().map(|t| t.hour).collect();
+    hours.sort();
+    hours.dedup();
+    // Distinct times are a subset of the grid, so equal counts mean equal sets
+    (minutes.len() * hours.len() == distinct.len())
+        .then(|| (join_numbers(&minutes), join_numbers(&hours)))
+}
+
+/// Minute and hour fields for a list of times. Times that form a grid
+/// combine exactly (see [`time_fields`]); otherwise only the first time is kept.
+fn lossy_times(times: &[TimeOfDay], warnings: &mut Vec<String>) -> (String, String) {
+    if let Some(fields) = time_fields(times) {
+        return fields;
+    }
+    let first = times[0];
+    warnings.push(format!(
+        "only {first} kept (cron cannot combine times with different minutes)"
+    ));
+    (first.minute.to_string(), first.hour.to_string())
+}
+
+/// Day-of-week field for an ordinal weekday: `1#2` (second monday) or `5L` (last friday).
+fn lossy_nth_weekday(
+    ordinal: OrdinalPosition,
+    weekday: Weekday,
+    warnings: &mut Vec<String>,
+) -> String {
+    let dow = cron_dow_number(weekday);
+    let nth = match ordinal {
+        OrdinalPosition::First => 1,
+        OrdinalPosition::Second => 2,
+        OrdinalPosition::Third => 3,
+        OrdinalPosition::Fourth => 4,
+        OrdinalPosition::Fifth => 5,
+        OrdinalPosition::Last => {
+            warnings.push("uses the non-standard L day-of-week extension".to_string());
+            return format!("{dow}L");
+        }
+    };
+    warnings.push("uses the non-standard # extension".to_string());
+    format!("{dow}#{nth}")
+}
+
+fn cron_dow(filter: &DayFilter) -> String {
+    day_filter_to_cron_dow(filter, false).unwrap_or_else(|_| "*".to_string())
+}
+
+fn join_numbers(nums: &[u8]) -> String {
+    nums.iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Month field from a `during` list: `*` when empty, otherwise the sorted
+/// months with runs of three or more collapsed into ranges (`1-3,7`).
+fn month_field(during: &[MonthName], named: bool) -> String {
+    let mut months: Vec<u8> = during.iter().map(|m| m.number()).collect();
+    months.sort();
+    months.dedup();
+    if months.is_empty() {
+        return "*".to_string();
+    }
+    let name = |n: u8| {
+        if named {
+            CRON_MONTH_NAMES[n as usize - 1].to_string()
+        } else {
+            n.to_string()
+        }
+    };
+
+    let mut parts = Vec::new();
+    let mut i = 0;
+    while i < months.len() {
+        let mut j = i;
+        while j + 1 < months.len() && months[j + 1] == months[j] + 1 {
+            j += 1;
+        }
+        if j - i >= 2 {
+            parts.push(format!("{}-{}", name(months[i]), name(months[j])));
+        } else {
+            parts.extend(months[i..=j].iter().map(|&n| name(n)));
+        }
+        i = j + 1;
+    }
+    parts.join(",")
+}
+
+const CRON_MONTH_NAMES: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+/// Indexed by cron DOW number (0=Sunday).
+const CRON_DOW_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+fn day_filter_to_cron_dow(filter: &DayFilter, named: bool) -> Result<String, ScheduleError> {
+    let name = |n: u8| {
+        if named {
+            CRON_DOW_NAMES[n as usize].to_string()
+        } else {
+            n.to_string()
+        }
+    };
     match filter {
         DayFilter::Every => Ok("*".to_string()),
-        DayFilter::Weekday => Ok("1-5".to_string()),
-        DayFilter::Weekend => Ok("0,6".to_string()),
+        DayFilter::Weekday => Ok(format!("{}-{}", name(1), name(5))),
+        DayFilter::Weekend => Ok(format!("{},{}", name(0), name(6))),
         DayFilter::Days(days) => {
             let mut nums: Vec<u8> = days.iter().map(|d| cron_dow_number(*d)).collect();
             nums.sort();
-            Ok(nums
-                .iter()
-                .map(|n| n.to_string())
-                .collect::<Vec<_>>()
-                .join(","))
+            Ok(nums.iter().map(|&n| name(n)).collect::<Vec<_>>().join(","))
         }
     }
 }
@@ -227,9 +895,7 @@ pub fn from_cron(cron: &str) -> Result<Schedule, ScheduleError> {
     }
 
     // Standard time-based cron
-    let minute: u8 = parse_single_value(minute_field, "minute", 0, 59)?;
-    let hour: u8 = parse_single_value(hour_field, "hour", 0, 23)?;
-    let time = TimeOfDay { hour, minute };
+    let times = parse_cron_times(minute_field, hour_field)?;
 
     // DOM-based (monthly) - when DOM is specified and DOW is *
     if dom_field != "*" && dow_field == "*" {
@@ -237,7 +903,8 @@ pub fn from_cron(cron: &str) -> Result<Schedule, ScheduleError> {
         let mut schedule = Schedule::new(ScheduleExpr::MonthRepeat {
             interval: 1,
             target,
-            times: vec![time],
+            times,
+            day_filter: None,
         });
         schedule.during = during;
         return Ok(schedule);
@@ -248,39 +915,48 @@ pub fn from_cron(cron: &str) -> Result<Schedule, ScheduleError> {
     let mut schedule = Schedule::new(ScheduleExpr::DayRepeat {
         interval: 1,
         days,
-        times: vec![time],
+        times,
     });
     schedule.during = during;
     Ok(schedule)
 }
 
 /// Parse @ shortcuts like @daily, @hourly, etc.
-fn parse_cron_shortcut(cron: &str) -> Result<Schedule, ScheduleError> {
-    match cron.to_lowercase().as_str() {
-        "@yearly" | "@annually" => Ok(Schedule::new(ScheduleExpr::YearRepeat {
+pub(crate) fn parse_cron_shortcut(cron: &str) -> Result<Schedule, ScheduleError> {
+    cron_shortcut_expr(&cron.to_lowercase())
+        .map(Schedule::new)
+        .ok_or_else(|| ScheduleError::cron(format!("unknown @ shortcut: {cron}")))
+}
+
+/// The expression a lowercase @ shortcut stands for, if it is one.
+pub(crate) fn cron_shortcut_expr(shortcut: &str) -> Option<ScheduleExpr> {
+    let midnight = vec![TimeOfDay { hour: 0, minute: 0 }];
+    match shortcut {
+        "@yearly" | "@annually" => Some(ScheduleExpr::YearRepeat {
             interval: 1,
             target: YearTarget::Date {
                 month: MonthName::January,
                 day: 1,
             },
-            times: vec![TimeOfDay { hour: 0, minute: 0 }],
-        })),
-        "@monthly" => Ok(Schedule::new(ScheduleExpr::MonthRepeat {
+            times: midnight,
+        }),
+        "@monthly" => Some(ScheduleExpr::MonthRepeat {
             interval: 1,
             target: MonthTarget::Days(vec![DayOfMonthSpec::Single(1)]),
-            times: vec![TimeOfDay { hour: 0, minute: 0 }],
-        })),
-        "@weekly" => Ok(Schedule::new(ScheduleExpr::DayRepeat {
+            times: midnight,
+            day_filter: None,
+        }),
+        "@weekly" => Some(ScheduleExpr::DayRepeat {
             interval: 1,
             days: DayFilter::Days(vec![Weekday::Sunday]),
-            times: vec![TimeOfDay { hour: 0, minute: 0 }],
-        })),
-        "@daily" | "@midnight" => Ok(Schedule::new(ScheduleExpr::DayRepeat {
+            times: midnight,
+        }),
+        "@daily" | "@midnight" => Some(ScheduleExpr::DayRepeat {
             interval: 1,
             days: DayFilter::Every,
-            times: vec![TimeOfDay { hour: 0, minute: 0 }],
-        })),
-        "@hourly" => Ok(Schedule::new(ScheduleExpr::IntervalRepeat {
+            times: midnight,
+        }),
+        "@hourly" => Some(ScheduleExpr::IntervalRepeat {
             interval: 1,
             unit: IntervalUnit::Hours,
             from: TimeOfDay { hour: 0, minute: 0 },
@@ -289,13 +965,13 @@ fn parse_cron_shortcut(cron: &str) -> Result<Schedule, ScheduleError> {
                 minute: 59,
             },
             day_filter: None,
-        })),
-        _ => Err(ScheduleError::cron(format!("unknown @ shortcut: {cron}"))),
+        }),
+        _ => None,
     }
 }
 
-/// Parse month field into a Vec<MonthName> for the `during` clause.
-fn parse_month_field(field: &str) -> Result<Vec<MonthName>, ScheduleError> {
+/// Parse month field into `during` specs, one per month.
+fn parse_month_field(field: &str) -> Result<Vec<DuringSpec>, ScheduleError> {
     if field == "*" {
         return Ok(vec![]);
     }
@@ -348,7 +1024,7 @@ fn parse_month_field(field: &str) -> Result<Vec<MonthName>, ScheduleError> {
         }
     }
 
-    Ok(months)
+    Ok(months.into_iter().map(DuringSpec::Single).collect())
 }
 
 /// Parse a single month value (number 1-12 or name JAN-DEC).
@@ -385,7 +1061,7 @@ fn try_parse_nth_weekday(
     hour_field: &str,
     dom_field: &str,
     dow_field: &str,
-    during: &[MonthName],
+    during: &[DuringSpec],
 ) -> Result<Option<Schedule>, ScheduleError> {
     // Check for # pattern (nth weekday of month)
     if let Some((dow_str, nth_str)) = dow_field.split_once('#') {
@@ -412,13 +1088,13 @@ fn try_parse_nth_weekday(
             ));
         }
 
-        let minute: u8 = parse_single_value(minute_field, "minute", 0, 59)?;
-        let hour: u8 = parse_single_value(hour_field, "hour", 0, 23)?;
+        let times = parse_cron_times(minute_field, hour_field)?;
 
         let mut schedule = Schedule::new(ScheduleExpr::MonthRepeat {
             interval: 1,
             target: MonthTarget::OrdinalWeekday { ordinal, weekday },
-            times: vec![TimeOfDay { hour, minute }],
+            times,
+            day_filter: None,
         });
         schedule.during = during.to_vec();
         return Ok(Some(schedule));
@@ -436,8 +1112,7 @@ fn try_parse_nth_weekday(
             ));
         }
 
-        let minute: u8 = parse_single_value(minute_field, "minute", 0, 59)?;
-        let hour: u8 = parse_single_value(hour_field, "hour", 0, 23)?;
+        let times = parse_cron_times(minute_field, hour_field)?;
 
         let mut schedule = Schedule::new(ScheduleExpr::MonthRepeat {
             interval: 1,
@@ -445,7 +1120,8 @@ fn try_parse_nth_weekday(
                 ordinal: OrdinalPosition::Last,
                 weekday,
             },
-            times: vec![TimeOfDay { hour, minute }],
+            times,
+            day_filter: None,
         });
         schedule.during = during.to_vec();
         return Ok(Some(schedule));
@@ -460,7 +1136,7 @@ fn try_parse_last_day(
     hour_field: &str,
     dom_field: &str,
     dow_field: &str,
-    during: &[MonthName],
+    during: &[DuringSpec],
 ) -> Result<Option<Schedule>, ScheduleError> {
     if dom_field != "L" && dom_field != "LW" {
         return Ok(None);
@@ -472,8 +1148,7 @@ fn try_parse_last_day(
         ));
     }
 
-    let minute: u8 = parse_single_value(minute_field, "minute", 0, 59)?;
-    let hour: u8 = parse_single_value(hour_field, "hour", 0, 23)?;
+    let times = parse_cron_times(minute_field, hour_field)?;
 
     let target = if dom_field == "LW" {
         MonthTarget::LastWeekday
@@ -484,7 +1159,8 @@ fn try_parse_last_day(
     let mut schedule = Schedule::new(ScheduleExpr::MonthRepeat {
         interval: 1,
         target,
-        times: vec![TimeOfDay { hour, minute }],
+        times,
+        day_filter: None,
     });
     schedule.during = during.to_vec();
     Ok(Some(schedule))
@@ -496,7 +1172,7 @@ fn try_parse_nearest_weekday(
     hour_field: &str,
     dom_field: &str,
     dow_field: &str,
-    during: &[MonthName],
+    during: &[DuringSpec],
 ) -> Result<Option<Schedule>, ScheduleError> {
     if !dom_field.ends_with('W') || dom_field == "LW" {
         return Ok(None);
@@ -518,8 +1194,7 @@ fn try_parse_nearest_weekday(
         )));
     }
 
-    let minute: u8 = parse_single_value(minute_field, "minute", 0, 59)?;
-    let hour: u8 = parse_single_value(hour_field, "hour", 0, 23)?;
+    let times = parse_cron_times(minute_field, hour_field)?;
 
     let target = MonthTarget::NearestWeekday {
         day,
@@ -529,7 +1204,8 @@ fn try_parse_nearest_weekday(
     let mut schedule = Schedule::new(ScheduleExpr::MonthRepeat {
         interval: 1,
         target,
-        times: vec![TimeOfDay { hour, minute }],
+        times,
+        day_filter: None,
     });
     schedule.during = during.to_vec();
     Ok(Some(schedule))
@@ -541,7 +1217,7 @@ fn try_parse_interval(
     hour_field: &str,
     dom_field: &str,
     dow_field: &str,
-    during: &[MonthName],
+    during: &[DuringSpec],
 ) -> Result<Option<Schedule>, ScheduleError> {
     // Minute interval: */N or range/N
     if minute_field.contains('/') {
@@ -639,11 +1315,10 @@ fn try_parse_interval(
         }
     }
 
-    // Hour interval: 0 */N or 0 range/N
-    if hour_field.contains('/') && (minute_field == "0" || minute_field == "00") {
-        let (range_part, step_str) = hour_field
-            .split_once('/')
-            .ok_or_else(|| ScheduleError::cron("invalid hour interval"))?;
+    // Hour interval: M */N, M range/N, or M * (every hour at minute M)
+    let minute_offset = minute_field.parse::<u8>().ok().filter(|m| *m <= 59);
+    if let (Some(minute), true) = (minute_offset, hour_field.contains('/') || hour_field == "*") {
+        let (range_part, step_str) = hour_field.split_once('/').unwrap_or(("*", "1"));
 
         let interval: u32 = step_str
             .parse()
@@ -677,11 +1352,12 @@ fn try_parse_interval(
         };
 
         if (dom_field == "*" || dom_field == "?") && (dow_field == "*" || dow_field == "?") {
-            // Use :59 only for full day (00:00 to 23:59), otherwise use :00
+            // Use :59 only for full day (00:00 to 23:59), otherwise end on
+            // the last firing
             let end_minute = if from_hour == 0 && to_hour == 23 {
                 59
             } else {
-                0
+                minute
             };
 
             let mut schedule = Schedule::new(ScheduleExpr::IntervalRepeat {
@@ -689,7 +1365,7 @@ fn try_parse_interval(
                 unit: IntervalUnit::Hours,
                 from: TimeOfDay {
                     hour: from_hour,
-                    minute: 0,
+                    minute,
                 },
                 to: TimeOfDay {
                     hour: to_hour,
@@ -853,23 +1529,11 @@ fn parse_cron_dow(field: &str) -> Result<DayFilter, ScheduleError> {
         }
     }
 
-    // Check for special patterns
-    if days.len() == 5 {
-        let mut sorted = days.clone();
-        sorted.sort_by_key(|d| d.number());
-        if sorted == Weekday::all_weekdays() {
-            return Ok(DayFilter::Weekday);
-        }
-    }
-    if days.len() == 2 {
-        let mut sorted = days.clone();
-        sorted.sort_by_key(|d| d.number());
-        if sorted == vec![Weekday::Saturday, Weekday::Sunday] {
-            return Ok(DayFilter::Weekend);
-        }
-    }
-
-    Ok(DayFilter::Days(days))
+    // Cron has no weekend setting, so 0,6 reads as the default weekend
+    Ok(match DayFilter::Days(days).canonical() {
+        DayFilter::Days(days) if days == [Weekday::Saturday, Weekday::Sunday] => DayFilter::Weekend,
+        filter => filter,
+    })
 }
 
 /// Parse a DOW value (number 0-7 or name SUN-SAT), normalizing 7 to 0.
@@ -914,6 +1578,32 @@ fn cron_dow_to_weekday(n: u8) -> Result<Weekday, ScheduleError> {
     }
 }
 
+/// Parse the minute and hour fields into times. Either field may be a comma
+/// list; every hour pairs with every minute, so `0,30 9,17` gives four times.
+fn parse_cron_times(minute_field: &str, hour_field: &str) -> Result<Vec<TimeOfDay>, ScheduleError> {
+    let minutes = parse_value_list(minute_field, "minute", 0, 59)?;
+    let hours = parse_value_list(hour_field, "hour", 0, 23)?;
+    let mut times: Vec<TimeOfDay> = hours
+        .iter()
+        .flat_map(|&hour| {
+            minutes
+                .iter()
+                .map(move |&minute| TimeOfDay { hour, minute })
+        })
+        .collect();
+    times.sort();
+    times.dedup();
+    Ok(times)
+}
+
+/// Parse a comma list of numeric values, each validated like a single value.
+fn parse_value_list(field: &str, name: &str, min: u8, max: u8) -> Result<Vec<u8>, ScheduleError> {
+    field
+        .split(',')
+        .map(|value| parse_single_value(value, name, min, max))
+        .collect()
+}
+
 /// Parse a single numeric value with validation.
 fn parse_single_value(field: &str, name: &str, min: u8, max: u8) -> Result<u8, ScheduleError> {
     let value: u8 = field
@@ -968,6 +1658,153 @@ mod tests {
     use super::*;
     use crate::parser::parse;
 
+    #[test]
+    fn test_to_bitmasks() {
+        let masks = |input| to_bitmasks(&parse(input).unwrap());
+        assert_eq!(
+            masks("every 15 min from 00:00 to 23:59 during jan, jul"),
+            Some(CronBitmasks {
+                minutes: 1 | 1 << 15 | 1 << 30 | 1 << 45,
+                hours: (1 << 24) - 1,
+                dom: u32::MAX - 1,
+                months: 1 << 1 | 1 << 7,
+                dow: 0x7f,
+            })
+        );
+        let m = masks("every month on the 1st, 15th at 9:00").unwrap();
+        assert_eq!(m.dom, 1 << 1 | 1 << 15);
+        assert_eq!(m.dow, 0x7f);
+        assert_eq!(masks("every weekend at 10:00").unwrap().dow, 1 | 1 << 6);
+        assert_eq!(
+            masks("every 2 hours from 00:00 to 23:59").unwrap().hours,
+            0x555555
+        );
+        assert_eq!(
+            masks("every month on the nearest weekday to 15th at 9:00"),
+            None
+        );
+        assert_eq!(masks("every day at 9:00 except dec 25"), None);
+    }
+
+    #[test]
+    fn test_to_cron_lossy_exact_when_expressible() {
+        let s = parse("every weekday at 9:00").unwrap();
+        assert_eq!(to_cron_lossy(&s), ("0 9 * * 1-5".to_string(), vec![]));
+    }
+
+    #[test]
+    fn test_to_cron_lossy_approximations() {
+        let cases = [
+            ("every 45 min from 09:00 to 17:00", "*/45 9-17 * * *", 2),
+            (
+                "every 15 min from 09:00 to 17:59 on weekdays",
+                "*/15 9-17 * * 1-5",
+                0,
+            ),
+            (
+                "every 2 hours from 09:00 to 17:00",
+                "0 9,11,13,15,17 * * *",
+                0,
+            ),
+            ("every 3 hours from 22:00 to 04:00", "0 22,1,4 * * *", 0),
+            ("every day at 9:00, 17:00 except dec 25", "0 9,17 * * *", 1),
+            ("every day at 9:00, 17:30", "0 9 * * *", 1),
+            ("every 2 days at 9:00", "0 9 */2 * *", 1),
+            ("every 2 days on weekdays at 9:00", "0 9 */2 * 1-5", 2),
+            ("every 2 weeks on mon, fri at 9:00", "0 9 * * 1,5", 1),
+            ("every month on the last day at 17:00", "0 17 L * *", 1),
+            (
+                "every month on the last 3 days at 02:00",
+                "0 2 29-31 * *",
+                1,
+            ),
+            (
+                "every month on the second tuesday at 10:00",
+                "0 10 * * 2#2",
+                1,
+            ),
+            ("every 3 months on the 1st at 9:00", "0 9 1 */3 *", 1),
+            (
+                "every year on the last friday of nov at 12:00",
+                "0 12 * 11 5L",
+                1,
+            ),
+            ("on 2026-03-15 at 09:00", "0 9 15 3 *", 1),
+            ("on jan 1, jul 1 at 09:00", "0 9 1 1,7 *", 1),
+            ("on feb 14, dec 25 at 09:00", "0 9 14,25 2,12 *", 2),
+            ("on friday at 17:00", "0 17 * * 5", 1),
+            ("every 3 weekdays at 09:00", "0 9 * * 1-5", 1),
+            (
+                "every day at 9:00 until 2026-12-31 during jan, jul",
+                "0 9 * 1,7 *",
+                1,
+            ),
+        ];
+        for (input, cron, warning_count) in cases {
+            let (got, warnings) = to_cron_lossy(&parse(input).unwrap());
+            assert_eq!(got, cron, "{input}");
+            assert_eq!(warnings.len(), warning_count, "{input}: {warnings:?}");
+        }
+    }
+
+    #[test]
+    fn test_cron_blockers_agree_with_to_cron() {
+        let inputs = [
+            "every day at 9:00",
+            "every 15 min from 00:00 to 23:59",
+            "every 7 min from 00:00 to 23:59",
+            "every 2 weeks on mon at 9:00",
+            "every month on the 1st at 9:00",
+            "every month on the last day at 9:00",
+            "every month on the next nearest weekday to 15th at 9:00",
+            "every 2 days at 9:00, 17:00 until 2026-12-31",
+            "every day at 9:00 during jun 15 to aug 31",
+            "on feb 14 at 9:00",
+            "every year in iso weeks 1 on mon at 9:00",
+        ];
+        for input in inputs {
+            let s = parse(input).unwrap();
+            let blockers = cron_blockers(&s);
+            match to_cron(&s) {
+                Ok(_) => assert!(blockers.is_empty(), "{input}: {blockers:?}"),
+                Err(e) if e.to_string().contains("breaks at hour boundaries") => {
+                    assert_eq!(blockers, ["minute interval breaks at hour boundaries"]);
+                }
+                Err(e) => assert!(
+                    blockers
+                        .first()
+                        .is_some_and(|b| e.to_string().contains(&format!("({b})"))),
+                    "{input}: {e} vs {blockers:?}"
+                ),
+            }
+        }
+        let s = parse("every 2 days at 9:00, 17:30 until 2026-12-31").unwrap();
+        assert_eq!(
+            cron_blockers(&s),
+            [
+                "until clauses not supported",
+                "multi-day intervals not supported",
+                "multiple times not supported",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_to_cron_lossy_output_parses() {
+        let inputs = [
+            "every 45 min from 09:00 to 17:00",
+            "every 2 days at 9:00",
+            "every month on the last weekday at 15:00",
+            "every month on the first monday at 10:00",
+            "every 3 months on the 1st at 9:00",
+            "every year on dec 25 at 00:00",
+        ];
+        for input in inputs {
+            let (cron, _) = to_cron_lossy(&parse(input).unwrap());
+            assert!(from_cron(&cron).is_ok(), "{input} -> {cron}");
+        }
+    }
+
     #[test]
     fn test_to_cron_every_day() {
         let s = parse("every day at 9:00").unwrap();
@@ -1004,6 +1841,15 @@ mod tests {
         assert_eq!(to_cron(&s).unwrap(), "0 */2 * * *");
     }
 
+    #[test]
+    fn test_to_cron_interval_hours_past_the_hour() {
+        let s = parse("every hour at :15").unwrap();
+        assert_eq!(to_cron(&s).unwrap(), "15 * * * *");
+        let s = parse("every 3 hours at :05").unwrap();
+        assert_eq!(to_cron(&s).unwrap(), "5 */3 * * *");
+        assert!(to_cron(&parse("every 30 min from 00:15 to 23:59").unwrap()).is_err());
+    }
+
     #[test]
     fn test_to_cron_month_single_day() {
         let s = parse("every month on the 1st at 9:00").unwrap();
@@ -1016,6 +1862,12 @@ mod tests {
         assert_eq!(to_cron(&s).unwrap(), "0 9 1,15 * *");
     }
 
+    #[test]
+    fn test_to_cron_not_expressible_month_day_filter() {
+        let s = parse("every month on the 1st, 15th at 9:00 on weekdays").unwrap();
+        assert!(to_cron(&s).is_err());
+    }
+
     #[test]
     fn test_to_cron_not_expressible_45min() {
         let s = parse("every 45 min from 09:00 to 17:00").unwrap();
@@ -1046,6 +1898,18 @@ mod tests {
         assert!(to_cron(&s).is_err());
     }
 
+    #[test]
+    fn test_to_cron_not_expressible_interval_days() {
+        let s = parse("every 2 days from 00:00 to 23:59").unwrap();
+        assert!(to_cron(&s).is_err());
+    }
+
+    #[test]
+    fn test_to_cron_not_expressible_overnight_interval() {
+        let s = parse("every 2 hours from 22:00 to 06:00").unwrap();
+        assert!(to_cron(&s).is_err());
+    }
+
     #[test]
     fn test_from_cron_every_day() {
         let s = from_cron("0 9 * * *").unwrap();
@@ -1070,6 +1934,15 @@ mod tests {
         assert_eq!(s.to_string(), "every 30 min from 00:00 to 23:59");
     }
 
+    #[test]
+    fn test_from_cron_minute_past_every_hour() {
+        let s = from_cron("15 * * * *").unwrap();
+        assert_eq!(s, parse("every hour at :15").unwrap());
+        let s = from_cron("30 8-18/2 * * *").unwrap();
+        assert_eq!(s.to_string(), "every 2 hours from 08:30 to 18:30");
+        assert!(from_cron("60 * * * *").is_err());
+    }
+
     #[test]
     fn test_from_cron_dom_range() {
         let s = from_cron("0 9 1-5 * *").unwrap();
@@ -1121,6 +1994,66 @@ mod tests {
         assert_eq!(s.to_string(), "every monday, wednesday, friday at 09:00");
     }
 
+    #[test]
+    fn test_to_cron_during_month_field() {
+        let cases = [
+            ("every day at 9:00 during jan, jul", "0 9 * 1,7 *"),
+            (
+                "every weekday at 9:00 during jun to aug, dec",
+                "0 9 * 6-8,12 1-5",
+            ),
+            (
+                "every month on the 1st at 9:00 during nov to feb",
+                "0 9 1 1,2,11,12 *",
+            ),
+            (
+                "every 15 min from 00:00 to 23:59 during mar",
+                "*/15 * * 3 *",
+            ),
+        ];
+        for (input, cron) in cases {
+            let s = parse(input).unwrap();
+            assert_eq!(to_cron(&s).unwrap(), cron, "{input}");
+            assert_eq!(to_cron(&from_cron(cron).unwrap()).unwrap(), cron, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_to_cron_during_dates() {
+        let s = parse("every day at 9:00 during jun 15 to aug 31").unwrap();
+        assert!(to_cron(&s).is_err());
+        let (cron, warnings) = to_cron_lossy(&s);
+        assert_eq!(cron, "0 9 * 6-8 *");
+        assert_eq!(
+            warnings,
+            vec!["during widened to whole months (cron months have no day bounds)"]
+        );
+        // Whole-month date ranges are plain month ranges
+        let s = parse("every day at 9:00 during jun 1 to aug 31").unwrap();
+        assert_eq!(to_cron(&s).unwrap(), "0 9 * 6-8 *");
+    }
+
+    #[test]
+    fn test_to_cron_named() {
+        let cases = [
+            (
+                "every weekday at 9:00 during jan, jul",
+                "0 9 * JAN,JUL MON-FRI",
+            ),
+            ("every weekend at 10:00", "0 10 * * SUN,SAT"),
+            (
+                "every mon, wed, fri at 9:00 during jun to aug",
+                "0 9 * JUN-AUG MON,WED,FRI",
+            ),
+            ("every month on the 1st at 9:00", "0 9 1 * *"),
+        ];
+        for (input, cron) in cases {
+            let s = parse(input).unwrap();
+            assert_eq!(to_cron_named(&s).unwrap(), cron, "{input}");
+            assert_eq!(from_cron(cron).unwrap().expr, s.expr, "{input}");
+        }
+    }
+
     #[test]
     fn test_from_cron_named_month() {
         let s = from_cron("0 9 1 JAN,JUL *").unwrap();
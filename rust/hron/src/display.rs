@@ -1,11 +1,14 @@
-use std::fmt;
+use core::fmt;
 
 use crate::ast::*;
 
+/// The default form is canonical and round-trips through the parser. The
+/// alternate form (`{:#}`) prints short weekday names (`every mon, fri at
+/// 09:00`), which also parse back to the same schedule.
 impl fmt::Display for Schedule {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // Write the expression
-        write!(f, "{}", self.expr)?;
+        // Write the expression, passing the formatter through to keep `{:#}`
+        fmt::Display::fmt(&self.expr, f)?;
 
         // Write trailing clauses in order: except, until, starting, during, timezone
         if !self.except.is_empty() {
@@ -17,6 +20,14 @@ impl fmt::Display for Schedule {
                 match exc {
                     Exception::Named { month, day } => write!(f, "{} {}", month.as_str(), day)?,
                     Exception::Iso(d) => write!(f, "{d}")?,
+                    Exception::Weekday(day) => f.write_str(day_name(f, *day))?,
+                    Exception::IsoRange(start, end) => write!(f, "{start} to {end}")?,
+                    Exception::NamedRange {
+                        start_month,
+                        start_day,
+                        end_month,
+                        end_day,
+                    } => write!(f, "{start_month} {start_day} to {end_month} {end_day}")?,
                 }
             }
         }
@@ -25,21 +36,23 @@ impl fmt::Display for Schedule {
             match until {
                 UntilSpec::Iso(d) => write!(f, " until {d}")?,
                 UntilSpec::Named { month, day } => write!(f, " until {} {}", month.as_str(), day)?,
+                UntilSpec::Count(1) => write!(f, " until 1 time")?,
+                UntilSpec::Count(n) => write!(f, " until {n} times")?,
             }
         }
 
         if let Some(anchor) = &self.anchor {
             write!(f, " starting {anchor}")?;
+        } else if let Some(relative) = self.anchor_relative {
+            write!(f, " starting {relative}")?;
+        }
+        if let Some(time) = &self.anchor_time {
+            write!(f, " at {time}")?;
         }
 
         if !self.during.is_empty() {
             write!(f, " during ")?;
-            for (i, month) in self.during.iter().enumerate() {
-                if i > 0 {
-                    write!(f, ", ")?;
-                }
-                write!(f, "{}", month.as_str())?;
-            }
+            write_during_specs(f, &self.during)?;
         }
 
         if let Some(tz) = &self.timezone {
@@ -63,7 +76,8 @@ impl fmt::Display for ScheduleExpr {
                 write!(f, "every {interval} {}", unit_display(*interval, *unit))?;
                 write!(f, " from {from} to {to}")?;
                 if let Some(df) = day_filter {
-                    write!(f, " on {df}")?;
+                    write!(f, " on ")?;
+                    fmt::Display::fmt(df, f)?;
                 }
             }
             ScheduleExpr::DayRepeat {
@@ -72,12 +86,24 @@ impl fmt::Display for ScheduleExpr {
                 times,
             } => {
                 if *interval > 1 {
-                    write!(f, "every {interval} days at ")?;
+                    write!(f, "every {interval} days ")?;
+                    if *days != DayFilter::Every {
+                        write!(f, "on ")?;
+                        fmt::Display::fmt(days, f)?;
+                        write!(f, " ")?;
+                    }
+                    write!(f, "at ")?;
                 } else {
-                    write!(f, "every {days} at ")?;
+                    write!(f, "every ")?;
+                    fmt::Display::fmt(days, f)?;
+                    write!(f, " at ")?;
                 }
                 write_time_list(f, times)?;
             }
+            ScheduleExpr::WeekdayRepeat { interval, times } => {
+                write!(f, "every {interval} weekdays at ")?;
+                write_time_list(f, times)?;
+            }
             ScheduleExpr::WeekRepeat {
                 interval,
                 days,
@@ -96,6 +122,7 @@ impl fmt::Display for ScheduleExpr {
                 interval,
                 target,
                 times,
+                day_filter,
             } => {
                 if *interval > 1 {
                     write!(f, "every {interval} months on the ")?;
@@ -105,7 +132,11 @@ impl fmt::Display for ScheduleExpr {
                 match target {
                     MonthTarget::Days(specs) => write_ordinal_day_specs(f, specs)?,
                     MonthTarget::LastDay => write!(f, "last day")?,
+                    MonthTarget::LastNDays(n) => write!(f, "last {n} days")?,
                     MonthTarget::LastWeekday => write!(f, "last weekday")?,
+                    MonthTarget::BusinessDay(n) => {
+                        write!(f, "{n}{} business day", ordinal_suffix(*n))?
+                    }
                     MonthTarget::NearestWeekday { day, direction } => {
                         if let Some(dir) = direction {
                             match dir {
@@ -116,21 +147,42 @@ impl fmt::Display for ScheduleExpr {
                         write!(f, "nearest weekday to {}{}", day, ordinal_suffix(*day))?;
                     }
                     MonthTarget::OrdinalWeekday { ordinal, weekday } => {
-                        write!(f, "{} {}", ordinal.as_str(), weekday.as_str())?;
+                        let weekday = day_name(f, *weekday);
+                        write!(f, "{} {weekday}", ordinal.as_str())?;
+                    }
+                    MonthTarget::OrdinalWeekdays { ordinals, weekday } => {
+                        let weekday = day_name(f, *weekday);
+                        for (i, ordinal) in ordinals.iter().enumerate() {
+                            if i + 1 == ordinals.len() {
+                                write!(f, " and ")?;
+                            } else if i > 0 {
+                                write!(f, ", ")?;
+                            }
+                            f.write_str(ordinal.as_str())?;
+                        }
+                        write!(f, " {weekday}")?;
                     }
                 }
                 write!(f, " at ")?;
                 write_time_list(f, times)?;
+                if let Some(df) = day_filter {
+                    write!(f, " on ")?;
+                    fmt::Display::fmt(df, f)?;
+                }
             }
             ScheduleExpr::SingleDate { date, times } => {
                 write!(f, "on ")?;
-                match date {
-                    DateSpec::Named { month, day } => {
-                        write!(f, "{} {day}", month.as_str())?;
-                    }
-                    DateSpec::Iso(d) => {
-                        write!(f, "{d}")?;
+                write_date_spec(f, date)?;
+                write!(f, " at ")?;
+                write_time_list(f, times)?;
+            }
+            ScheduleExpr::MultiDate { dates, times } => {
+                write!(f, "on ")?;
+                for (i, date) in dates.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
                     }
+                    write_date_spec(f, date)?;
                 }
                 write!(f, " at ")?;
                 write_time_list(f, times)?;
@@ -154,11 +206,11 @@ impl fmt::Display for ScheduleExpr {
                         weekday,
                         month,
                     } => {
+                        let weekday = day_name(f, *weekday);
                         write!(
                             f,
-                            "the {} {} of {}",
+                            "the {} {weekday} of {}",
                             ordinal.as_str(),
-                            weekday.as_str(),
                             month.as_str()
                         )?;
                     }
@@ -178,6 +230,19 @@ impl fmt::Display for ScheduleExpr {
                 write!(f, " at ")?;
                 write_time_list(f, times)?;
             }
+            ScheduleExpr::IsoWeekRepeat { weeks, days, times } => {
+                write!(f, "every year in iso weeks ")?;
+                for (i, week) in weeks.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{week}")?;
+                }
+                write!(f, " on ")?;
+                write_day_list(f, days)?;
+                write!(f, " at ")?;
+                write_time_list(f, times)?;
+            }
         }
         Ok(())
     }
@@ -189,13 +254,53 @@ impl fmt::Display for TimeOfDay {
     }
 }
 
-impl fmt::Display for DayFilter {
+impl fmt::Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(day_name(f, *self))
+    }
+}
+
+impl fmt::Display for MonthName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Display for CivilDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}",
+            self.year(),
+            self.month(),
+            self.day()
+        )
+    }
+}
+
+impl fmt::Display for RelativeAnchor {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            RelativeAnchor::Today => f.write_str("today"),
+            RelativeAnchor::Now => f.write_str("now"),
+            RelativeAnchor::Named { month, day } => write!(f, "{month} {day}"),
+        }
+    }
+}
+
+impl fmt::Display for DateSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_date_spec(f, self)
+    }
+}
+
+impl fmt::Display for DayFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.canonical() {
             DayFilter::Every => write!(f, "day"),
             DayFilter::Weekday => write!(f, "weekday"),
             DayFilter::Weekend => write!(f, "weekend"),
-            DayFilter::Days(days) => write_day_list(f, days),
+            DayFilter::Days(days) => write_day_list(f, &days),
         }
     }
 }
@@ -215,7 +320,7 @@ fn write_day_list(f: &mut fmt::Formatter<'_>, days: &[Weekday]) -> fmt::Result {
         if i > 0 {
             write!(f, ", ")?;
         }
-        write!(f, "{}", day.as_str())?;
+        fmt::Display::fmt(day, f)?;
     }
     Ok(())
 }
@@ -242,6 +347,41 @@ fn write_ordinal_day_specs(f: &mut fmt::Formatter<'_>, specs: &[DayOfMonthSpec])
     Ok(())
 }
 
+fn write_date_spec(f: &mut fmt::Formatter<'_>, date: &DateSpec) -> fmt::Result {
+    match date {
+        DateSpec::Named { month, day } => write!(f, "{} {day}", month.as_str()),
+        DateSpec::Iso(d) => write!(f, "{d}"),
+        DateSpec::NextWeekday(day) => f.write_str(day_name(f, *day)),
+    }
+}
+
+fn write_during_specs(f: &mut fmt::Formatter<'_>, specs: &[DuringSpec]) -> fmt::Result {
+    for (i, spec) in specs.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        // Date ranges covering whole months print as months
+        match spec.collapsed() {
+            DuringSpec::Single(m) => write!(f, "{m}")?,
+            DuringSpec::Range(start, end) => write!(f, "{start} to {end}")?,
+            DuringSpec::Dates(start, end) if start == end => write!(f, "{} {}", start.0, start.1)?,
+            DuringSpec::Dates(start, end) => {
+                write!(f, "{} {} to {} {}", start.0, start.1, end.0, end.1)?
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Full weekday name, or the short form (`mon`) under the alternate flag.
+fn day_name(f: &fmt::Formatter<'_>, day: Weekday) -> &'static str {
+    if f.alternate() {
+        day.short()
+    } else {
+        day.as_str()
+    }
+}
+
 fn ordinal_suffix(n: u8) -> &'static str {
     match n % 100 {
         11..=13 => "th",
@@ -270,12 +410,20 @@ fn unit_display(interval: u32, unit: IntervalUnit) -> &'static str {
                 "hours"
             }
         }
+        IntervalUnit::Days => {
+            if interval == 1 {
+                "day"
+            } else {
+                "days"
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::parser::parse;
+    use crate::prelude::*;
 
     #[test]
     fn test_roundtrip_every_day() {
@@ -301,6 +449,33 @@ mod tests {
         assert_eq!(s.to_string(), "every month on the 1st, 15th at 09:00");
     }
 
+    #[test]
+    fn test_roundtrip_month_last_n_days() {
+        let s = parse("every month on the last 3 days at 2:00").unwrap();
+        assert_eq!(s.to_string(), "every month on the last 3 days at 02:00");
+    }
+
+    #[test]
+    fn test_roundtrip_month_day_filter() {
+        let s = parse("every month on the 1st, 15th at 09:00 on weekday").unwrap();
+        assert_eq!(
+            s.to_string(),
+            "every month on the 1st, 15th at 09:00 on weekday"
+        );
+    }
+
+    #[test]
+    fn test_frequency_shorthand_displays_long_form() {
+        assert_eq!(
+            parse("daily at 9:00").unwrap().to_string(),
+            "every day at 09:00"
+        );
+        assert_eq!(
+            parse("hourly").unwrap().to_string(),
+            "every 1 hour from 00:00 to 23:59"
+        );
+    }
+
     #[test]
     fn test_roundtrip_ordinal_weekday() {
         let s = parse("every month on the first monday at 10:00").unwrap();
@@ -319,6 +494,26 @@ mod tests {
         assert_eq!(s.to_string(), "on 2026-03-15 at 14:30");
     }
 
+    #[test]
+    fn test_roundtrip_on_multi() {
+        let s = parse("on feb 14, 2026-12-25 at 9:00").unwrap();
+        assert_eq!(s.to_string(), "on feb 14, 2026-12-25 at 09:00");
+        // Dates are sorted and de-duplicated, named dates first
+        let s = parse("on 2026-12-25, dec 25, 2026-02-14, feb 14, 2026-12-25 at 9:00").unwrap();
+        assert_eq!(
+            s.to_string(),
+            "on feb 14, dec 25, 2026-02-14, 2026-12-25 at 09:00"
+        );
+        assert_eq!(parse(&s.to_string()).unwrap(), s);
+        // A list that de-duplicates to one date is a single date
+        let s = parse("on feb 14, feb 14 at 9:00").unwrap();
+        assert!(matches!(
+            s.expr,
+            crate::ast::ScheduleExpr::SingleDate { .. }
+        ));
+        assert_eq!(s.to_string(), "on feb 14 at 09:00");
+    }
+
     #[test]
     fn test_roundtrip_timezone() {
         let s = parse("every weekday at 9:00 in America/Vancouver").unwrap();
@@ -331,12 +526,35 @@ mod tests {
         assert_eq!(s.to_string(), "every weekday at 09:00 except dec 25, jan 1");
     }
 
+    #[test]
+    fn test_roundtrip_except_weekday() {
+        let s = parse("every day at 9:00 except on weekends, dec 25").unwrap();
+        assert_eq!(
+            s.to_string(),
+            "every day at 09:00 except saturday, sunday, dec 25"
+        );
+        assert_eq!(parse(&s.to_string()).unwrap(), s);
+    }
+
     #[test]
     fn test_roundtrip_until_iso() {
         let s = parse("every day at 09:00 until 2026-12-31").unwrap();
         assert_eq!(s.to_string(), "every day at 09:00 until 2026-12-31");
     }
 
+    #[test]
+    fn test_roundtrip_until_count() {
+        let s = parse("every day at 09:00 until 3 occurrences").unwrap();
+        assert_eq!(s.to_string(), "every day at 09:00 until 3 times");
+    }
+
+    #[test]
+    fn test_roundtrip_until_one_time() {
+        let s = parse("every day at 09:00 until 1 occurrence").unwrap();
+        assert_eq!(s.to_string(), "every day at 09:00 until 1 time");
+        assert_eq!(parse(&s.to_string()).unwrap(), s);
+    }
+
     #[test]
     fn test_roundtrip_starting() {
         let s = parse("every 2 weeks on monday at 9:00 starting 2026-01-05").unwrap();
@@ -346,6 +564,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_roundtrip_starting_relative() {
+        for input in [
+            "every 2 days at 09:00 starting today",
+            "every 2 weeks on monday at 09:00 starting now in UTC",
+            "every 2 weeks on monday at 09:00 starting jan 5 in UTC",
+            "every day at 09:00 starting today at 12:00",
+            "every day at 09:00 starting 2026-01-05 at 12:30",
+        ] {
+            let s = parse(input).unwrap();
+            assert_eq!(s.to_string(), input);
+            assert_eq!(parse(&s.to_string()).unwrap(), s);
+        }
+    }
+
     #[test]
     fn test_roundtrip_year_date() {
         let s = parse("every year on dec 25 at 00:00").unwrap();
@@ -400,6 +633,44 @@ mod tests {
         assert_eq!(s.to_string(), "every weekday at 09:00 during jan, jun");
     }
 
+    #[test]
+    fn test_roundtrip_during_range() {
+        let s = parse("every weekday at 9:00 during jun to aug").unwrap();
+        assert_eq!(s.to_string(), "every weekday at 09:00 during jun to aug");
+    }
+
+    #[test]
+    fn test_roundtrip_during_range_wrapping() {
+        let s = parse("every day at 9:00 during nov to feb, jul").unwrap();
+        assert_eq!(s.to_string(), "every day at 09:00 during jul, nov to feb");
+        assert_eq!(parse(&s.to_string()).unwrap(), s);
+    }
+
+    #[test]
+    fn test_roundtrip_during_dates() {
+        let s = parse("every day at 9:00 during jun 15 to aug 31, dec 24").unwrap();
+        assert_eq!(
+            s.to_string(),
+            "every day at 09:00 during jun 15 to aug 31, dec 24"
+        );
+        let s = parse("every day at 9:00 during dec 24, jun 15 to aug 31").unwrap();
+        assert_eq!(
+            s.to_string(),
+            "every day at 09:00 during jun 15 to aug 31, dec 24"
+        );
+        assert_eq!(parse(&s.to_string()).unwrap(), s);
+        let s = parse("every day at 9:00 during jun 1 to aug 31, feb 1 to feb 29").unwrap();
+        assert_eq!(s.to_string(), "every day at 09:00 during feb, jun to aug");
+    }
+
+    #[test]
+    fn test_display_during_after_with_during() {
+        let s = parse("every day at 9:00 during jun to aug")
+            .unwrap()
+            .with_during(vec![crate::ast::MonthName::January]);
+        assert_eq!(s.to_string(), "every day at 09:00 during jan");
+    }
+
     #[test]
     fn test_roundtrip_day_range() {
         let s = parse("every month on the 1st to 15th at 9:00").unwrap();
@@ -426,4 +697,77 @@ mod tests {
             "every weekday at 09:00, 17:00 except dec 25 until 2027-12-31 during jan, mar in UTC"
         );
     }
+
+    #[test]
+    fn test_roundtrip_interval_days() {
+        let s = parse("every 3 days between 6am and 10pm on weekdays").unwrap();
+        assert_eq!(s.to_string(), "every 3 days from 06:00 to 22:00 on weekday");
+        assert_eq!(parse(&s.to_string()).unwrap(), s);
+        let s = parse("every 1 day from 06:00 to 22:00").unwrap();
+        assert_eq!(s.to_string(), "every 1 day from 06:00 to 22:00");
+    }
+
+    #[test]
+    fn test_roundtrip_every_month_and_year_target() {
+        let inputs = [
+            "every month on the 1st to 5th, 15th at 09:00",
+            "every month on the last day at 17:00",
+            "every month on the last weekday at 15:00",
+            "every month on the nearest weekday to 15th at 09:00",
+            "every month on the next nearest weekday to 31st at 09:00",
+            "every month on the previous nearest weekday to 1st at 09:00",
+            "every 2 months on the next nearest weekday to 15th at 09:00",
+            "every month on the fifth friday at 16:00",
+            "every month on the last sunday at 08:00",
+            "every month on the 1st, 15th at 09:00 on weekend",
+            "every month on the nearest weekday to 15th at 09:00 on monday, friday",
+            "every year on feb 29 at 09:00",
+            "every year on the last friday of dec at 17:00",
+            "every year on the 15th of mar at 09:00",
+            "every 2 years on the last weekday of jun at 15:00",
+        ];
+        for input in inputs {
+            let s = parse(input).unwrap();
+            assert_eq!(s.to_string(), input);
+            assert_eq!(parse(&s.to_string()).unwrap(), s, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_alternate_short_day_names() {
+        let cases = [
+            (
+                "every monday, wednesday, friday at 09:00",
+                "every mon, wed, fri at 09:00",
+            ),
+            (
+                "every 2 weeks on tuesday at 09:00",
+                "every 2 weeks on tue at 09:00",
+            ),
+            (
+                "every 30 min from 09:00 to 17:00 on saturday",
+                "every 30 min from 09:00 to 17:00 on sat",
+            ),
+            (
+                "every 2 days on thursday at 09:00 except sunday",
+                "every 2 days on thu at 09:00 except sun",
+            ),
+            (
+                "every month on the last sunday at 08:00",
+                "every month on the last sun at 08:00",
+            ),
+            (
+                "every year on the first monday of sep at 09:00",
+                "every year on the first mon of sep at 09:00",
+            ),
+            ("on friday at 17:00", "on fri at 17:00"),
+            ("every weekday at 09:00", "every weekday at 09:00"),
+        ];
+        for (input, short) in cases {
+            let s = parse(input).unwrap();
+            assert_eq!(s.to_string(), input);
+            assert_eq!(format!("{s:#}"), short);
+            assert_eq!(parse(short).unwrap(), s, "{input}");
+        }
+    }
 }
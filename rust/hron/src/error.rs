@@ -1,4 +1,5 @@
-use std::fmt;
+use crate::prelude::*;
+use core::fmt;
 
 /// Byte range within the input string.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -11,6 +12,20 @@ impl Span {
     pub fn new(start: usize, end: usize) -> Self {
         Self { start, end }
     }
+
+    /// 1-based line and column of the span's start within `input`. Columns
+    /// count characters, not bytes.
+    pub fn line_col(&self, input: &str) -> (usize, usize) {
+        let mut start = self.start.min(input.len());
+        while !input.is_char_boundary(start) {
+            start -= 1;
+        }
+        let before = &input[..start];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line = before.matches('\n').count() + 1;
+        let column = before[line_start..].chars().count() + 1;
+        (line, column)
+    }
 }
 
 /// All errors produced by hron.
@@ -34,6 +49,12 @@ pub enum ScheduleError {
         message: String,
     },
 
+    /// A timezone name that the tz database could not resolve at evaluation
+    /// time. The name may become valid once the database is loaded or updated.
+    Timezone {
+        message: String,
+    },
+
     Cron {
         message: String,
     },
@@ -45,12 +66,13 @@ impl fmt::Display for ScheduleError {
             Self::Lex { message, .. } => write!(f, "{message}"),
             Self::Parse { message, .. } => write!(f, "{message}"),
             Self::Eval { message } => write!(f, "{message}"),
+            Self::Timezone { message } => write!(f, "{message}"),
             Self::Cron { message } => write!(f, "{message}"),
         }
     }
 }
 
-impl std::error::Error for ScheduleError {}
+impl core::error::Error for ScheduleError {}
 
 impl ScheduleError {
     pub fn lex(message: impl Into<String>, span: Span, input: impl Into<String>) -> Self {
@@ -81,12 +103,38 @@ impl ScheduleError {
         }
     }
 
+    pub fn timezone(message: impl Into<String>) -> Self {
+        Self::Timezone {
+            message: message.into(),
+        }
+    }
+
     pub fn cron(message: impl Into<String>) -> Self {
         Self::Cron {
             message: message.into(),
         }
     }
 
+    /// The byte range of the input the error points at. Only lex and parse
+    /// errors carry a position.
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            Self::Lex { span, .. } | Self::Parse { span, .. } => Some(*span),
+            Self::Eval { .. } | Self::Timezone { .. } | Self::Cron { .. } => None,
+        }
+    }
+
+    /// Whether retrying the same call later could succeed.
+    ///
+    /// Only timezone lookups qualify: the tz database may load or update
+    /// after the failure. Lex, parse and cron errors need the input fixed and
+    /// should be surfaced to the user; other eval errors (date arithmetic
+    /// overflow near year 9999, invalid `until`/`starting` dates) are
+    /// deterministic and will fail the same way every time.
+    pub fn is_recoverable(&self) -> bool {
+        matches!(self, Self::Timezone { .. })
+    }
+
     /// Format a rich error with underline and optional suggestion.
     pub fn display_rich(&self) -> String {
         match self {
@@ -101,8 +149,9 @@ impl ScheduleError {
                 input,
                 suggestion,
             } => format_span_error("error", message, span, input, suggestion.as_deref()),
-            Self::Eval { message } => format!("error: {message}"),
-            Self::Cron { message } => format!("error: {message}"),
+            Self::Eval { message } | Self::Timezone { message } | Self::Cron { message } => {
+                format!("error: {message}")
+            }
         }
     }
 }
@@ -115,9 +164,24 @@ fn format_span_error(
     suggestion: Option<&str>,
 ) -> String {
     let mut out = format!("{prefix}: {message}\n");
-    out.push_str(&format!("  {input}\n"));
-    let padding = " ".repeat(span.start + 2);
-    let underline = "^".repeat((span.end - span.start).max(1));
+    let (padding, underline) = if input.contains('\n') {
+        // Show only the offending line, underlined up to its end
+        let (line, column) = span.line_col(input);
+        let text = input.lines().nth(line - 1).unwrap_or("");
+        let width = span
+            .end
+            .saturating_sub(span.start)
+            .min(text.chars().count() + 1 - column);
+        out.push_str(&format!("  --> line {line}, column {column}\n"));
+        out.push_str(&format!("  {text}\n"));
+        (" ".repeat(column + 1), "^".repeat(width.max(1)))
+    } else {
+        out.push_str(&format!("  {input}\n"));
+        (
+            " ".repeat(span.start + 2),
+            "^".repeat((span.end - span.start).max(1)),
+        )
+    };
     out.push_str(&padding);
     out.push_str(&underline);
     if let Some(sug) = suggestion {
@@ -131,3 +195,52 @@ impl fmt::Display for Span {
         write!(f, "{}..{}", self.start, self.end)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_col() {
+        let input = "every day\n  at 25:00\n";
+        assert_eq!(Span::new(0, 5).line_col(input), (1, 1));
+        assert_eq!(Span::new(15, 20).line_col(input), (2, 6));
+        assert_eq!(Span::new(input.len(), input.len()).line_col(input), (3, 1));
+        // Columns count characters: "é" is two bytes
+        assert_eq!(Span::new(3, 4).line_col("é\nab"), (2, 1));
+        assert_eq!(Span::new(1, 2).line_col("é"), (1, 1));
+    }
+
+    #[test]
+    fn test_display_rich_multiline() {
+        let err = crate::Schedule::parse("every day\n  at 25:00").unwrap_err();
+        let rich = err.display_rich();
+        let lines: Vec<&str> = rich.lines().collect();
+        assert_eq!(lines[1], "  --> line 2, column 6");
+        assert_eq!(lines[2], "    at 25:00");
+        assert_eq!(lines[3].find('^'), Some(7));
+
+        // Single-line input keeps the original layout
+        let err = crate::Schedule::parse("every day at 25:00").unwrap_err();
+        assert!(!err.display_rich().contains("-->"));
+    }
+
+    #[test]
+    fn test_is_recoverable() {
+        let parse_err = crate::Schedule::parse("every day at 25:00").unwrap_err();
+        assert!(!parse_err.is_recoverable());
+        assert!(!ScheduleError::eval("overflow").is_recoverable());
+        assert!(!ScheduleError::cron("bad cron").is_recoverable());
+
+        // Hand-built ASTs skip the parser's timezone check
+        #[cfg(feature = "eval")]
+        {
+            let mut schedule = crate::Schedule::parse("every day at 09:00").unwrap();
+            schedule.timezone = Some("Mars/Olympus_Mons".into());
+            let now: jiff::Zoned = "2026-02-06T12:00:00+00:00[UTC]".parse().unwrap();
+            let err = schedule.next_from(&now).unwrap_err();
+            assert!(matches!(err, ScheduleError::Timezone { .. }));
+            assert!(err.is_recoverable());
+        }
+    }
+}
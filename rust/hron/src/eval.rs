@@ -1,7 +1,7 @@
 use std::sync::LazyLock;
 
-use jiff::civil::{Date, Time};
-use jiff::tz::TimeZone;
+use jiff::civil::{Date, DateTime, Time};
+use jiff::tz::{AmbiguousOffset, TimeZone};
 use jiff::Zoned;
 
 use crate::ast::*;
@@ -68,10 +68,14 @@ static EPOCH_MONDAY: LazyLock<Date> = LazyLock::new(|| Date::new(1970, 1, 5).unw
 static EPOCH_DATE: LazyLock<Date> = LazyLock::new(|| Date::new(1970, 1, 1).unwrap());
 
 /// Resolve the timezone for a schedule, falling back to UTC for deterministic behavior.
-fn resolve_tz(tz: &Option<String>) -> Result<TimeZone, ScheduleError> {
-    match tz {
+///
+/// Floating schedules without an explicit `in` clause use the timezone of
+/// `reference` (the caller's `now`/`datetime`) instead of UTC.
+fn resolve_tz(schedule: &Schedule, reference: &Zoned) -> Result<TimeZone, ScheduleError> {
+    match &schedule.timezone {
         Some(name) => TimeZone::get(name)
-            .map_err(|e| ScheduleError::eval(format!("invalid timezone '{name}': {e}"))),
+            .map_err(|e| ScheduleError::timezone(format!("invalid timezone '{name}': {e}"))),
+        None if schedule.floating => Ok(reference.time_zone().clone()),
         None => Ok(TimeZone::UTC),
     }
 }
@@ -81,6 +85,20 @@ fn to_time(tod: &TimeOfDay) -> Time {
     Time::new(tod.hour as i8, tod.minute as i8, 0, 0).unwrap()
 }
 
+/// Wall-clock hour and minute of a time, dropping seconds.
+fn time_of_day(time: Time) -> TimeOfDay {
+    TimeOfDay {
+        hour: time.hour() as u8,
+        minute: time.minute() as u8,
+    }
+}
+
+/// Time of day `minutes` (at least 0) past a window's start-day midnight,
+/// wrapping into the next day.
+fn slot_time(minutes: i64) -> Time {
+    to_time(&TimeOfDay::from_minutes((minutes % 1440) as u16).unwrap())
+}
+
 /// Set the time on a date in a timezone, returning a Zoned datetime.
 fn at_time_on_date(date: Date, time: Time, tz: &TimeZone) -> Result<Zoned, ScheduleError> {
     let dt = date.to_datetime(time);
@@ -88,22 +106,10 @@ fn at_time_on_date(date: Date, time: Time, tz: &TimeZone) -> Result<Zoned, Sched
         .map_err(|e| ScheduleError::eval(format!("cannot create zoned datetime: {e}")))
 }
 
-/// Check if a date's weekday matches the day filter.
-fn matches_day_filter(date: Date, filter: &DayFilter) -> bool {
-    let wd = Weekday::from_jiff(date.weekday());
-    match filter {
-        DayFilter::Every => true,
-        DayFilter::Weekday => matches!(
-            wd,
-            Weekday::Monday
-                | Weekday::Tuesday
-                | Weekday::Wednesday
-                | Weekday::Thursday
-                | Weekday::Friday
-        ),
-        DayFilter::Weekend => matches!(wd, Weekday::Saturday | Weekday::Sunday),
-        DayFilter::Days(days) => days.contains(&wd),
-    }
+/// Check if a date's weekday matches the day filter, with `weekend` covering
+/// the schedule's configured days.
+fn matches_day_filter(date: Date, filter: &DayFilter, weekend: WeekendDays) -> bool {
+    filter.contains(Weekday::from_jiff(date.weekday()), weekend)
 }
 
 /// Get the last day of a month.
@@ -128,6 +134,21 @@ fn last_weekday_of_month(year: i16, month: i8) -> Date {
     }
 }
 
+/// Get the nth business day (Mon-Fri, 1-indexed) of a month, not counting
+/// days `excepted` removes. Returns None if the month has fewer.
+fn nth_business_day(year: i16, month: i8, n: u8, excepted: &dyn Fn(Date) -> bool) -> Option<Date> {
+    let last = last_day_of_month(year, month);
+    (1..=last.day())
+        .map(|d| Date::new(year, month, d).unwrap())
+        .filter(|d| {
+            let wd = d.weekday();
+            wd != jiff::civil::Weekday::Saturday
+                && wd != jiff::civil::Weekday::Sunday
+                && !excepted(*d)
+        })
+        .nth(n as usize - 1)
+}
+
 /// Get the nth weekday of a month (1-indexed). Returns None if it doesn't exist.
 fn nth_weekday_of_month(year: i16, month: i8, weekday: Weekday, n: u8) -> Option<Date> {
     let target_wd = weekday.to_jiff();
@@ -243,6 +264,20 @@ fn weeks_between(a: Date, b: Date) -> i64 {
     span.get_days() as i64 / 7
 }
 
+/// Days from the start of the week to `weekday` (0-6).
+fn days_into_week(weekday: jiff::civil::Weekday, week_start: WeekStart) -> i64 {
+    match week_start {
+        WeekStart::Monday => weekday.to_monday_zero_offset() as i64,
+        WeekStart::Sunday => weekday.to_sunday_zero_offset() as i64,
+    }
+}
+
+/// First day of the week holding `date`.
+fn week_start_of(date: Date, week_start: WeekStart) -> Result<Date, ScheduleError> {
+    date.checked_add(jiff::Span::new().days(-days_into_week(date.weekday(), week_start)))
+        .map_err(|e| ScheduleError::eval(format!("{e}")))
+}
+
 /// Count days between two dates (signed).
 fn days_between(a: Date, b: Date) -> i64 {
     a.until(b).unwrap().get_days() as i64
@@ -257,12 +292,18 @@ fn months_between_ym(a: Date, b: Date) -> i64 {
 struct ParsedExceptions {
     named: Vec<(u8, u8)>, // (month_number, day)
     iso_dates: Vec<Date>,
+    weekdays: Vec<Weekday>,
+    iso_ranges: Vec<(Date, Date)>,
+    named_ranges: Vec<((u8, u8), (u8, u8))>, // inclusive (month_number, day) bounds
 }
 
 impl ParsedExceptions {
     fn from_exceptions(exceptions: &[Exception]) -> Self {
         let mut named = Vec::new();
         let mut iso_dates = Vec::new();
+        let mut weekdays = Vec::new();
+        let mut iso_ranges = Vec::new();
+        let mut named_ranges = Vec::new();
         for exc in exceptions {
             match exc {
                 Exception::Named { month, day } => {
@@ -273,9 +314,30 @@ impl ParsedExceptions {
                         iso_dates.push(d);
                     }
                 }
+                Exception::Weekday(day) => weekdays.push(*day),
+                Exception::IsoRange(start, end) => {
+                    if let (Ok(start), Ok(end)) = (start.parse::<Date>(), end.parse::<Date>()) {
+                        iso_ranges.push((start, end));
+                    }
+                }
+                Exception::NamedRange {
+                    start_month,
+                    start_day,
+                    end_month,
+                    end_day,
+                } => named_ranges.push((
+                    (start_month.number(), *start_day),
+                    (end_month.number(), *end_day),
+                )),
             }
         }
-        ParsedExceptions { named, iso_dates }
+        ParsedExceptions {
+            named,
+            iso_dates,
+            weekdays,
+            iso_ranges,
+            named_ranges,
+        }
     }
 
     fn is_excepted(&self, date: Date) -> bool {
@@ -289,59 +351,355 @@ impl ParsedExceptions {
                 return true;
             }
         }
-        false
+        if !self.ranges_containing(date).is_empty() {
+            return true;
+        }
+        self.weekdays.contains(&Weekday::from_jiff(date.weekday()))
+    }
+
+    /// Concrete bounds of every range exception covering `date`.
+    fn ranges_containing(&self, date: Date) -> Vec<(Date, Date)> {
+        let mut found: Vec<(Date, Date)> = self
+            .iso_ranges
+            .iter()
+            .copied()
+            .filter(|&(start, end)| start <= date && date <= end)
+            .collect();
+        let key = (date.month() as u8, date.day() as u8);
+        let year = date.year();
+        for &(start, end) in &self.named_ranges {
+            let bounds = if start <= end {
+                (start <= key && key <= end).then_some((year, year))
+            } else if key >= start {
+                Some((year, year + 1)) // wraps into next year
+            } else if key <= end {
+                Some((year - 1, year))
+            } else {
+                None
+            };
+            if let Some((start_year, end_year)) = bounds {
+                let start = clamped_date(start_year, start);
+                let end = clamped_date(end_year, end);
+                if let (Some(start), Some(end)) = (start, end) {
+                    found.push((start, end));
+                }
+            }
+        }
+        found
+    }
+
+    /// The day after the excepted run `date` falls in, so long ranges are
+    /// skipped in one step rather than day by day.
+    fn skip_forward(&self, date: Date) -> Result<Date, ScheduleError> {
+        let past = self
+            .ranges_containing(date)
+            .into_iter()
+            .map(|(_, end)| end)
+            .max();
+        past.unwrap_or(date)
+            .tomorrow()
+            .map_err(|e| ScheduleError::eval(format!("{e}")))
+    }
+
+    /// The day before the excepted run `date` falls in.
+    fn skip_backward(&self, date: Date) -> Result<Date, ScheduleError> {
+        let before = self
+            .ranges_containing(date)
+            .into_iter()
+            .map(|(start, _)| start)
+            .min();
+        before
+            .unwrap_or(date)
+            .yesterday()
+            .map_err(|e| ScheduleError::eval(format!("{e}")))
     }
 }
 
-/// Check if a date's month is in the `during` list.
-/// If `during` is empty, all months match.
-fn matches_during(date: Date, during: &[MonthName]) -> bool {
+/// The date for `(month, day)` in `year`, with days past the month's end
+/// (feb 29 in a common year) moved back to its last day.
+fn clamped_date(year: i16, (month, day): (u8, u8)) -> Option<Date> {
+    Date::new(year, month as i8, 1).ok()?;
+    let last = last_day_of_month(year, month as i8);
+    Date::new(year, month as i8, (day as i8).min(last.day())).ok()
+}
+
+/// Check if a date falls within any `during` spec.
+/// If `during` is empty, all dates match.
+fn matches_during(date: Date, during: &[DuringSpec]) -> bool {
     if during.is_empty() {
         return true;
     }
-    let m = date.month() as u8;
-    during.iter().any(|mn| mn.number() == m)
+    let Some(month) = month_number_to_name(date.month() as u8) else {
+        return false;
+    };
+    during
+        .iter()
+        .any(|spec| spec.contains(month, date.day() as u8))
 }
 
-/// Find the 1st of the next valid `during` month after `date`.
-fn next_during_month(date: Date, during: &[MonthName]) -> Date {
-    let current_month = date.month() as u8;
-    let mut months: Vec<u8> = during.iter().map(|mn| mn.number()).collect();
-    months.sort();
-
-    // Find first month > current_month
-    for &m in &months {
-        if m > current_month {
-            return Date::new(date.year(), m as i8, 1).unwrap();
-        }
+/// The month-day in `year`, or the day after the month's end when the day
+/// does not exist that year (feb 29 in a common year).
+fn month_day_in_year(year: i16, month: MonthName, day: u8) -> Option<Date> {
+    let last = last_day_of_month(year, month.number() as i8);
+    if day as i8 > last.day() {
+        last.tomorrow().ok()
+    } else {
+        Date::new(year, month.number() as i8, day as i8).ok()
     }
-    // Wrap to first month of next year
-    Date::new(date.year() + 1, months[0] as i8, 1).unwrap()
 }
 
-/// Resolve an UntilSpec to a concrete Date.
-fn resolve_until(until: &UntilSpec, now: &Zoned) -> Result<Date, ScheduleError> {
+/// Find the first day of the next `during` period starting after `date`.
+fn next_during_start(date: Date, during: &[DuringSpec]) -> Date {
+    during
+        .iter()
+        .filter_map(|spec| {
+            let ((month, day), _) = spec.bounds();
+            [date.year(), date.year().saturating_add(1)]
+                .into_iter()
+                .filter_map(|y| month_day_in_year(y, month, day))
+                .find(|start| *start > date)
+        })
+        .min()
+        .unwrap_or(date)
+}
+
+/// Find the last day of the previous `during` period ending before `date`.
+fn prev_during_end(date: Date, during: &[DuringSpec]) -> Date {
+    during
+        .iter()
+        .filter_map(|spec| {
+            let (_, (month, day)) = spec.bounds();
+            [date.year(), date.year().saturating_sub(1)]
+                .into_iter()
+                .map(|y| {
+                    let last = last_day_of_month(y, month.number() as i8);
+                    last.with()
+                        .day((day as i8).min(last.day()))
+                        .build()
+                        .unwrap_or(last)
+                })
+                .find(|end| *end < date)
+        })
+        .max()
+        .unwrap_or(date)
+}
+
+/// Resolve an UntilSpec to a concrete Date. Count-based specs have no end date
+/// and resolve to `None`; see [`counted_end`].
+fn resolve_until(until: &UntilSpec, now: &Zoned) -> Result<Option<Date>, ScheduleError> {
     match until {
         UntilSpec::Iso(s) => s
             .parse()
+            .map(Some)
             .map_err(|e| ScheduleError::eval(format!("invalid until date '{s}': {e}"))),
+        UntilSpec::Count(_) => Ok(None),
         UntilSpec::Named { month, day } => {
             let year = now.date().year();
             // Try this year first, then next year
             for y in [year, year + 1] {
                 if let Ok(d) = Date::new(y, month.number() as i8, *day as i8) {
                     if d >= now.date() {
-                        return Ok(d);
+                        return Ok(Some(d));
                     }
                 }
             }
             // Fallback: next year
             Date::new(year + 1, month.number() as i8, *day as i8)
+                .map(Some)
                 .map_err(|e| ScheduleError::eval(format!("invalid until date: {e}")))
         }
     }
 }
 
+/// The concrete date the schedule stops on, as seen from `now`. A named
+/// `until` resolves like [`resolve_until`]; `until N times` is the date of the
+/// Nth occurrence, so it is `None` once the count is used up.
+pub fn effective_until(schedule: &Schedule, now: &Zoned) -> Result<Option<Date>, ScheduleError> {
+    match &schedule.until {
+        None => Ok(None),
+        Some(UntilSpec::Count(_)) => Ok(counted_end(schedule, now, None)?
+            .filter(|end| end > now)
+            .map(|end| end.date())),
+        Some(until) => resolve_until(until, now),
+    }
+}
+
+/// The `until N times` budget and the `starting` date it counts from. The
+/// anchor must be a fixed date: counting from the evaluation time would start
+/// the budget afresh on every call.
+fn until_count(schedule: &Schedule) -> Result<Option<(u32, Date)>, ScheduleError> {
+    let Some(UntilSpec::Count(count)) = schedule.until else {
+        return Ok(None);
+    };
+    match (schedule.anchor, schedule.anchor_relative) {
+        (Some(anchor), None) => Ok(Some((count, anchor.to_jiff()))),
+        _ => Err(ScheduleError::eval(
+            "'until N times' counts from a 'starting' date; add one such as 'starting 2026-01-05'",
+        )),
+    }
+}
+
+/// The last occurrence `until N times` allows: the Nth from the `starting`
+/// anchor, or the last one if the schedule ends sooner. Before the anchor's
+/// first instant when the count is 0. `None` without a count.
+fn counted_end(
+    schedule: &Schedule,
+    reference: &Zoned,
+    is_holiday: Option<&dyn Fn(Date) -> bool>,
+) -> Result<Option<Zoned>, ScheduleError> {
+    let Some((count, anchor)) = until_count(schedule)? else {
+        return Ok(None);
+    };
+    let tz = resolve_tz(schedule, reference)?;
+    let mut last = anchor_start(schedule, anchor, &tz)?
+        .checked_sub(jiff::Span::new().nanoseconds(1))
+        .map_err(|e| ScheduleError::eval(format!("overflow: {e}")))?;
+    for _ in 0..count {
+        match next_uncounted(schedule, &last, is_holiday)? {
+            Some(dt) => last = dt,
+            None => break,
+        }
+    }
+    Ok(Some(last))
+}
+
+/// Reject what resolves against the evaluation time, a relative anchor
+/// (`starting today` / `starting jan 6`) or an `on <weekday>` date, in an
+/// operation that has no evaluation time. Resolving it against the datetime
+/// being checked would make every date its own anchor, and every friday the
+/// upcoming one.
+fn require_fixed_dates(schedule: &Schedule, operation: &str) -> Result<(), ScheduleError> {
+    if let Some(relative) = schedule.anchor_relative {
+        return Err(ScheduleError::eval(format!(
+            "{operation} has no evaluation time to resolve 'starting {relative}' against; \
+             pin the anchor to a date first"
+        )));
+    }
+    let dates = match &schedule.expr {
+        ScheduleExpr::SingleDate { date, .. } => std::slice::from_ref(date),
+        ScheduleExpr::MultiDate { dates, .. } => dates.as_slice(),
+        _ => &[],
+    };
+    match dates.iter().find(|d| matches!(d, DateSpec::NextWeekday(_))) {
+        Some(weekday) => Err(ScheduleError::eval(format!(
+            "{operation} has no evaluation time to resolve 'on {weekday}' against; \
+             use a date instead"
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Resolve the `starting` anchor. `starting today` and `starting now` are
+/// `now`'s date in the schedule timezone; `starting jan 6` is the next jan 6
+/// on or after it.
+fn resolve_anchor(schedule: &Schedule, now: &Zoned) -> Result<Option<Date>, ScheduleError> {
+    let Some(relative) = schedule.anchor_relative else {
+        return Ok(schedule.anchor.map(CivilDate::to_jiff));
+    };
+    let tz = resolve_tz(schedule, now)?;
+    let today = now.with_time_zone(tz).date();
+    let RelativeAnchor::Named { month, day } = relative else {
+        return Ok(Some(today));
+    };
+    // Try up to 8 years forward (covers leap year cycles)
+    for y in 0..8 {
+        if let Ok(date) = Date::new(today.year() + y, month.number() as i8, day as i8) {
+            if date >= today {
+                return Ok(Some(date));
+            }
+        }
+    }
+    Err(ScheduleError::eval(format!(
+        "starting date {month} {day} never occurs"
+    )))
+}
+
+/// The instant nothing occurs before: the anchor date at the `starting ... at`
+/// time, or at midnight.
+fn anchor_start(schedule: &Schedule, anchor: Date, tz: &TimeZone) -> Result<Zoned, ScheduleError> {
+    let time = schedule
+        .anchor_time
+        .as_ref()
+        .map_or(Time::midnight(), to_time);
+    at_time_on_date(anchor, time, tz)
+}
+
+/// Whether the expression still fires on the `starting` date once its
+/// `starting ... at` time has come: its latest time that day is no earlier.
+/// Interval windows that wrap past midnight count their steps up to 23:59.
+fn fires_after_anchor_time(schedule: &Schedule) -> bool {
+    let Some(at) = schedule.anchor_time else {
+        return true;
+    };
+    let latest = match &schedule.expr {
+        ScheduleExpr::IntervalRepeat {
+            interval,
+            unit,
+            from,
+            to,
+            ..
+        } => {
+            let step = match unit {
+                IntervalUnit::Minutes => *interval,
+                IntervalUnit::Hours => *interval * 60,
+                IntervalUnit::Days => return *from >= at,
+            };
+            let from_minutes = from.to_minutes() as u32;
+            let steps = (from.minutes_until(*to) as u32 / step).min((1439 - from_minutes) / step);
+            TimeOfDay::from_minutes((from_minutes + steps * step) as u16)
+        }
+        expr => expr.times().unwrap_or_default().iter().max().copied(),
+    };
+    latest.is_some_and(|latest| latest >= at)
+}
+
+/// Whether `datetime` falls before the `starting` anchor's first instant.
+fn before_anchor(
+    schedule: &Schedule,
+    anchor: Option<Date>,
+    datetime: &Zoned,
+    tz: &TimeZone,
+) -> Result<bool, ScheduleError> {
+    match anchor {
+        Some(anchor) => Ok(*datetime < anchor_start(schedule, anchor, tz)?),
+        None => Ok(false),
+    }
+}
+
+/// Pin what `schedule` resolves against the evaluation time, as seen from
+/// `now`: each `on <weekday>` date becomes the one day it names (the nearest
+/// such weekday with a time still ahead), and a relative `starting` anchor
+/// becomes its date. The pinned schedule fires the same way however often it
+/// is evaluated afterwards. Returns `None` when there is nothing to pin.
+fn pin_relative(schedule: &Schedule, now: &Zoned) -> Result<Option<Schedule>, ScheduleError> {
+    let is_next_weekday = |d: &DateSpec| matches!(d, DateSpec::NextWeekday(_));
+    let relative_date = match &schedule.expr {
+        ScheduleExpr::SingleDate { date, .. } => is_next_weekday(date),
+        ScheduleExpr::MultiDate { dates, .. } => dates.iter().any(is_next_weekday),
+        _ => false,
+    };
+    if !relative_date && schedule.anchor_relative.is_none() {
+        return Ok(None);
+    }
+    let tz = resolve_tz(schedule, now)?;
+    let mut pinned = schedule.clone();
+    if schedule.anchor_relative.is_some() {
+        pinned.anchor = resolve_anchor(schedule, now)?.map(CivilDate::from);
+        pinned.anchor_relative = None;
+    }
+    let (dates, times) = match &mut pinned.expr {
+        ScheduleExpr::SingleDate { date, times } => (std::slice::from_mut(date), &*times),
+        ScheduleExpr::MultiDate { dates, times } => (dates.as_mut_slice(), &*times),
+        _ => return Ok(Some(pinned)),
+    };
+    for date in dates.iter_mut().filter(|d| is_next_weekday(d)) {
+        if let Some(next) = next_single_date(date, times, &tz, now)? {
+            *date = DateSpec::Iso(next.with_time_zone(tz.clone()).date().to_string());
+        }
+    }
+    Ok(Some(pinned))
+}
+
 /// Check if a datetime matches any of the scheduled times, accounting for DST gaps.
 ///
 /// A time matches if either:
@@ -377,53 +735,124 @@ fn earliest_future_at_times(
     tz: &TimeZone,
     now: &Zoned,
 ) -> Result<Option<Zoned>, ScheduleError> {
+    // Compare resolved instants, not times of day: a time in a DST gap
+    // resolves forward and may land after a later listed time
     let mut best: Option<Zoned> = None;
     for tod in times {
-        let t = to_time(tod);
-        let candidate = at_time_on_date(date, t, tz)?;
-        if candidate > *now {
-            best = Some(match best {
-                Some(prev) if candidate < prev => candidate,
-                Some(prev) => prev,
-                None => candidate,
-            });
+        let candidate = at_time_on_date(date, to_time(tod), tz)?;
+        if candidate > *now && best.as_ref().is_none_or(|prev| candidate < *prev) {
+            best = Some(candidate);
         }
     }
     Ok(best)
 }
 
+/// Compute the next occurrence as wall-clock civil time, for schedules
+/// without an `in` timezone. Evaluated in UTC, which has no DST, so every
+/// scheduled time exists exactly once.
+pub fn next_civil_from(
+    schedule: &Schedule,
+    now: DateTime,
+) -> Result<Option<DateTime>, ScheduleError> {
+    if let Some(tz) = &schedule.timezone {
+        return Err(ScheduleError::eval(format!(
+            "civil evaluation needs a schedule without a timezone (found 'in {tz}')"
+        )));
+    }
+    let now = now
+        .to_zoned(TimeZone::UTC)
+        .map_err(|e| ScheduleError::eval(format!("{e}")))?;
+    Ok(next_from(schedule, &now)?.map(|next| next.datetime()))
+}
+
 /// Compute next occurrence from `now` for a given schedule.
 pub fn next_from(schedule: &Schedule, now: &Zoned) -> Result<Option<Zoned>, ScheduleError> {
-    let tz = resolve_tz(&schedule.timezone)?;
-    let anchor = schedule.anchor;
+    next_from_excluding(schedule, now, None)
+}
+
+/// Like [`next_from`], also skipping dates the calendar marks as holidays.
+pub fn next_from_with_calendar(
+    schedule: &Schedule,
+    now: &Zoned,
+    is_holiday: &dyn Fn(Date) -> bool,
+) -> Result<Option<Zoned>, ScheduleError> {
+    next_from_excluding(schedule, now, Some(is_holiday))
+}
+
+/// Like [`next_from`], evaluated in `tz` instead of the schedule's own
+/// timezone. The override goes through [`resolve_tz`] like an `in` clause.
+pub fn next_from_in(
+    schedule: &Schedule,
+    now: &Zoned,
+    tz: &str,
+) -> Result<Option<Zoned>, ScheduleError> {
+    let mut scoped = schedule.clone();
+    scoped.timezone = Some(tz.to_string());
+    next_from(&scoped, now)
+}
+
+fn next_from_excluding(
+    schedule: &Schedule,
+    now: &Zoned,
+    is_holiday: Option<&dyn Fn(Date) -> bool>,
+) -> Result<Option<Zoned>, ScheduleError> {
+    let end = counted_end(schedule, now, is_holiday)?;
+    let next = next_uncounted(schedule, now, is_holiday)?;
+    Ok(next.filter(|dt| end.as_ref().is_none_or(|end| dt <= end)))
+}
+
+/// The next occurrence ignoring an `until N times` budget.
+fn next_uncounted(
+    schedule: &Schedule,
+    now: &Zoned,
+    is_holiday: Option<&dyn Fn(Date) -> bool>,
+) -> Result<Option<Zoned>, ScheduleError> {
+    let tz = resolve_tz(schedule, now)?;
+    let anchor = resolve_anchor(schedule, now)?;
 
     // Resolve until date if present
     let until_date = match &schedule.until {
-        Some(until) => Some(resolve_until(until, now)?),
+        Some(until) => resolve_until(until, now)?,
         None => None,
     };
 
     let parsed_exceptions = ParsedExceptions::from_exceptions(&schedule.except);
-    let has_exceptions = !schedule.except.is_empty();
-    let has_during = !schedule.during.is_empty();
-    let needs_tz_conversion = until_date.is_some() || has_during || has_exceptions;
-
-    // Check if expression is NearestWeekday with direction (can cross month boundaries)
-    let handles_during_internally = matches!(
-        &schedule.expr,
-        ScheduleExpr::MonthRepeat {
-            target: MonthTarget::NearestWeekday {
-                direction: Some(_),
-                ..
-            },
-            ..
+    let has_exceptions = !schedule.except.is_empty() || is_holiday.is_some();
+    let during = &schedule.during;
+    let has_during = !during.is_empty();
+    let during_months = schedule.during_months();
+    let month_day_filter = month_day_filter(&schedule.expr);
+    let needs_tz_conversion =
+        until_date.is_some() || has_during || has_exceptions || month_day_filter.is_some();
+
+    // Targets that cross month boundaries check `during` by their source month
+    let handles_during_internally = during_by_source_month(&schedule.expr);
+
+    // Business-day targets count only days that are not excepted
+    let excepted = |d: Date| parsed_exceptions.is_excepted(d) || is_holiday.is_some_and(|h| h(d));
+
+    // Nothing occurs before the starting anchor, so search from its start
+    let mut current = match anchor {
+        Some(start) if before_anchor(schedule, anchor, now, &tz)? => {
+            anchor_start(schedule, start, &tz)?
+                .checked_sub(jiff::Span::new().nanoseconds(1))
+                .map_err(|e| ScheduleError::eval(format!("{e}")))?
         }
-    );
+        _ => now.clone(),
+    };
 
     // Retry loop for exceptions and during filter: if candidate is filtered, skip and retry
-    let mut current = now.clone();
     for _ in 0..1000 {
-        let candidate = next_expr(&schedule.expr, &tz, &anchor, &current, &schedule.during)?;
+        let candidate = next_expr(
+            &schedule.expr,
+            &tz,
+            &anchor,
+            &current,
+            &during_months,
+            schedule.week_start,
+            schedule.weekend,
+            &excepted,
+        )?;
 
         let candidate = match candidate {
             Some(c) => c,
@@ -446,25 +875,24 @@ pub fn next_from(schedule: &Schedule, now: &Zoned) -> Result<Option<Zoned>, Sche
 
         // Apply during filter
         // Skip this check for expressions that handle during internally (NearestWeekday with direction)
-        if has_during
-            && !handles_during_internally
-            && !matches_during(c_date.unwrap(), &schedule.during)
-        {
-            // Skip ahead to 1st of next valid during month
-            let skip_to = next_during_month(c_date.unwrap(), &schedule.during);
+        if has_during && !handles_during_internally && !matches_during(c_date.unwrap(), during) {
+            // Skip ahead to the start of the next during period
+            let skip_to = next_during_start(c_date.unwrap(), during);
             current = at_time_on_date(skip_to, Time::new(0, 0, 0, 0).unwrap(), &tz)?
                 .checked_add(jiff::Span::new().seconds(-1))
                 .map_err(|e| ScheduleError::eval(format!("{e}")))?;
             continue;
         }
 
-        // Apply except filter
-        if has_exceptions && parsed_exceptions.is_excepted(c_date.unwrap()) {
-            // Advance past this day and retry
-            let next_day = c_date
-                .unwrap()
-                .tomorrow()
-                .map_err(|e| ScheduleError::eval(format!("{e}")))?;
+        // Apply except filter (a monthly weekday filter skips days the same way)
+        if (has_exceptions
+            && (parsed_exceptions.is_excepted(c_date.unwrap())
+                || is_holiday.is_some_and(|h| h(c_date.unwrap()))))
+            || month_day_filter
+                .is_some_and(|df| !matches_day_filter(c_date.unwrap(), df, schedule.weekend))
+        {
+            // Advance past this day (or the whole excepted range) and retry
+            let next_day = parsed_exceptions.skip_forward(c_date.unwrap())?;
             current = at_time_on_date(next_day, Time::new(0, 0, 0, 0).unwrap(), &tz)?
                 .checked_add(jiff::Span::new().seconds(-1))
                 .map_err(|e| ScheduleError::eval(format!("{e}")))?;
@@ -481,20 +909,58 @@ pub fn next_from(schedule: &Schedule, now: &Zoned) -> Result<Option<Zoned>, Sche
     Ok(None)
 }
 
+/// The weekday filter of a monthly expression (`every month on the 1st at 09:00 on weekdays`).
+fn month_day_filter(expr: &ScheduleExpr) -> Option<&DayFilter> {
+    match expr {
+        ScheduleExpr::MonthRepeat {
+            day_filter: Some(df),
+            ..
+        } => Some(df),
+        _ => None,
+    }
+}
+
+/// Compute the next occurrence at or after `instant`.
+pub fn next_after(schedule: &Schedule, instant: &Zoned) -> Result<Option<Zoned>, ScheduleError> {
+    // Occurrences always land on a whole minute; `matches` compares only hour
+    // and minute, so guard against e.g. 09:00:30 matching a 09:00 schedule.
+    let on_minute = instant.time().second() == 0 && instant.time().subsec_nanosecond() == 0;
+    if on_minute && matches(schedule, instant)? {
+        return Ok(Some(instant.clone()));
+    }
+    next_from(schedule, instant)
+}
+
 /// Compute next occurrence for the expression part only.
+#[allow(clippy::too_many_arguments)]
 fn next_expr(
     expr: &ScheduleExpr,
     tz: &TimeZone,
     anchor: &Option<jiff::civil::Date>,
     now: &Zoned,
     during: &[MonthName],
+    week_start: WeekStart,
+    weekend: WeekendDays,
+    excepted: &dyn Fn(Date) -> bool,
 ) -> Result<Option<Zoned>, ScheduleError> {
     match expr {
         ScheduleExpr::DayRepeat {
             interval,
             days,
             times,
-        } => next_day_repeat(*interval, days, times, tz, anchor, now),
+        } => next_day_repeat(*interval, days, weekend, times, tz, anchor, now),
+
+        ScheduleExpr::WeekdayRepeat { interval, times } => {
+            next_weekday_repeat(*interval, times, tz, anchor, now)
+        }
+
+        ScheduleExpr::IntervalRepeat {
+            interval,
+            unit: IntervalUnit::Days,
+            from,
+            day_filter,
+            ..
+        } => next_interval_days(*interval, from, day_filter, weekend, tz, anchor, now),
 
         ScheduleExpr::IntervalRepeat {
             interval,
@@ -502,27 +968,34 @@ fn next_expr(
             from,
             to,
             day_filter,
-        } => next_interval_repeat(*interval, *unit, from, to, day_filter, tz, now),
+        } => next_interval_repeat(*interval, *unit, from, to, day_filter, weekend, tz, now),
 
         ScheduleExpr::WeekRepeat {
             interval,
             days,
             times,
-        } => next_week_repeat(*interval, days, times, tz, anchor, now),
+        } => next_week_repeat(*interval, days, times, tz, anchor, now, week_start),
 
         ScheduleExpr::MonthRepeat {
             interval,
             target,
             times,
-        } => next_month_repeat(*interval, target, times, tz, anchor, now, during),
+            ..
+        } => next_month_repeat(*interval, target, times, tz, anchor, now, during, excepted),
 
         ScheduleExpr::SingleDate { date, times } => next_single_date(date, times, tz, now),
 
+        ScheduleExpr::MultiDate { dates, times } => next_multi_date(dates, times, tz, now),
+
         ScheduleExpr::YearRepeat {
             interval,
             target,
             times,
         } => next_year_repeat(*interval, target, times, tz, anchor, now),
+
+        ScheduleExpr::IsoWeekRepeat { weeks, days, times } => {
+            next_iso_week_repeat(weeks, days, times, tz, now)
+        }
     }
 }
 
@@ -535,10 +1008,118 @@ pub fn next_n_from(
     Occurrences::new(schedule, now.clone()).take(n).collect()
 }
 
+/// Compute up to `n` occurrences like [`next_n_from`], but keep the
+/// occurrences found before an error instead of discarding them. The error
+/// that stopped the enumeration, if any, is returned alongside.
+pub fn next_n_from_partial(
+    schedule: &Schedule,
+    now: &Zoned,
+    n: usize,
+) -> (Vec<Zoned>, Option<ScheduleError>) {
+    let mut results = Vec::new();
+    for occurrence in Occurrences::new(schedule, now.clone()).take(n) {
+        match occurrence {
+            Ok(dt) => results.push(dt),
+            Err(e) => return (results, Some(e)),
+        }
+    }
+    (results, None)
+}
+
+/// Find the occurrence after skipping `count` occurrences past `now`, without
+/// collecting them: `count = 0` is [`next_from`]. Equivalent to
+/// `Occurrences::new(schedule, now).nth(count)`.
+pub fn next_skipping(
+    schedule: &Schedule,
+    now: &Zoned,
+    count: usize,
+) -> Result<Option<Zoned>, ScheduleError> {
+    if let Some(skipped) = skip_aligned(schedule, now, count)? {
+        return Ok(Some(skipped));
+    }
+    Occurrences::new(schedule, now.clone())
+        .nth(count)
+        .transpose()
+}
+
+/// Jump straight to the `count`th occurrence after the next one for
+/// single-time day and week repeats, whose occurrences fall at a fixed
+/// cadence of dates. Returns `None` (use the slow path) when modifiers could
+/// drop dates or the expression has no fixed cadence.
+fn skip_aligned(
+    schedule: &Schedule,
+    now: &Zoned,
+    count: usize,
+) -> Result<Option<Zoned>, ScheduleError> {
+    if count == 0
+        || !schedule.except.is_empty()
+        || schedule.until.is_some()
+        || !schedule.during.is_empty()
+    {
+        return Ok(None);
+    }
+    let (interval, days, time) = match &schedule.expr {
+        ScheduleExpr::DayRepeat {
+            interval,
+            days: DayFilter::Every,
+            times,
+        } if times.len() == 1 => (*interval, Vec::new(), times[0]),
+        ScheduleExpr::WeekRepeat {
+            interval,
+            days,
+            times,
+        } if times.len() == 1 => {
+            let mut days: Vec<i64> = days
+                .iter()
+                .map(|d| days_into_week(d.to_jiff(), schedule.week_start))
+                .collect();
+            days.sort();
+            days.dedup();
+            (*interval, days, times[0])
+        }
+        _ => return Ok(None),
+    };
+    let Some(first) = next_from(schedule, now)? else {
+        return Ok(None);
+    };
+    let tz = resolve_tz(schedule, now)?;
+    let first_date = first.with_time_zone(tz.clone()).date();
+
+    let overflow = || ScheduleError::eval(format!("skipping {count} occurrences overflows"));
+    let offset_days = if days.is_empty() {
+        i64::try_from(count)
+            .ok()
+            .and_then(|count| count.checked_mul(interval as i64))
+            .ok_or_else(overflow)?
+    } else {
+        // Position within the week's sorted days, then whole weeks plus a day offset
+        let weekday = days_into_week(first_date.weekday(), schedule.week_start);
+        let position = days.iter().position(|&d| d == weekday).unwrap_or(0);
+        let position = position.checked_add(count).ok_or_else(overflow)?;
+        let target = days[position % days.len()];
+        i64::try_from(position / days.len())
+            .ok()
+            .and_then(|weeks| weeks.checked_mul(interval as i64 * 7))
+            .and_then(|days| days.checked_add(target - weekday))
+            .ok_or_else(overflow)?
+    };
+    let date = jiff::Span::new()
+        .try_days(offset_days)
+        .and_then(|span| first_date.checked_add(span))
+        .map_err(|e| ScheduleError::eval(format!("{e}")))?;
+    at_time_on_date(date, to_time(&time), &tz).map(Some)
+}
+
 /// Lazy iterator over schedule occurrences starting after a given datetime.
 pub struct Occurrences<'a> {
     schedule: &'a Schedule,
+    /// `schedule` with `on <weekday>` and a relative anchor pinned against the
+    /// iterator's start; set on the first call to `next`.
+    pinned: Option<Schedule>,
     current: Zoned,
+    /// The last occurrence `until N times` allows; resolved on the first call to `next`.
+    end: Option<Zoned>,
+    started: bool,
 }
 
 impl<'a> Occurrences<'a> {
@@ -546,22 +1127,45 @@ impl<'a> Occurrences<'a> {
     pub fn new(schedule: &'a Schedule, from: Zoned) -> Self {
         Self {
             schedule,
+            pinned: None,
             current: from,
+            end: None,
+            started: false,
         }
     }
+
+    /// The schedule being iterated, with anything relative pinned.
+    fn schedule(&self) -> &Schedule {
+        self.pinned.as_ref().unwrap_or(self.schedule)
+    }
+
+    /// Move the cursor to `dt` so the next call returns the occurrence after it.
+    /// `next_from` is strictly after its cursor, so `dt` itself is not repeated.
+    fn resume_after(&mut self, dt: &Zoned) {
+        self.current = dt.clone();
+    }
 }
 
 impl Iterator for Occurrences<'_> {
     type Item = Result<Zoned, ScheduleError>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match next_from(self.schedule, &self.current) {
+        if !self.started {
+            self.started = true;
+            match pin_relative(self.schedule, &self.current) {
+                Ok(pinned) => self.pinned = pinned,
+                Err(e) => return Some(Err(e)),
+            }
+            // Walked once here, so each step below skips the count
+            match counted_end(self.schedule, &self.current, None) {
+                Ok(end) => self.end = end,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        match next_uncounted(self.schedule(), &self.current, None) {
+            Ok(Some(dt)) if self.end.as_ref().is_some_and(|end| dt > *end) => None,
             Ok(Some(dt)) => {
-                // Advance cursor by 1 minute to avoid returning same occurrence
-                match dt.checked_add(jiff::Span::new().minutes(1)) {
-                    Ok(c) => self.current = c,
-                    Err(e) => return Some(Err(ScheduleError::eval(format!("overflow: {e}")))),
-                }
+                self.resume_after(&dt);
                 Some(Ok(dt))
             }
             Ok(None) => None, // No more occurrences
@@ -570,18 +1174,117 @@ impl Iterator for Occurrences<'_> {
     }
 }
 
-/// Bounded iterator for occurrences where from < occurrence <= to.
+/// Lazy iterator over the civil dates a schedule fires on, starting at a given
+/// date. Each date is yielded once however many times fall on it.
+pub struct OccurrenceDates<'a> {
+    schedule: &'a Schedule,
+    from: Date,
+    /// Built on the first call to `next`, since resolving the start can fail.
+    inner: Option<Occurrences<'a>>,
+    last: Option<Date>,
+    done: bool,
+}
+
+impl<'a> OccurrenceDates<'a> {
+    /// Create a new iterator over dates on or after `from`.
+    pub fn new(schedule: &'a Schedule, from: Date) -> Self {
+        Self {
+            schedule,
+            from,
+            inner: None,
+            last: None,
+            done: false,
+        }
+    }
+
+    /// Occurrences starting at midnight on `from` in the schedule's timezone.
+    fn start(&self) -> Result<Occurrences<'a>, ScheduleError> {
+        let overflow = |e: jiff::Error| ScheduleError::eval(format!("overflow: {e}"));
+        let reference = self.from.to_zoned(TimeZone::UTC).map_err(overflow)?;
+        let cursor = self
+            .from
+            .to_zoned(resolve_tz(self.schedule, &reference)?)
+            .and_then(|start| start.checked_sub(jiff::Span::new().nanoseconds(1)))
+            .map_err(overflow)?;
+        Ok(Occurrences::new(self.schedule, cursor))
+    }
+}
+
+impl Iterator for OccurrenceDates<'_> {
+    type Item = Result<Date, ScheduleError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        if self.inner.is_none() {
+            match self.start() {
+                Ok(inner) => self.inner = Some(inner),
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+        let inner = self.inner.as_mut()?;
+        loop {
+            let dt = match inner.next()? {
+                Ok(dt) => dt,
+                Err(e) => return Some(Err(e)),
+            };
+            let date = dt.date();
+            if self.last == Some(date) {
+                continue;
+            }
+            self.last = Some(date);
+            // Skip the rest of the day's times
+            if let Ok(end_of_day) = dt
+                .tomorrow()
+                .and_then(|t| t.start_of_day())
+                .and_then(|t| t.checked_sub(jiff::Span::new().nanoseconds(1)))
+            {
+                inner.resume_after(&end_of_day);
+            }
+            return Some(Ok(date));
+        }
+    }
+}
+
+/// Bounded iterator for occurrences where from < occurrence <= to, or other
+/// bounds chosen with [`BoundedOccurrences::with_bounds`].
 pub struct BoundedOccurrences<'a> {
     inner: Occurrences<'a>,
     to: Zoned,
+    end_inclusive: bool,
 }
 
 impl<'a> BoundedOccurrences<'a> {
     /// Create a new bounded iterator for occurrences in the range (from, to].
     pub fn new(schedule: &'a Schedule, from: Zoned, to: Zoned) -> Self {
+        Self::with_bounds(schedule, from, to, false, true)
+    }
+
+    /// Create a bounded iterator with explicit inclusivity at each end, e.g.
+    /// `[from, to)` with `start_inclusive = true, end_inclusive = false`.
+    pub fn with_bounds(
+        schedule: &'a Schedule,
+        from: Zoned,
+        to: Zoned,
+        start_inclusive: bool,
+        end_inclusive: bool,
+    ) -> Self {
+        // Occurrences are strictly after their cursor, so step back one
+        // nanosecond to include an occurrence landing exactly on `from`.
+        let from = if start_inclusive {
+            from.checked_sub(jiff::Span::new().nanoseconds(1))
+                .unwrap_or(from)
+        } else {
+            from
+        };
         Self {
             inner: Occurrences::new(schedule, from),
             to,
+            end_inclusive,
         }
     }
 }
@@ -591,7 +1294,7 @@ impl Iterator for BoundedOccurrences<'_> {
 
     fn next(&mut self) -> Option<Self::Item> {
         match self.inner.next() {
-            Some(Ok(dt)) if dt <= self.to => Some(Ok(dt)),
+            Some(Ok(dt)) if dt < self.to || (self.end_inclusive && dt == self.to) => Some(Ok(dt)),
             Some(Ok(_)) => None, // Past end bound
             Some(Err(e)) => Some(Err(e)),
             None => None,
@@ -599,57 +1302,546 @@ impl Iterator for BoundedOccurrences<'_> {
     }
 }
 
-/// Create a bounded iterator of occurrences in the range (from, to].
-pub fn between<'a>(schedule: &'a Schedule, from: &Zoned, to: &Zoned) -> BoundedOccurrences<'a> {
-    BoundedOccurrences::new(schedule, from.clone(), to.clone())
+/// Iterator over every `n`th occurrence after a starting point, equivalent to
+/// `Occurrences::new(..).step_by(n)`.
+///
+/// For minute/hour interval schedules, a step that stays inside the current
+/// `from`-`to` window is computed arithmetically instead of visiting each
+/// skipped slot. Anything else (window edges, DST offset changes, `until N
+/// times`) falls back to stepping one occurrence at a time.
+pub struct SteppedOccurrences<'a> {
+    inner: Occurrences<'a>,
+    every_nth: usize,
+    last: Option<Zoned>,
 }
 
-/// Check if a datetime matches the schedule.
-pub fn matches(schedule: &Schedule, datetime: &Zoned) -> Result<bool, ScheduleError> {
-    let tz = resolve_tz(&schedule.timezone)?;
-    let zdt = datetime.with_time_zone(tz.clone());
-    let date = zdt.date();
-
-    // Check during filter
-    if !matches_during(date, &schedule.during) {
-        return Ok(false);
-    }
-
-    // Check exceptions
-    if !schedule.except.is_empty() {
-        let parsed_exceptions = ParsedExceptions::from_exceptions(&schedule.except);
-        if parsed_exceptions.is_excepted(date) {
-            return Ok(false);
+impl<'a> SteppedOccurrences<'a> {
+    /// Create an iterator yielding the first occurrence after `from`, then
+    /// every `every_nth` occurrence after that.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `every_nth` is 0, like [`Iterator::step_by`].
+    pub fn new(schedule: &'a Schedule, from: Zoned, every_nth: usize) -> Self {
+        assert!(every_nth != 0, "every_nth must be at least 1");
+        Self {
+            inner: Occurrences::new(schedule, from),
+            every_nth,
+            last: None,
+        }
+    }
+
+    /// The occurrence `every_nth` slots after `last`, if it lies in the same
+    /// interval window with no UTC offset change in between.
+    fn jump_within_window(&self, last: &Zoned) -> Result<Option<Zoned>, ScheduleError> {
+        let schedule = self.inner.schedule();
+        let ScheduleExpr::IntervalRepeat {
+            interval,
+            unit,
+            from,
+            to,
+            ..
+        } = &schedule.expr
+        else {
+            return Ok(None);
+        };
+        let step_minutes = match unit {
+            IntervalUnit::Minutes => *interval as i64,
+            IntervalUnit::Hours => *interval as i64 * 60,
+            IntervalUnit::Days => return Ok(None),
+        };
+        if matches!(schedule.until, Some(UntilSpec::Count(_))) {
+            return Ok(None);
+        }
+        let Ok(n) = i64::try_from(self.every_nth) else {
+            return Ok(None);
+        };
+        let Some(jump) = step_minutes.checked_mul(n) else {
+            return Ok(None);
+        };
+        let tz = resolve_tz(schedule, last)?;
+        let last = last.with_time_zone(tz.clone());
+        let Ok(candidate) = last.checked_add(jiff::Span::new().minutes(jump)) else {
+            return Ok(None);
+        };
+        let (_, _, window_end) = interval_window(&last, from, to, &tz)?;
+        if candidate > window_end || candidate.offset() != last.offset() {
+            return Ok(None);
+        }
+        let before = candidate
+            .checked_sub(jiff::Span::new().nanoseconds(1))
+            .map_err(|e| ScheduleError::eval(format!("overflow: {e}")))?;
+        Ok(next_from(schedule, &before)?.filter(|dt| *dt == candidate))
+    }
+}
+
+impl Iterator for SteppedOccurrences<'_> {
+    type Item = Result<Zoned, ScheduleError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = match self.last.clone() {
+            None => self.inner.next()?,
+            Some(last) => match self.jump_within_window(&last) {
+                Ok(Some(dt)) => {
+                    self.inner.resume_after(&dt);
+                    Ok(dt)
+                }
+                Ok(None) => self.inner.nth(self.every_nth - 1)?,
+                Err(e) => Err(e),
+            },
+        };
+        if let Ok(dt) = &result {
+            self.last = Some(dt.clone());
+        }
+        Some(result)
+    }
+}
+
+/// Create a bounded iterator of occurrences in the range (from, to].
+pub fn between<'a>(schedule: &'a Schedule, from: &Zoned, to: &Zoned) -> BoundedOccurrences<'a> {
+    BoundedOccurrences::new(schedule, from.clone(), to.clone())
+}
+
+/// Like [`between`], but an inverted range (`from` after `to`) is an error
+/// rather than an empty iterator.
+pub fn try_between<'a>(
+    schedule: &'a Schedule,
+    from: &Zoned,
+    to: &Zoned,
+) -> Result<BoundedOccurrences<'a>, ScheduleError> {
+    if from > to {
+        return Err(ScheduleError::eval(format!(
+            "inverted range: from {from} is after to {to}"
+        )));
+    }
+    Ok(between(schedule, from, to))
+}
+
+/// Create a bounded iterator of occurrences between `from` and `to` with the
+/// given inclusivity at each end.
+pub fn between_with_bounds<'a>(
+    schedule: &'a Schedule,
+    from: &Zoned,
+    to: &Zoned,
+    start_inclusive: bool,
+    end_inclusive: bool,
+) -> BoundedOccurrences<'a> {
+    BoundedOccurrences::with_bounds(
+        schedule,
+        from.clone(),
+        to.clone(),
+        start_inclusive,
+        end_inclusive,
+    )
+}
+
+/// A schedule paired with its next fire time, for use in priority queues.
+///
+/// Equality and ordering compare only the `next` instant, so items sort by
+/// when they fire. `BinaryHeap` is a max-heap: wrap items in
+/// [`std::cmp::Reverse`] to pop the earliest first.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Reverse;
+/// use std::collections::BinaryHeap;
+/// use hron::{Schedule, ScheduledItem};
+///
+/// let now: jiff::Zoned = "2026-02-06T12:00:00+00:00[UTC]".parse().unwrap();
+/// let mut queue = BinaryHeap::new();
+/// for expr in ["every day at 18:00 in UTC", "every day at 13:00 in UTC"] {
+///     let schedule = Schedule::parse(expr).unwrap();
+///     if let Some(item) = ScheduledItem::new(schedule, &now).unwrap() {
+///         queue.push(Reverse(item));
+///     }
+/// }
+///
+/// let Reverse(first) = queue.pop().unwrap();
+/// assert_eq!(first.next.to_string(), "2026-02-06T13:00:00+00:00[UTC]");
+///
+/// // Re-queue the schedule at its following occurrence.
+/// let again = first.advance().unwrap().unwrap();
+/// assert_eq!(again.next.to_string(), "2026-02-07T13:00:00+00:00[UTC]");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ScheduledItem {
+    pub schedule: Schedule,
+    pub next: Zoned,
+}
+
+impl ScheduledItem {
+    /// Pair `schedule` with its next occurrence after `now`, or `None` if it
+    /// never fires again.
+    pub fn new(schedule: Schedule, now: &Zoned) -> Result<Option<Self>, ScheduleError> {
+        Ok(next_from(&schedule, now)?.map(|next| Self { schedule, next }))
+    }
+
+    /// Move to the occurrence after `next`, or `None` if there is none.
+    pub fn advance(self) -> Result<Option<Self>, ScheduleError> {
+        let now = self.next;
+        Self::new(self.schedule, &now)
+    }
+}
+
+impl PartialEq for ScheduledItem {
+    fn eq(&self, other: &Self) -> bool {
+        self.next.timestamp() == other.next.timestamp()
+    }
+}
+
+impl Eq for ScheduledItem {}
+
+impl PartialOrd for ScheduledItem {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ScheduledItem {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.next.timestamp().cmp(&other.next.timestamp())
+    }
+}
+
+/// Collect occurrences in the half-open range [window_start, window_end).
+pub fn due_in_window(
+    schedule: &Schedule,
+    window_start: &Zoned,
+    window_end: &Zoned,
+) -> Result<Vec<Zoned>, ScheduleError> {
+    between_with_bounds(schedule, window_start, window_end, true, false).collect()
+}
+
+/// Check the `starting`, `during`, `except`, and `until` modifiers for a date in the
+/// schedule's timezone.
+fn matches_modifiers(
+    schedule: &Schedule,
+    date: Date,
+    datetime: &Zoned,
+) -> Result<bool, ScheduleError> {
+    match resolve_anchor(schedule, datetime)? {
+        Some(start) if date < start => return Ok(false),
+        Some(start) if date == start && !fires_after_anchor_time(schedule) => return Ok(false),
+        _ => {}
+    }
+
+    // Targets that cross month boundaries check `during` by their source
+    // month, in `matches_date_level`
+    if !during_by_source_month(&schedule.expr) && !matches_during(date, &schedule.during) {
+        return Ok(false);
+    }
+
+    if !schedule.except.is_empty() {
+        let parsed_exceptions = ParsedExceptions::from_exceptions(&schedule.except);
+        if parsed_exceptions.is_excepted(date) {
+            return Ok(false);
         }
     }
 
-    // Check until
     if let Some(ref until) = schedule.until {
-        let until_date = resolve_until(until, datetime)?;
-        if date > until_date {
+        if resolve_until(until, datetime)?.is_some_and(|until_date| date > until_date) {
             return Ok(false);
         }
     }
+    if counted_end(schedule, datetime, None)?.is_some_and(|end| date > end.date()) {
+        return Ok(false);
+    }
 
-    match &schedule.expr {
+    Ok(true)
+}
+
+/// Check if a datetime falls inside an interval schedule's active `from`-`to` window,
+/// ignoring the step. Non-interval schedules fall back to `matches`.
+pub fn window_contains(schedule: &Schedule, datetime: &Zoned) -> Result<bool, ScheduleError> {
+    require_fixed_dates(schedule, "window_contains")?;
+    let ScheduleExpr::IntervalRepeat {
+        interval,
+        unit,
+        from,
+        to,
+        day_filter,
+    } = &schedule.expr
+    else {
+        return matches(schedule, datetime);
+    };
+
+    let tz = resolve_tz(schedule, datetime)?;
+    let zdt = datetime.with_time_zone(tz.clone());
+    let date = zdt.date();
+
+    if !matches_modifiers(schedule, date, datetime)?
+        || before_anchor(schedule, resolve_anchor(schedule, datetime)?, datetime, &tz)?
+    {
+        return Ok(false);
+    }
+    let (start, from_resolved, to_resolved) = interval_window(&zdt, from, to, &tz)?;
+    if let Some(df) = day_filter {
+        if !matches_day_filter(start, df, schedule.weekend) {
+            return Ok(false);
+        }
+    }
+    if *unit == IntervalUnit::Days
+        && !interval_day_aligned(resolve_anchor(schedule, datetime)?, *interval, start)
+    {
+        return Ok(false);
+    }
+
+    Ok(from_resolved.timestamp() <= zdt.timestamp() && zdt.timestamp() <= to_resolved.timestamp())
+}
+
+/// List every occurrence on one civil date, over the half-open window from the
+/// start of that day to the start of the next in the schedule's timezone.
+pub fn occurrences_on(schedule: &Schedule, date: Date) -> Result<Vec<Zoned>, ScheduleError> {
+    require_fixed_dates(schedule, "occurrences_on")?;
+    let reference = date
+        .to_zoned(TimeZone::UTC)
+        .map_err(|e| ScheduleError::eval(format!("{e}")))?;
+    let tz = resolve_tz(schedule, &reference)?;
+    let next_day = date
+        .tomorrow()
+        .map_err(|e| ScheduleError::eval(format!("{e}")))?;
+    let start = date
+        .to_zoned(tz.clone())
+        .map_err(|e| ScheduleError::eval(format!("{e}")))?;
+    let end = next_day
+        .to_zoned(tz)
+        .map_err(|e| ScheduleError::eval(format!("{e}")))?;
+    between_with_bounds(schedule, &start, &end, true, false).collect()
+}
+
+/// How a DST transition moved an occurrence away from its written wall-clock time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DstAnomaly {
+    /// `scheduled` fell in a spring-forward gap, so the occurrence was
+    /// shifted later by the length of the gap (02:30 fires at 03:30).
+    Gap { scheduled: TimeOfDay },
+    /// The wall-clock time happened twice in a fall-back fold; the occurrence
+    /// is the earlier of the two and the repeat does not fire.
+    Fold,
+}
+
+/// Occurrences in `(from, to]` whose written time did not resolve to exactly
+/// one instant, using the same resolution as evaluation.
+///
+/// Only wall-clock times can be affected: minute and hour intervals step by
+/// elapsed time and never report anomalies.
+pub fn dst_anomalies_between(
+    schedule: &Schedule,
+    from: &Zoned,
+    to: &Zoned,
+) -> Result<Vec<(Zoned, DstAnomaly)>, ScheduleError> {
+    let times: &[TimeOfDay] = match &schedule.expr {
+        ScheduleExpr::IntervalRepeat {
+            unit: IntervalUnit::Days,
+            from,
+            ..
+        } => std::slice::from_ref(from),
+        expr => expr.times().unwrap_or_default(),
+    };
+    if times.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut anomalies = Vec::new();
+    for occurrence in between(schedule, from, to) {
+        let occurrence = occurrence?;
+        let tz = occurrence.time_zone();
+        let date = occurrence.date();
+        for tod in times {
+            let time = to_time(tod);
+            let anomaly = match tz.to_ambiguous_zoned(date.to_datetime(time)).offset() {
+                AmbiguousOffset::Gap { .. }
+                    if at_time_on_date(date, time, tz)?.timestamp() == occurrence.timestamp() =>
+                {
+                    DstAnomaly::Gap { scheduled: *tod }
+                }
+                AmbiguousOffset::Fold { .. } if occurrence.time() == time => DstAnomaly::Fold,
+                _ => continue,
+            };
+            anomalies.push((occurrence.clone(), anomaly));
+            break;
+        }
+    }
+    Ok(anomalies)
+}
+
+/// List the dates in a calendar month on which the schedule fires, computed
+/// from the expression's targets rather than by walking occurrences.
+pub fn dates_in_month(
+    schedule: &Schedule,
+    year: i16,
+    month: i8,
+) -> Result<Vec<Date>, ScheduleError> {
+    require_fixed_dates(schedule, "dates_in_month")?;
+    let first = Date::new(year, month, 1)
+        .map_err(|e| ScheduleError::eval(format!("invalid month {year}-{month}: {e}")))?;
+    let last = last_day_of_month(year, month);
+    let reference = first
+        .to_zoned(TimeZone::UTC)
+        .map_err(|e| ScheduleError::eval(format!("{e}")))?;
+    let anchor = resolve_anchor(schedule, &reference)?;
+    let days = (1..=last.day()).map(|d| Date::new(year, month, d).unwrap());
+
+    let mut dates: Vec<Date> = match &schedule.expr {
         ScheduleExpr::DayRepeat {
+            interval, days: df, ..
+        } => days
+            .filter(|&d| {
+                matches_day_filter(d, df, schedule.weekend)
+                    && (*interval <= 1 || interval_day_aligned(anchor, *interval, d))
+            })
+            .collect(),
+        ScheduleExpr::WeekdayRepeat { interval, .. } => days
+            .filter(|&d| weekday_interval_aligned(anchor, *interval, d))
+            .collect(),
+        ScheduleExpr::IntervalRepeat {
             interval,
-            days,
-            times,
+            unit,
+            from,
+            to,
+            day_filter,
         } => {
-            if !matches_day_filter(date, days) {
-                return Ok(false);
+            let starts_window = |d: Date| {
+                day_filter
+                    .as_ref()
+                    .is_none_or(|df| matches_day_filter(d, df, schedule.weekend))
+                    && (*unit != IntervalUnit::Days || interval_day_aligned(anchor, *interval, d))
+            };
+            let spills = window_spills(*interval, *unit, from, to);
+            days.filter(|&d| starts_window(d) || (spills && d.yesterday().is_ok_and(starts_window)))
+                .collect()
+        }
+        ScheduleExpr::WeekRepeat {
+            interval,
+            days: weekdays,
+            ..
+        } => days
+            .filter(|&d| {
+                let start = |date| week_start_of(date, schedule.week_start).unwrap();
+                let weeks = weeks_between(start(anchor.unwrap_or(*EPOCH_MONDAY)), start(d));
+                weekdays.contains(&Weekday::from_jiff(d.weekday()))
+                    && weeks >= 0
+                    && weeks % (*interval as i64) == 0
+            })
+            .collect(),
+        ScheduleExpr::MonthRepeat {
+            interval,
+            target,
+            day_filter,
+            ..
+        } => {
+            let parsed_exceptions = ParsedExceptions::from_exceptions(&schedule.except);
+            let excepted = |d: Date| parsed_exceptions.is_excepted(d);
+            let mut dates = Vec::new();
+            for source in month_repeat_sources(schedule, target, *interval, first, anchor)? {
+                dates.extend(month_target_dates(
+                    target,
+                    source.year(),
+                    source.month(),
+                    &excepted,
+                ));
             }
-            if !time_matches_with_dst(date, times, &tz, &zdt)? {
-                return Ok(false);
+            dates.retain(|d| {
+                first <= *d
+                    && *d <= last
+                    && day_filter
+                        .as_ref()
+                        .is_none_or(|df| matches_day_filter(*d, df, schedule.weekend))
+            });
+            dates
+        }
+        ScheduleExpr::SingleDate { date, .. } => date_spec_in_month(date, year, month)?,
+        ScheduleExpr::MultiDate { dates, .. } => {
+            let mut found = Vec::new();
+            for date in dates {
+                found.extend(date_spec_in_month(date, year, month)?);
             }
-            if *interval > 1 {
-                let anchor_date = schedule.anchor.unwrap_or(*EPOCH_DATE);
-                let day_offset = days_between(anchor_date, date);
-                return Ok(day_offset >= 0 && day_offset % (*interval as i64) == 0);
+            found
+        }
+        ScheduleExpr::YearRepeat {
+            interval, target, ..
+        } => {
+            let year_offset = year as i64 - anchor.unwrap_or(*EPOCH_DATE).year() as i64;
+            let aligned =
+                *interval <= 1 || (year_offset >= 0 && year_offset % (*interval as i64) == 0);
+            year_target_date(target, year)
+                .filter(|d| aligned && d.month() == month)
+                .into_iter()
+                .collect()
+        }
+        ScheduleExpr::IsoWeekRepeat {
+            weeks,
+            days: weekdays,
+            ..
+        } => days
+            .filter(|&d| matches_iso_week(d, weeks, weekdays))
+            .collect(),
+    };
+
+    dates.sort();
+    dates.dedup();
+    let mut result = Vec::with_capacity(dates.len());
+    for date in dates {
+        if matches_modifiers(schedule, date, &reference)? {
+            result.push(date);
+        }
+    }
+    Ok(result)
+}
+
+/// Count the occurrences in a calendar year, honoring `except`, `during`,
+/// `until`, and `starting`. Fixed-time schedules multiply the dates from
+/// [`dates_in_month`] by their times, less those before the `starting ... at`
+/// time on the starting date; interval windows and `until N times` walk the
+/// year's occurrences instead.
+pub fn count_in_year(schedule: &Schedule, year: i16) -> Result<usize, ScheduleError> {
+    require_fixed_dates(schedule, "count_in_year")?;
+    let counted = !matches!(schedule.until, Some(UntilSpec::Count(_)));
+    if let (Some(times), true) = (schedule.expr.times(), counted) {
+        let anchor = schedule.anchor.map(CivilDate::to_jiff);
+        let early = schedule
+            .anchor_time
+            .map_or(0, |at| times.iter().filter(|&&t| t < at).count());
+        let mut count = 0;
+        for month in 1..=12 {
+            for date in dates_in_month(schedule, year, month)? {
+                count += times.len() - if Some(date) == anchor { early } else { 0 };
             }
-            Ok(true)
         }
+        return Ok(count);
+    }
+
+    let invalid_year = |e: jiff::Error| ScheduleError::eval(format!("invalid year {year}: {e}"));
+    let reference = Date::new(year, 1, 1)
+        .map_err(invalid_year)?
+        .to_zoned(TimeZone::UTC)
+        .map_err(invalid_year)?;
+    let tz = resolve_tz(schedule, &reference)?;
+    let start = at_time_on_date(
+        Date::new(year, 1, 1).map_err(invalid_year)?,
+        Time::midnight(),
+        &tz,
+    )?;
+    let end = start
+        .checked_add(jiff::Span::new().years(1))
+        .map_err(invalid_year)?;
+    let mut count = 0;
+    for occurrence in between_with_bounds(schedule, &start, &end, true, false) {
+        occurrence?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Estimate how many times a year the schedule fires, from the expression
+/// alone. `except` and `until` are ignored, `during` scales the rate by the
+/// share of months kept, and calendar quirks are averaged (the 31st lands in
+/// 7 months, feb 29 once every four years). Returns `None` for a single ISO
+/// date, which has no yearly rate.
+pub fn approx_per_year(schedule: &Schedule) -> Option<f64> {
+    let per_year = match &schedule.expr {
         ScheduleExpr::IntervalRepeat {
             interval,
             unit,
@@ -657,149 +1849,526 @@ pub fn matches(schedule: &Schedule, datetime: &Zoned) -> Result<bool, ScheduleEr
             to,
             day_filter,
         } => {
-            if let Some(df) = day_filter {
-                if !matches_day_filter(date, df) {
-                    return Ok(false);
+            let days = day_filter
+                .as_ref()
+                .map_or(365.0, |df| days_per_year(df, schedule.weekend));
+            match unit {
+                IntervalUnit::Days => days / *interval as f64,
+                IntervalUnit::Minutes | IntervalUnit::Hours => {
+                    let step = match unit {
+                        IntervalUnit::Hours => *interval * 60,
+                        _ => *interval,
+                    };
+                    let span = from.minutes_until(*to) as u32;
+                    (span / step + 1) as f64 * days
                 }
             }
-            let from_t = to_time(from);
-            let to_t = to_time(to);
-            // Use instant-based arithmetic for DST correctness
-            let from_resolved = at_time_on_date(date, from_t, &tz)?;
-            let to_resolved = at_time_on_date(date, to_t, &tz)?;
-            let current_secs = zdt.timestamp().as_second();
-            let from_secs = from_resolved.timestamp().as_second();
-            let to_secs = to_resolved.timestamp().as_second();
-            if current_secs < from_secs || current_secs > to_secs {
-                return Ok(false);
-            }
-            let elapsed_secs = current_secs - from_secs;
-            let step_secs: i64 = match unit {
-                IntervalUnit::Minutes => *interval as i64 * 60,
-                IntervalUnit::Hours => *interval as i64 * 3600,
-            };
-            Ok(elapsed_secs >= 0 && elapsed_secs % step_secs == 0)
+        }
+        ScheduleExpr::DayRepeat {
+            interval,
+            days,
+            times,
+        } => days_per_year(days, schedule.weekend) / *interval as f64 * times.len() as f64,
+        ScheduleExpr::WeekdayRepeat { interval, times } => {
+            days_per_year(&DayFilter::Weekday, schedule.weekend) / *interval as f64
+                * times.len() as f64
         }
         ScheduleExpr::WeekRepeat {
             interval,
             days,
             times,
         } => {
-            let wd = Weekday::from_jiff(date.weekday());
-            if !days.contains(&wd) {
-                return Ok(false);
-            }
-            if !time_matches_with_dst(date, times, &tz, &zdt)? {
-                return Ok(false);
-            }
-            let anchor_date = schedule.anchor.unwrap_or(*EPOCH_MONDAY);
-            let weeks = weeks_between(anchor_date, date);
-            Ok(weeks >= 0 && weeks % (*interval as i64) == 0)
+            let mut unique: Vec<u8> = days.iter().map(|d| d.number()).collect();
+            unique.sort();
+            unique.dedup();
+            52.0 / *interval as f64 * unique.len() as f64 * times.len() as f64
         }
         ScheduleExpr::MonthRepeat {
             interval,
             target,
             times,
+            day_filter,
         } => {
-            if !time_matches_with_dst(date, times, &tz, &zdt)? {
-                return Ok(false);
-            }
-            if *interval > 1 {
-                let anchor_date = schedule.anchor.unwrap_or(*EPOCH_DATE);
-                let month_offset = months_between_ym(anchor_date, date);
-                if month_offset < 0 || month_offset % (*interval as i64) != 0 {
-                    return Ok(false);
-                }
-            }
-            match target {
+            let per_year = match target {
                 MonthTarget::Days(_) => {
-                    let expanded = target.expand_days();
-                    Ok(expanded.contains(&(date.day() as u8)))
-                }
-                MonthTarget::LastDay => {
-                    let last = last_day_of_month(date.year(), date.month());
-                    Ok(date == last)
-                }
-                MonthTarget::LastWeekday => {
-                    let last_wd = last_weekday_of_month(date.year(), date.month());
-                    Ok(date == last_wd)
-                }
-                MonthTarget::NearestWeekday { day, direction } => {
-                    match nearest_weekday(date.year(), date.month(), *day, *direction) {
-                        Some(target_date) => Ok(date == target_date),
-                        None => Ok(false),
-                    }
+                    let mut days = target.expand_days();
+                    days.sort();
+                    days.dedup();
+                    days.iter().map(|&d| months_with_day(d)).sum()
                 }
-                MonthTarget::OrdinalWeekday { ordinal, weekday } => {
-                    let target_date = match ordinal {
-                        OrdinalPosition::Last => {
-                            last_weekday_in_month(date.year(), date.month(), *weekday)
-                        }
-                        _ => {
-                            match ordinal_to_n(*ordinal).and_then(|n| {
-                                nth_weekday_of_month(date.year(), date.month(), *weekday, n)
-                            }) {
-                                Some(d) => d,
-                                None => return Ok(false),
-                            }
-                        }
-                    };
-                    Ok(date == target_date)
+                MonthTarget::OrdinalWeekday {
+                    ordinal: OrdinalPosition::Fifth,
+                    ..
+                } => FIFTH_WEEKDAY_MONTHS,
+                // Approximate: ignores months where "fourth" and "last" coincide
+                MonthTarget::OrdinalWeekdays { ordinals, .. } => ordinals
+                    .iter()
+                    .map(|o| match o {
+                        OrdinalPosition::Fifth => FIFTH_WEEKDAY_MONTHS,
+                        _ => 12.0,
+                    })
+                    .sum(),
+                // Day k from the end exists in as many months as day k does
+                MonthTarget::LastNDays(n) => (1..=*n).map(months_with_day).sum(),
+                // Months without the day are skipped, as for a plain day
+                MonthTarget::NearestWeekday { day, .. } => months_with_day(*day),
+                // Every month has at least 20 weekdays; beyond that, average
+                // over the 28-year weekday cycle
+                MonthTarget::BusinessDay(n) if *n > 20 => {
+                    let months = (2001..2029)
+                        .flat_map(|y| (1..=12).map(move |m| (y, m)))
+                        .filter(|&(y, m)| nth_business_day(y, m, *n, &|_| false).is_some())
+                        .count();
+                    months as f64 / 28.0
                 }
-            }
+                _ => 12.0,
+            };
+            let kept = day_filter
+                .as_ref()
+                .map_or(1.0, |df| days_per_year(df, schedule.weekend) / 365.0);
+            per_year * kept / *interval as f64 * times.len() as f64
         }
-        ScheduleExpr::SingleDate {
-            date: date_spec,
-            times,
-        } => {
-            if !time_matches_with_dst(date, times, &tz, &zdt)? {
-                return Ok(false);
+        ScheduleExpr::SingleDate { date, times } => match date {
+            // One-shots (fixed or relative to evaluation) have no yearly rate
+            DateSpec::Iso(_) | DateSpec::NextWeekday(_) => return None,
+            DateSpec::Named { month, day } => {
+                return Some(
+                    year_date_rate(*month, *day)
+                        * during_keeps(schedule, *month)
+                        * times.len() as f64,
+                );
             }
-            match date_spec {
-                DateSpec::Iso(s) => {
-                    let target: Date = s
-                        .parse()
-                        .map_err(|e| ScheduleError::eval(format!("invalid date '{s}': {e}")))?;
-                    Ok(date == target)
-                }
-                DateSpec::Named { month, day } => {
-                    Ok(date.month() == month.number() as i8 && date.day() == *day as i8)
-                }
+        },
+        ScheduleExpr::MultiDate { dates, times } => {
+            // ISO dates have no yearly rate; only named dates recur
+            let named: Vec<(MonthName, u8)> = dates
+                .iter()
+                .filter_map(|d| match d {
+                    DateSpec::Named { month, day } => Some((*month, *day)),
+                    DateSpec::Iso(_) | DateSpec::NextWeekday(_) => None,
+                })
+                .collect();
+            if named.is_empty() {
+                return None;
             }
+            let rate: f64 = named
+                .iter()
+                .map(|&(month, day)| year_date_rate(month, day) * during_keeps(schedule, month))
+                .sum();
+            return Some(rate * times.len() as f64);
         }
         ScheduleExpr::YearRepeat {
             interval,
             target,
             times,
         } => {
-            if !time_matches_with_dst(date, times, &tz, &zdt)? {
-                return Ok(false);
-            }
-            if *interval > 1 {
-                let anchor_year = schedule.anchor.unwrap_or(*EPOCH_DATE).year();
-                let year_offset = date.year() as i64 - anchor_year as i64;
-                if year_offset < 0 || year_offset % (*interval as i64) != 0 {
-                    return Ok(false);
-                }
-            }
-            match target {
-                YearTarget::Date { month, day } => {
-                    Ok(date.month() == month.number() as i8 && date.day() == *day as i8)
+            let (month, rate) = match target {
+                YearTarget::Date { month, day } | YearTarget::DayOfMonth { day, month } => {
+                    (*month, year_date_rate(*month, *day))
                 }
                 YearTarget::OrdinalWeekday {
-                    ordinal,
-                    weekday,
+                    ordinal: OrdinalPosition::Fifth,
                     month,
-                } => {
-                    if date.month() != month.number() as i8 {
-                        return Ok(false);
-                    }
-                    let target_date = match ordinal {
-                        OrdinalPosition::Last => {
-                            last_weekday_in_month(date.year(), date.month(), *weekday)
-                        }
-                        _ => {
-                            match ordinal_to_n(*ordinal).and_then(|n| {
+                    ..
+                } => (*month, FIFTH_WEEKDAY_MONTHS / 12.0),
+                YearTarget::OrdinalWeekday { month, .. } | YearTarget::LastWeekday { month } => {
+                    (*month, 1.0)
+                }
+            };
+            return Some(
+                rate * during_keeps(schedule, month) / *interval as f64 * times.len() as f64,
+            );
+        }
+        ScheduleExpr::IsoWeekRepeat { weeks, days, times } => {
+            let mut unique_weeks = weeks.clone();
+            unique_weeks.sort();
+            unique_weeks.dedup();
+            let mut unique_days: Vec<u8> = days.iter().map(|d| d.number()).collect();
+            unique_days.sort();
+            unique_days.dedup();
+            let week_rate: f64 = unique_weeks
+                .iter()
+                .map(|&w| if w == 53 { LONG_ISO_YEARS } else { 1.0 })
+                .sum();
+            week_rate * unique_days.len() as f64 * times.len() as f64
+        }
+    };
+
+    let mut months: Vec<u8> = schedule
+        .during_months()
+        .iter()
+        .map(|m| m.number())
+        .collect();
+    months.sort();
+    months.dedup();
+    let kept = if months.is_empty() {
+        1.0
+    } else if schedule.during_has_dates() {
+        // Share of days kept, over a common year
+        let during = &schedule.during;
+        let first = Date::new(2025, 1, 1).unwrap();
+        let kept_days = (0..365)
+            .filter_map(|i| first.checked_add(jiff::Span::new().days(i)).ok())
+            .filter(|&d| matches_during(d, during))
+            .count();
+        kept_days as f64 / 365.0
+    } else {
+        months.len() as f64 / 12.0
+    };
+    Some(per_year * kept)
+}
+
+/// Share of ISO years with a week 53: 71 in every 400-year cycle.
+const LONG_ISO_YEARS: f64 = 71.0 / 400.0;
+
+/// Average number of months per year with a fifth occurrence of a given
+/// weekday: each month has (length - 28) / 7 odds, summed over a 365.25-day
+/// year.
+const FIFTH_WEEKDAY_MONTHS: f64 = (365.25 - 12.0 * 28.0) / 7.0;
+
+fn days_per_year(filter: &DayFilter, weekend: WeekendDays) -> f64 {
+    match filter.canonical() {
+        DayFilter::Every => 365.0,
+        DayFilter::Weekday => 260.0,
+        DayFilter::Weekend => 52.0 * weekend.days().len() as f64,
+        DayFilter::Days(days) => {
+            let mut unique: Vec<u8> = days.iter().map(|d| d.number()).collect();
+            unique.sort();
+            unique.dedup();
+            52.0 * unique.len() as f64
+        }
+    }
+}
+
+/// Average number of months per year that have day `day`.
+fn months_with_day(day: u8) -> f64 {
+    match day {
+        0..=28 => 12.0,
+        29 => 11.25,
+        30 => 11.0,
+        _ => 7.0,
+    }
+}
+
+/// Average yearly rate of a fixed month/day: feb 29 exists one year in four.
+fn year_date_rate(month: MonthName, day: u8) -> f64 {
+    if month == MonthName::February && day == 29 {
+        0.25
+    } else {
+        1.0
+    }
+}
+
+fn during_keeps(schedule: &Schedule, month: MonthName) -> f64 {
+    if schedule.during.is_empty() || schedule.during_months().contains(&month) {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+/// Find the interval window covering `zdt`'s wall-clock time: its start date
+/// and resolved `from`/`to` instants. When `to` is before `from` the window
+/// wraps past midnight, so times before `from` belong to yesterday's window.
+fn interval_window(
+    zdt: &Zoned,
+    from: &TimeOfDay,
+    to: &TimeOfDay,
+    tz: &TimeZone,
+) -> Result<(Date, Zoned, Zoned), ScheduleError> {
+    let (from_t, to_t) = (to_time(from), to_time(to));
+    let wraps = to < from;
+    let date = zdt.date();
+    let start = if wraps && zdt.time() < from_t {
+        date.yesterday()
+            .map_err(|e| ScheduleError::eval(format!("{e}")))?
+    } else {
+        date
+    };
+    let end = if wraps {
+        start
+            .tomorrow()
+            .map_err(|e| ScheduleError::eval(format!("{e}")))?
+    } else {
+        start
+    };
+    Ok((
+        start,
+        at_time_on_date(start, from_t, tz)?,
+        at_time_on_date(end, to_t, tz)?,
+    ))
+}
+
+/// Whether `date` is one of the aligned days of a day-step interval window.
+fn interval_day_aligned(anchor: Option<Date>, interval: u32, date: Date) -> bool {
+    let anchor_date = anchor.unwrap_or(*EPOCH_DATE);
+    let day_offset = days_between(anchor_date, date);
+    day_offset >= 0 && day_offset % (interval as i64) == 0
+}
+
+/// Number of business days (Monday-Friday) from the week of the epoch to
+/// `date`. A weekend date shares the index of the following Monday.
+fn business_day_index(date: Date) -> i64 {
+    // 1970-01-01 was a Thursday, 3 days after the Monday that starts its week
+    let days = days_between(*EPOCH_DATE, date) + 3;
+    days.div_euclid(7) * 5 + days.rem_euclid(7).min(5)
+}
+
+/// The weekday with the given [`business_day_index`].
+fn business_day_date(index: i64) -> Result<Date, ScheduleError> {
+    let days = index.div_euclid(5) * 7 + index.rem_euclid(5) - 3;
+    EPOCH_DATE
+        .checked_add(jiff::Span::new().days(days))
+        .map_err(|e| ScheduleError::eval(format!("{e}")))
+}
+
+/// Check whether `date` is a business day an `every N weekdays` schedule fires on.
+fn weekday_interval_aligned(anchor: Option<Date>, interval: u32, date: Date) -> bool {
+    let offset = business_day_index(date) - business_day_index(anchor.unwrap_or(*EPOCH_DATE));
+    matches_day_filter(date, &DayFilter::Weekday, WeekendDays::default())
+        && offset >= 0
+        && offset % interval as i64 == 0
+}
+
+/// Check if a datetime matches the schedule.
+pub fn matches(schedule: &Schedule, datetime: &Zoned) -> Result<bool, ScheduleError> {
+    require_fixed_dates(schedule, "matches")?;
+    matches_in(schedule, datetime, &resolve_tz(schedule, datetime)?)
+}
+
+/// Check many datetimes against the schedule. An explicit `in` timezone is
+/// looked up once rather than per element.
+pub fn matches_all(schedule: &Schedule, datetimes: &[Zoned]) -> Result<Vec<bool>, ScheduleError> {
+    require_fixed_dates(schedule, "matches_all")?;
+    let Some(first) = datetimes.first() else {
+        return Ok(Vec::new());
+    };
+    let fixed_tz = match schedule.timezone {
+        Some(_) => Some(resolve_tz(schedule, first)?),
+        None => None,
+    };
+    datetimes
+        .iter()
+        .map(|dt| match &fixed_tz {
+            Some(tz) => matches_in(schedule, dt, tz),
+            None => matches_in(schedule, dt, &resolve_tz(schedule, dt)?),
+        })
+        .collect()
+}
+
+fn matches_in(schedule: &Schedule, datetime: &Zoned, tz: &TimeZone) -> Result<bool, ScheduleError> {
+    let anchor = resolve_anchor(schedule, datetime)?;
+    // Skip the conversion when `datetime` is already in the schedule's zone
+    let converted;
+    let zdt = if datetime.time_zone() == tz {
+        datetime
+    } else {
+        converted = datetime.with_time_zone(tz.clone());
+        &converted
+    };
+    let date = zdt.date();
+
+    if !matches_modifiers(schedule, date, datetime)?
+        || before_anchor(schedule, anchor, datetime, tz)?
+    {
+        return Ok(false);
+    }
+    // `matches_modifiers` compares dates; a later time on the Nth day is past the count
+    if counted_end(schedule, datetime, None)?.is_some_and(|end| *datetime > end) {
+        return Ok(false);
+    }
+
+    match &schedule.expr {
+        ScheduleExpr::IntervalRepeat {
+            interval,
+            unit,
+            from,
+            to,
+            day_filter,
+        } if *unit != IntervalUnit::Days => {
+            // Use instant-based arithmetic for DST correctness
+            let (start, from_resolved, to_resolved) = interval_window(zdt, from, to, tz)?;
+            if let Some(df) = day_filter {
+                if !matches_day_filter(start, df, schedule.weekend) {
+                    return Ok(false);
+                }
+            }
+            let current_secs = zdt.timestamp().as_second();
+            let from_secs = from_resolved.timestamp().as_second();
+            let to_secs = to_resolved.timestamp().as_second();
+            if current_secs < from_secs || current_secs > to_secs {
+                return Ok(false);
+            }
+            let elapsed_secs = current_secs - from_secs;
+            let step_secs: i64 = match unit {
+                IntervalUnit::Minutes => *interval as i64 * 60,
+                IntervalUnit::Hours => *interval as i64 * 3600,
+                IntervalUnit::Days => unreachable!("day-step intervals handled below"),
+            };
+            Ok(elapsed_secs >= 0 && elapsed_secs % step_secs == 0)
+        }
+        ScheduleExpr::IntervalRepeat { from, .. } => {
+            Ok(matches_date_level(schedule, date, anchor)?
+                && time_matches_with_dst(date, std::slice::from_ref(from), tz, zdt)?)
+        }
+        expr => {
+            let times = expr.times().unwrap_or_default();
+            Ok(matches_date_level(schedule, date, anchor)?
+                && time_matches_with_dst(date, times, tz, zdt)?)
+        }
+    }
+}
+
+/// Whether a sub-day interval window wraps past midnight and still has a
+/// step landing at or before `to` on the next day.
+fn window_spills(interval: u32, unit: IntervalUnit, from: &TimeOfDay, to: &TimeOfDay) -> bool {
+    let step_minutes = match unit {
+        IntervalUnit::Minutes => interval as i64,
+        IntervalUnit::Hours => interval as i64 * 60,
+        IntervalUnit::Days => return false,
+    };
+    to < from && {
+        let from_minutes = from.to_minutes() as i64;
+        let steps_to_midnight = (1440 - from_minutes + step_minutes - 1) / step_minutes;
+        steps_to_midnight * step_minutes <= from.minutes_until(*to) as i64
+    }
+}
+
+/// Check whether the schedule fires at any time on `date`, ignoring `times`.
+/// Named `until` dates resolve as if evaluated on `date`.
+pub fn matches_date(schedule: &Schedule, date: Date) -> Result<bool, ScheduleError> {
+    require_fixed_dates(schedule, "matches_date")?;
+    let overflow = |e: jiff::Error| ScheduleError::eval(format!("overflow: {e}"));
+    let utc = date.to_zoned(TimeZone::UTC).map_err(overflow)?;
+    let reference = date
+        .to_zoned(resolve_tz(schedule, &utc)?)
+        .map_err(overflow)?;
+    let anchor = resolve_anchor(schedule, &reference)?;
+    Ok(matches_modifiers(schedule, date, &reference)?
+        && matches_date_level(schedule, date, anchor)?)
+}
+
+/// The date-level half of [`matches`]: day filters, interval alignment and
+/// month/year targets. `during`, `except` and `until` are checked by
+/// [`matches_modifiers`].
+fn matches_date_level(
+    schedule: &Schedule,
+    date: Date,
+    anchor: Option<Date>,
+) -> Result<bool, ScheduleError> {
+    match &schedule.expr {
+        ScheduleExpr::DayRepeat { interval, days, .. } => {
+            if !matches_day_filter(date, days, schedule.weekend) {
+                return Ok(false);
+            }
+            if *interval > 1 {
+                let anchor_date = anchor.unwrap_or(*EPOCH_DATE);
+                let day_offset = days_between(anchor_date, date);
+                return Ok(day_offset >= 0 && day_offset % (*interval as i64) == 0);
+            }
+            Ok(true)
+        }
+        ScheduleExpr::WeekdayRepeat { interval, .. } => {
+            Ok(weekday_interval_aligned(anchor, *interval, date))
+        }
+        ScheduleExpr::IntervalRepeat {
+            interval,
+            unit: IntervalUnit::Days,
+            day_filter,
+            ..
+        } => Ok(day_filter
+            .as_ref()
+            .is_none_or(|df| matches_day_filter(date, df, schedule.weekend))
+            && interval_day_aligned(anchor, *interval, date)),
+        ScheduleExpr::IntervalRepeat {
+            interval,
+            unit,
+            from,
+            to,
+            day_filter,
+        } => {
+            let Some(df) = day_filter else {
+                return Ok(true);
+            };
+            Ok(matches_day_filter(date, df, schedule.weekend)
+                || (window_spills(*interval, *unit, from, to)
+                    && date
+                        .yesterday()
+                        .is_ok_and(|d| matches_day_filter(d, df, schedule.weekend))))
+        }
+        ScheduleExpr::WeekRepeat { interval, days, .. } => {
+            let wd = Weekday::from_jiff(date.weekday());
+            if !days.contains(&wd) {
+                return Ok(false);
+            }
+            let anchor_date = anchor.unwrap_or(*EPOCH_MONDAY);
+            let weeks = weeks_between(
+                week_start_of(anchor_date, schedule.week_start)?,
+                week_start_of(date, schedule.week_start)?,
+            );
+            Ok(weeks >= 0 && weeks % (*interval as i64) == 0)
+        }
+        ScheduleExpr::MonthRepeat {
+            interval,
+            target,
+            day_filter,
+            ..
+        } => {
+            if let Some(df) = day_filter {
+                if !matches_day_filter(date, df, schedule.weekend) {
+                    return Ok(false);
+                }
+            }
+            let parsed_exceptions = ParsedExceptions::from_exceptions(&schedule.except);
+            let excepted = |d: Date| parsed_exceptions.is_excepted(d);
+            for source in month_repeat_sources(schedule, target, *interval, date, anchor)? {
+                if month_target_dates(target, source.year(), source.month(), &excepted)
+                    .contains(&date)
+                {
+                    return Ok(true);
+                }
+            }
+            Ok(false)
+        }
+        ScheduleExpr::SingleDate {
+            date: date_spec, ..
+        } => matches_date_spec(date, date_spec),
+        ScheduleExpr::MultiDate { dates, .. } => {
+            for date_spec in dates {
+                if matches_date_spec(date, date_spec)? {
+                    return Ok(true);
+                }
+            }
+            Ok(false)
+        }
+        ScheduleExpr::YearRepeat {
+            interval, target, ..
+        } => {
+            if *interval > 1 {
+                let anchor_year = anchor.unwrap_or(*EPOCH_DATE).year();
+                let year_offset = date.year() as i64 - anchor_year as i64;
+                if year_offset < 0 || year_offset % (*interval as i64) != 0 {
+                    return Ok(false);
+                }
+            }
+            match target {
+                YearTarget::Date { month, day } => {
+                    Ok(date.month() == month.number() as i8 && date.day() == *day as i8)
+                }
+                YearTarget::OrdinalWeekday {
+                    ordinal,
+                    weekday,
+                    month,
+                } => {
+                    if date.month() != month.number() as i8 {
+                        return Ok(false);
+                    }
+                    let target_date = match ordinal {
+                        OrdinalPosition::Last => {
+                            last_weekday_in_month(date.year(), date.month(), *weekday)
+                        }
+                        _ => {
+                            match ordinal_to_n(*ordinal).and_then(|n| {
                                 nth_weekday_of_month(date.year(), date.month(), *weekday, n)
                             }) {
                                 Some(d) => d,
@@ -821,6 +2390,27 @@ pub fn matches(schedule: &Schedule, datetime: &Zoned) -> Result<bool, ScheduleEr
                 }
             }
         }
+        ScheduleExpr::IsoWeekRepeat { weeks, days, .. } => Ok(matches_iso_week(date, weeks, days)),
+    }
+}
+
+/// Check if any occurrence lies within `tolerance` of `datetime` (inclusive on both sides).
+pub fn matches_within(
+    schedule: &Schedule,
+    datetime: &Zoned,
+    tolerance: jiff::Span,
+) -> Result<bool, ScheduleError> {
+    require_fixed_dates(schedule, "matches_within")?;
+    let overflow = |e: jiff::Error| ScheduleError::eval(format!("overflow: {e}"));
+    let earliest = datetime.checked_sub(tolerance).map_err(overflow)?;
+    let latest = datetime.checked_add(tolerance).map_err(overflow)?;
+    // next_from is strictly after its argument; step back so `earliest` itself counts.
+    let search_from = earliest
+        .checked_sub(jiff::Span::new().nanoseconds(1))
+        .map_err(overflow)?;
+    match next_from(schedule, &search_from)? {
+        Some(next) => Ok(next <= latest),
+        None => Ok(false),
     }
 }
 
@@ -828,39 +2418,73 @@ pub fn matches(schedule: &Schedule, datetime: &Zoned) -> Result<bool, ScheduleEr
 /// Returns None if no previous occurrence exists (e.g., before a starting anchor
 /// or for single dates in the future).
 pub fn previous_from(schedule: &Schedule, now: &Zoned) -> Result<Option<Zoned>, ScheduleError> {
-    let tz = resolve_tz(&schedule.timezone)?;
-    let anchor = schedule.anchor;
+    // Past the last counted occurrence, search back from just after it
+    let capped;
+    let now = match counted_end(schedule, now, None)? {
+        Some(end) if *now > end => {
+            capped = end
+                .checked_add(jiff::Span::new().nanoseconds(1))
+                .map_err(|e| ScheduleError::eval(format!("overflow: {e}")))?;
+            &capped
+        }
+        _ => now,
+    };
+    if schedule.anchor_relative == Some(RelativeAnchor::Now) {
+        return Ok(None);
+    }
+    let pinned = pin_relative(schedule, now)?;
+    let schedule = pinned.as_ref().unwrap_or(schedule);
+    let tz = resolve_tz(schedule, now)?;
+    let anchor = resolve_anchor(schedule, now)?;
 
     // Resolve starting date - if result would be before this, return None
     let starting_date = anchor;
 
-    // Resolve until date if present - for previousFrom, we still find occurrences
-    // but if now is after until, the previous occurrence is bounded by until
+    // Resolve until date if present - when now is past it, the previous
+    // occurrence is the last one on or before it
     let until_date = match &schedule.until {
-        Some(until) => Some(resolve_until(until, now)?),
+        Some(until) => resolve_until(until, now)?,
         None => None,
     };
 
     let parsed_exceptions = ParsedExceptions::from_exceptions(&schedule.except);
     let has_exceptions = !schedule.except.is_empty();
-    let has_during = !schedule.during.is_empty();
+    let during = &schedule.during;
+    let has_during = !during.is_empty();
+    let during_months = schedule.during_months();
+    let month_day_filter = month_day_filter(&schedule.expr);
+
+    // Targets that cross month boundaries check `during` by their source month
+    let handles_during_internally = during_by_source_month(&schedule.expr);
+
+    // Nothing occurs before the starting anchor
+    if let Some(start) = starting_date {
+        if *now <= anchor_start(schedule, start, &tz)? {
+            return Ok(None);
+        }
+    }
 
-    // Check if expression is NearestWeekday with direction (can cross month boundaries)
-    let handles_during_internally = matches!(
-        &schedule.expr,
-        ScheduleExpr::MonthRepeat {
-            target: MonthTarget::NearestWeekday {
-                direction: Some(_),
-                ..
-            },
-            ..
+    // Search back from the end of the until date rather than from `now`
+    let mut current = match until_date {
+        Some(until) if now.with_time_zone(tz.clone()).date() > until => {
+            at_time_on_date(until, Time::new(23, 59, 59, 0).unwrap(), &tz)?
         }
-    );
+        _ => now.clone(),
+    };
 
     // Retry loop for exceptions and during filter
-    let mut current = now.clone();
+    let excepted = |d: Date| parsed_exceptions.is_excepted(d);
     for _ in 0..1000 {
-        let candidate = prev_expr(&schedule.expr, &tz, &anchor, &current, &schedule.during)?;
+        let candidate = prev_expr(
+            &schedule.expr,
+            &tz,
+            &anchor,
+            &current,
+            &during_months,
+            schedule.week_start,
+            schedule.weekend,
+            &excepted,
+        )?;
 
         let candidate = match candidate {
             Some(c) => c,
@@ -870,44 +2494,27 @@ pub fn previous_from(schedule: &Schedule, now: &Zoned) -> Result<Option<Zoned>,
         let c_date = candidate.with_time_zone(tz.clone()).date();
 
         // Apply starting filter - if before starting anchor, no previous occurrence
-        if let Some(start) = starting_date {
-            if c_date < start {
-                return Ok(None);
-            }
-            // Also check if on starting date but time is before the occurrence
-            if c_date == start {
-                // The candidate is valid if it's >= start date
-                // (we already checked c_date >= start above)
-            }
-        }
-
-        // Apply until filter for previousFrom:
-        // If candidate is after until, we need to search earlier
-        // This handles the case where now is after until
-        if let Some(ref until) = until_date {
-            if c_date > *until {
-                // Move current backward past until and retry
-                current = at_time_on_date(*until, Time::new(23, 59, 59, 0).unwrap(), &tz)?;
-                continue;
-            }
+        if before_anchor(schedule, starting_date, &candidate, &tz)? {
+            return Ok(None);
         }
 
         // Apply during filter
-        if has_during && !handles_during_internally && !matches_during(c_date, &schedule.during) {
-            // Skip backward to last day of previous valid during month
-            let skip_to = prev_during_month(c_date, &schedule.during);
+        if has_during && !handles_during_internally && !matches_during(c_date, during) {
+            // Skip backward to the last day of the previous during period
+            let skip_to = prev_during_end(c_date, during);
             current = at_time_on_date(skip_to, Time::new(23, 59, 59, 0).unwrap(), &tz)?
                 .checked_add(jiff::Span::new().seconds(1))
                 .map_err(|e| ScheduleError::eval(format!("{e}")))?;
             continue;
         }
 
-        // Apply except filter
-        if has_exceptions && parsed_exceptions.is_excepted(c_date) {
-            // Go back to end of previous day and retry
-            let prev_day = c_date
-                .yesterday()
-                .map_err(|e| ScheduleError::eval(format!("{e}")))?;
+        // Apply except filter (a monthly weekday filter skips days the same way)
+        if (has_exceptions && parsed_exceptions.is_excepted(c_date))
+            || month_day_filter.is_some_and(|df| !matches_day_filter(c_date, df, schedule.weekend))
+        {
+            // Go back to the end of the previous day (or before the whole
+            // excepted range) and retry
+            let prev_day = parsed_exceptions.skip_backward(c_date)?;
             current = at_time_on_date(prev_day, Time::new(23, 59, 59, 0).unwrap(), &tz)?
                 .checked_add(jiff::Span::new().seconds(1))
                 .map_err(|e| ScheduleError::eval(format!("{e}")))?;
@@ -921,19 +2528,35 @@ pub fn previous_from(schedule: &Schedule, now: &Zoned) -> Result<Option<Zoned>,
 }
 
 /// Compute previous occurrence for the expression part only.
+#[allow(clippy::too_many_arguments)]
 fn prev_expr(
     expr: &ScheduleExpr,
     tz: &TimeZone,
     anchor: &Option<jiff::civil::Date>,
     now: &Zoned,
     during: &[MonthName],
+    week_start: WeekStart,
+    weekend: WeekendDays,
+    excepted: &dyn Fn(Date) -> bool,
 ) -> Result<Option<Zoned>, ScheduleError> {
     match expr {
         ScheduleExpr::DayRepeat {
             interval,
             days,
             times,
-        } => prev_day_repeat(*interval, days, times, tz, anchor, now),
+        } => prev_day_repeat(*interval, days, weekend, times, tz, anchor, now),
+
+        ScheduleExpr::WeekdayRepeat { interval, times } => {
+            prev_weekday_repeat(*interval, times, tz, anchor, now)
+        }
+
+        ScheduleExpr::IntervalRepeat {
+            interval,
+            unit: IntervalUnit::Days,
+            from,
+            day_filter,
+            ..
+        } => prev_interval_days(*interval, from, day_filter, weekend, tz, anchor, now),
 
         ScheduleExpr::IntervalRepeat {
             interval,
@@ -941,60 +2564,35 @@ fn prev_expr(
             from,
             to,
             day_filter,
-        } => prev_interval_repeat(*interval, *unit, from, to, day_filter, tz, now),
+        } => prev_interval_repeat(*interval, *unit, from, to, day_filter, weekend, tz, now),
 
         ScheduleExpr::WeekRepeat {
             interval,
             days,
             times,
-        } => prev_week_repeat(*interval, days, times, tz, anchor, now),
+        } => prev_week_repeat(*interval, days, times, tz, anchor, now, week_start),
 
         ScheduleExpr::MonthRepeat {
             interval,
             target,
             times,
-        } => prev_month_repeat(*interval, target, times, tz, anchor, now, during),
+            ..
+        } => prev_month_repeat(*interval, target, times, tz, anchor, now, during, excepted),
 
         ScheduleExpr::SingleDate { date, times } => prev_single_date(date, times, tz, now),
 
+        ScheduleExpr::MultiDate { dates, times } => prev_multi_date(dates, times, tz, now),
+
         ScheduleExpr::YearRepeat {
             interval,
             target,
             times,
         } => prev_year_repeat(*interval, target, times, tz, anchor, now),
-    }
-}
-
-/// Find the last day of the previous valid during month.
-fn prev_during_month(date: Date, during: &[MonthName]) -> Date {
-    let mut m = date.month();
-    let mut y = date.year();
 
-    // Go back one month first
-    if m == 1 {
-        m = 12;
-        y -= 1;
-    } else {
-        m -= 1;
-    }
-
-    // Find a valid during month going backward
-    for _ in 0..12 {
-        if let Some(month_name) = month_number_to_name(m as u8) {
-            if during.contains(&month_name) {
-                return last_day_of_month(y, m);
-            }
-        }
-        if m == 1 {
-            m = 12;
-            y -= 1;
-        } else {
-            m -= 1;
+        ScheduleExpr::IsoWeekRepeat { weeks, days, times } => {
+            prev_iso_week_repeat(weeks, days, times, tz, now)
         }
     }
-
-    // Fallback (shouldn't happen with valid during clause)
-    date.yesterday().unwrap_or(date)
 }
 
 /// Convert month number (1-12) to MonthName.
@@ -1032,6 +2630,7 @@ fn ordinal_to_n(ord: OrdinalPosition) -> Option<u8> {
 fn next_day_repeat(
     interval: u32,
     days: &DayFilter,
+    weekend: WeekendDays,
     times: &[TimeOfDay],
     tz: &TimeZone,
     anchor: &Option<jiff::civil::Date>,
@@ -1042,7 +2641,7 @@ fn next_day_repeat(
 
     if interval <= 1 {
         // Original behavior for interval=1
-        if matches_day_filter(date, days) {
+        if matches_day_filter(date, days, weekend) {
             if let Some(candidate) = earliest_future_at_times(date, times, tz, now)? {
                 return Ok(Some(candidate));
             }
@@ -1051,7 +2650,7 @@ fn next_day_repeat(
             date = date
                 .tomorrow()
                 .map_err(|e| ScheduleError::eval(format!("{e}")))?;
-            if matches_day_filter(date, days) {
+            if matches_day_filter(date, days, weekend) {
                 if let Some(candidate) = earliest_future_at_times(date, times, tz, now)? {
                     return Ok(Some(candidate));
                 }
@@ -1060,10 +2659,10 @@ fn next_day_repeat(
         return Ok(None);
     }
 
-    // Interval > 1: day intervals only apply to DayFilter::Every.
+    // Interval > 1: the filter narrows the aligned days, skipping (not
+    // moving) any that fail it.
     // O(1) alignment via modular arithmetic: compute the next aligned day
-    // >= today, then check at most 2 dates (today's aligned date if time
-    // hasn't passed, otherwise the next aligned date).
+    // >= today, then check aligned dates in order.
     let anchor_date = anchor.unwrap_or(*EPOCH_DATE);
     let interval_i64 = interval as i64;
 
@@ -1076,11 +2675,14 @@ fn next_day_repeat(
             .map_err(|e| ScheduleError::eval(format!("{e}")))?
     };
 
-    // At most 2 iterations: aligned_date (if time hasn't passed) or next aligned date.
+    // Two aligned dates suffice without a filter (today's may have passed);
+    // eight cover every weekday unless the interval is a multiple of 7.
     let mut cur = aligned_date;
-    for _ in 0..2 {
-        if let Some(candidate) = earliest_future_at_times(cur, times, tz, now)? {
-            return Ok(Some(candidate));
+    for _ in 0..8 {
+        if matches_day_filter(cur, days, weekend) {
+            if let Some(candidate) = earliest_future_at_times(cur, times, tz, now)? {
+                return Ok(Some(candidate));
+            }
         }
         cur = cur
             .checked_add(jiff::Span::new().days(interval_i64))
@@ -1090,31 +2692,44 @@ fn next_day_repeat(
     Ok(None)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn next_interval_repeat(
     interval: u32,
     unit: IntervalUnit,
     from: &TimeOfDay,
     to: &TimeOfDay,
     day_filter: &Option<DayFilter>,
+    weekend: WeekendDays,
     tz: &TimeZone,
     now: &Zoned,
 ) -> Result<Option<Zoned>, ScheduleError> {
     let now_in_tz = now.with_time_zone(tz.clone());
-    let from_t = to_time(from);
-    let to_t = to_time(to);
     let step_minutes: i64 = match unit {
         IntervalUnit::Minutes => interval as i64,
         IntervalUnit::Hours => interval as i64 * 60,
+        IntervalUnit::Days => unreachable!("day-step intervals use next_interval_days"),
     };
 
-    let from_minutes = from_t.hour() as i64 * 60 + from_t.minute() as i64;
-    let to_minutes = to_t.hour() as i64 * 60 + to_t.minute() as i64;
-    let mut date = now_in_tz.date();
+    let from_minutes = from.to_minutes() as i64;
+    let wraps = to < from;
+    // Window end in minutes from the start day's midnight
+    let end_minutes = from_minutes + from.minutes_until(*to) as i64;
+    let now_date = now_in_tz.date();
+    let now_of_day = time_of_day(now_in_tz.time()).to_minutes() as i64;
+
+    // A window that wraps past midnight may have started yesterday
+    let mut date = if wraps {
+        now_date
+            .yesterday()
+            .map_err(|e| ScheduleError::eval(format!("{e}")))?
+    } else {
+        now_date
+    };
 
     // Search up to 400 days forward (covers weekday gaps, etc.)
     for _ in 0..400 {
         if let Some(df) = day_filter {
-            if !matches_day_filter(date, df) {
+            if !matches_day_filter(date, df, weekend) {
                 date = date
                     .tomorrow()
                     .map_err(|e| ScheduleError::eval(format!("{e}")))?;
@@ -1122,12 +2737,9 @@ fn next_interval_repeat(
             }
         }
 
-        // Compute the next valid slot
-        let now_minutes = if date == now_in_tz.date() {
-            now_in_tz.time().hour() as i64 * 60 + now_in_tz.time().minute() as i64
-        } else {
-            -1 // Future day: any slot from `from` is valid
-        };
+        // Minutes from this window's start-day midnight to now
+        // (negative for future days: any slot from `from` is valid)
+        let now_minutes = days_between(date, now_date) * 1440 + now_of_day;
 
         let next_slot = if now_minutes < from_minutes {
             from_minutes
@@ -1136,11 +2748,11 @@ fn next_interval_repeat(
             from_minutes + (elapsed / step_minutes + 1) * step_minutes
         };
 
-        if next_slot <= to_minutes {
-            let h = (next_slot / 60) as i8;
-            let m = (next_slot % 60) as i8;
-            let t = Time::new(h, m, 0, 0).unwrap();
-            let candidate = at_time_on_date(date, t, tz)?;
+        if next_slot <= end_minutes {
+            let slot_date = date
+                .checked_add(jiff::Span::new().days(next_slot / 1440))
+                .map_err(|e| ScheduleError::eval(format!("{e}")))?;
+            let candidate = at_time_on_date(slot_date, slot_time(next_slot), tz)?;
             if candidate > *now {
                 return Ok(Some(candidate));
             }
@@ -1154,59 +2766,99 @@ fn next_interval_repeat(
     Ok(None)
 }
 
-fn next_week_repeat(
+/// Day-step interval windows (`every 3 days from 06:00 to 22:00`) fire once
+/// at `from` on each aligned day. Aligned days failing `day_filter` are
+/// skipped, not shifted.
+fn next_interval_days(
     interval: u32,
-    days: &[Weekday],
-    times: &[TimeOfDay],
+    from: &TimeOfDay,
+    day_filter: &Option<DayFilter>,
+    weekend: WeekendDays,
     tz: &TimeZone,
     anchor: &Option<jiff::civil::Date>,
     now: &Zoned,
 ) -> Result<Option<Zoned>, ScheduleError> {
     let now_in_tz = now.with_time_zone(tz.clone());
-    let anchor_date = anchor.unwrap_or(*EPOCH_MONDAY);
-
     let date = now_in_tz.date();
+    let anchor_date = anchor.unwrap_or(*EPOCH_DATE);
+    let interval_i64 = interval as i64;
 
-    // Sort target DOWs by number for earliest-first matching
-    let mut sorted_days: Vec<Weekday> = days.to_vec();
-    sorted_days.sort_by_key(|d| d.to_jiff().to_monday_one_offset());
-
-    // Find Monday of current week and Monday of anchor week
-    let dow_offset = date.weekday().to_monday_one_offset() as i64 - 1;
-    let current_monday = date
-        .checked_add(jiff::Span::new().days(-dow_offset))
-        .map_err(|e| ScheduleError::eval(format!("{e}")))?;
-
-    let anchor_dow_offset = anchor_date.weekday().to_monday_one_offset() as i64 - 1;
-    let anchor_monday = anchor_date
-        .checked_add(jiff::Span::new().days(-anchor_dow_offset))
-        .map_err(|e| ScheduleError::eval(format!("{e}")))?;
+    let remainder = days_between(anchor_date, date).rem_euclid(interval_i64);
+    let mut cur = if remainder == 0 {
+        date
+    } else {
+        date.checked_add(jiff::Span::new().days(interval_i64 - remainder))
+            .map_err(|e| ScheduleError::eval(format!("{e}")))?
+    };
+
+    // Eight aligned days cover every weekday (plus today's window having
+    // passed) unless the interval is a multiple of 7.
+    for _ in 0..8 {
+        if day_filter
+            .as_ref()
+            .is_none_or(|df| matches_day_filter(cur, df, weekend))
+        {
+            if let Some(candidate) =
+                earliest_future_at_times(cur, std::slice::from_ref(from), tz, now)?
+            {
+                return Ok(Some(candidate));
+            }
+        }
+        cur = cur
+            .checked_add(jiff::Span::new().days(interval_i64))
+            .map_err(|e| ScheduleError::eval(format!("{e}")))?;
+    }
+
+    Ok(None)
+}
+
+fn next_week_repeat(
+    interval: u32,
+    days: &[Weekday],
+    times: &[TimeOfDay],
+    tz: &TimeZone,
+    anchor: &Option<jiff::civil::Date>,
+    now: &Zoned,
+    week_start: WeekStart,
+) -> Result<Option<Zoned>, ScheduleError> {
+    let now_in_tz = now.with_time_zone(tz.clone());
+    let anchor_date = anchor.unwrap_or(*EPOCH_MONDAY);
+
+    let date = now_in_tz.date();
+
+    // Sort target DOWs by position in the week for earliest-first matching
+    let mut sorted_days: Vec<Weekday> = days.to_vec();
+    sorted_days.sort_by_key(|d| days_into_week(d.to_jiff(), week_start));
+
+    // Find the start of the current week and of the anchor week
+    let current_week = week_start_of(date, week_start)?;
+    let anchor_week = week_start_of(anchor_date, week_start)?;
 
-    // O(1) alignment: compute the first aligned Monday >= current Monday,
+    // O(1) alignment: compute the first aligned week start >= current week start,
     // then check at most 2 aligned weeks (current aligned week if any
     // target day's time hasn't passed, otherwise the next aligned week).
-    let weeks_since_anchor = weeks_between(anchor_monday, current_monday);
-    let first_aligned_monday = if weeks_since_anchor < 0 {
-        // Current week is before anchor week, so anchor_monday is the first aligned week
-        anchor_monday
+    let weeks_since_anchor = weeks_between(anchor_week, current_week);
+    let first_aligned_week = if weeks_since_anchor < 0 {
+        // Current week is before anchor week, so anchor_week is the first aligned week
+        anchor_week
     } else {
         let remainder = weeks_since_anchor % (interval as i64);
         if remainder == 0 {
-            current_monday
+            current_week
         } else {
-            current_monday
+            current_week
                 .checked_add(jiff::Span::new().days((interval as i64 - remainder) * 7))
                 .map_err(|e| ScheduleError::eval(format!("{e}")))?
         }
     };
 
-    let mut cur_monday = first_aligned_monday;
+    let mut cur_week = first_aligned_week;
 
     for _ in 0..2 {
         // Aligned week — try each target DOW
         for wd in &sorted_days {
-            let day_offset = wd.to_jiff().to_monday_one_offset() as i64 - 1;
-            let target_date = cur_monday
+            let day_offset = days_into_week(wd.to_jiff(), week_start);
+            let target_date = cur_week
                 .checked_add(jiff::Span::new().days(day_offset))
                 .map_err(|e| ScheduleError::eval(format!("{e}")))?;
             if let Some(candidate) = earliest_future_at_times(target_date, times, tz, now)? {
@@ -1216,7 +2868,7 @@ fn next_week_repeat(
 
         // Advance to next aligned week
         let skip_weeks = interval as i64;
-        cur_monday = cur_monday
+        cur_week = cur_week
             .checked_add(jiff::Span::new().days(skip_weeks * 7))
             .map_err(|e| ScheduleError::eval(format!("{e}")))?;
     }
@@ -1224,6 +2876,7 @@ fn next_week_repeat(
     Ok(None)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn next_month_repeat(
     interval: u32,
     target: &MonthTarget,
@@ -1232,11 +2885,20 @@ fn next_month_repeat(
     anchor: &Option<jiff::civil::Date>,
     now: &Zoned,
     during: &[MonthName],
+    excepted: &dyn Fn(Date) -> bool,
 ) -> Result<Option<Zoned>, ScheduleError> {
     let now_in_tz = now.with_time_zone(tz.clone());
 
     let mut year = now_in_tz.date().year();
     let mut month = now_in_tz.date().month();
+    // Last month's target may have spilled into this one (jan 31 -> feb 2)
+    if target_spills(target) {
+        month -= 1;
+        if month < 1 {
+            month = 12;
+            year -= 1;
+        }
+    }
 
     let anchor_date = anchor.unwrap_or(*EPOCH_DATE);
     let max_iter = if interval > 1 {
@@ -1247,14 +2909,7 @@ fn next_month_repeat(
 
     // For NearestWeekday with direction, we need to apply the during filter here
     // because the result can cross month boundaries
-    let apply_during_filter = !during.is_empty()
-        && matches!(
-            target,
-            MonthTarget::NearestWeekday {
-                direction: Some(_),
-                ..
-            }
-        );
+    let apply_during_filter = !during.is_empty() && target_spills(target);
 
     // Search forward
     for _ in 0..max_iter {
@@ -1281,41 +2936,7 @@ fn next_month_repeat(
                 continue;
             }
         }
-        let date_candidates = match target {
-            MonthTarget::Days(_) => {
-                let expanded = target.expand_days();
-                let mut c = Vec::new();
-                for day_num in expanded {
-                    // Skip if this month doesn't have this day
-                    let last = last_day_of_month(year, month);
-                    if (day_num as i8) <= last.day() {
-                        if let Ok(date) = Date::new(year, month, day_num as i8) {
-                            c.push(date);
-                        }
-                    }
-                }
-                c
-            }
-            MonthTarget::LastDay => {
-                vec![last_day_of_month(year, month)]
-            }
-            MonthTarget::LastWeekday => {
-                vec![last_weekday_of_month(year, month)]
-            }
-            MonthTarget::NearestWeekday { day, direction } => {
-                match nearest_weekday(year, month, *day, *direction) {
-                    Some(d) => vec![d],
-                    None => vec![],
-                }
-            }
-            MonthTarget::OrdinalWeekday { ordinal, weekday } => match ordinal {
-                OrdinalPosition::Last => vec![last_weekday_in_month(year, month, *weekday)],
-                _ => ordinal_to_n(*ordinal)
-                    .and_then(|n| nth_weekday_of_month(year, month, *weekday, n))
-                    .into_iter()
-                    .collect(),
-            },
-        };
+        let date_candidates = month_target_dates(target, year, month, excepted);
 
         // For each candidate date, try all times and find the earliest future one
         let mut best: Option<Zoned> = None;
@@ -1343,6 +2964,151 @@ fn next_month_repeat(
     Ok(None)
 }
 
+/// Whether a monthly target can land outside the month it belongs to.
+/// Directional nearest-weekday targets spill into the neighbouring months.
+fn target_spills(target: &MonthTarget) -> bool {
+    matches!(
+        target,
+        MonthTarget::NearestWeekday {
+            direction: Some(_),
+            ..
+        }
+    )
+}
+
+/// Whether `during` applies to the month a target comes from rather than the
+/// month its date lands in, as for targets that spill into the next month.
+fn during_by_source_month(expr: &ScheduleExpr) -> bool {
+    matches!(expr, ScheduleExpr::MonthRepeat { target, .. } if target_spills(target))
+}
+
+/// The first days of the months whose `target` can land in the month of
+/// `date`, keeping only months on the interval. For targets that spill into
+/// the neighbouring months, those are included and `during` filters them.
+fn month_repeat_sources(
+    schedule: &Schedule,
+    target: &MonthTarget,
+    interval: u32,
+    date: Date,
+    anchor: Option<Date>,
+) -> Result<Vec<Date>, ScheduleError> {
+    let spills = target_spills(target);
+    let offsets: &[i32] = if spills { &[-1, 0, 1] } else { &[0] };
+    let anchor_date = anchor.unwrap_or(*EPOCH_DATE);
+    let mut sources = Vec::with_capacity(offsets.len());
+    for &offset in offsets {
+        let source = date
+            .first_of_month()
+            .checked_add(jiff::Span::new().months(offset))
+            .map_err(|e| ScheduleError::eval(format!("{e}")))?;
+        let month_offset = months_between_ym(anchor_date, source);
+        let aligned = interval <= 1 || (month_offset >= 0 && month_offset % (interval as i64) == 0);
+        let in_during = !spills
+            || schedule.during.is_empty()
+            || schedule
+                .during_months()
+                .iter()
+                .any(|m| m.number() == source.month() as u8);
+        if aligned && in_during {
+            sources.push(source);
+        }
+    }
+    Ok(sources)
+}
+
+/// Expand a monthly target into its dates for one month, in ascending order.
+/// Business-day targets skip over days `excepted` removes.
+fn month_target_dates(
+    target: &MonthTarget,
+    year: i16,
+    month: i8,
+    excepted: &dyn Fn(Date) -> bool,
+) -> Vec<Date> {
+    match target {
+        MonthTarget::Days(_) => {
+            let last = last_day_of_month(year, month);
+            let mut dates: Vec<Date> = target
+                .expand_days()
+                .into_iter()
+                .filter(|&d| (d as i8) <= last.day())
+                .filter_map(|d| Date::new(year, month, d as i8).ok())
+                .collect();
+            dates.sort();
+            dates
+        }
+        MonthTarget::LastDay => vec![last_day_of_month(year, month)],
+        MonthTarget::LastNDays(n) => {
+            let last = last_day_of_month(year, month).day();
+            let first = (last - *n as i8 + 1).max(1);
+            (first..=last)
+                .map(|d| Date::new(year, month, d).unwrap())
+                .collect()
+        }
+        MonthTarget::LastWeekday => vec![last_weekday_of_month(year, month)],
+        MonthTarget::NearestWeekday { day, direction } => {
+            nearest_weekday(year, month, *day, *direction)
+                .into_iter()
+                .collect()
+        }
+        MonthTarget::OrdinalWeekday { ordinal, weekday } => {
+            ordinal_weekday_in_month(year, month, *ordinal, *weekday)
+                .into_iter()
+                .collect()
+        }
+        MonthTarget::OrdinalWeekdays { ordinals, weekday } => {
+            // "fourth and last" can land on the same day
+            let mut dates: Vec<Date> = ordinals
+                .iter()
+                .filter_map(|&ordinal| ordinal_weekday_in_month(year, month, ordinal, *weekday))
+                .collect();
+            dates.sort();
+            dates.dedup();
+            dates
+        }
+        MonthTarget::BusinessDay(n) => nth_business_day(year, month, *n, excepted)
+            .into_iter()
+            .collect(),
+    }
+}
+
+/// The `ordinal` `weekday` of a month, if that month has one.
+fn ordinal_weekday_in_month(
+    year: i16,
+    month: i8,
+    ordinal: OrdinalPosition,
+    weekday: Weekday,
+) -> Option<Date> {
+    match ordinal {
+        OrdinalPosition::Last => Some(last_weekday_in_month(year, month, weekday)),
+        _ => ordinal_to_n(ordinal).and_then(|n| nth_weekday_of_month(year, month, weekday, n)),
+    }
+}
+
+/// Resolve a yearly target to its date in `year`, if that year has one.
+fn year_target_date(target: &YearTarget, year: i16) -> Option<Date> {
+    match target {
+        YearTarget::Date { month, day } | YearTarget::DayOfMonth { day, month } => {
+            Date::new(year, month.number() as i8, *day as i8).ok()
+        }
+        YearTarget::OrdinalWeekday {
+            ordinal,
+            weekday,
+            month,
+        } => {
+            let m = month.number() as i8;
+            match ordinal {
+                OrdinalPosition::Last => Some(last_weekday_in_month(year, m, *weekday)),
+                _ => {
+                    ordinal_to_n(*ordinal).and_then(|n| nth_weekday_of_month(year, m, *weekday, n))
+                }
+            }
+        }
+        YearTarget::LastWeekday { month } => {
+            Some(last_weekday_of_month(year, month.number() as i8))
+        }
+    }
+}
+
 fn next_single_date(
     date_spec: &DateSpec,
     times: &[TimeOfDay],
@@ -1371,7 +3137,157 @@ fn next_single_date(
             }
             Ok(None)
         }
+        DateSpec::NextWeekday(day) => {
+            // Today if a time is still ahead, otherwise within the next week
+            let today = now_in_tz.date();
+            for offset in 0..=7 {
+                let date = today
+                    .checked_add(jiff::Span::new().days(offset))
+                    .map_err(|e| ScheduleError::eval(format!("{e}")))?;
+                if Weekday::from_jiff(date.weekday()) != *day {
+                    continue;
+                }
+                if let Some(candidate) = earliest_future_at_times(date, times, tz, now)? {
+                    return Ok(Some(candidate));
+                }
+            }
+            Ok(None)
+        }
+    }
+}
+
+/// Check whether `date` is the date a [`DateSpec`] names.
+fn matches_date_spec(date: Date, spec: &DateSpec) -> Result<bool, ScheduleError> {
+    match spec {
+        DateSpec::Iso(s) => {
+            let target: Date = s
+                .parse()
+                .map_err(|e| ScheduleError::eval(format!("invalid date '{s}': {e}")))?;
+            Ok(date == target)
+        }
+        DateSpec::Named { month, day } => {
+            Ok(date.month() == month.number() as i8 && date.day() == *day as i8)
+        }
+        // Date-level checks reject it up front; any day with that weekday can be it
+        DateSpec::NextWeekday(day) => Ok(Weekday::from_jiff(date.weekday()) == *day),
+    }
+}
+
+/// The date a [`DateSpec`] names within the given month, if any. An
+/// `on <weekday>` date names none: [`dates_in_month`] rejects it up front.
+fn date_spec_in_month(spec: &DateSpec, year: i16, month: i8) -> Result<Vec<Date>, ScheduleError> {
+    let target = match spec {
+        DateSpec::Iso(s) => Some(
+            s.parse::<Date>()
+                .map_err(|e| ScheduleError::eval(format!("invalid date '{s}': {e}")))?,
+        ),
+        DateSpec::Named { month: m, day } => Date::new(year, m.number() as i8, *day as i8).ok(),
+        DateSpec::NextWeekday(_) => None,
+    };
+    Ok(target
+        .into_iter()
+        .filter(|d| d.year() == year && d.month() == month)
+        .collect())
+}
+
+/// Earliest next occurrence across several dates.
+fn next_multi_date(
+    dates: &[DateSpec],
+    times: &[TimeOfDay],
+    tz: &TimeZone,
+    now: &Zoned,
+) -> Result<Option<Zoned>, ScheduleError> {
+    let mut best: Option<Zoned> = None;
+    for date in dates {
+        if let Some(candidate) = next_single_date(date, times, tz, now)? {
+            if best.as_ref().is_none_or(|b| candidate < *b) {
+                best = Some(candidate);
+            }
+        }
+    }
+    Ok(best)
+}
+
+/// Latest previous occurrence across several dates.
+fn prev_multi_date(
+    dates: &[DateSpec],
+    times: &[TimeOfDay],
+    tz: &TimeZone,
+    now: &Zoned,
+) -> Result<Option<Zoned>, ScheduleError> {
+    let mut best: Option<Zoned> = None;
+    for date in dates {
+        if let Some(candidate) = prev_single_date(date, times, tz, now)? {
+            if best.as_ref().is_none_or(|b| candidate > *b) {
+                best = Some(candidate);
+            }
+        }
+    }
+    Ok(best)
+}
+
+/// Check whether `date` falls on one of `days` in one of the ISO `weeks`.
+fn matches_iso_week(date: Date, weeks: &[u8], days: &[Weekday]) -> bool {
+    weeks.contains(&(date.iso_week_date().week() as u8))
+        && days.contains(&Weekday::from_jiff(date.weekday()))
+}
+
+/// Dates in ISO week-numbering `year` on one of `days` in one of `weeks`,
+/// ascending. Week 53 yields nothing in years with only 52 weeks.
+fn iso_week_dates(year: i16, weeks: &[u8], days: &[Weekday]) -> Vec<Date> {
+    let mut dates: Vec<Date> = weeks
+        .iter()
+        .flat_map(|&week| {
+            days.iter().filter_map(move |day| {
+                jiff::civil::ISOWeekDate::new(year, week as i8, day.to_jiff())
+                    .ok()
+                    .map(|iso| iso.date())
+            })
+        })
+        .collect();
+    dates.sort();
+    dates.dedup();
+    dates
+}
+
+/// Long ISO years (with a week 53) can be up to 6 years apart, so a search
+/// covering 7 ISO years always finds a match.
+const ISO_YEAR_HORIZON: i16 = 7;
+
+fn next_iso_week_repeat(
+    weeks: &[u8],
+    days: &[Weekday],
+    times: &[TimeOfDay],
+    tz: &TimeZone,
+    now: &Zoned,
+) -> Result<Option<Zoned>, ScheduleError> {
+    let start_year = now.with_time_zone(tz.clone()).date().iso_week_date().year();
+    for year in start_year..start_year.saturating_add(ISO_YEAR_HORIZON) {
+        for date in iso_week_dates(year, weeks, days) {
+            if let Some(candidate) = earliest_future_at_times(date, times, tz, now)? {
+                return Ok(Some(candidate));
+            }
+        }
+    }
+    Ok(None)
+}
+
+fn prev_iso_week_repeat(
+    weeks: &[u8],
+    days: &[Weekday],
+    times: &[TimeOfDay],
+    tz: &TimeZone,
+    now: &Zoned,
+) -> Result<Option<Zoned>, ScheduleError> {
+    let start_year = now.with_time_zone(tz.clone()).date().iso_week_date().year();
+    for year in (start_year.saturating_sub(ISO_YEAR_HORIZON - 1)..=start_year).rev() {
+        for date in iso_week_dates(year, weeks, days).into_iter().rev() {
+            if let Some(candidate) = latest_past_at_times(date, times, tz, now)? {
+                return Ok(Some(candidate));
+            }
+        }
     }
+    Ok(None)
 }
 
 fn next_year_repeat(
@@ -1386,7 +3302,12 @@ fn next_year_repeat(
     let start_year = now_in_tz.date().year();
     let anchor_year = anchor.unwrap_or(*EPOCH_DATE).year();
 
-    let max_iter = if interval > 1 { 8 * interval as i16 } else { 8 };
+    // The start year itself may be past the target, and feb 29 can be 8 years apart (2096, 2104)
+    let max_iter = if interval > 1 {
+        8 * interval as i16 + 1
+    } else {
+        9
+    };
 
     for y in 0..max_iter {
         let year = start_year + y;
@@ -1399,29 +3320,7 @@ fn next_year_repeat(
             }
         }
 
-        let target_date = match target {
-            YearTarget::Date { month, day } => {
-                Date::new(year, month.number() as i8, *day as i8).ok()
-            }
-            YearTarget::OrdinalWeekday {
-                ordinal,
-                weekday,
-                month,
-            } => {
-                let m = month.number() as i8;
-                match ordinal {
-                    OrdinalPosition::Last => Some(last_weekday_in_month(year, m, *weekday)),
-                    _ => ordinal_to_n(*ordinal)
-                        .and_then(|n| nth_weekday_of_month(year, m, *weekday, n)),
-                }
-            }
-            YearTarget::DayOfMonth { day, month } => {
-                Date::new(year, month.number() as i8, *day as i8).ok()
-            }
-            YearTarget::LastWeekday { month } => {
-                Some(last_weekday_of_month(year, month.number() as i8))
-            }
-        };
+        let target_date = year_target_date(target, year);
 
         if let Some(date) = target_date {
             if let Some(candidate) = earliest_future_at_times(date, times, tz, now)? {
@@ -1435,9 +3334,66 @@ fn next_year_repeat(
 
 // --- Prev helpers for each schedule variant (mirror of next_* functions) ---
 
+/// Next occurrence of `every N weekdays`: today if it is an aligned business
+/// day with a time still ahead, otherwise the next aligned business day.
+fn next_weekday_repeat(
+    interval: u32,
+    times: &[TimeOfDay],
+    tz: &TimeZone,
+    anchor: &Option<jiff::civil::Date>,
+    now: &Zoned,
+) -> Result<Option<Zoned>, ScheduleError> {
+    let today = now.with_time_zone(tz.clone()).date();
+    let anchor_index = business_day_index(anchor.unwrap_or(*EPOCH_DATE));
+    let interval = interval as i64;
+    let today_index = business_day_index(today);
+    let is_weekday = matches_day_filter(today, &DayFilter::Weekday, WeekendDays::default());
+
+    if is_weekday && (today_index - anchor_index).rem_euclid(interval) == 0 {
+        if let Some(candidate) = earliest_future_at_times(today, times, tz, now)? {
+            return Ok(Some(candidate));
+        }
+    }
+    // A weekend already shares the following Monday's index
+    let after = if is_weekday {
+        today_index + 1
+    } else {
+        today_index
+    };
+    let index = after + (anchor_index - after).rem_euclid(interval);
+    earliest_future_at_times(business_day_date(index)?, times, tz, now)
+}
+
+/// Previous occurrence of `every N weekdays`, mirroring [`next_weekday_repeat`].
+fn prev_weekday_repeat(
+    interval: u32,
+    times: &[TimeOfDay],
+    tz: &TimeZone,
+    anchor: &Option<jiff::civil::Date>,
+    now: &Zoned,
+) -> Result<Option<Zoned>, ScheduleError> {
+    let today = now.with_time_zone(tz.clone()).date();
+    let anchor_index = business_day_index(anchor.unwrap_or(*EPOCH_DATE));
+    let interval = interval as i64;
+    let today_index = business_day_index(today);
+
+    if matches_day_filter(today, &DayFilter::Weekday, WeekendDays::default())
+        && (today_index - anchor_index).rem_euclid(interval) == 0
+    {
+        if let Some(candidate) = latest_past_at_times(today, times, tz, now)? {
+            return Ok(Some(candidate));
+        }
+    }
+    // On a weekend the index is the following Monday's, so one back is Friday
+    let before = today_index - 1;
+    let index = before - (before - anchor_index).rem_euclid(interval);
+    latest_at_times(business_day_date(index)?, times, tz)
+}
+
 fn prev_day_repeat(
     interval: u32,
     days: &DayFilter,
+    weekend: WeekendDays,
     times: &[TimeOfDay],
     tz: &TimeZone,
     anchor: &Option<jiff::civil::Date>,
@@ -1448,7 +3404,7 @@ fn prev_day_repeat(
 
     if interval <= 1 {
         // Check today first (for times that have already passed)
-        if matches_day_filter(date, days) {
+        if matches_day_filter(date, days, weekend) {
             if let Some(candidate) = latest_past_at_times(date, times, tz, now)? {
                 return Ok(Some(candidate));
             }
@@ -1458,7 +3414,7 @@ fn prev_day_repeat(
             date = date
                 .yesterday()
                 .map_err(|e| ScheduleError::eval(format!("{e}")))?;
-            if matches_day_filter(date, days) {
+            if matches_day_filter(date, days, weekend) {
                 if let Some(candidate) = latest_at_times(date, times, tz)? {
                     return Ok(Some(candidate));
                 }
@@ -1480,56 +3436,52 @@ fn prev_day_repeat(
             .map_err(|e| ScheduleError::eval(format!("{e}")))?
     };
 
-    // Check aligned_date (if time hasn't passed) or previous aligned date
+    // Check aligned_date (if time has passed) or previous aligned dates,
+    // skipping any the filter rejects
     let mut cur = aligned_date;
-    for _ in 0..2 {
-        if let Some(candidate) = latest_past_at_times(cur, times, tz, now)? {
-            return Ok(Some(candidate));
-        }
-        // If we're on aligned_date but times haven't passed, go to previous aligned
-        if let Some(candidate) = latest_at_times(cur, times, tz)? {
-            if candidate < *now {
+    for _ in 0..8 {
+        if matches_day_filter(cur, days, weekend) {
+            if let Some(candidate) = latest_past_at_times(cur, times, tz, now)? {
                 return Ok(Some(candidate));
             }
         }
         cur = cur
             .checked_add(jiff::Span::new().days(-interval_i64))
             .map_err(|e| ScheduleError::eval(format!("{e}")))?;
-        if let Some(candidate) = latest_at_times(cur, times, tz)? {
-            return Ok(Some(candidate));
-        }
     }
 
     Ok(None)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn prev_interval_repeat(
     interval: u32,
     unit: IntervalUnit,
     from: &TimeOfDay,
     to: &TimeOfDay,
     day_filter: &Option<DayFilter>,
+    weekend: WeekendDays,
     tz: &TimeZone,
     now: &Zoned,
 ) -> Result<Option<Zoned>, ScheduleError> {
     let now_in_tz = now.with_time_zone(tz.clone());
-    let from_t = to_time(from);
-    let to_t = to_time(to);
     let step_minutes: i64 = match unit {
         IntervalUnit::Minutes => interval as i64,
         IntervalUnit::Hours => interval as i64 * 60,
+        IntervalUnit::Days => unreachable!("day-step intervals use prev_interval_days"),
     };
 
-    let mut date = now_in_tz.date();
-    let now_time = now_in_tz.time();
-    let now_minutes = now_time.hour() as i64 * 60 + now_time.minute() as i64;
-    let from_minutes = from_t.hour() as i64 * 60 + from_t.minute() as i64;
-    let to_minutes = to_t.hour() as i64 * 60 + to_t.minute() as i64;
+    let now_date = now_in_tz.date();
+    let mut date = now_date;
+    let now_of_day = time_of_day(now_in_tz.time()).to_minutes() as i64;
+    let from_minutes = from.to_minutes() as i64;
+    // Window end in minutes from the start day's midnight (past 1440 when it wraps)
+    let end_minutes = from_minutes + from.minutes_until(*to) as i64;
 
     // Search up to 8 days back
     for _ in 0..8 {
         if let Some(ref df) = day_filter {
-            if !matches_day_filter(date, df) {
+            if !matches_day_filter(date, df, weekend) {
                 date = date
                     .yesterday()
                     .map_err(|e| ScheduleError::eval(format!("{e}")))?;
@@ -1537,35 +3489,22 @@ fn prev_interval_repeat(
             }
         }
 
-        // Find the last slot on this day that is before now
-        let search_until = if date == now_in_tz.date() {
-            // On the same day, search until now
-            now_minutes.min(to_minutes)
-        } else {
-            // On a previous day, search until end of window
-            to_minutes
-        };
+        // Minutes from this window's start-day midnight to now
+        let now_minutes = days_between(date, now_date) * 1440 + now_of_day;
+        let search_until = now_minutes.min(end_minutes);
 
         if search_until >= from_minutes {
-            // Calculate the last slot <= search_until
-            let slots_in_range = (search_until - from_minutes) / step_minutes;
-            let last_slot_minutes = from_minutes + slots_in_range * step_minutes;
-
-            // On the same day, we need strictly before now
-            if date == now_in_tz.date() && last_slot_minutes >= now_minutes {
-                // Go back one step
-                let prev_slot = last_slot_minutes - step_minutes;
-                if prev_slot >= from_minutes {
-                    let h = (prev_slot / 60) as i8;
-                    let m = (prev_slot % 60) as i8;
-                    let t = Time::new(h, m, 0, 0).unwrap();
-                    return at_time_on_date(date, t, tz).map(Some);
-                }
-            } else if last_slot_minutes >= from_minutes {
-                let h = (last_slot_minutes / 60) as i8;
-                let m = (last_slot_minutes % 60) as i8;
-                let t = Time::new(h, m, 0, 0).unwrap();
-                return at_time_on_date(date, t, tz).map(Some);
+            // Last slot <= search_until, stepping back once if it is not strictly before now
+            let mut slot =
+                from_minutes + (search_until - from_minutes) / step_minutes * step_minutes;
+            if slot >= now_minutes {
+                slot -= step_minutes;
+            }
+            if slot >= from_minutes {
+                let slot_date = date
+                    .checked_add(jiff::Span::new().days(slot / 1440))
+                    .map_err(|e| ScheduleError::eval(format!("{e}")))?;
+                return at_time_on_date(slot_date, slot_time(slot), tz).map(Some);
             }
         }
 
@@ -1577,6 +3516,43 @@ fn prev_interval_repeat(
     Ok(None)
 }
 
+fn prev_interval_days(
+    interval: u32,
+    from: &TimeOfDay,
+    day_filter: &Option<DayFilter>,
+    weekend: WeekendDays,
+    tz: &TimeZone,
+    anchor: &Option<jiff::civil::Date>,
+    now: &Zoned,
+) -> Result<Option<Zoned>, ScheduleError> {
+    let now_in_tz = now.with_time_zone(tz.clone());
+    let date = now_in_tz.date();
+    let anchor_date = anchor.unwrap_or(*EPOCH_DATE);
+    let interval_i64 = interval as i64;
+
+    let remainder = days_between(anchor_date, date).rem_euclid(interval_i64);
+    let mut cur = date
+        .checked_add(jiff::Span::new().days(-remainder))
+        .map_err(|e| ScheduleError::eval(format!("{e}")))?;
+
+    for _ in 0..8 {
+        if day_filter
+            .as_ref()
+            .is_none_or(|df| matches_day_filter(cur, df, weekend))
+        {
+            if let Some(candidate) = latest_past_at_times(cur, std::slice::from_ref(from), tz, now)?
+            {
+                return Ok(Some(candidate));
+            }
+        }
+        cur = cur
+            .checked_add(jiff::Span::new().days(-interval_i64))
+            .map_err(|e| ScheduleError::eval(format!("{e}")))?;
+    }
+
+    Ok(None)
+}
+
 fn prev_week_repeat(
     interval: u32,
     days: &[Weekday],
@@ -1584,38 +3560,31 @@ fn prev_week_repeat(
     tz: &TimeZone,
     anchor: &Option<jiff::civil::Date>,
     now: &Zoned,
+    week_start: WeekStart,
 ) -> Result<Option<Zoned>, ScheduleError> {
     let now_in_tz = now.with_time_zone(tz.clone());
     let date = now_in_tz.date();
 
-    // Get the Monday of the current week
-    let days_since_monday = (date.weekday().to_monday_zero_offset()) as i64;
-    let current_monday = date
-        .checked_add(jiff::Span::new().days(-days_since_monday))
-        .map_err(|e| ScheduleError::eval(format!("{e}")))?;
-
+    // Get the start of the current week and of the anchor week
+    let current_week = week_start_of(date, week_start)?;
     let anchor_date = anchor.unwrap_or(*EPOCH_MONDAY);
-    // Get Monday of anchor week
-    let anchor_days_since_monday = anchor_date.weekday().to_monday_zero_offset() as i64;
-    let anchor_monday = anchor_date
-        .checked_add(jiff::Span::new().days(-anchor_days_since_monday))
-        .map_err(|e| ScheduleError::eval(format!("{e}")))?;
+    let anchor_week = week_start_of(anchor_date, week_start)?;
 
     let interval_i64 = interval as i64;
 
     // First check current week if it's aligned
-    let weeks = weeks_between(anchor_monday, current_monday);
+    let weeks = weeks_between(anchor_week, current_week);
     let aligned = weeks >= 0 && weeks % interval_i64 == 0;
 
     if aligned {
         // Check days in this week up to and including today (in reverse order)
         let mut sorted_days = days.to_vec();
-        sorted_days.sort_by_key(|d| d.to_jiff().to_monday_zero_offset());
+        sorted_days.sort_by_key(|d| days_into_week(d.to_jiff(), week_start));
         sorted_days.reverse();
 
         for wd in &sorted_days {
-            let day_offset = wd.to_jiff().to_monday_zero_offset() as i64;
-            let target_date = current_monday
+            let day_offset = days_into_week(wd.to_jiff(), week_start);
+            let target_date = current_week
                 .checked_add(jiff::Span::new().days(day_offset))
                 .map_err(|e| ScheduleError::eval(format!("{e}")))?;
 
@@ -1634,32 +3603,32 @@ fn prev_week_repeat(
     }
 
     // Go back to previous aligned weeks
-    let mut check_monday = if aligned {
-        current_monday
+    let mut check_week = if aligned {
+        current_week
             .checked_add(jiff::Span::new().days(-interval_i64 * 7))
             .map_err(|e| ScheduleError::eval(format!("{e}")))?
     } else {
-        // Find the most recent aligned Monday
+        // Find the most recent aligned week start
         let remainder = weeks.rem_euclid(interval_i64);
-        current_monday
+        current_week
             .checked_add(jiff::Span::new().days(-remainder * 7))
             .map_err(|e| ScheduleError::eval(format!("{e}")))?
     };
 
     for _ in 0..54 {
-        let wks = weeks_between(anchor_monday, check_monday);
+        let wks = weeks_between(anchor_week, check_week);
         if wks < 0 {
             return Ok(None); // Before anchor
         }
 
         // Check all days in this week (reverse order for latest first)
         let mut sorted_days = days.to_vec();
-        sorted_days.sort_by_key(|d| d.to_jiff().to_monday_zero_offset());
+        sorted_days.sort_by_key(|d| days_into_week(d.to_jiff(), week_start));
         sorted_days.reverse();
 
         for wd in &sorted_days {
-            let day_offset = wd.to_jiff().to_monday_zero_offset() as i64;
-            let target_date = check_monday
+            let day_offset = days_into_week(wd.to_jiff(), week_start);
+            let target_date = check_week
                 .checked_add(jiff::Span::new().days(day_offset))
                 .map_err(|e| ScheduleError::eval(format!("{e}")))?;
 
@@ -1670,7 +3639,7 @@ fn prev_week_repeat(
             }
         }
 
-        check_monday = check_monday
+        check_week = check_week
             .checked_add(jiff::Span::new().days(-interval_i64 * 7))
             .map_err(|e| ScheduleError::eval(format!("{e}")))?;
     }
@@ -1678,6 +3647,7 @@ fn prev_week_repeat(
     Ok(None)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn prev_month_repeat(
     interval: u32,
     target: &MonthTarget,
@@ -1686,6 +3656,7 @@ fn prev_month_repeat(
     anchor: &Option<jiff::civil::Date>,
     now: &Zoned,
     _during: &[MonthName],
+    excepted: &dyn Fn(Date) -> bool,
 ) -> Result<Option<Zoned>, ScheduleError> {
     let now_in_tz = now.with_time_zone(tz.clone());
     let start_date = now_in_tz.date();
@@ -1712,37 +3683,8 @@ fn prev_month_repeat(
             }
         }
 
-        let target_dates = match target {
-            MonthTarget::Days(_) => {
-                let expanded = target.expand_days();
-                let mut dates: Vec<Date> = expanded
-                    .iter()
-                    .filter_map(|&d| Date::new(year, month, d as i8).ok())
-                    .collect();
-                dates.sort();
-                dates.reverse(); // Latest first
-                dates
-            }
-            MonthTarget::LastDay => {
-                vec![last_day_of_month(year, month)]
-            }
-            MonthTarget::LastWeekday => {
-                vec![last_weekday_of_month(year, month)]
-            }
-            MonthTarget::NearestWeekday { day, direction } => {
-                match nearest_weekday(year, month, *day, *direction) {
-                    Some(d) => vec![d],
-                    None => vec![],
-                }
-            }
-            MonthTarget::OrdinalWeekday { ordinal, weekday } => match ordinal {
-                OrdinalPosition::Last => vec![last_weekday_in_month(year, month, *weekday)],
-                _ => ordinal_to_n(*ordinal)
-                    .and_then(|n| nth_weekday_of_month(year, month, *weekday, n))
-                    .into_iter()
-                    .collect(),
-            },
-        };
+        let mut target_dates = month_target_dates(target, year, month, excepted);
+        target_dates.reverse(); // Latest first
 
         for date in target_dates {
             if date > start_date {
@@ -1783,6 +3725,8 @@ fn prev_single_date(
     let now_date = now_in_tz.date();
 
     let target_date = match date_spec {
+        // `previous_from` pins `on <weekday>` to an ISO date first
+        DateSpec::NextWeekday(_) => return Ok(None),
         DateSpec::Iso(s) => s
             .parse::<Date>()
             .map_err(|e| ScheduleError::eval(format!("invalid date '{s}': {e}")))?,
@@ -1838,7 +3782,12 @@ fn prev_year_repeat(
     let start_date = now_in_tz.date();
     let anchor_year = anchor.unwrap_or(*EPOCH_DATE).year();
 
-    let max_iter = if interval > 1 { 8 * interval as i16 } else { 8 };
+    // The start year itself may be past the target, and feb 29 can be 8 years apart (2096, 2104)
+    let max_iter = if interval > 1 {
+        8 * interval as i16 + 1
+    } else {
+        9
+    };
 
     for y in 0..max_iter {
         let year = start_year - y;
@@ -1851,33 +3800,11 @@ fn prev_year_repeat(
             }
         }
 
-        let target_date = match target {
-            YearTarget::Date { month, day } => {
-                Date::new(year, month.number() as i8, *day as i8).ok()
-            }
-            YearTarget::OrdinalWeekday {
-                ordinal,
-                weekday,
-                month,
-            } => {
-                let m = month.number() as i8;
-                match ordinal {
-                    OrdinalPosition::Last => Some(last_weekday_in_month(year, m, *weekday)),
-                    _ => ordinal_to_n(*ordinal)
-                        .and_then(|n| nth_weekday_of_month(year, m, *weekday, n)),
-                }
-            }
-            YearTarget::DayOfMonth { day, month } => {
-                Date::new(year, month.number() as i8, *day as i8).ok()
-            }
-            YearTarget::LastWeekday { month } => {
-                Some(last_weekday_of_month(year, month.number() as i8))
-            }
-        };
-
-        if let Some(date) = target_date {
-            if date > start_date {
-                continue; // Future date
+        let target_date = year_target_date(target, year);
+
+        if let Some(date) = target_date {
+            if date > start_date {
+                continue; // Future date
             }
             if date == start_date {
                 if let Some(candidate) = latest_past_at_times(date, times, tz, now)? {
@@ -1918,177 +3845,1401 @@ fn latest_at_times(
     times: &[TimeOfDay],
     tz: &TimeZone,
 ) -> Result<Option<Zoned>, ScheduleError> {
-    let mut sorted_times = times.to_vec();
-    sorted_times.sort_by_key(|t| (t.hour, t.minute));
+    times
+        .iter()
+        .max()
+        .map(|tod| at_time_on_date(date, to_time(tod), tz))
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse;
+
+    fn fixed_now() -> Zoned {
+        // 2026-02-06T12:00:00 in system tz
+        let date = Date::new(2026, 2, 6).unwrap();
+        let time = Time::new(12, 0, 0, 0).unwrap();
+        date.to_datetime(time).to_zoned(TimeZone::UTC).unwrap()
+    }
+
+    #[test]
+    fn test_next_every_day() {
+        let s = parse("every day at 09:00 in UTC").unwrap();
+        let now = fixed_now();
+        let next = next_from(&s, &now).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2026, 2, 7).unwrap());
+        assert_eq!(next.time().hour(), 9);
+    }
+
+    #[test]
+    fn test_next_every_weekday() {
+        let s = parse("every weekday at 9:00 in UTC").unwrap();
+        let now = fixed_now();
+        let next = next_from(&s, &now).unwrap().unwrap();
+        // 2026-02-06 is a Friday, time already passed at 12:00
+        // Next weekday is Monday 2026-02-09
+        assert_eq!(next.date(), Date::new(2026, 2, 9).unwrap());
+    }
+
+    #[test]
+    fn test_next_weekend() {
+        let s = parse("every weekend at 10:00 in UTC").unwrap();
+        let now = fixed_now();
+        let next = next_from(&s, &now).unwrap().unwrap();
+        // 2026-02-07 is Saturday
+        assert_eq!(next.date(), Date::new(2026, 2, 7).unwrap());
+    }
+
+    #[test]
+    fn test_next_interval() {
+        let s = parse("every 45 min from 09:00 to 17:00 in UTC").unwrap();
+        let now = fixed_now();
+        let next = next_from(&s, &now).unwrap().unwrap();
+        // At 12:00, next 45-min tick: 9:00+45*4=12:00, but > now means 12:45
+        assert_eq!(next.time().hour(), 12);
+        assert_eq!(next.time().minute(), 45);
+    }
+
+    #[test]
+    fn test_next_month_on_day() {
+        let s = parse("every month on the 1st at 9:00 in UTC").unwrap();
+        let now = fixed_now();
+        let next = next_from(&s, &now).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2026, 3, 1).unwrap());
+    }
+
+    #[test]
+    fn test_next_month_days_with_weekday_filter() {
+        // Feb 15, Mar 1 and Mar 15 2026 are all Sundays
+        let s = parse("every month on the 1st, 15th at 9:00 on weekdays in UTC").unwrap();
+        let now = fixed_now();
+        let next = next_from(&s, &now).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2026, 4, 1).unwrap());
+
+        let sunday = now.with().day(15).hour(9).minute(0).build().unwrap();
+        assert!(!matches(&s, &sunday).unwrap());
+        let prev = previous_from(&s, &sunday).unwrap().unwrap();
+        // Feb 1 is also a Sunday, so the previous weekday target is Jan 15
+        assert_eq!(prev.date(), Date::new(2026, 1, 15).unwrap());
+    }
+
+    #[test]
+    fn test_next_month_last_day() {
+        let s = parse("every month on the last day at 17:00 in UTC").unwrap();
+        let now = fixed_now();
+        let next = next_from(&s, &now).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2026, 2, 28).unwrap());
+    }
+
+    #[test]
+    fn test_month_last_n_days() {
+        let s = parse("every month on the last 3 days at 02:00 in UTC").unwrap();
+        let now = fixed_now();
+        let next = next_from(&s, &now).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2026, 2, 26).unwrap());
+        let prev = previous_from(&s, &now).unwrap().unwrap();
+        assert_eq!(prev.date(), Date::new(2026, 1, 31).unwrap());
+        assert!(matches(&s, &next).unwrap());
+        assert!(!matches(&s, &next.with().day(25).build().unwrap()).unwrap());
+        assert_eq!(
+            dates_in_month(&s, 2026, 4).unwrap(),
+            [28, 29, 30].map(|d| Date::new(2026, 4, d).unwrap())
+        );
+
+        // More days than february has: the whole month
+        let s = parse("every month on the last 30 days at 02:00 in UTC").unwrap();
+        assert_eq!(dates_in_month(&s, 2026, 2).unwrap().len(), 28);
+        assert_eq!(
+            dates_in_month(&s, 2026, 3).unwrap()[0],
+            Date::new(2026, 3, 2).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_month_business_day() {
+        let s = parse("every month on the first business day at 09:00 in UTC").unwrap();
+        let now = fixed_now();
+        // March 1 2026 is a Sunday
+        let next = next_from(&s, &now).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2026, 3, 2).unwrap());
+        let prev = previous_from(&s, &now).unwrap().unwrap();
+        assert_eq!(prev.date(), Date::new(2026, 2, 2).unwrap());
+        assert!(matches(&s, &next).unwrap());
+        assert!(!matches(&s, &next.with().day(3).build().unwrap()).unwrap());
+        assert_eq!(approx_per_year(&s), Some(12.0));
+
+        let s = parse("every month on the 3rd business day at 09:00 in UTC").unwrap();
+        assert_eq!(
+            dates_in_month(&s, 2026, 2).unwrap(),
+            [Date::new(2026, 2, 4).unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_month_business_day_skips_excepted() {
+        // June 1 2026 is a Monday; the excepted day is not a business day
+        let s = parse("every month on the first business day at 09:00 except 2026-06-01 in UTC")
+            .unwrap();
+        let now = Date::new(2026, 5, 15)
+            .unwrap()
+            .to_zoned(TimeZone::UTC)
+            .unwrap();
+        let next = next_from(&s, &now).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2026, 6, 2).unwrap());
+        let later = Date::new(2026, 6, 10)
+            .unwrap()
+            .to_zoned(TimeZone::UTC)
+            .unwrap();
+        let prev = previous_from(&s, &later).unwrap().unwrap();
+        assert_eq!(prev.date(), Date::new(2026, 6, 2).unwrap());
+        assert!(matches(&s, &next).unwrap());
+        assert_eq!(
+            dates_in_month(&s, 2026, 6).unwrap(),
+            [Date::new(2026, 6, 2).unwrap()]
+        );
+
+        // Holiday calendars count the same way
+        let holiday = Date::new(2026, 6, 2).unwrap();
+        let next = next_from_with_calendar(&s, &now, &|d| d == holiday)
+            .unwrap()
+            .unwrap();
+        assert_eq!(next.date(), Date::new(2026, 6, 3).unwrap());
+    }
+
+    #[test]
+    fn test_next_ordinal_first_monday() {
+        let s = parse("every month on the first monday at 10:00 in UTC").unwrap();
+        let now = fixed_now();
+        let next = next_from(&s, &now).unwrap().unwrap();
+        // First Monday of March 2026 = March 2
+        assert_eq!(next.date(), Date::new(2026, 3, 2).unwrap());
+    }
+
+    #[test]
+    fn test_next_single_date_iso() {
+        let s = parse("on 2026-03-15 at 14:30 in UTC").unwrap();
+        let now = fixed_now();
+        let next = next_from(&s, &now).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2026, 3, 15).unwrap());
+        assert_eq!(next.time().hour(), 14);
+        assert_eq!(next.time().minute(), 30);
+    }
+
+    #[test]
+    fn test_next_single_date_named() {
+        let s = parse("on feb 14 at 9:00 in UTC").unwrap();
+        let now = fixed_now();
+        let next = next_from(&s, &now).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2026, 2, 14).unwrap());
+    }
+
+    #[test]
+    fn test_next_after_inclusive() {
+        let s = parse("every day at 12:00 in UTC").unwrap();
+        let now = fixed_now();
+        assert_eq!(next_after(&s, &now).unwrap().unwrap(), now);
+        assert_eq!(
+            next_from(&s, &now).unwrap().unwrap().date(),
+            Date::new(2026, 2, 7).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_next_after_mid_minute_is_exclusive() {
+        let s = parse("every day at 12:00 in UTC").unwrap();
+        let now = fixed_now()
+            .checked_add(jiff::Span::new().seconds(30))
+            .unwrap();
+        let next = next_after(&s, &now).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2026, 2, 7).unwrap());
+    }
+
+    #[test]
+    fn test_next_after_non_matching_delegates() {
+        let s = parse("every day at 09:00 in UTC").unwrap();
+        let now = fixed_now();
+        assert_eq!(next_after(&s, &now).unwrap(), next_from(&s, &now).unwrap());
+    }
+
+    #[test]
+    fn test_floating_time_uses_input_timezone() {
+        let s = parse("every day at 09:00").unwrap();
+        let now: Zoned = "2026-02-06T12:00:00-05:00[America/New_York]"
+            .parse()
+            .unwrap();
+
+        // Default: times are UTC, 09:00 UTC is 04:00 in New York
+        let utc_next = next_from(&s, &now).unwrap().unwrap();
+        assert_eq!(utc_next.with_time_zone(TimeZone::UTC).time().hour(), 9);
+
+        let floating = s.with_floating_time();
+        let next = next_from(&floating, &now).unwrap().unwrap();
+        assert_eq!(
+            next.to_string(),
+            "2026-02-07T09:00:00-05:00[America/New_York]"
+        );
+        assert!(matches(&floating, &next).unwrap());
+        let utc_next_local = utc_next.with_time_zone(now.time_zone().clone());
+        assert!(!matches(&floating, &utc_next_local).unwrap());
+    }
+
+    #[test]
+    fn test_day_interval_with_filter() {
+        // Aligned from the anchor friday: feb 6, 9 (mon), 12, 15 (sun), 18, 21 (sat), 24
+        let s = parse("every 3 days on weekdays at 09:00 starting 2026-02-06 in UTC").unwrap();
+        let now = fixed_now();
+        let got: Vec<Date> = s
+            .occurrences(&now)
+            .take(4)
+            .map(|r| r.unwrap().date())
+            .collect();
+        assert_eq!(got, [9, 12, 18, 24].map(|d| Date::new(2026, 2, d).unwrap()));
+        let sunday = "2026-02-15T09:00:00+00:00[UTC]".parse::<Zoned>().unwrap();
+        assert!(!matches(&s, &sunday).unwrap());
+        assert!(matches(&s, &sunday.with().day(12).build().unwrap()).unwrap());
+        let prev = previous_from(&s, &sunday).unwrap().unwrap();
+        assert_eq!(prev.date(), Date::new(2026, 2, 12).unwrap());
+        assert_eq!(
+            dates_in_month(&s, 2026, 2).unwrap(),
+            [6, 9, 12, 18, 24, 27].map(|d| Date::new(2026, 2, d).unwrap())
+        );
+
+        // Every 7th day from a wednesday never lands on a weekend. Parsing
+        // rejects that, but a later `with_anchor` can still get there.
+        assert!(parse("every 7 days on weekends at 09:00 starting 2026-02-04 in UTC").is_err());
+        let s = parse("every 7 days on weekends at 09:00 starting 2026-02-07 in UTC")
+            .unwrap()
+            .with_anchor(Date::new(2026, 2, 4).unwrap());
+        assert_eq!(next_from(&s, &now).unwrap(), None);
+        assert_eq!(previous_from(&s, &now).unwrap(), None);
+    }
+
+    #[test]
+    fn test_week_start_sunday() {
+        let s = parse("every 2 weeks on sun, mon at 09:00 starting 2026-01-05 in UTC")
+            .unwrap()
+            .with_week_start(WeekStart::Sunday);
+        // Sunday weeks from jan 4: jan 4-10, jan 18-24, feb 1-7
+        let now = fixed_now();
+        let next = next_from(&s, &now).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2026, 2, 15).unwrap());
+        assert!(matches(&s, &next).unwrap());
+        let prev = previous_from(&s, &now).unwrap().unwrap();
+        assert_eq!(prev.date(), Date::new(2026, 2, 2).unwrap());
+        assert!(matches(&s, &prev).unwrap());
+        assert!(!matches(&s, &prev.with().day(8).build().unwrap()).unwrap());
+        assert_eq!(
+            dates_in_month(&s, 2026, 2).unwrap(),
+            [1, 2, 15, 16]
+                .map(|d| Date::new(2026, 2, d).unwrap())
+                .to_vec()
+        );
+
+        // Monday weeks pair each sunday with the monday before it
+        let s = s.with_week_start(WeekStart::Monday);
+        assert_eq!(
+            dates_in_month(&s, 2026, 2).unwrap(),
+            [2, 8, 16, 22]
+                .map(|d| Date::new(2026, 2, d).unwrap())
+                .to_vec()
+        );
+        let prev = previous_from(&s, &now).unwrap().unwrap();
+        assert_eq!(prev.date(), Date::new(2026, 2, 2).unwrap());
+        // A mid-week anchor aligns by its week, same as next_from
+        let s = parse("every 2 weeks on mon at 09:00 starting 2026-01-07 in UTC").unwrap();
+        let monday = "2026-01-12T09:00:00+00:00[UTC]".parse::<Zoned>().unwrap();
+        assert!(!matches(&s, &monday).unwrap());
+        assert_eq!(
+            next_from(&s, &monday).unwrap().unwrap().date(),
+            Date::new(2026, 1, 19).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_weekend_days() {
+        let s = parse("every weekend at 10:00 in UTC")
+            .unwrap()
+            .with_weekend(WeekendDays::FRIDAY_SATURDAY);
+        let now = fixed_now(); // friday 12:00
+        let next = next_from(&s, &now).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2026, 2, 7).unwrap());
+        let prev = previous_from(&s, &now).unwrap().unwrap();
+        assert_eq!(prev.date(), Date::new(2026, 2, 6).unwrap());
+        assert!(!matches(&s, &next.with().day(8).build().unwrap()).unwrap());
+        assert_eq!(dates_in_month(&s, 2026, 2).unwrap().len(), 8);
+        assert_eq!(approx_per_year(&s), Some(104.0));
+
+        // Weekday lists keep their literal days
+        let s = parse("every 30 min from 09:00 to 09:30 on weekends in UTC")
+            .unwrap()
+            .with_weekend(WeekendDays::new(&[Weekday::Friday]).unwrap());
+        assert_eq!(
+            next_from(&s, &now).unwrap().unwrap().date(),
+            Date::new(2026, 2, 13).unwrap()
+        );
+        assert_eq!(approx_per_year(&s), Some(104.0));
+        assert_eq!(s.to_cron_lossy().0, "*/30 9-9 * * 5");
+        assert_eq!(
+            s.to_string(),
+            "every 30 min from 09:00 to 09:30 on weekend in UTC"
+        );
+
+        // Written-out day lists keep their literal days
+        let s = parse("every saturday, sunday at 10:00 in UTC")
+            .unwrap()
+            .with_weekend(WeekendDays::FRIDAY_SATURDAY);
+        let next = next_from(&s, &now).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2026, 2, 7).unwrap());
+        assert_eq!(s.to_cron().unwrap(), "0 10 * * 0,6");
+        assert_eq!(WeekendDays::new(&[]), None);
+    }
+
+    #[test]
+    fn test_floating_time_explicit_timezone_wins() {
+        let s = parse("every day at 09:00 in UTC")
+            .unwrap()
+            .with_floating_time();
+        let now: Zoned = "2026-02-06T12:00:00-05:00[America/New_York]"
+            .parse()
+            .unwrap();
+        let next = next_from(&s, &now).unwrap().unwrap();
+        assert_eq!(next.to_string(), "2026-02-07T09:00:00+00:00[UTC]");
+    }
+
+    #[test]
+    fn test_next_n() {
+        let s = parse("every day at 09:00 in UTC").unwrap();
+        let now = fixed_now();
+        let results = next_n_from(&s, &now, 3).unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].date(), Date::new(2026, 2, 7).unwrap());
+        assert_eq!(results[1].date(), Date::new(2026, 2, 8).unwrap());
+        assert_eq!(results[2].date(), Date::new(2026, 2, 9).unwrap());
+    }
+
+    #[test]
+    fn test_iso_date_in_past() {
+        let s = parse("on 2020-01-01 at 00:00 in UTC").unwrap();
+        let now = fixed_now();
+        let next = next_from(&s, &now).unwrap();
+        assert!(next.is_none());
+    }
+
+    #[test]
+    fn test_month_skip_31() {
+        // February doesn't have 31 days — should skip to March
+        let s = parse("every month on the 31st at 09:00 in UTC").unwrap();
+        let now = fixed_now();
+        let next = next_from(&s, &now).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2026, 3, 31).unwrap());
+    }
+
+    #[test]
+    fn test_next_year_repeat_date() {
+        let s = parse("every year on dec 25 at 00:00 in UTC").unwrap();
+        let now = fixed_now();
+        let next = next_from(&s, &now).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2026, 12, 25).unwrap());
+    }
+
+    #[test]
+    fn test_next_year_repeat_ordinal_weekday() {
+        let s = parse("every year on the first monday of march at 10:00 in UTC").unwrap();
+        let now = fixed_now();
+        let next = next_from(&s, &now).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2026, 3, 2).unwrap());
+    }
+
+    #[test]
+    fn test_iso_week_repeat() {
+        // ISO week 1 of 2026 starts on Monday 2025-12-29
+        let s = parse("every year in iso weeks 1, 14 on monday at 09:00 in UTC").unwrap();
+        let now = fixed_now();
+        let next = next_from(&s, &now).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2026, 3, 30).unwrap());
+        assert!(matches(&s, &next).unwrap());
+
+        let prev = previous_from(&s, &now).unwrap().unwrap();
+        assert_eq!(prev.date(), Date::new(2025, 12, 29).unwrap());
+
+        let tuesday = next.with().day(31).build().unwrap();
+        assert!(!matches(&s, &tuesday).unwrap());
+    }
+
+    #[test]
+    fn test_iso_week_53_skips_short_years() {
+        // 2026 has 53 ISO weeks; the next long year is 2032
+        let s = parse("every year in iso weeks 53 on friday at 09:00 in UTC").unwrap();
+        let first = next_from(&s, &fixed_now()).unwrap().unwrap();
+        assert_eq!(first.date(), Date::new(2027, 1, 1).unwrap());
+        let second = next_from(&s, &first).unwrap().unwrap();
+        assert_eq!(second.date(), Date::new(2032, 12, 31).unwrap());
+        let back = previous_from(&s, &second).unwrap().unwrap();
+        assert_eq!(back, first);
+    }
+
+    #[test]
+    fn test_next_from_with_calendar() {
+        let s = parse("every weekday at 09:00 except feb 9 in UTC").unwrap();
+        let now = fixed_now();
+        // feb 9 by except, feb 10 by the calendar
+        let holiday = Date::new(2026, 2, 10).unwrap();
+        let next = next_from_with_calendar(&s, &now, &|d| d == holiday)
+            .unwrap()
+            .unwrap();
+        assert_eq!(next.date(), Date::new(2026, 2, 11).unwrap());
+
+        // A calendar that rejects everything runs out rather than looping
+        assert_eq!(next_from_with_calendar(&s, &now, &|_| true).unwrap(), None);
+        assert_eq!(
+            next_from_with_calendar(&s, &now, &|_| false).unwrap(),
+            next_from(&s, &now).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_next_civil_from() {
+        // A spring-forward day in most zones; civil time has no gap
+        let s = parse("every day at 02:30").unwrap();
+        let now = Date::new(2026, 3, 8).unwrap().at(0, 0, 0, 0);
+        let next = next_civil_from(&s, now).unwrap();
+        assert_eq!(next, Some(Date::new(2026, 3, 8).unwrap().at(2, 30, 0, 0)));
+
+        let s = parse("every day at 02:30 in America/New_York").unwrap();
+        assert!(next_civil_from(&s, now).is_err());
+    }
+
+    #[test]
+    fn test_multi_date() {
+        let s = parse("on feb 14, dec 25 at 09:00 in UTC").unwrap();
+        let now = fixed_now();
+        let next = next_from(&s, &now).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2026, 2, 14).unwrap());
+        assert!(matches(&s, &next).unwrap());
+        let after = next_from(&s, &next).unwrap().unwrap();
+        assert_eq!(after.date(), Date::new(2026, 12, 25).unwrap());
+
+        let prev = previous_from(&s, &now).unwrap().unwrap();
+        assert_eq!(prev.date(), Date::new(2025, 12, 25).unwrap());
+        assert!(!matches(&s, &now).unwrap());
+
+        // ISO-only lists run out
+        let s = parse("on 2026-01-10, 2026-03-01 at 09:00 in UTC").unwrap();
+        let next = next_from(&s, &now).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2026, 3, 1).unwrap());
+        assert_eq!(next_from(&s, &next).unwrap(), None);
+    }
+
+    #[test]
+    fn test_weekday_interval() {
+        // Anchored on Monday 2026-02-02: every third business day is
+        // Mon 2, Thu 5, Tue 10, Fri 13, Wed 18, ...
+        let s = parse("every 3 weekdays at 09:00 starting 2026-02-02 in UTC").unwrap();
+        let now = fixed_now(); // Friday 2026-02-06 12:00
+        let dates: Vec<Date> = s
+            .occurrences(&now)
+            .take(4)
+            .map(|z| z.unwrap().date())
+            .collect();
+        assert_eq!(
+            dates,
+            [10, 13, 18, 23].map(|d| Date::new(2026, 2, d).unwrap())
+        );
+
+        let prev = previous_from(&s, &now).unwrap().unwrap();
+        assert_eq!(prev.date(), Date::new(2026, 2, 5).unwrap());
+        assert!(matches(&s, &prev).unwrap());
+        let friday: Zoned = "2026-02-06T09:00:00+00:00[UTC]".parse().unwrap();
+        assert!(!matches(&s, &friday).unwrap());
+        assert_eq!(
+            dates_in_month(&s, 2026, 2).unwrap(),
+            [2, 5, 10, 13, 18, 23, 26].map(|d| Date::new(2026, 2, d).unwrap())
+        );
+
+        // From a weekend the count resumes on the next aligned weekday
+        let saturday: Zoned = "2026-02-07T12:00:00+00:00[UTC]".parse().unwrap();
+        let next = next_from(&s, &saturday).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2026, 2, 10).unwrap());
+        let prev = previous_from(&s, &saturday).unwrap().unwrap();
+        assert_eq!(prev.date(), Date::new(2026, 2, 5).unwrap());
+
+        // A weekend anchor counts from the following monday
+        let s = parse("every 2 weekdays at 09:00 starting 2026-02-07 in UTC").unwrap();
+        let next = next_from(&s, &saturday).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2026, 2, 9).unwrap());
+
+        assert_eq!(approx_per_year(&s), Some(130.0));
+    }
+
+    #[test]
+    fn test_next_weekday_date() {
+        let now = fixed_now(); // Friday 2026-02-06 12:00 UTC
+        let next = |input: &str| {
+            next_from(&parse(input).unwrap(), &now)
+                .unwrap()
+                .unwrap()
+                .to_string()
+        };
+        // Today while a time is still ahead, otherwise next week
+        assert_eq!(
+            next("on friday at 17:00 in UTC"),
+            "2026-02-06T17:00:00+00:00[UTC]"
+        );
+        assert_eq!(
+            next("on friday at 09:00 in UTC"),
+            "2026-02-13T09:00:00+00:00[UTC]"
+        );
+        assert_eq!(
+            next("on monday at 09:00 in UTC"),
+            "2026-02-09T09:00:00+00:00[UTC]"
+        );
+
+        let s = parse("on friday at 09:00, 17:00 in UTC").unwrap();
+        let prev = previous_from(&s, &now).unwrap().unwrap();
+        assert_eq!(prev.to_string(), "2026-02-06T09:00:00+00:00[UTC]");
+        // A single occurrence, pinned to the upcoming friday
+        let s = parse("on friday at 17:00 in UTC").unwrap();
+        assert_eq!(previous_from(&s, &now).unwrap(), None);
+        let all = next_n_from(&s, &now, 3).unwrap();
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].to_string(), "2026-02-06T17:00:00+00:00[UTC]");
+        let to = now.checked_add(jiff::Span::new().days(30)).unwrap();
+        assert_eq!(between(&s, &now, &to).count(), 1);
+        assert!(s.is_one_shot());
+        assert_eq!(approx_per_year(&s), None);
+
+        // Nothing to resolve the weekday against without an evaluation time
+        let err = matches(&s, &all[0]).unwrap_err();
+        assert!(err.to_string().contains("no evaluation time"), "{err}");
+        assert!(matches_date(&s, all[0].date()).is_err());
+        assert!(dates_in_month(&s, 2026, 2).is_err());
+        assert!(count_in_year(&s, 2026).is_err());
+    }
+
+    #[test]
+    fn test_during_dates() {
+        let s = parse("every day at 09:00 during jun 15 to aug 31 in UTC").unwrap();
+        let now = fixed_now();
+        let next = next_from(&s, &now).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2026, 6, 15).unwrap());
+        assert!(!matches(&s, &next.with().day(14).build().unwrap()).unwrap());
+
+        let prev = previous_from(&s, &now).unwrap().unwrap();
+        assert_eq!(prev.date(), Date::new(2025, 8, 31).unwrap());
+
+        // Wrapping past december, ending on feb 29 in a common year
+        let s = parse("every day at 09:00 during dec 20 to feb 29 in UTC").unwrap();
+        let after = "2026-02-28T12:00:00+00:00[UTC]".parse::<Zoned>().unwrap();
+        let next = next_from(&s, &after).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2026, 12, 20).unwrap());
+        let prev = previous_from(&s, &next).unwrap().unwrap();
+        assert_eq!(prev.date(), Date::new(2026, 2, 28).unwrap());
+
+        assert_eq!(
+            approx_per_year(&parse("every day at 09:00 during jun 15 to jun 24").unwrap()),
+            Some(10.0)
+        );
+    }
+
+    #[test]
+    fn test_except_weekday_skips_matching_days() {
+        // fixed_now is Friday 12:00, so the next two weekend days are skipped
+        let s = parse("every day at 09:00 except saturday, sunday in UTC").unwrap();
+        let now = fixed_now();
+        let next = next_from(&s, &now).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2026, 2, 9).unwrap());
+
+        let prev = previous_from(&s, &next).unwrap().unwrap();
+        assert_eq!(prev.date(), Date::new(2026, 2, 6).unwrap());
+
+        let saturday = next.with().day(7).build().unwrap();
+        assert!(!matches(&s, &saturday).unwrap());
+    }
+
+    #[test]
+    fn test_except_skips_holiday() {
+        // Every weekday at 09:00, except dec 25 and jan 1
+        let s = parse("every weekday at 09:00 except dec 25, jan 1 in UTC").unwrap();
+        // Set now to just before Christmas 2026 (dec 24 evening)
+        let now = Date::new(2026, 12, 24)
+            .unwrap()
+            .to_datetime(Time::new(20, 0, 0, 0).unwrap())
+            .to_zoned(TimeZone::UTC)
+            .unwrap();
+        let next = next_from(&s, &now).unwrap().unwrap();
+        // Dec 25 is Friday but excepted, so next = Dec 28 (Monday)
+        assert_eq!(next.date(), Date::new(2026, 12, 28).unwrap());
+    }
+
+    #[test]
+    fn test_previous_from_starting_and_until_bounds() {
+        let at = |d: i8, h: i8, m: i8| {
+            Date::new(2026, 2, d)
+                .unwrap()
+                .to_datetime(Time::new(h, m, 0, 0).unwrap())
+                .to_zoned(TimeZone::UTC)
+                .unwrap()
+        };
+        let s = parse("every day at 09:00 starting 2026-02-10 in UTC").unwrap();
+        assert_eq!(previous_from(&s, &at(6, 12, 0)).unwrap(), None);
+        assert_eq!(previous_from(&s, &at(10, 0, 0)).unwrap(), None);
+        assert_eq!(previous_from(&s, &at(10, 9, 0)).unwrap(), None);
+        assert_eq!(
+            previous_from(&s, &at(10, 9, 1)).unwrap(),
+            Some(at(10, 9, 0))
+        );
+
+        let s = parse("every day at 09:00 until 2026-02-03 in UTC").unwrap();
+        assert_eq!(previous_from(&s, &at(6, 12, 0)).unwrap(), Some(at(3, 9, 0)));
+        assert_eq!(previous_from(&s, &at(4, 0, 0)).unwrap(), Some(at(3, 9, 0)));
+        assert_eq!(previous_from(&s, &at(3, 8, 0)).unwrap(), Some(at(2, 9, 0)));
+
+        let s = parse("every day at 09:00 until 2026-02-12 starting 2026-02-10 in UTC").unwrap();
+        assert_eq!(
+            previous_from(&s, &at(28, 0, 0)).unwrap(),
+            Some(at(12, 9, 0))
+        );
+        assert_eq!(previous_from(&s, &at(10, 8, 0)).unwrap(), None);
+    }
+
+    #[test]
+    fn test_effective_until() {
+        let now = fixed_now();
+        let until = |input: &str| effective_until(&parse(input).unwrap(), &now).unwrap();
+        assert_eq!(until("every day at 09:00 in UTC"), None);
+        assert_eq!(
+            until("every day at 09:00 until 2026-03-01 in UTC"),
+            Some(Date::new(2026, 3, 1).unwrap())
+        );
+        // Named dates already past this year roll to next year
+        assert_eq!(
+            until("every day at 09:00 until jan 15 in UTC"),
+            Some(Date::new(2027, 1, 15).unwrap())
+        );
+        // Occurrences since the anchor spend the budget
+        assert_eq!(
+            until("every day at 09:00 until 3 times starting 2026-02-05 in UTC"),
+            Some(Date::new(2026, 2, 7).unwrap())
+        );
+        assert_eq!(
+            until("every day at 09:00 until 3 times starting 2026-02-01 in UTC"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_except_ranges() {
+        let s = parse("every day at 09:00 except 2026-02-07 to 2026-03-31 in UTC").unwrap();
+        let now = fixed_now();
+        let next = next_from(&s, &now).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2026, 4, 1).unwrap());
+        let prev = previous_from(&s, &next).unwrap().unwrap();
+        assert_eq!(prev.date(), Date::new(2026, 2, 6).unwrap());
+        assert!(!matches(&s, &next.with().day(31).month(3).build().unwrap()).unwrap());
+
+        // Named ranges recur and wrap past december
+        let s = parse("every day at 09:00 except dec 24 to jan 2 in UTC").unwrap();
+        let christmas_eve = Date::new(2026, 12, 23)
+            .unwrap()
+            .to_datetime(Time::new(12, 0, 0, 0).unwrap())
+            .to_zoned(TimeZone::UTC)
+            .unwrap();
+        let next = next_from(&s, &christmas_eve).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2027, 1, 3).unwrap());
+        let prev = previous_from(&s, &next).unwrap().unwrap();
+        assert_eq!(prev.date(), Date::new(2026, 12, 23).unwrap());
+        assert_eq!(
+            dates_in_month(&s, 2026, 1).unwrap()[0],
+            Date::new(2026, 1, 3).unwrap()
+        );
 
-    if let Some(tod) = sorted_times.last() {
-        return at_time_on_date(date, to_time(tod), tz).map(Some);
+        // A year-long shutdown is skipped in one step, well inside the retry limit
+        let s = parse("every day at 09:00 except 2026-02-07 to 2030-02-06 in UTC").unwrap();
+        let next = next_from(&s, &now).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2030, 2, 7).unwrap());
     }
-    Ok(None)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::parser::parse;
 
-    fn fixed_now() -> Zoned {
-        // 2026-02-06T12:00:00 in system tz
-        let date = Date::new(2026, 2, 6).unwrap();
-        let time = Time::new(12, 0, 0, 0).unwrap();
-        date.to_datetime(time).to_zoned(TimeZone::UTC).unwrap()
+    #[test]
+    fn test_until_limits_results() {
+        let s = parse("every day at 09:00 until 2026-02-10 in UTC").unwrap();
+        let now = fixed_now();
+        let results = next_n_from(&s, &now, 10).unwrap();
+        // Should get Feb 7, 8, 9, 10 (4 results, not 10)
+        assert_eq!(results.len(), 4);
+        assert_eq!(
+            results.last().unwrap().date(),
+            Date::new(2026, 2, 10).unwrap()
+        );
     }
 
     #[test]
-    fn test_next_every_day() {
-        let s = parse("every day at 09:00 in UTC").unwrap();
+    fn test_until_count_limits_occurrences() {
+        let s = parse("every day at 09:00 until 3 times starting 2026-02-07 in UTC").unwrap();
         let now = fixed_now();
-        let next = next_from(&s, &now).unwrap().unwrap();
-        assert_eq!(next.date(), Date::new(2026, 2, 7).unwrap());
-        assert_eq!(next.time().hour(), 9);
+        let results = next_n_from(&s, &now, 10).unwrap();
+        assert_eq!(results.len(), 3);
+        let last = &results[2];
+        assert_eq!(last.date(), Date::new(2026, 2, 9).unwrap());
+
+        // The stateless evaluators count from the anchor too
+        let far = now.checked_add(jiff::Span::new().days(30)).unwrap();
+        assert_eq!(next_from(&s, &far).unwrap(), None);
+        assert_eq!(next_from(&s, &results[1]).unwrap().as_ref(), Some(last));
+        assert_eq!(next_from(&s, last).unwrap(), None);
+        assert_eq!(previous_from(&s, &far).unwrap().as_ref(), Some(last));
+        assert!(matches(&s, last).unwrap());
+        let after = last.checked_add(jiff::Span::new().days(1)).unwrap();
+        assert!(!matches(&s, &after).unwrap());
+        assert!(!matches_date(&s, after.date()).unwrap());
+        assert_eq!(between(&s, &now, &far).count(), 3);
     }
 
     #[test]
-    fn test_next_every_weekday() {
-        let s = parse("every weekday at 9:00 in UTC").unwrap();
+    fn test_until_count_needs_fixed_starting_date() {
         let now = fixed_now();
-        let next = next_from(&s, &now).unwrap().unwrap();
-        // 2026-02-06 is a Friday, time already passed at 12:00
-        // Next weekday is Monday 2026-02-09
-        assert_eq!(next.date(), Date::new(2026, 2, 9).unwrap());
+        for input in [
+            "every day at 09:00 until 3 times in UTC",
+            "every day at 09:00 until 3 times starting today in UTC",
+        ] {
+            let s = parse(input).unwrap();
+            let err = next_from(&s, &now).unwrap_err();
+            assert!(
+                err.to_string().contains("'starting' date"),
+                "{input}: {err}"
+            );
+            assert!(previous_from(&s, &now).is_err(), "{input}");
+            assert!(next_n_from(&s, &now, 3).is_err(), "{input}");
+        }
     }
 
     #[test]
-    fn test_next_weekend() {
-        let s = parse("every weekend at 10:00 in UTC").unwrap();
+    fn test_starting_today_anchors_to_eval_date() {
+        // fixed_now is Feb 6 12:00: 09:00 has passed, so the next aligned day is Feb 8
+        let s = parse("every 2 days at 09:00, 13:00 starting today in UTC").unwrap();
         let now = fixed_now();
         let next = next_from(&s, &now).unwrap().unwrap();
-        // 2026-02-07 is Saturday
+        assert_eq!(next.to_string(), "2026-02-06T13:00:00+00:00[UTC]");
+        let later = next_from(&s, &next).unwrap().unwrap();
+        assert_eq!(later.to_string(), "2026-02-08T09:00:00+00:00[UTC]");
+        let prev = previous_from(&s, &now).unwrap().unwrap();
+        assert_eq!(prev.to_string(), "2026-02-06T09:00:00+00:00[UTC]");
+
+        // A different evaluation date re-anchors the series
+        let tomorrow = now.checked_add(jiff::Span::new().days(1)).unwrap();
+        let next = next_from(&s, &tomorrow).unwrap().unwrap();
         assert_eq!(next.date(), Date::new(2026, 2, 7).unwrap());
     }
 
     #[test]
-    fn test_next_interval() {
-        let s = parse("every 45 min from 09:00 to 17:00 in UTC").unwrap();
+    fn test_relative_anchor_needs_evaluation_time() {
         let now = fixed_now();
-        let next = next_from(&s, &now).unwrap().unwrap();
-        // At 12:00, next 45-min tick: 9:00+45*4=12:00, but > now means 12:45
-        assert_eq!(next.time().hour(), 12);
-        assert_eq!(next.time().minute(), 45);
+        for input in [
+            "every 2 days at 09:00 starting today in UTC",
+            "every 2 days at 09:00 starting now in UTC",
+            "every 2 days at 09:00 starting feb 6 in UTC",
+        ] {
+            let s = parse(input).unwrap();
+            let next = next_from(&s, &now).unwrap().unwrap();
+            let err = matches(&s, &next).unwrap_err();
+            assert!(err.to_string().contains("no evaluation time"), "{err}");
+            assert!(matches_all(&s, std::slice::from_ref(&next)).is_err());
+            assert!(matches_date(&s, next.date()).is_err());
+            assert!(matches_within(&s, &next, jiff::Span::new().minutes(1)).is_err());
+            assert!(window_contains(&s, &next).is_err());
+            assert!(dates_in_month(&s, 2026, 2).is_err());
+            assert!(count_in_year(&s, 2026).is_err());
+            assert!(occurrences_on(&s, next.date()).is_err());
+
+            // Pinned to the evaluation date, the cadence matches next_from
+            let pinned = s.clone().with_anchor(now.date());
+            let day_after = next.checked_add(jiff::Span::new().days(1)).unwrap();
+            assert!(matches(&pinned, &next).unwrap());
+            assert!(!matches(&pinned, &day_after).unwrap());
+        }
     }
 
     #[test]
-    fn test_next_month_on_day() {
-        let s = parse("every month on the 1st at 9:00 in UTC").unwrap();
+    fn test_starting_named_date_resolves_forward() {
         let now = fixed_now();
+        // Feb 9 is still ahead this year; jan 5 has passed, so it is next year's
+        let s = parse("every 2 weeks on monday at 09:00 starting feb 9 in UTC").unwrap();
         let next = next_from(&s, &now).unwrap().unwrap();
-        assert_eq!(next.date(), Date::new(2026, 3, 1).unwrap());
+        assert_eq!(next.date(), Date::new(2026, 2, 9).unwrap());
+        let s = parse("every day at 09:00 starting jan 5 in UTC").unwrap();
+        let next = next_from(&s, &now).unwrap().unwrap();
+        assert_eq!(next.date(), Date::new(2027, 1, 5).unwrap());
+        assert!(previous_from(&s, &now).unwrap().is_none());
     }
 
     #[test]
-    fn test_next_month_last_day() {
-        let s = parse("every month on the last day at 17:00 in UTC").unwrap();
+    fn test_starting_named_date_pinned_by_iterator() {
+        // Resolved once against the iterator's start, so the series keeps
+        // going after the anchor date passes
+        let s = parse("every day at 09:00 starting feb 9 in UTC").unwrap();
+        let dates: Vec<Date> = Occurrences::new(&s, fixed_now())
+            .take(3)
+            .map(|dt| dt.unwrap().date())
+            .collect();
+        assert_eq!(
+            dates,
+            [
+                Date::new(2026, 2, 9).unwrap(),
+                Date::new(2026, 2, 10).unwrap(),
+                Date::new(2026, 2, 11).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_starting_time_bounds_first_day() {
+        let s = parse("every day at 09:00, 17:00 starting 2026-02-07 at 12:00 in UTC").unwrap();
         let now = fixed_now();
         let next = next_from(&s, &now).unwrap().unwrap();
-        assert_eq!(next.date(), Date::new(2026, 2, 28).unwrap());
+        assert_eq!(next.to_string(), "2026-02-07T17:00:00+00:00[UTC]");
+
+        let morning: Zoned = "2026-02-07T09:00:00+00:00[UTC]".parse().unwrap();
+        assert!(!matches(&s, &morning).unwrap());
+        let later: Zoned = "2026-02-08T00:00:00+00:00[UTC]".parse().unwrap();
+        assert_eq!(previous_from(&s, &later).unwrap(), Some(next.clone()));
+        let before: Zoned = "2026-02-07T17:00:00+00:00[UTC]".parse().unwrap();
+        assert!(previous_from(&s, &before).unwrap().is_none());
+        // The date still counts as a firing day
+        assert!(matches_date(&s, next.date()).unwrap());
     }
 
     #[test]
-    fn test_next_ordinal_first_monday() {
-        let s = parse("every month on the first monday at 10:00 in UTC").unwrap();
+    fn test_starting_now_has_no_previous() {
+        let s = parse("every day at 09:00 starting now in UTC").unwrap();
         let now = fixed_now();
+        assert!(previous_from(&s, &now).unwrap().is_none());
         let next = next_from(&s, &now).unwrap().unwrap();
-        // First Monday of March 2026 = March 2
-        assert_eq!(next.date(), Date::new(2026, 3, 2).unwrap());
+        assert_eq!(next.date(), Date::new(2026, 2, 7).unwrap());
     }
 
     #[test]
-    fn test_next_single_date_iso() {
-        let s = parse("on 2026-03-15 at 14:30 in UTC").unwrap();
+    fn test_until_count_runs_from_starting_anchor() {
+        // Series: Feb 2, 3, 4, 5, 6 at 09:00; four are already past at Feb 6 12:00
+        let s = parse("every day at 09:00 until 5 times starting 2026-02-02 in UTC").unwrap();
         let now = fixed_now();
-        let next = next_from(&s, &now).unwrap().unwrap();
-        assert_eq!(next.date(), Date::new(2026, 3, 15).unwrap());
-        assert_eq!(next.time().hour(), 14);
-        assert_eq!(next.time().minute(), 30);
+        let results = next_n_from(&s, &now, 10).unwrap();
+        assert!(results.is_empty());
+
+        let before: Zoned = "2026-02-04T12:00:00+00:00[UTC]".parse().unwrap();
+        let results = next_n_from(&s, &before, 10).unwrap();
+        let dates: Vec<Date> = results.iter().map(|z| z.date()).collect();
+        assert_eq!(
+            dates,
+            vec![
+                Date::new(2026, 2, 5).unwrap(),
+                Date::new(2026, 2, 6).unwrap()
+            ]
+        );
     }
 
     #[test]
-    fn test_next_single_date_named() {
-        let s = parse("on feb 14 at 9:00 in UTC").unwrap();
+    fn test_next_interval_between_business_hours_on_weekdays() {
+        let s = parse("every 15 minutes between 9am and 5pm on weekdays in UTC").unwrap();
+        let now = fixed_now(); // Friday 12:00
+        let results = next_n_from(&s, &now, 22).unwrap();
+        // Friday 12:15 .. 17:00 is 20 slots, then Monday 09:00, 09:15
+        assert_eq!(results[0].time(), Time::new(12, 15, 0, 0).unwrap());
+        assert_eq!(results[19].time(), Time::new(17, 0, 0, 0).unwrap());
+        assert_eq!(results[20].date(), Date::new(2026, 2, 9).unwrap());
+        assert_eq!(results[20].time(), Time::new(9, 0, 0, 0).unwrap());
+        assert_eq!(results[21].time(), Time::new(9, 15, 0, 0).unwrap());
+        for pair in results[..20].windows(2) {
+            assert_eq!(
+                pair[0].until(&pair[1]).unwrap().get_minutes(),
+                15,
+                "slots must be 15 minutes apart"
+            );
+        }
+    }
+
+    #[test]
+    fn test_window_contains_interval() {
+        let s = parse("every 30 min from 09:00 to 17:00 on weekday in UTC").unwrap();
+        let at = |d: i8, h: i8, m: i8| {
+            Date::new(2026, 2, d)
+                .unwrap()
+                .to_datetime(Time::new(h, m, 0, 0).unwrap())
+                .to_zoned(TimeZone::UTC)
+                .unwrap()
+        };
+        // Friday 2026-02-06
+        assert!(window_contains(&s, &at(6, 9, 0)).unwrap());
+        assert!(window_contains(&s, &at(6, 12, 17)).unwrap());
+        assert!(!matches(&s, &at(6, 12, 17)).unwrap());
+        assert!(window_contains(&s, &at(6, 17, 0)).unwrap());
+        assert!(!window_contains(&s, &at(6, 8, 59)).unwrap());
+        assert!(!window_contains(&s, &at(6, 17, 1)).unwrap());
+        // Saturday 2026-02-07 is filtered out by the day filter
+        assert!(!window_contains(&s, &at(7, 12, 0)).unwrap());
+    }
+
+    #[test]
+    fn test_window_contains_respects_modifiers() {
+        let s = parse("every 30 min from 09:00 to 17:00 except 2026-02-06 in UTC").unwrap();
+        assert!(!window_contains(&s, &fixed_now()).unwrap());
+    }
+
+    #[test]
+    fn test_window_contains_non_interval_same_as_matches() {
+        let s = parse("every day at 12:00 in UTC").unwrap();
         let now = fixed_now();
-        let next = next_from(&s, &now).unwrap().unwrap();
-        assert_eq!(next.date(), Date::new(2026, 2, 14).unwrap());
+        assert!(window_contains(&s, &now).unwrap());
+        let later = now.checked_add(jiff::Span::new().minutes(5)).unwrap();
+        assert!(!window_contains(&s, &later).unwrap());
     }
 
     #[test]
-    fn test_next_n() {
+    fn test_dates_in_month_agrees_with_between() {
+        let exprs = [
+            "every weekday at 09:00 except 2026-03-02 in UTC",
+            "every 3 days at 09:00 starting 2026-01-01 in UTC",
+            "every 2 weeks on monday, thursday at 09:00 starting 2026-01-05 in UTC",
+            "every 30 min from 09:00 to 10:00 on weekend in UTC",
+            "every 2 hours from 22:00 to 06:00 on friday in UTC",
+            "every 3 hours from 22:00 to 00:30 on friday in UTC",
+            "every month on the 31st at 09:00 in UTC",
+            "every month on the nearest weekday to 1st at 09:00 in UTC",
+            "every month on the previous nearest weekday to 1st at 09:00 in UTC",
+            "every month on the next nearest weekday to 31st at 09:00 in UTC",
+            // `during` follows the month the target comes from
+            "every month on the next nearest weekday to 31st at 09:00 during jan in UTC",
+            "every month on the previous nearest weekday to 1st at 09:00 during mar in UTC",
+            "every 2 months on the last friday at 09:00 starting 2026-01-01 in UTC",
+            "every year on feb 29 at 09:00 in UTC",
+            "on mar 15 at 09:00 in UTC",
+            "every day at 09:00 until 2026-05-10 during apr, may in UTC",
+        ];
+        for expr in exprs {
+            let s = parse(expr).unwrap();
+            for month in 1..=12 {
+                let first = Date::new(2026, month, 1).unwrap();
+                let from = first
+                    .to_zoned(TimeZone::UTC)
+                    .unwrap()
+                    .checked_sub(jiff::Span::new().seconds(1))
+                    .unwrap();
+                let to = last_day_of_month(2026, month)
+                    .to_datetime(Time::new(23, 59, 59, 0).unwrap())
+                    .to_zoned(TimeZone::UTC)
+                    .unwrap();
+                let mut expected: Vec<Date> =
+                    between(&s, &from, &to).map(|r| r.unwrap().date()).collect();
+                expected.dedup();
+                assert_eq!(
+                    dates_in_month(&s, 2026, month).unwrap(),
+                    expected,
+                    "{expr} in month {month}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_occurrences_on_dst_days() {
+        let s = parse("every 1 hours from 00:00 to 23:59 in America/New_York").unwrap();
+        let on = |d: Date| occurrences_on(&s, d).unwrap();
+        let normal = on(jiff::civil::date(2026, 3, 7));
+        assert_eq!(normal.len(), 24);
+        assert_eq!(
+            normal[0].to_string(),
+            "2026-03-07T00:00:00-05:00[America/New_York]"
+        );
+        assert_eq!(
+            normal[23].to_string(),
+            "2026-03-07T23:00:00-05:00[America/New_York]"
+        );
+        // 23-hour and 25-hour days stay within their own civil date
+        for d in [
+            jiff::civil::date(2026, 3, 8),
+            jiff::civil::date(2026, 11, 1),
+        ] {
+            let day = on(d);
+            assert!(!day.is_empty());
+            assert!(day.iter().all(|z| z.date() == d), "{d}: {day:?}");
+        }
+
+        let s = parse("every weekday at 09:00, 17:00 in UTC").unwrap();
+        assert_eq!(
+            occurrences_on(&s, jiff::civil::date(2026, 2, 6))
+                .unwrap()
+                .len(),
+            2
+        );
+        assert!(occurrences_on(&s, jiff::civil::date(2026, 2, 7))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_dst_anomalies_between() {
+        let from: Zoned = "2026-01-01T00:00:00-05:00[America/New_York]"
+            .parse()
+            .unwrap();
+        let to: Zoned = "2026-12-31T00:00:00-05:00[America/New_York]"
+            .parse()
+            .unwrap();
+        let anomalies =
+            |input: &str| dst_anomalies_between(&parse(input).unwrap(), &from, &to).unwrap();
+
+        let found = anomalies("every day at 01:30, 02:30 in America/New_York");
+        assert_eq!(found.len(), 2);
+        assert_eq!(
+            found[0].0.to_string(),
+            "2026-03-08T03:30:00-04:00[America/New_York]"
+        );
+        assert_eq!(
+            found[0].1,
+            DstAnomaly::Gap {
+                scheduled: TimeOfDay {
+                    hour: 2,
+                    minute: 30
+                }
+            }
+        );
+        assert_eq!(
+            found[1].0.to_string(),
+            "2026-11-01T01:30:00-04:00[America/New_York]"
+        );
+        assert_eq!(found[1].1, DstAnomaly::Fold);
+
+        // A written 03:30 on the spring-forward day is not a shifted 02:30
+        assert!(anomalies("every day at 03:30 in America/New_York").is_empty());
+        assert!(anomalies("every 30 min from 00:00 to 23:59 in America/New_York").is_empty());
+        assert_eq!(
+            anomalies("every 7 days from 02:15 to 04:00 starting 2026-03-01 in America/New_York")
+                .len(),
+            1
+        );
+        assert!(anomalies("every day at 02:30 in UTC").is_empty());
+    }
+
+    #[test]
+    fn test_dates_in_month_invalid_month() {
+        let s = parse("every day at 09:00").unwrap();
+        assert!(dates_in_month(&s, 2026, 13).is_err());
+    }
+
+    #[test]
+    fn test_interval_wraps_midnight() {
+        let s = parse("every 2 hours from 22:00 to 06:00 in UTC").unwrap();
+        let hours: Vec<String> = next_n_from(&s, &fixed_now(), 6)
+            .unwrap()
+            .iter()
+            .map(|z| z.strftime("%a %H:%M").to_string())
+            .collect();
+        assert_eq!(
+            hours,
+            [
+                "Fri 22:00",
+                "Sat 00:00",
+                "Sat 02:00",
+                "Sat 04:00",
+                "Sat 06:00",
+                "Sat 22:00"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_interval_wraps_midnight_boundary() {
+        let s = parse("every 1 hour from 23:00 to 01:00 on friday in UTC").unwrap();
+        let at = |d: i8, h: i8, m: i8| {
+            Date::new(2026, 2, d)
+                .unwrap()
+                .to_datetime(Time::new(h, m, 0, 0).unwrap())
+                .to_zoned(TimeZone::UTC)
+                .unwrap()
+        };
+        // Friday's window runs into Saturday; the day filter applies to the start day
+        let results = next_n_from(&s, &fixed_now(), 4).unwrap();
+        assert_eq!(
+            results,
+            vec![at(6, 23, 0), at(7, 0, 0), at(7, 1, 0), at(13, 23, 0)]
+        );
+
+        assert!(matches(&s, &at(7, 0, 0)).unwrap());
+        assert!(matches(&s, &at(7, 1, 0)).unwrap());
+        assert!(!matches(&s, &at(7, 23, 0)).unwrap());
+        assert!(!matches(&s, &at(6, 0, 0)).unwrap());
+        assert!(window_contains(&s, &at(7, 0, 30)).unwrap());
+        assert!(!window_contains(&s, &at(7, 1, 30)).unwrap());
+
+        assert_eq!(previous_from(&s, &at(7, 1, 30)).unwrap(), Some(at(7, 1, 0)));
+        assert_eq!(previous_from(&s, &at(7, 0, 30)).unwrap(), Some(at(7, 0, 0)));
+        let prev_week = at(7, 1, 0).checked_sub(jiff::Span::new().days(7)).unwrap();
+        assert_eq!(previous_from(&s, &at(6, 23, 0)).unwrap(), Some(prev_week));
+    }
+
+    #[test]
+    fn test_next_from_in_overrides_timezone() {
         let s = parse("every day at 09:00 in UTC").unwrap();
         let now = fixed_now();
-        let results = next_n_from(&s, &now, 3).unwrap();
-        assert_eq!(results.len(), 3);
-        assert_eq!(results[0].date(), Date::new(2026, 2, 7).unwrap());
-        assert_eq!(results[1].date(), Date::new(2026, 2, 8).unwrap());
-        assert_eq!(results[2].date(), Date::new(2026, 2, 9).unwrap());
+        let next = next_from_in(&s, &now, "America/New_York").unwrap().unwrap();
+        assert_eq!(
+            next.to_string(),
+            "2026-02-06T09:00:00-05:00[America/New_York]"
+        );
+        // The schedule itself is untouched
+        assert_eq!(
+            next_from(&s, &now).unwrap().unwrap().to_string(),
+            "2026-02-07T09:00:00+00:00[UTC]"
+        );
+        let err = next_from_in(&s, &now, "Mars/Olympus_Mons").unwrap_err();
+        assert!(err.is_recoverable());
     }
 
     #[test]
-    fn test_iso_date_in_past() {
-        let s = parse("on 2020-01-01 at 00:00 in UTC").unwrap();
-        let now = fixed_now();
-        let next = next_from(&s, &now).unwrap();
-        assert!(next.is_none());
+    fn test_interval_twelve_hour_window() {
+        let s = parse("every 30 min from 9am to 5pm on weekdays in UTC").unwrap();
+        let now = fixed_now(); // friday 12:00
+        assert_eq!(
+            next_from(&s, &now).unwrap().unwrap().to_string(),
+            "2026-02-06T12:30:00+00:00[UTC]"
+        );
+        let evening: Zoned = "2026-02-06T17:00:00+00:00[UTC]".parse().unwrap();
+        assert_eq!(
+            next_from(&s, &evening).unwrap().unwrap().to_string(),
+            "2026-02-09T09:00:00+00:00[UTC]"
+        );
+        assert_eq!(
+            previous_from(&s, &evening).unwrap().unwrap().to_string(),
+            "2026-02-06T16:30:00+00:00[UTC]"
+        );
     }
 
     #[test]
-    fn test_month_skip_31() {
-        // February doesn't have 31 days — should skip to March
-        let s = parse("every month on the 31st at 09:00 in UTC").unwrap();
-        let now = fixed_now();
-        let next = next_from(&s, &now).unwrap().unwrap();
-        assert_eq!(next.date(), Date::new(2026, 3, 31).unwrap());
+    fn test_interval_wraps_midnight_across_dst() {
+        // Spring forward in New York: 2026-03-08 02:00 EST -> 03:00 EDT
+        let s = parse("every 1 hour from 22:00 to 04:00 in America/New_York").unwrap();
+        let tz = TimeZone::get("America/New_York").unwrap();
+        let from = Date::new(2026, 3, 7)
+            .unwrap()
+            .to_datetime(Time::new(21, 0, 0, 0).unwrap())
+            .to_zoned(tz)
+            .unwrap();
+        let results = next_n_from(&s, &from, 7).unwrap();
+        let stamps: Vec<String> = results.iter().map(|z| z.to_string()).collect();
+        assert_eq!(
+            stamps,
+            [
+                "2026-03-07T22:00:00-05:00[America/New_York]",
+                "2026-03-07T23:00:00-05:00[America/New_York]",
+                "2026-03-08T00:00:00-05:00[America/New_York]",
+                "2026-03-08T01:00:00-05:00[America/New_York]",
+                "2026-03-08T03:00:00-04:00[America/New_York]",
+                "2026-03-08T04:00:00-04:00[America/New_York]",
+                "2026-03-08T22:00:00-04:00[America/New_York]",
+            ]
+        );
+        let last = &results[5];
+        assert_eq!(previous_from(&s, last).unwrap().as_ref(), Some(&results[4]));
     }
 
     #[test]
-    fn test_next_year_repeat_date() {
-        let s = parse("every year on dec 25 at 00:00 in UTC").unwrap();
-        let now = fixed_now();
-        let next = next_from(&s, &now).unwrap().unwrap();
-        assert_eq!(next.date(), Date::new(2026, 12, 25).unwrap());
+    fn test_interval_days_skips_filtered_aligned_days() {
+        let s = parse("every 3 days from 06:00 to 22:00 on weekday starting 2026-02-06 in UTC")
+            .unwrap();
+        let at = |d: i8, h: i8| {
+            Date::new(2026, 2, d)
+                .unwrap()
+                .to_datetime(Time::new(h, 0, 0, 0).unwrap())
+                .to_zoned(TimeZone::UTC)
+                .unwrap()
+        };
+        // Aligned days: Fri 6th, Mon 9th, Thu 12th, Sun 15th (filtered), Wed 18th
+        let results = next_n_from(&s, &fixed_now(), 3).unwrap();
+        assert_eq!(results, vec![at(9, 6), at(12, 6), at(18, 6)]);
+        assert_eq!(previous_from(&s, &at(18, 0)).unwrap(), Some(at(12, 6)));
+
+        assert!(matches(&s, &at(12, 6)).unwrap());
+        assert!(!matches(&s, &at(12, 7)).unwrap());
+        assert!(!matches(&s, &at(13, 6)).unwrap());
+        assert!(window_contains(&s, &at(12, 21)).unwrap());
+        assert!(!window_contains(&s, &at(13, 12)).unwrap());
+        assert!(!window_contains(&s, &at(15, 12)).unwrap());
     }
 
     #[test]
-    fn test_next_year_repeat_ordinal_weekday() {
-        let s = parse("every year on the first monday of march at 10:00 in UTC").unwrap();
-        let now = fixed_now();
-        let next = next_from(&s, &now).unwrap().unwrap();
-        assert_eq!(next.date(), Date::new(2026, 3, 2).unwrap());
+    fn test_matches_all_agrees_with_matches() {
+        for expr in [
+            "every weekday at 12:00 in America/New_York",
+            "every day at 12:00",
+        ] {
+            let s = parse(expr).unwrap();
+            let datetimes: Vec<Zoned> = (0..48)
+                .map(|h| fixed_now().checked_add(jiff::Span::new().hours(h)).unwrap())
+                .collect();
+            let expected: Vec<bool> = datetimes
+                .iter()
+                .map(|dt| matches(&s, dt).unwrap())
+                .collect();
+            assert_eq!(matches_all(&s, &datetimes).unwrap(), expected);
+            assert!(expected.contains(&true));
+        }
+        assert!(matches_all(&parse("every day at 12:00").unwrap(), &[])
+            .unwrap()
+            .is_empty());
     }
 
     #[test]
-    fn test_except_skips_holiday() {
-        // Every weekday at 09:00, except dec 25 and jan 1
-        let s = parse("every weekday at 09:00 except dec 25, jan 1 in UTC").unwrap();
-        // Set now to just before Christmas 2026 (dec 24 evening)
-        let now = Date::new(2026, 12, 24)
+    fn test_matches_date_agrees_with_occurrences_on() {
+        for expr in [
+            "every weekday at 09:00 except 2026-03-02",
+            "every 3 days at 08:00 starting 2026-02-01",
+            "every month on the first and last friday at 9:00 in America/New_York",
+            "every 2 weeks on monday at 07:00 starting 2026-02-02",
+            "every 30 min from 22:00 to 02:00 on friday",
+            "every 3 hours from 22:00 to 00:30 on friday",
+            "every year on the first monday of march at 10:00",
+            "every day at 12:00 until 2026-03-20 during mar",
+            // Jan 31 is a Saturday and Mar 1 a Sunday, so these spill over
+            "every month on the next nearest weekday to 31st at 09:00",
+            "every month on the previous nearest weekday to 1st at 09:00",
+            "every 2 months on the next nearest weekday to 31st at 09:00 starting 2026-01-01",
+            "every month on the next nearest weekday to 31st at 09:00 during jan",
+            "every month on the previous nearest weekday to 1st at 09:00 during mar",
+            // The starting time falls after some or all of the first day's times
+            "every day at 9:00 starting 2026-02-10 at 10:00",
+            "every day at 9:00, 11:00 starting 2026-02-10 at 10:00",
+            "every 30 min from 08:00 to 09:30 starting 2026-02-10 at 10:00",
+            "every 2 hours from 22:00 to 02:00 starting 2026-02-10 at 23:30",
+            "every 3 days from 06:00 to 22:00 starting 2026-02-10 at 07:00",
+        ] {
+            let s = parse(expr).unwrap();
+            let mut date = Date::new(2026, 2, 1).unwrap();
+            while date < Date::new(2026, 5, 1).unwrap() {
+                assert_eq!(
+                    matches_date(&s, date).unwrap(),
+                    !occurrences_on(&s, date).unwrap().is_empty(),
+                    "{expr} on {date}"
+                );
+                date = date.tomorrow().unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn test_matches_within_tolerance() {
+        let s = parse("every day at 09:00 in UTC").unwrap();
+        let late = Date::new(2026, 2, 7)
             .unwrap()
-            .to_datetime(Time::new(20, 0, 0, 0).unwrap())
+            .to_datetime(Time::new(9, 0, 3, 0).unwrap())
             .to_zoned(TimeZone::UTC)
             .unwrap();
-        let next = next_from(&s, &now).unwrap().unwrap();
-        // Dec 25 is Friday but excepted, so next = Dec 28 (Monday)
-        assert_eq!(next.date(), Date::new(2026, 12, 28).unwrap());
+        let early = Date::new(2026, 2, 7)
+            .unwrap()
+            .to_datetime(Time::new(8, 59, 55, 0).unwrap())
+            .to_zoned(TimeZone::UTC)
+            .unwrap();
+        let tolerance = jiff::Span::new().seconds(5);
+        assert!(!matches(&s, &early).unwrap());
+        assert!(matches_within(&s, &early, tolerance).unwrap());
+        assert!(matches_within(&s, &late, tolerance).unwrap());
+        assert!(!matches_within(&s, &early, jiff::Span::new().seconds(2)).unwrap());
     }
 
     #[test]
-    fn test_until_limits_results() {
-        let s = parse("every day at 09:00 until 2026-02-10 in UTC").unwrap();
-        let now = fixed_now();
-        let results = next_n_from(&s, &now, 10).unwrap();
-        // Should get Feb 7, 8, 9, 10 (4 results, not 10)
-        assert_eq!(results.len(), 4);
+    fn test_matches_within_bounds_inclusive() {
+        let s = parse("every day at 09:00 in UTC").unwrap();
+        let dt = Date::new(2026, 2, 7)
+            .unwrap()
+            .to_datetime(Time::new(9, 0, 5, 0).unwrap())
+            .to_zoned(TimeZone::UTC)
+            .unwrap();
+        assert!(matches_within(&s, &dt, jiff::Span::new().seconds(5)).unwrap());
+    }
+
+    #[test]
+    fn test_matches_within_zero_tolerance_is_exact() {
+        let s = parse("every day at 09:00 in UTC").unwrap();
+        let exact = Date::new(2026, 2, 7)
+            .unwrap()
+            .to_datetime(Time::new(9, 0, 0, 0).unwrap())
+            .to_zoned(TimeZone::UTC)
+            .unwrap();
+        assert!(matches_within(&s, &exact, jiff::Span::new()).unwrap());
+        assert!(!matches_within(&s, &fixed_now(), jiff::Span::new()).unwrap());
+    }
+
+    #[test]
+    fn test_count_in_year() {
+        let count = |input: &str| count_in_year(&parse(input).unwrap(), 2026).unwrap();
+        // 2026 has 261 weekdays
+        assert_eq!(count("every weekday at 09:00 in UTC"), 261);
+        assert_eq!(count("every day at 9:00, 17:00 during jan in UTC"), 62);
+        assert_eq!(count("every day at 09:00 until 2026-03-31 in UTC"), 90);
         assert_eq!(
-            results.last().unwrap().date(),
-            Date::new(2026, 2, 10).unwrap()
+            count("every day at 09:00 until 10 times starting 2026-03-01 in UTC"),
+            10
+        );
+        assert_eq!(
+            count("every 30 min from 09:00 to 17:00 on weekdays in UTC"),
+            17 * 261
+        );
+        assert_eq!(count("on 2027-01-01 at 09:00 in UTC"), 0);
+    }
+
+    #[test]
+    fn test_starting_bounds_every_api() {
+        let s = parse("every day at 09:00 starting 2027-01-01 in UTC").unwrap();
+        let before = Date::new(2026, 6, 1).unwrap();
+        let at_nine = before
+            .to_datetime(Time::new(9, 0, 0, 0).unwrap())
+            .to_zoned(TimeZone::UTC)
+            .unwrap();
+        assert!(!matches(&s, &at_nine).unwrap());
+        assert!(!matches_date(&s, before).unwrap());
+        assert!(dates_in_month(&s, 2026, 6).unwrap().is_empty());
+        assert_eq!(count_in_year(&s, 2026).unwrap(), 0);
+        assert!(matches_date(&s, Date::new(2027, 1, 1).unwrap()).unwrap());
+        assert_eq!(count_in_year(&s, 2027).unwrap(), 365);
+    }
+
+    #[test]
+    fn test_starting_time_bounds_date_level_apis() {
+        let feb_10 = Date::new(2026, 2, 10).unwrap();
+        let s = parse("every day at 9:00 starting 2026-02-10 at 10:00 in UTC").unwrap();
+        assert!(!matches_date(&s, feb_10).unwrap());
+        assert!(!dates_in_month(&s, 2026, 2).unwrap().contains(&feb_10));
+        assert_eq!(count_in_year(&s, 2026).unwrap(), 324);
+
+        let s = parse("every day at 9:00, 11:00 starting 2026-02-10 at 10:00 in UTC").unwrap();
+        assert!(matches_date(&s, feb_10).unwrap());
+        assert_eq!(dates_in_month(&s, 2026, 2).unwrap().first(), Some(&feb_10));
+        assert_eq!(count_in_year(&s, 2026).unwrap(), 649);
+    }
+
+    #[test]
+    fn test_approx_per_year() {
+        let rate = |input: &str| approx_per_year(&parse(input).unwrap());
+        assert_eq!(rate("every day at 09:00, 17:00"), Some(730.0));
+        assert_eq!(rate("every 2 weeks on mon, fri at 09:00"), Some(52.0));
+        assert_eq!(rate("every month on the 1st, 31st at 09:00"), Some(19.0));
+        assert_eq!(
+            rate("every month on the next nearest weekday to 31st at 09:00"),
+            Some(7.0)
+        );
+        assert_eq!(
+            rate("every month on the nearest weekday to 15th at 09:00"),
+            Some(12.0)
+        );
+        assert_eq!(rate("every year on feb 29 at 09:00"), Some(0.25));
+        assert_eq!(rate("every 1 hour from 22:00 to 02:00"), Some(5.0 * 365.0));
+        assert_eq!(rate("every day at 09:00 during jan to jun"), Some(182.5));
+        assert_eq!(rate("every year on dec 25 at 09:00 during jan"), Some(0.0));
+        assert_eq!(
+            rate("every year in iso weeks 1, 2 on mon, fri at 09:00"),
+            Some(4.0)
+        );
+        assert_eq!(
+            rate("every day at 09:00 except dec 25 until 5 times"),
+            Some(365.0)
         );
     }
 }
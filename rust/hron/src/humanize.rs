@@ -0,0 +1,122 @@
+//! Relative English descriptions of an upcoming occurrence ("in 5 minutes",
+//! "tomorrow at 09:00"). Locale-agnostic: bucket boundaries are fixed.
+
+use jiff::Zoned;
+
+/// Describe `next` relative to `now`, in the timezone of `next`.
+///
+/// Buckets, in order: under a minute, minutes (under an hour), hours (under
+/// six hours), then by calendar day: today, tomorrow, weekday name (under a
+/// week), weeks (under four weeks), and finally the full date.
+pub(crate) fn relative(now: &Zoned, next: &Zoned) -> String {
+    let seconds = next.timestamp().as_second() - now.timestamp().as_second();
+    let minutes = seconds / 60;
+
+    if minutes < 1 {
+        return "in less than a minute".to_string();
+    }
+    if minutes < 60 {
+        return format!("in {}", plural(minutes, "minute"));
+    }
+    if minutes < 6 * 60 {
+        let hours = (minutes + 30) / 60;
+        return format!("in about {}", plural(hours, "hour"));
+    }
+
+    let now_date = now.with_time_zone(next.time_zone().clone()).date();
+    let next_date = next.date();
+    let days = now_date.until(next_date).map_or(0, |s| s.get_days() as i64);
+    let time = next.time().strftime("%H:%M");
+
+    match days {
+        0 => format!("today at {time}"),
+        1 => format!("tomorrow at {time}"),
+        2..=6 => format!(
+            "on {} at {time}",
+            next_date.strftime("%A").to_string().to_lowercase()
+        ),
+        7..=27 => format!("in about {}", plural((days + 3) / 7, "week")),
+        _ => format!("on {next_date} at {time}"),
+    }
+}
+
+/// Label the calendar day of `next` for a list heading: `Today`, `Tomorrow`,
+/// then `Mon Feb 9`, with the year added once it differs from `now`'s.
+/// Days are counted in the timezone of `next`, as in [`relative`].
+pub(crate) fn day_label(now: &Zoned, next: &Zoned) -> String {
+    let now_date = now.with_time_zone(next.time_zone().clone()).date();
+    let next_date = next.date();
+    let days = now_date.until(next_date).map_or(0, |s| s.get_days() as i64);
+
+    match days {
+        0 => "Today".to_string(),
+        1 => "Tomorrow".to_string(),
+        _ if next_date.year() == now_date.year() => next_date.strftime("%a %b %-d").to_string(),
+        _ => next_date.strftime("%a %b %-d %Y").to_string(),
+    }
+}
+
+fn plural(n: i64, unit: &str) -> String {
+    if n == 1 {
+        format!("1 {unit}")
+    } else {
+        format!("{n} {unit}s")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> Zoned {
+        "2026-02-06T12:00:00+00:00[UTC]".parse().unwrap()
+    }
+
+    fn after(span: jiff::Span) -> Zoned {
+        now().checked_add(span).unwrap()
+    }
+
+    #[test]
+    fn test_minutes_and_hours() {
+        let s = jiff::Span::new();
+        assert_eq!(
+            relative(&now(), &after(s.seconds(30))),
+            "in less than a minute"
+        );
+        assert_eq!(relative(&now(), &after(s.minutes(1))), "in 1 minute");
+        assert_eq!(relative(&now(), &after(s.minutes(45))), "in 45 minutes");
+        assert_eq!(relative(&now(), &after(s.minutes(60))), "in about 1 hour");
+        assert_eq!(relative(&now(), &after(s.minutes(100))), "in about 2 hours");
+    }
+
+    #[test]
+    fn test_calendar_days() {
+        let s = jiff::Span::new();
+        assert_eq!(relative(&now(), &after(s.hours(8))), "today at 20:00");
+        assert_eq!(relative(&now(), &after(s.hours(21))), "tomorrow at 09:00");
+        assert_eq!(relative(&now(), &after(s.hours(69))), "on monday at 09:00");
+        assert_eq!(relative(&now(), &after(s.days(10))), "in about 1 week");
+        assert_eq!(relative(&now(), &after(s.days(25))), "in about 4 weeks");
+        assert_eq!(
+            relative(&now(), &after(s.days(40))),
+            "on 2026-03-18 at 12:00"
+        );
+    }
+
+    #[test]
+    fn test_day_label() {
+        let s = jiff::Span::new();
+        assert_eq!(day_label(&now(), &after(s.hours(8))), "Today");
+        assert_eq!(day_label(&now(), &after(s.hours(21))), "Tomorrow");
+        assert_eq!(day_label(&now(), &after(s.hours(69))), "Mon Feb 9");
+        assert_eq!(day_label(&now(), &after(s.days(340))), "Tue Jan 12 2027");
+    }
+
+    #[test]
+    fn test_days_counted_in_next_timezone() {
+        // Still Feb 6 in UTC, but already 00:30 on Feb 7 in Tokyo
+        let now: Zoned = "2026-02-06T15:30:00+00:00[UTC]".parse().unwrap();
+        let next: Zoned = "2026-02-08T09:00:00+09:00[Asia/Tokyo]".parse().unwrap();
+        assert_eq!(relative(&now, &next), "tomorrow at 09:00");
+    }
+}
@@ -1,4 +1,5 @@
 use crate::error::{ScheduleError, Span};
+use crate::prelude::*;
 
 /// Token produced by the lexer.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -15,6 +16,7 @@ pub enum TokenKind {
     At,
     From,
     To,
+    Through,
     In,
     Of,
     The,
@@ -27,12 +29,18 @@ pub enum TokenKind {
     Nearest,
     Next,
     Previous,
+    Between,
+    And,
+    Times,
+    Iso,
+    Past,
 
     // Day keywords
     Day,
     Weekday,
     Weekend,
     Weeks,
+    Business,
     Month,
 
     // Day names
@@ -42,15 +50,29 @@ pub enum TokenKind {
     MonthName(String), // lowercase short: "jan", "feb", ...
 
     // Ordinals
-    Ordinal(String), // "first", "second", "third", "fourth", "fifth"
+    Ordinal(String), // "first", "second", ... "thirty-first"
+
+    // Bare frequency shorthands
+    Frequency(String), // "daily", "weekly", "monthly", "yearly", "hourly"
+
+    // Cron shortcuts
+    Shortcut(String), // "@daily", "@hourly", ... — lowercase, with the '@'
+
+    // Time-of-day periods
+    Period(String), // "morning", "afternoon", "evening", "night"
+
+    // Relative anchors
+    Relative(String), // "today", "now"
 
     // Interval units
     IntervalUnit(String), // "min", "mins", "minute", "minutes", "hour", "hours", "hr", "hrs"
 
     // Literals
     Number(u32),
+    Decimal(String),    // 1.5 — raw text, converted by the parser
     OrdinalNumber(u32), // 1st, 2nd, 3rd, 15th — the number part
-    Time(u8, u8),       // HH:MM
+    Time(u8, u8),       // HH:MM, 9am, 5:30pm
+    MinuteOffset(u8),   // :15 — minutes past the hour
     IsoDate(String),    // 2026-03-15
 
     // Punctuation
@@ -106,6 +128,20 @@ impl<'a> Lexer<'a> {
                 continue;
             }
 
+            // Cron shortcut: @daily
+            if ch == b'@' {
+                let tok = self.lex_shortcut();
+                tokens.push(tok);
+                continue;
+            }
+
+            // Minutes past the hour: :MM
+            if ch == b':' {
+                let tok = self.lex_minute_offset()?;
+                tokens.push(tok);
+                continue;
+            }
+
             // Try time literal: HH:MM (but not ISO date YYYY-MM-DD)
             if ch.is_ascii_digit() {
                 let tok = self.lex_number_or_time_or_date()?;
@@ -129,6 +165,16 @@ impl<'a> Lexer<'a> {
         Ok(tokens)
     }
 
+    fn next_word_is(&self, word: &str) -> bool {
+        let rest = self.input[self.pos..].trim_start();
+        rest.len() >= word.len()
+            && rest[..word.len()].eq_ignore_ascii_case(word)
+            && !rest[word.len()..]
+                .bytes()
+                .next()
+                .is_some_and(|b| b.is_ascii_alphanumeric() || b == b'_')
+    }
+
     fn skip_whitespace(&mut self) {
         while self.pos < self.bytes.len() && self.bytes[self.pos].is_ascii_whitespace() {
             self.pos += 1;
@@ -151,12 +197,65 @@ impl<'a> Lexer<'a> {
                 self.input,
             ));
         }
+        // Nothing may follow the timezone, so trailing words after an
+        // `Area/Location` name are most likely a location with spaces in it
+        let rest = self.input[self.pos..].trim_end();
+        let continues_name = !rest.trim_start().is_empty()
+            && rest.split_whitespace().all(|w| {
+                w.bytes()
+                    .all(|b| b.is_ascii_alphabetic() || b == b'_' || b == b'-')
+            });
+        if tz.contains('/') && continues_name {
+            let suggestion: Vec<&str> = core::iter::once(tz)
+                .chain(rest.split_whitespace())
+                .collect();
+            return Err(ScheduleError::lex(
+                format!(
+                    "timezone names cannot contain spaces; did you mean '{}'?",
+                    suggestion.join("_")
+                ),
+                Span::new(start, self.pos + rest.len()),
+                self.input,
+            ));
+        }
         Ok(Token {
             kind: TokenKind::Timezone(tz.to_string()),
             span: Span::new(start, self.pos),
         })
     }
 
+    fn lex_shortcut(&mut self) -> Token {
+        let start = self.pos;
+        self.pos += 1; // skip '@'
+        while self.pos < self.bytes.len() && self.bytes[self.pos].is_ascii_alphanumeric() {
+            self.pos += 1;
+        }
+        Token {
+            kind: TokenKind::Shortcut(self.input[start..self.pos].to_lowercase()),
+            span: Span::new(start, self.pos),
+        }
+    }
+
+    fn lex_minute_offset(&mut self) -> Result<Token, ScheduleError> {
+        let start = self.pos;
+        self.pos += 1; // skip ':'
+        while self.pos < self.bytes.len() && self.bytes[self.pos].is_ascii_digit() {
+            self.pos += 1;
+        }
+        let digits = &self.input[start + 1..self.pos];
+        match digits.parse::<u8>() {
+            Ok(minute) if digits.len() == 2 && minute <= 59 => Ok(Token {
+                kind: TokenKind::MinuteOffset(minute),
+                span: Span::new(start, self.pos),
+            }),
+            _ => Err(ScheduleError::lex(
+                "expected two-digit minute (:00 to :59) after ':'",
+                Span::new(start, self.pos.max(start + 1)),
+                self.input,
+            )),
+        }
+    }
+
     fn lex_number_or_time_or_date(&mut self) -> Result<Token, ScheduleError> {
         let start = self.pos;
         // Read digits
@@ -211,6 +310,9 @@ impl<'a> Lexer<'a> {
                 let minute: u8 = min_digits.parse().map_err(|_| {
                     ScheduleError::lex("invalid minute", Span::new(start, self.pos), self.input)
                 })?;
+                if let Some(pm) = self.lex_meridiem() {
+                    return self.meridiem_time(hour, minute, pm, start);
+                }
                 if hour > 23 || minute > 59 {
                     return Err(ScheduleError::lex(
                         "invalid time",
@@ -225,6 +327,31 @@ impl<'a> Lexer<'a> {
             }
         }
 
+        // Check for 12-hour time without minutes: 9am, 5pm
+        if digits.len() <= 2 {
+            if let Some(pm) = self.lex_meridiem() {
+                let hour: u8 = digits.parse().map_err(|_| {
+                    ScheduleError::lex("invalid hour", Span::new(start, self.pos), self.input)
+                })?;
+                return self.meridiem_time(hour, 0, pm, start);
+            }
+        }
+
+        // Check for decimal: 1.5
+        if self.pos + 1 < self.bytes.len()
+            && self.bytes[self.pos] == b'.'
+            && self.bytes[self.pos + 1].is_ascii_digit()
+        {
+            self.pos += 1; // skip '.'
+            while self.pos < self.bytes.len() && self.bytes[self.pos].is_ascii_digit() {
+                self.pos += 1;
+            }
+            return Ok(Token {
+                kind: TokenKind::Decimal(self.input[start..self.pos].to_string()),
+                span: Span::new(start, self.pos),
+            });
+        }
+
         let num: u32 = digits.parse().map_err(|_| {
             ScheduleError::lex("invalid number", Span::new(start, self.pos), self.input)
         })?;
@@ -250,10 +377,66 @@ impl<'a> Lexer<'a> {
         })
     }
 
+    /// Consume an `am`/`pm` suffix directly following a time, returning whether it was `pm`.
+    fn lex_meridiem(&mut self) -> Option<bool> {
+        if self.pos + 1 >= self.bytes.len() {
+            return None;
+        }
+        let pm = match &self.bytes[self.pos..self.pos + 2] {
+            b"am" | b"AM" | b"Am" | b"aM" => false,
+            b"pm" | b"PM" | b"Pm" | b"pM" => true,
+            _ => return None,
+        };
+        // Must not run into a longer word (e.g. "9amx")
+        if self
+            .bytes
+            .get(self.pos + 2)
+            .is_some_and(|b| b.is_ascii_alphanumeric())
+        {
+            return None;
+        }
+        self.pos += 2;
+        Some(pm)
+    }
+
+    /// Convert a 12-hour clock time to a 24-hour `Time` token.
+    fn meridiem_time(
+        &self,
+        hour: u8,
+        minute: u8,
+        pm: bool,
+        start: usize,
+    ) -> Result<Token, ScheduleError> {
+        if !(1..=12).contains(&hour) || minute > 59 {
+            return Err(ScheduleError::lex(
+                "invalid 12-hour time (hour must be 1-12)",
+                Span::new(start, self.pos),
+                self.input,
+            ));
+        }
+        let hour = match (hour, pm) {
+            (12, false) => 0,
+            (12, true) => 12,
+            (h, false) => h,
+            (h, true) => h + 12,
+        };
+        Ok(Token {
+            kind: TokenKind::Time(hour, minute),
+            span: Span::new(start, self.pos),
+        })
+    }
+
     fn lex_word(&mut self) -> Result<Token, ScheduleError> {
         let start = self.pos;
         while self.pos < self.bytes.len()
-            && (self.bytes[self.pos].is_ascii_alphanumeric() || self.bytes[self.pos] == b'_')
+            && (self.bytes[self.pos].is_ascii_alphanumeric()
+                || self.bytes[self.pos] == b'_'
+                // hyphenated ordinals: "twenty-first"
+                || (self.bytes[self.pos] == b'-'
+                    && self
+                        .bytes
+                        .get(self.pos + 1)
+                        .is_some_and(|b| b.is_ascii_alphabetic())))
         {
             self.pos += 1;
         }
@@ -265,8 +448,10 @@ impl<'a> Lexer<'a> {
             "at" => TokenKind::At,
             "from" => TokenKind::From,
             "to" => TokenKind::To,
+            "through" => TokenKind::Through,
             "in" => {
-                self.after_in = true;
+                // "in the morning" and "in iso weeks" are not timezone clauses
+                self.after_in = !self.next_word_is("the") && !self.next_word_is("iso");
                 TokenKind::In
             }
             "of" => TokenKind::Of,
@@ -280,21 +465,31 @@ impl<'a> Lexer<'a> {
             "nearest" => TokenKind::Nearest,
             "next" => TokenKind::Next,
             "previous" => TokenKind::Previous,
+            "between" => TokenKind::Between,
+            "and" => TokenKind::And,
+            "time" | "times" | "occurrence" | "occurrences" => TokenKind::Times,
+            "iso" => TokenKind::Iso,
+            "past" => TokenKind::Past,
 
             "day" | "days" => TokenKind::Day,
             "weekday" | "weekdays" => TokenKind::Weekday,
+            "business" => TokenKind::Business,
             "weekend" | "weekends" => TokenKind::Weekend,
             "weeks" | "week" => TokenKind::Weeks,
             "month" | "months" => TokenKind::Month,
             "years" => TokenKind::Year,
 
-            "monday" | "mon" => TokenKind::DayName("monday".into()),
-            "tuesday" | "tue" => TokenKind::DayName("tuesday".into()),
-            "wednesday" | "wed" => TokenKind::DayName("wednesday".into()),
-            "thursday" | "thu" => TokenKind::DayName("thursday".into()),
-            "friday" | "fri" => TokenKind::DayName("friday".into()),
-            "saturday" | "sat" => TokenKind::DayName("saturday".into()),
-            "sunday" | "sun" => TokenKind::DayName("sunday".into()),
+            "daily" | "weekly" | "monthly" | "yearly" | "hourly" => {
+                TokenKind::Frequency(word.clone())
+            }
+
+            "monday" | "mondays" | "mon" => TokenKind::DayName("monday".into()),
+            "tuesday" | "tuesdays" | "tue" => TokenKind::DayName("tuesday".into()),
+            "wednesday" | "wednesdays" | "wed" => TokenKind::DayName("wednesday".into()),
+            "thursday" | "thursdays" | "thu" => TokenKind::DayName("thursday".into()),
+            "friday" | "fridays" | "fri" => TokenKind::DayName("friday".into()),
+            "saturday" | "saturdays" | "sat" => TokenKind::DayName("saturday".into()),
+            "sunday" | "sundays" | "sun" => TokenKind::DayName("sunday".into()),
 
             "january" | "jan" => TokenKind::MonthName("jan".into()),
             "february" | "feb" => TokenKind::MonthName("feb".into()),
@@ -309,11 +504,10 @@ impl<'a> Lexer<'a> {
             "november" | "nov" => TokenKind::MonthName("nov".into()),
             "december" | "dec" => TokenKind::MonthName("dec".into()),
 
-            "first" => TokenKind::Ordinal("first".into()),
-            "second" => TokenKind::Ordinal("second".into()),
-            "third" => TokenKind::Ordinal("third".into()),
-            "fourth" => TokenKind::Ordinal("fourth".into()),
-            "fifth" => TokenKind::Ordinal("fifth".into()),
+            "morning" | "afternoon" | "evening" | "night" => TokenKind::Period(word.clone()),
+            "today" | "now" => TokenKind::Relative(word.clone()),
+
+            w if ordinal_word_value(w).is_some() => TokenKind::Ordinal(word.clone()),
 
             "min" | "mins" | "minute" | "minutes" => TokenKind::IntervalUnit("min".into()),
             "hour" | "hours" | "hr" | "hrs" => TokenKind::IntervalUnit("hours".into()),
@@ -334,6 +528,40 @@ impl<'a> Lexer<'a> {
     }
 }
 
+/// Day number of an ordinal word: `first` is 1, `thirty-first` is 31.
+pub(crate) fn ordinal_word_value(word: &str) -> Option<u32> {
+    const UNITS: [&str; 9] = [
+        "first", "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth", "ninth",
+    ];
+    const TEENS: [&str; 10] = [
+        "tenth",
+        "eleventh",
+        "twelfth",
+        "thirteenth",
+        "fourteenth",
+        "fifteenth",
+        "sixteenth",
+        "seventeenth",
+        "eighteenth",
+        "nineteenth",
+    ];
+    let position = |list: &[&str], w: &str| list.iter().position(|u| *u == w).map(|i| i as u32);
+    match word {
+        "twentieth" => Some(20),
+        "thirtieth" => Some(30),
+        "thirty-first" => Some(31),
+        _ => {
+            if let Some(unit) = word.strip_prefix("twenty-") {
+                position(&UNITS, unit).map(|i| 21 + i)
+            } else {
+                position(&UNITS, word)
+                    .map(|i| 1 + i)
+                    .or_else(|| position(&TEENS, word).map(|i| 10 + i))
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -383,6 +611,41 @@ mod tests {
         assert_eq!(tokens[2].kind, TokenKind::IntervalUnit("min".into()));
     }
 
+    #[test]
+    fn test_meridiem_times() {
+        let mut lexer = Lexer::new("9am 5pm 12am 12pm 9:30PM");
+        let kinds: Vec<_> = lexer
+            .tokenize()
+            .unwrap()
+            .into_iter()
+            .map(|t| t.kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                TokenKind::Time(9, 0),
+                TokenKind::Time(17, 0),
+                TokenKind::Time(0, 0),
+                TokenKind::Time(12, 0),
+                TokenKind::Time(21, 30),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_meridiem_invalid_hour() {
+        assert!(Lexer::new("13pm").tokenize().is_err());
+        assert!(Lexer::new("0am").tokenize().is_err());
+    }
+
+    #[test]
+    fn test_between_and_tokens() {
+        let mut lexer = Lexer::new("every 15 min between 9am and 5pm");
+        let tokens = lexer.tokenize().unwrap();
+        assert_eq!(tokens[3].kind, TokenKind::Between);
+        assert_eq!(tokens[5].kind, TokenKind::And);
+    }
+
     #[test]
     fn test_except_token() {
         let mut lexer = Lexer::new("every weekday at 09:00 except dec 25");
@@ -412,4 +675,57 @@ mod tests {
         let tokens = lexer.tokenize().unwrap();
         assert_eq!(tokens[1].kind, TokenKind::Year);
     }
+
+    #[test]
+    fn test_ordinal_words() {
+        let mut lexer = Lexer::new("first twenty-first Thirty-First");
+        let tokens = lexer.tokenize().unwrap();
+        for (tok, word) in tokens.iter().zip(["first", "twenty-first", "thirty-first"]) {
+            assert_eq!(tok.kind, TokenKind::Ordinal(word.into()));
+        }
+        assert_eq!(ordinal_word_value("twelfth"), Some(12));
+        assert_eq!(ordinal_word_value("twenty-ninth"), Some(29));
+        assert_eq!(ordinal_word_value("thirty-second"), None);
+        assert!(Lexer::new("twenty-").tokenize().is_err());
+    }
+
+    #[test]
+    fn test_minute_offset() {
+        let mut lexer = Lexer::new("at :05 past every hour");
+        let tokens = lexer.tokenize().unwrap();
+        assert_eq!(tokens[1].kind, TokenKind::MinuteOffset(5));
+        assert_eq!(tokens[2].kind, TokenKind::Past);
+        assert!(Lexer::new("every hour at :60").tokenize().is_err());
+        assert!(Lexer::new("every hour at :5").tokenize().is_err());
+    }
+
+    #[test]
+    fn test_timezone_with_spaces() {
+        let err = Lexer::new("every day at 9:00 in America/New York")
+            .tokenize()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "timezone names cannot contain spaces; did you mean 'America/New_York'?"
+        );
+        assert_eq!(err.span(), Some(Span::new(21, 37)));
+
+        let err = Lexer::new("every day in America/Port of Spain ")
+            .tokenize()
+            .unwrap_err();
+        assert!(err.to_string().contains("'America/Port_of_Spain'"));
+
+        // Not a continuation of an Area/Location name
+        let err = Lexer::new("every day in UTC foo").tokenize().unwrap_err();
+        assert!(!err.to_string().contains("spaces"));
+    }
+
+    #[test]
+    fn test_in_the_period_is_not_timezone() {
+        let mut lexer = Lexer::new("every day in the morning in Europe/London");
+        let tokens = lexer.tokenize().unwrap();
+        assert_eq!(tokens[3].kind, TokenKind::The);
+        assert_eq!(tokens[4].kind, TokenKind::Period("morning".into()));
+        assert_eq!(tokens[6].kind, TokenKind::Timezone("Europe/London".into()));
+    }
 }
@@ -10,23 +10,55 @@
 //! let schedule: Schedule = "every weekday at 09:00".parse().unwrap();
 //! println!("{}", schedule); // "every weekday at 09:00"
 //! ```
+//!
+//! # Features
+//!
+//! - `eval` (default): occurrence evaluation via `jiff` — `next_from`,
+//!   `matches`, iterators, humanized output and strict timezone checks.
+//! - `serde` (default): `Serialize`/`Deserialize` for [`Schedule`] and
+//!   `json_schema`.
+//! - `tokio`: async occurrence streams.
+//!
+//! With default features off the crate is `no_std` + `alloc`: schedules can
+//! still be parsed, inspected, displayed and converted to and from cron.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 pub mod ast;
 pub(crate) mod cron;
 pub(crate) mod display;
 pub mod error;
+#[cfg(feature = "eval")]
 pub(crate) mod eval;
+#[cfg(feature = "eval")]
+pub(crate) mod humanize;
 pub(crate) mod lexer;
 pub(crate) mod parser;
+pub(crate) mod prelude;
+#[cfg(feature = "serde")]
+pub(crate) mod schema;
+#[cfg(feature = "tokio")]
+pub(crate) mod stream;
 
 pub use ast::{Schedule, ScheduleExpr};
+pub use cron::CronBitmasks;
 pub use error::ScheduleError;
-pub use eval::{BoundedOccurrences, Occurrences};
+#[cfg(feature = "eval")]
+pub use eval::{
+    BoundedOccurrences, DstAnomaly, OccurrenceDates, Occurrences, ScheduledItem, SteppedOccurrences,
+};
+#[cfg(feature = "serde")]
+pub use schema::json_schema;
 
+use crate::prelude::*;
+use core::fmt;
+use core::str::FromStr;
+#[cfg(feature = "eval")]
 use jiff::Zoned;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use std::str::FromStr;
 
 // --- Schedule convenience methods ---
 
@@ -49,12 +81,87 @@ impl Schedule {
         parser::parse(input)
     }
 
-    /// Compute the next occurrence after `now`.
+    /// Parse a config file of hron expressions, one per line.
+    ///
+    /// Blank lines and lines starting with `#` are skipped. Each remaining
+    /// line is returned with its 1-based line number and parse result, so one
+    /// bad line does not hide the rest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let input = "# backups\nevery day at 02:00\n\nevery blursday at 9:00\n";
+    /// let results = Schedule::parse_all(input);
+    /// assert_eq!(results.len(), 2);
+    /// assert_eq!(results[0].0, 2);
+    /// assert!(results[0].1.is_ok());
+    /// assert_eq!(results[1].0, 4);
+    /// assert!(results[1].1.is_err());
+    /// ```
+    pub fn parse_all(input: &str) -> Vec<(usize, Result<Self, ScheduleError>)> {
+        parser::parse_all(input)
+    }
+
+    /// Parse an hron expression string, also checking that the `in` timezone
+    /// exists in the tz database.
+    ///
+    /// [`parse`](Self::parse) defers that check to evaluation, which suits
+    /// environments where the tz database may differ between parse and eval
+    /// (e.g. wasm). Use this for linting configs up front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// assert!(Schedule::parse("every day at 09:00 in Mars/Phobos").is_ok());
+    /// assert!(Schedule::parse_strict("every day at 09:00 in Mars/Phobos").is_err());
+    /// ```
+    #[cfg(feature = "eval")]
+    pub fn parse_strict(input: &str) -> Result<Self, ScheduleError> {
+        parser::parse_strict(input)
+    }
+
+    /// Parse an hron expression string, also returning the byte range of
+    /// each trailing clause (`except`, `until`, `starting`, `during`, `in`),
+    /// e.g. for highlighting them in an editor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::ast::Clause;
+    /// use hron::Schedule;
+    ///
+    /// let input = "every weekday at 9:00 except dec 25, jan 1 in UTC";
+    /// let (schedule, spans) = Schedule::parse_with_spans(input).unwrap();
+    /// assert_eq!(schedule, Schedule::parse(input).unwrap());
+    ///
+    /// let except = spans.clause_span(Clause::Except).unwrap();
+    /// assert_eq!(&input[except.start..except.end], "except dec 25, jan 1");
+    /// assert!(spans.clause_span(Clause::Until).is_none());
+    /// ```
+    pub fn parse_with_spans(input: &str) -> Result<(Self, ast::ClauseSpans), ScheduleError> {
+        parser::parse_with_spans(input)
+    }
+
+    /// Compute the next occurrence strictly after `now`.
+    ///
+    /// If `now` is itself an occurrence it is skipped; use
+    /// [`next_after`](Self::next_after) for an inclusive lower bound.
     ///
     /// Returns `Ok(None)` when there are no future occurrences (e.g., past the
     /// `until` date). Returns `Err` on evaluation errors such as invalid
     /// timezone or date arithmetic overflow.
     ///
+    /// **Starting:** A `starting` date is both the interval alignment anchor and
+    /// a lower bound, so when `now` is before it the result is on or after it.
+    ///
+    /// **Timezone:** Schedules without an `in` clause are evaluated in UTC, not
+    /// in the timezone of `now`. Use [`with_floating_time`](Self::with_floating_time)
+    /// to evaluate them in local civil time instead.
+    ///
     /// **DST behavior:** When a scheduled time falls in a DST gap (e.g. 2:30 AM
     /// during spring-forward), the occurrence shifts to the next valid time as
     /// resolved by the `jiff` library. During fall-back, ambiguous times resolve
@@ -70,10 +177,175 @@ impl Schedule {
     /// let next = schedule.next_from(&now).unwrap().unwrap();
     /// assert_eq!(next.to_string(), "2025-06-15T09:00:00+00:00[UTC]");
     /// ```
+    #[cfg(feature = "eval")]
     pub fn next_from(&self, now: &Zoned) -> Result<Option<Zoned>, ScheduleError> {
         eval::next_from(self, now)
     }
 
+    /// Like [`next_from`](Self::next_from), but evaluated in the IANA
+    /// timezone `tz` for this call only, as if the expression ended in
+    /// `in <tz>`. The override replaces any `in` clause and floating time.
+    ///
+    /// Lets one parsed schedule fire per tenant without re-parsing. An
+    /// unknown zone returns a [`ScheduleError::Timezone`] error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every day at 09:00").unwrap();
+    /// let now: jiff::Zoned = "2025-06-15T08:00:00+00:00[UTC]".parse().unwrap();
+    ///
+    /// let tokyo = schedule.next_from_in(&now, "Asia/Tokyo").unwrap().unwrap();
+    /// assert_eq!(tokyo.to_string(), "2025-06-16T09:00:00+09:00[Asia/Tokyo]");
+    /// let denver = schedule.next_from_in(&now, "America/Denver").unwrap().unwrap();
+    /// assert_eq!(denver.to_string(), "2025-06-15T09:00:00-06:00[America/Denver]");
+    /// ```
+    #[cfg(feature = "eval")]
+    pub fn next_from_in(&self, now: &Zoned, tz: &str) -> Result<Option<Zoned>, ScheduleError> {
+        eval::next_from_in(self, now, tz)
+    }
+
+    /// Like [`next_from`](Self::next_from), also skipping any date for which
+    /// `is_holiday` returns true, as if it were listed in `except`.
+    ///
+    /// Use this for calendars that move from year to year (public holidays,
+    /// exchange closures) without spelling every date into the expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every weekday at 09:00 in UTC").unwrap();
+    /// let now: jiff::Zoned = "2025-07-03T12:00:00+00:00[UTC]".parse().unwrap();
+    /// let independence_day = jiff::civil::date(2025, 7, 4);
+    ///
+    /// let next = schedule
+    ///     .next_from_with_calendar(&now, &|d| d == independence_day)
+    ///     .unwrap()
+    ///     .unwrap();
+    /// assert_eq!(next.date(), jiff::civil::date(2025, 7, 7));
+    /// ```
+    #[cfg(feature = "eval")]
+    pub fn next_from_with_calendar(
+        &self,
+        now: &Zoned,
+        is_holiday: &dyn Fn(jiff::civil::Date) -> bool,
+    ) -> Result<Option<Zoned>, ScheduleError> {
+        eval::next_from_with_calendar(self, now, is_holiday)
+    }
+
+    /// Compute the next occurrence after the civil datetime `now`, as a civil
+    /// datetime with no timezone or DST involved.
+    ///
+    /// Only for schedules without an `in` clause; schedules with one return
+    /// `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    /// use jiff::civil::date;
+    ///
+    /// let schedule = Schedule::parse("every day at 09:00").unwrap();
+    /// let next = schedule.next_civil_from(date(2026, 2, 6).at(12, 0, 0, 0)).unwrap();
+    /// assert_eq!(next, Some(date(2026, 2, 7).at(9, 0, 0, 0)));
+    /// ```
+    #[cfg(feature = "eval")]
+    pub fn next_civil_from(
+        &self,
+        now: jiff::civil::DateTime,
+    ) -> Result<Option<jiff::civil::DateTime>, ScheduleError> {
+        eval::next_civil_from(self, now)
+    }
+
+    /// Compute the next occurrence at or after `instant`.
+    ///
+    /// Unlike [`next_from`](Self::next_from), an `instant` that is exactly an
+    /// occurrence of this schedule is returned as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every day at 09:00 in UTC").unwrap();
+    /// let instant: jiff::Zoned = "2025-06-15T09:00:00+00:00[UTC]".parse().unwrap();
+    /// assert_eq!(schedule.next_after(&instant).unwrap().unwrap(), instant);
+    ///
+    /// let next = schedule.next_from(&instant).unwrap().unwrap();
+    /// assert_eq!(next.to_string(), "2025-06-16T09:00:00+00:00[UTC]");
+    /// ```
+    #[cfg(feature = "eval")]
+    pub fn next_after(&self, instant: &Zoned) -> Result<Option<Zoned>, ScheduleError> {
+        eval::next_after(self, instant)
+    }
+
+    /// Describe the next occurrence after `now` in relative English, such as
+    /// `"in 45 minutes"`, `"in about 2 hours"`, or `"tomorrow at 09:00"`.
+    ///
+    /// Calendar-day wording uses the timezone of the occurrence. Returns
+    /// `None` when there is no next occurrence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every day at 09:00 in UTC").unwrap();
+    /// let now: jiff::Zoned = "2025-06-15T12:00:00+00:00[UTC]".parse().unwrap();
+    /// let text = schedule.next_from_humanized(&now).unwrap();
+    /// assert_eq!(text.as_deref(), Some("tomorrow at 09:00"));
+    /// ```
+    #[cfg(feature = "eval")]
+    pub fn next_from_humanized(&self, now: &Zoned) -> Result<Option<String>, ScheduleError> {
+        Ok(self
+            .next_from(now)?
+            .map(|next| humanize::relative(now, &next)))
+    }
+
+    /// The next `n` occurrences after `now`, each paired with a label for its
+    /// calendar day: `"Today"`, `"Tomorrow"`, or `"Mon Feb 9"` (with the
+    /// year once it differs from `now`'s). Meant for digests that list
+    /// upcoming runs under day headings.
+    ///
+    /// Occurrences on the same day share a label and arrive consecutively, so
+    /// grouping them is a matter of starting a new heading whenever the label
+    /// changes. Days are counted in the timezone of each occurrence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every weekday at 09:00, 17:00 in UTC").unwrap();
+    /// let now: jiff::Zoned = "2026-02-05T12:00:00+00:00[UTC]".parse().unwrap(); // thursday
+    /// let labels: Vec<String> = schedule
+    ///     .humanize_next_list(&now, 4)
+    ///     .unwrap()
+    ///     .into_iter()
+    ///     .map(|(_, label)| label)
+    ///     .collect();
+    /// assert_eq!(labels, ["Today", "Tomorrow", "Tomorrow", "Mon Feb 9"]);
+    /// ```
+    #[cfg(feature = "eval")]
+    pub fn humanize_next_list(
+        &self,
+        now: &Zoned,
+        n: usize,
+    ) -> Result<Vec<(Zoned, String)>, ScheduleError> {
+        Ok(self
+            .next_n_from(now, n)?
+            .into_iter()
+            .map(|next| {
+                let label = humanize::day_label(now, &next);
+                (next, label)
+            })
+            .collect())
+    }
+
     /// Compute the next `n` occurrences after `now`.
     ///
     /// # Examples
@@ -88,10 +360,56 @@ impl Schedule {
     /// assert_eq!(next_3[0].to_string(), "2025-06-15T09:00:00+00:00[UTC]");
     /// assert_eq!(next_3[2].to_string(), "2025-06-17T09:00:00+00:00[UTC]");
     /// ```
+    #[cfg(feature = "eval")]
     pub fn next_n_from(&self, now: &Zoned, n: usize) -> Result<Vec<Zoned>, ScheduleError> {
         eval::next_n_from(self, now, n)
     }
 
+    /// Compute up to `n` occurrences after `now`, keeping the ones found
+    /// before an error (such as running past the end of the supported date
+    /// range) along with the error that stopped the enumeration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every day at 09:00 in UTC").unwrap();
+    /// let now: jiff::Zoned = "9999-12-28T12:00:00+00:00[UTC]".parse().unwrap();
+    /// let (found, err) = schedule.next_n_from_partial(&now, 5);
+    /// assert_eq!(found.len(), 2);
+    /// assert_eq!(found[1].to_string(), "9999-12-30T09:00:00+00:00[UTC]");
+    /// assert!(err.is_some());
+    /// ```
+    #[cfg(feature = "eval")]
+    pub fn next_n_from_partial(
+        &self,
+        now: &Zoned,
+        n: usize,
+    ) -> (Vec<Zoned>, Option<ScheduleError>) {
+        eval::next_n_from_partial(self, now, n)
+    }
+
+    /// Skip `count` occurrences after `now` and return the one after them,
+    /// without collecting the skipped ones. `count = 0` is
+    /// [`next_from`](Self::next_from). Returns `Err` when the skipped
+    /// distance overflows the supported date range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every day at 09:00 in UTC").unwrap();
+    /// let now: jiff::Zoned = "2025-06-15T08:00:00+00:00[UTC]".parse().unwrap();
+    /// let next = schedule.next_skipping(&now, 999).unwrap().unwrap();
+    /// assert_eq!(next.to_string(), "2028-03-10T09:00:00+00:00[UTC]");
+    /// ```
+    #[cfg(feature = "eval")]
+    pub fn next_skipping(&self, now: &Zoned, count: usize) -> Result<Option<Zoned>, ScheduleError> {
+        eval::next_skipping(self, now, count)
+    }
+
     /// Compute the most recent occurrence strictly before `now`.
     ///
     /// Returns `None` if no previous occurrence exists, which can happen when:
@@ -108,12 +426,19 @@ impl Schedule {
     /// let prev = schedule.previous_from(&now).unwrap().unwrap();
     /// assert_eq!(prev.to_string(), "2025-06-15T09:00:00+00:00[UTC]");
     /// ```
+    #[cfg(feature = "eval")]
     pub fn previous_from(&self, now: &Zoned) -> Result<Option<Zoned>, ScheduleError> {
         eval::previous_from(self, now)
     }
 
     /// Check if a datetime matches this schedule.
     ///
+    /// A relative anchor (`starting today` / `starting now`) is an error here:
+    /// resolving it against `datetime` would make every date its own anchor.
+    /// Pin it with [`with_anchor`](Self::with_anchor) first. An `on friday`
+    /// one-shot is an error for the same reason: every friday would be the
+    /// upcoming one.
+    ///
     /// # Examples
     ///
     /// ```
@@ -127,129 +452,912 @@ impl Schedule {
     /// let non_matching: jiff::Zoned = "2025-06-15T10:00:00+00:00[UTC]".parse().unwrap();
     /// assert!(!schedule.matches(&non_matching).unwrap());
     /// ```
+    #[cfg(feature = "eval")]
     pub fn matches(&self, datetime: &Zoned) -> Result<bool, ScheduleError> {
         eval::matches(self, datetime)
     }
 
-    /// Set the anchor date for multi-week intervals.
+    /// Check each datetime against the schedule, returning one result per input.
+    ///
+    /// Cheaper than calling [`matches`](Self::matches) in a loop when the
+    /// schedule has an `in` timezone, which is resolved only once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every day at 09:00 in UTC").unwrap();
+    /// let slots: Vec<jiff::Zoned> = ["2025-06-15T09:00:00+00:00[UTC]", "2025-06-15T10:00:00+00:00[UTC]"]
+    ///     .iter()
+    ///     .map(|s| s.parse().unwrap())
+    ///     .collect();
+    /// assert_eq!(schedule.matches_all(&slots).unwrap(), vec![true, false]);
+    /// ```
+    #[cfg(feature = "eval")]
+    pub fn matches_all(&self, datetimes: &[Zoned]) -> Result<Vec<bool>, ScheduleError> {
+        eval::matches_all(self, datetimes)
+    }
+
+    /// Check whether the schedule fires at any time on `date`.
+    ///
+    /// Only date-level rules are checked (day filters, intervals, targets,
+    /// `during`, `except` and `until`); the `at` times are ignored.
+    ///
+    /// Like [`matches`](Self::matches), this errors for a relative anchor
+    /// (`starting today` / `starting now`) or an `on <weekday>` date, which
+    /// need an evaluation time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every weekday at 09:00 except dec 25").unwrap();
+    /// assert!(schedule.matches_date(jiff::civil::date(2026, 12, 24)).unwrap());
+    /// assert!(!schedule.matches_date(jiff::civil::date(2026, 12, 25)).unwrap());
+    /// assert!(!schedule.matches_date(jiff::civil::date(2026, 12, 26)).unwrap());
+    /// ```
+    #[cfg(feature = "eval")]
+    pub fn matches_date(&self, date: jiff::civil::Date) -> Result<bool, ScheduleError> {
+        eval::matches_date(self, date)
+    }
+
+    /// List the dates in a calendar month on which the schedule fires.
+    ///
+    /// Dates are civil dates in the schedule's timezone, computed directly from
+    /// the expression's targets, so this is much cheaper than filtering
+    /// [`between`](Self::between) for a month grid. `except`, `until`,
+    /// `starting`, and `during` are applied; named `until`/`starting` dates
+    /// resolve relative to the requested month.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    /// use jiff::civil::date;
+    ///
+    /// let schedule = Schedule::parse("every month on the 1st, 15th at 09:00 except 2026-02-15").unwrap();
+    /// assert_eq!(schedule.dates_in_month(2026, 2).unwrap(), vec![date(2026, 2, 1)]);
+    /// ```
+    #[cfg(feature = "eval")]
+    pub fn dates_in_month(
+        &self,
+        year: i16,
+        month: i8,
+    ) -> Result<Vec<jiff::civil::Date>, ScheduleError> {
+        eval::dates_in_month(self, year, month)
+    }
+
+    /// List every occurrence on one civil date, for day-detail views.
+    ///
+    /// The window runs from the start of `date` to the start of the next day
+    /// in the schedule's timezone, so DST days of 23 or 25 hours are covered
+    /// exactly. Floating schedules are evaluated in UTC, as in
+    /// [`dates_in_month`](Self::dates_in_month).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    /// use jiff::civil::date;
+    ///
+    /// let schedule = Schedule::parse("every weekday at 09:00, 17:00 in Europe/London").unwrap();
+    /// let times = schedule.occurrences_on(date(2026, 3, 30)).unwrap();
+    /// assert_eq!(times.len(), 2);
+    /// assert_eq!(times[0].to_string(), "2026-03-30T09:00:00+01:00[Europe/London]");
+    /// ```
+    #[cfg(feature = "eval")]
+    pub fn occurrences_on(&self, date: jiff::civil::Date) -> Result<Vec<Zoned>, ScheduleError> {
+        eval::occurrences_on(self, date)
+    }
+
+    /// Count the occurrences in a calendar year exactly, honoring `except`,
+    /// `during`, `until`, and `starting`. See
+    /// [`approx_per_year`](Self::approx_per_year) for a cheap estimate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every weekday at 09:00 except dec 25 in UTC").unwrap();
+    /// assert_eq!(schedule.count_in_year(2026).unwrap(), 260);
+    /// ```
+    #[cfg(feature = "eval")]
+    pub fn count_in_year(&self, year: i16) -> Result<usize, ScheduleError> {
+        eval::count_in_year(self, year)
+    }
+
+    /// Check if a datetime falls inside the schedule's active window.
+    ///
+    /// For interval schedules (`every 30 min from 09:00 to 17:00`), returns true
+    /// when `datetime` is within `[from, to]` on a matching day, ignoring the
+    /// step. For all other schedules this is the same as [`matches`](Self::matches).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every 30 min from 09:00 to 17:00 in UTC").unwrap();
+    /// let now: jiff::Zoned = "2025-06-15T12:17:00+00:00[UTC]".parse().unwrap();
+    /// assert!(!schedule.matches(&now).unwrap());
+    /// assert!(schedule.window_contains(&now).unwrap());
+    /// ```
+    #[cfg(feature = "eval")]
+    pub fn window_contains(&self, datetime: &Zoned) -> Result<bool, ScheduleError> {
+        eval::window_contains(self, datetime)
+    }
+
+    /// Check if any occurrence lies within `tolerance` of `datetime`.
+    ///
+    /// Useful when a job runner wakes a few seconds off the scheduled instant.
+    /// Both bounds are inclusive. [`matches`](Self::matches) remains exact.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every day at 09:00 in UTC").unwrap();
+    /// let woke: jiff::Zoned = "2025-06-15T08:59:57+00:00[UTC]".parse().unwrap();
+    /// assert!(!schedule.matches(&woke).unwrap());
+    /// assert!(schedule.matches_within(&woke, jiff::Span::new().seconds(5)).unwrap());
+    /// ```
+    #[cfg(feature = "eval")]
+    pub fn matches_within(
+        &self,
+        datetime: &Zoned,
+        tolerance: jiff::Span,
+    ) -> Result<bool, ScheduleError> {
+        eval::matches_within(self, datetime, tolerance)
+    }
+
+    /// Set the anchor date for multi-week intervals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// # #[cfg(feature = "eval")]
+    /// # {
+    /// // Every 2 weeks on Monday, anchored to a specific start date
+    /// let schedule = Schedule::parse("every 2 weeks on monday at 09:00 in UTC").unwrap()
+    ///     .with_anchor(jiff::civil::date(2025, 1, 6));
+    /// let now: jiff::Zoned = "2025-01-19T10:00:00+00:00[UTC]".parse().unwrap();
+    /// let next = schedule.next_from(&now).unwrap().unwrap();
+    /// assert_eq!(next.to_string(), "2025-01-20T09:00:00+00:00[UTC]");
+    /// # }
+    /// ```
+    ///
+    /// Accepts a `jiff::civil::Date` (with `eval`) or an [`ast::CivilDate`].
+    pub fn with_anchor(mut self, date: impl Into<ast::CivilDate>) -> Self {
+        self.anchor = Some(date.into());
+        self.anchor_relative = None;
+        self
+    }
+
+    /// Return a copy with semantically equivalent forms normalized, for
+    /// deduplication and equality checks. See [`normalize`](Self::normalize).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::ast::{DayFilter, ScheduleExpr, TimeOfDay, Weekday};
+    /// use hron::Schedule;
+    ///
+    /// let built = Schedule::new(ScheduleExpr::DayRepeat {
+    ///     interval: 1,
+    ///     days: DayFilter::Days(Weekday::all_weekdays()),
+    ///     times: vec![TimeOfDay::MORNING],
+    /// });
+    /// let keyword = Schedule::parse("every weekday at 09:00").unwrap();
+    /// assert_ne!(built, keyword);
+    /// assert_eq!(built.canonical_form(), keyword);
+    /// ```
+    pub fn canonical_form(&self) -> Self {
+        let mut schedule = self.clone();
+        schedule.normalize();
+        schedule
+    }
+
+    /// Write the expression (the same text as `to_string()`) into `w`
+    /// without allocating, e.g. to reuse one buffer across log lines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every weekday at 9:00 except dec 25").unwrap();
+    /// let mut line = String::from("next run: ");
+    /// schedule.write_canonical(&mut line).unwrap();
+    /// assert_eq!(line, "next run: every weekday at 09:00 except dec 25");
+    /// ```
+    pub fn write_canonical(&self, w: &mut impl fmt::Write) -> fmt::Result {
+        write!(w, "{self}")
+    }
+
+    /// Canonicalize the schedule in place, so equivalent schedules have equal
+    /// ASTs and display identically.
+    ///
+    /// - `at` times are sorted and de-duplicated.
+    /// - Day lists are sorted and de-duplicated, and day filters covering
+    ///   exactly the weekdays, the weekend, or all seven days collapse to
+    ///   `weekday`, `weekend`, and `day`.
+    /// - Ordinal days (`the 15th, 1st`) and ISO week numbers are sorted.
+    /// - `on` date lists are sorted and de-duplicated: named dates first,
+    ///   then ISO dates.
+    /// - `except` entries are de-duplicated and ordered weekdays first, then
+    ///   named dates, then ISO dates.
+    /// - `during` specs that overlap or touch are merged and ordered by their
+    ///   first day, so `during jun, jul to aug` becomes `during jun to aug`.
+    ///
+    /// Parsing already normalizes times, day lists, and `on` date lists. It
+    /// keeps `except` and `during` in the order written, which `normalize`
+    /// does not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let mut schedule =
+    ///     Schedule::parse("every month on the 15th, 1st at 09:00 except dec 25, jan 1").unwrap();
+    /// schedule.normalize();
+    /// assert_eq!(
+    ///     schedule.to_string(),
+    ///     "every month on the 1st, 15th at 09:00 except jan 1, dec 25"
+    /// );
+    /// ```
+    pub fn normalize(&mut self) {
+        if let Some(times) = self.expr.times_mut() {
+            times.sort();
+            times.dedup();
+        }
+        let sort_days = |days: &mut Vec<ast::Weekday>| {
+            days.sort_by_key(|d| d.number());
+            days.dedup();
+        };
+        match &mut self.expr {
+            ScheduleExpr::DayRepeat { days, .. } => *days = days.canonical(),
+            ScheduleExpr::IntervalRepeat {
+                day_filter: Some(df),
+                ..
+            } => *df = df.canonical(),
+            ScheduleExpr::MonthRepeat {
+                target, day_filter, ..
+            } => {
+                if let ast::MonthTarget::Days(specs) = target {
+                    specs.sort_by_key(|spec| spec.sort_key());
+                    specs.dedup();
+                }
+                if let Some(df) = day_filter {
+                    *df = df.canonical();
+                }
+            }
+            ScheduleExpr::MultiDate { dates, .. } => {
+                dates.sort_by(|a, b| a.sort_key().cmp(&b.sort_key()));
+                dates.dedup();
+            }
+            ScheduleExpr::WeekRepeat { days, .. } => sort_days(days),
+            ScheduleExpr::IsoWeekRepeat { weeks, days, .. } => {
+                weeks.sort();
+                weeks.dedup();
+                sort_days(days);
+            }
+            _ => {}
+        }
+
+        self.except
+            .sort_by(|a, b| exception_key(a).cmp(&exception_key(b)));
+        self.except.dedup();
+
+        self.during = ast::DuringSpec::merged(&self.during);
+    }
+
+    /// Fold `other` into this schedule when the two differ only in their
+    /// `at` times, giving one schedule with the sorted, de-duplicated union.
+    ///
+    /// `None` when anything else differs (expression, modifiers, timezone),
+    /// or for interval windows, which have no time list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let morning = Schedule::parse("every weekday at 09:00").unwrap();
+    /// let evening = Schedule::parse("every weekday at 17:00, 09:00").unwrap();
+    /// let merged = morning.merge_times(&evening).unwrap();
+    /// assert_eq!(merged.to_string(), "every weekday at 09:00, 17:00");
+    ///
+    /// let weekend = Schedule::parse("every weekend at 17:00").unwrap();
+    /// assert!(morning.merge_times(&weekend).is_none());
+    /// ```
+    pub fn merge_times(&self, other: &Schedule) -> Option<Schedule> {
+        let mut times: Vec<ast::TimeOfDay> = self.times()?.to_vec();
+        times.extend_from_slice(other.times()?);
+        times.sort();
+        times.dedup();
+
+        let mut merged = self.clone();
+        *merged.expr.times_mut()? = times.clone();
+        let mut rhs = other.clone();
+        *rhs.expr.times_mut()? = times;
+        (merged == rhs).then_some(merged)
+    }
+
+    /// Clone this schedule with its `at` times replaced, sorted and
+    /// de-duplicated as parsing would.
+    ///
+    /// Interval windows have no time list, so they (and an empty `times`)
+    /// come back unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::{ast::TimeOfDay, Schedule};
+    ///
+    /// let base = Schedule::parse("every weekday at 09:00 except dec 25 in UTC").unwrap();
+    /// let times = vec![TimeOfDay { hour: 17, minute: 0 }, TimeOfDay { hour: 8, minute: 30 }];
+    /// let shifted = base.clone_with_times(times);
+    /// assert_eq!(shifted.to_string(), "every weekday at 08:30, 17:00 except dec 25 in UTC");
+    ///
+    /// let interval = Schedule::parse("every 30 min from 09:00 to 17:00").unwrap();
+    /// assert_eq!(interval.clone_with_times(vec![TimeOfDay::MORNING]), interval);
+    /// ```
+    pub fn clone_with_times(&self, mut times: Vec<ast::TimeOfDay>) -> Schedule {
+        let mut schedule = self.clone();
+        if times.is_empty() {
+            return schedule;
+        }
+        times.sort();
+        times.dedup();
+        if let Some(slot) = schedule.expr.times_mut() {
+            *slot = times;
+        }
+        schedule
+    }
+
+    /// Check if an input string is a valid hron expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// assert!(Schedule::validate("every day at 09:00"));
+    /// assert!(!Schedule::validate("not a valid expression"));
+    /// ```
+    pub fn validate(input: &str) -> bool {
+        Self::parse(input).is_ok()
+    }
+
+    /// Check if an input string is a valid hron expression, returning the
+    /// parse error if it is not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// assert!(Schedule::validate_detailed("every day at 09:00").is_ok());
+    /// let err = Schedule::validate_detailed("every day at 25:00").unwrap_err();
+    /// assert_eq!(err.to_string(), "invalid time");
+    /// assert_eq!(err.span().map(|s| (s.start, s.end)), Some((13, 18)));
+    /// ```
+    pub fn validate_detailed(input: &str) -> Result<(), ScheduleError> {
+        Self::parse(input).map(|_| ())
+    }
+
+    /// Convert a 5-field cron expression to a Schedule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::from_cron("0 9 * * 1-5").unwrap();
+    /// assert_eq!(schedule.to_string(), "every weekday at 09:00");
+    /// ```
+    pub fn from_cron(cron_expr: &str) -> Result<Self, ScheduleError> {
+        cron::from_cron(cron_expr)
+    }
+
+    /// Explain a cron expression in human-readable form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let explanation = Schedule::explain_cron("0 9 * * 1-5").unwrap();
+    /// assert_eq!(explanation, "every weekday at 09:00");
+    /// ```
+    pub fn explain_cron(cron_expr: &str) -> Result<String, ScheduleError> {
+        cron::explain_cron(cron_expr)
+    }
+
+    /// Convert this schedule to a 5-field cron expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every day at 09:00").unwrap();
+    /// assert_eq!(schedule.to_cron().unwrap(), "0 9 * * *");
+    ///
+    /// // Schedules that exceed cron's capabilities return an error
+    /// let schedule = Schedule::parse("every 2 weeks on monday at 09:00").unwrap();
+    /// assert!(schedule.to_cron().is_err());
+    /// ```
+    pub fn to_cron(&self) -> Result<String, ScheduleError> {
+        cron::to_cron(self)
+    }
+
+    /// Convert this schedule to a 5-field cron expression using symbolic
+    /// month and day-of-week names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every weekday at 09:00 during jan, jul").unwrap();
+    /// assert_eq!(schedule.to_cron().unwrap(), "0 9 * 1,7 1-5");
+    /// assert_eq!(schedule.to_cron_named().unwrap(), "0 9 * JAN,JUL MON-FRI");
+    /// ```
+    pub fn to_cron_named(&self) -> Result<String, ScheduleError> {
+        cron::to_cron_named(self)
+    }
+
+    /// Convert this schedule to a 5-field cron expression, or `None` when
+    /// [`to_cron`](Self::to_cron) would fail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every day at 09:00").unwrap();
+    /// assert_eq!(schedule.as_cron_or_none().as_deref(), Some("0 9 * * *"));
+    ///
+    /// let schedule = Schedule::parse("every 2 weeks on monday at 09:00").unwrap();
+    /// assert_eq!(schedule.as_cron_or_none(), None);
+    /// ```
+    pub fn as_cron_or_none(&self) -> Option<String> {
+        if !cron::cron_blockers(self).is_empty() {
+            return None;
+        }
+        cron::to_cron(self).ok()
+    }
+
+    /// The cron fields of this schedule as bit sets, for matching in a
+    /// tight loop outside hron (e.g. across an FFI boundary).
+    ///
+    /// `None` for schedules [`to_cron`](Self::to_cron) rejects, and for
+    /// nearest-weekday targets, whose day of month varies with the calendar.
+    /// The masks describe wall-clock times in the schedule's timezone and
+    /// know nothing of DST gaps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every weekday at 9:00, 17:00").unwrap();
+    /// let masks = schedule.to_bitmasks().unwrap();
+    /// assert_eq!(masks.minutes, 1);
+    /// assert_eq!(masks.hours, 1 << 9 | 1 << 17);
+    /// assert_eq!(masks.dow, 0b0111110);
+    ///
+    /// let schedule = Schedule::parse("every 2 weeks on monday at 9:00").unwrap();
+    /// assert!(schedule.to_bitmasks().is_none());
+    /// ```
+    pub fn to_bitmasks(&self) -> Option<CronBitmasks> {
+        cron::to_bitmasks(self)
+    }
+
+    /// Every reason this schedule can't convert to cron, in the words of
+    /// [`to_cron`](Self::to_cron)'s errors. Empty when it converts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every 2 days at 09:00 until 2026-12-31").unwrap();
+    /// assert_eq!(
+    ///     schedule.cron_blockers(),
+    ///     ["until clauses not supported", "multi-day intervals not supported"]
+    /// );
+    /// ```
+    pub fn cron_blockers(&self) -> Vec<&'static str> {
+        cron::cron_blockers(self)
+    }
+
+    /// Convert this schedule to the closest 5-field cron expression, with a
+    /// warning for each way the result diverges from the schedule.
+    ///
+    /// Unlike [`to_cron`](Self::to_cron) this never fails. Schedules that
+    /// `to_cron` accepts convert exactly with no warnings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every 45 min from 09:00 to 17:00").unwrap();
+    /// let (cron, warnings) = schedule.to_cron_lossy();
+    /// assert_eq!(cron, "*/45 9-17 * * *");
+    /// assert!(!warnings.is_empty());
+    /// ```
+    pub fn to_cron_lossy(&self) -> (String, Vec<String>) {
+        cron::to_cron_lossy(self)
+    }
+
+    /// Get the timezone for this schedule, if specified.
+    ///
+    /// `None` means the schedule is evaluated in UTC (or in the caller's zone
+    /// with [`with_floating_time`](Self::with_floating_time)); that fallback is
+    /// not reflected here. See [`timezone_or`](Self::timezone_or).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every day at 09:00 in America/New_York").unwrap();
+    /// assert_eq!(schedule.timezone(), Some("America/New_York"));
+    ///
+    /// let schedule = Schedule::parse("every day at 09:00").unwrap();
+    /// assert_eq!(schedule.timezone(), None);
+    /// ```
+    pub fn timezone(&self) -> Option<&str> {
+        self.timezone.as_deref()
+    }
+
+    /// Get the timezone for this schedule, or `default` when it has no `in`
+    /// clause.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every day at 09:00").unwrap();
+    /// assert_eq!(schedule.timezone_or("Europe/Berlin"), "Europe/Berlin");
+    ///
+    /// let schedule = Schedule::parse("every day at 09:00 in Asia/Tokyo").unwrap();
+    /// assert_eq!(schedule.timezone_or("Europe/Berlin"), "Asia/Tokyo");
+    /// ```
+    pub fn timezone_or<'a>(&'a self, default: &'a str) -> &'a str {
+        self.timezone.as_deref().unwrap_or(default)
+    }
+
+    /// Replace the timezone in place; `None` removes the `in` clause so the
+    /// schedule falls back to UTC.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// // Apply a tenant's default zone to schedules that omitted one
+    /// let mut schedule = Schedule::parse("every day at 09:00").unwrap();
+    /// if schedule.timezone().is_none() {
+    ///     schedule.set_timezone(Some("Europe/Berlin".into()));
+    /// }
+    /// assert_eq!(schedule.to_string(), "every day at 09:00 in Europe/Berlin");
+    /// ```
+    pub fn set_timezone(&mut self, tz: Option<String>) {
+        self.timezone = tz;
+    }
+
+    /// Get the schedule expression.
+    pub fn expr(&self) -> &ScheduleExpr {
+        &self.expr
+    }
+
+    /// Get the expression kind as a snake_case name (`"day_repeat"`, ...).
+    /// See [`ScheduleExpr::kind`] for the typed form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every 2 weeks on monday at 09:00").unwrap();
+    /// assert_eq!(schedule.expr_kind(), "week_repeat");
+    /// assert_eq!(schedule.interval(), Some(2));
+    /// assert_eq!(schedule.times().map(|t| t.len()), Some(1));
+    /// assert_eq!(schedule.day_filter(), None);
+    /// ```
+    pub fn expr_kind(&self) -> &'static str {
+        self.expr.kind().as_str()
+    }
+
+    /// Get the repeat interval. See [`ScheduleExpr::interval`].
+    pub fn interval(&self) -> Option<u32> {
+        self.expr.interval()
+    }
+
+    /// Get the times of day. See [`ScheduleExpr::times`].
+    pub fn times(&self) -> Option<&[ast::TimeOfDay]> {
+        self.expr.times()
+    }
+
+    /// Get the day filter. See [`ScheduleExpr::day_filter`].
+    pub fn day_filter(&self) -> Option<&ast::DayFilter> {
+        self.expr.day_filter()
+    }
+
+    /// Get the exception dates.
+    pub fn except(&self) -> &[ast::Exception] {
+        &self.except
+    }
+
+    /// Get the until spec, if specified.
+    pub fn until(&self) -> Option<&ast::UntilSpec> {
+        self.until.as_ref()
+    }
+
+    /// Get the anchor date, if specified.
+    #[cfg(feature = "eval")]
+    pub fn anchor(&self) -> Option<jiff::civil::Date> {
+        self.anchor.map(ast::CivilDate::to_jiff)
+    }
+
+    /// Get the anchor date as an [`ast::CivilDate`], if specified.
+    ///
+    /// Same as [`Schedule::anchor`] but available without the `eval` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::{ast::CivilDate, Schedule};
+    ///
+    /// let schedule = Schedule::parse("every 2 weeks on monday at 9:00 starting 2026-01-05").unwrap();
+    /// assert_eq!(schedule.anchor_date(), CivilDate::new(2026, 1, 5));
+    /// ```
+    pub fn anchor_date(&self) -> Option<ast::CivilDate> {
+        self.anchor
+    }
+
+    /// Resolve the `until` clause to the concrete date the schedule stops on.
+    ///
+    /// A named date (`until dec 31`) resolves to its next occurrence on or
+    /// after `now`, the same way evaluation does. `until N times` resolves to
+    /// the date of the Nth occurrence from the `starting` date; once the count
+    /// is used up there is no stop date left and this returns `None`, as it
+    /// does when there is no `until` clause.
     ///
     /// # Examples
     ///
     /// ```
     /// use hron::Schedule;
     ///
-    /// // Every 2 weeks on Monday, anchored to a specific start date
-    /// let schedule = Schedule::parse("every 2 weeks on monday at 09:00 in UTC").unwrap()
-    ///     .with_anchor(jiff::civil::date(2025, 1, 6));
-    /// let now: jiff::Zoned = "2025-01-19T10:00:00+00:00[UTC]".parse().unwrap();
-    /// let next = schedule.next_from(&now).unwrap().unwrap();
-    /// assert_eq!(next.to_string(), "2025-01-20T09:00:00+00:00[UTC]");
+    /// let now: jiff::Zoned = "2026-02-06T12:00:00+00:00[UTC]".parse().unwrap();
+    /// let schedule = Schedule::parse("every day at 09:00 until dec 31 in UTC").unwrap();
+    /// assert_eq!(schedule.effective_until(&now).unwrap(), Some(jiff::civil::date(2026, 12, 31)));
+    ///
+    /// let schedule =
+    ///     Schedule::parse("every monday at 09:00 until 3 times starting 2026-02-09 in UTC").unwrap();
+    /// assert_eq!(schedule.effective_until(&now).unwrap(), Some(jiff::civil::date(2026, 2, 23)));
     /// ```
-    pub fn with_anchor(mut self, date: jiff::civil::Date) -> Self {
-        self.anchor = Some(date);
-        self
+    #[cfg(feature = "eval")]
+    pub fn effective_until(&self, now: &Zoned) -> Result<Option<jiff::civil::Date>, ScheduleError> {
+        eval::effective_until(self, now)
     }
 
-    /// Check if an input string is a valid hron expression.
+    /// Get the relative anchor (`starting today` / `starting now` /
+    /// `starting jan 6`), if specified.
+    ///
+    /// A relative anchor resolves against the `now` of each evaluation, so the
+    /// same schedule can give different results depending on when it is
+    /// evaluated.
+    pub fn relative_anchor(&self) -> Option<ast::RelativeAnchor> {
+        self.anchor_relative
+    }
+
+    /// Get the time on the starting date before which nothing occurs
+    /// (`starting 2026-01-06 at 09:00`), if specified.
+    pub fn anchor_time(&self) -> Option<ast::TimeOfDay> {
+        self.anchor_time
+    }
+
+    /// Get the during months filter.
+    ///
+    /// Ranges such as `during nov to feb` are expanded into individual months,
+    /// and date ranges such as `during jun 15 to aug 31` into every month
+    /// they touch.
+    pub fn during(&self) -> Vec<ast::MonthName> {
+        self.during_months()
+    }
+
+    /// Get the `during` clause as written: months, month ranges, and date
+    /// ranges.
+    pub fn during_specs(&self) -> &[ast::DuringSpec] {
+        &self.during
+    }
+
+    /// Evaluate times in the timezone of the `Zoned` passed to `next_from`,
+    /// `matches`, etc., rather than UTC, when the schedule has no `in` clause.
+    ///
+    /// An explicit `in <tz>` always takes precedence. Floating mode is an
+    /// evaluation setting and is not part of the expression's string form.
     ///
     /// # Examples
     ///
     /// ```
     /// use hron::Schedule;
     ///
-    /// assert!(Schedule::validate("every day at 09:00"));
-    /// assert!(!Schedule::validate("not a valid expression"));
+    /// # #[cfg(feature = "eval")]
+    /// # {
+    /// let now: jiff::Zoned = "2025-06-15T08:00:00-04:00[America/New_York]".parse().unwrap();
+    ///
+    /// // Without `in`, times default to UTC (08:00 in New York is already 12:00 UTC)
+    /// let schedule = Schedule::parse("every day at 09:00").unwrap();
+    /// let next = schedule.next_from(&now).unwrap().unwrap();
+    /// assert_eq!(next.to_string(), "2025-06-16T09:00:00+00:00[UTC]");
+    ///
+    /// let next = schedule.with_floating_time().next_from(&now).unwrap().unwrap();
+    /// assert_eq!(next.to_string(), "2025-06-15T09:00:00-04:00[America/New_York]");
+    /// # }
     /// ```
-    pub fn validate(input: &str) -> bool {
-        Self::parse(input).is_ok()
+    pub fn with_floating_time(mut self) -> Self {
+        self.floating = true;
+        self
     }
 
-    /// Convert a 5-field cron expression to a Schedule.
+    /// Check if this schedule uses floating (caller-local) time.
+    pub fn is_floating(&self) -> bool {
+        self.floating
+    }
+
+    /// Start weeks on `week_start` when aligning `every N weeks`. Defaults to
+    /// [`WeekStart::Monday`](ast::WeekStart::Monday).
+    ///
+    /// This only moves the week boundary used to count N-week steps; it never
+    /// changes which weekdays fire. It affects [`WeekRepeat`](ScheduleExpr::WeekRepeat)
+    /// schedules with an interval above 1 and nothing else. Like floating
+    /// time, it is an evaluation setting outside the string form.
     ///
     /// # Examples
     ///
     /// ```
+    /// use hron::ast::WeekStart;
     /// use hron::Schedule;
     ///
-    /// let schedule = Schedule::from_cron("0 9 * * 1-5").unwrap();
-    /// assert_eq!(schedule.to_string(), "every weekday at 09:00");
+    /// # #[cfg(feature = "eval")]
+    /// # {
+    /// let now: jiff::Zoned = "2026-01-12T00:00:00+00:00[UTC]".parse().unwrap();
+    /// let schedule = Schedule::parse("every 2 weeks on sunday at 09:00 starting 2026-01-05").unwrap();
+    ///
+    /// // Monday weeks: the anchor week ends on sunday jan 11
+    /// let next = schedule.next_from(&now).unwrap().unwrap();
+    /// assert_eq!(next.date().to_string(), "2026-01-25");
+    ///
+    /// // Sunday weeks: sunday jan 18 opens the next aligned week
+    /// let sunday = schedule.with_week_start(WeekStart::Sunday);
+    /// let next = sunday.next_from(&now).unwrap().unwrap();
+    /// assert_eq!(next.date().to_string(), "2026-01-18");
+    /// # }
     /// ```
-    pub fn from_cron(cron_expr: &str) -> Result<Self, ScheduleError> {
-        cron::from_cron(cron_expr)
+    pub fn with_week_start(mut self, week_start: ast::WeekStart) -> Self {
+        self.week_start = week_start;
+        self
     }
 
-    /// Explain a cron expression in human-readable form.
+    /// The first day of the week used to align `every N weeks`.
+    pub fn week_start(&self) -> ast::WeekStart {
+        self.week_start
+    }
+
+    /// Redefine the days `weekend` covers. Defaults to
+    /// [`WeekendDays::SATURDAY_SUNDAY`](ast::WeekendDays::SATURDAY_SUNDAY).
+    ///
+    /// Evaluation and cron conversion both follow the setting; the string
+    /// form still reads `weekend`. Day lists such as `saturday, sunday` keep
+    /// their literal days. Like [`with_week_start`](Self::with_week_start),
+    /// it is an evaluation setting outside the string form.
     ///
     /// # Examples
     ///
     /// ```
+    /// use hron::ast::WeekendDays;
     /// use hron::Schedule;
     ///
-    /// let explanation = Schedule::explain_cron("0 9 * * 1-5").unwrap();
-    /// assert_eq!(explanation, "every weekday at 09:00");
+    /// # #[cfg(feature = "eval")]
+    /// # {
+    /// let now: jiff::Zoned = "2026-02-02T00:00:00+00:00[UTC]".parse().unwrap();
+    /// let schedule = Schedule::parse("every weekend at 10:00 in UTC")
+    ///     .unwrap()
+    ///     .with_weekend(WeekendDays::FRIDAY_SATURDAY);
+    ///
+    /// let next = schedule.next_from(&now).unwrap().unwrap();
+    /// assert_eq!(next.date().to_string(), "2026-02-06"); // friday
+    /// assert_eq!(schedule.to_cron().unwrap(), "0 10 * * 5,6");
+    /// # }
     /// ```
-    pub fn explain_cron(cron_expr: &str) -> Result<String, ScheduleError> {
-        cron::explain_cron(cron_expr)
+    pub fn with_weekend(mut self, weekend: ast::WeekendDays) -> Self {
+        self.weekend = weekend;
+        self
     }
 
-    /// Convert this schedule to a 5-field cron expression.
+    /// The days `weekend` covers.
+    pub fn weekend(&self) -> ast::WeekendDays {
+        self.weekend
+    }
+
+    /// Check whether this schedule produces a finite set of occurrences.
+    ///
+    /// True for ISO dates and upcoming weekdays only (`on 2026-03-15 at
+    /// 09:00`, `on friday at 17:00`) and for any schedule bounded by
+    /// `until <iso-date>` or `until N times`. A named single date
+    /// (`on feb 14`) recurs every year, and a named `until` is re-resolved
+    /// against each evaluation time, so neither makes a schedule finite.
     ///
     /// # Examples
     ///
     /// ```
     /// use hron::Schedule;
     ///
-    /// let schedule = Schedule::parse("every day at 09:00").unwrap();
-    /// assert_eq!(schedule.to_cron().unwrap(), "0 9 * * *");
-    ///
-    /// // Schedules that exceed cron's capabilities return an error
-    /// let schedule = Schedule::parse("every 2 weeks on monday at 09:00").unwrap();
-    /// assert!(schedule.to_cron().is_err());
+    /// assert!(Schedule::parse("on 2026-03-15 at 09:00").unwrap().is_one_shot());
+    /// assert!(Schedule::parse("every day at 09:00 until 5 times").unwrap().is_one_shot());
+    /// assert!(Schedule::parse("on feb 14 at 09:00").unwrap().is_recurring());
     /// ```
-    pub fn to_cron(&self) -> Result<String, ScheduleError> {
-        cron::to_cron(self)
+    pub fn is_one_shot(&self) -> bool {
+        let single_iso = match &self.expr {
+            ScheduleExpr::SingleDate { date, .. } => {
+                matches!(date, ast::DateSpec::Iso(_) | ast::DateSpec::NextWeekday(_))
+            }
+            ScheduleExpr::MultiDate { dates, .. } => dates
+                .iter()
+                .all(|d| matches!(d, ast::DateSpec::Iso(_) | ast::DateSpec::NextWeekday(_))),
+            _ => false,
+        };
+        let bounded = matches!(
+            self.until,
+            Some(ast::UntilSpec::Iso(_) | ast::UntilSpec::Count(_))
+        );
+        single_iso || bounded
     }
 
-    /// Get the timezone for this schedule, if specified.
+    /// Check whether this schedule repeats indefinitely. The inverse of
+    /// [`is_one_shot`](Self::is_one_shot).
+    pub fn is_recurring(&self) -> bool {
+        !self.is_one_shot()
+    }
+
+    /// Estimate how many times a year this schedule fires, computed from the
+    /// expression rather than by iterating occurrences.
+    ///
+    /// This is an estimate: `except` and `until` are ignored, and calendar
+    /// effects are averaged (52 weeks, 260 weekdays, the 31st in 7 months).
+    /// `during` scales the rate by the share of months kept. Returns `None`
+    /// for a single ISO date.
     ///
     /// # Examples
     ///
     /// ```
     /// use hron::Schedule;
     ///
-    /// let schedule = Schedule::parse("every day at 09:00 in America/New_York").unwrap();
-    /// assert_eq!(schedule.timezone(), Some("America/New_York"));
+    /// let s = Schedule::parse("every weekday at 09:00").unwrap();
+    /// assert_eq!(s.approx_per_year(), Some(260.0));
     ///
-    /// let schedule = Schedule::parse("every day at 09:00").unwrap();
-    /// assert_eq!(schedule.timezone(), None);
+    /// let s = Schedule::parse("every 30 min from 09:00 to 17:00").unwrap();
+    /// assert_eq!(s.approx_per_year(), Some(17.0 * 365.0));
+    ///
+    /// assert_eq!(Schedule::parse("on 2026-03-15 at 09:00").unwrap().approx_per_year(), None);
     /// ```
-    pub fn timezone(&self) -> Option<&str> {
-        self.timezone.as_deref()
-    }
-
-    /// Get the schedule expression.
-    pub fn expr(&self) -> &ScheduleExpr {
-        &self.expr
-    }
-
-    /// Get the exception dates.
-    pub fn except(&self) -> &[ast::Exception] {
-        &self.except
-    }
-
-    /// Get the until spec, if specified.
-    pub fn until(&self) -> Option<&ast::UntilSpec> {
-        self.until.as_ref()
-    }
-
-    /// Get the anchor date, if specified.
-    pub fn anchor(&self) -> Option<jiff::civil::Date> {
-        self.anchor
-    }
-
-    /// Get the during months filter.
-    pub fn during(&self) -> &[ast::MonthName] {
-        &self.during
+    #[cfg(feature = "eval")]
+    pub fn approx_per_year(&self) -> Option<f64> {
+        eval::approx_per_year(self)
     }
 
     /// Set the timezone.
@@ -270,9 +1378,45 @@ impl Schedule {
         self
     }
 
+    /// Stop after `n` occurrences, as `until N times` does.
+    ///
+    /// The count runs from the `starting` date, which must be fixed: evaluating
+    /// without one, or with `starting today` / `starting now`, is an error.
+    /// Every evaluator enforces it, so the iterators end by themselves and
+    /// `next_from` returns `None` once the `n`th occurrence has passed. A
+    /// schedule holds one `until`, so this replaces an `until` date rather
+    /// than combining with it. A count of 0 yields nothing, but like any count
+    /// below 1 it has no string form that parses back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// # #[cfg(feature = "eval")]
+    /// # {
+    /// let schedule = Schedule::parse("every day at 09:00 in UTC")
+    ///     .unwrap()
+    ///     .with_anchor(jiff::civil::date(2026, 2, 7))
+    ///     .with_until_count(3);
+    /// let from: jiff::Zoned = "2026-02-06T12:00:00+00:00[UTC]".parse().unwrap();
+    ///
+    /// let all: Vec<_> = schedule.occurrences(&from).collect::<Result<_, _>>().unwrap();
+    /// assert_eq!(all.len(), 3);
+    /// assert_eq!(schedule.next_from(&all[2]).unwrap(), None);
+    /// assert_eq!(
+    ///     schedule.to_string(),
+    ///     "every day at 09:00 until 3 times starting 2026-02-07 in UTC"
+    /// );
+    /// # }
+    /// ```
+    pub fn with_until_count(self, n: u32) -> Self {
+        self.with_until(ast::UntilSpec::Count(n))
+    }
+
     /// Set the during months filter.
     pub fn with_during(mut self, months: Vec<ast::MonthName>) -> Self {
-        self.during = months;
+        self.during = months.into_iter().map(ast::DuringSpec::Single).collect();
         self
     }
 
@@ -280,7 +1424,7 @@ impl Schedule {
     ///
     /// The iterator yields `Result<Zoned, ScheduleError>` values. It is unbounded
     /// for repeating schedules (will iterate forever unless limited), but respects
-    /// the `until` clause if specified in the schedule.
+    /// the `until` clause if specified in the schedule, including `until N times`.
     ///
     /// # Examples
     ///
@@ -295,14 +1439,116 @@ impl Schedule {
     /// assert_eq!(first_5.len(), 5);
     /// assert_eq!(first_5[0].to_string(), "2025-06-15T09:00:00+00:00[UTC]");
     /// ```
+    #[cfg(feature = "eval")]
     pub fn occurrences(&self, from: &Zoned) -> eval::Occurrences<'_> {
         eval::Occurrences::new(self, from.clone())
     }
 
+    /// Returns a lazy iterator over the dates the schedule fires on, starting
+    /// at `from`.
+    ///
+    /// Each date is yielded once, however many `at` times fall on it, which is
+    /// what a calendar grid needs. Dates are civil dates in the schedule's
+    /// timezone; floating schedules are evaluated in UTC, as in
+    /// [`dates_in_month`](Self::dates_in_month).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    /// use jiff::civil::date;
+    ///
+    /// let schedule = Schedule::parse("every day at 09:00, 17:00 in UTC").unwrap();
+    /// let dates: Vec<_> = schedule
+    ///     .occurrence_dates(date(2026, 2, 6))
+    ///     .take(3)
+    ///     .collect::<Result<_, _>>()
+    ///     .unwrap();
+    /// assert_eq!(dates, vec![date(2026, 2, 6), date(2026, 2, 7), date(2026, 2, 8)]);
+    /// ```
+    #[cfg(feature = "eval")]
+    pub fn occurrence_dates(&self, from: jiff::civil::Date) -> eval::OccurrenceDates<'_> {
+        eval::OccurrenceDates::new(self, from)
+    }
+
+    /// Returns an async stream of occurrences after `from`, each yielded once
+    /// its time arrives. Requires the `tokio` feature and a tokio runtime with
+    /// the timer enabled.
+    ///
+    /// Occurrences already in the past are yielded immediately, so a `from`
+    /// in the past replays the backlog before waiting on the next one. The
+    /// stream ends when the schedule does (`until`, one-shot dates).
+    ///
+    /// Each wait is computed from the wall clock when the occurrence is
+    /// produced, but tokio sleeps on the monotonic clock. If the system clock
+    /// jumps (NTP correction, suspend, manual change) during a long wait, the
+    /// item can arrive early or late by the size of the jump. Consumers that
+    /// need exact wall-clock alignment should compare the yielded time with
+    /// `Zoned::now()` themselves.
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use futures_util::StreamExt;
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every day at 09:00 in UTC").unwrap();
+    /// let from: jiff::Zoned = "2025-06-15T08:00:00+00:00[UTC]".parse().unwrap();
+    ///
+    /// // Past occurrences arrive without waiting
+    /// let first: Vec<_> = schedule.stream(&from).take(2).collect().await;
+    /// assert_eq!(first[0].as_ref().unwrap().to_string(), "2025-06-15T09:00:00+00:00[UTC]");
+    /// # }
+    /// ```
+    #[cfg(feature = "tokio")]
+    pub fn stream(
+        &self,
+        from: &Zoned,
+    ) -> impl futures_core::Stream<Item = Result<Zoned, ScheduleError>> + '_ {
+        stream::occurrence_stream(self, from.clone())
+    }
+
+    /// Returns a lazy iterator of every `every_nth` occurrence after `from`,
+    /// starting with the first.
+    ///
+    /// Yields the same values as `occurrences(from).step_by(every_nth)`, but
+    /// minute/hour interval schedules jump over skipped slots arithmetically
+    /// instead of computing each one. Useful for down-sampling dense schedules.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `every_nth` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every 1 min from 09:00 to 17:00 in UTC").unwrap();
+    /// let from: jiff::Zoned = "2025-06-15T08:00:00+00:00[UTC]".parse().unwrap();
+    ///
+    /// let hourly: Vec<_> = schedule
+    ///     .occurrences_stepped(&from, 60)
+    ///     .take(3)
+    ///     .collect::<Result<_, _>>()
+    ///     .unwrap();
+    /// assert_eq!(hourly[2].to_string(), "2025-06-15T11:00:00+00:00[UTC]");
+    /// ```
+    #[cfg(feature = "eval")]
+    pub fn occurrences_stepped(
+        &self,
+        from: &Zoned,
+        every_nth: usize,
+    ) -> eval::SteppedOccurrences<'_> {
+        eval::SteppedOccurrences::new(self, from.clone(), every_nth)
+    }
+
     /// Returns a bounded iterator of occurrences in the range `(from, to]`.
     ///
     /// The iterator yields occurrences strictly after `from` and up to and including `to`.
     /// This is useful for querying all occurrences within a specific date range.
+    /// If `from` is after `to` the iterator is empty; use
+    /// [`try_between`](Self::try_between) to treat that as an error.
     ///
     /// # Examples
     ///
@@ -316,9 +1562,127 @@ impl Schedule {
     /// let occurrences: Vec<_> = schedule.between(&from, &to).collect::<Result<_, _>>().unwrap();
     /// assert_eq!(occurrences.len(), 4); // June 15, 16, 17, 18 at 09:00
     /// ```
+    #[cfg(feature = "eval")]
     pub fn between(&self, from: &Zoned, to: &Zoned) -> eval::BoundedOccurrences<'_> {
         eval::between(self, from, to)
     }
+
+    /// Like [`between`](Self::between), but rejects an inverted range
+    /// (`from` after `to`) instead of yielding nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every day at 09:00 in UTC").unwrap();
+    /// let from: jiff::Zoned = "2025-06-15T08:00:00+00:00[UTC]".parse().unwrap();
+    /// let to: jiff::Zoned = "2025-06-18T10:00:00+00:00[UTC]".parse().unwrap();
+    ///
+    /// assert_eq!(schedule.try_between(&from, &to).unwrap().count(), 4);
+    /// assert!(schedule.try_between(&to, &from).is_err());
+    /// ```
+    #[cfg(feature = "eval")]
+    pub fn try_between(
+        &self,
+        from: &Zoned,
+        to: &Zoned,
+    ) -> Result<eval::BoundedOccurrences<'_>, ScheduleError> {
+        eval::try_between(self, from, to)
+    }
+
+    /// Occurrences in `(from, to]` that a DST transition moved off their
+    /// written wall-clock time, for auditing a schedule before the clocks
+    /// change.
+    ///
+    /// A time in a spring-forward gap fires later by the length of the gap
+    /// ([`DstAnomaly::Gap`]); a time repeated by a fall-back fold fires once,
+    /// at its first instance ([`DstAnomaly::Fold`]). Minute and hour
+    /// intervals step by elapsed time and never report anomalies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::{DstAnomaly, Schedule};
+    ///
+    /// let schedule = Schedule::parse("every day at 02:30 in America/New_York").unwrap();
+    /// let from: jiff::Zoned = "2026-01-01T00:00:00-05:00[America/New_York]".parse().unwrap();
+    /// let to: jiff::Zoned = "2026-12-31T00:00:00-05:00[America/New_York]".parse().unwrap();
+    ///
+    /// let anomalies = schedule.dst_anomalies_between(&from, &to).unwrap();
+    /// assert_eq!(anomalies.len(), 1);
+    /// let (shifted, anomaly) = &anomalies[0];
+    /// assert_eq!(shifted.to_string(), "2026-03-08T03:30:00-04:00[America/New_York]");
+    /// assert!(matches!(anomaly, DstAnomaly::Gap { .. }));
+    /// ```
+    #[cfg(feature = "eval")]
+    pub fn dst_anomalies_between(
+        &self,
+        from: &Zoned,
+        to: &Zoned,
+    ) -> Result<Vec<(Zoned, DstAnomaly)>, ScheduleError> {
+        eval::dst_anomalies_between(self, from, to)
+    }
+
+    /// Returns a bounded iterator of occurrences between `from` and `to`, with
+    /// the inclusivity of each end chosen by the caller.
+    ///
+    /// [`between`](Self::between) is `(from, to]`. Pass `true, false` for the
+    /// half-open `[from, to)` ranges common in time-series bucketing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every day at 09:00 in UTC").unwrap();
+    /// let from: jiff::Zoned = "2025-06-15T09:00:00+00:00[UTC]".parse().unwrap();
+    /// let to: jiff::Zoned = "2025-06-18T09:00:00+00:00[UTC]".parse().unwrap();
+    ///
+    /// let bucket: Vec<_> = schedule
+    ///     .between_with_bounds(&from, &to, true, false)
+    ///     .collect::<Result<_, _>>()
+    ///     .unwrap();
+    /// assert_eq!(bucket.len(), 3); // June 15, 16, 17 at 09:00
+    /// ```
+    #[cfg(feature = "eval")]
+    pub fn between_with_bounds(
+        &self,
+        from: &Zoned,
+        to: &Zoned,
+        start_inclusive: bool,
+        end_inclusive: bool,
+    ) -> eval::BoundedOccurrences<'_> {
+        eval::between_with_bounds(self, from, to, start_inclusive, end_inclusive)
+    }
+
+    /// Returns the occurrences due in the half-open window `[window_start, window_end)`.
+    ///
+    /// Intended for tick-based schedulers: pass the previous tick's `window_end`
+    /// as this tick's `window_start` and every occurrence fires exactly once,
+    /// with no gaps or double-firing at tick boundaries. Contrast with
+    /// [`between`](Self::between), which uses `(from, to]` bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hron::Schedule;
+    ///
+    /// let schedule = Schedule::parse("every 15 min from 09:00 to 10:00 in UTC").unwrap();
+    /// let start: jiff::Zoned = "2025-06-15T09:00:00+00:00[UTC]".parse().unwrap();
+    /// let end: jiff::Zoned = "2025-06-15T09:30:00+00:00[UTC]".parse().unwrap();
+    ///
+    /// let due = schedule.due_in_window(&start, &end).unwrap();
+    /// assert_eq!(due.len(), 2); // 09:00 and 09:15; 09:30 belongs to the next tick
+    /// ```
+    #[cfg(feature = "eval")]
+    pub fn due_in_window(
+        &self,
+        window_start: &Zoned,
+        window_end: &Zoned,
+    ) -> Result<Vec<Zoned>, ScheduleError> {
+        eval::due_in_window(self, window_start, window_end)
+    }
 }
 
 impl FromStr for Schedule {
@@ -331,7 +1695,9 @@ impl FromStr for Schedule {
 
 /// Serialization produces a structured JSON object with fields like `kind`,
 /// `interval`, `times`, `except`, `timezone`, etc. — designed for inspection,
-/// logging, and debugging.
+/// logging, and debugging. Every repeating schedule carries `interval`, even
+/// when it is 1. [`json_schema`] describes its shape; keep the two
+/// in sync.
 ///
 /// **Note:** Serialization and deserialization are intentionally asymmetric.
 /// `Serialize` produces a structured JSON object while `Deserialize` expects
@@ -357,18 +1723,19 @@ impl Serialize for Schedule {
                 map.serialize_entry("kind", "every")?;
                 map.serialize_entry(
                     "interval",
-                    &serde_json::json!({
-                        "value": interval,
-                        "unit": match unit {
+                    &IntervalJson {
+                        value: *interval,
+                        unit: match unit {
                             ast::IntervalUnit::Minutes => "minutes",
                             ast::IntervalUnit::Hours => "hours",
-                        }
-                    }),
+                            ast::IntervalUnit::Days => "days",
+                        },
+                    },
                 )?;
                 map.serialize_entry("from", from)?;
                 map.serialize_entry("to", to)?;
                 if let Some(df) = day_filter {
-                    map.serialize_entry("days", &day_filter_to_json(df))?;
+                    map.serialize_entry("days", &DayFilterJson(df, self.weekend))?;
                 }
             }
             ScheduleExpr::DayRepeat {
@@ -377,16 +1744,29 @@ impl Serialize for Schedule {
                 times,
             } => {
                 map.serialize_entry("kind", "every")?;
-                if *interval > 1 {
-                    map.serialize_entry(
-                        "interval",
-                        &serde_json::json!({
-                            "value": interval,
-                            "unit": "days"
-                        }),
-                    )?;
-                }
-                map.serialize_entry("days", &day_filter_to_json(days))?;
+                map.serialize_entry(
+                    "interval",
+                    &IntervalJson {
+                        value: *interval,
+                        unit: "days",
+                    },
+                )?;
+                map.serialize_entry("days", &DayFilterJson(days, self.weekend))?;
+                map.serialize_entry("times", times)?;
+            }
+            ScheduleExpr::WeekdayRepeat { interval, times } => {
+                map.serialize_entry("kind", "every")?;
+                map.serialize_entry(
+                    "interval",
+                    &IntervalJson {
+                        value: *interval,
+                        unit: "weekdays",
+                    },
+                )?;
+                map.serialize_entry(
+                    "days",
+                    &DayFilterJson(&ast::DayFilter::Weekday, self.weekend),
+                )?;
                 map.serialize_entry("times", times)?;
             }
             ScheduleExpr::WeekRepeat {
@@ -397,10 +1777,10 @@ impl Serialize for Schedule {
                 map.serialize_entry("kind", "every")?;
                 map.serialize_entry(
                     "interval",
-                    &serde_json::json!({
-                        "value": interval,
-                        "unit": "weeks"
-                    }),
+                    &IntervalJson {
+                        value: *interval,
+                        unit: "weeks",
+                    },
                 )?;
                 map.serialize_entry("days", days)?;
                 map.serialize_entry("times", times)?;
@@ -409,29 +1789,31 @@ impl Serialize for Schedule {
                 interval,
                 target,
                 times,
+                day_filter,
             } => {
                 map.serialize_entry("kind", "every")?;
                 map.serialize_entry("repeat", "monthly")?;
-                if *interval > 1 {
-                    map.serialize_entry(
-                        "interval",
-                        &serde_json::json!({
-                            "value": interval,
-                            "unit": "months"
-                        }),
-                    )?;
-                }
+                map.serialize_entry(
+                    "interval",
+                    &IntervalJson {
+                        value: *interval,
+                        unit: "months",
+                    },
+                )?;
                 map.serialize_entry("target", target)?;
                 map.serialize_entry("times", times)?;
+                if let Some(df) = day_filter {
+                    map.serialize_entry("days", &DayFilterJson(df, self.weekend))?;
+                }
             }
             ScheduleExpr::SingleDate { date, times } => {
                 map.serialize_entry("kind", "on")?;
-                match date {
-                    ast::DateSpec::Iso(d) => map.serialize_entry("date", d)?,
-                    ast::DateSpec::Named { month, day } => {
-                        map.serialize_entry("date", &format!("{} {}", month.as_str(), day))?;
-                    }
-                }
+                map.serialize_entry("date", &Displayed(date))?;
+                map.serialize_entry("times", times)?;
+            }
+            ScheduleExpr::MultiDate { dates, times } => {
+                map.serialize_entry("kind", "on")?;
+                map.serialize_entry("dates", &DisplayedSeq(dates))?;
                 map.serialize_entry("times", times)?;
             }
             ScheduleExpr::YearRepeat {
@@ -441,49 +1823,109 @@ impl Serialize for Schedule {
             } => {
                 map.serialize_entry("kind", "every")?;
                 map.serialize_entry("repeat", "yearly")?;
-                if *interval > 1 {
-                    map.serialize_entry(
-                        "interval",
-                        &serde_json::json!({
-                            "value": interval,
-                            "unit": "years"
-                        }),
-                    )?;
-                }
+                map.serialize_entry(
+                    "interval",
+                    &IntervalJson {
+                        value: *interval,
+                        unit: "years",
+                    },
+                )?;
                 map.serialize_entry("target", target)?;
                 map.serialize_entry("times", times)?;
             }
+            ScheduleExpr::IsoWeekRepeat { weeks, days, times } => {
+                map.serialize_entry("kind", "every")?;
+                map.serialize_entry("repeat", "yearly")?;
+                map.serialize_entry("iso_weeks", weeks)?;
+                map.serialize_entry("days", days)?;
+                map.serialize_entry("times", times)?;
+            }
         }
 
         // Shared modifiers — always present for a consistent JSON shape
         map.serialize_entry("except", &self.except)?;
         map.serialize_entry("until", &self.until)?;
-        map.serialize_entry("starting", &self.anchor.as_ref().map(|a| a.to_string()))?;
-        map.serialize_entry("during", &self.during)?;
+        match (&self.anchor, self.anchor_relative) {
+            (Some(anchor), _) => map.serialize_entry("starting", &Displayed(anchor))?,
+            (None, Some(relative)) => map.serialize_entry("starting", &Displayed(relative))?,
+            (None, None) => map.serialize_entry("starting", &None::<&str>)?,
+        }
+        map.serialize_entry("starting_time", &self.anchor_time.as_ref().map(Displayed))?;
+        map.serialize_entry("during", &self.during_months())?;
         map.serialize_entry("timezone", &self.timezone)?;
 
         map.end()
     }
 }
 
+/// Sort key for `except` entries: weekdays, then named dates, then ISO dates.
+fn exception_key(exception: &ast::Exception) -> (u8, u8, u8, &str) {
+    match exception {
+        ast::Exception::Weekday(day) => (0, day.number(), 0, ""),
+        ast::Exception::Named { month, day } => (1, month.number(), *day, ""),
+        ast::Exception::NamedRange {
+            start_month,
+            start_day,
+            ..
+        } => (1, start_month.number(), *start_day, ""),
+        ast::Exception::Iso(date) | ast::Exception::IsoRange(date, _) => (2, 0, 0, date),
+    }
+}
+
+/// The `interval` object of serialized repeating schedules.
+#[cfg(feature = "serde")]
+#[derive(Serialize)]
+struct IntervalJson {
+    value: u32,
+    unit: &'static str,
+}
+
+/// Serializes a value as the string its `Display` impl writes, without an
+/// intermediate `String`.
+#[cfg(feature = "serde")]
+struct Displayed<T>(T);
+
+#[cfg(feature = "serde")]
+impl<T: fmt::Display> Serialize for Displayed<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.0)
+    }
+}
+
+/// Serializes a slice as an array of [`Displayed`] strings.
+#[cfg(feature = "serde")]
+struct DisplayedSeq<'a, T>(&'a [T]);
+
+#[cfg(feature = "serde")]
+impl<T: fmt::Display> Serialize for DisplayedSeq<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().map(Displayed))
+    }
+}
+
+/// Serializes a day filter as the full names of the days it covers.
+#[cfg(feature = "serde")]
+struct DayFilterJson<'a>(&'a ast::DayFilter, ast::WeekendDays);
+
 #[cfg(feature = "serde")]
-fn day_filter_to_json(filter: &ast::DayFilter) -> serde_json::Value {
-    match filter {
-        ast::DayFilter::Every => serde_json::json!([
+impl Serialize for DayFilterJson<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        const EVERY: [&str; 7] = [
             "monday",
             "tuesday",
             "wednesday",
             "thursday",
             "friday",
             "saturday",
-            "sunday"
-        ]),
-        ast::DayFilter::Weekday => {
-            serde_json::json!(["monday", "tuesday", "wednesday", "thursday", "friday"])
-        }
-        ast::DayFilter::Weekend => serde_json::json!(["saturday", "sunday"]),
-        ast::DayFilter::Days(days) => {
-            serde_json::json!(days.iter().map(|d| d.as_str()).collect::<Vec<_>>())
+            "sunday",
+        ];
+        match self.0 {
+            ast::DayFilter::Every => EVERY.serialize(serializer),
+            ast::DayFilter::Weekday => EVERY[..5].serialize(serializer),
+            ast::DayFilter::Weekend => {
+                serializer.collect_seq(self.1.days().iter().map(|d| d.as_str()))
+            }
+            ast::DayFilter::Days(days) => serializer.collect_seq(days.iter().map(|d| d.as_str())),
         }
     }
 }
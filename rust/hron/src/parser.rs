@@ -3,13 +3,18 @@
 
 use crate::ast::*;
 use crate::error::{ScheduleError, Span};
-use crate::lexer::{Token, TokenKind};
+use crate::lexer::{ordinal_word_value, Token, TokenKind};
+use crate::prelude::*;
 
 /// Parser state: consumes a slice of tokens.
 struct Parser<'a> {
     tokens: &'a [Token],
     pos: usize,
     input: &'a str,
+    /// Resolve the `in` timezone against the tz database while parsing.
+    strict: bool,
+    /// Byte ranges of the trailing clauses parsed so far.
+    clause_spans: ClauseSpans,
 }
 
 impl<'a> Parser<'a> {
@@ -18,6 +23,8 @@ impl<'a> Parser<'a> {
             tokens,
             pos: 0,
             input,
+            strict: false,
+            clause_spans: ClauseSpans::default(),
         }
     }
 
@@ -50,6 +57,14 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Span from `start` to the end of the last consumed token.
+    fn span_since(&self, start: Span) -> Span {
+        let end = self.tokens[..self.pos]
+            .last()
+            .map_or(start.end, |t| t.span.end);
+        Span::new(start.start, end)
+    }
+
     fn error(&self, message: String, span: Span) -> ScheduleError {
         ScheduleError::parse(message, span, self.input, None)
     }
@@ -81,20 +96,7 @@ impl<'a> Parser<'a> {
         day: u8,
         span: Span,
     ) -> Result<(), ScheduleError> {
-        let max = match month {
-            MonthName::January => 31,
-            MonthName::February => 29,
-            MonthName::March => 31,
-            MonthName::April => 30,
-            MonthName::May => 31,
-            MonthName::June => 30,
-            MonthName::July => 31,
-            MonthName::August => 31,
-            MonthName::September => 30,
-            MonthName::October => 31,
-            MonthName::November => 30,
-            MonthName::December => 31,
-        };
+        let max = month.max_day();
         if day < 1 || day > max {
             return Err(self.error(
                 format!("invalid day {} for {} (max {})", day, month.as_str(), max),
@@ -141,7 +143,7 @@ impl<'a> Parser<'a> {
 
     // --- Grammar productions ---
 
-    // expression = every_expr | on_expr
+    // expression = every_expr | on_expr | frequency_expr | past_hour_expr | cron_shortcut
     fn parse_expression(&mut self) -> Result<Schedule, ScheduleError> {
         let span = self.current_span();
         let expr = match self.peek().map(|t| &t.kind) {
@@ -153,8 +155,17 @@ impl<'a> Parser<'a> {
                 self.advance();
                 self.parse_on()?
             }
+            Some(TokenKind::Frequency(_)) => self.parse_frequency()?,
+            Some(TokenKind::Shortcut(_)) => self.parse_shortcut()?,
+            Some(TokenKind::At) => {
+                self.advance();
+                self.parse_past_every_hour()?
+            }
             _ => {
-                return Err(self.error("expected 'every' or 'on'".into(), span));
+                return Err(self.error(
+                    "expected 'every', 'on', 'at', or a frequency keyword".into(),
+                    span,
+                ));
             }
         };
 
@@ -169,50 +180,91 @@ impl<'a> Parser<'a> {
 
         // except <date>, ...
         if matches!(self.peek().map(|t| &t.kind), Some(TokenKind::Except)) {
+            let start = self.current_span();
             self.advance();
             schedule.except = self.parse_exception_list()?;
+            self.clause_spans.except = Some(self.span_since(start));
         }
 
         // until <date>
         if matches!(self.peek().map(|t| &t.kind), Some(TokenKind::Until)) {
+            let start = self.current_span();
             self.advance();
             schedule.until = Some(self.parse_until_spec()?);
+            self.clause_spans.until = Some(self.span_since(start));
         }
 
-        // starting <iso-date>
+        // starting <iso-date | month-day | today | now> [at <time>]
         if matches!(self.peek().map(|t| &t.kind), Some(TokenKind::Starting)) {
+            let start = self.current_span();
             self.advance();
             match self.peek().map(|t| &t.kind) {
                 Some(TokenKind::IsoDate(d)) => {
-                    let date: jiff::civil::Date = d.parse().map_err(|e| {
-                        self.error(format!("invalid starting date: {e}"), self.current_span())
+                    let date: CivilDate = d.parse().map_err(|_| {
+                        self.error(format!("invalid starting date: {d}"), self.current_span())
                     })?;
                     self.advance();
                     schedule.anchor = Some(date);
                 }
+                Some(TokenKind::MonthName(m)) => {
+                    let month = parse_month_name(m).unwrap();
+                    self.advance();
+                    let (day, day_span) = self.parse_day_number("after month name in starting")?;
+                    self.validate_named_date(month, day, day_span)?;
+                    schedule.anchor_relative = Some(RelativeAnchor::Named { month, day });
+                }
+                Some(TokenKind::Relative(word)) => {
+                    schedule.anchor_relative = Some(match word.as_str() {
+                        "today" => RelativeAnchor::Today,
+                        _ => RelativeAnchor::Now,
+                    });
+                    self.advance();
+                }
                 _ => {
                     let span = self.current_span();
                     return Err(self.error(
-                        "expected ISO date (YYYY-MM-DD) after 'starting'".into(),
+                        "expected ISO date (YYYY-MM-DD), month-day, 'today', or 'now' after 'starting'"
+                            .into(),
                         span,
                     ));
                 }
             }
+            if matches!(self.peek().map(|t| &t.kind), Some(TokenKind::At)) {
+                if schedule.anchor_relative == Some(RelativeAnchor::Now) {
+                    let span = self.current_span();
+                    return Err(self.error("'starting now' already includes a time".into(), span));
+                }
+                self.advance();
+                schedule.anchor_time = Some(self.parse_time()?);
+            }
+            self.clause_spans.starting = Some(self.span_since(start));
         }
+        self.check_day_steps_reachable(&schedule)?;
 
         // during <month_list>
         if matches!(self.peek().map(|t| &t.kind), Some(TokenKind::During)) {
+            let start = self.current_span();
             self.advance();
-            schedule.during = self.parse_month_list()?;
+            schedule.during = self.parse_during_list()?;
+            self.clause_spans.during = Some(self.span_since(start));
         }
 
         // in <timezone>
         if matches!(self.peek().map(|t| &t.kind), Some(TokenKind::In)) {
+            let start = self.current_span();
             self.advance();
             match self.peek().map(|t| &t.kind) {
                 Some(TokenKind::Timezone(tz)) => {
+                    #[cfg(feature = "eval")]
+                    if self.strict {
+                        if let Err(e) = jiff::tz::TimeZone::get(tz) {
+                            let span = self.current_span();
+                            return Err(self.error(format!("invalid timezone '{tz}': {e}"), span));
+                        }
+                    }
                     schedule.timezone = Some(tz.clone());
                     self.advance();
+                    self.clause_spans.timezone = Some(self.span_since(start));
                 }
                 _ => {
                     let span = self.current_span();
@@ -224,42 +276,153 @@ impl<'a> Parser<'a> {
         Ok(schedule)
     }
 
+    /// Reject a day step whose filter excludes every aligned day. A step that
+    /// is a multiple of 7 lands on the anchor's weekday every time, so a
+    /// filter without that weekday never fires. With `starting today` or
+    /// `starting now` that weekday depends on the evaluation date, so such a
+    /// step needs a filter that lets every day through.
+    fn check_day_steps_reachable(&self, schedule: &Schedule) -> Result<(), ScheduleError> {
+        let (interval, filter) = match &schedule.expr {
+            ScheduleExpr::DayRepeat { interval, days, .. } => (*interval, days),
+            ScheduleExpr::IntervalRepeat {
+                interval,
+                unit: IntervalUnit::Days,
+                day_filter: Some(filter),
+                ..
+            } => (*interval, filter),
+            _ => return Ok(()),
+        };
+        if interval % 7 != 0 {
+            return Ok(());
+        }
+        if let Some(relative) = schedule.anchor_relative {
+            let every_day = (1..=7)
+                .filter_map(Weekday::from_number)
+                .all(|day| filter.contains(day, schedule.weekend));
+            if every_day {
+                return Ok(());
+            }
+            return Err(self.error(
+                format!(
+                    "every {interval} days starting {relative} lands on the weekday of the evaluation date, which the day filter may exclude"
+                ),
+                Span::new(0, self.input.len()),
+            ));
+        }
+        let anchor = schedule
+            .anchor
+            .unwrap_or_else(|| CivilDate::new(1970, 1, 1).unwrap());
+        let weekday = anchor.weekday();
+        if filter.contains(weekday, schedule.weekend) {
+            return Ok(());
+        }
+        Err(self.error(
+            format!(
+                "every {interval} days always lands on a {}, which the day filter excludes",
+                weekday.as_str()
+            ),
+            Span::new(0, self.input.len()),
+        ))
+    }
+
+    // "except [on] exception, ...": dates, day names, "weekday", "weekend"
     fn parse_exception_list(&mut self) -> Result<Vec<Exception>, ScheduleError> {
+        if matches!(self.peek().map(|t| &t.kind), Some(TokenKind::On)) {
+            self.advance();
+        }
+
         let mut exceptions = Vec::new();
-        exceptions.push(self.parse_exception()?);
+        exceptions.extend(self.parse_exception()?);
 
         while matches!(self.peek().map(|t| &t.kind), Some(TokenKind::Comma)) {
             self.advance();
-            exceptions.push(self.parse_exception()?);
+            exceptions.extend(self.parse_exception()?);
         }
 
         Ok(exceptions)
     }
 
     fn validate_iso_date(&self, d: &str) -> Result<(), ScheduleError> {
-        d.parse::<jiff::civil::Date>()
+        d.parse::<CivilDate>()
             .map_err(|_| self.error(format!("invalid date: {d}"), self.current_span()))?;
         Ok(())
     }
 
-    fn parse_exception(&mut self) -> Result<Exception, ScheduleError> {
+    fn parse_exception(&mut self) -> Result<Vec<Exception>, ScheduleError> {
+        let weekdays = |days: &[Weekday]| days.iter().map(|&d| Exception::Weekday(d)).collect();
         match self.peek().map(|t| &t.kind) {
             Some(TokenKind::IsoDate(d)) => {
                 let d = d.clone();
                 self.validate_iso_date(&d)?;
                 self.advance();
-                Ok(Exception::Iso(d))
+                if !self.at_range_separator() {
+                    return Ok(vec![Exception::Iso(d)]);
+                }
+                self.advance();
+                let span = self.current_span();
+                let end = match self.peek().map(|t| &t.kind) {
+                    Some(TokenKind::IsoDate(end)) => end.clone(),
+                    _ => return Err(self.error("expected ISO date after 'to'".into(), span)),
+                };
+                self.validate_iso_date(&end)?;
+                // ISO dates order lexically
+                if end < d {
+                    return Err(self.error(format!("exception range ends before {d}"), span));
+                }
+                self.advance();
+                Ok(vec![Exception::IsoRange(d, end)])
             }
             Some(TokenKind::MonthName(m)) => {
                 let month = parse_month_name(m).unwrap();
                 self.advance();
                 let (day, day_span) = self.parse_day_number("after month name in exception")?;
                 self.validate_named_date(month, day, day_span)?;
-                Ok(Exception::Named { month, day })
+                if !self.at_range_separator() {
+                    return Ok(vec![Exception::Named { month, day }]);
+                }
+                self.advance();
+                let end_month = match self.peek().map(|t| &t.kind) {
+                    Some(TokenKind::MonthName(m)) => parse_month_name(m).unwrap(),
+                    _ => {
+                        let span = self.current_span();
+                        return Err(self.error("expected month name after 'to'".into(), span));
+                    }
+                };
+                self.advance();
+                let (end_day, end_span) = self.parse_day_number("after month name in exception")?;
+                self.validate_named_date(end_month, end_day, end_span)?;
+                Ok(vec![Exception::NamedRange {
+                    start_month: month,
+                    start_day: day,
+                    end_month,
+                    end_day,
+                }])
+            }
+            Some(TokenKind::DayName(name)) => {
+                let day = parse_weekday(name).unwrap();
+                self.advance();
+                Ok(vec![Exception::Weekday(day)])
+            }
+            Some(TokenKind::Weekday) => {
+                self.advance();
+                Ok(weekdays(&[
+                    Weekday::Monday,
+                    Weekday::Tuesday,
+                    Weekday::Wednesday,
+                    Weekday::Thursday,
+                    Weekday::Friday,
+                ]))
+            }
+            Some(TokenKind::Weekend) => {
+                self.advance();
+                Ok(weekdays(&[Weekday::Saturday, Weekday::Sunday]))
             }
             _ => {
                 let span = self.current_span();
-                Err(self.error("expected ISO date or month-day in exception".into(), span))
+                Err(self.error(
+                    "expected ISO date, month-day, or day name in exception".into(),
+                    span,
+                ))
             }
         }
     }
@@ -279,9 +442,22 @@ impl<'a> Parser<'a> {
                 self.validate_named_date(month, day, day_span)?;
                 Ok(UntilSpec::Named { month, day })
             }
+            Some(TokenKind::Number(n)) => {
+                let n = *n;
+                if n == 0 {
+                    let span = self.current_span();
+                    return Err(self.error("occurrence count must be at least 1".into(), span));
+                }
+                self.advance();
+                self.consume_kind("'times'", |k| matches!(k, TokenKind::Times))?;
+                Ok(UntilSpec::Count(n))
+            }
             _ => {
                 let span = self.current_span();
-                Err(self.error("expected ISO date or month-day after 'until'".into(), span))
+                Err(self.error(
+                    "expected ISO date, month-day, or count after 'until'".into(),
+                    span,
+                ))
             }
         }
     }
@@ -297,7 +473,10 @@ impl<'a> Parser<'a> {
                 self.parse_year_repeat(1)
             }
             // "every day at ..."
-            Some(TokenKind::Day) => self.parse_day_repeat(1, DayFilter::Every),
+            Some(TokenKind::Day) => {
+                self.advance();
+                self.parse_day_repeat(1, DayFilter::Every)
+            }
             // "every weekday at ..."
             Some(TokenKind::Weekday) => {
                 self.advance();
@@ -311,7 +490,18 @@ impl<'a> Parser<'a> {
             // "every monday ..." or "every monday, wednesday, friday at ..."
             Some(TokenKind::DayName(_)) => {
                 let days = self.parse_day_list()?;
-                self.parse_day_repeat(1, DayFilter::Days(days))
+                // "every monday every 2 weeks at ..." is a week repeat
+                if matches!(self.peek().map(|t| &t.kind), Some(TokenKind::Every)) {
+                    self.advance();
+                    let interval = self.parse_week_interval()?;
+                    let times = self.parse_times_or_midnight()?;
+                    return Ok(ScheduleExpr::WeekRepeat {
+                        interval,
+                        days,
+                        times,
+                    });
+                }
+                self.parse_day_repeat(1, DayFilter::Days(days).canonical())
             }
             // "every week on ..."
             Some(TokenKind::Weeks) => {
@@ -323,8 +513,12 @@ impl<'a> Parser<'a> {
                 self.advance();
                 self.parse_month_repeat(1)
             }
+            // "every hour at :15", "every minute from 09:00 to 10:00"
+            Some(TokenKind::IntervalUnit(_)) => self.parse_interval_repeat(1),
             // "every N ..." — could be interval or week repeat
             Some(TokenKind::Number(_)) => self.parse_number_repeat(),
+            // "every 1.5 hours from ..."
+            Some(TokenKind::Decimal(_)) => self.parse_fractional_interval(),
             _ => {
                 let span = self.current_span();
                 Err(self.error(
@@ -336,18 +530,96 @@ impl<'a> Parser<'a> {
         }
     }
 
-    // day_repeat: day_target already parsed (or "day" not yet consumed)
+    // Cron `@` shortcut: "@daily" is "every day at 00:00", and takes the same trailing
+    // clauses as that form ("@daily in UTC").
+    fn parse_shortcut(&mut self) -> Result<ScheduleExpr, ScheduleError> {
+        let span = self.current_span();
+        let name = match &self.peek().unwrap().kind {
+            TokenKind::Shortcut(name) => name.clone(),
+            _ => unreachable!(),
+        };
+        self.advance();
+        crate::cron::cron_shortcut_expr(&name).ok_or_else(|| {
+            ScheduleError::parse(
+                format!("unknown @ shortcut: {name}"),
+                span,
+                self.input,
+                Some("@yearly, @monthly, @weekly, @daily, @midnight, or @hourly".into()),
+            )
+        })
+    }
+
+    // Bare shorthand: "daily", "weekly", "monthly", "yearly", or "hourly". Each maps to the
+    // canonical "every ..." form with interval 1 and accepts what that form does. Without
+    // "on", weekly falls on monday (the default week start), monthly on the 1st, and yearly
+    // on jan 1; without a window, hourly runs all day.
+    fn parse_frequency(&mut self) -> Result<ScheduleExpr, ScheduleError> {
+        let word = match &self.peek().unwrap().kind {
+            TokenKind::Frequency(w) => w.clone(),
+            _ => unreachable!("parse_frequency called without Frequency token"),
+        };
+        self.advance();
+
+        let next = self.peek().map(|t| &t.kind);
+        let has_on = matches!(next, Some(TokenKind::On));
+        match word.as_str() {
+            "daily" => self.parse_day_repeat(1, DayFilter::Every),
+            "weekly" if has_on => self.parse_week_repeat(1),
+            "weekly" => Ok(ScheduleExpr::WeekRepeat {
+                interval: 1,
+                days: vec![Weekday::Monday],
+                times: self.parse_times_or_midnight()?,
+            }),
+            "monthly" if has_on => self.parse_month_repeat(1),
+            "monthly" => Ok(ScheduleExpr::MonthRepeat {
+                interval: 1,
+                target: MonthTarget::Days(vec![DayOfMonthSpec::Single(1)]),
+                times: self.parse_times_or_midnight()?,
+                day_filter: None,
+            }),
+            // "yearly in iso weeks ..." as well as "yearly on ..."
+            "yearly"
+                if has_on
+                    || matches!(next, Some(TokenKind::In))
+                        && matches!(
+                            self.tokens.get(self.pos + 1).map(|t| &t.kind),
+                            Some(TokenKind::Iso)
+                        ) =>
+            {
+                self.parse_year_repeat(1)
+            }
+            "yearly" => Ok(ScheduleExpr::YearRepeat {
+                interval: 1,
+                target: YearTarget::Date {
+                    month: MonthName::January,
+                    day: 1,
+                },
+                times: self.parse_times_or_midnight()?,
+            }),
+            // "hourly at :15" and "hourly from 09:00 to 17:00" as after "every hour"
+            "hourly"
+                if matches!(
+                    next,
+                    Some(TokenKind::At | TokenKind::From | TokenKind::Between)
+                ) =>
+            {
+                self.parse_interval_window(1, IntervalUnit::Hours)
+            }
+            "hourly" => {
+                let day_filter = self.parse_optional_day_filter()?;
+                Ok(hourly_at(1, 0, day_filter))
+            }
+            _ => unreachable!("lexer only produces known frequency keywords"),
+        }
+    }
+
+    // day_repeat: day_target (or "day") already consumed
     fn parse_day_repeat(
         &mut self,
         interval: u32,
         days: DayFilter,
     ) -> Result<ScheduleExpr, ScheduleError> {
-        // If days is Every, consume the "day" token
-        if days == DayFilter::Every {
-            self.consume_kind("'day'", |k| matches!(k, TokenKind::Day))?;
-        }
-        self.consume_kind("'at'", |k| matches!(k, TokenKind::At))?;
-        let times = self.parse_time_list()?;
+        let times = self.parse_times_or_midnight()?;
         Ok(ScheduleExpr::DayRepeat {
             interval,
             days,
@@ -355,7 +627,8 @@ impl<'a> Parser<'a> {
         })
     }
 
-    // After "every N": dispatch to interval_repeat, week_repeat, day_repeat, month_repeat, or year_repeat
+    // After "every N": dispatch to interval_repeat, week_repeat, day_repeat, weekday_repeat,
+    // month_repeat, or year_repeat
     fn parse_number_repeat(&mut self) -> Result<ScheduleExpr, ScheduleError> {
         let num = match &self.peek().unwrap().kind {
             TokenKind::Number(n) => *n,
@@ -375,8 +648,38 @@ impl<'a> Parser<'a> {
             }
             // "every N min/hours from ..."
             Some(TokenKind::IntervalUnit(_)) => self.parse_interval_repeat(num),
-            // "every N days at ..." / "every N day at ..."
-            Some(TokenKind::Day) => self.parse_day_repeat(num, DayFilter::Every),
+            // "every N days from HH:MM to HH:MM" — day-step interval window
+            Some(TokenKind::Day)
+                if matches!(
+                    self.tokens.get(self.pos + 1).map(|t| &t.kind),
+                    Some(TokenKind::From | TokenKind::Between)
+                ) =>
+            {
+                self.parse_interval_repeat(num)
+            }
+            // "every N days [on day_target] at ..." / "every N day at ..."
+            Some(TokenKind::Day) => {
+                self.advance();
+                let days = if matches!(self.peek().map(|t| &t.kind), Some(TokenKind::On)) {
+                    self.advance();
+                    self.parse_day_target()?
+                } else {
+                    DayFilter::Every
+                };
+                self.parse_day_repeat(num, days)
+            }
+            // "every N weekdays at ..." — every Nth business day
+            Some(TokenKind::Weekday) => {
+                self.advance();
+                if num == 1 {
+                    return self.parse_day_repeat(1, DayFilter::Weekday);
+                }
+                let times = self.parse_times_or_midnight()?;
+                Ok(ScheduleExpr::WeekdayRepeat {
+                    interval: num,
+                    times,
+                })
+            }
             // "every N months on ..." / "every N month on ..."
             Some(TokenKind::Month) => {
                 self.advance();
@@ -390,40 +693,98 @@ impl<'a> Parser<'a> {
             _ => {
                 let span = self.current_span();
                 Err(self.error(
-                    "expected 'weeks', 'days', 'months', 'years', 'min', 'minutes', 'hour', or 'hours' after number".into(),
+                    "expected 'weeks', 'days', 'weekdays', 'months', 'years', 'min', 'minutes', 'hour', or 'hours' after number".into(),
                     span,
                 ))
             }
         }
     }
 
-    // interval_repeat: "every [N] unit from HH:MM to HH:MM [on day_target]"
-    fn parse_interval_repeat(&mut self, interval: u32) -> Result<ScheduleExpr, ScheduleError> {
-        let unit_str = match &self.peek().unwrap().kind {
-            TokenKind::IntervalUnit(u) => u.clone(),
-            _ => unreachable!("parse_interval_repeat called without IntervalUnit token"),
+    // "every D hours ..." where D is a decimal: converted to whole minutes, so
+    // "every 1.5 hours" is the same schedule as "every 90 min"
+    fn parse_fractional_interval(&mut self) -> Result<ScheduleExpr, ScheduleError> {
+        let (text, span) = match self.peek().unwrap() {
+            Token {
+                kind: TokenKind::Decimal(text),
+                span,
+            } => (text.clone(), *span),
+            _ => unreachable!("parse_fractional_interval called without Decimal token"),
         };
         self.advance();
 
-        let unit = match unit_str.as_str() {
-            "min" => IntervalUnit::Minutes,
-            "hours" => IntervalUnit::Hours,
-            _ => unreachable!("lexer produced invalid IntervalUnit: {unit_str}"),
+        if !matches!(self.peek().map(|t| &t.kind), Some(TokenKind::IntervalUnit(u)) if u == "hours")
+        {
+            return Err(self.error(
+                "fractional intervals are only supported in hours".into(),
+                span,
+            ));
+        }
+        self.advance();
+
+        let Some(minutes) = fractional_hours_to_minutes(&text) else {
+            return Err(self.error(
+                "fractional hours must come to a whole number of minutes".into(),
+                span,
+            ));
         };
+        if minutes == 0 {
+            return Err(self.error("interval must be at least 1".into(), span));
+        }
 
-        self.consume_kind("'from'", |k| matches!(k, TokenKind::From))?;
-        let from = self.parse_time()?;
-        self.consume_kind("'to'", |k| matches!(k, TokenKind::To))?;
-        let to = self.parse_time()?;
+        if minutes % 60 == 0 {
+            self.parse_interval_window(minutes / 60, IntervalUnit::Hours)
+        } else {
+            self.parse_interval_window(minutes, IntervalUnit::Minutes)
+        }
+    }
 
-        // Optional "on day_target"
-        let day_filter = if matches!(self.peek().map(|t| &t.kind), Some(TokenKind::On)) {
+    // interval_repeat: "every [N] unit (from HH:MM to HH:MM | between HH:MM and HH:MM) [on day_target]"
+    // where unit is min, hours, or days
+    fn parse_interval_repeat(&mut self, interval: u32) -> Result<ScheduleExpr, ScheduleError> {
+        let unit = match &self.peek().unwrap().kind {
+            TokenKind::IntervalUnit(u) => match u.as_str() {
+                "min" => IntervalUnit::Minutes,
+                "hours" => IntervalUnit::Hours,
+                _ => unreachable!("lexer produced invalid IntervalUnit: {u}"),
+            },
+            TokenKind::Day => IntervalUnit::Days,
+            _ => unreachable!("parse_interval_repeat called without unit token"),
+        };
+        self.advance();
+        self.parse_interval_window(interval, unit)
+    }
+
+    // The window and optional day filter after the interval unit
+    fn parse_interval_window(
+        &mut self,
+        interval: u32,
+        unit: IntervalUnit,
+    ) -> Result<ScheduleExpr, ScheduleError> {
+        // "every 2 hours at :15": all day, starting MM past midnight
+        if unit == IntervalUnit::Hours
+            && matches!(self.peek().map(|t| &t.kind), Some(TokenKind::At))
+        {
             self.advance();
-            Some(self.parse_day_target()?)
+            let minute = self.parse_minute_past(|k| matches!(k, TokenKind::MinuteOffset(_)))?;
+            let day_filter = self.parse_optional_day_filter()?;
+            return Ok(hourly_at(interval, minute, day_filter));
+        }
+
+        // "from HH:MM to HH:MM" or "between HH:MM and HH:MM"
+        let (from, to) = if matches!(self.peek().map(|t| &t.kind), Some(TokenKind::Between)) {
+            self.advance();
+            let from = self.parse_time()?;
+            self.consume_kind("'and'", |k| matches!(k, TokenKind::And))?;
+            (from, self.parse_time()?)
         } else {
-            None
+            self.consume_kind("'from' or 'between'", |k| matches!(k, TokenKind::From))?;
+            let from = self.parse_time()?;
+            self.consume_kind("'to'", |k| matches!(k, TokenKind::To))?;
+            (from, self.parse_time()?)
         };
 
+        let day_filter = self.parse_optional_day_filter()?;
+
         Ok(ScheduleExpr::IntervalRepeat {
             interval,
             unit,
@@ -433,12 +794,74 @@ impl<'a> Parser<'a> {
         })
     }
 
+    // Optional "on day_target" after an interval window
+    fn parse_optional_day_filter(&mut self) -> Result<Option<DayFilter>, ScheduleError> {
+        if matches!(self.peek().map(|t| &t.kind), Some(TokenKind::On)) {
+            self.advance();
+            Ok(Some(self.parse_day_target()?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    // "at 15 past every hour", "at :15 past every hour": "at" already consumed
+    fn parse_past_every_hour(&mut self) -> Result<ScheduleExpr, ScheduleError> {
+        let minute = self.parse_minute_past(|k| {
+            matches!(k, TokenKind::MinuteOffset(_) | TokenKind::Number(0..=59))
+        })?;
+        self.consume_kind("'past'", |k| matches!(k, TokenKind::Past))?;
+        self.consume_kind("'every'", |k| matches!(k, TokenKind::Every))?;
+        self.consume_kind(
+            "'hour'",
+            |k| matches!(k, TokenKind::IntervalUnit(u) if u == "hours"),
+        )?;
+        let day_filter = self.parse_optional_day_filter()?;
+        Ok(hourly_at(1, minute, day_filter))
+    }
+
+    // Minutes past the hour, as ":MM" or (where `accept` allows) a bare number
+    fn parse_minute_past(
+        &mut self,
+        accept: impl Fn(&TokenKind) -> bool,
+    ) -> Result<u8, ScheduleError> {
+        let span = self.current_span();
+        match self.peek().map(|t| &t.kind) {
+            Some(kind) if accept(kind) => {
+                let minute = match kind {
+                    TokenKind::MinuteOffset(m) => *m,
+                    TokenKind::Number(n) => *n as u8,
+                    _ => unreachable!("accept only admits minute tokens"),
+                };
+                self.advance();
+                Ok(minute)
+            }
+            _ => Err(self.error("expected minutes past the hour (e.g. :15)".into(), span)),
+        }
+    }
+
     // week_repeat: "every N weeks on day_list at HH:MM[, HH:MM]"
+    // "week" or "N weeks", after a day list and a second "every"
+    fn parse_week_interval(&mut self) -> Result<u32, ScheduleError> {
+        let span = self.current_span();
+        let interval = match self.peek().map(|t| &t.kind) {
+            Some(TokenKind::Number(0)) => {
+                return Err(self.error("interval must be at least 1".into(), span));
+            }
+            Some(TokenKind::Number(n)) => {
+                let n = *n;
+                self.advance();
+                n
+            }
+            _ => 1,
+        };
+        self.consume_kind("'week' or 'weeks'", |k| matches!(k, TokenKind::Weeks))?;
+        Ok(interval)
+    }
+
     fn parse_week_repeat(&mut self, interval: u32) -> Result<ScheduleExpr, ScheduleError> {
         self.consume_kind("'on'", |k| matches!(k, TokenKind::On))?;
         let days = self.parse_day_list()?;
-        self.consume_kind("'at'", |k| matches!(k, TokenKind::At))?;
-        let times = self.parse_time_list()?;
+        let times = self.parse_times_or_midnight()?;
 
         Ok(ScheduleExpr::WeekRepeat {
             interval,
@@ -453,6 +876,11 @@ impl<'a> Parser<'a> {
         self.consume_kind("'the'", |k| matches!(k, TokenKind::The))?;
 
         let target = match self.peek().map(|t| &t.kind) {
+            // "the first monday", "the first and last friday", as opposed to
+            // "the first" (day 1)
+            Some(TokenKind::Ordinal(_) | TokenKind::Last) if self.ordinal_weekdays_ahead() => {
+                self.parse_ordinal_weekdays()?
+            }
             Some(TokenKind::Last) => {
                 self.advance();
                 match self.peek().map(|t| &t.kind) {
@@ -460,17 +888,26 @@ impl<'a> Parser<'a> {
                         self.advance();
                         MonthTarget::LastDay
                     }
-                    Some(TokenKind::Weekday) => {
+                    // "last N days"; "last 1 day" is the last day
+                    Some(TokenKind::Number(n)) => {
+                        let n = *n;
+                        let span = self.current_span();
+                        if !(1..=31).contains(&n) {
+                            return Err(
+                                self.error(format!("invalid day count: {n} (must be 1-31)"), span)
+                            );
+                        }
                         self.advance();
-                        MonthTarget::LastWeekday
+                        self.consume_kind("'days'", |k| matches!(k, TokenKind::Day))?;
+                        if n == 1 {
+                            MonthTarget::LastDay
+                        } else {
+                            MonthTarget::LastNDays(n as u8)
+                        }
                     }
-                    Some(TokenKind::DayName(name)) => {
-                        let weekday = parse_weekday(name).unwrap();
+                    Some(TokenKind::Weekday) => {
                         self.advance();
-                        MonthTarget::OrdinalWeekday {
-                            ordinal: OrdinalPosition::Last,
-                            weekday,
-                        }
+                        MonthTarget::LastWeekday
                     }
                     _ => {
                         let span = self.current_span();
@@ -481,24 +918,16 @@ impl<'a> Parser<'a> {
                     }
                 }
             }
-            Some(TokenKind::Ordinal(_)) => {
-                let ordinal = self.parse_ordinal_position()?;
-                match self.peek().map(|t| &t.kind) {
-                    Some(TokenKind::DayName(name)) => {
-                        let weekday = parse_weekday(name).unwrap();
-                        self.advance();
-                        MonthTarget::OrdinalWeekday { ordinal, weekday }
-                    }
-                    _ => {
-                        let span = self.current_span();
-                        return Err(self.error(
-                            "expected day name after ordinal in monthly expression".into(),
-                            span,
-                        ));
-                    }
-                }
+            // "the first weekday", "the 3rd business day"
+            Some(TokenKind::Ordinal(_) | TokenKind::OrdinalNumber(_))
+                if matches!(
+                    self.tokens.get(self.pos + 1).map(|t| &t.kind),
+                    Some(TokenKind::Weekday | TokenKind::Business)
+                ) =>
+            {
+                self.parse_business_day_target()?
             }
-            Some(TokenKind::OrdinalNumber(_)) => {
+            Some(TokenKind::Ordinal(_) | TokenKind::OrdinalNumber(_)) => {
                 let days = self.parse_ordinal_day_list()?;
                 MonthTarget::Days(days)
             }
@@ -515,16 +944,78 @@ impl<'a> Parser<'a> {
             }
         };
 
-        self.consume_kind("'at'", |k| matches!(k, TokenKind::At))?;
-        let times = self.parse_time_list()?;
+        let times = self.parse_times()?;
+
+        // Optional "on day_target" weekday filter
+        let day_filter = if matches!(self.peek().map(|t| &t.kind), Some(TokenKind::On)) {
+            self.advance();
+            Some(self.parse_day_target()?)
+        } else {
+            None
+        };
 
         Ok(ScheduleExpr::MonthRepeat {
             interval,
             target,
             times,
+            day_filter,
+        })
+    }
+
+    // <ordinal> (weekday | business day)
+    // Whether the tokens ahead are ordinal words joined by commas or "and"
+    // and then a day name: "first monday", "first, third and last friday"
+    fn ordinal_weekdays_ahead(&self) -> bool {
+        let mut i = self.pos;
+        loop {
+            match self.tokens.get(i).map(|t| &t.kind) {
+                Some(TokenKind::Ordinal(_) | TokenKind::Last) => i += 1,
+                _ => return false,
+            }
+            match self.tokens.get(i).map(|t| &t.kind) {
+                Some(TokenKind::DayName(_)) => return true,
+                Some(TokenKind::Comma | TokenKind::And) => i += 1,
+                _ => return false,
+            }
+        }
+    }
+
+    // "first monday", "first and last friday", "second, fourth tuesday"
+    fn parse_ordinal_weekdays(&mut self) -> Result<MonthTarget, ScheduleError> {
+        let mut ordinals = vec![self.parse_ordinal_position()?];
+        while matches!(
+            self.peek().map(|t| &t.kind),
+            Some(TokenKind::Comma | TokenKind::And)
+        ) {
+            self.advance();
+            ordinals.push(self.parse_ordinal_position()?);
+        }
+        let weekday =
+            self.parse_day_name("expected day name after ordinal in monthly expression")?;
+        ordinals.sort();
+        ordinals.dedup();
+        Ok(match ordinals[..] {
+            [ordinal] => MonthTarget::OrdinalWeekday { ordinal, weekday },
+            _ => MonthTarget::OrdinalWeekdays { ordinals, weekday },
         })
     }
 
+    fn parse_business_day_target(&mut self) -> Result<MonthTarget, ScheduleError> {
+        let span = self.current_span();
+        let n = self.parse_ordinal_day("expected ordinal before 'business day'")?;
+        // No month has more than 23 weekdays
+        if n > 23 {
+            return Err(self.error(format!("invalid business day: {n} (must be 1-23)"), span));
+        }
+        if matches!(self.peek().map(|t| &t.kind), Some(TokenKind::Business)) {
+            self.advance();
+            self.consume_kind("'day'", |k| matches!(k, TokenKind::Day))?;
+        } else {
+            self.consume_kind("'weekday'", |k| matches!(k, TokenKind::Weekday))?;
+        }
+        Ok(MonthTarget::BusinessDay(n))
+    }
+
     // [next|previous] nearest weekday to <day>
     fn parse_nearest_weekday_target(&mut self) -> Result<MonthTarget, ScheduleError> {
         // Optional direction: "next" or "previous"
@@ -550,21 +1041,35 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_ordinal_day_number(&mut self) -> Result<u8, ScheduleError> {
-        match self.peek().map(|t| &t.kind) {
-            Some(TokenKind::OrdinalNumber(n)) => {
-                let d = self.validate_day_number(*n)?;
+        self.parse_ordinal_day("expected ordinal day number")
+    }
+
+    /// Parse an ordinal day of month, as digits (`21st`) or a word
+    /// (`twenty-first`), validating it's 1-31.
+    fn parse_ordinal_day(&mut self, expected: &str) -> Result<u8, ScheduleError> {
+        let n = match self.peek().map(|t| &t.kind) {
+            Some(TokenKind::OrdinalNumber(n)) => Some(*n),
+            Some(TokenKind::Ordinal(word)) => ordinal_word_value(word),
+            _ => None,
+        };
+        match n {
+            Some(n) => {
+                let d = self.validate_day_number(n)?;
                 self.advance();
                 Ok(d)
             }
-            _ => {
+            None => {
                 let span = self.current_span();
-                Err(self.error("expected ordinal day number".into(), span))
+                Err(self.error(expected.into(), span))
             }
         }
     }
 
     // year_repeat: "every [N] year[s] on <year_target> at HH:MM"
     fn parse_year_repeat(&mut self, interval: u32) -> Result<ScheduleExpr, ScheduleError> {
+        if matches!(self.peek().map(|t| &t.kind), Some(TokenKind::In)) {
+            return self.parse_iso_week_repeat(interval);
+        }
         self.consume_kind("'on'", |k| matches!(k, TokenKind::On))?;
 
         let target = match self.peek().map(|t| &t.kind) {
@@ -590,8 +1095,7 @@ impl<'a> Parser<'a> {
             }
         };
 
-        self.consume_kind("'at'", |k| matches!(k, TokenKind::At))?;
-        let times = self.parse_time_list()?;
+        let times = self.parse_times()?;
 
         Ok(ScheduleExpr::YearRepeat {
             interval,
@@ -600,6 +1104,47 @@ impl<'a> Parser<'a> {
         })
     }
 
+    // iso_week_repeat: "every year in iso week[s] N[, N] on day_list [at HH:MM]"
+    fn parse_iso_week_repeat(&mut self, interval: u32) -> Result<ScheduleExpr, ScheduleError> {
+        if interval != 1 {
+            let span = self.current_span();
+            return Err(self.error(
+                "ISO week schedules repeat every year; remove the interval".into(),
+                span,
+            ));
+        }
+        self.consume_kind("'in'", |k| matches!(k, TokenKind::In))?;
+        self.consume_kind("'iso'", |k| matches!(k, TokenKind::Iso))?;
+        self.consume_kind("'weeks'", |k| matches!(k, TokenKind::Weeks))?;
+
+        let mut weeks = vec![self.parse_iso_week_number()?];
+        while matches!(self.peek().map(|t| &t.kind), Some(TokenKind::Comma)) {
+            self.advance();
+            weeks.push(self.parse_iso_week_number()?);
+        }
+
+        self.consume_kind("'on'", |k| matches!(k, TokenKind::On))?;
+        let days = self.parse_day_list()?;
+        let times = self.parse_times_or_midnight()?;
+
+        Ok(ScheduleExpr::IsoWeekRepeat { weeks, days, times })
+    }
+
+    fn parse_iso_week_number(&mut self) -> Result<u8, ScheduleError> {
+        let span = self.current_span();
+        match self.peek().map(|t| &t.kind) {
+            Some(TokenKind::Number(n)) if (1..=53).contains(n) => {
+                let week = *n as u8;
+                self.advance();
+                Ok(week)
+            }
+            Some(TokenKind::Number(n)) => {
+                Err(self.error(format!("invalid ISO week {n} (expected 1-53)"), span))
+            }
+            _ => Err(self.error("expected ISO week number".into(), span)),
+        }
+    }
+
     // After "every year on the": parse ordinal weekday, day of month, or last weekday
     fn parse_year_target_after_the(&mut self) -> Result<YearTarget, ScheduleError> {
         match self.peek().map(|t| &t.kind) {
@@ -635,7 +1180,12 @@ impl<'a> Parser<'a> {
                 }
             }
             // "the first monday of march" or "the 15th of march"
-            Some(TokenKind::Ordinal(_)) => {
+            Some(TokenKind::Ordinal(_))
+                if matches!(
+                    self.tokens.get(self.pos + 1).map(|t| &t.kind),
+                    Some(TokenKind::DayName(_))
+                ) =>
+            {
                 let ordinal = self.parse_ordinal_position()?;
                 // Next must be a day name
                 match self.peek().map(|t| &t.kind) {
@@ -659,10 +1209,9 @@ impl<'a> Parser<'a> {
                     }
                 }
             }
-            Some(TokenKind::OrdinalNumber(n)) => {
-                let day = self.validate_day_number(*n)?;
+            Some(TokenKind::Ordinal(_) | TokenKind::OrdinalNumber(_)) => {
                 let day_span = self.current_span();
-                self.advance();
+                let day = self.parse_ordinal_day("expected ordinal day number")?;
                 self.consume_kind("'of'", |k| matches!(k, TokenKind::Of))?;
                 let month = self.parse_month_name_token()?;
                 self.validate_named_date(month, day, day_span)?;
@@ -720,15 +1269,45 @@ impl<'a> Parser<'a> {
     }
 
     // on_expr: "on date_target at HH:MM[, HH:MM]"
+    // on: "on <date>[, <date>...] at HH:MM" or "on <weekday> at HH:MM"
     fn parse_on(&mut self) -> Result<ScheduleExpr, ScheduleError> {
-        let date = self.parse_date_target()?;
-        self.consume_kind("'at'", |k| matches!(k, TokenKind::At))?;
-        let times = self.parse_time_list()?;
-
-        Ok(ScheduleExpr::SingleDate { date, times })
-    }
+        // "on friday at 17:00": the nearest upcoming friday, a rolling one-shot
+        if let Some(TokenKind::DayName(name)) = self.peek().map(|t| &t.kind) {
+            let day = parse_weekday(name).unwrap();
+            self.advance();
+            if matches!(self.peek().map(|t| &t.kind), Some(TokenKind::Comma)) {
+                let span = self.current_span();
+                return Err(self.error(
+                    "a weekday cannot be combined with other dates; use 'every' for recurring days"
+                        .into(),
+                    span,
+                ));
+            }
+            let times = self.parse_times()?;
+            return Ok(ScheduleExpr::SingleDate {
+                date: DateSpec::NextWeekday(day),
+                times,
+            });
+        }
 
-    fn parse_date_target(&mut self) -> Result<DateSpec, ScheduleError> {
+        let mut dates = vec![self.parse_date_target()?];
+        while matches!(self.peek().map(|t| &t.kind), Some(TokenKind::Comma)) {
+            self.advance();
+            dates.push(self.parse_date_target()?);
+        }
+        let times = self.parse_times()?;
+
+        // Sort and de-duplicate the dates, like times
+        dates.sort_by(|a, b| a.sort_key().cmp(&b.sort_key()));
+        dates.dedup();
+        if dates.len() == 1 {
+            let date = dates.remove(0);
+            return Ok(ScheduleExpr::SingleDate { date, times });
+        }
+        Ok(ScheduleExpr::MultiDate { dates, times })
+    }
+
+    fn parse_date_target(&mut self) -> Result<DateSpec, ScheduleError> {
         match self.peek().map(|t| &t.kind) {
             Some(TokenKind::IsoDate(d)) => {
                 let d = d.clone();
@@ -766,7 +1345,7 @@ impl<'a> Parser<'a> {
             }
             Some(TokenKind::DayName(_)) => {
                 let days = self.parse_day_list()?;
-                Ok(DayFilter::Days(days))
+                Ok(DayFilter::Days(days).canonical())
             }
             _ => {
                 let span = self.current_span();
@@ -779,35 +1358,55 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_day_list(&mut self) -> Result<Vec<Weekday>, ScheduleError> {
-        let mut days = Vec::new();
+        let mut days = self.parse_day_range("expected day name")?;
+
+        while matches!(self.peek().map(|t| &t.kind), Some(TokenKind::Comma)) {
+            self.advance(); // skip comma
+            days.extend(self.parse_day_range("expected day name after ','")?);
+        }
+
+        Ok(days)
+    }
+
+    // A day name or a range of them: "monday to friday", "fri through mon"
+    // (wraps past sunday)
+    fn parse_day_range(&mut self, expected: &str) -> Result<Vec<Weekday>, ScheduleError> {
+        let start = self.parse_day_name(expected)?;
+        if !self.at_range_separator() {
+            return Ok(vec![start]);
+        }
+        self.advance();
+        let end = self.parse_day_name("expected day name after 'to'")?;
+        let len = (end.number() + 7 - start.number()) % 7 + 1;
+        Ok((0..len)
+            .filter_map(|i| Weekday::from_number((start.number() - 1 + i) % 7 + 1))
+            .collect())
+    }
+
+    fn parse_day_name(&mut self, expected: &str) -> Result<Weekday, ScheduleError> {
         match self.peek().map(|t| &t.kind) {
             Some(TokenKind::DayName(name)) => {
-                days.push(parse_weekday(name).unwrap());
+                let day = parse_weekday(name).unwrap();
                 self.advance();
+                Ok(day)
             }
             _ => {
                 let span = self.current_span();
-                return Err(self.error("expected day name".into(), span));
-            }
-        }
-
-        while matches!(self.peek().map(|t| &t.kind), Some(TokenKind::Comma)) {
-            self.advance(); // skip comma
-            match self.peek().map(|t| &t.kind) {
-                Some(TokenKind::DayName(name)) => {
-                    days.push(parse_weekday(name).unwrap());
-                    self.advance();
-                }
-                _ => {
-                    let span = self.current_span();
-                    return Err(self.error("expected day name after ','".into(), span));
-                }
+                Err(self.error(expected.into(), span))
             }
         }
+    }
 
-        Ok(days)
+    /// Whether the next token separates a range: `to`, or its synonym `through`.
+    /// `through` is only accepted in ranges, never in `from ... to ...` windows.
+    fn at_range_separator(&self) -> bool {
+        matches!(
+            self.peek().map(|t| &t.kind),
+            Some(TokenKind::To | TokenKind::Through)
+        )
     }
 
+    // Day specs are sorted and de-duplicated: "the 15th, 1st, 15th" is "the 1st, 15th"
     fn parse_ordinal_day_list(&mut self) -> Result<Vec<DayOfMonthSpec>, ScheduleError> {
         let mut specs = Vec::new();
         specs.push(self.parse_ordinal_day_spec()?);
@@ -817,36 +1416,18 @@ impl<'a> Parser<'a> {
             specs.push(self.parse_ordinal_day_spec()?);
         }
 
+        specs.sort_by_key(|spec| spec.sort_key());
+        specs.dedup_by_key(|spec| spec.sort_key());
         Ok(specs)
     }
 
     fn parse_ordinal_day_spec(&mut self) -> Result<DayOfMonthSpec, ScheduleError> {
-        let start = match self.peek().map(|t| &t.kind) {
-            Some(TokenKind::OrdinalNumber(n)) => {
-                let d = self.validate_day_number(*n)?;
-                self.advance();
-                d
-            }
-            _ => {
-                let span = self.current_span();
-                return Err(self.error("expected ordinal day number".into(), span));
-            }
-        };
+        let start = self.parse_ordinal_day("expected ordinal day number")?;
 
-        // Check for range: "1st to 15th"
-        if matches!(self.peek().map(|t| &t.kind), Some(TokenKind::To)) {
+        // Check for range: "1st to 15th", "1st through 15th"
+        if self.at_range_separator() {
             self.advance(); // skip "to"
-            let end = match self.peek().map(|t| &t.kind) {
-                Some(TokenKind::OrdinalNumber(n)) => {
-                    let d = self.validate_day_number(*n)?;
-                    self.advance();
-                    d
-                }
-                _ => {
-                    let span = self.current_span();
-                    return Err(self.error("expected ordinal day number after 'to'".into(), span));
-                }
-            };
+            let end = self.parse_ordinal_day("expected ordinal day number after 'to'")?;
             if start > end {
                 let span = self.current_span();
                 return Err(self.error(
@@ -863,21 +1444,111 @@ impl<'a> Parser<'a> {
         }
     }
 
-    fn parse_month_list(&mut self) -> Result<Vec<MonthName>, ScheduleError> {
-        let mut months = vec![self.parse_month_name_token()?];
+    // Specs are sorted by where they start and de-duplicated: "during mar, jan, mar"
+    // is "during jan, mar". Overlapping specs are not merged.
+    fn parse_during_list(&mut self) -> Result<Vec<DuringSpec>, ScheduleError> {
+        let mut specs = vec![self.parse_during_spec()?];
         while matches!(self.peek().map(|t| &t.kind), Some(TokenKind::Comma)) {
             self.advance();
-            months.push(self.parse_month_name_token()?);
+            specs.push(self.parse_during_spec()?);
+        }
+        specs.sort_by_key(|spec| spec.sort_key());
+        specs.dedup_by_key(|spec| spec.sort_key());
+        Ok(specs)
+    }
+
+    // during_spec: "jun", "jun to aug" (wraps past december: "nov to feb"),
+    // or month-day bounds "jun 15 to aug 31". A bound without a day covers
+    // its whole month.
+    fn parse_during_spec(&mut self) -> Result<DuringSpec, ScheduleError> {
+        let start = self.parse_month_name_token()?;
+        let start_day = self.parse_during_day(start)?;
+        if !self.at_range_separator() {
+            // "during jun 15" is that one day
+            return Ok(match start_day {
+                Some(day) => DuringSpec::Dates((start, day), (start, day)),
+                None => DuringSpec::Single(start),
+            });
+        }
+        self.advance();
+        let end = self.parse_month_name_token()?;
+        let end_day = self.parse_during_day(end)?;
+        if start_day.is_none() && end_day.is_none() {
+            return Ok(DuringSpec::Range(start, end));
+        }
+        Ok(DuringSpec::Dates(
+            (start, start_day.unwrap_or(1)),
+            (end, end_day.unwrap_or(end.max_day())),
+        ))
+    }
+
+    /// Optional day after a month name in `during`, validated for the month.
+    fn parse_during_day(&mut self, month: MonthName) -> Result<Option<u8>, ScheduleError> {
+        if !matches!(
+            self.peek().map(|t| &t.kind),
+            Some(TokenKind::Number(_) | TokenKind::OrdinalNumber(_))
+        ) {
+            return Ok(None);
+        }
+        let (day, span) = self.parse_day_number("after month name in during")?;
+        self.validate_named_date(month, day, span)?;
+        Ok(Some(day))
+    }
+
+    // times: "at HH:MM, ..." or "in the morning|afternoon|evening|night"
+    fn parse_times(&mut self) -> Result<Vec<TimeOfDay>, ScheduleError> {
+        if matches!(self.peek().map(|t| &t.kind), Some(TokenKind::In)) {
+            self.advance();
+            self.consume_kind("'the'", |k| matches!(k, TokenKind::The))?;
+            let span = self.current_span();
+            let time = match self.peek().map(|t| &t.kind) {
+                Some(TokenKind::Period(p)) => match p.as_str() {
+                    "morning" => TimeOfDay::MORNING,
+                    "afternoon" => TimeOfDay::AFTERNOON,
+                    "evening" => TimeOfDay::EVENING,
+                    _ => TimeOfDay::NIGHT,
+                },
+                _ => {
+                    return Err(self.error(
+                        "expected morning, afternoon, evening, or night".into(),
+                        span,
+                    ));
+                }
+            };
+            self.advance();
+            return Ok(vec![time]);
+        }
+        self.consume_kind("'at'", |k| matches!(k, TokenKind::At))?;
+        self.parse_time_list()
+    }
+
+    // Day and week repeats may omit times: "every day", "every weekday in UTC"
+    // fire at 00:00. A bare "in" starts the timezone clause, not "in the ...".
+    fn parse_times_or_midnight(&mut self) -> Result<Vec<TimeOfDay>, ScheduleError> {
+        let has_times = match self.peek().map(|t| &t.kind) {
+            Some(TokenKind::At) => true,
+            Some(TokenKind::In) => matches!(
+                self.tokens.get(self.pos + 1).map(|t| &t.kind),
+                Some(TokenKind::The)
+            ),
+            _ => false,
+        };
+        if has_times {
+            self.parse_times()
+        } else {
+            Ok(vec![TimeOfDay::MIDNIGHT])
         }
-        Ok(months)
     }
 
+    // Times are sorted and de-duplicated: "at 17:00, 09:00, 09:00" is "at 09:00, 17:00"
     fn parse_time_list(&mut self) -> Result<Vec<TimeOfDay>, ScheduleError> {
         let mut times = vec![self.parse_time()?];
         while matches!(self.peek().map(|t| &t.kind), Some(TokenKind::Comma)) {
             self.advance();
             times.push(self.parse_time()?);
         }
+        times.sort();
+        times.dedup();
         Ok(times)
     }
 
@@ -897,8 +1568,73 @@ impl<'a> Parser<'a> {
     }
 }
 
+/// Convert decimal hours (`1.5`) to whole minutes, or `None` if they don't
+/// come to a whole number of minutes.
+fn fractional_hours_to_minutes(text: &str) -> Option<u32> {
+    let (whole, frac) = text.split_once('.')?;
+    if frac.len() > 9 {
+        return None;
+    }
+    let scale = 10u64.pow(frac.len() as u32);
+    let scaled = whole
+        .parse::<u64>()
+        .ok()?
+        .checked_mul(scale)?
+        .checked_add(frac.parse().ok()?)?;
+    let total = scaled.checked_mul(60)?;
+    if total % scale != 0 {
+        return None;
+    }
+    u32::try_from(total / scale).ok()
+}
+
+/// An all-day hour step firing `minute` past the hour: `every hour at :15`
+/// is `every 1 hour from 00:15 to 23:59`.
+fn hourly_at(interval: u32, minute: u8, day_filter: Option<DayFilter>) -> ScheduleExpr {
+    ScheduleExpr::IntervalRepeat {
+        interval,
+        unit: IntervalUnit::Hours,
+        from: TimeOfDay { hour: 0, minute },
+        to: TimeOfDay {
+            hour: 23,
+            minute: 59,
+        },
+        day_filter,
+    }
+}
+
 /// Parse an hron expression string into a Schedule AST.
 pub fn parse(input: &str) -> Result<Schedule, ScheduleError> {
+    parse_with(input, false).map(|(schedule, _)| schedule)
+}
+
+/// Like [`parse`], but also checks that the `in` timezone exists in the tz database.
+#[cfg(feature = "eval")]
+pub fn parse_strict(input: &str) -> Result<Schedule, ScheduleError> {
+    parse_with(input, true).map(|(schedule, _)| schedule)
+}
+
+/// Like [`parse`], also returning where each trailing clause sits in `input`.
+pub fn parse_with_spans(input: &str) -> Result<(Schedule, ClauseSpans), ScheduleError> {
+    parse_with(input, false)
+}
+
+/// Parse each line of a config file, skipping blank lines and `#` comments.
+///
+/// Returns the 1-based line number alongside each line's parse result.
+pub fn parse_all(input: &str) -> Vec<(usize, Result<Schedule, ScheduleError>)> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| {
+            let line = line.trim();
+            !line.is_empty() && !line.starts_with('#')
+        })
+        .map(|(i, line)| (i + 1, parse(line)))
+        .collect()
+}
+
+fn parse_with(input: &str, strict: bool) -> Result<(Schedule, ClauseSpans), ScheduleError> {
     let mut lexer = crate::lexer::Lexer::new(input);
     let tokens = lexer.tokenize()?;
 
@@ -912,6 +1648,7 @@ pub fn parse(input: &str) -> Result<Schedule, ScheduleError> {
     }
 
     let mut parser = Parser::new(&tokens, input);
+    parser.strict = strict;
     let schedule = parser.parse_expression()?;
 
     // Ensure all tokens consumed
@@ -925,7 +1662,7 @@ pub fn parse(input: &str) -> Result<Schedule, ScheduleError> {
         ));
     }
 
-    Ok(schedule)
+    Ok((schedule, parser.clause_spans))
 }
 
 #[cfg(test)]
@@ -980,6 +1717,23 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "eval")]
+    #[test]
+    fn test_parse_strict_timezone() {
+        assert!(parse("every day at 9:00 in Mars/Phobos").is_ok());
+        assert!(parse_strict("every day at 9:00 in Europe/London").is_ok());
+        assert!(parse_strict("every day at 9:00").is_ok());
+
+        let input = "every day at 9:00 in Mars/Phobos";
+        let err = parse_strict(input).unwrap_err();
+        match err {
+            ScheduleError::Parse { span, .. } => {
+                assert_eq!(&input[span.start..span.end], "Mars/Phobos");
+            }
+            _ => panic!("expected parse error"),
+        }
+    }
+
     #[test]
     fn test_parse_interval() {
         let s = parse("every 30 min from 09:00 to 17:00").unwrap();
@@ -1018,6 +1772,78 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_interval_between_and_meridiem() {
+        let s = parse("every 15 minutes between 9am and 5pm on weekdays").unwrap();
+        assert_eq!(
+            s.expr,
+            ScheduleExpr::IntervalRepeat {
+                interval: 15,
+                unit: IntervalUnit::Minutes,
+                from: TimeOfDay { hour: 9, minute: 0 },
+                to: TimeOfDay {
+                    hour: 17,
+                    minute: 0
+                },
+                day_filter: Some(DayFilter::Weekday),
+            }
+        );
+        assert_eq!(
+            s,
+            parse("every 15 min from 09:00 to 17:00 on weekday").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_error_between_without_and() {
+        assert!(parse("every 15 min between 9am to 5pm").is_err());
+    }
+
+    #[test]
+    fn test_parse_interval_days() {
+        let s = parse("every 3 days from 06:00 to 22:00 on weekdays").unwrap();
+        assert_eq!(
+            s.expr,
+            ScheduleExpr::IntervalRepeat {
+                interval: 3,
+                unit: IntervalUnit::Days,
+                from: TimeOfDay { hour: 6, minute: 0 },
+                to: TimeOfDay {
+                    hour: 22,
+                    minute: 0
+                },
+                day_filter: Some(DayFilter::Weekday),
+            }
+        );
+        // "at" still selects a plain day repeat
+        assert!(matches!(
+            parse("every 3 days at 06:00").unwrap().expr,
+            ScheduleExpr::DayRepeat { interval: 3, .. }
+        ));
+    }
+
+    #[test]
+    fn test_parse_day_step_filter_must_be_reachable() {
+        // 1970-01-01 was a thursday, so every 7th day from it is one too
+        assert!(parse("every 7 days on thursday at 09:00").is_ok());
+        let err = parse("every 7 days on monday at 09:00").unwrap_err();
+        assert!(
+            err.to_string().contains("always lands on a thursday"),
+            "{err}"
+        );
+        assert!(parse("every 14 days from 06:00 to 22:00 on weekend").is_err());
+        // 2026-02-07 is a saturday
+        assert!(parse("every 14 days from 06:00 to 22:00 on weekend starting 2026-02-07").is_ok());
+        assert!(parse("every 7 days on monday at 09:00 starting 2026-02-07").is_err());
+        // A relative anchor lands on whatever weekday the evaluation date is
+        let err = parse("every 7 days on monday at 09:00 starting today").unwrap_err();
+        assert!(err.to_string().contains("day filter may exclude"), "{err}");
+        assert!(parse("every 14 days on weekday at 09:00 starting now").is_err());
+        assert!(parse("every 7 days at 09:00 starting today").is_ok());
+        assert!(parse("every 6 days on monday at 09:00 starting today").is_ok());
+        assert!(parse("every 6 days on monday at 09:00").is_ok());
+    }
+
     #[test]
     fn test_parse_week_repeat() {
         let s = parse("every 2 weeks on monday at 9:00").unwrap();
@@ -1055,6 +1881,126 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_month_repeat_day_filter() {
+        let s = parse("every month on the 1st, 15th at 9:00 on weekdays").unwrap();
+        match &s.expr {
+            ScheduleExpr::MonthRepeat { day_filter, .. } => {
+                assert_eq!(*day_filter, Some(DayFilter::Weekday));
+            }
+            _ => panic!("expected MonthRepeat"),
+        }
+    }
+
+    #[test]
+    fn test_parse_frequency_shorthands() {
+        let cases = [
+            ("daily at 9:00", "every day at 9:00"),
+            ("weekly on monday at 9:00", "every week on monday at 9:00"),
+            (
+                "monthly on the 1st at 9:00",
+                "every month on the 1st at 9:00",
+            ),
+            ("yearly on dec 25 at 00:00", "every year on dec 25 at 00:00"),
+            ("hourly", "every 1 hours from 00:00 to 23:59"),
+            ("Daily at 9:00 in UTC", "every day at 9:00 in UTC"),
+            ("daily", "every day at 00:00"),
+            ("weekly", "every week on monday at 00:00"),
+            ("weekly at 9:00", "every week on monday at 9:00"),
+            ("monthly", "every month on the 1st at 00:00"),
+            ("yearly in UTC", "every year on jan 1 at 00:00 in UTC"),
+            (
+                "yearly in iso weeks 1 on monday",
+                "every year in iso weeks 1 on monday",
+            ),
+            (
+                "hourly on weekdays",
+                "every hour from 00:00 to 23:59 on weekdays",
+            ),
+            ("hourly from 9:00 to 17:00", "every hour from 9:00 to 17:00"),
+            ("hourly at :15 on weekends", "every hour at :15 on weekends"),
+        ];
+        for (short, long) in cases {
+            assert_eq!(parse(short).unwrap(), parse(long).unwrap(), "{short}");
+        }
+    }
+
+    #[test]
+    fn test_parse_frequency_rejects_every() {
+        assert!(parse("every daily at 9:00").is_err());
+    }
+
+    #[test]
+    fn test_parse_omitted_time_defaults_to_midnight() {
+        let s = parse("every weekday in UTC").unwrap();
+        assert_eq!(s.expr.times(), Some(&[TimeOfDay::MIDNIGHT][..]));
+        assert_eq!(s.timezone.as_deref(), Some("UTC"));
+        assert!(parse("every day 9:00").is_err());
+    }
+
+    #[test]
+    fn test_parse_ordinal_day_list_sorted_and_deduplicated() {
+        let s = parse("every month on the 15th, 1st to 5th, 15th, 1st at 09:00").unwrap();
+        match &s.expr {
+            ScheduleExpr::MonthRepeat { target, .. } => assert_eq!(
+                *target,
+                MonthTarget::Days(vec![
+                    DayOfMonthSpec::Single(1),
+                    DayOfMonthSpec::Range(1, 5),
+                    DayOfMonthSpec::Single(15),
+                ])
+            ),
+            _ => panic!("expected MonthRepeat"),
+        }
+    }
+
+    #[test]
+    fn test_parse_during_list_sorted_and_deduplicated() {
+        let s = parse("every day at 09:00 during mar, jan, nov to feb, mar 1 to mar 31").unwrap();
+        assert_eq!(
+            s.during,
+            vec![
+                DuringSpec::Single(MonthName::January),
+                DuringSpec::Single(MonthName::March),
+                DuringSpec::Range(MonthName::November, MonthName::February),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_time_list_sorted_and_deduplicated() {
+        let s = parse("every day at 17:00, 9:00, 09:00, 5pm").unwrap();
+        match &s.expr {
+            ScheduleExpr::DayRepeat { times, .. } => {
+                assert_eq!(
+                    *times,
+                    vec![
+                        TimeOfDay { hour: 9, minute: 0 },
+                        TimeOfDay {
+                            hour: 17,
+                            minute: 0
+                        }
+                    ]
+                );
+            }
+            _ => panic!("expected DayRepeat"),
+        }
+    }
+
+    #[test]
+    fn test_parse_time_of_day_keywords() {
+        let s = parse("every day in the evening").unwrap();
+        match &s.expr {
+            ScheduleExpr::DayRepeat { times, .. } => {
+                assert_eq!(*times, vec![TimeOfDay::EVENING]);
+            }
+            _ => panic!("expected DayRepeat"),
+        }
+        assert!(parse("every day in the").is_err());
+        assert!(parse("every day in the noon").is_err());
+        assert!(parse("every day at 9:00 in the morning").is_err());
+    }
+
     #[test]
     fn test_parse_month_last_day() {
         let s = parse("every month on the last day at 17:00").unwrap();
@@ -1066,6 +2012,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_month_last_n_days() {
+        let s = parse("every month on the last 3 days at 02:00").unwrap();
+        match &s.expr {
+            ScheduleExpr::MonthRepeat { target, .. } => {
+                assert_eq!(*target, MonthTarget::LastNDays(3));
+            }
+            _ => panic!("expected MonthRepeat"),
+        }
+        assert_eq!(
+            parse("every month on the last 1 day at 02:00").unwrap(),
+            parse("every month on the last day at 02:00").unwrap()
+        );
+        assert!(parse("every month on the last 0 days at 02:00").is_err());
+        assert!(parse("every month on the last 32 days at 02:00").is_err());
+        assert!(parse("every month on the last 3 at 02:00").is_err());
+    }
+
     #[test]
     fn test_parse_month_last_weekday() {
         let s = parse("every month on the last weekday at 15:00").unwrap();
@@ -1077,6 +2041,97 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_minutes_past_every_hour() {
+        let expected = parse("every 1 hour from 00:15 to 23:59").unwrap();
+        for input in [
+            "every hour at :15",
+            "every 1 hour at :15",
+            "hourly at :15",
+            "at 15 past every hour",
+            "at :15 past every hour",
+        ] {
+            assert_eq!(parse(input).unwrap(), expected, "{input}");
+        }
+        assert_eq!(
+            parse("at 0 past every hour").unwrap(),
+            parse("hourly").unwrap()
+        );
+        assert_eq!(
+            parse("every 2 hours at :30 on weekdays").unwrap(),
+            parse("every 2 hours from 00:30 to 23:59 on weekday").unwrap()
+        );
+        assert!(parse("every hour at 9:00").is_err());
+        assert!(parse("at 15 past every day").is_err());
+        assert!(parse("every 30 min at :15").is_err());
+    }
+
+    #[test]
+    fn test_parse_week_repeat_day_first() {
+        for (input, interval) in [
+            ("every monday, thursday every 2 weeks at 9:00", 2),
+            ("every monday, thursday every week at 9:00", 1),
+        ] {
+            let s = parse(input).unwrap();
+            match &s.expr {
+                ScheduleExpr::WeekRepeat {
+                    interval: i, days, ..
+                } => {
+                    assert_eq!(*i, interval);
+                    assert_eq!(*days, vec![Weekday::Monday, Weekday::Thursday]);
+                }
+                _ => panic!("expected WeekRepeat"),
+            }
+        }
+        assert_eq!(
+            parse("every monday every 2 weeks at 9:00").unwrap(),
+            parse("every 2 weeks on monday at 9:00").unwrap()
+        );
+        assert!(parse("every monday every 0 weeks at 9:00").is_err());
+        assert!(parse("every monday every 2 days at 9:00").is_err());
+    }
+
+    #[test]
+    fn test_parse_with_spans() {
+        let input = "every day at 9:00 except dec 25 until 3 times starting 2026-01-06 during jun to aug in Europe/Berlin";
+        let (schedule, spans) = parse_with_spans(input).unwrap();
+        assert_eq!(schedule, parse(input).unwrap());
+        let text = |clause| {
+            let span = spans.clause_span(clause).unwrap();
+            &input[span.start..span.end]
+        };
+        assert_eq!(text(Clause::Except), "except dec 25");
+        assert_eq!(text(Clause::Until), "until 3 times");
+        assert_eq!(text(Clause::Starting), "starting 2026-01-06");
+        assert_eq!(text(Clause::During), "during jun to aug");
+        assert_eq!(text(Clause::Timezone), "in Europe/Berlin");
+
+        let (_, spans) = parse_with_spans("every day at 9:00").unwrap();
+        assert_eq!(spans, ClauseSpans::default());
+        let (_, spans) = parse_with_spans("@daily").unwrap();
+        assert!(spans.clause_span(Clause::Timezone).is_none());
+    }
+
+    #[test]
+    fn test_parse_month_business_day() {
+        for (input, n) in [
+            ("every month on the first business day at 9:00", 1),
+            ("every month on the first weekday at 9:00", 1),
+            ("every month on the 3rd business day at 9:00", 3),
+            ("every month on the twenty-third weekday at 9:00", 23),
+        ] {
+            let s = parse(input).unwrap();
+            match &s.expr {
+                ScheduleExpr::MonthRepeat { target, .. } => {
+                    assert_eq!(*target, MonthTarget::BusinessDay(n), "{input}");
+                }
+                _ => panic!("expected MonthRepeat"),
+            }
+        }
+        assert!(parse("every month on the 24th business day at 9:00").is_err());
+        assert!(parse("every month on the first business at 9:00").is_err());
+    }
+
     #[test]
     fn test_parse_ordinal_weekday() {
         let s = parse("every month on the first monday at 10:00").unwrap();
@@ -1118,6 +2173,60 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_ordinal_weekdays() {
+        let s = parse("every month on the last, first and third friday at 9:00").unwrap();
+        match &s.expr {
+            ScheduleExpr::MonthRepeat { target, .. } => {
+                assert_eq!(
+                    *target,
+                    MonthTarget::OrdinalWeekdays {
+                        ordinals: vec![
+                            OrdinalPosition::First,
+                            OrdinalPosition::Third,
+                            OrdinalPosition::Last,
+                        ],
+                        weekday: Weekday::Friday,
+                    }
+                );
+            }
+            _ => panic!("expected MonthRepeat"),
+        }
+        // A repeated ordinal collapses to the single-ordinal target
+        let s = parse("every month on the first and first friday at 9:00").unwrap();
+        match &s.expr {
+            ScheduleExpr::MonthRepeat { target, .. } => {
+                assert_eq!(
+                    *target,
+                    MonthTarget::OrdinalWeekday {
+                        ordinal: OrdinalPosition::First,
+                        weekday: Weekday::Friday,
+                    }
+                );
+            }
+            _ => panic!("expected MonthRepeat"),
+        }
+        assert!(parse("every month on the first and friday at 9:00").is_err());
+    }
+
+    #[test]
+    fn test_parse_ordinal_word_days() {
+        assert_eq!(
+            parse("every month on the first, twenty-second at 9:00").unwrap(),
+            parse("every month on the 1st, 22nd at 9:00").unwrap()
+        );
+        assert_eq!(
+            parse("every month on the third to fifth at 9:00").unwrap(),
+            parse("every month on the 3rd to 5th at 9:00").unwrap()
+        );
+        assert_eq!(
+            parse("every year on the fourteenth of feb at 9:00").unwrap(),
+            parse("every year on the 14th of feb at 9:00").unwrap()
+        );
+        assert!(parse("every year on the thirtieth of feb at 9:00").is_err());
+        assert!(parse("every month on the sixth monday at 9:00").is_err());
+    }
+
     #[test]
     fn test_parse_single_date_named() {
         let s = parse("on feb 14 at 9:00").unwrap();
@@ -1153,6 +2262,65 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_multi_date() {
+        let s = parse("on feb 14, 2026-12-25 at 9:00").unwrap();
+        match &s.expr {
+            ScheduleExpr::MultiDate { dates, times } => {
+                assert_eq!(
+                    *dates,
+                    vec![
+                        DateSpec::Named {
+                            month: MonthName::February,
+                            day: 14
+                        },
+                        DateSpec::Iso("2026-12-25".into()),
+                    ]
+                );
+                assert_eq!(times.len(), 1);
+            }
+            _ => panic!("expected MultiDate"),
+        }
+        assert!(parse("on feb 14, at 9:00").is_err());
+        assert!(parse("on feb 30, dec 25 at 9:00").is_err());
+    }
+
+    #[test]
+    fn test_parse_weekday_interval() {
+        let s = parse("every 3 weekdays at 9:00").unwrap();
+        assert_eq!(
+            s.expr,
+            ScheduleExpr::WeekdayRepeat {
+                interval: 3,
+                times: vec![TimeOfDay::MORNING],
+            }
+        );
+        assert_eq!(s.to_string(), "every 3 weekdays at 09:00");
+        assert_eq!(
+            parse("every 1 weekday at 9:00").unwrap(),
+            parse("every weekday at 9:00").unwrap()
+        );
+        assert!(parse("every 0 weekdays at 9:00").is_err());
+    }
+
+    #[test]
+    fn test_parse_next_weekday_date() {
+        let s = parse("on Fri at 5pm").unwrap();
+        assert_eq!(
+            s.expr,
+            ScheduleExpr::SingleDate {
+                date: DateSpec::NextWeekday(Weekday::Friday),
+                times: vec![TimeOfDay {
+                    hour: 17,
+                    minute: 0
+                }],
+            }
+        );
+        assert_eq!(s.to_string(), "on friday at 17:00");
+        assert!(parse("on friday, dec 25 at 9:00").is_err());
+        assert!(parse("on friday").is_err());
+    }
+
     #[test]
     fn test_parse_with_timezone() {
         let s = parse("every weekday at 9:00 in America/Vancouver").unwrap();
@@ -1186,6 +2354,49 @@ mod tests {
         assert_eq!(s.except[0], Exception::Iso("2026-12-25".into()));
     }
 
+    #[test]
+    fn test_parse_except_ranges() {
+        let s =
+            parse("every weekday at 9:00 except 2026-12-24 to 2026-12-31, dec 24 through jan 2")
+                .unwrap();
+        assert_eq!(
+            s.except,
+            vec![
+                Exception::IsoRange("2026-12-24".into(), "2026-12-31".into()),
+                Exception::NamedRange {
+                    start_month: MonthName::December,
+                    start_day: 24,
+                    end_month: MonthName::January,
+                    end_day: 2,
+                },
+            ]
+        );
+        assert!(parse("every day at 9:00 except 2026-12-31 to 2026-12-24").is_err());
+        assert!(parse("every day at 9:00 except 2026-12-24 to dec 31").is_err());
+        assert!(parse("every day at 9:00 except dec 24 to 2026-12-31").is_err());
+        assert!(parse("every day at 9:00 except dec 24 to feb 30").is_err());
+    }
+
+    #[test]
+    fn test_parse_except_weekdays() {
+        let s = parse("every day at 9:00 except on saturdays, sundays").unwrap();
+        assert_eq!(
+            s.except,
+            vec![
+                Exception::Weekday(Weekday::Saturday),
+                Exception::Weekday(Weekday::Sunday)
+            ]
+        );
+        assert_eq!(
+            parse("every day at 9:00 except weekends").unwrap().except,
+            s.except
+        );
+
+        let s = parse("every day at 9:00 except monday, dec 25").unwrap();
+        assert_eq!(s.except[0], Exception::Weekday(Weekday::Monday));
+        assert!(matches!(s.except[1], Exception::Named { .. }));
+    }
+
     #[test]
     fn test_parse_until_iso() {
         let s = parse("every day at 09:00 until 2026-12-31").unwrap();
@@ -1204,10 +2415,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_until_count() {
+        let s = parse("every day at 09:00 until 10 times").unwrap();
+        assert_eq!(s.until, Some(UntilSpec::Count(10)));
+        let s = parse("every day at 09:00 until 1 occurrence").unwrap();
+        assert_eq!(s.until, Some(UntilSpec::Count(1)));
+        assert!(parse("every day at 09:00 until 0 times").is_err());
+        assert!(parse("every day at 09:00 until 10").is_err());
+    }
+
     #[test]
     fn test_parse_starting() {
         let s = parse("every 2 weeks on monday at 9:00 starting 2026-01-05").unwrap();
-        assert_eq!(s.anchor, Some(jiff::civil::Date::new(2026, 1, 5).unwrap()));
+        assert_eq!(s.anchor, CivilDate::new(2026, 1, 5));
+    }
+
+    #[test]
+    fn test_parse_starting_named_date_and_time() {
+        let s = parse("every 2 weeks on monday at 9:00 starting jan 5").unwrap();
+        assert_eq!(s.anchor, None);
+        assert_eq!(
+            s.anchor_relative,
+            Some(RelativeAnchor::Named {
+                month: MonthName::January,
+                day: 5
+            })
+        );
+        let s = parse("every day at 9:00 starting 2026-01-05 at 12:30").unwrap();
+        assert_eq!(s.anchor, CivilDate::new(2026, 1, 5));
+        assert_eq!(
+            s.anchor_time,
+            Some(TimeOfDay {
+                hour: 12,
+                minute: 30
+            })
+        );
+        assert!(parse("every day at 9:00 starting feb 30").is_err());
+        assert!(parse("every day at 9:00 starting now at 12:00").is_err());
+        assert!(parse("every 2 weeks on monday at 9:00 starting monday").is_err());
+    }
+
+    #[test]
+    fn test_parse_starting_relative() {
+        let s = parse("every 2 days at 9:00 starting today").unwrap();
+        assert_eq!(s.anchor, None);
+        assert_eq!(s.anchor_relative, Some(RelativeAnchor::Today));
+        let s = parse("every 2 days at 9:00 starting now in UTC").unwrap();
+        assert_eq!(s.anchor_relative, Some(RelativeAnchor::Now));
     }
 
     #[test]
@@ -1279,6 +2534,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_iso_week_repeat() {
+        let s = parse("every year in iso weeks 1, 53 on mon, fri at 9:00").unwrap();
+        assert_eq!(
+            s.expr,
+            ScheduleExpr::IsoWeekRepeat {
+                weeks: vec![1, 53],
+                days: vec![Weekday::Monday, Weekday::Friday],
+                times: vec![TimeOfDay { hour: 9, minute: 0 }],
+            }
+        );
+        let err = parse("every year in iso weeks 54 on monday").unwrap_err();
+        assert!(err.to_string().contains("invalid ISO week 54"));
+        assert!(parse("every 2 years in iso weeks 1 on monday").is_err());
+        assert!(parse("every year in iso weeks on monday").is_err());
+    }
+
     #[test]
     fn test_parse_all_clauses() {
         let s = parse(
@@ -1287,10 +2559,86 @@ mod tests {
         .unwrap();
         assert_eq!(s.except.len(), 1);
         assert_eq!(s.until, Some(UntilSpec::Iso("2027-12-31".into())));
-        assert_eq!(s.anchor, Some(jiff::civil::Date::new(2026, 1, 1).unwrap()));
+        assert_eq!(s.anchor, CivilDate::new(2026, 1, 1));
         assert_eq!(s.timezone, Some("UTC".into()));
     }
 
+    #[test]
+    fn test_parse_during_range() {
+        let s = parse("every day at 09:00 during jun to aug").unwrap();
+        assert_eq!(
+            s.during_months(),
+            vec![MonthName::June, MonthName::July, MonthName::August]
+        );
+    }
+
+    #[test]
+    fn test_parse_during_range_wraps() {
+        let s = parse("every day at 09:00 during nov to feb, jun").unwrap();
+        assert_eq!(
+            s.during_months(),
+            vec![
+                MonthName::June,
+                MonthName::November,
+                MonthName::December,
+                MonthName::January,
+                MonthName::February
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_during_dates() {
+        let s = parse("every day at 09:00 during jun 15 to aug, dec 24").unwrap();
+        assert_eq!(
+            s.during,
+            vec![
+                DuringSpec::Dates((MonthName::June, 15), (MonthName::August, 31)),
+                DuringSpec::Dates((MonthName::December, 24), (MonthName::December, 24)),
+            ]
+        );
+        assert_eq!(
+            s.during_months(),
+            vec![
+                MonthName::June,
+                MonthName::July,
+                MonthName::August,
+                MonthName::December
+            ]
+        );
+        let err = parse("every day at 09:00 during jun 31 to aug").unwrap_err();
+        assert!(err.to_string().contains("invalid day 31 for jun"));
+    }
+
+    #[test]
+    fn test_parse_cron_shortcut() {
+        let s = parse("@daily").unwrap();
+        assert_eq!(s.to_string(), "every day at 00:00");
+        assert_eq!(s, parse("every day at 00:00").unwrap());
+        assert_eq!(
+            parse(" @Weekly ").unwrap().to_string(),
+            "every sunday at 00:00"
+        );
+        let err = parse("@fortnightly").unwrap_err();
+        assert_eq!(err.span(), Some(Span::new(0, 12)));
+        assert!(err.to_string().contains("unknown @ shortcut"));
+    }
+
+    #[test]
+    fn test_parse_cron_shortcut_trailing_clauses() {
+        assert_eq!(
+            parse("@daily in UTC").unwrap(),
+            parse("every day at 00:00 in UTC").unwrap()
+        );
+        assert_eq!(
+            parse("@monthly except dec 25").unwrap(),
+            parse("every month on the 1st at 00:00 except dec 25").unwrap()
+        );
+        let err = parse("@fortnightly in UTC").unwrap_err();
+        assert_eq!(err.span(), Some(Span::new(0, 12)));
+        assert!(parse("every day at 9:00 @daily").is_err());
+    }
+
     #[test]
     fn test_error_on_empty() {
         assert!(parse("").is_err());
@@ -1300,4 +2648,67 @@ mod tests {
     fn test_error_on_garbage() {
         assert!(parse("hello world").is_err());
     }
+
+    #[test]
+    fn test_parse_all_skips_blanks_and_comments() {
+        let input = "# nightly jobs\nevery day at 2:00\n\n  # indented comment\nevery 2 hours from 0:00 to 23:59\nnonsense\n";
+        let results = parse_all(input);
+        let lines: Vec<usize> = results.iter().map(|(line, _)| *line).collect();
+        assert_eq!(lines, vec![2, 5, 6]);
+        assert!(results[0].1.is_ok());
+        assert!(results[1].1.is_ok());
+        assert!(results[2].1.is_err());
+        assert!(parse_all("\n# only comments\n").is_empty());
+    }
+
+    #[test]
+    fn test_fractional_hours() {
+        let s = parse("every 1.5 hours from 09:00 to 17:00").unwrap();
+        assert_eq!(s, parse("every 90 min from 09:00 to 17:00").unwrap());
+        assert_eq!(s.to_string(), "every 90 min from 09:00 to 17:00");
+        assert_eq!(
+            parse("every 2.0 hours from 09:00 to 17:00").unwrap(),
+            parse("every 2 hours from 09:00 to 17:00").unwrap()
+        );
+        assert_eq!(fractional_hours_to_minutes("0.25"), Some(15));
+        assert_eq!(fractional_hours_to_minutes("1.01"), None);
+
+        let err = parse("every 1.01 hours from 09:00 to 17:00").unwrap_err();
+        assert!(err.to_string().contains("whole number of minutes"));
+        assert_eq!(err.span(), Some(Span::new(6, 10)));
+        assert!(parse("every 0.0 hours from 09:00 to 17:00").is_err());
+        assert!(parse("every 1.5 min from 09:00 to 17:00").is_err());
+        assert!(parse("every 1.5 days at 09:00").is_err());
+    }
+
+    #[test]
+    fn test_through_ranges() {
+        assert_eq!(
+            parse("every month on the 1st through 15th at 9:00").unwrap(),
+            parse("every month on the 1st to 15th at 9:00").unwrap()
+        );
+        assert_eq!(
+            parse("every day at 9:00 during jun through aug").unwrap(),
+            parse("every day at 9:00 during jun to aug").unwrap()
+        );
+        assert_eq!(
+            parse("every monday through friday at 9:00")
+                .unwrap()
+                .to_string(),
+            "every weekday at 09:00"
+        );
+        assert_eq!(
+            parse("every fri to mon at 9:00").unwrap().to_string(),
+            "every monday, friday, saturday, sunday at 09:00"
+        );
+        assert_eq!(
+            parse("every 2 weeks on mon through wed, fri at 9:00")
+                .unwrap()
+                .to_string(),
+            "every 2 weeks on monday, tuesday, wednesday, friday at 09:00"
+        );
+        // Windows keep the plain "to"
+        assert!(parse("every 30 min from 09:00 through 17:00").is_err());
+        assert!(parse("every monday through at 9:00").is_err());
+    }
 }
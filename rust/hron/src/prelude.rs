@@ -0,0 +1,7 @@
+//! `alloc` types the `no_std` build needs in scope; under `std` these are the
+//! same items the standard prelude provides.
+
+pub(crate) use alloc::format;
+pub(crate) use alloc::string::{String, ToString};
+pub(crate) use alloc::vec;
+pub(crate) use alloc::vec::Vec;
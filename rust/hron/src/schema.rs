@@ -0,0 +1,567 @@
+//! JSON Schema for the structured `Serialize` output of [`Schedule`](crate::Schedule).
+
+use serde_json::{json, Value};
+
+/// Identifier of the schema version. Bumped whenever a change to the
+/// serialized shape could reject previously valid documents.
+const SCHEMA_ID: &str = "urn:hron:schedule:v1";
+
+/// JSON Schema (draft 2020-12) describing the object produced by serializing
+/// a [`Schedule`](crate::Schedule), for validating stored schedules.
+///
+/// The `$id` carries the schema version (`urn:hron:schedule:v1`). Additive
+/// changes keep the version; changes that reject previously valid documents
+/// bump it.
+///
+/// # Examples
+///
+/// ```
+/// let schema = hron::json_schema();
+/// assert_eq!(schema["$id"], "urn:hron:schedule:v1");
+/// assert!(schema["required"].as_array().unwrap().contains(&"kind".into()));
+/// ```
+pub fn json_schema() -> Value {
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "$id": SCHEMA_ID,
+        "title": "hron schedule",
+        "type": "object",
+        "properties": {
+            "kind": { "enum": ["every", "on"] },
+            "repeat": { "enum": ["monthly", "yearly"] },
+            "interval": {
+                "type": "object",
+                "properties": {
+                    "value": { "type": "integer", "minimum": 1 },
+                    "unit": {
+                        "enum": ["minutes", "hours", "days", "weekdays", "weeks", "months", "years"]
+                    }
+                },
+                "required": ["value", "unit"],
+                "additionalProperties": false
+            },
+            "from": { "$ref": "#/$defs/time" },
+            "to": { "$ref": "#/$defs/time" },
+            "days": { "type": "array", "items": { "$ref": "#/$defs/weekday" }, "minItems": 1 },
+            "times": { "type": "array", "items": { "$ref": "#/$defs/time" }, "minItems": 1 },
+            "target": {
+                "anyOf": [
+                    { "$ref": "#/$defs/month_target" },
+                    { "$ref": "#/$defs/year_target" }
+                ]
+            },
+            "date": {
+                "description": "ISO date (`2026-03-15`), month and day (`feb 14`), or weekday (`friday`)",
+                "type": "string"
+            },
+            "dates": {
+                "type": "array",
+                "items": { "type": "string" },
+                "minItems": 2
+            },
+            "iso_weeks": {
+                "type": "array",
+                "items": { "type": "integer", "minimum": 1, "maximum": 53 },
+                "minItems": 1
+            },
+            "except": { "type": "array", "items": { "$ref": "#/$defs/exception" } },
+            "until": {
+                "anyOf": [{ "type": "null" }, { "$ref": "#/$defs/until" }]
+            },
+            "starting": {
+                "description": "ISO date, month-day (`jan 6`), `today`, or `now`",
+                "type": ["string", "null"]
+            },
+            "starting_time": {
+                "description": "Time of day (HH:MM) on the starting date before which nothing occurs",
+                "type": ["string", "null"]
+            },
+            "during": { "type": "array", "items": { "$ref": "#/$defs/month" } },
+            "timezone": { "type": ["string", "null"] }
+        },
+        "required": ["kind", "except", "until", "starting", "starting_time", "during", "timezone"],
+        "additionalProperties": false,
+        "anyOf": [
+            { "required": ["interval", "from", "to"] },
+            {
+                "properties": { "kind": { "const": "every" } },
+                "required": ["days", "times"],
+                "not": { "required": ["repeat"] }
+            },
+            {
+                "properties": { "repeat": { "const": "monthly" } },
+                "required": ["repeat", "target", "times"]
+            },
+            {
+                "properties": { "repeat": { "const": "yearly" } },
+                "required": ["repeat", "target", "times"]
+            },
+            {
+                "properties": { "repeat": { "const": "yearly" } },
+                "required": ["repeat", "iso_weeks", "days", "times"]
+            },
+            {
+                "properties": { "kind": { "const": "on" } },
+                "required": ["date", "times"]
+            },
+            {
+                "properties": { "kind": { "const": "on" } },
+                "required": ["dates", "times"]
+            }
+        ],
+        "$defs": {
+            "time": { "type": "string", "pattern": "^([01][0-9]|2[0-3]):[0-5][0-9]$" },
+            "weekday": {
+                "enum": [
+                    "monday", "tuesday", "wednesday", "thursday", "friday", "saturday", "sunday"
+                ]
+            },
+            "month": {
+                "enum": [
+                    "january", "february", "march", "april", "may", "june",
+                    "july", "august", "september", "october", "november", "december"
+                ]
+            },
+            "day": { "type": "integer", "minimum": 1, "maximum": 31 },
+            "ordinal": { "enum": ["first", "second", "third", "fourth", "fifth", "last"] },
+            "month_day": {
+                "type": "object",
+                "properties": {
+                    "month": { "$ref": "#/$defs/month" },
+                    "day": { "$ref": "#/$defs/day" }
+                },
+                "required": ["month", "day"],
+                "additionalProperties": false
+            },
+            "month_target": {
+                "oneOf": [
+                    { "enum": ["last_day", "last_weekday"] },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "days": {
+                                "type": "array",
+                                "items": {
+                                    "oneOf": [
+                                        {
+                                            "type": "object",
+                                            "properties": { "single": { "$ref": "#/$defs/day" } },
+                                            "required": ["single"],
+                                            "additionalProperties": false
+                                        },
+                                        {
+                                            "type": "object",
+                                            "properties": {
+                                                "range": {
+                                                    "type": "array",
+                                                    "items": { "$ref": "#/$defs/day" },
+                                                    "minItems": 2,
+                                                    "maxItems": 2
+                                                }
+                                            },
+                                            "required": ["range"],
+                                            "additionalProperties": false
+                                        }
+                                    ]
+                                },
+                                "minItems": 1
+                            }
+                        },
+                        "required": ["days"],
+                        "additionalProperties": false
+                    },
+                    {
+                        "type": "object",
+                        "properties": { "last_n_days": { "$ref": "#/$defs/day" } },
+                        "required": ["last_n_days"],
+                        "additionalProperties": false
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "nearest_weekday": {
+                                "type": "object",
+                                "properties": {
+                                    "day": { "$ref": "#/$defs/day" },
+                                    "direction": { "enum": [null, "next", "previous"] }
+                                },
+                                "required": ["day", "direction"],
+                                "additionalProperties": false
+                            }
+                        },
+                        "required": ["nearest_weekday"],
+                        "additionalProperties": false
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "ordinal_weekday": {
+                                "type": "object",
+                                "properties": {
+                                    "ordinal": { "$ref": "#/$defs/ordinal" },
+                                    "weekday": { "$ref": "#/$defs/weekday" }
+                                },
+                                "required": ["ordinal", "weekday"],
+                                "additionalProperties": false
+                            }
+                        },
+                        "required": ["ordinal_weekday"],
+                        "additionalProperties": false
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "ordinal_weekdays": {
+                                "type": "object",
+                                "properties": {
+                                    "ordinals": {
+                                        "type": "array",
+                                        "items": { "$ref": "#/$defs/ordinal" },
+                                        "minItems": 2
+                                    },
+                                    "weekday": { "$ref": "#/$defs/weekday" }
+                                },
+                                "required": ["ordinals", "weekday"],
+                                "additionalProperties": false
+                            }
+                        },
+                        "required": ["ordinal_weekdays"],
+                        "additionalProperties": false
+                    },
+                    {
+                        "type": "object",
+                        "properties": { "business_day": { "$ref": "#/$defs/day" } },
+                        "required": ["business_day"],
+                        "additionalProperties": false
+                    }
+                ]
+            },
+            "year_target": {
+                "oneOf": [
+                    {
+                        "type": "object",
+                        "properties": { "date": { "$ref": "#/$defs/month_day" } },
+                        "required": ["date"],
+                        "additionalProperties": false
+                    },
+                    {
+                        "type": "object",
+                        "properties": { "day_of_month": { "$ref": "#/$defs/month_day" } },
+                        "required": ["day_of_month"],
+                        "additionalProperties": false
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "ordinal_weekday": {
+                                "type": "object",
+                                "properties": {
+                                    "ordinal": { "$ref": "#/$defs/ordinal" },
+                                    "weekday": { "$ref": "#/$defs/weekday" },
+                                    "month": { "$ref": "#/$defs/month" }
+                                },
+                                "required": ["ordinal", "weekday", "month"],
+                                "additionalProperties": false
+                            }
+                        },
+                        "required": ["ordinal_weekday"],
+                        "additionalProperties": false
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "last_weekday": {
+                                "type": "object",
+                                "properties": { "month": { "$ref": "#/$defs/month" } },
+                                "required": ["month"],
+                                "additionalProperties": false
+                            }
+                        },
+                        "required": ["last_weekday"],
+                        "additionalProperties": false
+                    }
+                ]
+            },
+            "exception": {
+                "oneOf": [
+                    {
+                        "type": "object",
+                        "properties": { "named": { "$ref": "#/$defs/month_day" } },
+                        "required": ["named"],
+                        "additionalProperties": false
+                    },
+                    {
+                        "type": "object",
+                        "properties": { "iso": { "type": "string" } },
+                        "required": ["iso"],
+                        "additionalProperties": false
+                    },
+                    {
+                        "type": "object",
+                        "properties": { "weekday": { "$ref": "#/$defs/weekday" } },
+                        "required": ["weekday"],
+                        "additionalProperties": false
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "iso_range": {
+                                "type": "array",
+                                "items": { "type": "string" },
+                                "minItems": 2,
+                                "maxItems": 2
+                            }
+                        },
+                        "required": ["iso_range"],
+                        "additionalProperties": false
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "named_range": {
+                                "type": "object",
+                                "properties": {
+                                    "start_month": { "$ref": "#/$defs/month" },
+                                    "start_day": { "$ref": "#/$defs/day" },
+                                    "end_month": { "$ref": "#/$defs/month" },
+                                    "end_day": { "$ref": "#/$defs/day" }
+                                },
+                                "required": ["start_month", "start_day", "end_month", "end_day"],
+                                "additionalProperties": false
+                            }
+                        },
+                        "required": ["named_range"],
+                        "additionalProperties": false
+                    }
+                ]
+            },
+            "until": {
+                "oneOf": [
+                    {
+                        "type": "object",
+                        "properties": { "iso": { "type": "string" } },
+                        "required": ["iso"],
+                        "additionalProperties": false
+                    },
+                    {
+                        "type": "object",
+                        "properties": { "named": { "$ref": "#/$defs/month_day" } },
+                        "required": ["named"],
+                        "additionalProperties": false
+                    },
+                    {
+                        "type": "object",
+                        "properties": { "count": { "type": "integer", "minimum": 1 } },
+                        "required": ["count"],
+                        "additionalProperties": false
+                    }
+                ]
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Schedule;
+
+    /// Minimal validator for the keywords the schema uses. `pattern` and
+    /// `description` are not checked.
+    fn validate(schema: &Value, root: &Value, value: &Value) -> bool {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            let name = reference.trim_start_matches("#/$defs/");
+            return validate(&root["$defs"][name], root, value);
+        }
+        if let Some(types) = schema.get("type") {
+            let allowed: Vec<&str> = match types {
+                Value::String(t) => vec![t.as_str()],
+                Value::Array(ts) => ts.iter().filter_map(Value::as_str).collect(),
+                _ => vec![],
+            };
+            let actual = match value {
+                Value::Null => "null",
+                Value::Bool(_) => "boolean",
+                Value::Number(n) if n.is_u64() || n.is_i64() => "integer",
+                Value::Number(_) => "number",
+                Value::String(_) => "string",
+                Value::Array(_) => "array",
+                Value::Object(_) => "object",
+            };
+            if !allowed.contains(&actual) {
+                return false;
+            }
+        }
+        if let Some(options) = schema.get("enum").and_then(Value::as_array) {
+            if !options.contains(value) {
+                return false;
+            }
+        }
+        if schema.get("const").is_some_and(|c| c != value) {
+            return false;
+        }
+        if let Some(n) = value.as_f64() {
+            if schema["minimum"].as_f64().is_some_and(|min| n < min)
+                || schema["maximum"].as_f64().is_some_and(|max| n > max)
+            {
+                return false;
+            }
+        }
+        if let Some(items) = value.as_array() {
+            if schema["minItems"]
+                .as_u64()
+                .is_some_and(|min| (items.len() as u64) < min)
+                || schema["maxItems"]
+                    .as_u64()
+                    .is_some_and(|max| (items.len() as u64) > max)
+            {
+                return false;
+            }
+            if let Some(item_schema) = schema.get("items") {
+                if !items.iter().all(|item| validate(item_schema, root, item)) {
+                    return false;
+                }
+            }
+        }
+        if let Some(object) = value.as_object() {
+            let properties = schema.get("properties").and_then(Value::as_object);
+            if let Some(required) = schema.get("required").and_then(Value::as_array) {
+                if !required
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .all(|key| object.contains_key(key))
+                {
+                    return false;
+                }
+            }
+            for (key, field) in object {
+                let valid = match properties.and_then(|p| p.get(key)) {
+                    Some(field_schema) => validate(field_schema, root, field),
+                    None => schema["additionalProperties"] != false,
+                };
+                if !valid {
+                    return false;
+                }
+            }
+        }
+        if let Some(negated) = schema.get("not") {
+            if validate(negated, root, value) {
+                return false;
+            }
+        }
+        if let Some(options) = schema.get("anyOf").and_then(Value::as_array) {
+            if !options.iter().any(|s| validate(s, root, value)) {
+                return false;
+            }
+        }
+        if let Some(options) = schema.get("oneOf").and_then(Value::as_array) {
+            if options.iter().filter(|s| validate(s, root, value)).count() != 1 {
+                return false;
+            }
+        }
+        true
+    }
+
+    #[test]
+    fn test_serialized_schedules_match_schema() {
+        let schema = json_schema();
+        let inputs = [
+            "every weekday at 9:00 except dec 25, 2026-07-04, saturday until 2027-12-31 starting 2026-01-05 during jan, jun in UTC",
+            "every day at 9:00 except 2026-12-24 to 2026-12-31, dec 24 to jan 2",
+            "every 30 min from 9:00 to 17:00 on weekdays",
+            "every 3 days from 06:00 to 22:00",
+            "every 2 days at 9:00, 17:00",
+            "every 3 weekdays at 9:00",
+            "every 2 weeks on mon, fri at 9:00",
+            "every month on the 1st to 5th, 15th at 9:00 on weekdays",
+            "every month on the last day at 9:00",
+            "every month on the last 3 days at 02:00",
+            "every month on the last weekday at 9:00",
+            "every month on the 3rd business day at 9:00",
+            "every month on the nearest weekday to 15th at 9:00",
+            "every month on the next nearest weekday to 15th at 9:00",
+            "every month on the second tuesday at 9:00",
+            "on 2026-03-15 at 9:00",
+            "on feb 14 at 9:00",
+            "on feb 14, 2026-12-25 at 9:00",
+            "on friday at 17:00",
+            "every year on dec 25 at 9:00",
+            "every 2 years on the first monday of march at 9:00",
+            "every year on the 15th of march at 9:00",
+            "every year on the last weekday of dec at 9:00",
+            "every year in iso weeks 1, 53 on monday",
+            "every day at 9:00 until 5 times starting today",
+            "every day at 9:00 until dec 31 starting 2026-01-06",
+        ];
+        for input in inputs {
+            let value = serde_json::to_value(Schedule::parse(input).unwrap()).unwrap();
+            assert!(validate(&schema, &schema, &value), "{input}: {value}");
+        }
+    }
+
+    #[test]
+    fn test_interval_serialized_when_one() {
+        for (input, unit) in [
+            ("every day at 9:00", "days"),
+            ("every week on monday at 9:00", "weeks"),
+            ("every month on the 1st at 9:00", "months"),
+            ("every year on dec 25 at 9:00", "years"),
+        ] {
+            let value = serde_json::to_value(Schedule::parse(input).unwrap()).unwrap();
+            assert_eq!(
+                value["interval"],
+                json!({ "value": 1, "unit": unit }),
+                "{input}"
+            );
+        }
+        let value = serde_json::to_value(Schedule::parse("on feb 14 at 9:00").unwrap()).unwrap();
+        assert!(value.get("interval").is_none());
+    }
+
+    #[test]
+    fn test_string_fields_serialized() {
+        let value = |input| serde_json::to_value(Schedule::parse(input).unwrap()).unwrap();
+        assert_eq!(
+            value("on feb 14, 2026-12-25 at 9:00")["dates"],
+            json!(["feb 14", "2026-12-25"])
+        );
+        assert_eq!(value("on friday at 9:00")["date"], json!("friday"));
+        assert_eq!(
+            value("every 3 days at 9:00 starting 2026-01-05")["starting"],
+            json!("2026-01-05")
+        );
+        assert_eq!(value("every day at 9:00")["starting"], json!(null));
+        assert_eq!(
+            value("every 3 days at 9:00 starting jan 6 at 12:00")["starting"],
+            json!("jan 6")
+        );
+        assert_eq!(
+            value("every 3 days at 9:00 starting jan 6 at 12:00")["starting_time"],
+            json!("12:00")
+        );
+        assert_eq!(
+            value("every weekend at 9:00")["days"],
+            json!(["saturday", "sunday"])
+        );
+    }
+
+    #[test]
+    fn test_schema_rejects_drifted_documents() {
+        let schema = json_schema();
+        let mut value =
+            serde_json::to_value(Schedule::parse("every day at 9:00").unwrap()).unwrap();
+        assert!(validate(&schema, &schema, &value));
+        value["frequency"] = json!("daily");
+        assert!(!validate(&schema, &schema, &value));
+
+        let mut value =
+            serde_json::to_value(Schedule::parse("every day at 9:00").unwrap()).unwrap();
+        value["days"] = json!(["funday"]);
+        assert!(!validate(&schema, &schema, &value));
+
+        let mut value =
+            serde_json::to_value(Schedule::parse("every day at 9:00").unwrap()).unwrap();
+        value.as_object_mut().unwrap().remove("timezone");
+        assert!(!validate(&schema, &schema, &value));
+    }
+}
@@ -0,0 +1,77 @@
+use futures_core::Stream;
+use jiff::{Timestamp, Zoned};
+
+use crate::ast::Schedule;
+use crate::error::ScheduleError;
+use crate::eval::Occurrences;
+
+/// Drive [`Occurrences`] with tokio timers, holding each item until its time.
+/// Errors are yielded as soon as they occur.
+pub(crate) fn occurrence_stream(
+    schedule: &Schedule,
+    from: Zoned,
+) -> impl Stream<Item = Result<Zoned, ScheduleError>> + '_ {
+    futures_util::stream::unfold(
+        Occurrences::new(schedule, from),
+        |mut occurrences| async move {
+            let item = occurrences.next()?;
+            if let Ok(dt) = &item {
+                sleep_until(dt).await;
+            }
+            Some((item, occurrences))
+        },
+    )
+}
+
+/// Sleep for the wall-clock delta to `dt`; past times return immediately.
+async fn sleep_until(dt: &Zoned) {
+    let delta = dt.timestamp().duration_since(Timestamp::now());
+    if let Ok(wait) = std::time::Duration::try_from(delta) {
+        tokio::time::sleep(wait).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::StreamExt;
+
+    #[tokio::test]
+    async fn test_past_occurrences_match_iterator() {
+        let schedule = Schedule::parse("every 2 hours from 09:00 to 17:00 in UTC").unwrap();
+        let from: Zoned = "2026-02-06T12:00:00+00:00[UTC]".parse().unwrap();
+        let streamed: Vec<Zoned> = schedule
+            .stream(&from)
+            .take(6)
+            .map(Result::unwrap)
+            .collect()
+            .await;
+        let iterated: Vec<Zoned> = schedule
+            .occurrences(&from)
+            .take(6)
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(streamed, iterated);
+    }
+
+    #[tokio::test]
+    async fn test_stream_ends_with_schedule() {
+        let schedule = Schedule::parse("on 2026-02-10 at 09:00 in UTC").unwrap();
+        let from: Zoned = "2026-02-06T12:00:00+00:00[UTC]".parse().unwrap();
+        let all: Vec<_> = schedule.stream(&from).collect().await;
+        assert_eq!(all.len(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_waits_for_future_occurrence() {
+        let schedule = Schedule::parse("every 1 min from 00:00 to 23:59 in UTC").unwrap();
+        let from = Zoned::now().with_time_zone(jiff::tz::TimeZone::UTC);
+        let started = tokio::time::Instant::now();
+        let mut stream = std::pin::pin!(schedule.stream(&from));
+        let next = stream.next().await.unwrap().unwrap();
+        assert!(next > from);
+        // The paused clock auto-advances through the sleep
+        assert!(started.elapsed() > std::time::Duration::ZERO);
+        assert!(started.elapsed() <= std::time::Duration::from_secs(60));
+    }
+}
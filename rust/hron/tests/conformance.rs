@@ -154,11 +154,21 @@ fn run_eval_matches(index: usize) {
     let case = &SPEC["eval"]["matches"]["tests"][index];
     let expr_str = case["expression"].as_str().unwrap();
     let dt_str = case["datetime"].as_str().unwrap();
-    let expected = case["expected"].as_bool().unwrap();
 
     let schedule =
         Schedule::parse(expr_str).unwrap_or_else(|e| panic!("parse failed for '{expr_str}': {e}"));
     let dt = parse_zoned(dt_str);
+    if let Some(needle) = case["error_contains"].as_str() {
+        match schedule.matches(&dt) {
+            Ok(got) => panic!("expected matches error for '{expr_str}', got {got}"),
+            Err(e) => assert!(
+                e.to_string().contains(needle),
+                "matches error for '{expr_str}' should contain '{needle}', got: {e}"
+            ),
+        }
+        return;
+    }
+    let expected = case["expected"].as_bool().unwrap();
     let got = schedule
         .matches(&dt)
         .unwrap_or_else(|e| panic!("matches error for '{expr_str}': {e}"));
@@ -290,7 +300,6 @@ fn run_eval_error(index: usize) {
     match Schedule::parse(expr_str) {
         Err(_) => {
             // Caught at parse time — acceptable
-            return;
         }
         Ok(schedule) => {
             let now = default_now();
@@ -308,8 +317,8 @@ fn run_eval_error(index: usize) {
 // Cron
 // ---------------------------------------------------------------------------
 
-fn run_cron_to_cron(index: usize) {
-    let case = &SPEC["cron"]["to_cron"]["tests"][index];
+fn run_cron_to_cron(section: &str, index: usize) {
+    let case = &SPEC["cron"][section]["tests"][index];
     let hron_expr = case["hron"].as_str().unwrap();
     let expected_cron = case["cron"].as_str().unwrap();
 
@@ -7,8 +7,9 @@
 //! - Integration with std::iter combinators
 //! - Memory efficiency patterns
 
-use hron::Schedule;
-use jiff::{tz::TimeZone, Zoned};
+use hron::ast::WeekStart;
+use hron::{Schedule, ScheduledItem};
+use jiff::{civil::date, tz::TimeZone, Zoned};
 
 fn parse_zoned(s: &str) -> Zoned {
     s.parse().expect("valid zoned datetime")
@@ -301,6 +302,21 @@ fn between_empty_range() {
     assert!(results.is_empty());
 }
 
+#[test]
+fn between_inverted_range_is_empty_but_try_between_errors() {
+    let schedule = Schedule::parse("every day at 09:00 in UTC").unwrap();
+    let from = parse_zoned("2026-02-05T00:00:00+00:00[UTC]");
+    let to = parse_zoned("2026-02-01T00:00:00+00:00[UTC]");
+
+    assert_eq!(schedule.between(&from, &to).count(), 0);
+    let err = schedule.try_between(&from, &to).err().unwrap();
+    assert!(err.to_string().contains("inverted range"));
+
+    // An empty range is not inverted
+    assert_eq!(schedule.try_between(&from, &from).unwrap().count(), 0);
+    assert_eq!(schedule.try_between(&to, &from).unwrap().count(), 4);
+}
+
 #[test]
 fn occurrences_single_date_terminates() {
     let schedule = Schedule::parse("on 2026-02-14 at 14:00 in UTC").unwrap();
@@ -377,6 +393,427 @@ fn occurrences_multiple_times_per_day() {
     assert_eq!(results[2].time().hour(), 17);
 }
 
+#[test]
+fn occurrences_times_straddling_midnight_interleave_by_instant() {
+    // Each time belongs to its own calendar day; there is no "next day's
+    // batch" grouping, so 00:30 fires before 23:30 on the same date.
+    let schedule = Schedule::parse("every day at 23:30, 00:30 in UTC").unwrap();
+    let from = parse_zoned("2026-02-01T12:00:00+00:00[UTC]");
+
+    let results: Vec<_> = schedule
+        .occurrences(&from)
+        .take(4)
+        .map(|r| r.unwrap().to_string())
+        .collect();
+    assert_eq!(
+        results,
+        [
+            "2026-02-01T23:30:00+00:00[UTC]",
+            "2026-02-02T00:30:00+00:00[UTC]",
+            "2026-02-02T23:30:00+00:00[UTC]",
+            "2026-02-03T00:30:00+00:00[UTC]",
+        ]
+    );
+
+    // Between the two times, the next fire is the same day's 23:30 and the
+    // previous one is that morning's 00:30.
+    let mid = parse_zoned("2026-02-02T01:00:00+00:00[UTC]");
+    let next = schedule.next_from(&mid).unwrap().unwrap();
+    assert_eq!(next.to_string(), "2026-02-02T23:30:00+00:00[UTC]");
+    let prev = schedule.previous_from(&mid).unwrap().unwrap();
+    assert_eq!(prev.to_string(), "2026-02-02T00:30:00+00:00[UTC]");
+
+    // Exactly at 00:30 the next fire is that evening, not the next 00:30.
+    let at = parse_zoned("2026-02-02T00:30:00+00:00[UTC]");
+    let next = schedule.next_from(&at).unwrap().unwrap();
+    assert_eq!(next.to_string(), "2026-02-02T23:30:00+00:00[UTC]");
+}
+
+// =============================================================================
+// Occurrence Dates
+// =============================================================================
+
+#[test]
+fn occurrence_dates_yields_each_date_once() {
+    let schedule = Schedule::parse("every weekday at 09:00, 12:00, 17:00 in UTC").unwrap();
+
+    let dates: Vec<_> = schedule
+        .occurrence_dates(date(2026, 2, 6))
+        .take(3)
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert_eq!(
+        dates,
+        vec![date(2026, 2, 6), date(2026, 2, 9), date(2026, 2, 10)]
+    );
+}
+
+#[test]
+fn occurrence_dates_agrees_with_dates_in_month() {
+    let schedule =
+        Schedule::parse("every month on the first and last friday at 09:00, 17:00 in Asia/Tokyo")
+            .unwrap();
+
+    let dates: Vec<_> = schedule
+        .occurrence_dates(date(2026, 3, 1))
+        .take_while(|d| d.as_ref().map_or(true, |d| d.month() == 3))
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert_eq!(dates, schedule.dates_in_month(2026, 3).unwrap());
+}
+
+#[test]
+fn occurrence_dates_counts_every_time_under_until_count() {
+    // Three occurrences cover both of Feb 6's times and Feb 7 at 09:00
+    let schedule =
+        Schedule::parse("every day at 09:00, 17:00 until 3 times starting 2026-02-06 in UTC")
+            .unwrap();
+
+    let dates: Vec<_> = schedule
+        .occurrence_dates(date(2026, 2, 6))
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert_eq!(dates, vec![date(2026, 2, 6), date(2026, 2, 7)]);
+}
+
+#[test]
+fn occurrence_dates_single_date_terminates() {
+    let schedule = Schedule::parse("on 2026-03-15 at 09:00, 17:00 in UTC").unwrap();
+
+    let dates: Vec<_> = schedule
+        .occurrence_dates(date(2026, 2, 6))
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert_eq!(dates, vec![date(2026, 3, 15)]);
+}
+
+// =============================================================================
+// Tick Windows (due_in_window)
+// =============================================================================
+
+#[test]
+fn due_in_window_includes_start_excludes_end() {
+    let schedule = Schedule::parse("every 15 min from 09:00 to 10:00 in UTC").unwrap();
+    let start = parse_zoned("2026-02-01T09:00:00+00:00[UTC]");
+    let end = parse_zoned("2026-02-01T09:30:00+00:00[UTC]");
+
+    let due = schedule.due_in_window(&start, &end).unwrap();
+
+    assert_eq!(due.len(), 2);
+    assert_eq!(due[0], start);
+    assert_eq!(due[1].time().minute(), 15);
+}
+
+#[test]
+fn due_in_window_consecutive_ticks_fire_each_occurrence_once() {
+    let schedule = Schedule::parse("every 15 min from 09:00 to 17:00 in UTC").unwrap();
+    let mut window_start = parse_zoned("2026-02-01T08:50:00+00:00[UTC]");
+    let tick = jiff::Span::new().minutes(5);
+
+    let mut fired = Vec::new();
+    for _ in 0..120 {
+        let window_end = window_start.checked_add(tick).unwrap();
+        fired.extend(schedule.due_in_window(&window_start, &window_end).unwrap());
+        window_start = window_end;
+    }
+
+    // 08:50 + 120 * 5 min = 18:50, covering the whole 09:00-17:00 window
+    let expected: Vec<Zoned> = schedule
+        .between(
+            &parse_zoned("2026-02-01T08:59:00+00:00[UTC]"),
+            &parse_zoned("2026-02-01T17:00:00+00:00[UTC]"),
+        )
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(fired.len(), 33);
+    assert_eq!(fired, expected);
+}
+
+#[test]
+fn due_in_window_ticks_aligned_to_occurrences() {
+    // Tick boundaries land exactly on occurrences — the classic double-fire case
+    let schedule = Schedule::parse("every day at 09:00, 12:00 in UTC").unwrap();
+    let boundaries = [
+        "2026-02-01T06:00:00+00:00[UTC]",
+        "2026-02-01T09:00:00+00:00[UTC]",
+        "2026-02-01T12:00:00+00:00[UTC]",
+        "2026-02-01T15:00:00+00:00[UTC]",
+    ];
+
+    let counts: Vec<usize> = boundaries
+        .windows(2)
+        .map(|w| {
+            schedule
+                .due_in_window(&parse_zoned(w[0]), &parse_zoned(w[1]))
+                .unwrap()
+                .len()
+        })
+        .collect();
+
+    assert_eq!(counts, vec![0, 1, 1]);
+}
+
+#[test]
+fn due_in_window_empty_window() {
+    let schedule = Schedule::parse("every day at 09:00 in UTC").unwrap();
+    let at = parse_zoned("2026-02-01T09:00:00+00:00[UTC]");
+
+    assert!(schedule.due_in_window(&at, &at).unwrap().is_empty());
+}
+
+#[test]
+fn polling_stops_after_until_count() {
+    let schedule =
+        Schedule::parse("every day at 09:00 until 3 times starting 2026-02-01 in UTC").unwrap();
+
+    // Tick a day at a time well past the third firing
+    let mut window_start = parse_zoned("2026-01-31T00:00:00+00:00[UTC]");
+    let mut fired = Vec::new();
+    for _ in 0..10 {
+        let window_end = window_start.tomorrow().unwrap();
+        fired.extend(schedule.due_in_window(&window_start, &window_end).unwrap());
+        window_start = window_end;
+    }
+    assert_eq!(
+        fired,
+        [1, 2, 3].map(|d| parse_zoned(&format!("2026-02-0{d}T09:00:00+00:00[UTC]")))
+    );
+
+    // A queue entry advanced past its last firing drops out
+    let mut item = ScheduledItem::new(schedule, &parse_zoned("2026-01-31T00:00:00+00:00[UTC]"))
+        .unwrap()
+        .unwrap();
+    for _ in 0..2 {
+        item = item.advance().unwrap().unwrap();
+    }
+    assert_eq!(item.next, fired[2]);
+    assert!(item.advance().unwrap().is_none());
+}
+
+// =============================================================================
+// Fifth-Occurrence Months
+// =============================================================================
+
+/// Months in 2024–2028 that have a fifth Friday (only these fire).
+const FIFTH_FRIDAYS: [&str; 21] = [
+    "2024-03-29",
+    "2024-05-31",
+    "2024-08-30",
+    "2024-11-29",
+    "2025-01-31",
+    "2025-05-30",
+    "2025-08-29",
+    "2025-10-31",
+    "2026-01-30",
+    "2026-05-29",
+    "2026-07-31",
+    "2026-10-30",
+    "2027-01-29",
+    "2027-04-30",
+    "2027-07-30",
+    "2027-10-29",
+    "2027-12-31",
+    "2028-03-31",
+    "2028-06-30",
+    "2028-09-29",
+    "2028-12-29",
+];
+
+fn fifth_friday_window() -> (Zoned, Zoned) {
+    (
+        parse_zoned("2024-01-01T00:00:00+00:00[UTC]"),
+        parse_zoned("2029-01-01T00:00:00+00:00[UTC]"),
+    )
+}
+
+#[test]
+fn fifth_weekday_skips_short_months() {
+    let schedule = Schedule::parse("every month on the fifth friday at 09:00 in UTC").unwrap();
+    let (from, to) = fifth_friday_window();
+
+    let dates: Vec<String> = schedule
+        .between(&from, &to)
+        .map(|r| r.unwrap().date().to_string())
+        .collect();
+
+    assert_eq!(dates, FIFTH_FRIDAYS);
+}
+
+#[test]
+fn fifth_weekday_previous_and_matches_agree() {
+    let schedule = Schedule::parse("every month on the fifth friday at 09:00 in UTC").unwrap();
+    let (_, to) = fifth_friday_window();
+
+    let mut cursor = to;
+    let mut dates = Vec::new();
+    while let Some(prev) = schedule.previous_from(&cursor).unwrap() {
+        if prev.year() < 2024 {
+            break;
+        }
+        assert!(schedule.matches(&prev).unwrap());
+        dates.push(prev.date().to_string());
+        cursor = prev;
+    }
+    dates.reverse();
+
+    assert_eq!(dates, FIFTH_FRIDAYS);
+    // A fourth-and-last Friday in a short month never matches.
+    let feb = parse_zoned("2026-02-27T09:00:00+00:00[UTC]");
+    assert!(!schedule.matches(&feb).unwrap());
+}
+
+#[test]
+fn fifth_weekday_is_subset_of_last_weekday() {
+    // A fifth Friday is always the last one, so `last friday` is the
+    // "fall back to last in short months" variant of `fifth friday`.
+    let last = Schedule::parse("every month on the last friday at 09:00 in UTC").unwrap();
+    let (from, to) = fifth_friday_window();
+
+    let last_dates: Vec<String> = last
+        .between(&from, &to)
+        .map(|r| r.unwrap().date().to_string())
+        .collect();
+
+    assert_eq!(last_dates.len(), 60);
+    assert!(FIFTH_FRIDAYS
+        .iter()
+        .all(|d| last_dates.iter().any(|l| l == d)));
+}
+
+// =============================================================================
+// Between Bounds
+// =============================================================================
+
+fn bounded_days(start_inclusive: bool, end_inclusive: bool) -> Vec<i8> {
+    let schedule = Schedule::parse("every day at 09:00 in UTC").unwrap();
+    let from = parse_zoned("2026-02-01T09:00:00+00:00[UTC]");
+    let to = parse_zoned("2026-02-03T09:00:00+00:00[UTC]");
+    schedule
+        .between_with_bounds(&from, &to, start_inclusive, end_inclusive)
+        .map(|r| r.unwrap().date().day())
+        .collect()
+}
+
+#[test]
+fn between_bounds_exclusive_start_inclusive_end() {
+    assert_eq!(bounded_days(false, true), vec![2, 3]);
+}
+
+#[test]
+fn between_bounds_inclusive_start_exclusive_end() {
+    assert_eq!(bounded_days(true, false), vec![1, 2]);
+}
+
+#[test]
+fn between_bounds_inclusive_both() {
+    assert_eq!(bounded_days(true, true), vec![1, 2, 3]);
+}
+
+#[test]
+fn between_bounds_exclusive_both() {
+    assert_eq!(bounded_days(false, false), vec![2]);
+}
+
+#[test]
+fn between_bounds_default_matches_between() {
+    let schedule = Schedule::parse("every day at 09:00 in UTC").unwrap();
+    let from = parse_zoned("2026-02-01T09:00:00+00:00[UTC]");
+    let to = parse_zoned("2026-02-03T09:00:00+00:00[UTC]");
+    let between: Vec<_> = schedule.between(&from, &to).map(|r| r.unwrap()).collect();
+    let bounded: Vec<_> = schedule
+        .between_with_bounds(&from, &to, false, true)
+        .map(|r| r.unwrap())
+        .collect();
+    assert_eq!(between, bounded);
+}
+
+// =============================================================================
+// Stepped Occurrences
+// =============================================================================
+
+#[test]
+fn occurrences_stepped_agrees_with_step_by() {
+    let expressions = [
+        "every 1 min from 09:00 to 17:00 in UTC",
+        "every 7 min from 09:00 to 10:00 on weekdays in UTC",
+        "every 15 min from 22:00 to 02:00 in UTC",
+        "every 30 min from 00:00 to 23:59 in America/New_York",
+        "every 2 hours from 00:00 to 23:59 except mar 9 in America/New_York",
+        "every 5 min from 09:00 to 17:00 until 20 times starting 2025-03-07 in UTC",
+        "every day at 09:00, 17:00 in UTC",
+    ];
+    // Covers the 2025-03-09 spring-forward in New York
+    let from = parse_zoned("2025-03-07T08:00:00+00:00[UTC]");
+    for expr in expressions {
+        let schedule = Schedule::parse(expr).unwrap();
+        for n in [1, 3, 17, 50] {
+            let expected: Vec<Zoned> = schedule
+                .occurrences(&from)
+                .step_by(n)
+                .take(40)
+                .collect::<Result<_, _>>()
+                .unwrap();
+            let got: Vec<Zoned> = schedule
+                .occurrences_stepped(&from, n)
+                .take(40)
+                .collect::<Result<_, _>>()
+                .unwrap();
+            assert_eq!(got, expected, "{expr} every {n}");
+        }
+    }
+}
+
+#[test]
+fn occurrences_resume_strictly_after_each_yield() {
+    // The cursor is the yielded instant itself, so every kind of expression
+    // must step strictly forward from an occurrence without repeating it.
+    let expressions = [
+        "every 1 min from 09:00 to 09:05 in UTC",
+        "every day at 09:00, 09:01 in UTC",
+        "every 2 weeks on mon, tue at 23:59 in UTC",
+        "every month on the last day at 00:00 in UTC",
+        "every year on feb 29 at 12:00 in UTC",
+        "every day at 01:30 in America/New_York",
+    ];
+    let from = parse_zoned("2024-01-01T00:00:00+00:00[UTC]");
+    for expr in expressions {
+        let schedule = Schedule::parse(expr).unwrap();
+        let results: Vec<Zoned> = schedule
+            .occurrences(&from)
+            .take(20)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(results.len(), 20, "{expr}");
+        for pair in results.windows(2) {
+            assert!(pair[0] < pair[1], "{expr}: {} then {}", pair[0], pair[1]);
+        }
+    }
+}
+
+#[test]
+fn occurrences_every_minute_is_consecutive() {
+    let schedule = Schedule::parse("every 1 min from 09:00 to 17:00 in UTC").unwrap();
+    let from = parse_zoned("2026-02-02T08:59:00+00:00[UTC]");
+    let minutes: Vec<i8> = schedule
+        .occurrences(&from)
+        .take(3)
+        .map(|r| r.unwrap().minute())
+        .collect();
+    assert_eq!(minutes, vec![0, 1, 2]);
+}
+
+#[test]
+#[should_panic(expected = "every_nth must be at least 1")]
+fn occurrences_stepped_rejects_zero() {
+    let schedule = Schedule::parse("every day at 09:00 in UTC").unwrap();
+    let from = parse_zoned("2026-02-01T00:00:00+00:00[UTC]");
+    let _ = schedule.occurrences_stepped(&from, 0);
+}
+
 // =============================================================================
 // Chained Operations
 // =============================================================================
@@ -402,3 +839,64 @@ fn complex_iterator_chain() {
     // Feb 2026: 2,3,4,5,6 are Mon-Fri
     assert_eq!(weekday_days, vec![2, 3, 4, 5, 6]);
 }
+
+// =============================================================================
+// Skipping
+// =============================================================================
+
+#[test]
+fn next_skipping_agrees_with_nth() {
+    let expressions = [
+        // Arithmetic fast path
+        "every day at 09:00 in UTC",
+        "every 3 days at 02:30 in America/New_York",
+        "every 2 weeks on fri, mon, wed at 23:59 in UTC",
+        "every week on sunday at 01:30 starting 2025-01-05 in America/New_York",
+        // Stepped one occurrence at a time
+        "every day at 09:00, 17:00 in UTC",
+        "every weekday at 09:00 except mar 10 in UTC",
+        "every month on the last day at 12:00 in UTC",
+        "every 30 min from 09:00 to 10:00 in UTC",
+        "every day at 09:00 until 20 times starting 2025-03-02 in UTC",
+    ];
+    let from = parse_zoned("2025-03-01T12:00:00+00:00[UTC]");
+    for expr in expressions {
+        let schedule = Schedule::parse(expr).unwrap();
+        for count in [0, 1, 5, 19, 20, 100] {
+            let expected = schedule.occurrences(&from).nth(count).transpose().unwrap();
+            let got = schedule.next_skipping(&from, count).unwrap();
+            assert_eq!(got, expected, "{expr} skipping {count}");
+        }
+    }
+}
+
+#[test]
+fn next_skipping_agrees_with_nth_on_sunday_weeks() {
+    let from = parse_zoned("2026-02-06T12:00:00+00:00[UTC]");
+    for expr in [
+        "every 2 weeks on sun, mon at 09:00 in UTC",
+        "every 3 weeks on sat, sun, wed at 09:00 in UTC",
+    ] {
+        let schedule = Schedule::parse(expr)
+            .unwrap()
+            .with_week_start(WeekStart::Sunday);
+        for count in [1, 2, 3, 7, 20] {
+            let expected = schedule.occurrences(&from).nth(count).transpose().unwrap();
+            let got = schedule.next_skipping(&from, count).unwrap();
+            assert_eq!(got, expected, "{expr} skipping {count}");
+        }
+    }
+}
+
+#[test]
+fn next_skipping_rejects_overflowing_counts() {
+    let from = parse_zoned("2026-02-06T12:00:00+00:00[UTC]");
+    for expr in [
+        "every 2 days at 09:00 in UTC",
+        "every 2 weeks on monday at 09:00 in UTC",
+    ] {
+        let schedule = Schedule::parse(expr).unwrap();
+        assert!(schedule.next_skipping(&from, usize::MAX / 2).is_err());
+        assert!(schedule.next_skipping(&from, usize::MAX).is_err());
+    }
+}
@@ -95,6 +95,8 @@ fn arb_hron_expression() -> impl Strategy<Value = String> {
                 };
                 format!("every {i} {unit} from 09:00 to 17:00 in UTC")
             }),
+        // WeekdayRepeat: "every 3 weekdays at 09:00 in UTC"
+        (2u32..10, arb_time_list()).prop_map(|(i, t)| format!("every {i} weekdays at {t} in UTC")),
         // WeekRepeat: "every 2 weeks on monday at 09:00 in UTC"
         (1u32..5, arb_weekday_name(), arb_time_list())
             .prop_map(|(i, d, t)| format!("every {i} weeks on {d} at {t} in UTC")),
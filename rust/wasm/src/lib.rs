@@ -8,11 +8,15 @@ pub struct Schedule {
 
 #[wasm_bindgen]
 impl Schedule {
-    /// Parse an hron expression string.
+    /// Parse an hron expression string. Throws a `{ message, start, end }`
+    /// object like [`validateDetailed`](Self::validate_detailed) returns, so
+    /// `e.message` reads the same as for any other error.
     #[wasm_bindgen]
-    pub fn parse(input: &str) -> Result<Schedule, JsError> {
-        let inner = hron::Schedule::parse(input).map_err(|e| JsError::new(&e.to_string()))?;
-        Ok(Schedule { inner })
+    pub fn parse(input: &str) -> Result<Schedule, JsValue> {
+        match hron::Schedule::parse(input) {
+            Ok(inner) => Ok(Schedule { inner }),
+            Err(e) => Err(error_info(input, &e)?),
+        }
     }
 
     /// Compute the next occurrence after `now`.
@@ -28,6 +32,18 @@ impl Schedule {
         Ok(result.map(|z| z.to_string()))
     }
 
+    /// Describe the next occurrence after `now` in relative English
+    /// (e.g. "tomorrow at 09:00").
+    #[wasm_bindgen(js_name = "nextFromHumanized")]
+    pub fn next_from_humanized(&self, now: &str) -> Result<Option<String>, JsError> {
+        let now: jiff::Zoned = now
+            .parse()
+            .map_err(|e: jiff::Error| JsError::new(&format!("{e}")))?;
+        self.inner
+            .next_from_humanized(&now)
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
     /// Compute the next `n` occurrences after `now`.
     #[wasm_bindgen(js_name = "nextNFrom")]
     pub fn next_n_from(&self, now: &str, n: u32) -> Result<JsValue, JsError> {
@@ -55,6 +71,27 @@ impl Schedule {
         Ok(result.map(|z| z.to_string()))
     }
 
+    /// Compute up to `n` occurrences before `now`, most recent first.
+    #[wasm_bindgen(js_name = "previousNFrom")]
+    pub fn previous_n_from(&self, now: &str, n: u32) -> Result<JsValue, JsError> {
+        let mut cursor: jiff::Zoned = now
+            .parse()
+            .map_err(|e: jiff::Error| JsError::new(&format!("{e}")))?;
+        let mut strings = Vec::new();
+        for _ in 0..n {
+            let Some(prev) = self
+                .inner
+                .previous_from(&cursor)
+                .map_err(|e| JsError::new(&e.to_string()))?
+            else {
+                break;
+            };
+            strings.push(prev.to_string());
+            cursor = prev;
+        }
+        serde_wasm_bindgen::to_value(&strings).map_err(|e| JsError::new(&e.to_string()))
+    }
+
     /// Check if a datetime matches this schedule.
     pub fn matches(&self, datetime: &str) -> Result<bool, JsError> {
         let dt: jiff::Zoned = datetime
@@ -65,6 +102,24 @@ impl Schedule {
             .map_err(|e| JsError::new(&e.to_string()))
     }
 
+    /// Check each datetime against this schedule. Returns an array of booleans.
+    #[wasm_bindgen(js_name = "matchesAll")]
+    pub fn matches_all(&self, datetimes: Vec<String>) -> Result<JsValue, JsError> {
+        let parsed: Vec<jiff::Zoned> = datetimes
+            .iter()
+            .enumerate()
+            .map(|(i, s)| {
+                s.parse()
+                    .map_err(|e: jiff::Error| JsError::new(&format!("datetimes[{i}]: {e}")))
+            })
+            .collect::<Result<_, _>>()?;
+        let results = self
+            .inner
+            .matches_all(&parsed)
+            .map_err(|e| JsError::new(&e.to_string()))?;
+        serde_wasm_bindgen::to_value(&results).map_err(|e| JsError::new(&e.to_string()))
+    }
+
     /// Get the structured JSON representation.
     #[wasm_bindgen(js_name = "toJSON")]
     pub fn to_json(&self) -> Result<JsValue, JsError> {
@@ -90,6 +145,18 @@ impl Schedule {
         hron::Schedule::parse(input).is_ok()
     }
 
+    /// Validate an expression, returning `null` when it is valid or a
+    /// `{ message, start, end }` object describing the error. `start`/`end`
+    /// are UTF-16 offsets (JS string indices), or `null` when the error has
+    /// no position.
+    #[wasm_bindgen(js_name = "validateDetailed")]
+    pub fn validate_detailed(input: &str) -> Result<JsValue, JsError> {
+        match hron::Schedule::validate_detailed(input) {
+            Ok(_) => Ok(JsValue::NULL),
+            Err(e) => error_info(input, &e),
+        }
+    }
+
     /// Get the timezone, if specified.
     #[wasm_bindgen(getter)]
     pub fn timezone(&self) -> Option<String> {
@@ -131,6 +198,32 @@ impl Schedule {
     }
 }
 
+/// A parse error located in its input, returned by `Schedule.validateDetailed`
+/// and thrown by `Schedule.parse`.
+#[derive(serde::Serialize)]
+struct ErrorInfo {
+    message: String,
+    start: Option<usize>,
+    end: Option<usize>,
+}
+
+/// Describe `e` as an [`ErrorInfo`] object, with its span converted from
+/// UTF-8 byte offsets into `input` to UTF-16 offsets (JS string indices).
+fn error_info(input: &str, e: &hron::ScheduleError) -> Result<JsValue, JsError> {
+    let utf16_offset = |byte: usize| {
+        input
+            .get(..byte.min(input.len()))
+            .map_or(0, |prefix| prefix.encode_utf16().count())
+    };
+    let span = e.span();
+    let info = ErrorInfo {
+        message: e.to_string(),
+        start: span.map(|s| utf16_offset(s.start)),
+        end: span.map(|s| utf16_offset(s.end)),
+    };
+    serde_wasm_bindgen::to_value(&info).map_err(|e| JsError::new(&e.to_string()))
+}
+
 /// Explain a cron expression in human-readable form.
 #[wasm_bindgen(js_name = "explainCron")]
 pub fn explain_cron(cron_expr: &str) -> Result<String, JsError> {
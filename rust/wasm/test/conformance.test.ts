@@ -32,6 +32,30 @@ describe("parse roundtrip", () => {
     "combined_clauses",
     "case_insensitivity",
     "ordinal_in_dates",
+    "time_of_day_keywords",
+    "time_list_normalization",
+    "default_midnight",
+    "weekday_exceptions",
+    "iso_weeks",
+    "during_dates",
+    "cron_shortcuts",
+    "ordinal_words",
+    "day_interval_filter",
+    "day_step_windows",
+    "last_n_days",
+    "day_list_canonicalization",
+    "fractional_hours",
+    "weekday_dates",
+    "weekday_interval",
+    "through_ranges",
+    "twelve_hour_windows",
+    "business_days",
+    "except_ranges",
+    "minutes_past_hour",
+    "ordinal_weekdays",
+    "twelve_hour_times",
+    "between_windows",
+    "frequency_keywords",
   ];
 
   for (const section of parseSections) {
@@ -59,6 +83,20 @@ describe("parse errors", () => {
     const name = tc.name ?? tc.input;
     it(name, () => {
       expect(() => Schedule.parse(tc.input)).toThrow();
+      const info = Schedule.validateDetailed(tc.input);
+      expect(info.message).toBeTruthy();
+      if (info.start !== null) {
+        expect(info.start).toBeLessThanOrEqual(info.end);
+        expect(info.end).toBeLessThanOrEqual(tc.input.length);
+      }
+      // parse throws the same located error
+      let thrown: unknown;
+      try {
+        Schedule.parse(tc.input);
+      } catch (e) {
+        thrown = e;
+      }
+      expect(thrown).toEqual(info);
     });
   }
 });
@@ -140,6 +178,10 @@ describe("eval matches", () => {
     const name = tc.name ?? tc.expression;
     it(name, () => {
       const schedule = Schedule.parse(tc.expression);
+      if (tc.error_contains) {
+        expect(() => schedule.matches(tc.datetime)).toThrow(tc.error_contains);
+        return;
+      }
       expect(schedule.matches(tc.datetime)).toBe(tc.expected);
     });
   }
@@ -162,6 +204,47 @@ describe("eval previous_from", () => {
   }
 });
 
+describe("eval previousNFrom", () => {
+  it("returns the most recent occurrences first", () => {
+    const schedule = Schedule.parse("every day at 09:00 in UTC");
+    const results = schedule.previousNFrom(defaultNow, 3) as string[];
+    expect(results).toEqual([
+      "2026-02-06T09:00:00+00:00[UTC]",
+      "2026-02-05T09:00:00+00:00[UTC]",
+      "2026-02-04T09:00:00+00:00[UTC]",
+    ]);
+  });
+
+  it("stops at n, or when occurrences run out", () => {
+    const daily = Schedule.parse("every day at 09:00 in UTC");
+    expect(daily.previousNFrom(defaultNow, 0)).toEqual([]);
+    expect((daily.previousNFrom(defaultNow, 10) as string[]).length).toBe(10);
+
+    const once = Schedule.parse("on 2026-02-03 at 09:00 in UTC");
+    expect(once.previousNFrom(defaultNow, 5)).toEqual([
+      "2026-02-03T09:00:00+00:00[UTC]",
+    ]);
+  });
+
+  // Each result is previousFrom of the one before it
+  const tests = spec.eval.previous_from.tests;
+  for (const tc of tests) {
+    const name = tc.name ?? tc.expression;
+    it(name, () => {
+      const schedule = Schedule.parse(tc.expression);
+      const results = schedule.previousNFrom(tc.now, 3) as string[];
+      let cursor: string = tc.now;
+      for (const result of results) {
+        expect(result).toBe(schedule.previousFrom(cursor));
+        cursor = result;
+      }
+      if (results.length < 3) {
+        expect(schedule.previousFrom(cursor)).toBeUndefined();
+      }
+    });
+  }
+});
+
 // ===========================================================================
 // Eval errors conformance
 // ===========================================================================
@@ -189,16 +272,18 @@ describe("eval errors", () => {
 // ===========================================================================
 
 describe("cron", () => {
-  describe("to_cron", () => {
-    const tests = spec.cron.to_cron.tests;
-    for (const tc of tests) {
-      const name = tc.name ?? tc.hron;
-      it(name, () => {
-        const schedule = Schedule.parse(tc.hron);
-        expect(schedule.toCron()).toBe(tc.cron);
-      });
-    }
-  });
+  for (const section of ["to_cron", "to_cron_during"]) {
+    describe(section, () => {
+      const tests = spec.cron[section].tests;
+      for (const tc of tests) {
+        const name = tc.name ?? tc.hron;
+        it(name, () => {
+          const schedule = Schedule.parse(tc.hron);
+          expect(schedule.toCron()).toBe(tc.cron);
+        });
+      }
+    });
+  }
 
   describe("to_cron errors", () => {
     const tests = spec.cron.to_cron_errors.tests;
@@ -23,7 +23,9 @@ export type MonthName =
   | "nov"
   | "dec";
 
-export type IntervalUnit = "min" | "hours";
+// "days" is a day-granularity step: the window is active on every Nth day
+// (aligned like "every N days at ...") and fires once, at the window start.
+export type IntervalUnit = "min" | "hours" | "days";
 
 export type OrdinalPosition =
   | "first"
@@ -61,9 +63,24 @@ export type NearestDirection = "next" | "previous";
 export type MonthTarget =
   | { type: "days"; specs: DayOfMonthSpec[] }
   | { type: "lastDay" }
+  // The final N calendar days of each month (`the last 3 days`), N >= 2.
+  // Counts back from the month's real length; N beyond it covers the whole
+  // month.
+  | { type: "lastNDays"; n: number }
   | { type: "lastWeekday" }
   | { type: "nearestWeekday"; day: number; direction: NearestDirection | null }
-  | { type: "ordinalWeekday"; ordinal: OrdinalPosition; weekday: Weekday };
+  | { type: "ordinalWeekday"; ordinal: OrdinalPosition; weekday: Weekday }
+  // Several ordinal weekdays of the month (`the first and last friday`).
+  // Ordinals are sorted and distinct; a single ordinal is an ordinalWeekday.
+  | {
+      type: "ordinalWeekdays";
+      ordinals: OrdinalPosition[];
+      weekday: Weekday;
+    }
+  // The Nth monday-to-friday day of the month (`the first business day`,
+  // `the 3rd weekday`), N in 1-23. Excepted dates are not business days, so
+  // an `except` landing on it moves the occurrence to the next one.
+  | { type: "businessDay"; n: number };
 
 // --- Year target ---
 
@@ -82,13 +99,28 @@ export type YearTarget =
 
 export type DateSpec =
   | { type: "named"; month: MonthName; day: number }
-  | { type: "iso"; date: string };
+  | { type: "iso"; date: string }
+  // `on friday`: the nearest upcoming day with that weekday, resolved against
+  // the evaluation time rather than a fixed date
+  | { type: "nextWeekday"; day: Weekday };
 
 // --- Exception ---
 
 export type Exception =
   | { type: "named"; month: MonthName; day: number }
-  | { type: "iso"; date: string };
+  | { type: "iso"; date: string }
+  | { type: "weekday"; day: Weekday }
+  // One-off inclusive ISO date range: `2026-12-24 to 2026-12-31`
+  | { type: "isoRange"; start: string; end: string }
+  // Recurring inclusive range of named dates: `dec 24 to jan 2` matches every
+  // year, wrapping past december when the end comes first
+  | {
+      type: "namedRange";
+      startMonth: MonthName;
+      startDay: number;
+      endMonth: MonthName;
+      endDay: number;
+    };
 
 // --- Until spec ---
 
@@ -108,6 +140,11 @@ export type ScheduleExpr =
       dayFilter: DayFilter | null;
     }
   | { type: "dayRepeat"; interval: number; days: DayFilter; times: TimeOfDay[] }
+  // `every 3 weekdays at 09:00`: every Nth business day. Only Monday through
+  // Friday count toward the interval, so weekends neither fire nor advance it.
+  // Days are counted from the `starting` anchor (or its next weekday),
+  // defaulting to 1970-01-01.
+  | { type: "weekdayRepeat"; interval: number; times: TimeOfDay[] }
   | {
       type: "weekRepeat";
       interval: number;
@@ -126,8 +163,32 @@ export type ScheduleExpr =
       interval: number;
       target: YearTarget;
       times: TimeOfDay[];
+    }
+  // Days in ISO 8601 week numbers 1-53 of every year
+  | {
+      type: "isoWeekRepeat";
+      weeks: number[];
+      days: Weekday[];
+      times: TimeOfDay[];
     };
 
+// --- During spec ---
+
+/**
+ * A single month, inclusive month range, or inclusive month-day range in a
+ * `during` clause.
+ *
+ * Wraps past December when start > end: `nov to feb` is nov, dec, jan, feb.
+ * Month forms leave the days null; an end of feb 29 means the last day of
+ * February in common years.
+ */
+export interface DuringSpec {
+  start: MonthName;
+  end: MonthName;
+  startDay: number | null;
+  endDay: number | null;
+}
+
 // --- Schedule (top-level) ---
 
 export interface ScheduleData {
@@ -135,8 +196,12 @@ export interface ScheduleData {
   timezone: string | null;
   except: Exception[];
   until: UntilSpec | null;
-  anchor: string | null; // ISO date string (YYYY-MM-DD)
-  during: MonthName[];
+  anchor: string | null; // ISO date (YYYY-MM-DD), or "today" / "now" resolved at evaluation
+  // `starting jan 6`: resolved to the next such date at evaluation
+  anchorNamed: { month: MonthName; day: number } | null;
+  anchorTime: TimeOfDay | null; // `starting ... at <time>`
+  // `during` as written: months, month ranges, and date ranges
+  during: DuringSpec[];
 }
 
 // --- Helper functions ---
@@ -200,6 +265,24 @@ export function monthNumber(month: MonthName): number {
   return map[month];
 }
 
+export function monthFromNumber(n: number): MonthName | null {
+  const months: MonthName[] = [
+    "jan",
+    "feb",
+    "mar",
+    "apr",
+    "may",
+    "jun",
+    "jul",
+    "aug",
+    "sep",
+    "oct",
+    "nov",
+    "dec",
+  ];
+  return months[n - 1] ?? null;
+}
+
 export function parseWeekday(s: string): Weekday | null {
   const map: Record<string, Weekday> = {
     monday: "monday",
@@ -260,6 +343,85 @@ export function expandDaySpec(spec: DayOfMonthSpec): number[] {
   return result;
 }
 
+/** The month's longest length: February has 29 days. */
+export function monthMaxDay(month: MonthName): number {
+  const map: Record<MonthName, number> = {
+    jan: 31,
+    feb: 29,
+    mar: 31,
+    apr: 30,
+    may: 31,
+    jun: 30,
+    jul: 31,
+    aug: 31,
+    sep: 30,
+    oct: 31,
+    nov: 30,
+    dec: 31,
+  };
+  return map[month];
+}
+
+/**
+ * The first and last month-day a during spec covers. Whole months end on
+ * their longest day (feb 29).
+ */
+export function duringBounds(spec: DuringSpec): {
+  startDay: number;
+  endDay: number;
+} {
+  return {
+    startDay: spec.startDay ?? 1,
+    endDay: spec.endDay ?? monthMaxDay(spec.end),
+  };
+}
+
+/** Whether the month-day falls within a during spec. */
+export function duringContains(
+  spec: DuringSpec,
+  month: MonthName,
+  day: number,
+): boolean {
+  const { startDay, endDay } = duringBounds(spec);
+  const key = monthNumber(month) * 100 + day;
+  const start = monthNumber(spec.start) * 100 + startDay;
+  const end = monthNumber(spec.end) * 100 + endDay;
+  if (start <= end) {
+    return start <= key && key <= end;
+  }
+  return key >= start || key <= end;
+}
+
+/**
+ * Whether a during spec covers whole months: `jun 1 to aug 31` is
+ * `jun to aug`.
+ */
+export function duringWholeMonths(spec: DuringSpec): boolean {
+  const { startDay, endDay } = duringBounds(spec);
+  return startDay === 1 && endDay === monthMaxDay(spec.end);
+}
+
+/** The months a during spec touches, in calendar order from the start. */
+export function expandDuringSpec(spec: DuringSpec): MonthName[] {
+  const { startDay, endDay } = duringBounds(spec);
+  const start = monthNumber(spec.start);
+  // `jun 15 to jun 10` wraps through every month
+  const length =
+    spec.start === spec.end && startDay > endDay
+      ? 12
+      : ((monthNumber(spec.end) - start + 12) % 12) + 1;
+  const result: MonthName[] = [];
+  for (let i = 0; i < length; i++) {
+    result.push(monthFromNumber(((start - 1 + i) % 12) + 1) as MonthName);
+  }
+  return result;
+}
+
+/** Months any `during` spec touches, for month-level skipping. */
+export function duringMonths(schedule: ScheduleData): MonthName[] {
+  return schedule.during.flatMap(expandDuringSpec);
+}
+
 export function expandMonthTarget(target: MonthTarget): number[] {
   if (target.type === "days") {
     return target.specs.flatMap(expandDaySpec);
@@ -288,6 +450,31 @@ export const ALL_WEEKDAYS: Weekday[] = [
 
 export const ALL_WEEKEND: Weekday[] = ["saturday", "sunday"];
 
+export const RELATIVE_ANCHORS: string[] = ["today", "now"];
+
+/** Whether the `starting` anchor is resolved against the evaluation time. */
+export function hasRelativeAnchor(schedule: ScheduleData): boolean {
+  return (
+    schedule.anchorNamed !== null ||
+    (schedule.anchor !== null && RELATIVE_ANCHORS.includes(schedule.anchor))
+  );
+}
+
+/**
+ * Sort and de-duplicate an explicit day list, collapsing one covering exactly
+ * the weekdays or all seven days into the equivalent keyword filter.
+ * `saturday, sunday` stays a list: `weekend` is a keyword of its own.
+ */
+export function canonicalDayFilter(filter: DayFilter): DayFilter {
+  if (filter.type !== "days") return filter;
+  const days = [...new Set(filter.days)].sort(
+    (a, b) => weekdayNumber(a) - weekdayNumber(b),
+  );
+  if (days.length === 7) return { type: "every" };
+  if (days.join(",") === ALL_WEEKDAYS.join(",")) return { type: "weekday" };
+  return { type: "days", days };
+}
+
 export function newScheduleData(expr: ScheduleExpr): ScheduleData {
   return {
     expr,
@@ -295,6 +482,8 @@ export function newScheduleData(expr: ScheduleExpr): ScheduleData {
     except: [],
     until: null,
     anchor: null,
+    anchorNamed: null,
+    anchorTime: null,
     during: [],
   };
 }
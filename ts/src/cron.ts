@@ -3,21 +3,24 @@
 import type {
   DayFilter,
   DayOfMonthSpec,
+  DuringSpec,
   MonthName,
   MonthTarget,
   OrdinalPosition,
   ScheduleData,
+  ScheduleExpr,
   TimeOfDay,
   Weekday,
 } from "./ast.js";
 import {
-  ALL_WEEKDAYS,
   ALL_WEEKEND,
+  canonicalDayFilter,
   cronDowNumber,
+  duringMonths,
+  duringWholeMonths,
   monthNumber,
   newScheduleData,
   parseMonthName,
-  weekdayNumber,
 } from "./ast.js";
 import { HronError } from "./error.js";
 
@@ -33,11 +36,12 @@ export function toCron(schedule: ScheduleData): string {
       "not expressible as cron (until clauses not supported)",
     );
   }
-  if (schedule.during.length > 0) {
+  if (!schedule.during.every(duringWholeMonths)) {
     throw HronError.cron(
-      "not expressible as cron (during clauses not supported)",
+      "not expressible as cron (during dates narrower than whole months)",
     );
   }
+  const month = monthField(duringMonths(schedule));
 
   const expr = schedule.expr;
 
@@ -48,20 +52,29 @@ export function toCron(schedule: ScheduleData): string {
           "not expressible as cron (multi-day intervals not supported)",
         );
       }
-      if (expr.times.length !== 1) {
+      const fields = timeFields(expr.times);
+      if (fields === null) {
         throw HronError.cron(
           "not expressible as cron (multiple times not supported)",
         );
       }
-      const time = expr.times[0];
+      const { minute, hour } = fields;
       const dow = dayFilterToCronDow(expr.days);
-      return `${time.minute} ${time.hour} * * ${dow}`;
+      return `${minute} ${hour} * ${month} ${dow}`;
     }
 
     case "intervalRepeat": {
+      if (expr.unit === "days") {
+        throw HronError.cron(
+          "not expressible as cron (multi-day interval windows not supported)",
+        );
+      }
+      // Only expressible if window is full day (00:00 to 23:59). Hour steps
+      // may start past the hour: `from 00:15` fires at :15.
+      const offsetOk = expr.from.minute === 0 || expr.unit === "hours";
       const fullDay =
         expr.from.hour === 0 &&
-        expr.from.minute === 0 &&
+        offsetOk &&
         expr.to.hour === 23 &&
         expr.to.minute === 59;
       if (!fullDay) {
@@ -81,10 +94,13 @@ export function toCron(schedule: ScheduleData): string {
             `not expressible as cron (*/${expr.interval} breaks at hour boundaries)`,
           );
         }
-        return `*/${expr.interval} * * * *`;
+        return `*/${expr.interval} * * ${month} *`;
       }
       // hours
-      return `0 */${expr.interval} * * *`;
+      if (expr.interval === 1 && expr.from.minute !== 0) {
+        return `${expr.from.minute} * * ${month} *`;
+      }
+      return `${expr.from.minute} */${expr.interval} * ${month} *`;
     }
 
     case "weekRepeat":
@@ -98,12 +114,13 @@ export function toCron(schedule: ScheduleData): string {
           "not expressible as cron (multi-month intervals not supported)",
         );
       }
-      if (expr.times.length !== 1) {
+      const fields = timeFields(expr.times);
+      if (fields === null) {
         throw HronError.cron(
           "not expressible as cron (multiple times not supported)",
         );
       }
-      const time = expr.times[0];
+      const { minute, hour } = fields;
       const { target } = expr;
       if (target.type === "days") {
         const expanded = target.specs.flatMap((s) => {
@@ -113,13 +130,25 @@ export function toCron(schedule: ScheduleData): string {
           return r;
         });
         const dom = expanded.join(",");
-        return `${time.minute} ${time.hour} ${dom} * *`;
+        return `${minute} ${hour} ${dom} ${month} *`;
       }
       if (target.type === "lastDay") {
         throw HronError.cron(
           "not expressible as cron (last day of month not supported)",
         );
       }
+      if (target.type === "lastNDays") {
+        throw HronError.cron(
+          "not expressible as cron (last days of month not supported)",
+        );
+      }
+      // The first business day is always the weekday nearest the 1st
+      if (target.type === "businessDay") {
+        if (target.n === 1) return `${minute} ${hour} 1W ${month} *`;
+        throw HronError.cron(
+          "not expressible as cron (nth business day of month not supported)",
+        );
+      }
       if (target.type === "lastWeekday") {
         throw HronError.cron(
           "not expressible as cron (last weekday of month not supported)",
@@ -130,13 +159,18 @@ export function toCron(schedule: ScheduleData): string {
           "not expressible as cron (ordinal weekday of month not supported)",
         );
       }
+      if (target.type === "ordinalWeekdays") {
+        throw HronError.cron(
+          "not expressible as cron (ordinal weekdays of month not supported)",
+        );
+      }
       // nearestWeekday
       if (target.direction !== null) {
         throw HronError.cron(
           "not expressible as cron (directional nearest weekday not supported)",
         );
       }
-      return `${time.minute} ${time.hour} ${target.day}W * *`;
+      return `${minute} ${hour} ${target.day}W ${month} *`;
     }
 
     case "singleDate":
@@ -148,7 +182,60 @@ export function toCron(schedule: ScheduleData): string {
       throw HronError.cron(
         "not expressible as cron (yearly schedules not supported in 5-field cron)",
       );
+
+    case "weekdayRepeat":
+      throw HronError.cron(
+        "not expressible as cron (business-day intervals not supported)",
+      );
+
+    case "isoWeekRepeat":
+      throw HronError.cron(
+        "not expressible as cron (cron has no week-of-year field)",
+      );
+  }
+}
+
+/**
+ * Exact minute and hour fields for a list of times: `0 9,17`, `0,30 9`, or
+ * `0,30 9,17` when the times are every listed minute at every listed hour.
+ * Null when comma fields would fire at times not in the list.
+ */
+function timeFields(
+  times: TimeOfDay[],
+): { minute: string; hour: string } | null {
+  const distinct = new Set(times.map((t) => t.hour * 60 + t.minute));
+  const minutes = [...new Set(times.map((t) => t.minute))];
+  const hours = [...new Set(times.map((t) => t.hour))];
+  // Distinct times are a subset of the grid, so equal counts mean equal sets
+  if (minutes.length * hours.length !== distinct.size) return null;
+  return {
+    minute: minutes.sort((a, b) => a - b).join(","),
+    hour: hours.sort((a, b) => a - b).join(","),
+  };
+}
+
+/**
+ * The cron month field for a `during` clause. Runs of three or more
+ * consecutive months collapse into a range.
+ */
+function monthField(during: MonthName[]): string {
+  const months = [...new Set(during.map(monthNumber))].sort((a, b) => a - b);
+  if (months.length === 0) return "*";
+  const parts: string[] = [];
+  let i = 0;
+  while (i < months.length) {
+    let j = i;
+    while (j + 1 < months.length && months[j + 1] === months[j] + 1) {
+      j++;
+    }
+    if (j - i >= 2) {
+      parts.push(`${months[i]}-${months[j]}`);
+    } else {
+      for (let k = i; k <= j; k++) parts.push(String(months[k]));
+    }
+    i = j + 1;
   }
+  return parts.join(",");
 }
 
 function dayFilterToCronDow(filter: DayFilter): string {
@@ -237,9 +324,7 @@ export function fromCron(cron: string): ScheduleData {
   if (intervalResult) return intervalResult;
 
   // Standard time-based cron
-  const minute = parseSingleValue(minuteField, "minute", 0, 59);
-  const hour = parseSingleValue(hourField, "hour", 0, 23);
-  const time: TimeOfDay = { hour, minute };
+  const times = parseCronTimes(minuteField, hourField);
 
   // DOM-based (monthly) - when DOM is specified and DOW is *
   if (domField !== "*" && dowField === "*") {
@@ -248,7 +333,7 @@ export function fromCron(cron: string): ScheduleData {
       type: "monthRepeat",
       interval: 1,
       target,
-      times: [time],
+      times,
     });
     schedule.during = during;
     return schedule;
@@ -260,61 +345,71 @@ export function fromCron(cron: string): ScheduleData {
     type: "dayRepeat",
     interval: 1,
     days,
-    times: [time],
+    times,
   });
   schedule.during = during;
   return schedule;
 }
 
 /** Parse @ shortcuts like @daily, @hourly, etc. */
-function parseCronShortcut(cron: string): ScheduleData {
-  switch (cron.toLowerCase()) {
+export function parseCronShortcut(cron: string): ScheduleData {
+  const expr = cronShortcutExpr(cron.toLowerCase());
+  if (!expr) {
+    throw HronError.cron(`unknown @ shortcut: ${cron}`);
+  }
+  return newScheduleData(expr);
+}
+
+/** The expression a lowercase @ shortcut stands for, if it is one. */
+export function cronShortcutExpr(shortcut: string): ScheduleExpr | null {
+  const midnight = [{ hour: 0, minute: 0 }];
+  switch (shortcut) {
     case "@yearly":
     case "@annually":
-      return newScheduleData({
+      return {
         type: "yearRepeat",
         interval: 1,
         target: { type: "date", month: "jan", day: 1 },
-        times: [{ hour: 0, minute: 0 }],
-      });
+        times: midnight,
+      };
     case "@monthly":
-      return newScheduleData({
+      return {
         type: "monthRepeat",
         interval: 1,
         target: { type: "days", specs: [{ type: "single", day: 1 }] },
-        times: [{ hour: 0, minute: 0 }],
-      });
+        times: midnight,
+      };
     case "@weekly":
-      return newScheduleData({
+      return {
         type: "dayRepeat",
         interval: 1,
         days: { type: "days", days: ["sunday"] },
-        times: [{ hour: 0, minute: 0 }],
-      });
+        times: midnight,
+      };
     case "@daily":
     case "@midnight":
-      return newScheduleData({
+      return {
         type: "dayRepeat",
         interval: 1,
         days: { type: "every" },
-        times: [{ hour: 0, minute: 0 }],
-      });
+        times: midnight,
+      };
     case "@hourly":
-      return newScheduleData({
+      return {
         type: "intervalRepeat",
         interval: 1,
         unit: "hours",
         from: { hour: 0, minute: 0 },
         to: { hour: 23, minute: 59 },
         dayFilter: null,
-      });
+      };
     default:
-      throw HronError.cron(`unknown @ shortcut: ${cron}`);
+      return null;
   }
 }
 
-/** Parse month field into a MonthName[] for the `during` clause. */
-function parseMonthField(field: string): MonthName[] {
+/** Parse month field into `during` specs, one per month. */
+function parseMonthField(field: string): DuringSpec[] {
   if (field === "*") return [];
 
   const months: MonthName[] = [];
@@ -368,7 +463,12 @@ function parseMonthField(field: string): MonthName[] {
     }
   }
 
-  return months;
+  return months.map((m) => ({
+    start: m,
+    end: m,
+    startDay: null,
+    endDay: null,
+  }));
 }
 
 /** Parse a single month value (number 1-12 or name JAN-DEC). */
@@ -414,7 +514,7 @@ function tryParseNthWeekday(
   hourField: string,
   domField: string,
   dowField: string,
-  during: MonthName[],
+  during: DuringSpec[],
 ): ScheduleData | null {
   // Check for # pattern (nth weekday of month)
   if (dowField.includes("#")) {
@@ -431,8 +531,7 @@ function tryParseNthWeekday(
       throw HronError.cron("DOM must be * when using # for nth weekday");
     }
 
-    const minute = parseSingleValue(minuteField, "minute", 0, 59);
-    const hour = parseSingleValue(hourField, "hour", 0, 23);
+    const times = parseCronTimes(minuteField, hourField);
 
     const ordinalMap: Record<number, OrdinalPosition> = {
       1: "first",
@@ -446,7 +545,7 @@ function tryParseNthWeekday(
       type: "monthRepeat",
       interval: 1,
       target: { type: "ordinalWeekday", ordinal: ordinalMap[nth], weekday },
-      times: [{ hour, minute }],
+      times,
     });
     schedule.during = during;
     return schedule;
@@ -462,14 +561,13 @@ function tryParseNthWeekday(
       throw HronError.cron("DOM must be * when using nL for last weekday");
     }
 
-    const minute = parseSingleValue(minuteField, "minute", 0, 59);
-    const hour = parseSingleValue(hourField, "hour", 0, 23);
+    const times = parseCronTimes(minuteField, hourField);
 
     const schedule = newScheduleData({
       type: "monthRepeat",
       interval: 1,
       target: { type: "ordinalWeekday", ordinal: "last", weekday },
-      times: [{ hour, minute }],
+      times,
     });
     schedule.during = during;
     return schedule;
@@ -484,7 +582,7 @@ function tryParseLastDay(
   hourField: string,
   domField: string,
   dowField: string,
-  during: MonthName[],
+  during: DuringSpec[],
 ): ScheduleData | null {
   if (domField !== "L" && domField !== "LW") {
     return null;
@@ -494,8 +592,7 @@ function tryParseLastDay(
     throw HronError.cron("DOW must be * when using L or LW in DOM");
   }
 
-  const minute = parseSingleValue(minuteField, "minute", 0, 59);
-  const hour = parseSingleValue(hourField, "hour", 0, 23);
+  const times = parseCronTimes(minuteField, hourField);
 
   const target: MonthTarget =
     domField === "LW" ? { type: "lastWeekday" } : { type: "lastDay" };
@@ -504,7 +601,7 @@ function tryParseLastDay(
     type: "monthRepeat",
     interval: 1,
     target,
-    times: [{ hour, minute }],
+    times,
   });
   schedule.during = during;
   return schedule;
@@ -516,7 +613,7 @@ function tryParseNearestWeekday(
   hourField: string,
   domField: string,
   dowField: string,
-  during: MonthName[],
+  during: DuringSpec[],
 ): ScheduleData | null {
   if (!domField.endsWith("W") || domField === "LW") {
     return null;
@@ -537,8 +634,7 @@ function tryParseNearestWeekday(
     throw HronError.cron(`W day must be 1-31, got ${day}`);
   }
 
-  const minute = parseSingleValue(minuteField, "minute", 0, 59);
-  const hour = parseSingleValue(hourField, "hour", 0, 23);
+  const times = parseCronTimes(minuteField, hourField);
 
   const target: MonthTarget = {
     type: "nearestWeekday",
@@ -550,7 +646,7 @@ function tryParseNearestWeekday(
     type: "monthRepeat",
     interval: 1,
     target,
-    times: [{ hour, minute }],
+    times,
   });
   schedule.during = during;
   return schedule;
@@ -562,7 +658,7 @@ function tryParseInterval(
   hourField: string,
   domField: string,
   dowField: string,
-  during: MonthName[],
+  during: DuringSpec[],
 ): ScheduleData | null {
   // Minute interval: */N or range/N
   if (minuteField.includes("/")) {
@@ -653,12 +749,12 @@ function tryParseInterval(
     }
   }
 
-  // Hour interval: 0 */N or 0 range/N
-  if (
-    hourField.includes("/") &&
-    (minuteField === "0" || minuteField === "00")
-  ) {
-    const [rangePart, stepStr] = hourField.split("/");
+  // Hour interval: M */N, M range/N, or M * (every hour at minute M)
+  const minuteOk = /^\d+$/.test(minuteField) && parseInt(minuteField, 10) <= 59;
+  if (minuteOk && (hourField.includes("/") || hourField === "*")) {
+    const minute = parseInt(minuteField, 10);
+    const [rangePart, step] = hourField.split("/");
+    const stepStr = step || "1";
     const interval = parseInt(stepStr, 10);
 
     if (Number.isNaN(interval)) {
@@ -696,14 +792,15 @@ function tryParseInterval(
       (domField === "*" || domField === "?") &&
       (dowField === "*" || dowField === "?")
     ) {
-      // Use :59 only for full day (00:00 to 23:59), otherwise use :00
-      const endMinute = fromHour === 0 && toHour === 23 ? 59 : 0;
+      // Use :59 only for full day (00:00 to 23:59), otherwise end on the
+      // last firing
+      const endMinute = fromHour === 0 && toHour === 23 ? 59 : minute;
 
       const schedule = newScheduleData({
         type: "intervalRepeat",
         interval,
         unit: "hours",
-        from: { hour: fromHour, minute: 0 },
+        from: { hour: fromHour, minute },
         to: { hour: toHour, minute: endMinute },
         dayFilter: null,
       });
@@ -861,31 +958,15 @@ function parseCronDow(field: string): DayFilter {
     }
   }
 
-  // Check for special patterns
-  if (days.length === 5) {
-    const sorted = [...days].sort(
-      (a, b) => weekdayNumber(a) - weekdayNumber(b),
-    );
-    const weekdays = [...ALL_WEEKDAYS].sort(
-      (a, b) => weekdayNumber(a) - weekdayNumber(b),
-    );
-    if (JSON.stringify(sorted) === JSON.stringify(weekdays)) {
-      return { type: "weekday" };
-    }
-  }
-  if (days.length === 2) {
-    const sorted = [...days].sort(
-      (a, b) => weekdayNumber(a) - weekdayNumber(b),
-    );
-    const weekend = [...ALL_WEEKEND].sort(
-      (a, b) => weekdayNumber(a) - weekdayNumber(b),
-    );
-    if (JSON.stringify(sorted) === JSON.stringify(weekend)) {
-      return { type: "weekend" };
-    }
+  // Cron has no weekend keyword, so 0,6 reads as the weekend
+  const canonical = canonicalDayFilter({ type: "days", days });
+  if (
+    canonical.type === "days" &&
+    canonical.days.join(",") === ALL_WEEKEND.join(",")
+  ) {
+    return { type: "weekend" };
   }
-
-  return { type: "days", days };
+  return canonical;
 }
 
 /** Parse a DOW value (number 0-7 or name SUN-SAT), normalizing 7 to 0. */
@@ -940,6 +1021,29 @@ function cronDowToWeekday(n: number): Weekday {
   return result;
 }
 
+/**
+ * Parse the minute and hour fields into times. Either field may be a comma
+ * list; every hour pairs with every minute, so `0,30 9,17` gives four times.
+ */
+function parseCronTimes(minuteField: string, hourField: string): TimeOfDay[] {
+  const minutes = parseValueList(minuteField, "minute", 0, 59);
+  const hours = parseValueList(hourField, "hour", 0, 23);
+  const keys = new Set(hours.flatMap((h) => minutes.map((m) => h * 60 + m)));
+  return [...keys]
+    .sort((a, b) => a - b)
+    .map((k) => ({ hour: Math.floor(k / 60), minute: k % 60 }));
+}
+
+/** Parse a comma list of numeric values, each validated like a single value. */
+function parseValueList(
+  field: string,
+  name: string,
+  min: number,
+  max: number,
+): number[] {
+  return field.split(",").map((v) => parseSingleValue(v, name, min, max));
+}
+
 /** Parse a single numeric value with validation. */
 function parseSingleValue(
   field: string,
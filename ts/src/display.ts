@@ -3,12 +3,18 @@
 import type {
   DayFilter,
   DayOfMonthSpec,
+  DuringSpec,
   IntervalUnit,
   ScheduleData,
   ScheduleExpr,
   TimeOfDay,
   Weekday,
 } from "./ast.js";
+import {
+  canonicalDayFilter,
+  duringBounds,
+  duringWholeMonths,
+} from "./ast.js";
 
 /** Render a schedule as its canonical string form. */
 export function display(schedule: ScheduleData): string {
@@ -20,7 +26,12 @@ export function display(schedule: ScheduleData): string {
     out += schedule.except
       .map((exc) => {
         if (exc.type === "named") return `${exc.month} ${exc.day}`;
-        return exc.date;
+        if (exc.type === "iso") return exc.date;
+        if (exc.type === "isoRange") return `${exc.start} to ${exc.end}`;
+        if (exc.type === "namedRange") {
+          return `${exc.startMonth} ${exc.startDay} to ${exc.endMonth} ${exc.endDay}`;
+        }
+        return exc.day;
       })
       .join(", ");
   }
@@ -35,10 +46,16 @@ export function display(schedule: ScheduleData): string {
 
   if (schedule.anchor) {
     out += ` starting ${schedule.anchor}`;
+  } else if (schedule.anchorNamed) {
+    const { month, day } = schedule.anchorNamed;
+    out += ` starting ${month} ${day}`;
+  }
+  if (schedule.anchorTime) {
+    out += ` at ${formatTime(schedule.anchorTime)}`;
   }
 
   if (schedule.during.length > 0) {
-    out += ` during ${schedule.during.join(", ")}`;
+    out += ` during ${schedule.during.map(displayDuringSpec).join(", ")}`;
   }
 
   if (schedule.timezone) {
@@ -48,6 +65,19 @@ export function display(schedule: ScheduleData): string {
   return out;
 }
 
+function displayDuringSpec(spec: DuringSpec): string {
+  const { start, end } = spec;
+  const { startDay, endDay } = duringBounds(spec);
+  // Date ranges covering whole months print as months
+  if (duringWholeMonths(spec)) {
+    return start === end ? start : `${start} to ${end}`;
+  }
+  if (start === end && startDay === endDay) {
+    return `${start} ${startDay}`;
+  }
+  return `${start} ${startDay} to ${end} ${endDay}`;
+}
+
 function displayExpr(expr: ScheduleExpr): string {
   switch (expr.type) {
     case "intervalRepeat": {
@@ -60,9 +90,15 @@ function displayExpr(expr: ScheduleExpr): string {
     }
     case "dayRepeat":
       if (expr.interval > 1) {
-        return `every ${expr.interval} days at ${formatTimeList(expr.times)}`;
+        let out = `every ${expr.interval} days `;
+        if (expr.days.type !== "every") {
+          out += `on ${displayDayFilter(expr.days)} `;
+        }
+        return `${out}at ${formatTimeList(expr.times)}`;
       }
       return `every ${displayDayFilter(expr.days)} at ${formatTimeList(expr.times)}`;
+    case "weekdayRepeat":
+      return `every ${expr.interval} weekdays at ${formatTimeList(expr.times)}`;
     case "weekRepeat":
       if (expr.interval > 1) {
         return `every ${expr.interval} weeks on ${formatDayList(expr.days)} at ${formatTimeList(expr.times)}`;
@@ -74,10 +110,19 @@ function displayExpr(expr: ScheduleExpr): string {
         targetStr = formatOrdinalDaySpecs(expr.target.specs);
       } else if (expr.target.type === "lastDay") {
         targetStr = "last day";
+      } else if (expr.target.type === "lastNDays") {
+        targetStr = `last ${expr.target.n} days`;
+      } else if (expr.target.type === "businessDay") {
+        const { n } = expr.target;
+        targetStr = `${n}${ordinalSuffix(n)} business day`;
       } else if (expr.target.type === "lastWeekday") {
         targetStr = "last weekday";
       } else if (expr.target.type === "ordinalWeekday") {
         targetStr = `${expr.target.ordinal} ${expr.target.weekday}`;
+      } else if (expr.target.type === "ordinalWeekdays") {
+        const { ordinals, weekday } = expr.target;
+        const rest = ordinals.slice(0, -1).join(", ");
+        targetStr = `${rest} and ${ordinals[ordinals.length - 1]} ${weekday}`;
       } else if (expr.target.type === "nearestWeekday") {
         const { day, direction } = expr.target;
         const dirPrefix = direction ? `${direction} ` : "";
@@ -96,6 +141,8 @@ function displayExpr(expr: ScheduleExpr): string {
       let dateStr: string;
       if (expr.date.type === "named") {
         dateStr = `${expr.date.month} ${expr.date.day}`;
+      } else if (expr.date.type === "nextWeekday") {
+        dateStr = expr.date.day;
       } else {
         dateStr = expr.date.date;
       }
@@ -121,6 +168,8 @@ function displayExpr(expr: ScheduleExpr): string {
       }
       return `every year on ${targetStr} at ${formatTimeList(expr.times)}`;
     }
+    case "isoWeekRepeat":
+      return `every year in iso weeks ${expr.weeks.join(", ")} on ${formatDayList(expr.days)} at ${formatTimeList(expr.times)}`;
     default: {
       const _exhaustive: never = expr;
       throw new Error(
@@ -138,8 +187,11 @@ function displayDayFilter(filter: DayFilter): string {
       return "weekday";
     case "weekend":
       return "weekend";
-    case "days":
-      return formatDayList(filter.days);
+    case "days": {
+      const canonical = canonicalDayFilter(filter);
+      if (canonical.type !== "days") return displayDayFilter(canonical);
+      return formatDayList(canonical.days);
+    }
     default: {
       const _exhaustive: never = filter;
       throw new Error(
@@ -191,5 +243,8 @@ function unitDisplay(interval: number, unit: IntervalUnit): string {
   if (unit === "min") {
     return interval === 1 ? "minute" : "min";
   }
+  if (unit === "days") {
+    return interval === 1 ? "day" : "days";
+  }
   return interval === 1 ? "hour" : "hours";
 }
@@ -4,6 +4,7 @@ import { Temporal } from "@js-temporal/polyfill";
 import type {
   DateSpec,
   DayFilter,
+  DuringSpec,
   Exception,
   MonthName,
   MonthTarget,
@@ -17,11 +18,16 @@ import type {
   YearTarget,
 } from "./ast.js";
 import {
+  duringBounds,
+  duringContains,
+  duringMonths,
   expandMonthTarget,
+  hasRelativeAnchor,
   monthNumber,
   ordinalToN,
   weekdayNumber,
 } from "./ast.js";
+import { HronError } from "./error.js";
 
 type ZDT = Temporal.ZonedDateTime;
 type PD = Temporal.PlainDate;
@@ -123,6 +129,37 @@ function lastDayOfMonth(year: number, month: number): PD {
     .subtract({ days: 1 });
 }
 
+/** The final `n` days of the month, ascending; the whole month if shorter. */
+function lastNDaysOfMonth(year: number, month: number, n: number): PD[] {
+  const last = lastDayOfMonth(year, month);
+  const dates: PD[] = [];
+  for (let day = Math.max(last.day - n + 1, 1); day <= last.day; day++) {
+    dates.push(last.with({ day }));
+  }
+  return dates;
+}
+
+/**
+ * The nth business day (Mon-Fri, 1-indexed) of a month, not counting excepted
+ * days. Null if the month has fewer.
+ */
+function nthBusinessDay(
+  year: number,
+  month: number,
+  n: number,
+  exceptions: Exception[],
+): PD | null {
+  const last = lastDayOfMonth(year, month);
+  let count = 0;
+  for (let day = 1; day <= last.day; day++) {
+    const d = last.with({ day });
+    if (d.dayOfWeek > 5 || isExcepted(d, exceptions)) continue;
+    count++;
+    if (count === n) return d;
+  }
+  return null;
+}
+
 function lastWeekdayOfMonth(year: number, month: number): PD {
   let d = lastDayOfMonth(year, month);
   while (d.dayOfWeek === 6 || d.dayOfWeek === 7) {
@@ -251,71 +288,208 @@ function euclideanMod(a: number, b: number): number {
   return ((a % b) + b) % b;
 }
 
+/**
+ * Number of business days (Monday-Friday) from the week of the epoch to
+ * `date`. A weekend date shares the index of the following Monday.
+ */
+function businessDayIndex(date: PD): number {
+  // 1970-01-01 was a Thursday, 3 days after the Monday that starts its week
+  const days = daysBetween(EPOCH_DATE, date) + 3;
+  return Math.floor(days / 7) * 5 + Math.min(euclideanMod(days, 7), 5);
+}
+
+/** The weekday with the given business day index. */
+function businessDayDate(index: number): PD {
+  return EPOCH_DATE.add({
+    days: Math.floor(index / 5) * 7 + euclideanMod(index, 5) - 3,
+  });
+}
+
+/** Whether `date` is a business day an `every N weekdays` schedule fires on. */
+function weekdayIntervalAligned(
+  anchor: PD | null,
+  interval: number,
+  date: PD,
+): boolean {
+  const offset =
+    businessDayIndex(date) - businessDayIndex(anchor ?? EPOCH_DATE);
+  return date.dayOfWeek <= 5 && offset >= 0 && offset % interval === 0;
+}
+
 function isExcepted(date: PD, exceptions: Exception[]): boolean {
   for (const exc of exceptions) {
     if (exc.type === "named") {
       if (date.month === monthNumber(exc.month) && date.day === exc.day) {
         return true;
       }
-    } else {
+    } else if (exc.type === "iso") {
       const excDate = Temporal.PlainDate.from(exc.date);
       if (Temporal.PlainDate.compare(date, excDate) === 0) {
         return true;
       }
+    } else if (exc.type === "isoRange" || exc.type === "namedRange") {
+      if (rangeBounds(date, exc) !== null) return true;
+    } else if (date.dayOfWeek === weekdayNumber(exc.day)) {
+      return true;
     }
   }
   return false;
 }
 
+type RangeException = Extract<Exception, { type: "isoRange" | "namedRange" }>;
+
 interface ParsedExceptions {
   named: Array<{ month: number; day: number }>;
   isoDates: PD[];
+  weekdays: Set<number>;
+  ranges: RangeException[];
 }
 
 function parseExceptions(exceptions: Exception[]): ParsedExceptions {
   const named: Array<{ month: number; day: number }> = [];
   const isoDates: PD[] = [];
+  const weekdays = new Set<number>();
+  const ranges: RangeException[] = [];
   for (const exc of exceptions) {
     if (exc.type === "named") {
       named.push({ month: monthNumber(exc.month), day: exc.day });
-    } else {
+    } else if (exc.type === "iso") {
       isoDates.push(Temporal.PlainDate.from(exc.date));
+    } else if (exc.type === "isoRange" || exc.type === "namedRange") {
+      ranges.push(exc);
+    } else {
+      weekdays.add(weekdayNumber(exc.day));
     }
   }
-  return { named, isoDates };
+  return { named, isoDates, weekdays, ranges };
 }
 
 function isExceptedParsed(date: PD, parsed: ParsedExceptions): boolean {
+  if (parsed.weekdays.has(date.dayOfWeek)) return true;
   for (const n of parsed.named) {
     if (date.month === n.month && date.day === n.day) return true;
   }
   for (const d of parsed.isoDates) {
     if (Temporal.PlainDate.compare(date, d) === 0) return true;
   }
-  return false;
+  return parsed.ranges.some((r) => rangeBounds(date, r) !== null);
 }
 
-function matchesDuring(date: PD, during: MonthName[]): boolean {
+/** Concrete bounds of a range exception if it covers `date`. */
+function rangeBounds(
+  date: PD,
+  exc: RangeException,
+): { start: PD; end: PD } | null {
+  if (exc.type === "isoRange") {
+    const start = Temporal.PlainDate.from(exc.start);
+    const end = Temporal.PlainDate.from(exc.end);
+    if (
+      Temporal.PlainDate.compare(date, start) < 0 ||
+      Temporal.PlainDate.compare(date, end) > 0
+    ) {
+      return null;
+    }
+    return { start, end };
+  }
+  const startMonth = monthNumber(exc.startMonth);
+  const endMonth = monthNumber(exc.endMonth);
+  const startKey = startMonth * 100 + exc.startDay;
+  const endKey = endMonth * 100 + exc.endDay;
+  const key = date.month * 100 + date.day;
+  let startYear: number;
+  let endYear: number;
+  if (startKey <= endKey) {
+    if (key < startKey || key > endKey) return null;
+    startYear = date.year;
+    endYear = date.year;
+  } else if (key >= startKey) {
+    // Wraps into next year
+    startYear = date.year;
+    endYear = date.year + 1;
+  } else if (key <= endKey) {
+    startYear = date.year - 1;
+    endYear = date.year;
+  } else {
+    return null;
+  }
+  return {
+    start: clampedDate(startYear, startMonth, exc.startDay),
+    end: clampedDate(endYear, endMonth, exc.endDay),
+  };
+}
+
+/**
+ * The date for `month day` in `year`, with days past the month's end (feb 29
+ * in a common year) moved back to its last day.
+ */
+function clampedDate(year: number, month: number, day: number): PD {
+  const last = lastDayOfMonth(year, month);
+  return last.with({ day: Math.min(day, last.day) });
+}
+
+/**
+ * The day after the excepted run `date` falls in, so long ranges are skipped
+ * in one step rather than day by day.
+ */
+function skipExceptionsForward(date: PD, ranges: RangeException[]): PD {
+  let end = date;
+  for (const r of ranges) {
+    const bounds = rangeBounds(date, r);
+    if (bounds && Temporal.PlainDate.compare(bounds.end, end) > 0) {
+      end = bounds.end;
+    }
+  }
+  return end.add({ days: 1 });
+}
+
+/** The day before the excepted run `date` falls in. */
+function skipExceptionsBackward(date: PD, ranges: RangeException[]): PD {
+  let start = date;
+  for (const r of ranges) {
+    const bounds = rangeBounds(date, r);
+    if (bounds && Temporal.PlainDate.compare(bounds.start, start) < 0) {
+      start = bounds.start;
+    }
+  }
+  return start.subtract({ days: 1 });
+}
+
+/**
+ * Check if a date falls within any `during` spec. If `during` is empty, all
+ * dates match.
+ */
+function matchesDuring(date: PD, during: DuringSpec[]): boolean {
   if (during.length === 0) return true;
-  return during.some((mn) => monthNumber(mn) === date.month);
+  const month = numberToMonthName(date.month);
+  return during.some((spec) => duringContains(spec, month, date.day));
 }
 
-/** Find the 1st of the next valid `during` month after `date`. */
-function nextDuringMonth(date: PD, during: MonthName[]): PD {
-  const currentMonth = date.month;
-  const months = during.map((mn) => monthNumber(mn)).sort((a, b) => a - b);
+/**
+ * The month-day in `year`, or the day after the month's end when the day
+ * does not exist that year (feb 29 in a common year).
+ */
+function monthDayInYear(year: number, month: MonthName, day: number): PD {
+  const last = lastDayOfMonth(year, monthNumber(month));
+  if (day > last.day) return last.add({ days: 1 });
+  return last.with({ day });
+}
 
-  for (const m of months) {
-    if (m > currentMonth) {
-      return Temporal.PlainDate.from({ year: date.year, month: m, day: 1 });
+/** Find the first day of the next `during` period starting after `date`. */
+function nextDuringStart(date: PD, during: DuringSpec[]): PD {
+  let best: PD | null = null;
+  for (const spec of during) {
+    const { startDay } = duringBounds(spec);
+    for (const year of [date.year, date.year + 1]) {
+      const start = monthDayInYear(year, spec.start, startDay);
+      if (Temporal.PlainDate.compare(start, date) > 0) {
+        if (best === null || Temporal.PlainDate.compare(start, best) < 0) {
+          best = start;
+        }
+        break;
+      }
     }
   }
-  // Wrap to first month of next year
-  return Temporal.PlainDate.from({
-    year: date.year + 1,
-    month: months[0],
-    day: 1,
-  });
+  return best ?? date;
 }
 
 function resolveUntil(until: UntilSpec, now: ZDT): PD {
@@ -374,7 +548,76 @@ function earliestFutureAtTimes(
 
 // --- Public API ---
 
+/**
+ * Pin a relative anchor to a date in the schedule timezone: `starting today`
+ * and `starting now` to `now`'s date, `starting jan 6` to the next jan 6 on or
+ * after it.
+ */
+function resolveAnchor(schedule: ScheduleData, now: ZDT): ScheduleData {
+  if (!hasRelativeAnchor(schedule)) {
+    return schedule;
+  }
+  const tz = resolveTz(schedule.timezone);
+  const today = now.withTimeZone(tz).toPlainDate();
+  const named = schedule.anchorNamed;
+  if (named === null) {
+    return { ...schedule, anchor: today.toString() };
+  }
+  // Try up to 8 years forward (covers leap year cycles)
+  for (let y = 0; y < 8; y++) {
+    try {
+      const d = Temporal.PlainDate.from(
+        {
+          year: today.year + y,
+          month: monthNumber(named.month),
+          day: named.day,
+        },
+        { overflow: "reject" },
+      );
+      if (Temporal.PlainDate.compare(d, today) >= 0) {
+        return { ...schedule, anchor: d.toString(), anchorNamed: null };
+      }
+    } catch {
+      // Invalid date, try next year
+    }
+  }
+  throw HronError.eval(
+    `starting date ${named.month} ${named.day} never occurs`,
+  );
+}
+
+/**
+ * The instant nothing occurs before: the resolved anchor date at the
+ * `starting ... at` time, or at midnight.
+ */
+function anchorStart(schedule: ScheduleData, tz: string): ZDT | null {
+  if (!schedule.anchor) return null;
+  const time = schedule.anchorTime
+    ? toPlainTime(schedule.anchorTime)
+    : MIDNIGHT;
+  return atTimeOnDate(Temporal.PlainDate.from(schedule.anchor), time, tz);
+}
+
+/**
+ * Pin what the schedule resolves against the evaluation time, as seen from
+ * `now`: `on <weekday>` becomes the one date it names (the nearest such
+ * weekday with a time still ahead), and a relative anchor becomes its date.
+ */
+function pinRelative(schedule: ScheduleData, now: ZDT): ScheduleData {
+  schedule = resolveAnchor(schedule, now);
+  const expr = schedule.expr;
+  if (expr.type !== "singleDate" || expr.date.type !== "nextWeekday") {
+    return schedule;
+  }
+  const tz = resolveTz(schedule.timezone);
+  const next = nextSingleDate(expr.date, expr.times, tz, now);
+  if (next === null) return schedule;
+  const date = next.withTimeZone(tz).toPlainDate().toString();
+  return { ...schedule, expr: { ...expr, date: { type: "iso", date } } };
+}
+
 export function nextFrom(schedule: ScheduleData, now: ZDT): ZDT | null {
+  schedule = resolveAnchor(schedule, now);
   const tz = resolveTz(schedule.timezone);
 
   const untilDate = schedule.until ? resolveUntil(schedule.until, now) : null;
@@ -382,6 +625,7 @@ export function nextFrom(schedule: ScheduleData, now: ZDT): ZDT | null {
   const parsedExceptions = parseExceptions(schedule.except);
   const hasExceptions = schedule.except.length > 0;
   const hasDuring = schedule.during.length > 0;
+  const during = schedule.during;
 
   // Check if expression is NearestWeekday with direction (can cross month boundaries)
   const handlesDuringInternally =
@@ -389,14 +633,20 @@ export function nextFrom(schedule: ScheduleData, now: ZDT): ZDT | null {
     schedule.expr.target.type === "nearestWeekday" &&
     schedule.expr.target.direction !== null;
 
+  // Nothing occurs before the starting anchor, so search from its start
   let current = now;
+  const start = anchorStart(schedule, tz);
+  if (start !== null && Temporal.ZonedDateTime.compare(now, start) < 0) {
+    current = start.subtract({ nanoseconds: 1 });
+  }
   for (let i = 0; i < 1000; i++) {
     const candidate = nextExpr(
       schedule.expr,
       tz,
       schedule.anchor,
       current,
-      schedule.during,
+      duringMonths(schedule),
+      schedule.except,
     );
 
     if (candidate === null) return null;
@@ -416,17 +666,18 @@ export function nextFrom(schedule: ScheduleData, now: ZDT): ZDT | null {
     if (
       hasDuring &&
       !handlesDuringInternally &&
-      !matchesDuring(cDate, schedule.during)
+      !matchesDuring(cDate, during)
     ) {
-      // Skip ahead to 1st of next valid during month
-      const skipTo = nextDuringMonth(cDate, schedule.during);
+      // Skip ahead to the start of the next during period
+      const skipTo = nextDuringStart(cDate, during);
       current = atTimeOnDate(skipTo, MIDNIGHT, tz).subtract({ seconds: 1 });
       continue;
     }
 
     // Apply except filter
     if (hasExceptions && isExceptedParsed(cDate, parsedExceptions)) {
-      const nextDay = cDate.add({ days: 1 });
+      // Advance past this day (or the whole excepted range) and retry
+      const nextDay = skipExceptionsForward(cDate, parsedExceptions.ranges);
       current = atTimeOnDate(nextDay, MIDNIGHT, tz).subtract({ seconds: 1 });
       continue;
     }
@@ -443,6 +694,7 @@ function nextExpr(
   anchor: string | null,
   now: ZDT,
   during: MonthName[],
+  exceptions: Exception[],
 ): ZDT | null {
   switch (expr.type) {
     case "dayRepeat":
@@ -455,6 +707,10 @@ function nextExpr(
         now,
       );
     case "intervalRepeat":
+      if (expr.unit === "days") {
+        const { interval, days, times } = dayStepRepeat(expr);
+        return nextDayRepeat(interval, days, times, tz, anchor, now);
+      }
       return nextIntervalRepeat(
         expr.interval,
         expr.unit,
@@ -482,6 +738,7 @@ function nextExpr(
         anchor,
         now,
         during,
+        exceptions,
       );
     case "singleDate":
       return nextSingleDate(expr.date, expr.times, tz, now);
@@ -494,10 +751,15 @@ function nextExpr(
         anchor,
         now,
       );
+    case "isoWeekRepeat":
+      return nextIsoWeekRepeat(expr.weeks, expr.days, expr.times, tz, now);
+    case "weekdayRepeat":
+      return nextWeekdayRepeat(expr.interval, expr.times, tz, anchor, now);
   }
 }
 
 export function nextNFrom(schedule: ScheduleData, now: ZDT, n: number): ZDT[] {
+  schedule = pinRelative(schedule, now);
   const results: ZDT[] = [];
   let current = now;
   for (let i = 0; i < n; i++) {
@@ -510,12 +772,33 @@ export function nextNFrom(schedule: ScheduleData, now: ZDT, n: number): ZDT[] {
 }
 
 export function matches(schedule: ScheduleData, datetime: ZDT): boolean {
+  // There is no evaluation time to resolve a relative anchor against;
+  // resolving it against `datetime` would make every date its own anchor.
+  if (hasRelativeAnchor(schedule)) {
+    const named = schedule.anchorNamed;
+    const anchor = named ? `${named.month} ${named.day}` : schedule.anchor;
+    throw HronError.eval(
+      `matches has no evaluation time to resolve 'starting ${anchor}' against; pin the anchor to a date first`,
+    );
+  }
+  // Nor an `on <weekday>` date: every such weekday would be the upcoming one
+  const expr = schedule.expr;
+  if (expr.type === "singleDate" && expr.date.type === "nextWeekday") {
+    throw HronError.eval(
+      `matches has no evaluation time to resolve 'on ${expr.date.day}' against; use a date instead`,
+    );
+  }
   const tz = resolveTz(schedule.timezone);
   const zdt = datetime.withTimeZone(tz);
   const date = zdt.toPlainDate();
 
   if (!matchesDuring(date, schedule.during)) return false;
   if (isExcepted(date, schedule.except)) return false;
+  // Nothing occurs before the starting anchor
+  const start = anchorStart(schedule, tz);
+  if (start !== null && Temporal.ZonedDateTime.compare(datetime, start) < 0) {
+    return false;
+  }
 
   if (schedule.until) {
     const untilDate = resolveUntil(schedule.until, datetime);
@@ -549,6 +832,14 @@ export function matches(schedule: ScheduleData, datetime: ZDT): boolean {
     case "intervalRepeat": {
       const { interval, unit, from, to, dayFilter } = schedule.expr;
       if (dayFilter && !matchesDayFilter(date, dayFilter)) return false;
+      if (unit === "days") {
+        if (!timeMatchesWithDst([from])) return false;
+        const anchorDate = schedule.anchor
+          ? Temporal.PlainDate.from(schedule.anchor)
+          : EPOCH_DATE;
+        const dayOffset = daysBetween(anchorDate, date);
+        return dayOffset >= 0 && dayOffset % interval === 0;
+      }
       const fromMinutes = from.hour * 60 + from.minute;
       const toMinutes = to.hour * 60 + to.minute;
       const currentMinutes = zdt.hour * 60 + zdt.minute;
@@ -589,29 +880,39 @@ export function matches(schedule: ScheduleData, datetime: ZDT): boolean {
         const last = lastDayOfMonth(date.year, date.month);
         return Temporal.PlainDate.compare(date, last) === 0;
       }
+      if (target.type === "lastNDays") {
+        const last = lastDayOfMonth(date.year, date.month);
+        return date.day > last.day - target.n;
+      }
+      if (target.type === "businessDay") {
+        const d = nthBusinessDay(
+          date.year,
+          date.month,
+          target.n,
+          schedule.except,
+        );
+        return d !== null && Temporal.PlainDate.compare(d, date) === 0;
+      }
       if (target.type === "lastWeekday") {
         const lastWd = lastWeekdayOfMonth(date.year, date.month);
         return Temporal.PlainDate.compare(date, lastWd) === 0;
       }
       if (target.type === "ordinalWeekday") {
-        let targetDate: PD | null;
-        if (target.ordinal === "last") {
-          targetDate = lastWeekdayInMonth(
-            date.year,
-            date.month,
-            target.weekday,
-          );
-        } else {
-          targetDate = nthWeekdayOfMonth(
-            date.year,
-            date.month,
-            target.weekday,
-            ordinalToN(target.ordinal),
-          );
-        }
+        const targetDate = getOrdinalWeekday(
+          date.year,
+          date.month,
+          target.ordinal,
+          target.weekday,
+        );
         if (!targetDate) return false;
         return Temporal.PlainDate.compare(date, targetDate) === 0;
       }
+      if (target.type === "ordinalWeekdays") {
+        return target.ordinals.some((o) => {
+          const d = getOrdinalWeekday(date.year, date.month, o, target.weekday);
+          return d !== null && Temporal.PlainDate.compare(date, d) === 0;
+        });
+      }
       // nearestWeekday
       const targetDate = nearestWeekday(
         date.year,
@@ -635,6 +936,10 @@ export function matches(schedule: ScheduleData, datetime: ZDT): boolean {
           date.day === dateSpec.day
         );
       }
+      // `matches` rejects it up front; any day with that weekday can be it
+      if (dateSpec.type === "nextWeekday") {
+        return date.dayOfWeek === weekdayNumber(dateSpec.day);
+      }
       return false;
     }
     case "yearRepeat": {
@@ -650,6 +955,23 @@ export function matches(schedule: ScheduleData, datetime: ZDT): boolean {
       }
       return matchesYearTarget(schedule.expr.target, date);
     }
+    case "weekdayRepeat": {
+      const anchorDate = schedule.anchor
+        ? Temporal.PlainDate.from(schedule.anchor)
+        : null;
+      if (!weekdayIntervalAligned(anchorDate, schedule.expr.interval, date)) {
+        return false;
+      }
+      return timeMatchesWithDst(schedule.expr.times);
+    }
+    case "isoWeekRepeat": {
+      const { weeks, days, times } = schedule.expr;
+      if (!weeks.includes(isoWeekOf(date).week)) return false;
+      if (!days.some((d) => weekdayNameToNumber(d) === date.dayOfWeek)) {
+        return false;
+      }
+      return timeMatchesWithDst(times);
+    }
   }
 }
 
@@ -718,7 +1040,8 @@ function nextDayRepeat(
     return null;
   }
 
-  // Interval > 1: day intervals only apply to DayFilter::Every
+  // Interval > 1: the filter narrows the aligned days, skipping (not
+  // moving) any that fail it
   const anchorDate = anchor ? Temporal.PlainDate.from(anchor) : EPOCH_DATE;
 
   // Find the next aligned day >= today
@@ -728,8 +1051,10 @@ function nextDayRepeat(
     remainder === 0 ? date : date.add({ days: interval - remainder });
 
   for (let i = 0; i < 400; i++) {
-    const candidate = earliestFutureAtTimes(alignedDate, times, tz, now);
-    if (candidate) return candidate;
+    if (matchesDayFilter(alignedDate, days)) {
+      const candidate = earliestFutureAtTimes(alignedDate, times, tz, now);
+      if (candidate) return candidate;
+    }
     alignedDate = alignedDate.add({ days: interval });
   }
 
@@ -841,6 +1166,7 @@ function nextWeekRepeat(
   return null;
 }
 
+/** Business-day targets skip over days `exceptions` removes. */
 function nextMonthRepeat(
   interval: number,
   target: MonthTarget,
@@ -849,6 +1175,7 @@ function nextMonthRepeat(
   anchor: string | null,
   now: ZDT,
   during: MonthName[],
+  exceptions: Exception[],
 ): ZDT | null {
   const nowInTz = now.withTimeZone(tz);
   let year = nowInTz.year;
@@ -907,23 +1234,27 @@ function nextMonthRepeat(
       }
     } else if (target.type === "lastDay") {
       dateCandidates.push(lastDayOfMonth(year, month));
+    } else if (target.type === "lastNDays") {
+      dateCandidates.push(...lastNDaysOfMonth(year, month, target.n));
+    } else if (target.type === "businessDay") {
+      const businessDay = nthBusinessDay(year, month, target.n, exceptions);
+      if (businessDay) dateCandidates.push(businessDay);
     } else if (target.type === "lastWeekday") {
       dateCandidates.push(lastWeekdayOfMonth(year, month));
     } else if (target.type === "ordinalWeekday") {
-      let owDate: PD | null;
-      if (target.ordinal === "last") {
-        owDate = lastWeekdayInMonth(year, month, target.weekday);
-      } else {
-        owDate = nthWeekdayOfMonth(
-          year,
-          month,
-          target.weekday,
-          ordinalToN(target.ordinal),
-        );
-      }
+      const owDate = getOrdinalWeekday(
+        year,
+        month,
+        target.ordinal,
+        target.weekday,
+      );
       if (owDate) {
         dateCandidates.push(owDate);
       }
+    } else if (target.type === "ordinalWeekdays") {
+      dateCandidates.push(
+        ...ordinalWeekdayDates(year, month, target.ordinals, target.weekday),
+      );
     } else {
       // nearestWeekday
       const nwDate = nearestWeekday(year, month, target.day, target.direction);
@@ -991,6 +1322,18 @@ function nextSingleDate(
     return null;
   }
 
+  if (dateSpec.type === "nextWeekday") {
+    // Today if a time is still ahead, otherwise within the next week
+    const today = nowInTz.toPlainDate();
+    for (let offset = 0; offset < 8; offset++) {
+      const date = today.add({ days: offset });
+      if (date.dayOfWeek !== weekdayNumber(dateSpec.day)) continue;
+      const candidate = earliestFutureAtTimes(date, times, tz, now);
+      if (candidate) return candidate;
+    }
+    return null;
+  }
+
   return null;
 }
 
@@ -1082,19 +1425,108 @@ function nextYearRepeat(
   return null;
 }
 
+// ISO years searched for a matching week; week 53 only exists in long ISO
+// years.
+const ISO_YEAR_HORIZON = 7;
+
+/**
+ * ISO 8601 week-numbering year and week of `date`. Weeks belong to the year
+ * of their Thursday.
+ */
+function isoWeekOf(date: PD): { year: number; week: number } {
+  const thursday = date.add({ days: 4 - date.dayOfWeek });
+  return {
+    year: thursday.year,
+    week: Math.floor((thursday.dayOfYear - 1) / 7) + 1,
+  };
+}
+
+/**
+ * Dates in ISO week-numbering `year` on one of `days` in one of `weeks`,
+ * ascending. Week 53 yields nothing in years with only 52 weeks.
+ */
+function isoWeekDates(year: number, weeks: number[], days: Weekday[]): PD[] {
+  // Jan 4 is always in ISO week 1
+  const jan4 = Temporal.PlainDate.from({ year, month: 1, day: 4 });
+  const week1Monday = jan4.subtract({ days: jan4.dayOfWeek - 1 });
+  const dates: PD[] = [];
+  for (const week of weeks) {
+    for (const wd of days) {
+      const d = week1Monday.add({
+        days: (week - 1) * 7 + weekdayNameToNumber(wd) - 1,
+      });
+      if (isoWeekOf(d).year !== year) continue;
+      if (!dates.some((e) => Temporal.PlainDate.compare(e, d) === 0)) {
+        dates.push(d);
+      }
+    }
+  }
+  return dates.sort(Temporal.PlainDate.compare);
+}
+
+function nextIsoWeekRepeat(
+  weeks: number[],
+  days: Weekday[],
+  times: TimeOfDay[],
+  tz: string,
+  now: ZDT,
+): ZDT | null {
+  const startYear = isoWeekOf(now.withTimeZone(tz).toPlainDate()).year;
+  for (let year = startYear; year < startYear + ISO_YEAR_HORIZON; year++) {
+    for (const d of isoWeekDates(year, weeks, days)) {
+      const candidate = earliestFutureAtTimes(d, times, tz, now);
+      if (candidate) return candidate;
+    }
+  }
+  return null;
+}
+
+/**
+ * Today if it is an aligned business day with a time still ahead, otherwise
+ * the next aligned business day.
+ */
+function nextWeekdayRepeat(
+  interval: number,
+  times: TimeOfDay[],
+  tz: string,
+  anchor: string | null,
+  now: ZDT,
+): ZDT | null {
+  const today = now.withTimeZone(tz).toPlainDate();
+  const anchorIndex = businessDayIndex(
+    anchor ? Temporal.PlainDate.from(anchor) : EPOCH_DATE,
+  );
+  const todayIndex = businessDayIndex(today);
+  const isWeekday = today.dayOfWeek <= 5;
+
+  if (isWeekday && euclideanMod(todayIndex - anchorIndex, interval) === 0) {
+    const candidate = earliestFutureAtTimes(today, times, tz, now);
+    if (candidate) return candidate;
+  }
+  // A weekend already shares the following Monday's index
+  const after = isWeekday ? todayIndex + 1 : todayIndex;
+  const index = after + euclideanMod(anchorIndex - after, interval);
+  return earliestFutureAtTimes(businessDayDate(index), times, tz, now);
+}
+
 /**
  * Compute the most recent occurrence strictly before `now`.
  * Returns null if no previous occurrence exists (e.g., before a starting anchor
- * or for single dates in the future).
+ * or for single dates in the future). With `starting now` nothing occurs before
+ * `now`, so there is never a previous occurrence.
  */
 export function previousFrom(schedule: ScheduleData, now: ZDT): ZDT | null {
+  if (schedule.anchor === "now") return null;
+  schedule = pinRelative(schedule, now);
   const tz = resolveTz(schedule.timezone);
   const anchor = schedule.anchor;
+  const start = anchorStart(schedule, tz);
 
   // Parse exceptions once
   const parsedExceptions = parseExceptions(schedule.except);
   const hasExceptions = schedule.except.length > 0;
   const hasDuring = schedule.during.length > 0;
+  const during = schedule.during;
 
   // Retry loop for exceptions and during filter
   let current = now;
@@ -1106,11 +1538,11 @@ export function previousFrom(schedule: ScheduleData, now: ZDT): ZDT | null {
     const cDate = candidate.withTimeZone(tz).toPlainDate();
 
     // Check starting anchor - if before anchor, no previous occurrence
-    if (anchor) {
-      const anchorDate = Temporal.PlainDate.from(anchor);
-      if (Temporal.PlainDate.compare(cDate, anchorDate) < 0) {
-        return null;
-      }
+    if (
+      start !== null &&
+      Temporal.ZonedDateTime.compare(candidate, start) < 0
+    ) {
+      return null;
     }
 
     // Apply until filter for previousFrom:
@@ -1126,8 +1558,8 @@ export function previousFrom(schedule: ScheduleData, now: ZDT): ZDT | null {
     }
 
     // Apply during filter
-    if (hasDuring && !matchesDuring(cDate, schedule.during)) {
-      const skipTo = prevDuringMonth(cDate, schedule.during);
+    if (hasDuring && !matchesDuring(cDate, during)) {
+      const skipTo = prevDuringEnd(cDate, during);
       const endOfDay = toPlainTime({ hour: 23, minute: 59 });
       current = atTimeOnDate(skipTo, endOfDay, tz).add({
         seconds: 1,
@@ -1137,7 +1569,8 @@ export function previousFrom(schedule: ScheduleData, now: ZDT): ZDT | null {
 
     // Apply except filter
     if (hasExceptions && isExceptedParsed(cDate, parsedExceptions)) {
-      const prevDay = cDate.subtract({ days: 1 });
+      // Go back before this day (or the whole excepted range) and retry
+      const prevDay = skipExceptionsBackward(cDate, parsedExceptions.ranges);
       const endOfDay = toPlainTime({ hour: 23, minute: 59 });
       current = atTimeOnDate(prevDay, endOfDay, tz).add({
         seconds: 1,
@@ -1166,20 +1599,42 @@ function prevExpr(
     case "dayRepeat":
       return prevDayRepeat(expr, tz, anchor, now);
     case "intervalRepeat":
+      if (expr.unit === "days") {
+        return prevDayRepeat(dayStepRepeat(expr), tz, anchor, now);
+      }
       return prevIntervalRepeat(expr, tz, now);
     case "weekRepeat":
       return prevWeekRepeat(expr, tz, anchor, now);
     case "monthRepeat":
-      return prevMonthRepeat(expr, tz, anchor, now);
+      return prevMonthRepeat(expr, tz, anchor, now, schedule.except);
     case "singleDate":
       return prevSingleDate(expr, tz, now);
     case "yearRepeat":
       return prevYearRepeat(expr, tz, anchor, now);
+    case "isoWeekRepeat":
+      return prevIsoWeekRepeat(expr, tz, now);
+    case "weekdayRepeat":
+      return prevWeekdayRepeat(expr, tz, anchor, now);
     default:
       return null;
   }
 }
 
+/**
+ * A day-step interval window fires once, at the window start, on each
+ * aligned day, which is the day repeat with `from` as its only time.
+ */
+function dayStepRepeat(
+  expr: Extract<ScheduleExpr, { type: "intervalRepeat" }>,
+): Extract<ScheduleExpr, { type: "dayRepeat" }> {
+  return {
+    type: "dayRepeat",
+    interval: expr.interval,
+    days: expr.dayFilter ?? { type: "every" },
+    times: [expr.from],
+  };
+}
+
 function prevDayRepeat(
   expr: Extract<ScheduleExpr, { type: "dayRepeat" }>,
   tz: string,
@@ -1214,13 +1669,13 @@ function prevDayRepeat(
   const alignedDate =
     remainder === 0 ? date : date.subtract({ days: remainder });
 
-  for (let i = 0; i < 2; i++) {
+  // Eight aligned dates cover every weekday unless the interval is a
+  // multiple of 7, which the parser rejects when the filter can't match
+  for (let i = 0; i < 8; i++) {
     const checkDate = alignedDate.subtract({ days: i * interval });
-    const candidate = latestPastAtTimes(checkDate, times, tz, now);
-    if (candidate !== null) return candidate;
-    const latest = latestAtTimes(checkDate, times, tz);
-    if (latest !== null && Temporal.ZonedDateTime.compare(latest, now) < 0) {
-      return latest;
+    if (matchesDayFilter(checkDate, days)) {
+      const candidate = latestPastAtTimes(checkDate, times, tz, now);
+      if (candidate !== null) return candidate;
     }
   }
 
@@ -1328,6 +1783,7 @@ function prevMonthRepeat(
   tz: string,
   anchor: string | null,
   now: ZDT,
+  exceptions: Exception[],
 ): ZDT | null {
   const nowInTz = now.withTimeZone(tz);
   const startDate = nowInTz.toPlainDate();
@@ -1352,7 +1808,7 @@ function prevMonthRepeat(
       }
     }
 
-    const targetDates = getMonthTargetDates(year, month, target);
+    const targetDates = getMonthTargetDates(year, month, target, exceptions);
 
     for (const d of targetDates.sort((a, b) =>
       Temporal.PlainDate.compare(b, a),
@@ -1393,6 +1849,9 @@ function prevSingleDate(
       return latestPastAtTimes(targetDate, times, tz, now);
     }
     return latestAtTimes(targetDate, times, tz);
+  } else if (dateSpec.type === "nextWeekday") {
+    // `previousFrom` pins `on <weekday>` to an ISO date first
+    return null;
   } else {
     // Named date - find most recent occurrence
     const { month, day } = dateSpec;
@@ -1469,6 +1928,48 @@ function prevYearRepeat(
   return null;
 }
 
+function prevWeekdayRepeat(
+  expr: Extract<ScheduleExpr, { type: "weekdayRepeat" }>,
+  tz: string,
+  anchor: string | null,
+  now: ZDT,
+): ZDT | null {
+  const { interval, times } = expr;
+  const today = now.withTimeZone(tz).toPlainDate();
+  const anchorIndex = businessDayIndex(
+    anchor ? Temporal.PlainDate.from(anchor) : EPOCH_DATE,
+  );
+  const todayIndex = businessDayIndex(today);
+
+  if (
+    today.dayOfWeek <= 5 &&
+    euclideanMod(todayIndex - anchorIndex, interval) === 0
+  ) {
+    const candidate = latestPastAtTimes(today, times, tz, now);
+    if (candidate !== null) return candidate;
+  }
+  // On a weekend the index is the following Monday's, so one back is Friday
+  const before = todayIndex - 1;
+  const index = before - euclideanMod(before - anchorIndex, interval);
+  return latestAtTimes(businessDayDate(index), times, tz);
+}
+
+function prevIsoWeekRepeat(
+  expr: Extract<ScheduleExpr, { type: "isoWeekRepeat" }>,
+  tz: string,
+  now: ZDT,
+): ZDT | null {
+  const { weeks, days, times } = expr;
+  const startYear = isoWeekOf(now.withTimeZone(tz).toPlainDate()).year;
+  for (let year = startYear; year > startYear - ISO_YEAR_HORIZON; year--) {
+    for (const d of isoWeekDates(year, weeks, days).reverse()) {
+      const candidate = latestPastAtTimes(d, times, tz, now);
+      if (candidate) return candidate;
+    }
+  }
+  return null;
+}
+
 // Helper functions for prev*
 
 function latestPastAtTimes(
@@ -1515,21 +2016,23 @@ function prevMonth(
   return { year, month: month - 1 };
 }
 
-function prevDuringMonth(
-  date: Temporal.PlainDate,
-  during: MonthName[],
-): Temporal.PlainDate {
-  let { year, month } = prevMonth(date.year, date.month);
-
-  for (let i = 0; i < 12; i++) {
-    const monthName = numberToMonthName(month);
-    if (during.includes(monthName)) {
-      return lastDayOfMonth(year, month);
+/** Find the last day of the previous `during` period ending before `date`. */
+function prevDuringEnd(date: PD, during: DuringSpec[]): PD {
+  let best: PD | null = null;
+  for (const spec of during) {
+    const { endDay } = duringBounds(spec);
+    for (const year of [date.year, date.year - 1]) {
+      const last = lastDayOfMonth(year, monthNumber(spec.end));
+      const end = last.with({ day: Math.min(endDay, last.day) });
+      if (Temporal.PlainDate.compare(end, date) < 0) {
+        if (best === null || Temporal.PlainDate.compare(end, best) > 0) {
+          best = end;
+        }
+        break;
+      }
     }
-    ({ year, month } = prevMonth(year, month));
   }
-
-  return date.subtract({ days: 1 });
+  return best ?? date;
 }
 
 function numberToMonthName(n: number): MonthName {
@@ -1554,6 +2057,7 @@ function getMonthTargetDates(
   year: number,
   month: number,
   target: MonthTarget,
+  exceptions: Exception[],
 ): Temporal.PlainDate[] {
   switch (target.type) {
     case "days": {
@@ -1570,6 +2074,12 @@ function getMonthTargetDates(
     }
     case "lastDay":
       return [lastDayOfMonth(year, month)];
+    case "lastNDays":
+      return lastNDaysOfMonth(year, month, target.n);
+    case "businessDay": {
+      const d = nthBusinessDay(year, month, target.n, exceptions);
+      return d ? [d] : [];
+    }
     case "lastWeekday":
       return [lastWeekdayOfMonth(year, month)];
     case "nearestWeekday": {
@@ -1580,6 +2090,8 @@ function getMonthTargetDates(
       const d = getOrdinalWeekday(year, month, target.ordinal, target.weekday);
       return d ? [d] : [];
     }
+    case "ordinalWeekdays":
+      return ordinalWeekdayDates(year, month, target.ordinals, target.weekday);
     default:
       return [];
   }
@@ -1639,6 +2151,23 @@ function getOrdinalWeekday(
   return nthWeekdayOfMonth(year, month, day, ordinalToN(ordinal));
 }
 
+/** The distinct dates of several ordinal weekdays in a month. */
+function ordinalWeekdayDates(
+  year: number,
+  month: number,
+  ordinals: OrdinalPosition[],
+  day: Weekday,
+): Temporal.PlainDate[] {
+  const dates: Temporal.PlainDate[] = [];
+  for (const o of ordinals) {
+    const d = getOrdinalWeekday(year, month, o, day);
+    if (d && !dates.some((x) => Temporal.PlainDate.compare(x, d) === 0)) {
+      dates.push(d);
+    }
+  }
+  return dates;
+}
+
 function dayToNumber(day: Weekday): number {
   const map: Record<Weekday, number> = {
     monday: 1,
@@ -1663,6 +2192,7 @@ export function* occurrences(
   schedule: ScheduleData,
   from: ZDT,
 ): Generator<ZDT, void, unknown> {
+  schedule = pinRelative(schedule, from);
   let current = from;
   for (;;) {
     const next = nextFrom(schedule, current);
@@ -41,7 +41,11 @@ export class Schedule {
     }
   }
 
-  /** Compute the next occurrence after `now`. */
+  /**
+   * Compute the next occurrence after `now`. A `starting` date is both the
+   * interval anchor and a lower bound, so when `now` is before it the result
+   * is on or after it.
+   */
   nextFrom(now: Temporal.ZonedDateTime): Temporal.ZonedDateTime | null {
     return nextFrom(this.data, now);
   }
@@ -56,7 +60,12 @@ export class Schedule {
     return previousFrom(this.data, now);
   }
 
-  /** Check if a datetime matches this schedule. */
+  /**
+   * Check if a datetime matches this schedule.
+   *
+   * Throws for `starting today` / `starting now`, which need an evaluation
+   * time that `matches` does not take.
+   */
   matches(datetime: Temporal.ZonedDateTime): boolean {
     return matches(this.data, datetime);
   }
@@ -109,6 +118,7 @@ export type {
   DateSpec,
   DayFilter,
   DayOfMonthSpec,
+  DuringSpec,
   Exception,
   IntervalUnit,
   MonthName,
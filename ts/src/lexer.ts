@@ -11,6 +11,9 @@ export type TokenKind =
   | { type: "at" }
   | { type: "from" }
   | { type: "to" }
+  | { type: "through" }
+  | { type: "business" }
+  | { type: "past" }
   | { type: "in" }
   | { type: "of" }
   | { type: "the" }
@@ -23,16 +26,25 @@ export type TokenKind =
   | { type: "nearest" }
   | { type: "next" }
   | { type: "previous" }
+  | { type: "between" }
+  | { type: "and" }
+  | { type: "iso" }
   | { type: "day" }
   | { type: "weekday" }
   | { type: "weekend" }
   | { type: "weeks" }
   | { type: "month" }
+  | { type: "frequency"; word: string }
+  | { type: "shortcut"; name: string }
+  | { type: "period"; word: string }
+  | { type: "relative"; word: string }
   | { type: "dayName"; name: string }
   | { type: "monthName"; name: string }
   | { type: "ordinal"; name: string }
   | { type: "intervalUnit"; unit: string }
   | { type: "number"; value: number }
+  | { type: "decimal"; text: string }
+  | { type: "minuteOffset"; minute: number }
   | { type: "ordinalNumber"; value: number }
   | { type: "time"; hour: number; minute: number }
   | { type: "isoDate"; date: string }
@@ -84,6 +96,18 @@ class Lexer {
         continue;
       }
 
+      // Cron shortcut: @daily
+      if (ch === "@") {
+        tokens.push(this.lexShortcut());
+        continue;
+      }
+
+      // Minutes past the hour: :MM
+      if (ch === ":") {
+        tokens.push(this.lexMinuteOffset());
+        continue;
+      }
+
       if (isAlpha(ch)) {
         tokens.push(this.lexWord());
         continue;
@@ -98,6 +122,15 @@ class Lexer {
     return tokens;
   }
 
+  private nextWordIs(word: string): boolean {
+    const rest = this.input.slice(this.pos).trimStart();
+    if (rest.slice(0, word.length).toLowerCase() !== word) {
+      return false;
+    }
+    const after = rest[word.length];
+    return !(after !== undefined && (isAlphanumeric(after) || after === "_"));
+  }
+
   private skipWhitespace(): void {
     while (this.pos < this.input.length && isWhitespace(this.input[this.pos])) {
       this.pos++;
@@ -124,6 +157,39 @@ class Lexer {
     return { kind: { type: "timezone", tz }, span: { start, end: this.pos } };
   }
 
+  private lexShortcut(): Token {
+    const start = this.pos;
+    this.pos++; // skip '@'
+    while (
+      this.pos < this.input.length &&
+      isAlphanumeric(this.input[this.pos])
+    ) {
+      this.pos++;
+    }
+    const name = this.input.slice(start, this.pos).toLowerCase();
+    return { kind: { type: "shortcut", name }, span: { start, end: this.pos } };
+  }
+
+  private lexMinuteOffset(): Token {
+    const start = this.pos;
+    this.pos++; // skip ':'
+    while (this.pos < this.input.length && isDigit(this.input[this.pos])) {
+      this.pos++;
+    }
+    const digits = this.input.slice(start + 1, this.pos);
+    if (digits.length !== 2 || parseInt(digits, 10) > 59) {
+      throw HronError.lex(
+        "expected two-digit minute (:00 to :59) after ':'",
+        { start, end: Math.max(this.pos, start + 1) },
+        this.input,
+      );
+    }
+    return {
+      kind: { type: "minuteOffset", minute: parseInt(digits, 10) },
+      span: { start, end: this.pos },
+    };
+  }
+
   private lexNumberOrTimeOrDate(): Token {
     const start = this.pos;
     const numStart = this.pos;
@@ -171,6 +237,10 @@ class Lexer {
       if (minDigits.length === 2) {
         const hour = parseInt(digits, 10);
         const minute = parseInt(minDigits, 10);
+        const pm = this.lexMeridiem();
+        if (pm !== null) {
+          return this.meridiemTime(hour, minute, pm, start);
+        }
         if (hour > 23 || minute > 59) {
           throw HronError.lex(
             "invalid time",
@@ -185,6 +255,30 @@ class Lexer {
       }
     }
 
+    // Check for 12-hour time without minutes: 9am, 5pm
+    if (digits.length === 1 || digits.length === 2) {
+      const pm = this.lexMeridiem();
+      if (pm !== null) {
+        return this.meridiemTime(parseInt(digits, 10), 0, pm, start);
+      }
+    }
+
+    // Check for decimal: 1.5
+    if (
+      this.pos + 1 < this.input.length &&
+      this.input[this.pos] === "." &&
+      isDigit(this.input[this.pos + 1])
+    ) {
+      this.pos++; // skip '.'
+      while (this.pos < this.input.length && isDigit(this.input[this.pos])) {
+        this.pos++;
+      }
+      return {
+        kind: { type: "decimal", text: this.input.slice(start, this.pos) },
+        span: { start, end: this.pos },
+      };
+    }
+
     const num = parseInt(digits, 10);
     if (Number.isNaN(num)) {
       throw HronError.lex(
@@ -217,24 +311,74 @@ class Lexer {
     };
   }
 
+  /** Consume an am/pm suffix directly following a time, returning whether it was pm. */
+  private lexMeridiem(): boolean | null {
+    const suffix = this.input.slice(this.pos, this.pos + 2).toLowerCase();
+    if (suffix !== "am" && suffix !== "pm") {
+      return null;
+    }
+    // Must not run into a longer word (e.g. "9amx")
+    const after = this.input[this.pos + 2];
+    if (after !== undefined && isAlphanumeric(after)) {
+      return null;
+    }
+    this.pos += 2;
+    return suffix === "pm";
+  }
+
+  /** Convert a 12-hour clock time to a 24-hour time token. */
+  private meridiemTime(
+    hour: number,
+    minute: number,
+    pm: boolean,
+    start: number,
+  ): Token {
+    if (hour < 1 || hour > 12 || minute > 59) {
+      throw HronError.lex(
+        "invalid 12-hour time (hour must be 1-12)",
+        { start, end: this.pos },
+        this.input,
+      );
+    }
+    let h = hour;
+    if (h === 12) {
+      h = pm ? 12 : 0;
+    } else if (pm) {
+      h += 12;
+    }
+    return {
+      kind: { type: "time", hour: h, minute },
+      span: { start, end: this.pos },
+    };
+  }
+
   private lexWord(): Token {
     const start = this.pos;
     while (
       this.pos < this.input.length &&
-      (isAlphanumeric(this.input[this.pos]) || this.input[this.pos] === "_")
+      (isAlphanumeric(this.input[this.pos]) ||
+        this.input[this.pos] === "_" ||
+        // hyphenated ordinals: "twenty-first"
+        (this.input[this.pos] === "-" &&
+          this.pos + 1 < this.input.length &&
+          isAlpha(this.input[this.pos + 1])))
     ) {
       this.pos++;
     }
     const word = this.input.slice(start, this.pos).toLowerCase();
     const span = { start, end: this.pos };
 
-    const kind = KEYWORD_MAP[word];
+    let kind: TokenKind | undefined = KEYWORD_MAP[word];
+    if (kind === undefined && ordinalWordValue(word) !== null) {
+      kind = { type: "ordinal", name: word };
+    }
     if (kind === undefined) {
       throw HronError.lex(`unknown keyword '${word}'`, span, this.input);
     }
 
     if (kind.type === "in") {
-      this.afterIn = true;
+      // "in the morning" and "in iso weeks" are not timezone clauses
+      this.afterIn = !this.nextWordIs("the") && !this.nextWordIs("iso");
     }
 
     return { kind, span };
@@ -247,6 +391,9 @@ const KEYWORD_MAP: Record<string, TokenKind> = {
   at: { type: "at" },
   from: { type: "from" },
   to: { type: "to" },
+  through: { type: "through" },
+  business: { type: "business" },
+  past: { type: "past" },
   in: { type: "in" },
   of: { type: "of" },
   the: { type: "the" },
@@ -260,6 +407,9 @@ const KEYWORD_MAP: Record<string, TokenKind> = {
   nearest: { type: "nearest" },
   next: { type: "next" },
   previous: { type: "previous" },
+  between: { type: "between" },
+  and: { type: "and" },
+  iso: { type: "iso" },
 
   day: { type: "day" },
   days: { type: "day" },
@@ -271,20 +421,38 @@ const KEYWORD_MAP: Record<string, TokenKind> = {
   week: { type: "weeks" },
   month: { type: "month" },
   months: { type: "month" },
+  daily: { type: "frequency", word: "daily" },
+  weekly: { type: "frequency", word: "weekly" },
+  monthly: { type: "frequency", word: "monthly" },
+  yearly: { type: "frequency", word: "yearly" },
+  hourly: { type: "frequency", word: "hourly" },
+  morning: { type: "period", word: "morning" },
+  afternoon: { type: "period", word: "afternoon" },
+  evening: { type: "period", word: "evening" },
+  night: { type: "period", word: "night" },
+  today: { type: "relative", word: "today" },
+  now: { type: "relative", word: "now" },
 
   monday: { type: "dayName", name: "monday" },
+  mondays: { type: "dayName", name: "monday" },
   mon: { type: "dayName", name: "monday" },
   tuesday: { type: "dayName", name: "tuesday" },
+  tuesdays: { type: "dayName", name: "tuesday" },
   tue: { type: "dayName", name: "tuesday" },
   wednesday: { type: "dayName", name: "wednesday" },
+  wednesdays: { type: "dayName", name: "wednesday" },
   wed: { type: "dayName", name: "wednesday" },
   thursday: { type: "dayName", name: "thursday" },
+  thursdays: { type: "dayName", name: "thursday" },
   thu: { type: "dayName", name: "thursday" },
   friday: { type: "dayName", name: "friday" },
+  fridays: { type: "dayName", name: "friday" },
   fri: { type: "dayName", name: "friday" },
   saturday: { type: "dayName", name: "saturday" },
+  saturdays: { type: "dayName", name: "saturday" },
   sat: { type: "dayName", name: "saturday" },
   sunday: { type: "dayName", name: "sunday" },
+  sundays: { type: "dayName", name: "sunday" },
   sun: { type: "dayName", name: "sunday" },
 
   january: { type: "monthName", name: "jan" },
@@ -311,12 +479,6 @@ const KEYWORD_MAP: Record<string, TokenKind> = {
   december: { type: "monthName", name: "dec" },
   dec: { type: "monthName", name: "dec" },
 
-  first: { type: "ordinal", name: "first" },
-  second: { type: "ordinal", name: "second" },
-  third: { type: "ordinal", name: "third" },
-  fourth: { type: "ordinal", name: "fourth" },
-  fifth: { type: "ordinal", name: "fifth" },
-
   min: { type: "intervalUnit", unit: "min" },
   mins: { type: "intervalUnit", unit: "min" },
   minute: { type: "intervalUnit", unit: "min" },
@@ -327,6 +489,44 @@ const KEYWORD_MAP: Record<string, TokenKind> = {
   hrs: { type: "intervalUnit", unit: "hours" },
 };
 
+const ORDINAL_UNITS = [
+  "first",
+  "second",
+  "third",
+  "fourth",
+  "fifth",
+  "sixth",
+  "seventh",
+  "eighth",
+  "ninth",
+];
+const ORDINAL_TEENS = [
+  "tenth",
+  "eleventh",
+  "twelfth",
+  "thirteenth",
+  "fourteenth",
+  "fifteenth",
+  "sixteenth",
+  "seventeenth",
+  "eighteenth",
+  "nineteenth",
+];
+
+/** Day number of an ordinal word: `first` is 1, `thirty-first` is 31. */
+export function ordinalWordValue(word: string): number | null {
+  if (word === "twentieth") return 20;
+  if (word === "thirtieth") return 30;
+  if (word === "thirty-first") return 31;
+  if (word.startsWith("twenty-")) {
+    const unit = ORDINAL_UNITS.indexOf(word.slice("twenty-".length));
+    return unit === -1 ? null : 21 + unit;
+  }
+  if (ORDINAL_UNITS.includes(word)) return 1 + ORDINAL_UNITS.indexOf(word);
+  if (ORDINAL_TEENS.includes(word)) return 10 + ORDINAL_TEENS.indexOf(word);
+  return null;
+}
+
 function isDigit(ch: string): boolean {
   return ch >= "0" && ch <= "9";
 }
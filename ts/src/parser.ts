@@ -1,9 +1,11 @@
 // Hand-rolled recursive descent parser for hron expressions.
 
+import { Temporal } from "@js-temporal/polyfill";
 import type {
   DateSpec,
   DayFilter,
   DayOfMonthSpec,
+  DuringSpec,
   Exception,
   IntervalUnit,
   MonthName,
@@ -17,9 +19,45 @@ import type {
   Weekday,
   YearTarget,
 } from "./ast.js";
-import { newScheduleData, parseMonthName, parseWeekday } from "./ast.js";
+import {
+  ALL_WEEKDAYS,
+  ALL_WEEKEND,
+  canonicalDayFilter,
+  duringBounds,
+  hasRelativeAnchor,
+  monthMaxDay,
+  monthNumber,
+  newScheduleData,
+  parseMonthName,
+  parseWeekday,
+  weekdayFromNumber,
+  weekdayNumber,
+} from "./ast.js";
+import { cronShortcutExpr } from "./cron.js";
 import { HronError, type Span } from "./error.js";
-import { type Token, type TokenKind, tokenize } from "./lexer.js";
+import {
+  ordinalWordValue,
+  type Token,
+  type TokenKind,
+  tokenize,
+} from "./lexer.js";
+
+// Input-only sugar: "in the <period>" fires at a fixed time and displays as
+// HH:MM.
+const PERIOD_TIMES: Record<string, TimeOfDay> = {
+  morning: { hour: 9, minute: 0 },
+  afternoon: { hour: 14, minute: 0 },
+  evening: { hour: 18, minute: 0 },
+  night: { hour: 21, minute: 0 },
+};
+
+const ORDINAL_POSITIONS: Record<string, OrdinalPosition> = {
+  first: "first",
+  second: "second",
+  third: "third",
+  fourth: "fourth",
+  fifth: "fifth",
+};
 
 class Parser {
   private tokens: Token[];
@@ -95,8 +133,18 @@ class Parser {
     } else if (kind?.type === "on") {
       this.advance();
       expr = this.parseOn();
+    } else if (kind?.type === "frequency") {
+      expr = this.parseFrequency();
+    } else if (kind?.type === "shortcut") {
+      expr = this.parseShortcut();
+    } else if (kind?.type === "at") {
+      this.advance();
+      expr = this.parsePastEveryHour();
     } else {
-      throw this.error("expected 'every' or 'on'", span);
+      throw this.error(
+        "expected 'every', 'on', 'at', or a frequency keyword",
+        span,
+      );
     }
 
     return this.parseTrailingClauses(expr);
@@ -126,18 +174,43 @@ class Parser {
         this.validateIsoDate(startDate);
         schedule.anchor = startDate;
         this.advance();
+      } else if (k?.type === "monthName") {
+        const month = parseMonthName(
+          (k as { type: "monthName"; name: string }).name,
+        );
+        if (!month) throw this.error("invalid month name", this.currentSpan());
+        this.advance();
+        const dayPos = this.currentSpan().start;
+        const day = this.parseDayNumber(
+          "expected day number after month name in starting",
+        );
+        this.validateNamedDate(month, day, dayPos);
+        schedule.anchorNamed = { month, day };
+      } else if (k?.type === "relative") {
+        schedule.anchor = (k as { type: "relative"; word: string }).word;
+        this.advance();
       } else {
         throw this.error(
-          "expected ISO date (YYYY-MM-DD) after 'starting'",
+          "expected ISO date (YYYY-MM-DD), month-day, 'today', or 'now' after 'starting'",
           this.currentSpan(),
         );
       }
+      if (this.peekKind()?.type === "at") {
+        if (schedule.anchor === "now") {
+          throw this.error(
+            "'starting now' already includes a time",
+            this.currentSpan(),
+          );
+        }
+        this.advance();
+        schedule.anchorTime = this.parseTime();
+      }
     }
 
     // during
     if (this.peekKind()?.type === "during") {
       this.advance();
-      schedule.during = this.parseMonthList();
+      schedule.during = this.parseDuringList();
     }
 
     // in <timezone>
@@ -152,25 +225,105 @@ class Parser {
       }
     }
 
+    this.checkDayStepsReachable(schedule);
     return schedule;
   }
 
+  /**
+   * Reject a day step whose filter excludes every aligned day. A step that is
+   * a multiple of 7 lands on the anchor's weekday every time, so a filter
+   * without that weekday never fires. With `starting today` or
+   * `starting now` that weekday depends on the evaluation date, so such a step
+   * needs a filter that lets every day through.
+   */
+  private checkDayStepsReachable(schedule: ScheduleData): void {
+    const { expr } = schedule;
+    let filter: DayFilter;
+    if (expr.type === "dayRepeat") {
+      filter = expr.days;
+    } else if (
+      expr.type === "intervalRepeat" &&
+      expr.unit === "days" &&
+      expr.dayFilter
+    ) {
+      filter = expr.dayFilter;
+    } else {
+      return;
+    }
+    if (expr.interval % 7 !== 0) {
+      return;
+    }
+    let allowed: Weekday[];
+    switch (filter.type) {
+      case "every":
+        return;
+      case "weekday":
+        allowed = ALL_WEEKDAYS;
+        break;
+      case "weekend":
+        allowed = ALL_WEEKEND;
+        break;
+      case "days":
+        allowed = filter.days;
+        break;
+    }
+    if (hasRelativeAnchor(schedule)) {
+      if (new Set(allowed).size === 7) {
+        return;
+      }
+      const named = schedule.anchorNamed;
+      const relative = named ? `${named.month} ${named.day}` : schedule.anchor;
+      throw HronError.parse(
+        `every ${expr.interval} days starting ${relative} lands on the weekday of the evaluation date, which the day filter may exclude`,
+        { start: 0, end: this.input.length },
+        this.input,
+      );
+    }
+    const anchor = Temporal.PlainDate.from(schedule.anchor ?? "1970-01-01");
+    const weekday = weekdayFromNumber(anchor.dayOfWeek) as Weekday;
+    if (allowed.includes(weekday)) {
+      return;
+    }
+    throw HronError.parse(
+      `every ${expr.interval} days always lands on a ${weekday}, which the day filter excludes`,
+      { start: 0, end: this.input.length },
+      this.input,
+    );
+  }
+
+  // "except [on] exception, ...": dates, day names, "weekday", "weekend"
   private parseExceptionList(): Exception[] {
-    const exceptions: Exception[] = [this.parseException()];
+    if (this.peekKind()?.type === "on") {
+      this.advance();
+    }
+    const exceptions: Exception[] = this.parseException();
     while (this.peekKind()?.type === "comma") {
       this.advance();
-      exceptions.push(this.parseException());
+      exceptions.push(...this.parseException());
     }
     return exceptions;
   }
 
-  private parseException(): Exception {
+  private parseException(): Exception[] {
     const k = this.peekKind();
     if (k?.type === "isoDate") {
       const date = (k as { type: "isoDate"; date: string }).date;
       this.validateIsoDate(date);
       this.advance();
-      return { type: "iso", date };
+      if (!this.atRangeSeparator()) return [{ type: "iso", date }];
+      this.advance();
+      const span = this.currentSpan();
+      const end = this.peekKind();
+      if (end?.type !== "isoDate") {
+        throw this.error("expected ISO date after 'to'", span);
+      }
+      this.validateIsoDate(end.date);
+      // ISO dates order lexically
+      if (end.date < date) {
+        throw this.error(`exception range ends before ${date}`, span);
+      }
+      this.advance();
+      return [{ type: "isoRange", start: date, end: end.date }];
     }
     if (k?.type === "monthName") {
       const month = parseMonthName(
@@ -183,10 +336,46 @@ class Parser {
         "expected day number after month name in exception",
       );
       this.validateNamedDate(month, day, dayPos);
-      return { type: "named", month, day };
+      if (!this.atRangeSeparator()) return [{ type: "named", month, day }];
+      this.advance();
+      const endK = this.peekKind();
+      const endMonth =
+        endK?.type === "monthName" ? parseMonthName(endK.name) : null;
+      if (!endMonth) {
+        throw this.error("expected month name after 'to'", this.currentSpan());
+      }
+      this.advance();
+      const endPos = this.currentSpan().start;
+      const endDay = this.parseDayNumber(
+        "expected day number after month name in exception",
+      );
+      this.validateNamedDate(endMonth, endDay, endPos);
+      return [
+        {
+          type: "namedRange",
+          startMonth: month,
+          startDay: day,
+          endMonth,
+          endDay,
+        },
+      ];
+    }
+    if (k?.type === "dayName") {
+      const day = parseWeekday(k.name);
+      if (!day) throw this.error("invalid weekday", this.currentSpan());
+      this.advance();
+      return [{ type: "weekday", day }];
+    }
+    if (k?.type === "weekday") {
+      this.advance();
+      return ALL_WEEKDAYS.map((day): Exception => ({ type: "weekday", day }));
+    }
+    if (k?.type === "weekend") {
+      this.advance();
+      return ALL_WEEKEND.map((day): Exception => ({ type: "weekday", day }));
     }
     throw this.error(
-      "expected ISO date or month-day in exception",
+      "expected ISO date, month-day, or day name in exception",
       this.currentSpan(),
     );
   }
@@ -257,6 +446,7 @@ class Parser {
       return this.parseYearRepeat(1);
     }
     if (k.type === "day") {
+      this.advance();
       return this.parseDayRepeat(1, { type: "every" });
     }
     if (k.type === "weekday") {
@@ -269,7 +459,17 @@ class Parser {
     }
     if (k.type === "dayName") {
       const days = this.parseDayList();
-      return this.parseDayRepeat(1, { type: "days", days });
+      // "every monday every 2 weeks at ..." is a week repeat
+      if (this.peekKind()?.type === "every") {
+        this.advance();
+        const interval = this.parseWeekInterval();
+        const times = this.parseTimesOrMidnight();
+        return { type: "weekRepeat", interval, days, times };
+      }
+      return this.parseDayRepeat(
+        1,
+        canonicalDayFilter({ type: "days", days }),
+      );
     }
     if (k.type === "weeks") {
       this.advance();
@@ -279,9 +479,17 @@ class Parser {
       this.advance();
       return this.parseMonthRepeat(1);
     }
+    // "every hour at :15", "every minute from 09:00 to 10:00"
+    if (k.type === "intervalUnit") {
+      return this.parseIntervalRepeat(1);
+    }
     if (k.type === "number") {
       return this.parseNumberRepeat();
     }
+    // "every 1.5 hours from ..."
+    if (k.type === "decimal") {
+      return this.parseFractionalInterval();
+    }
 
     throw this.error(
       "expected day, weekday, weekend, week, year, day name, month, or number after 'every'",
@@ -289,12 +497,80 @@ class Parser {
     );
   }
 
-  private parseDayRepeat(interval: number, days: DayFilter): ScheduleExpr {
-    if (days.type === "every") {
-      this.consumeKind("'day'", (k) => k.type === "day");
+  // Cron `@` shortcut: "@daily" is "every day at 00:00", and takes the same
+  // trailing clauses as that form ("@daily in UTC").
+  private parseShortcut(): ScheduleExpr {
+    const span = this.currentSpan();
+    const k = this.peekKind() as { type: "shortcut"; name: string };
+    this.advance();
+    const expr = cronShortcutExpr(k.name);
+    if (!expr) {
+      throw HronError.parse(
+        `unknown @ shortcut: ${k.name}`,
+        span,
+        this.input,
+        "@yearly, @monthly, @weekly, @daily, @midnight, or @hourly",
+      );
     }
-    this.consumeKind("'at'", (k) => k.type === "at");
-    const times = this.parseTimeList();
+    return expr;
+  }
+
+  // Bare shorthand: "daily", "weekly", "monthly", "yearly", or "hourly". Each
+  // maps to the canonical "every ..." form with interval 1 and accepts what
+  // that form does. Without "on", weekly falls on monday (the default week
+  // start), monthly on the 1st, and yearly on jan 1; without a window, hourly
+  // runs all day.
+  private parseFrequency(): ScheduleExpr {
+    const k = this.peekKind() as { type: "frequency"; word: string };
+    this.advance();
+
+    const next = this.peekKind()?.type;
+    const hasOn = next === "on";
+    switch (k.word) {
+      case "daily":
+        return this.parseDayRepeat(1, { type: "every" });
+      case "weekly":
+        if (hasOn) return this.parseWeekRepeat(1);
+        return {
+          type: "weekRepeat",
+          interval: 1,
+          days: ["monday"],
+          times: this.parseTimesOrMidnight(),
+        };
+      case "monthly":
+        if (hasOn) return this.parseMonthRepeat(1);
+        return {
+          type: "monthRepeat",
+          interval: 1,
+          target: { type: "days", specs: [{ type: "single", day: 1 }] },
+          times: this.parseTimesOrMidnight(),
+        };
+      case "yearly":
+        // "yearly in iso weeks ..." as well as "yearly on ..."
+        if (
+          hasOn ||
+          (next === "in" && this.tokens[this.pos + 1]?.kind.type === "iso")
+        ) {
+          return this.parseYearRepeat(1);
+        }
+        return {
+          type: "yearRepeat",
+          interval: 1,
+          target: { type: "date", month: "jan", day: 1 },
+          times: this.parseTimesOrMidnight(),
+        };
+      default:
+        // "hourly at :15" and "hourly from 09:00 to 17:00" as after
+        // "every hour"
+        if (next === "at" || next === "from" || next === "between") {
+          return this.parseIntervalWindow(1, "hours");
+        }
+        return hourlyAt(1, 0, this.parseOptionalDayFilter());
+    }
+  }
+
+  private parseDayRepeat(interval: number, days: DayFilter): ScheduleExpr {
+    const times = this.parseTimesOrMidnight();
     return { type: "dayRepeat", interval, days, times };
   }
 
@@ -316,8 +592,27 @@ class Parser {
     if (next?.type === "intervalUnit") {
       return this.parseIntervalRepeat(num);
     }
+    // "every N days from HH:MM to HH:MM" — day-step interval window
+    if (next?.type === "day" && this.nextIsWindow()) {
+      this.advance();
+      return this.parseIntervalWindow(num, "days");
+    }
+    // "every N days [on day_target] at ..."
     if (next?.type === "day") {
-      return this.parseDayRepeat(num, { type: "every" });
+      this.advance();
+      let days: DayFilter = { type: "every" };
+      if (this.peekKind()?.type === "on") {
+        this.advance();
+        days = this.parseDayTarget();
+      }
+      return this.parseDayRepeat(num, days);
+    }
+    // "every N weekdays at ..." — every Nth business day
+    if (next?.type === "weekday") {
+      this.advance();
+      if (num === 1) return this.parseDayRepeat(1, { type: "weekday" });
+      const times = this.parseTimesOrMidnight();
+      return { type: "weekdayRepeat", interval: num, times };
     }
     if (next?.type === "month") {
       this.advance();
@@ -329,7 +624,7 @@ class Parser {
     }
 
     throw this.error(
-      "expected 'weeks', 'min', 'minutes', 'hour', 'hours', 'day(s)', 'month(s)', or 'year(s)' after number",
+      "expected 'weeks', 'min', 'minutes', 'hour', 'hours', 'day(s)', 'weekday(s)', 'month(s)', or 'year(s)' after number",
       this.currentSpan(),
     );
   }
@@ -341,26 +636,134 @@ class Parser {
     this.advance();
 
     const unit: IntervalUnit = unitStr === "min" ? "min" : "hours";
+    return this.parseIntervalWindow(interval, unit);
+  }
 
-    this.consumeKind("'from'", (k) => k.type === "from");
-    const from = this.parseTime();
-    this.consumeKind("'to'", (k) => k.type === "to");
-    const to = this.parseTime();
+  // "every D hours ..." where D is a decimal: converted to whole minutes, so
+  // "every 1.5 hours" is the same schedule as "every 90 min"
+  private parseFractionalInterval(): ScheduleExpr {
+    const span = this.currentSpan();
+    const k = this.peekKind();
+    if (k?.type !== "decimal") throw this.error("expected number", span);
+    this.advance();
 
-    let dayFilter: DayFilter | null = null;
-    if (this.peekKind()?.type === "on") {
+    const next = this.peekKind();
+    if (next?.type !== "intervalUnit" || next.unit === "min") {
+      throw this.error(
+        "fractional intervals are only supported in hours",
+        span,
+      );
+    }
+    this.advance();
+
+    const minutes = fractionalHoursToMinutes(k.text);
+    if (minutes === null) {
+      throw this.error(
+        "fractional hours must come to a whole number of minutes",
+        span,
+      );
+    }
+    if (minutes === 0) {
+      throw this.error("interval must be at least 1", span);
+    }
+
+    if (minutes % 60 === 0) {
+      return this.parseIntervalWindow(minutes / 60, "hours");
+    }
+    return this.parseIntervalWindow(minutes, "min");
+  }
+
+  // The window and optional day filter after the interval unit
+  private parseIntervalWindow(
+    interval: number,
+    unit: IntervalUnit,
+  ): ScheduleExpr {
+    // "every 2 hours at :15": all day, starting MM past midnight
+    if (unit === "hours" && this.peekKind()?.type === "at") {
+      this.advance();
+      const minute = this.parseMinutePast();
+      return hourlyAt(interval, minute, this.parseOptionalDayFilter());
+    }
+
+    // "from HH:MM to HH:MM" or "between HH:MM and HH:MM"
+    let from: TimeOfDay;
+    let to: TimeOfDay;
+    if (this.peekKind()?.type === "between") {
       this.advance();
-      dayFilter = this.parseDayTarget();
+      from = this.parseTime();
+      this.consumeKind("'and'", (k) => k.type === "and");
+      to = this.parseTime();
+    } else {
+      this.consumeKind("'from' or 'between'", (k) => k.type === "from");
+      from = this.parseTime();
+      this.consumeKind("'to'", (k) => k.type === "to");
+      to = this.parseTime();
     }
 
+    const dayFilter = this.parseOptionalDayFilter();
     return { type: "intervalRepeat", interval, unit, from, to, dayFilter };
   }
 
+  // Optional "on day_target" after an interval window
+  private parseOptionalDayFilter(): DayFilter | null {
+    if (this.peekKind()?.type === "on") {
+      this.advance();
+      return this.parseDayTarget();
+    }
+    return null;
+  }
+
+  // "at 15 past every hour", "at :15 past every hour": "at" already consumed
+  private parsePastEveryHour(): ScheduleExpr {
+    const minute = this.parseMinutePast(true);
+    this.consumeKind("'past'", (k) => k.type === "past");
+    this.consumeKind("'every'", (k) => k.type === "every");
+    const k = this.peekKind();
+    if (k?.type !== "intervalUnit" || k.unit !== "hours") {
+      throw this.error("expected 'hour'", this.currentSpan());
+    }
+    this.advance();
+    return hourlyAt(1, minute, this.parseOptionalDayFilter());
+  }
+
+  // Minutes past the hour, as ":MM" or (with `allowNumber`) a bare number
+  private parseMinutePast(allowNumber = false): number {
+    const k = this.peekKind();
+    let minute: number;
+    if (k?.type === "minuteOffset") {
+      minute = k.minute;
+    } else if (allowNumber && k?.type === "number" && k.value <= 59) {
+      minute = k.value;
+    } else {
+      throw this.error(
+        "expected minutes past the hour (e.g. :15)",
+        this.currentSpan(),
+      );
+    }
+    this.advance();
+    return minute;
+  }
+
+  // "week" or "N weeks", after a day list and a second "every"
+  private parseWeekInterval(): number {
+    const span = this.currentSpan();
+    const k = this.peekKind();
+    let interval = 1;
+    if (k?.type === "number") {
+      if (k.value === 0) {
+        throw this.error("interval must be at least 1", span);
+      }
+      interval = k.value;
+      this.advance();
+    }
+    this.consumeKind("'week' or 'weeks'", (k) => k.type === "weeks");
+    return interval;
+  }
+
   private parseWeekRepeat(interval: number): ScheduleExpr {
     this.consumeKind("'on'", (k) => k.type === "on");
     const days = this.parseDayList();
-    this.consumeKind("'at'", (k) => k.type === "at");
-    const times = this.parseTimeList();
+    const times = this.parseTimesOrMidnight();
     return { type: "weekRepeat", interval, days, times };
   }
 
@@ -371,45 +774,47 @@ class Parser {
     let target: MonthTarget;
     const k = this.peekKind();
 
-    if (k?.type === "last") {
+    // "the first monday", "the first and last friday", as opposed to
+    // "the first" (day 1)
+    if (
+      (k?.type === "ordinal" || k?.type === "last") &&
+      this.ordinalWeekdaysAhead()
+    ) {
+      target = this.parseOrdinalWeekdays();
+    } else if (k?.type === "last") {
       this.advance();
       const next = this.peekKind();
       if (next?.type === "day") {
         this.advance();
         target = { type: "lastDay" };
+      } else if (next?.type === "number") {
+        // "last N days"; "last 1 day" is the last day
+        const n = next.value;
+        if (n < 1 || n > 31) {
+          throw this.error(
+            `invalid day count: ${n} (must be 1-31)`,
+            this.currentSpan(),
+          );
+        }
+        this.advance();
+        this.consumeKind("'days'", (k) => k.type === "day");
+        target = n === 1 ? { type: "lastDay" } : { type: "lastNDays", n };
       } else if (next?.type === "weekday") {
         this.advance();
         target = { type: "lastWeekday" };
-      } else if (next?.type === "dayName") {
-        const weekday = parseWeekday(
-          (next as { type: "dayName"; name: string }).name,
-        );
-        if (!weekday) throw this.error("invalid weekday", this.currentSpan());
-        this.advance();
-        target = { type: "ordinalWeekday", ordinal: "last", weekday };
       } else {
         throw this.error(
           "expected 'day', 'weekday', or day name after 'last'",
           this.currentSpan(),
         );
       }
-    } else if (k?.type === "ordinal") {
-      const ordinal = this.parseOrdinalPosition();
-      const next = this.peekKind();
-      if (next?.type === "dayName") {
-        const weekday = parseWeekday(
-          (next as { type: "dayName"; name: string }).name,
-        );
-        if (!weekday) throw this.error("invalid weekday", this.currentSpan());
-        this.advance();
-        target = { type: "ordinalWeekday", ordinal, weekday };
-      } else {
-        throw this.error(
-          "expected day name after ordinal in month expression",
-          this.currentSpan(),
-        );
-      }
-    } else if (k?.type === "ordinalNumber") {
+    } else if (
+      // "the first weekday", "the 3rd business day"
+      (k?.type === "ordinal" || k?.type === "ordinalNumber") &&
+      this.nextIsBusinessDay()
+    ) {
+      target = this.parseBusinessDayTarget();
+    } else if (k?.type === "ordinal" || k?.type === "ordinalNumber") {
       const specs = this.parseOrdinalDayList();
       target = { type: "days", specs };
     } else if (
@@ -425,11 +830,66 @@ class Parser {
       );
     }
 
-    this.consumeKind("'at'", (k) => k.type === "at");
-    const times = this.parseTimeList();
+    const times = this.parseTimes();
     return { type: "monthRepeat", interval, target, times };
   }
 
+  // Whether a list of ordinals joined by "," or "and" ends in a day name
+  private ordinalWeekdaysAhead(): boolean {
+    let i = this.pos;
+    while (true) {
+      const ordinal = this.tokens[i]?.kind.type;
+      if (ordinal !== "ordinal" && ordinal !== "last") return false;
+      i++;
+      const next = this.tokens[i]?.kind.type;
+      if (next === "dayName") return true;
+      if (next !== "comma" && next !== "and") return false;
+      i++;
+    }
+  }
+
+  private parseOrdinalWeekdays(): MonthTarget {
+    const ordinals = [this.parseOrdinalPosition()];
+    while (
+      this.peekKind()?.type === "comma" ||
+      this.peekKind()?.type === "and"
+    ) {
+      this.advance();
+      ordinals.push(this.parseOrdinalPosition());
+    }
+    const weekday = this.parseDayName(
+      "expected day name after ordinal in monthly expression",
+    );
+    const positions: OrdinalPosition[] = [
+      ...Object.values(ORDINAL_POSITIONS),
+      "last",
+    ];
+    const sorted = [...new Set(ordinals)].sort(
+      (a, b) => positions.indexOf(a) - positions.indexOf(b),
+    );
+    if (sorted.length === 1) {
+      return { type: "ordinalWeekday", ordinal: sorted[0], weekday };
+    }
+    return { type: "ordinalWeekdays", ordinals: sorted, weekday };
+  }
+
+  // <ordinal> (weekday | business day)
+  private parseBusinessDayTarget(): MonthTarget {
+    const span = this.currentSpan();
+    const n = this.parseOrdinalDay("expected ordinal before 'business day'");
+    // No month has more than 23 weekdays
+    if (n > 23) {
+      throw this.error(`invalid business day: ${n} (must be 1-23)`, span);
+    }
+    if (this.peekKind()?.type === "business") {
+      this.advance();
+      this.consumeKind("'day'", (k) => k.type === "day");
+    } else {
+      this.consumeKind("'weekday'", (k) => k.type === "weekday");
+    }
+    return { type: "businessDay", n };
+  }
+
   // [next|previous] nearest weekday to <day>
   private parseNearestWeekdayTarget(): MonthTarget {
     // Optional direction: "next" or "previous"
@@ -454,22 +914,60 @@ class Parser {
   }
 
   private parseOrdinalDayNumber(): number {
+    return this.parseOrdinalDay("expected ordinal day number");
+  }
+
+  /** Parse an ordinal day of month, as digits (21st) or a word (twentieth). */
+  private parseOrdinalDay(expected: string): number {
     const k = this.peekKind();
+    let n: number | null = null;
     if (k?.type === "ordinalNumber") {
-      const d = (k as { type: "ordinalNumber"; value: number }).value;
-      if (d < 1 || d > 31) {
-        throw this.error(
-          `invalid day number ${d} (must be 1-31)`,
-          this.currentSpan(),
-        );
-      }
-      this.advance();
-      return d;
+      n = k.value;
+    } else if (k?.type === "ordinal") {
+      n = ordinalWordValue(k.name);
+    }
+    if (n === null) {
+      throw this.error(expected, this.currentSpan());
+    }
+    if (n < 1 || n > 31) {
+      throw this.error(
+        `invalid day number ${n} (must be 1-31)`,
+        this.currentSpan(),
+      );
+    }
+    this.advance();
+    return n;
+  }
+
+  private nextIsDayName(): boolean {
+    return this.tokens[this.pos + 1]?.kind.type === "dayName";
+  }
+
+  private nextIsWindow(): boolean {
+    const next = this.tokens[this.pos + 1]?.kind.type;
+    return next === "from" || next === "between";
+  }
+
+  private nextIsBusinessDay(): boolean {
+    const next = this.tokens[this.pos + 1]?.kind.type;
+    return next === "business" || next === "weekday";
+  }
+
+  private parseWeekdayToken(): Weekday {
+    const k = this.peekKind();
+    if (k?.type !== "dayName") {
+      throw this.error("expected day name", this.currentSpan());
     }
-    throw this.error("expected ordinal day number", this.currentSpan());
+    const weekday = parseWeekday(k.name);
+    if (!weekday) throw this.error("invalid weekday", this.currentSpan());
+    this.advance();
+    return weekday;
   }
 
   private parseYearRepeat(interval: number): ScheduleExpr {
+    if (this.peekKind()?.type === "in") {
+      return this.parseIsoWeekRepeat(interval);
+    }
     this.consumeKind("'on'", (k) => k.type === "on");
 
     let target: YearTarget;
@@ -495,11 +993,47 @@ class Parser {
       );
     }
 
-    this.consumeKind("'at'", (k) => k.type === "at");
-    const times = this.parseTimeList();
+    const times = this.parseTimes();
     return { type: "yearRepeat", interval, target, times };
   }
 
+  // iso_week_repeat: "every year in iso week[s] N[, N] on day_list [at HH:MM]"
+  private parseIsoWeekRepeat(interval: number): ScheduleExpr {
+    if (interval !== 1) {
+      throw this.error(
+        "ISO week schedules repeat every year; remove the interval",
+        this.currentSpan(),
+      );
+    }
+    this.consumeKind("'in'", (k) => k.type === "in");
+    this.consumeKind("'iso'", (k) => k.type === "iso");
+    this.consumeKind("'weeks'", (k) => k.type === "weeks");
+
+    const weeks = [this.parseIsoWeekNumber()];
+    while (this.peekKind()?.type === "comma") {
+      this.advance();
+      weeks.push(this.parseIsoWeekNumber());
+    }
+
+    this.consumeKind("'on'", (k) => k.type === "on");
+    const days = this.parseDayList();
+    const times = this.parseTimesOrMidnight();
+    return { type: "isoWeekRepeat", weeks, days, times };
+  }
+
+  private parseIsoWeekNumber(): number {
+    const span = this.currentSpan();
+    const k = this.peekKind();
+    if (k?.type !== "number") {
+      throw this.error("expected ISO week number", span);
+    }
+    if (k.value < 1 || k.value > 53) {
+      throw this.error(`invalid ISO week ${k.value} (expected 1-53)`, span);
+    }
+    this.advance();
+    return k.value;
+  }
+
   private parseYearTargetAfterThe(): YearTarget {
     const k = this.peekKind();
 
@@ -528,35 +1062,18 @@ class Parser {
       );
     }
 
-    if (k?.type === "ordinal") {
+    // "the first monday of march" or "the 15th of march"
+    if (k?.type === "ordinal" && this.nextIsDayName()) {
       const ordinal = this.parseOrdinalPosition();
-      const next = this.peekKind();
-      if (next?.type === "dayName") {
-        const weekday = parseWeekday(
-          (next as { type: "dayName"; name: string }).name,
-        );
-        if (!weekday) throw this.error("invalid weekday", this.currentSpan());
-        this.advance();
-        this.consumeKind("'of'", (k) => k.type === "of");
-        const month = this.parseMonthNameToken();
-        return { type: "ordinalWeekday", ordinal, weekday, month };
-      }
-      throw this.error(
-        "expected day name after ordinal in yearly expression",
-        this.currentSpan(),
-      );
+      const weekday = this.parseWeekdayToken();
+      this.consumeKind("'of'", (k) => k.type === "of");
+      const month = this.parseMonthNameToken();
+      return { type: "ordinalWeekday", ordinal, weekday, month };
     }
 
-    if (k?.type === "ordinalNumber") {
-      const day = (k as { type: "ordinalNumber"; value: number }).value;
-      if (day < 1 || day > 31) {
-        throw this.error(
-          `invalid day number ${day} (must be 1-31)`,
-          this.currentSpan(),
-        );
-      }
+    if (k?.type === "ordinal" || k?.type === "ordinalNumber") {
       const dayPos = this.currentSpan().start;
-      this.advance();
+      const day = this.parseOrdinalDay("expected ordinal day number");
       this.consumeKind("'of'", (k) => k.type === "of");
       const month = this.parseMonthNameToken();
       this.validateNamedDate(month, day, dayPos);
@@ -587,9 +1104,10 @@ class Parser {
     const k = this.peekKind();
 
     if (k?.type === "ordinal") {
-      const name = (k as { type: "ordinal"; name: string }).name;
+      const position = ORDINAL_POSITIONS[k.name];
+      if (!position) throw this.error(`unknown ordinal '${k.name}'`, span);
       this.advance();
-      return name as OrdinalPosition;
+      return position;
     }
     if (k?.type === "last") {
       this.advance();
@@ -602,28 +1120,26 @@ class Parser {
   }
 
   private parseOn(): ScheduleExpr {
+    // "on friday at 17:00": the nearest upcoming friday, a rolling one-shot
+    if (this.peekKind()?.type === "dayName") {
+      const day = this.parseWeekdayToken();
+      if (this.peekKind()?.type === "comma") {
+        throw this.error(
+          "a weekday cannot be combined with other dates; use 'every' for recurring days",
+          this.currentSpan(),
+        );
+      }
+      const times = this.parseTimes();
+      return { type: "singleDate", date: { type: "nextWeekday", day }, times };
+    }
+
     const date = this.parseDateTarget();
-    this.consumeKind("'at'", (k) => k.type === "at");
-    const times = this.parseTimeList();
+    const times = this.parseTimes();
     return { type: "singleDate", date, times };
   }
 
   private validateNamedDate(month: MonthName, day: number, pos: number): void {
-    const maxDays: Record<MonthName, number> = {
-      jan: 31,
-      feb: 29,
-      mar: 31,
-      apr: 30,
-      may: 31,
-      jun: 30,
-      jul: 31,
-      aug: 31,
-      sep: 30,
-      oct: 31,
-      nov: 30,
-      dec: 31,
-    };
-    const max = maxDays[month];
+    const max = monthMaxDay(month);
     if (day > max) {
       throw this.error(`invalid day ${day} for ${month} (max ${max})`, {
         start: pos,
@@ -691,7 +1207,7 @@ class Parser {
     }
     if (k?.type === "dayName") {
       const days = this.parseDayList();
-      return { type: "days", days };
+      return canonicalDayFilter({ type: "days", days });
     }
     throw this.error(
       "expected 'day', 'weekday', 'weekend', or day name",
@@ -700,73 +1216,71 @@ class Parser {
   }
 
   private parseDayList(): Weekday[] {
+    const days = this.parseDayRange("expected day name");
+    while (this.peekKind()?.type === "comma") {
+      this.advance();
+      days.push(...this.parseDayRange("expected day name after ','"));
+    }
+    return days;
+  }
+
+  // A day name or a range of them: "monday to friday", "fri through mon"
+  // (wraps past sunday)
+  private parseDayRange(expected: string): Weekday[] {
+    const start = this.parseDayName(expected);
+    if (!this.atRangeSeparator()) return [start];
+    this.advance();
+    const end = this.parseDayName("expected day name after 'to'");
+    const startNum = weekdayNumber(start);
+    const length = ((weekdayNumber(end) + 7 - startNum) % 7) + 1;
+    const days: Weekday[] = [];
+    for (let i = 0; i < length; i++) {
+      const day = weekdayFromNumber(((startNum - 1 + i) % 7) + 1);
+      if (day) days.push(day);
+    }
+    return days;
+  }
+
+  private parseDayName(expected: string): Weekday {
     const k = this.peekKind();
     if (k?.type !== "dayName") {
-      throw this.error("expected day name", this.currentSpan());
+      throw this.error(expected, this.currentSpan());
     }
-    const firstDay = parseWeekday(
-      (k as { type: "dayName"; name: string }).name,
-    );
-    if (!firstDay) throw this.error("invalid weekday", this.currentSpan());
-    const days: Weekday[] = [firstDay];
+    const day = parseWeekday(k.name);
+    if (!day) throw this.error("invalid weekday", this.currentSpan());
     this.advance();
+    return day;
+  }
 
-    while (this.peekKind()?.type === "comma") {
-      this.advance();
-      const next = this.peekKind();
-      if (next?.type !== "dayName") {
-        throw this.error("expected day name after ','", this.currentSpan());
-      }
-      const day = parseWeekday(
-        (next as { type: "dayName"; name: string }).name,
-      );
-      if (!day) throw this.error("invalid weekday", this.currentSpan());
-      days.push(day);
-      this.advance();
-    }
-    return days;
+  /**
+   * Whether the next token separates a range: `to`, or its synonym `through`.
+   * `through` is only accepted in ranges, never in `from ... to ...` windows.
+   */
+  private atRangeSeparator(): boolean {
+    const k = this.peekKind();
+    return k?.type === "to" || k?.type === "through";
   }
 
+  // Day specs are sorted and de-duplicated: "the 15th, 1st, 15th" is
+  // "the 1st, 15th"
   private parseOrdinalDayList(): DayOfMonthSpec[] {
     const specs: DayOfMonthSpec[] = [this.parseOrdinalDaySpec()];
     while (this.peekKind()?.type === "comma") {
       this.advance();
       specs.push(this.parseOrdinalDaySpec());
     }
-    return specs;
+    return sortedUnique(specs, daySpecKey);
   }
 
   private parseOrdinalDaySpec(): DayOfMonthSpec {
-    const k = this.peekKind();
-    if (k?.type !== "ordinalNumber") {
-      throw this.error("expected ordinal day number", this.currentSpan());
-    }
-    const start = (k as { type: "ordinalNumber"; value: number }).value;
-    if (start < 1 || start > 31) {
-      throw this.error(
-        `invalid day number ${start} (must be 1-31)`,
-        this.currentSpan(),
-      );
-    }
-    this.advance();
+    const start = this.parseOrdinalDay("expected ordinal day number");
 
-    if (this.peekKind()?.type === "to") {
-      this.advance();
-      const next = this.peekKind();
-      if (next?.type !== "ordinalNumber") {
-        throw this.error(
-          "expected ordinal day number after 'to'",
-          this.currentSpan(),
-        );
-      }
-      const end = (next as { type: "ordinalNumber"; value: number }).value;
-      if (end < 1 || end > 31) {
-        throw this.error(
-          `invalid day number ${end} (must be 1-31)`,
-          this.currentSpan(),
-        );
-      }
+    // "1st to 15th", "1st through 15th"
+    if (this.atRangeSeparator()) {
       this.advance();
+      const end = this.parseOrdinalDay(
+        "expected ordinal day number after 'to'",
+      );
       if (start > end) {
         throw this.error(
           `invalid day range: ${start} to ${end} (start must be <= end)`,
@@ -779,22 +1293,102 @@ class Parser {
     return { type: "single", day: start };
   }
 
-  private parseMonthList(): MonthName[] {
-    const months: MonthName[] = [this.parseMonthNameToken()];
+  // Specs are sorted by where they start and de-duplicated: "during mar,
+  // jan, mar" is "during jan, mar". Overlapping specs are not merged.
+  private parseDuringList(): DuringSpec[] {
+    const specs: DuringSpec[] = [this.parseDuringSpec()];
     while (this.peekKind()?.type === "comma") {
       this.advance();
-      months.push(this.parseMonthNameToken());
+      specs.push(this.parseDuringSpec());
     }
-    return months;
+    return sortedUnique(specs, duringSpecKey);
   }
 
+  // during_spec: "jun", "jun to aug" (wraps past december: "nov to feb"),
+  // or month-day bounds "jun 15 to aug 31". A bound without a day covers
+  // its whole month.
+  private parseDuringSpec(): DuringSpec {
+    const start = this.parseMonthNameToken();
+    const startDay = this.parseDuringDay(start);
+    if (!this.atRangeSeparator()) {
+      // "during jun 15" is that one day
+      return { start, end: start, startDay, endDay: startDay };
+    }
+    this.advance();
+    const end = this.parseMonthNameToken();
+    const endDay = this.parseDuringDay(end);
+    if (startDay === null && endDay === null) {
+      return { start, end, startDay: null, endDay: null };
+    }
+    return {
+      start,
+      end,
+      startDay: startDay ?? 1,
+      endDay: endDay ?? monthMaxDay(end),
+    };
+  }
+
+  /** Optional day after a month name in `during`, validated for the month. */
+  private parseDuringDay(month: MonthName): number | null {
+    const k = this.peekKind();
+    if (k?.type !== "number" && k?.type !== "ordinalNumber") {
+      return null;
+    }
+    const dayPos = this.currentSpan().start;
+    const day = this.parseDayNumber(
+      "expected day number after month name in during",
+    );
+    this.validateNamedDate(month, day, dayPos);
+    return day;
+  }
+
+  // times: "at HH:MM, ..." or "in the morning|afternoon|evening|night"
+  private parseTimes(): TimeOfDay[] {
+    if (this.peekKind()?.type === "in") {
+      this.advance();
+      this.consumeKind("'the'", (k) => k.type === "the");
+      const k = this.peekKind();
+      if (k?.type !== "period") {
+        throw this.error(
+          "expected morning, afternoon, evening, or night",
+          this.currentSpan(),
+        );
+      }
+      this.advance();
+      return [PERIOD_TIMES[k.word]];
+    }
+    this.consumeKind("'at'", (k) => k.type === "at");
+    return this.parseTimeList();
+  }
+
+  // Day and week repeats may omit times: "every day", "every weekday in UTC"
+  // fire at 00:00. A bare "in" starts the timezone clause, not "in the ...".
+  private parseTimesOrMidnight(): TimeOfDay[] {
+    const k = this.peekKind();
+    const hasTimes =
+      k?.type === "at" ||
+      (k?.type === "in" && this.tokens[this.pos + 1]?.kind.type === "the");
+    if (hasTimes) {
+      return this.parseTimes();
+    }
+    return [{ hour: 0, minute: 0 }];
+  }
+
+  // Times are sorted and de-duplicated: "at 17:00, 09:00, 09:00" is
+  // "at 09:00, 17:00"
   private parseTimeList(): TimeOfDay[] {
     const times: TimeOfDay[] = [this.parseTime()];
     while (this.peekKind()?.type === "comma") {
       this.advance();
       times.push(this.parseTime());
     }
-    return times;
+    times.sort((a, b) => a.hour - b.hour || a.minute - b.minute);
+    return times.filter(
+      (t, i) =>
+        i === 0 ||
+        t.hour !== times[i - 1].hour ||
+        t.minute !== times[i - 1].minute,
+    );
   }
 
   private parseTime(): TimeOfDay {
@@ -813,6 +1407,63 @@ class Parser {
   }
 }
 
+/** Ordering key for a day spec: by first day, then last day. */
+function daySpecKey(spec: DayOfMonthSpec): number[] {
+  return spec.type === "single" ? [spec.day, spec.day] : [spec.start, spec.end];
+}
+
+/**
+ * Ordering key for a during spec: by first month-day, then last month-day.
+ * A month and the date range covering the same days share a key, so
+ * `jun, jun 1 to jun 30` is `jun`.
+ */
+function duringSpecKey(spec: DuringSpec): number[] {
+  const { startDay, endDay } = duringBounds(spec);
+  return [monthNumber(spec.start), startDay, monthNumber(spec.end), endDay];
+}
+
+/** Sort `items` by `key` and drop those whose key repeats the previous one. */
+function sortedUnique<T>(items: T[], key: (item: T) => number[]): T[] {
+  const keyed = items.map((item) => ({ item, k: key(item) }));
+  const compare = (a: number[], b: number[]) =>
+    a.reduce((acc, x, i) => acc || x - b[i], 0);
+  keyed.sort((a, b) => compare(a.k, b.k));
+  return keyed
+    .filter((e, i) => i === 0 || compare(e.k, keyed[i - 1].k) !== 0)
+    .map((e) => e.item);
+}
+
+/**
+ * An all-day hour step firing `minute` past the hour: `every hour at :15` is
+ * `every 1 hour from 00:15 to 23:59`.
+ */
+function hourlyAt(
+  interval: number,
+  minute: number,
+  dayFilter: DayFilter | null,
+): ScheduleExpr {
+  return {
+    type: "intervalRepeat",
+    interval,
+    unit: "hours",
+    from: { hour: 0, minute },
+    to: { hour: 23, minute: 59 },
+    dayFilter,
+  };
+}
+
+/**
+ * Convert decimal hours (1.5) to whole minutes, or null if they don't come to
+ * a whole number of minutes.
+ */
+function fractionalHoursToMinutes(text: string): number | null {
+  const [whole, frac] = text.split(".");
+  const scaled = BigInt(whole + frac) * 60n;
+  const scale = 10n ** BigInt(frac.length);
+  if (scaled % scale !== 0n) return null;
+  return Number(scaled / scale);
+}
+
 /** Parse an hron expression string into a ScheduleData AST. */
 export function parse(input: string): ScheduleData {
   const tokens = tokenize(input);
@@ -33,6 +33,31 @@ describe("parse roundtrip", () => {
     "timezone_clause",
     "combined_clauses",
     "case_insensitivity",
+    "ordinal_in_dates",
+    "twelve_hour_times",
+    "between_windows",
+    "frequency_keywords",
+    "time_of_day_keywords",
+    "time_list_normalization",
+    "default_midnight",
+    "weekday_exceptions",
+    "iso_weeks",
+    "during_dates",
+    "cron_shortcuts",
+    "ordinal_words",
+    "day_interval_filter",
+    "day_step_windows",
+    "last_n_days",
+    "day_list_canonicalization",
+    "fractional_hours",
+    "weekday_dates",
+    "weekday_interval",
+    "through_ranges",
+    "twelve_hour_windows",
+    "business_days",
+    "except_ranges",
+    "minutes_past_hour",
+    "ordinal_weekdays",
   ];
 
   for (const section of parseSections) {
@@ -139,6 +164,10 @@ describe("eval matches", () => {
     it(name, () => {
       const schedule = Schedule.parse(tc.expression);
       const dt = parseZoned(tc.datetime);
+      if (tc.error_contains) {
+        expect(() => schedule.matches(dt)).toThrow(tc.error_contains);
+        return;
+      }
       expect(schedule.matches(dt)).toBe(tc.expected);
     });
   }
@@ -244,7 +273,10 @@ describe("eval errors", () => {
 
 describe("cron", () => {
   describe("to_cron", () => {
-    const tests = spec.cron.to_cron.tests;
+    const tests = [
+      ...spec.cron.to_cron.tests,
+      ...spec.cron.to_cron_during.tests,
+    ];
     for (const tc of tests) {
       const name = tc.name ?? tc.hron;
       it(name, () => {
@@ -212,6 +212,26 @@ describe("edge cases", () => {
 
     expect(count).toBe(1);
   });
+
+  it("upcoming weekday occurrence cannot be matched", () => {
+    const schedule = Schedule.parse("on friday at 17:00 in UTC");
+    const from = parseZoned("2026-02-06T12:00:00+00:00[UTC]");
+
+    const results = [...schedule.occurrences(from)];
+
+    expect(results.length).toBe(1);
+    expect(() => schedule.matches(results[0])).toThrow("no evaluation time");
+  });
+
+  it("matches rejects relative anchor", () => {
+    const now = parseZoned("2026-02-06T12:00:00+00:00[UTC]");
+    for (const anchor of ["today", "now"]) {
+      const schedule = Schedule.parse(
+        `every 2 days at 09:00 starting ${anchor} in UTC`,
+      );
+      expect(() => schedule.matches(now)).toThrow("no evaluation time");
+    }
+  });
 });
 
 // =============================================================================